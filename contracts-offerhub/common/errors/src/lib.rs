@@ -0,0 +1,173 @@
+#![no_std]
+//! Error-code namespace shared by the OfferHub contracts.
+//!
+//! Each contract keeps its own `#[contracterror]` enum with codes below `RANGE`, and is
+//! assigned a domain here. When one contract calls another through `call`, a contract
+//! error from the callee is re-raised as `domain * RANGE + code`, so reputation NFT
+//! errors surface as 1xx, escrow errors as 2xx, dispute errors as 3xx and so on, and the
+//! backend can tell which contract failed from the code alone. Errors below `RANGE` are
+//! always the invoked contract's own.
+
+use soroban_sdk::xdr::{ScErrorCode, ScErrorType};
+use soroban_sdk::{
+    panic_with_error, Address, Env, Error, InvokeError, Symbol, TryFromVal, Val, Vec,
+};
+
+#[cfg(test)]
+mod test;
+
+/// Width of each contract's code range
+pub const RANGE: u32 = 100;
+
+/// Code range owner, in order of assignment. Never renumber a domain.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ContractDomain {
+    ReputationNft = 1,
+    Escrow = 2,
+    Dispute = 3,
+    FeeManager = 4,
+    UserRegistry = 5,
+    Rating = 6,
+    EscrowFactory = 7,
+    Emergency = 8,
+    Publication = 9,
+    PublicationRegistry = 10,
+    ProjectPublication = 11,
+    Stat = 12,
+    TaskRecord = 13,
+    WorkHistory = 14,
+    Referral = 15,
+    SkillCertification = 16,
+    InvoiceNft = 17,
+    MilestoneApproval = 18,
+    PaymentSplitter = 19,
+    Treasury = 20,
+    MultisigTreasury = 21,
+    TimelockController = 22,
+    Governance = 23,
+    TokenVesting = 24,
+    InsurancePool = 25,
+    FreelancerBond = 26,
+    PrepaidCredits = 27,
+    ReputationOracle = 28,
+    EscrowRegistry = 29,
+    ComplianceRegistry = 30,
+    ProfileNft = 31,
+    OrganizationRegistry = 32,
+}
+
+impl ContractDomain {
+    pub fn from_u32(value: u32) -> Option<Self> {
+        use ContractDomain::*;
+        const ALL: [ContractDomain; 32] = [
+            ReputationNft,
+            Escrow,
+            Dispute,
+            FeeManager,
+            UserRegistry,
+            Rating,
+            EscrowFactory,
+            Emergency,
+            Publication,
+            PublicationRegistry,
+            ProjectPublication,
+            Stat,
+            TaskRecord,
+            WorkHistory,
+            Referral,
+            SkillCertification,
+            InvoiceNft,
+            MilestoneApproval,
+            PaymentSplitter,
+            Treasury,
+            MultisigTreasury,
+            TimelockController,
+            Governance,
+            TokenVesting,
+            InsurancePool,
+            FreelancerBond,
+            PrepaidCredits,
+            ReputationOracle,
+            EscrowRegistry,
+            ComplianceRegistry,
+            ProfileNft,
+            OrganizationRegistry,
+        ];
+        ALL.into_iter().find(|domain| *domain as u32 == value)
+    }
+}
+
+/// Namespaced code of a contract's local error code
+pub fn namespaced(domain: ContractDomain, code: u32) -> u32 {
+    domain as u32 * RANGE + code
+}
+
+/// Owner and local code of a namespaced code; `None` for local codes
+pub fn split(code: u32) -> Option<(ContractDomain, u32)> {
+    ContractDomain::from_u32(code / RANGE).map(|domain| (domain, code % RANGE))
+}
+
+/// Move a callee's contract error into its domain. Host errors, and contract errors a
+/// nested call already namespaced, pass through unchanged.
+pub fn namespace_error(domain: ContractDomain, error: Error) -> Error {
+    if error.is_type(ScErrorType::Contract) && error.get_code() < RANGE {
+        Error::from_contract_error(namespaced(domain, error.get_code()))
+    } else {
+        error
+    }
+}
+
+/// Receives the raw error of a failed call
+struct CallError(Error);
+
+impl TryFrom<Error> for CallError {
+    type Error = Error;
+
+    fn try_from(error: Error) -> Result<Self, Error> {
+        Ok(CallError(error))
+    }
+}
+
+/// Invoke `func` on a contract of `domain`, namespacing any contract error it raises
+pub fn try_call<T>(
+    env: &Env,
+    domain: ContractDomain,
+    contract: &Address,
+    func: &Symbol,
+    args: Vec<Val>,
+) -> Result<T, Error>
+where
+    T: TryFromVal<Env, Val>,
+{
+    match env.try_invoke_contract::<T, CallError>(contract, func, args) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(_)) => Err(Error::from_type_and_code(
+            ScErrorType::Value,
+            ScErrorCode::UnexpectedType,
+        )),
+        Err(Ok(CallError(error))) => Err(namespace_error(domain, error)),
+        Err(Err(InvokeError::Contract(code))) => {
+            Err(namespace_error(domain, Error::from_contract_error(code)))
+        }
+        Err(Err(InvokeError::Abort)) => Err(namespace_error(
+            domain,
+            Error::from_type_and_code(ScErrorType::Context, ScErrorCode::InvalidAction),
+        )),
+    }
+}
+
+/// `try_call` that fails the current invocation with the namespaced error
+pub fn call<T>(
+    env: &Env,
+    domain: ContractDomain,
+    contract: &Address,
+    func: &Symbol,
+    args: Vec<Val>,
+) -> T
+where
+    T: TryFromVal<Env, Val>,
+{
+    try_call(env, domain, contract, func, args)
+        .unwrap_or_else(|error| panic_with_error!(env, error))
+}
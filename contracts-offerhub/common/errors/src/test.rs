@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contracterror, contractimpl, panic_with_error, vec, Env, IntoVal, Symbol,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+enum CalleeError {
+    Rejected = 7,
+}
+
+#[contract]
+struct Callee;
+
+#[contractimpl]
+impl Callee {
+    pub fn ok(_env: Env) -> u32 {
+        42
+    }
+
+    pub fn reject(env: Env) -> u32 {
+        panic_with_error!(&env, CalleeError::Rejected)
+    }
+
+    pub fn forward(env: Env, callee: Address) -> u32 {
+        call(
+            &env,
+            ContractDomain::Dispute,
+            &callee,
+            &Symbol::new(&env, "reject"),
+            vec![&env],
+        )
+    }
+}
+
+#[test]
+fn test_namespaced_codes_round_trip() {
+    assert_eq!(namespaced(ContractDomain::ReputationNft, 3), 103);
+    assert_eq!(namespaced(ContractDomain::Escrow, 16), 216);
+    assert_eq!(split(316), Some((ContractDomain::Dispute, 16)));
+    assert_eq!(
+        split(3216),
+        Some((ContractDomain::OrganizationRegistry, 16))
+    );
+    assert_eq!(split(16), None);
+    assert_eq!(split(9_900), None);
+}
+
+#[test]
+fn test_callee_errors_are_namespaced() {
+    let env = Env::default();
+    let callee = env.register(Callee, ());
+    let forwarder = env.register(Callee, ());
+
+    let value: Result<u32, Error> = try_call(
+        &env,
+        ContractDomain::Escrow,
+        &callee,
+        &Symbol::new(&env, "ok"),
+        vec![&env],
+    );
+    assert_eq!(value, Ok(42));
+
+    let rejected: Result<u32, Error> = try_call(
+        &env,
+        ContractDomain::Escrow,
+        &callee,
+        &Symbol::new(&env, "reject"),
+        vec![&env],
+    );
+    assert_eq!(rejected, Err(Error::from_contract_error(207)));
+
+    // The innermost domain wins across nested calls
+    let nested: Result<u32, Error> = try_call(
+        &env,
+        ContractDomain::Escrow,
+        &forwarder,
+        &Symbol::new(&env, "forward"),
+        vec![&env, callee.into_val(&env)],
+    );
+    assert_eq!(nested, Err(Error::from_contract_error(307)));
+}
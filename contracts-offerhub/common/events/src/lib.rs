@@ -0,0 +1,87 @@
+#![no_std]
+//! Event schema shared by the OfferHub contracts.
+//!
+//! Every event published through this crate has a topic constant from here as its first
+//! topic and one of the payload structs below as its data. Each payload starts with a
+//! `version` field holding `SCHEMA_VERSION`, so an indexer can map a topic to its payload
+//! type once and branch on the version if a payload ever changes shape.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+#[cfg(test)]
+mod test;
+
+/// Bumped whenever a payload below changes shape.
+pub const SCHEMA_VERSION: u32 = 1;
+
+// Contract administration, topics `(name, actor)`: `ActionEvent`
+pub const CONTRACT_INITIALIZED: &str = "contract_initialized";
+pub const CONTRACT_PAUSED: &str = "contract_paused";
+pub const CONTRACT_UNPAUSED: &str = "contract_unpaused";
+
+// Topics `(name, actor, surface)`: `ActionEvent`
+pub const SURFACE_PAUSED: &str = "surface_paused";
+pub const SURFACE_UNPAUSED: &str = "surface_unpaused";
+
+// Topics `(name, pauser)`: `ActionEvent`
+pub const PAUSER_ADDED: &str = "pauser_added";
+pub const PAUSER_REMOVED: &str = "pauser_removed";
+
+// Topics `(name, holder)`: `ActionEvent`
+pub const EMERGENCY_ADDED: &str = "emergency_added";
+pub const EMERGENCY_REMOVED: &str = "emergency_removed";
+
+// Topics `(name, actor)`: `UpgradeEvent` and `MigrationEvent`
+pub const CONTRACT_UPGRADED: &str = "contract_upgraded";
+pub const CONTRACT_MIGRATED: &str = "contract_migrated";
+
+// Topics `(name, stage)`: `LifecycleEvent`
+pub const ESCROW_LIFECYCLE: &str = "escrow_lifecycle";
+
+/// Payload of events whose subject is fully named by their topics.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionEvent {
+    pub version: u32,
+    pub timestamp: u64,
+}
+
+impl ActionEvent {
+    pub fn now(env: &Env) -> Self {
+        ActionEvent {
+            version: SCHEMA_VERSION,
+            timestamp: env.ledger().timestamp(),
+        }
+    }
+}
+
+/// Payload of `contract_upgraded`; `from_version` is the storage version at upgrade time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeEvent {
+    pub version: u32,
+    pub wasm_hash: BytesN<32>,
+    pub from_version: u32,
+    pub timestamp: u64,
+}
+
+/// Payload of `contract_migrated`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationEvent {
+    pub version: u32,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub timestamp: u64,
+}
+
+/// Payload of `escrow_lifecycle`, with the parties and the amount moved by the transition.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LifecycleEvent {
+    pub version: u32,
+    pub client: Address,
+    pub freelancer: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
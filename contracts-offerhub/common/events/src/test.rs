@@ -0,0 +1,30 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, IntoVal, TryFromVal, Val};
+
+#[test]
+fn test_action_event_is_stamped() {
+    let env = Env::default();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    let event = ActionEvent::now(&env);
+    assert_eq!(event.version, SCHEMA_VERSION);
+    assert_eq!(event.timestamp, 1_000);
+}
+
+#[test]
+fn test_lifecycle_event_round_trips() {
+    let env = Env::default();
+    let event = LifecycleEvent {
+        version: SCHEMA_VERSION,
+        client: Address::generate(&env),
+        freelancer: Address::generate(&env),
+        amount: 500,
+        timestamp: 42,
+    };
+
+    let val: Val = event.clone().into_val(&env);
+    assert_eq!(LifecycleEvent::try_from_val(&env, &val).unwrap(), event);
+}
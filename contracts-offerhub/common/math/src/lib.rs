@@ -0,0 +1,113 @@
+#![no_std]
+//! Checked arithmetic and basis-point helpers shared by the OfferHub contracts.
+//!
+//! Every operation returns `MathError` instead of wrapping or panicking, and each
+//! contract maps it onto its own error enum. Fees and splits are expressed in basis
+//! points out of `MAX_BPS`; the caller picks the `Rounding` so it is explicit which
+//! side of a split keeps the dust.
+
+#[cfg(test)]
+mod test;
+
+/// 100% in basis points
+pub const MAX_BPS: u32 = 10_000;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum MathError {
+    /// Result does not fit in an i128
+    Overflow,
+    /// Denominator is zero
+    DivisionByZero,
+    /// Basis points or percentage above 100%
+    InvalidBps,
+}
+
+/// Direction a division rounds in when it doesn't divide evenly
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Rounding {
+    /// Towards negative infinity
+    Down,
+    /// Towards positive infinity
+    Up,
+    /// To the nearest integer, ties rounding up
+    HalfUp,
+}
+
+pub fn add(a: i128, b: i128) -> Result<i128, MathError> {
+    a.checked_add(b).ok_or(MathError::Overflow)
+}
+
+pub fn sub(a: i128, b: i128) -> Result<i128, MathError> {
+    a.checked_sub(b).ok_or(MathError::Overflow)
+}
+
+pub fn mul(a: i128, b: i128) -> Result<i128, MathError> {
+    a.checked_mul(b).ok_or(MathError::Overflow)
+}
+
+/// `value * numerator / denominator`, rounded as requested
+pub fn mul_div(
+    value: i128,
+    numerator: i128,
+    denominator: i128,
+    rounding: Rounding,
+) -> Result<i128, MathError> {
+    if denominator == 0 {
+        return Err(MathError::DivisionByZero);
+    }
+    let mut product = mul(value, numerator)?;
+    let mut denominator = denominator;
+    if denominator < 0 {
+        product = product.checked_neg().ok_or(MathError::Overflow)?;
+        denominator = denominator.checked_neg().ok_or(MathError::Overflow)?;
+    }
+
+    let quotient = product.div_euclid(denominator);
+    let remainder = product.rem_euclid(denominator);
+    let round_up = match rounding {
+        Rounding::Down => false,
+        Rounding::Up => remainder > 0,
+        Rounding::HalfUp => remainder >= denominator - remainder,
+    };
+    if round_up {
+        add(quotient, 1)
+    } else {
+        Ok(quotient)
+    }
+}
+
+/// `bps` basis points of `amount`
+pub fn bps_of(amount: i128, bps: u32, rounding: Rounding) -> Result<i128, MathError> {
+    if bps > MAX_BPS {
+        return Err(MathError::InvalidBps);
+    }
+    mul_div(amount, bps as i128, MAX_BPS as i128, rounding)
+}
+
+/// `percent` percent of `amount`
+pub fn percent_of(amount: i128, percent: u32, rounding: Rounding) -> Result<i128, MathError> {
+    if percent > 100 {
+        return Err(MathError::InvalidBps);
+    }
+    bps_of(amount, percent * 100, rounding)
+}
+
+/// Splits `amount` into `(share, rest)` where `share` is `bps` of it rounded down.
+/// The two parts always add back up to `amount`, so no dust is lost.
+pub fn split_bps(amount: i128, bps: u32) -> Result<(i128, i128), MathError> {
+    let share = bps_of(amount, bps, Rounding::Down)?;
+    Ok((share, sub(amount, share)?))
+}
+
+/// `value` reduced by `discount_bps`, rounded down
+pub fn apply_discount_bps(value: i128, discount_bps: u32) -> Result<i128, MathError> {
+    if discount_bps > MAX_BPS {
+        return Err(MathError::InvalidBps);
+    }
+    mul_div(
+        value,
+        (MAX_BPS - discount_bps) as i128,
+        MAX_BPS as i128,
+        Rounding::Down,
+    )
+}
@@ -0,0 +1,68 @@
+#![cfg(test)]
+
+use super::*;
+
+#[test]
+fn test_checked_arithmetic_reports_overflow() {
+    assert_eq!(add(2, 3), Ok(5));
+    assert_eq!(sub(2, 3), Ok(-1));
+    assert_eq!(mul(-4, 3), Ok(-12));
+    assert_eq!(add(i128::MAX, 1), Err(MathError::Overflow));
+    assert_eq!(sub(i128::MIN, 1), Err(MathError::Overflow));
+    assert_eq!(mul(i128::MAX, 2), Err(MathError::Overflow));
+    assert_eq!(
+        mul_div(i128::MAX, 2, 2, Rounding::Down),
+        Err(MathError::Overflow)
+    );
+    assert_eq!(
+        mul_div(10, 1, 0, Rounding::Down),
+        Err(MathError::DivisionByZero)
+    );
+}
+
+#[test]
+fn test_mul_div_rounding() {
+    assert_eq!(mul_div(10, 1, 4, Rounding::Down), Ok(2));
+    assert_eq!(mul_div(10, 1, 4, Rounding::Up), Ok(3));
+    assert_eq!(mul_div(10, 1, 4, Rounding::HalfUp), Ok(3));
+    assert_eq!(mul_div(9, 1, 4, Rounding::HalfUp), Ok(2));
+    assert_eq!(mul_div(12, 1, 4, Rounding::Up), Ok(3));
+
+    // Down and Up are floor and ceiling, also for negative values
+    assert_eq!(mul_div(-10, 1, 4, Rounding::Down), Ok(-3));
+    assert_eq!(mul_div(-10, 1, 4, Rounding::Up), Ok(-2));
+    assert_eq!(mul_div(10, 1, -4, Rounding::Down), Ok(-3));
+}
+
+#[test]
+fn test_basis_points_and_percentages() {
+    assert_eq!(bps_of(1_000, 250, Rounding::Down), Ok(25));
+    assert_eq!(bps_of(999, 250, Rounding::Down), Ok(24));
+    assert_eq!(bps_of(999, 250, Rounding::Up), Ok(25));
+    assert_eq!(bps_of(1_000, MAX_BPS, Rounding::Down), Ok(1_000));
+    assert_eq!(
+        bps_of(1_000, MAX_BPS + 1, Rounding::Down),
+        Err(MathError::InvalidBps)
+    );
+
+    assert_eq!(percent_of(200, 5, Rounding::Down), Ok(10));
+    assert_eq!(
+        percent_of(200, 101, Rounding::Down),
+        Err(MathError::InvalidBps)
+    );
+
+    assert_eq!(apply_discount_bps(500, 2_000), Ok(400));
+    assert_eq!(apply_discount_bps(500, MAX_BPS), Ok(0));
+    assert_eq!(
+        apply_discount_bps(500, MAX_BPS + 1),
+        Err(MathError::InvalidBps)
+    );
+}
+
+#[test]
+fn test_split_keeps_remainder() {
+    assert_eq!(split_bps(1_001, 5_000), Ok((500, 501)));
+    assert_eq!(split_bps(1_000, 0), Ok((0, 1_000)));
+    assert_eq!(split_bps(1_000, MAX_BPS), Ok((1_000, 0)));
+    assert_eq!(split_bps(1_000, MAX_BPS + 1), Err(MathError::InvalidBps));
+}
@@ -0,0 +1,384 @@
+#![no_std]
+//! Pause flags shared by the OfferHub contracts.
+//!
+//! A contract can be paused as a whole, or one named surface at a time (e.g. `funding`
+//! on the escrow). Pausing is open to the contract's admin and to addresses it grants the
+//! PAUSER role; lifting a pause is left to the admin. The EMERGENCY role may do both and is
+//! meant for the emergency contract, which pushes platform-wide pauses and staged resumes
+//! through `set_paused`. Authorization is checked here; each contract passes in its own
+//! admin and maps `PauseError` onto its error enum.
+//!
+//! `impl_pausable!` adds the pause entry points to a contract.
+
+use offerhub_events::{
+    ActionEvent, CONTRACT_PAUSED, CONTRACT_UNPAUSED, EMERGENCY_ADDED, EMERGENCY_REMOVED,
+    PAUSER_ADDED, PAUSER_REMOVED, SURFACE_PAUSED, SURFACE_UNPAUSED,
+};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+#[cfg(test)]
+mod test;
+
+/// Instance key of the global flag. It is the key the contracts used before this crate,
+/// so deployed pause state carries over.
+pub const PAUSED: Symbol = symbol_short!("PAUSED");
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PauseKey {
+    /// Pause flag for one named surface
+    Surface(Symbol),
+    /// Holder of the PAUSER role
+    Pauser(Address),
+    /// Holder of the EMERGENCY role
+    Emergency(Address),
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PauseError {
+    /// Caller holds no role that allows the change, e.g. a pauser tried to lift a pause
+    Unauthorized,
+    AlreadyPaused,
+    NotPaused,
+}
+
+/// Set the global flag to unpaused; called from contract initialization
+pub fn init(env: &Env) {
+    env.storage().instance().set(&PAUSED, &false);
+}
+
+pub fn is_paused(env: &Env) -> bool {
+    env.storage().instance().get(&PAUSED).unwrap_or(false)
+}
+
+/// True when the surface or the whole contract is paused
+pub fn is_surface_paused(env: &Env, surface: &Symbol) -> bool {
+    is_paused(env)
+        || env
+            .storage()
+            .instance()
+            .get(&PauseKey::Surface(surface.clone()))
+            .unwrap_or(false)
+}
+
+pub fn is_pauser(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&PauseKey::Pauser(address.clone()))
+}
+
+pub fn is_emergency(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&PauseKey::Emergency(address.clone()))
+}
+
+/// Authorize `caller` as the admin, a pauser or an emergency role holder
+pub fn require_pauser(env: &Env, caller: &Address, admin: &Address) -> Result<(), PauseError> {
+    caller.require_auth();
+    if caller != admin && !is_pauser(env, caller) && !is_emergency(env, caller) {
+        return Err(PauseError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Authorize `caller` as the admin or an emergency role holder, who may lift pauses
+pub fn require_resumer(env: &Env, caller: &Address, admin: &Address) -> Result<(), PauseError> {
+    caller.require_auth();
+    if caller != admin && !is_emergency(env, caller) {
+        return Err(PauseError::Unauthorized);
+    }
+    Ok(())
+}
+
+pub fn pause(env: &Env, caller: &Address, admin: &Address) -> Result<(), PauseError> {
+    require_pauser(env, caller, admin)?;
+    if is_paused(env) {
+        return Err(PauseError::AlreadyPaused);
+    }
+
+    write_paused(env, caller, true);
+    Ok(())
+}
+
+pub fn unpause(env: &Env, caller: &Address, admin: &Address) -> Result<(), PauseError> {
+    require_resumer(env, caller, admin)?;
+    if !is_paused(env) {
+        return Err(PauseError::NotPaused);
+    }
+
+    write_paused(env, caller, false);
+    Ok(())
+}
+
+/// Idempotent form of `pause`/`unpause`, the entry point the emergency contract pushes to.
+/// Pausers may only pause; unpausing takes the admin or the EMERGENCY role.
+pub fn set_paused(
+    env: &Env,
+    caller: &Address,
+    admin: &Address,
+    paused: bool,
+) -> Result<(), PauseError> {
+    if paused {
+        require_pauser(env, caller, admin)?;
+    } else {
+        require_resumer(env, caller, admin)?;
+    }
+    if is_paused(env) != paused {
+        write_paused(env, caller, paused);
+    }
+    Ok(())
+}
+
+pub fn pause_surface(
+    env: &Env,
+    caller: &Address,
+    admin: &Address,
+    surface: Symbol,
+) -> Result<(), PauseError> {
+    require_pauser(env, caller, admin)?;
+    let key = PauseKey::Surface(surface.clone());
+    if env.storage().instance().get(&key).unwrap_or(false) {
+        return Err(PauseError::AlreadyPaused);
+    }
+
+    env.storage().instance().set(&key, &true);
+    env.events().publish(
+        (Symbol::new(env, SURFACE_PAUSED), caller.clone(), surface),
+        ActionEvent::now(env),
+    );
+    Ok(())
+}
+
+/// Lift a surface pause (admin or emergency role)
+pub fn unpause_surface(
+    env: &Env,
+    caller: &Address,
+    admin: &Address,
+    surface: Symbol,
+) -> Result<(), PauseError> {
+    require_resumer(env, caller, admin)?;
+    let key = PauseKey::Surface(surface.clone());
+    if !env.storage().instance().get(&key).unwrap_or(false) {
+        return Err(PauseError::NotPaused);
+    }
+
+    env.storage().instance().remove(&key);
+    env.events().publish(
+        (Symbol::new(env, SURFACE_UNPAUSED), caller.clone(), surface),
+        ActionEvent::now(env),
+    );
+    Ok(())
+}
+
+/// Grant the PAUSER role (admin only)
+pub fn add_pauser(
+    env: &Env,
+    caller: &Address,
+    admin: &Address,
+    pauser: Address,
+) -> Result<(), PauseError> {
+    require_admin(caller, admin)?;
+    env.storage()
+        .instance()
+        .set(&PauseKey::Pauser(pauser.clone()), &true);
+    env.events().publish(
+        (Symbol::new(env, PAUSER_ADDED), pauser),
+        ActionEvent::now(env),
+    );
+    Ok(())
+}
+
+/// Revoke the PAUSER role (admin only)
+pub fn remove_pauser(
+    env: &Env,
+    caller: &Address,
+    admin: &Address,
+    pauser: Address,
+) -> Result<(), PauseError> {
+    require_admin(caller, admin)?;
+    env.storage()
+        .instance()
+        .remove(&PauseKey::Pauser(pauser.clone()));
+    env.events().publish(
+        (Symbol::new(env, PAUSER_REMOVED), pauser),
+        ActionEvent::now(env),
+    );
+    Ok(())
+}
+
+/// Grant the EMERGENCY role (admin only)
+pub fn add_emergency(
+    env: &Env,
+    caller: &Address,
+    admin: &Address,
+    holder: Address,
+) -> Result<(), PauseError> {
+    require_admin(caller, admin)?;
+    env.storage()
+        .instance()
+        .set(&PauseKey::Emergency(holder.clone()), &true);
+    env.events().publish(
+        (Symbol::new(env, EMERGENCY_ADDED), holder),
+        ActionEvent::now(env),
+    );
+    Ok(())
+}
+
+/// Revoke the EMERGENCY role (admin only)
+pub fn remove_emergency(
+    env: &Env,
+    caller: &Address,
+    admin: &Address,
+    holder: Address,
+) -> Result<(), PauseError> {
+    require_admin(caller, admin)?;
+    env.storage()
+        .instance()
+        .remove(&PauseKey::Emergency(holder.clone()));
+    env.events().publish(
+        (Symbol::new(env, EMERGENCY_REMOVED), holder),
+        ActionEvent::now(env),
+    );
+    Ok(())
+}
+
+fn require_admin(caller: &Address, admin: &Address) -> Result<(), PauseError> {
+    caller.require_auth();
+    if caller != admin {
+        return Err(PauseError::Unauthorized);
+    }
+    Ok(())
+}
+
+fn write_paused(env: &Env, caller: &Address, paused: bool) {
+    env.storage().instance().set(&PAUSED, &paused);
+    let name = if paused {
+        CONTRACT_PAUSED
+    } else {
+        CONTRACT_UNPAUSED
+    };
+    env.events().publish(
+        (Symbol::new(env, name), caller.clone()),
+        ActionEvent::now(env),
+    );
+}
+
+/// Implements the pause entry points on a contract: `is_paused`, `pause`, `unpause`,
+/// `set_paused`, `add_pauser`, `remove_pauser`, `is_pauser`, `add_emergency`,
+/// `remove_emergency` and `is_emergency`.
+///
+/// `$error` must implement `From<PauseError>`. `$admin` resolves the pause admin for a
+/// caller, as a `fn(&Env, &Address) -> Result<Address, $error>`; most contracts ignore the
+/// caller, while ones run by a set of owners treat each owner as the admin.
+///
+/// A contract that folds its own state into `is_paused` uses the `@roles` form, which
+/// leaves `is_paused` out.
+#[macro_export]
+macro_rules! impl_pausable {
+    ($contract:ident, $error:ty, $admin:expr) => {
+        #[soroban_sdk::contractimpl]
+        impl $contract {
+            /// Check whether the contract is paused
+            pub fn is_paused(env: soroban_sdk::Env) -> bool {
+                $crate::is_paused(&env)
+            }
+        }
+
+        $crate::impl_pausable!(@roles $contract, $error, $admin);
+    };
+    (@roles $contract:ident, $error:ty, $admin:expr) => {
+        #[soroban_sdk::contractimpl]
+        impl $contract {
+            /// Pause the contract (admin or pauser)
+            pub fn pause(
+                env: soroban_sdk::Env,
+                caller: soroban_sdk::Address,
+            ) -> Result<(), $error> {
+                let admin = $crate::__pause_admin::<$error>(&env, &caller, $admin)?;
+                $crate::pause(&env, &caller, &admin).map_err(<$error>::from)
+            }
+
+            /// Unpause the contract (admin or emergency role)
+            pub fn unpause(
+                env: soroban_sdk::Env,
+                caller: soroban_sdk::Address,
+            ) -> Result<(), $error> {
+                let admin = $crate::__pause_admin::<$error>(&env, &caller, $admin)?;
+                $crate::unpause(&env, &caller, &admin).map_err(<$error>::from)
+            }
+
+            /// Idempotent pause toggle, the entry point the emergency contract pushes to.
+            /// Pausers may only pause; unpausing takes the admin or the EMERGENCY role.
+            pub fn set_paused(
+                env: soroban_sdk::Env,
+                caller: soroban_sdk::Address,
+                paused: bool,
+            ) -> Result<(), $error> {
+                let admin = $crate::__pause_admin::<$error>(&env, &caller, $admin)?;
+                $crate::set_paused(&env, &caller, &admin, paused).map_err(<$error>::from)
+            }
+
+            /// Grant the PAUSER role (admin only)
+            pub fn add_pauser(
+                env: soroban_sdk::Env,
+                admin: soroban_sdk::Address,
+                pauser: soroban_sdk::Address,
+            ) -> Result<(), $error> {
+                let pause_admin = $crate::__pause_admin::<$error>(&env, &admin, $admin)?;
+                $crate::add_pauser(&env, &admin, &pause_admin, pauser).map_err(<$error>::from)
+            }
+
+            /// Revoke the PAUSER role (admin only)
+            pub fn remove_pauser(
+                env: soroban_sdk::Env,
+                admin: soroban_sdk::Address,
+                pauser: soroban_sdk::Address,
+            ) -> Result<(), $error> {
+                let pause_admin = $crate::__pause_admin::<$error>(&env, &admin, $admin)?;
+                $crate::remove_pauser(&env, &admin, &pause_admin, pauser).map_err(<$error>::from)
+            }
+
+            /// Check whether an address holds the PAUSER role
+            pub fn is_pauser(env: soroban_sdk::Env, address: soroban_sdk::Address) -> bool {
+                $crate::is_pauser(&env, &address)
+            }
+
+            /// Grant the EMERGENCY role, which may also lift pauses (admin only)
+            pub fn add_emergency(
+                env: soroban_sdk::Env,
+                admin: soroban_sdk::Address,
+                holder: soroban_sdk::Address,
+            ) -> Result<(), $error> {
+                let pause_admin = $crate::__pause_admin::<$error>(&env, &admin, $admin)?;
+                $crate::add_emergency(&env, &admin, &pause_admin, holder).map_err(<$error>::from)
+            }
+
+            /// Revoke the EMERGENCY role (admin only)
+            pub fn remove_emergency(
+                env: soroban_sdk::Env,
+                admin: soroban_sdk::Address,
+                holder: soroban_sdk::Address,
+            ) -> Result<(), $error> {
+                let pause_admin = $crate::__pause_admin::<$error>(&env, &admin, $admin)?;
+                $crate::remove_emergency(&env, &admin, &pause_admin, holder)
+                    .map_err(<$error>::from)
+            }
+
+            /// Check whether an address holds the EMERGENCY role
+            pub fn is_emergency(env: soroban_sdk::Env, address: soroban_sdk::Address) -> bool {
+                $crate::is_emergency(&env, &address)
+            }
+        }
+    };
+}
+
+/// Runs the admin lookup passed to `impl_pausable!`; typed so closures need no annotations.
+#[doc(hidden)]
+pub fn __pause_admin<E>(
+    env: &Env,
+    caller: &Address,
+    admin: fn(&Env, &Address) -> Result<Address, E>,
+) -> Result<Address, E> {
+    admin(env, caller)
+}
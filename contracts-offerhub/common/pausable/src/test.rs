@@ -0,0 +1,222 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contracterror, symbol_short, Address, Env};
+
+#[contract]
+struct Host;
+
+#[contract]
+struct Pausable;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+enum Error {
+    Unauthorized = 1,
+    AlreadyPaused = 2,
+    NotPaused = 3,
+    NotInitialized = 4,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
+
+const ADMIN: Symbol = symbol_short!("ADMIN");
+
+crate::impl_pausable!(Pausable, Error, |env, _| env
+    .storage()
+    .instance()
+    .get(&ADMIN)
+    .ok_or(Error::NotInitialized));
+
+/// Run `f` as the host contract. Every call gets its own frame, since an address can
+/// only authorize once per frame.
+fn call<T>(env: &Env, host: &Address, f: impl FnOnce() -> T) -> T {
+    env.as_contract(host, f)
+}
+
+#[test]
+fn test_admin_and_pausers_toggle_flags() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let host = env.register(Host, ());
+    let admin = Address::generate(&env);
+    let pauser = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    call(&env, &host, || init(&env));
+    assert_eq!(
+        call(&env, &host, || pause(&env, &stranger, &admin)),
+        Err(PauseError::Unauthorized)
+    );
+    assert_eq!(
+        call(&env, &host, || add_pauser(
+            &env,
+            &pauser,
+            &admin,
+            stranger.clone()
+        )),
+        Err(PauseError::Unauthorized)
+    );
+
+    call(&env, &host, || {
+        add_pauser(&env, &admin, &admin, pauser.clone())
+    })
+    .unwrap();
+    call(&env, &host, || pause(&env, &pauser, &admin)).unwrap();
+    assert!(call(&env, &host, || is_paused(&env)));
+    assert_eq!(
+        call(&env, &host, || pause(&env, &admin, &admin)),
+        Err(PauseError::AlreadyPaused)
+    );
+
+    // Pushes are idempotent, and only the admin lifts a pause
+    call(&env, &host, || set_paused(&env, &pauser, &admin, true)).unwrap();
+    assert_eq!(
+        call(&env, &host, || set_paused(&env, &pauser, &admin, false)),
+        Err(PauseError::Unauthorized)
+    );
+    assert_eq!(
+        call(&env, &host, || unpause(&env, &pauser, &admin)),
+        Err(PauseError::Unauthorized)
+    );
+    call(&env, &host, || set_paused(&env, &admin, &admin, false)).unwrap();
+    assert!(!call(&env, &host, || is_paused(&env)));
+    assert_eq!(
+        call(&env, &host, || unpause(&env, &admin, &admin)),
+        Err(PauseError::NotPaused)
+    );
+
+    call(&env, &host, || {
+        remove_pauser(&env, &admin, &admin, pauser.clone())
+    })
+    .unwrap();
+    assert_eq!(
+        call(&env, &host, || set_paused(&env, &pauser, &admin, true)),
+        Err(PauseError::Unauthorized)
+    );
+}
+
+#[test]
+fn test_surfaces_pause_independently() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let host = env.register(Host, ());
+    let admin = Address::generate(&env);
+    let funding = symbol_short!("funding");
+    let release = symbol_short!("release");
+
+    call(&env, &host, || {
+        pause_surface(&env, &admin, &admin, funding.clone())
+    })
+    .unwrap();
+    assert!(call(&env, &host, || is_surface_paused(&env, &funding)));
+    assert!(!call(&env, &host, || is_surface_paused(&env, &release)));
+    assert!(!call(&env, &host, || is_paused(&env)));
+
+    // A global pause covers every surface
+    call(&env, &host, || pause(&env, &admin, &admin)).unwrap();
+    assert!(call(&env, &host, || is_surface_paused(&env, &release)));
+    call(&env, &host, || unpause(&env, &admin, &admin)).unwrap();
+
+    call(&env, &host, || {
+        unpause_surface(&env, &admin, &admin, funding.clone())
+    })
+    .unwrap();
+    assert!(!call(&env, &host, || is_surface_paused(&env, &funding)));
+    assert_eq!(
+        call(&env, &host, || unpause_surface(
+            &env,
+            &admin,
+            &admin,
+            funding.clone()
+        )),
+        Err(PauseError::NotPaused)
+    );
+}
+
+#[test]
+fn test_impl_pausable_entry_points() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Pausable, ());
+    let client = PausableClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let pauser = Address::generate(&env);
+
+    assert_eq!(client.try_pause(&admin), Err(Ok(Error::NotInitialized)));
+    env.as_contract(&contract_id, || env.storage().instance().set(&ADMIN, &admin));
+
+    assert_eq!(
+        client.try_add_pauser(&pauser, &pauser),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.add_pauser(&admin, &pauser);
+    assert!(client.is_pauser(&pauser));
+
+    client.pause(&pauser);
+    assert!(client.is_paused());
+    assert_eq!(client.try_pause(&admin), Err(Ok(Error::AlreadyPaused)));
+    assert_eq!(client.try_unpause(&pauser), Err(Ok(Error::Unauthorized)));
+    assert_eq!(
+        client.try_set_paused(&pauser, &false),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+    client.set_paused(&pauser, &true);
+    client.set_paused(&admin, &false);
+    assert!(!client.is_paused());
+
+    client.remove_pauser(&admin, &pauser);
+    assert!(!client.is_pauser(&pauser));
+}
+
+#[test]
+fn test_emergency_role_pauses_and_resumes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Pausable, ());
+    let client = PausableClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let emergency = Address::generate(&env);
+    env.as_contract(&contract_id, || env.storage().instance().set(&ADMIN, &admin));
+
+    assert_eq!(
+        client.try_set_paused(&emergency, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_add_emergency(&emergency, &emergency),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.add_emergency(&admin, &emergency);
+    assert!(client.is_emergency(&emergency));
+    assert!(!client.is_pauser(&emergency));
+
+    // Unlike a pauser, the emergency role can lift the pauses it pushes
+    client.set_paused(&emergency, &true);
+    assert!(client.is_paused());
+    client.set_paused(&emergency, &false);
+    assert!(!client.is_paused());
+    client.pause(&emergency);
+    client.unpause(&emergency);
+    assert!(!client.is_paused());
+
+    client.remove_emergency(&admin, &emergency);
+    assert!(!client.is_emergency(&emergency));
+    assert_eq!(
+        client.try_set_paused(&emergency, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+}
@@ -0,0 +1,47 @@
+#![no_std]
+//! Reentrancy lock for flows that move tokens through cross-contract calls.
+//!
+//! `ReentrancyGuard::enter` sets a flag in instance storage and the guard clears it when
+//! dropped, so the lock spans exactly the scope holding the guard. A failed invocation
+//! rolls back its storage writes, so a panic while locked never leaves the flag behind.
+//! The host already rejects direct re-entry into a contract; the guard also covers
+//! re-entry through another entry point of the same contract later in the call tree.
+
+use soroban_sdk::{symbol_short, Env, Symbol};
+
+#[cfg(test)]
+mod test;
+
+/// Instance key of the lock flag
+pub const LOCKED: Symbol = symbol_short!("REENTRY");
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ReentrancyError {
+    /// A guarded flow is already running in this contract
+    Reentered,
+}
+
+/// Held for the duration of a guarded flow
+pub struct ReentrancyGuard {
+    env: Env,
+}
+
+impl ReentrancyGuard {
+    pub fn enter(env: &Env) -> Result<Self, ReentrancyError> {
+        if is_locked(env) {
+            return Err(ReentrancyError::Reentered);
+        }
+        env.storage().instance().set(&LOCKED, &true);
+        Ok(ReentrancyGuard { env: env.clone() })
+    }
+}
+
+impl Drop for ReentrancyGuard {
+    fn drop(&mut self) {
+        self.env.storage().instance().remove(&LOCKED);
+    }
+}
+
+pub fn is_locked(env: &Env) -> bool {
+    env.storage().instance().has(&LOCKED)
+}
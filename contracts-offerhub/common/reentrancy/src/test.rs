@@ -0,0 +1,27 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{contract, Env};
+
+#[contract]
+struct Host;
+
+#[test]
+fn test_guard_locks_until_dropped() {
+    let env = Env::default();
+    let host = env.register(Host, ());
+
+    env.as_contract(&host, || {
+        {
+            let _guard = ReentrancyGuard::enter(&env).unwrap();
+            assert!(is_locked(&env));
+            assert!(matches!(
+                ReentrancyGuard::enter(&env),
+                Err(ReentrancyError::Reentered)
+            ));
+        }
+
+        assert!(!is_locked(&env));
+        let _guard = ReentrancyGuard::enter(&env).unwrap();
+    });
+}
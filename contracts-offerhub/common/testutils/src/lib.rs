@@ -0,0 +1,213 @@
+//! Fixtures shared by the OfferHub contract tests.
+//!
+//! Provides a ledger-ready `Env`, generated users, funded Stellar asset tokens and a
+//! `Suite` that deploys the core contracts into one `Env` and wires them together, so
+//! cross-contract tests don't each repeat the same registration and init calls. Tests
+//! call contracts through the clients generated by `#[contractimpl]`; `contract_result`
+//! turns a `try_*` call into a plain `Result` to compare against the contract's errors.
+//!
+//! This crate is only meant for `[dev-dependencies]`. A contract in the suite can use
+//! it as well, but the suite links its own copy of that contract, so the contract's tests
+//! should keep using the crate-local client and error types rather than the suite's.
+
+use core::fmt::Debug;
+
+use dispute_contract::DisputeResolutionContractClient;
+use escrow_contract::EscrowContractClient;
+use escrow_factory::EscrowFactoryClient;
+use fee_manager_contract::FeeManagerContractClient;
+use rating_contract::ContractClient as RatingContractClient;
+use reputation_nft_contract::ContractClient as ReputationNftClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, InvokeError};
+use user_registry_contract::ContractClient as UserRegistryClient;
+
+#[cfg(test)]
+mod test;
+
+/// Ledger timestamp every fixture `Env` starts at
+pub const START_TIMESTAMP: u64 = 1_000;
+
+/// Dispute timeout the suite initializes the dispute contract with
+pub const DISPUTE_TIMEOUT: u64 = 86_400;
+
+/// Escrow timeout the suite opens escrows with
+pub const ESCROW_TIMEOUT: u64 = 3_600;
+
+/// Default `Env` with all auths mocked and the ledger clock set to `START_TIMESTAMP`
+pub fn setup_env() -> Env {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = START_TIMESTAMP);
+    env
+}
+
+/// `N` fresh addresses, e.g. `let [client, freelancer] = users(&env);`
+pub fn users<const N: usize>(env: &Env) -> [Address; N] {
+    core::array::from_fn(|_| Address::generate(env))
+}
+
+/// Unwraps a generated client's `try_*` result into the contract's own error type.
+/// Host failures and return values that fail to convert still panic.
+pub fn contract_result<T, C: Debug, E>(
+    result: Result<Result<T, C>, Result<E, InvokeError>>,
+) -> Result<T, E> {
+    match result {
+        Ok(value) => Ok(value.expect("contract returned an unexpected value type")),
+        Err(Ok(error)) => Err(error),
+        Err(Err(error)) => panic!("contract invocation failed: {:?}", error),
+    }
+}
+
+/// Stellar asset contract with clients for transfers and minting
+pub struct TestToken<'a> {
+    pub address: Address,
+    pub client: TokenClient<'a>,
+    pub admin: StellarAssetClient<'a>,
+}
+
+impl TestToken<'_> {
+    pub fn mint(&self, to: &Address, amount: i128) {
+        self.admin.mint(to, &amount);
+    }
+
+    pub fn balance(&self, address: &Address) -> i128 {
+        self.client.balance(address)
+    }
+}
+
+/// Registers a Stellar asset contract administered by `admin`
+pub fn create_token<'a>(env: &Env, admin: &Address) -> TestToken<'a> {
+    let address = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    TestToken {
+        client: TokenClient::new(env, &address),
+        admin: StellarAssetClient::new(env, &address),
+        address,
+    }
+}
+
+/// Vouches for escrows the way the factory's escrow index does. The suite escrow is
+/// registered directly, so the factory never indexes it.
+#[contract]
+pub struct EscrowIndex;
+
+#[contractimpl]
+impl EscrowIndex {
+    pub fn add_escrow(env: Env, escrow: Address) {
+        env.storage().instance().set(&escrow, &1u32);
+    }
+
+    pub fn get_escrow_id_by_address(env: Env, escrow_address: Address) -> Option<u32> {
+        env.storage().instance().get(&escrow_address)
+    }
+}
+
+/// The core contracts deployed into one `Env` and initialized with a shared admin.
+/// The escrow is registered up front so the dispute contract can point at it;
+/// `open_escrow` initializes it for a deal.
+pub struct Suite<'a> {
+    pub env: Env,
+    pub admin: Address,
+    pub platform_wallet: Address,
+    pub token: TestToken<'a>,
+    pub fee_manager: FeeManagerContractClient<'a>,
+    pub reputation: ReputationNftClient<'a>,
+    pub user_registry: UserRegistryClient<'a>,
+    pub rating: RatingContractClient<'a>,
+    pub escrow: EscrowContractClient<'a>,
+    pub dispute: DisputeResolutionContractClient<'a>,
+    pub factory: EscrowFactoryClient<'a>,
+}
+
+impl<'a> Suite<'a> {
+    pub fn deploy(env: &Env) -> Self {
+        let [admin, platform_wallet] = users(env);
+        let token = create_token(env, &admin);
+
+        let fee_manager = FeeManagerContractClient::new(
+            env,
+            &env.register(fee_manager_contract::FeeManagerContract, ()),
+        );
+        fee_manager.initialize(&admin, &platform_wallet);
+
+        let reputation =
+            ReputationNftClient::new(env, &env.register(reputation_nft_contract::Contract, ()));
+        reputation.init(&admin);
+
+        let user_registry =
+            UserRegistryClient::new(env, &env.register(user_registry_contract::Contract, ()));
+        user_registry.initialize_admin(&admin);
+
+        let rating = RatingContractClient::new(env, &env.register(rating_contract::Contract, ()));
+        rating.init(&admin);
+
+        let escrow =
+            EscrowContractClient::new(env, &env.register(escrow_contract::EscrowContract, ()));
+        let dispute = DisputeResolutionContractClient::new(
+            env,
+            &env.register(dispute_contract::DisputeResolutionContract, ()),
+        );
+        dispute.initialize(
+            &admin,
+            &DISPUTE_TIMEOUT,
+            &escrow.address,
+            &fee_manager.address,
+        );
+
+        // Platform settings the escrow reads; the suite escrow is registered directly,
+        // so no real wasm is needed
+        let factory = EscrowFactoryClient::new(
+            env,
+            &env.register(
+                escrow_factory::EscrowFactory,
+                (BytesN::from_array(env, &[0; 32]),),
+            ),
+        );
+        factory.initialize(&admin);
+        factory.set_dispute_contract(&admin, &dispute.address);
+        factory.set_fee_manager(&admin, &fee_manager.address);
+
+        // Lets the suite escrow collect fees like a factory-deployed one
+        let escrow_index = EscrowIndexClient::new(env, &env.register(EscrowIndex, ()));
+        escrow_index.add_escrow(&escrow.address);
+        fee_manager.set_escrow_factory(&admin, &escrow_index.address);
+
+        Suite {
+            env: env.clone(),
+            admin,
+            platform_wallet,
+            token,
+            fee_manager,
+            reputation,
+            user_registry,
+            rating,
+            escrow,
+            dispute,
+            factory,
+        }
+    }
+
+    /// Initializes the suite escrow between `client` and `freelancer`, with the suite
+    /// admin as arbitrator, links it to the suite factory for platform settings such as
+    /// the fee manager, wires it to the reputation contract, and mints `amount` of the
+    /// suite token to the client.
+    pub fn open_escrow(&self, client: &Address, freelancer: &Address, amount: i128) {
+        self.token.mint(client, amount);
+        self.escrow.set_platform(&self.factory.address);
+        self.escrow.init_contract_full(
+            client,
+            freelancer,
+            &self.admin,
+            &self.token.address,
+            &amount,
+            &ESCROW_TIMEOUT,
+        );
+        self.escrow
+            .set_reputation_contract(client, &self.reputation.address);
+        self.reputation
+            .add_minter(&self.admin, &self.escrow.address);
+    }
+}
@@ -0,0 +1,106 @@
+#![no_std]
+//! Upgrade path shared by the OfferHub contracts.
+//!
+//! An upgrade is two steps. `upgrade` swaps the contract's wasm; the new code then runs
+//! `migrate`, which calls the contract's `Migratable::migrate_from` hook with the version
+//! recorded in storage and bumps that version to the new `Upgradeable::VERSION`. Storage
+//! therefore always names the layout it was written with, and a migration can't run twice.
+
+use offerhub_events::{
+    MigrationEvent, UpgradeEvent, CONTRACT_MIGRATED, CONTRACT_UPGRADED, SCHEMA_VERSION,
+};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Symbol};
+
+#[cfg(test)]
+mod test;
+
+/// Instance key of the storage layout version
+pub const VERSION: Symbol = symbol_short!("VERSION");
+
+/// Version reported by contracts deployed before they tracked one
+pub const UNVERSIONED: u32 = 0;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum UpgradeError {
+    /// Storage is already at this code's version
+    AlreadyMigrated,
+    /// Storage was written by a newer version than this code
+    DowngradeNotSupported,
+}
+
+/// A contract that can replace its own code
+pub trait Upgradeable {
+    type Error: From<UpgradeError>;
+
+    /// Storage layout version this build writes
+    const VERSION: u32;
+
+    /// Check `caller` may upgrade and migrate the contract, including its auth
+    fn authorize_upgrade(env: &Env, caller: &Address) -> Result<(), Self::Error>;
+}
+
+/// Storage migration run by the new code after an upgrade
+pub trait Migratable: Upgradeable {
+    /// Rewrite storage from `from_version` to `Self::VERSION`
+    fn migrate_from(env: &Env, from_version: u32) -> Result<(), Self::Error>;
+}
+
+/// Record the version on first initialization
+pub fn init_version<C: Upgradeable>(env: &Env) {
+    env.storage().instance().set(&VERSION, &C::VERSION);
+}
+
+pub fn version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&VERSION)
+        .unwrap_or(UNVERSIONED)
+}
+
+/// Swap the contract's code; storage stays at the old version until `migrate`
+pub fn upgrade<C: Upgradeable>(
+    env: &Env,
+    caller: &Address,
+    new_wasm_hash: BytesN<32>,
+) -> Result<(), C::Error> {
+    C::authorize_upgrade(env, caller)?;
+
+    env.events().publish(
+        (Symbol::new(env, CONTRACT_UPGRADED), caller.clone()),
+        UpgradeEvent {
+            version: SCHEMA_VERSION,
+            wasm_hash: new_wasm_hash.clone(),
+            from_version: version(env),
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
+    Ok(())
+}
+
+/// Bring storage up to `C::VERSION`
+pub fn migrate<C: Migratable>(env: &Env, caller: &Address) -> Result<(), C::Error> {
+    C::authorize_upgrade(env, caller)?;
+
+    let from_version = version(env);
+    if from_version == C::VERSION {
+        return Err(UpgradeError::AlreadyMigrated.into());
+    }
+    if from_version > C::VERSION {
+        return Err(UpgradeError::DowngradeNotSupported.into());
+    }
+
+    C::migrate_from(env, from_version)?;
+    env.storage().instance().set(&VERSION, &C::VERSION);
+
+    env.events().publish(
+        (Symbol::new(env, CONTRACT_MIGRATED), caller.clone()),
+        MigrationEvent {
+            version: SCHEMA_VERSION,
+            from_version,
+            to_version: C::VERSION,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    Ok(())
+}
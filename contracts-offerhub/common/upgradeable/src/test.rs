@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, symbol_short, Address, Env};
+
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const MIGRATED: Symbol = symbol_short!("MIGRATED");
+
+#[contract]
+struct Host;
+
+/// Run `f` as the host contract. Every call gets its own frame, since an address can
+/// only authorize once per frame.
+fn call<T>(env: &Env, host: &Address, f: impl FnOnce() -> T) -> T {
+    env.as_contract(host, f)
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum HostError {
+    Unauthorized,
+    Upgrade(UpgradeError),
+}
+
+impl From<UpgradeError> for HostError {
+    fn from(error: UpgradeError) -> Self {
+        HostError::Upgrade(error)
+    }
+}
+
+struct V1;
+struct V2;
+
+impl Upgradeable for V1 {
+    type Error = HostError;
+    const VERSION: u32 = 1;
+
+    fn authorize_upgrade(env: &Env, caller: &Address) -> Result<(), HostError> {
+        caller.require_auth();
+        let admin: Address = env.storage().instance().get(&ADMIN).unwrap();
+        if *caller != admin {
+            return Err(HostError::Unauthorized);
+        }
+        Ok(())
+    }
+}
+
+impl Migratable for V1 {
+    fn migrate_from(_env: &Env, _from_version: u32) -> Result<(), HostError> {
+        Ok(())
+    }
+}
+
+impl Upgradeable for V2 {
+    type Error = HostError;
+    const VERSION: u32 = 2;
+
+    fn authorize_upgrade(env: &Env, caller: &Address) -> Result<(), HostError> {
+        V1::authorize_upgrade(env, caller)
+    }
+}
+
+impl Migratable for V2 {
+    fn migrate_from(env: &Env, from_version: u32) -> Result<(), HostError> {
+        env.storage().instance().set(&MIGRATED, &from_version);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_migrate_runs_hook_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let host = env.register(Host, ());
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    call(&env, &host, || {
+        assert_eq!(version(&env), UNVERSIONED);
+        env.storage().instance().set(&ADMIN, &admin);
+        init_version::<V1>(&env);
+        assert_eq!(version(&env), 1);
+    });
+    assert_eq!(
+        call(&env, &host, || migrate::<V1>(&env, &admin)),
+        Err(HostError::Upgrade(UpgradeError::AlreadyMigrated))
+    );
+
+    assert_eq!(
+        call(&env, &host, || migrate::<V2>(&env, &stranger)),
+        Err(HostError::Unauthorized)
+    );
+    call(&env, &host, || migrate::<V2>(&env, &admin)).unwrap();
+    call(&env, &host, || {
+        assert_eq!(version(&env), 2);
+        assert_eq!(env.storage().instance().get(&MIGRATED), Some(1u32));
+    });
+    assert_eq!(
+        call(&env, &host, || migrate::<V2>(&env, &admin)),
+        Err(HostError::Upgrade(UpgradeError::AlreadyMigrated))
+    );
+
+    // Old code can't run against newer storage
+    assert_eq!(
+        call(&env, &host, || migrate::<V1>(&env, &admin)),
+        Err(HostError::Upgrade(UpgradeError::DowngradeNotSupported))
+    );
+}
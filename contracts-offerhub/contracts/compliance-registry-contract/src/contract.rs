@@ -0,0 +1,170 @@
+use crate::error::Error;
+use crate::event::{
+    admin_initialized, flags_cleared, flags_set, jurisdiction_restricted, officer_added,
+    officer_removed,
+};
+use crate::storage::{
+    add_officer, get_admin, get_record, is_officer, is_restricted, remove_officer, remove_record,
+    set_admin, set_record, set_restricted,
+};
+use crate::types::ComplianceRecord;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub struct ComplianceRegistryContract;
+
+impl ComplianceRegistryContract {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        admin_initialized(&env, admin);
+        Ok(())
+    }
+
+    pub fn add_officer(env: Env, admin: Address, officer: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if is_officer(&env, &officer) {
+            return Err(Error::OfficerAlreadyAdded);
+        }
+
+        add_officer(&env, &officer);
+        officer_added(&env, officer);
+        Ok(())
+    }
+
+    pub fn remove_officer(env: Env, admin: Address, officer: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if !is_officer(&env, &officer) {
+            return Err(Error::OfficerNotFound);
+        }
+
+        remove_officer(&env, &officer);
+        officer_removed(&env, officer);
+        Ok(())
+    }
+
+    pub fn is_officer(env: Env, officer: Address) -> bool {
+        is_officer(&env, &officer)
+    }
+
+    /// Record an address's jurisdiction and sanction-screening result, replacing any
+    /// earlier one. `expires_at` of 0 keeps the screening valid until it is replaced.
+    pub fn set_flags(
+        env: Env,
+        officer: Address,
+        address: Address,
+        jurisdiction: Symbol,
+        sanctioned: bool,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        Self::require_officer(&env, &officer)?;
+
+        let now = env.ledger().timestamp();
+        if expires_at != 0 && expires_at <= now {
+            return Err(Error::InvalidExpiry);
+        }
+
+        set_record(
+            &env,
+            &ComplianceRecord {
+                address: address.clone(),
+                jurisdiction: jurisdiction.clone(),
+                sanctioned,
+                screened_by: officer.clone(),
+                screened_at: now,
+                expires_at,
+            },
+        );
+        flags_set(&env, address, officer, jurisdiction, sanctioned);
+        Ok(())
+    }
+
+    /// Drop an address's record, leaving it unscreened
+    pub fn clear_flags(env: Env, officer: Address, address: Address) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        Self::require_officer(&env, &officer)?;
+        if get_record(&env, &address).is_none() {
+            return Err(Error::RecordNotFound);
+        }
+
+        remove_record(&env, &address);
+        flags_cleared(&env, address, officer);
+        Ok(())
+    }
+
+    pub fn get_record(env: Env, address: Address) -> Option<ComplianceRecord> {
+        get_record(&env, &address)
+    }
+
+    /// Close an operation to, or reopen it for, addresses in a jurisdiction (admin only)
+    pub fn set_jurisdiction_restriction(
+        env: Env,
+        admin: Address,
+        jurisdiction: Symbol,
+        operation: Symbol,
+        restricted: bool,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        Self::require_admin(&env, &admin)?;
+
+        set_restricted(&env, &jurisdiction, &operation, restricted);
+        jurisdiction_restricted(&env, jurisdiction, operation, restricted);
+        Ok(())
+    }
+
+    pub fn is_restricted(env: Env, jurisdiction: Symbol, operation: Symbol) -> bool {
+        is_restricted(&env, &jurisdiction, &operation)
+    }
+
+    /// An address may perform an operation once it has an unexpired screening that
+    /// found no sanctions, and its jurisdiction is not restricted for the operation.
+    /// Unscreened addresses are never allowed.
+    pub fn is_allowed(env: Env, address: Address, operation: Symbol) -> bool {
+        get_record(&env, &address).is_some_and(|record| {
+            !record.sanctioned
+                && (record.expires_at == 0 || record.expires_at > env.ledger().timestamp())
+                && !is_restricted(&env, &record.jurisdiction, &operation)
+        })
+    }
+
+    /// `is_allowed` as an error, for contracts that gate on it
+    pub fn require_allowed(env: Env, address: Address, operation: Symbol) -> Result<(), Error> {
+        if !Self::is_allowed(env, address, operation) {
+            return Err(Error::NotAllowed);
+        }
+        Ok(())
+    }
+
+    fn require_officer(env: &Env, officer: &Address) -> Result<(), Error> {
+        officer.require_auth();
+        get_admin(env).ok_or(Error::NotInitialized)?;
+        if !is_officer(env, officer) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        admin.require_auth();
+        let stored = get_admin(env).ok_or(Error::NotInitialized)?;
+        if *admin != stored {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// Address is already a compliance officer
+    OfficerAlreadyAdded = 4,
+    /// Address is not a compliance officer
+    OfficerNotFound = 5,
+    /// No compliance record exists for the address
+    RecordNotFound = 6,
+    /// Expiry must be in the future
+    InvalidExpiry = 7,
+    /// Address is not allowed to perform the operation
+    NotAllowed = 8,
+    /// Contract is already paused
+    AlreadyPaused = 9,
+    /// Contract is not paused
+    NotPaused = 10,
+    /// Contract is paused
+    ContractPaused = 11,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
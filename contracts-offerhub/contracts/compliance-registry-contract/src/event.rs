@@ -0,0 +1,41 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub fn admin_initialized(e: &Env, admin: Address) {
+    let topics = (Symbol::new(e, "admin_initialized"),);
+    e.events().publish(topics, admin);
+}
+
+pub fn officer_added(e: &Env, officer: Address) {
+    let topics = (Symbol::new(e, "officer_added"), officer);
+    e.events().publish(topics, e.ledger().timestamp());
+}
+
+pub fn officer_removed(e: &Env, officer: Address) {
+    let topics = (Symbol::new(e, "officer_removed"), officer);
+    e.events().publish(topics, e.ledger().timestamp());
+}
+
+pub fn flags_set(
+    e: &Env,
+    address: Address,
+    officer: Address,
+    jurisdiction: Symbol,
+    sanctioned: bool,
+) {
+    let topics = (Symbol::new(e, "compliance_flags_set"), address, officer);
+    e.events().publish(topics, (jurisdiction, sanctioned));
+}
+
+pub fn flags_cleared(e: &Env, address: Address, officer: Address) {
+    let topics = (Symbol::new(e, "compliance_flags_cleared"), address, officer);
+    e.events().publish(topics, e.ledger().timestamp());
+}
+
+pub fn jurisdiction_restricted(e: &Env, jurisdiction: Symbol, operation: Symbol, restricted: bool) {
+    let topics = (
+        Symbol::new(e, "jurisdiction_restricted"),
+        jurisdiction,
+        operation,
+    );
+    e.events().publish(topics, restricted);
+}
@@ -0,0 +1,105 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::ComplianceRegistryContract;
+pub use crate::error::Error;
+pub use crate::types::ComplianceRecord;
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| {
+    ComplianceRegistryContract::pause_admin(env)
+});
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the contract with an admin address
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        ComplianceRegistryContract::initialize(env, admin)
+    }
+
+    /// Grant the compliance role (admin only)
+    pub fn add_officer(env: Env, admin: Address, officer: Address) -> Result<(), Error> {
+        ComplianceRegistryContract::add_officer(env, admin, officer)
+    }
+
+    /// Revoke the compliance role (admin only)
+    pub fn remove_officer(env: Env, admin: Address, officer: Address) -> Result<(), Error> {
+        ComplianceRegistryContract::remove_officer(env, admin, officer)
+    }
+
+    /// Check whether an address holds the compliance role
+    pub fn is_officer(env: Env, officer: Address) -> bool {
+        ComplianceRegistryContract::is_officer(env, officer)
+    }
+
+    /// Record an address's jurisdiction and screening result (compliance officers only)
+    pub fn set_flags(
+        env: Env,
+        officer: Address,
+        address: Address,
+        jurisdiction: Symbol,
+        sanctioned: bool,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        ComplianceRegistryContract::set_flags(
+            env,
+            officer,
+            address,
+            jurisdiction,
+            sanctioned,
+            expires_at,
+        )
+    }
+
+    /// Remove an address's compliance record (compliance officers only)
+    pub fn clear_flags(env: Env, officer: Address, address: Address) -> Result<(), Error> {
+        ComplianceRegistryContract::clear_flags(env, officer, address)
+    }
+
+    /// Get an address's compliance record
+    pub fn get_record(env: Env, address: Address) -> Option<ComplianceRecord> {
+        ComplianceRegistryContract::get_record(env, address)
+    }
+
+    /// Restrict or reopen an operation for a jurisdiction (admin only)
+    pub fn set_jurisdiction_restriction(
+        env: Env,
+        admin: Address,
+        jurisdiction: Symbol,
+        operation: Symbol,
+        restricted: bool,
+    ) -> Result<(), Error> {
+        ComplianceRegistryContract::set_jurisdiction_restriction(
+            env,
+            admin,
+            jurisdiction,
+            operation,
+            restricted,
+        )
+    }
+
+    /// Check whether an operation is restricted for a jurisdiction
+    pub fn is_restricted(env: Env, jurisdiction: Symbol, operation: Symbol) -> bool {
+        ComplianceRegistryContract::is_restricted(env, jurisdiction, operation)
+    }
+
+    /// Check whether an address may perform an operation
+    pub fn is_allowed(env: Env, address: Address, operation: Symbol) -> bool {
+        ComplianceRegistryContract::is_allowed(env, address, operation)
+    }
+
+    /// Fail with `NotAllowed` unless the address may perform the operation
+    pub fn require_allowed(env: Env, address: Address, operation: Symbol) -> Result<(), Error> {
+        ComplianceRegistryContract::require_allowed(env, address, operation)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::types::ComplianceRecord;
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Officer(Address),
+    Record(Address),
+    /// (jurisdiction, operation) -> the operation is closed to addresses in the jurisdiction
+    Restricted(Symbol, Symbol),
+}
+
+/// Get the admin address
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn is_officer(env: &Env, officer: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Officer(officer.clone()))
+}
+
+pub fn add_officer(env: &Env, officer: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Officer(officer.clone()), &true);
+}
+
+pub fn remove_officer(env: &Env, officer: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Officer(officer.clone()));
+}
+
+pub fn get_record(env: &Env, address: &Address) -> Option<ComplianceRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Record(address.clone()))
+}
+
+pub fn set_record(env: &Env, record: &ComplianceRecord) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Record(record.address.clone()), record);
+}
+
+pub fn remove_record(env: &Env, address: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Record(address.clone()));
+}
+
+pub fn is_restricted(env: &Env, jurisdiction: &Symbol, operation: &Symbol) -> bool {
+    env.storage().persistent().has(&DataKey::Restricted(
+        jurisdiction.clone(),
+        operation.clone(),
+    ))
+}
+
+pub fn set_restricted(env: &Env, jurisdiction: &Symbol, operation: &Symbol, restricted: bool) {
+    let key = DataKey::Restricted(jurisdiction.clone(), operation.clone());
+    if restricted {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
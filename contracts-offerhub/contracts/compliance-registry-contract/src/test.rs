@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, Address, Env};
+
+fn setup(env: &Env) -> (ContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let client = ContractClient::new(env, &env.register(Contract, ()));
+    let admin = Address::generate(env);
+    let officer = Address::generate(env);
+    client.initialize(&admin);
+    client.add_officer(&admin, &officer);
+    (client, admin, officer)
+}
+
+#[test]
+fn test_only_officers_set_flags() {
+    let env = Env::default();
+    let (client, admin, officer) = setup(&env);
+    let user = Address::generate(&env);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_flags(&stranger, &user, &symbol_short!("US"), &false, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_flags(&officer, &user, &symbol_short!("US"), &false, &1_000),
+        Err(Ok(Error::InvalidExpiry))
+    );
+    assert_eq!(
+        client.try_add_officer(&admin, &officer),
+        Err(Ok(Error::OfficerAlreadyAdded))
+    );
+
+    client.set_flags(&officer, &user, &symbol_short!("US"), &false, &0);
+    let record = client.get_record(&user).unwrap();
+    assert_eq!(record.screened_by, officer);
+    assert_eq!(record.screened_at, 1_000);
+
+    client.remove_officer(&admin, &officer);
+    assert!(!client.is_officer(&officer));
+    assert_eq!(
+        client.try_clear_flags(&officer, &user),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_is_allowed_checks_screening_sanctions_and_jurisdiction() {
+    let env = Env::default();
+    let (client, admin, officer) = setup(&env);
+    let user = Address::generate(&env);
+    let fund = symbol_short!("fund");
+
+    // Unscreened addresses are not allowed
+    assert!(!client.is_allowed(&user, &fund));
+    assert_eq!(
+        client.try_require_allowed(&user, &fund),
+        Err(Ok(Error::NotAllowed))
+    );
+
+    client.set_flags(&officer, &user, &symbol_short!("DE"), &false, &5_000);
+    assert!(client.is_allowed(&user, &fund));
+
+    client.set_jurisdiction_restriction(&admin, &symbol_short!("DE"), &fund, &true);
+    assert!(!client.is_allowed(&user, &fund));
+    assert!(client.is_allowed(&user, &symbol_short!("withdraw")));
+    client.set_jurisdiction_restriction(&admin, &symbol_short!("DE"), &fund, &false);
+    assert!(client.is_allowed(&user, &fund));
+
+    env.ledger().with_mut(|l| l.timestamp = 5_000);
+    assert!(!client.is_allowed(&user, &fund));
+
+    client.set_flags(&officer, &user, &symbol_short!("DE"), &true, &0);
+    assert!(!client.is_allowed(&user, &fund));
+
+    client.clear_flags(&officer, &user);
+    assert_eq!(client.get_record(&user), None);
+}
+
+#[test]
+fn test_pause_blocks_screening() {
+    let env = Env::default();
+    let (client, admin, officer) = setup(&env);
+    let user = Address::generate(&env);
+    let pauser = Address::generate(&env);
+
+    assert_eq!(client.try_pause(&officer), Err(Ok(Error::Unauthorized)));
+    client.add_pauser(&admin, &pauser);
+    client.pause(&pauser);
+    assert!(client.is_paused());
+    assert_eq!(
+        client.try_set_flags(&officer, &user, &symbol_short!("US"), &false, &0),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    client.unpause(&admin);
+    client.set_flags(&officer, &user, &symbol_short!("US"), &false, &0);
+    assert!(client.get_record(&user).is_some());
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{contracttype, Address, Symbol};
+
+/// Latest screening result for an address, written by a compliance officer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComplianceRecord {
+    pub address: Address,
+    pub jurisdiction: Symbol,
+    pub sanctioned: bool,
+    pub screened_by: Address,
+    pub screened_at: u64,
+    pub expires_at: u64, // 0 = the screening does not expire
+}
@@ -1,11 +1,25 @@
 use soroban_sdk::{panic_with_error, Address, Env, Map, String, Vec};
 
 use crate::{
-    storage::{ARBITRATORS, MEDIATORS, PAUSED},
+    contract::is_paused,
+    storage::{ARBITRATOR, ARBITRATORS, MEDIATORS},
     types::{ArbitratorData},
     error::{ Error},
 };
 
+pub fn check_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+    admin.require_auth();
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&ARBITRATOR)
+        .ok_or(Error::NotInitialized)?;
+    if stored_admin != *admin {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
+
 pub fn add_arbitrator(
     env: &Env,
     admin: Address,
@@ -14,7 +28,7 @@ pub fn add_arbitrator(
 ) -> Result<(), Error> {
     admin.require_auth();
 
-    if env.storage().instance().get(&PAUSED).unwrap_or(false) {
+    if is_paused(env) {
         return Err(Error::ContractPaused);
     }
 
@@ -51,7 +65,7 @@ pub fn add_arbitrator(
 pub fn remove_arbitrator(env: &Env, admin: Address, arbitrator: Address) -> Result<(), Error> {
     admin.require_auth();
 
-    if env.storage().instance().get(&PAUSED).unwrap_or(false) {
+    if is_paused(env) {
         return Err(Error::ContractPaused);
     }
 
@@ -95,7 +109,7 @@ pub fn is_valid_arbitrator(env: &Env, arbitrator: &Address) -> bool {
 pub fn add_mediator(env: &Env, admin: Address, mediator: Address) -> Result<(), Error> {
     admin.require_auth();
 
-    if env.storage().instance().get(&PAUSED).unwrap_or(false) {
+    if is_paused(env) {
         return Err(Error::ContractPaused);
     }
 
@@ -123,7 +137,7 @@ pub fn add_mediator(env: &Env, admin: Address, mediator: Address) -> Result<(),
 pub fn remove_mediator(env: &Env, admin: Address, mediator: Address) -> Result<(), Error> {
     admin.require_auth();
 
-    if env.storage().instance().get(&PAUSED).unwrap_or(false) {
+    if is_paused(env) {
         return Err(Error::ContractPaused);
     }
 
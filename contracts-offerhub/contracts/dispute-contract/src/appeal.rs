@@ -0,0 +1,346 @@
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    vec, Address, Env, IntoVal, Map, String, Symbol, Vec,
+};
+
+use crate::{
+    access::{check_admin, is_valid_arbitrator},
+    category::can_serve_category,
+    contract::{get_dispute, invoke_escrow_resolution, is_paused, SPLIT_TOTAL_BPS},
+    error::Error,
+    staking::{is_staked_arbitrator, staking_enabled},
+    storage::{APPEALS, APPEAL_CONFIG, DISPUTES, FEE_MANAGER, PENDING_ESCROW_SETTLEMENT},
+    timeline::appeal_deadline,
+    types::{Appeal, AppealConfig, DisputeData, DisputeOutcome, DisputeState},
+};
+
+const TOKEN_TRANSFER: &str = "transfer";
+const FEE_MANAGER_DEPOSIT_FEE: &str = "deposit_fee";
+// An appeal panel has to be larger than the single arbitrator it reviews
+const MIN_APPEAL_PANEL: u32 = 3;
+
+pub fn set_appeal_config(env: &Env, admin: Address, config: AppealConfig) -> Result<(), Error> {
+    check_admin(env, &admin)?;
+
+    if config.min_bond <= 0 || config.panel_size < MIN_APPEAL_PANEL {
+        return Err(Error::InvalidAppealConfig);
+    }
+
+    env.storage().instance().set(&APPEAL_CONFIG, &config);
+
+    env.events().publish(
+        (String::from_str(env, "appeal_config_updated"), admin),
+        (config.token, config.min_bond, config.panel_size),
+    );
+
+    Ok(())
+}
+
+pub fn get_appeal_config(env: &Env) -> Option<AppealConfig> {
+    env.storage().instance().get(&APPEAL_CONFIG)
+}
+
+pub fn appeals_enabled(env: &Env) -> bool {
+    env.storage().instance().has(&APPEAL_CONFIG)
+}
+
+/// Reopens a resolved dispute before a juror panel. The bond is held until the panel rules
+/// and is forfeited if the original decision stands.
+pub fn appeal(env: &Env, party: Address, dispute_id: u32, bond: i128) -> Result<(), Error> {
+    party.require_auth();
+
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let config = get_appeal_config(env).ok_or(Error::AppealsNotConfigured)?;
+
+    let dispute = get_dispute(env, dispute_id);
+    if dispute.initiator != party && dispute.respondent != Some(party.clone()) {
+        return Err(Error::Unauthorized);
+    }
+
+    // Timed-out disputes are closed, not decided, so there is nothing to appeal
+    if dispute.state != DisputeState::Resolved {
+        return Err(Error::InvalidOutcome);
+    }
+
+    if env.ledger().timestamp() > appeal_deadline(env, &dispute) {
+        return Err(Error::AppealWindowClosed);
+    }
+
+    if get_appeal(env, dispute_id).is_some() {
+        return Err(Error::AppealAlreadyFiled);
+    }
+
+    if bond < config.min_bond {
+        return Err(Error::InsufficientBond);
+    }
+
+    env.invoke_contract::<()>(
+        &config.token,
+        &Symbol::new(env, TOKEN_TRANSFER),
+        (party.clone(), env.current_contract_address(), bond).into_val(env),
+    );
+
+    let appeal = Appeal {
+        appellant: party.clone(),
+        bond,
+        original_outcome: dispute.outcome,
+        jurors: Vec::new(env),
+        votes: Map::new(env),
+        filed_at: env.ledger().timestamp(),
+        final_outcome: DisputeOutcome::None,
+    };
+    store_appeal(env, dispute_id, &appeal);
+
+    env.events().publish(
+        (String::from_str(env, "dispute_appealed"), dispute_id),
+        (party, bond, dispute.outcome),
+    );
+
+    Ok(())
+}
+
+pub fn assign_appeal_jurors(
+    env: &Env,
+    admin: Address,
+    dispute_id: u32,
+    jurors: Vec<Address>,
+) -> Result<(), Error> {
+    check_admin(env, &admin)?;
+
+    let config = get_appeal_config(env).ok_or(Error::AppealsNotConfigured)?;
+    let mut appeal = get_open_appeal(env, dispute_id)?;
+
+    if jurors.len() < config.panel_size {
+        return Err(Error::AppealPanelIncomplete);
+    }
+
+    // Jurors must be eligible arbitrators and independent of the original decision
+    let dispute = get_dispute(env, dispute_id);
+    for (i, juror) in jurors.iter().enumerate() {
+        if !is_valid_arbitrator(env, &juror)
+            || (staking_enabled(env) && !is_staked_arbitrator(env, &juror))
+            || dispute.arbitrator == Some(juror.clone())
+            || dispute.mediator == Some(juror.clone())
+            || !can_serve_category(env, &juror, dispute.category)
+            || jurors.first_index_of(&juror) != Some(i as u32)
+        {
+            return Err(Error::InvalidArbitrator);
+        }
+    }
+
+    appeal.jurors = jurors.clone();
+    appeal.votes = Map::new(env);
+    store_appeal(env, dispute_id, &appeal);
+
+    env.events().publish(
+        (String::from_str(env, "appeal_jurors_assigned"), dispute_id),
+        jurors,
+    );
+
+    Ok(())
+}
+
+pub fn cast_appeal_vote(
+    env: &Env,
+    juror: Address,
+    dispute_id: u32,
+    outcome: DisputeOutcome,
+) -> Result<(), Error> {
+    juror.require_auth();
+
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    if outcome == DisputeOutcome::None {
+        return Err(Error::InvalidOutcome);
+    }
+
+    let mut appeal = get_open_appeal(env, dispute_id)?;
+    if !appeal.jurors.contains(&juror) {
+        return Err(Error::Unauthorized);
+    }
+    if appeal.votes.contains_key(juror.clone()) {
+        return Err(Error::VoteAlreadyCast);
+    }
+
+    appeal.votes.set(juror.clone(), outcome);
+    store_appeal(env, dispute_id, &appeal);
+
+    env.events().publish(
+        (String::from_str(env, "appeal_vote_cast"), dispute_id),
+        (juror, outcome),
+    );
+
+    Ok(())
+}
+
+/// Settles the appeal once every juror has voted. The original decision stands unless
+/// another outcome wins a strict majority of the panel.
+pub fn finalize_appeal(env: &Env, dispute_id: u32) -> Result<DisputeOutcome, Error> {
+    let config = get_appeal_config(env).ok_or(Error::AppealsNotConfigured)?;
+    let mut appeal = get_open_appeal(env, dispute_id)?;
+
+    if appeal.jurors.is_empty() || appeal.votes.len() < appeal.jurors.len() {
+        return Err(Error::AppealPanelIncomplete);
+    }
+
+    let final_outcome = majority_outcome(&appeal);
+    let upheld = final_outcome == appeal.original_outcome;
+
+    // A failed appeal pays its bond to the platform as a fee, a successful one gets it back.
+    // The bond token has to be an accepted fee asset on the fee manager, which pulls the
+    // bond from this contract.
+    if upheld {
+        let fee_manager: Address = env
+            .storage()
+            .instance()
+            .get(&FEE_MANAGER)
+            .ok_or(Error::NotInitialized)?;
+        env.authorize_as_current_contract(vec![
+            env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: config.token.clone(),
+                    fn_name: Symbol::new(env, TOKEN_TRANSFER),
+                    args: (
+                        env.current_contract_address(),
+                        fee_manager.clone(),
+                        appeal.bond,
+                    )
+                        .into_val(env),
+                },
+                sub_invocations: vec![env],
+            }),
+        ]);
+        env.invoke_contract::<i128>(
+            &fee_manager,
+            &Symbol::new(env, FEE_MANAGER_DEPOSIT_FEE),
+            (env.current_contract_address(), config.token, appeal.bond).into_val(env),
+        );
+    } else {
+        env.invoke_contract::<()>(
+            &config.token,
+            &Symbol::new(env, TOKEN_TRANSFER),
+            (
+                env.current_contract_address(),
+                appeal.appellant.clone(),
+                appeal.bond,
+            )
+                .into_val(env),
+        );
+    }
+
+    appeal.final_outcome = final_outcome;
+    store_appeal(env, dispute_id, &appeal);
+
+    let mut disputes: Map<u32, DisputeData> = env.storage().instance().get(&DISPUTES).unwrap();
+    let mut dispute = disputes.get(dispute_id).ok_or(Error::DisputeNotFound)?;
+    if final_outcome != appeal.original_outcome {
+        // An overturned split no longer applies
+        dispute.freelancer_bps = None;
+    }
+    dispute.outcome = final_outcome;
+    dispute.resolution_timestamp = Some(env.ledger().timestamp());
+    disputes.set(dispute_id, dispute.clone());
+    env.storage().instance().set(&DISPUTES, &disputes);
+
+    settle_pending_escrow(env, dispute_id, &dispute);
+
+    env.events().publish(
+        (String::from_str(env, "appeal_finalized"), dispute_id),
+        (appeal.original_outcome, final_outcome, upheld),
+    );
+
+    Ok(final_outcome)
+}
+
+/// Pushes an unappealed outcome to the escrow once the appeal window has passed.
+pub fn finalize_resolution(env: &Env, dispute_id: u32) -> Result<(), Error> {
+    if !appeals_enabled(env) {
+        return Err(Error::AppealsNotConfigured);
+    }
+    let dispute = get_dispute(env, dispute_id);
+
+    if get_appeal(env, dispute_id).is_some() {
+        return Err(Error::AppealAlreadyFiled);
+    }
+
+    if env.ledger().timestamp() <= appeal_deadline(env, &dispute) {
+        return Err(Error::AppealWindowOpen);
+    }
+
+    settle_pending_escrow(env, dispute_id, &dispute);
+    Ok(())
+}
+
+pub fn get_appeal(env: &Env, dispute_id: u32) -> Option<Appeal> {
+    env.storage().persistent().get(&(APPEALS, dispute_id))
+}
+
+fn get_open_appeal(env: &Env, dispute_id: u32) -> Result<Appeal, Error> {
+    let appeal = get_appeal(env, dispute_id).ok_or(Error::AppealNotFound)?;
+    if appeal.final_outcome != DisputeOutcome::None {
+        return Err(Error::DisputeAlreadyResolved);
+    }
+    Ok(appeal)
+}
+
+fn store_appeal(env: &Env, dispute_id: u32, appeal: &Appeal) {
+    env.storage()
+        .persistent()
+        .set(&(APPEALS, dispute_id), appeal);
+}
+
+// A deferred outcome is settled long after the deciding arbitrator signed, so it goes
+// through the escrow's `apply_resolution`, which only needs this contract's authorization.
+fn settle_pending_escrow(env: &Env, dispute_id: u32, dispute: &DisputeData) {
+    let key = (PENDING_ESCROW_SETTLEMENT, dispute_id);
+    if !env.storage().persistent().has(&key) {
+        return;
+    }
+    let Some(escrow_contract) = dispute.escrow_contract.clone() else {
+        return;
+    };
+    env.storage().persistent().remove(&key);
+
+    let freelancer_bps = dispute.freelancer_bps.unwrap_or(match dispute.outcome {
+        DisputeOutcome::FavorFreelancer => SPLIT_TOTAL_BPS,
+        DisputeOutcome::FavorClient => 0,
+        DisputeOutcome::Split | DisputeOutcome::None => SPLIT_TOTAL_BPS / 2,
+    });
+    invoke_escrow_resolution(
+        env,
+        dispute_id,
+        &escrow_contract,
+        env.current_contract_address(),
+        dispute.outcome,
+        Some(freelancer_bps),
+    );
+}
+
+fn majority_outcome(appeal: &Appeal) -> DisputeOutcome {
+    let (mut freelancer, mut client, mut split) = (0u32, 0u32, 0u32);
+    for (_, outcome) in appeal.votes.iter() {
+        match outcome {
+            DisputeOutcome::FavorFreelancer => freelancer += 1,
+            DisputeOutcome::FavorClient => client += 1,
+            DisputeOutcome::Split => split += 1,
+            DisputeOutcome::None => {}
+        }
+    }
+
+    let majority = appeal.votes.len() / 2 + 1;
+    if freelancer >= majority {
+        DisputeOutcome::FavorFreelancer
+    } else if client >= majority {
+        DisputeOutcome::FavorClient
+    } else if split >= majority {
+        DisputeOutcome::Split
+    } else {
+        appeal.original_outcome
+    }
+}
@@ -0,0 +1,211 @@
+use soroban_sdk::{Address, Env, Map, String, Vec};
+
+use crate::{
+    access::{check_admin, is_valid_arbitrator},
+    contract::{get_dispute, is_paused},
+    error::Error,
+    staking::{is_staked_arbitrator, staking_enabled},
+    storage::{
+        ARBITRATOR_CATEGORIES, CATEGORY_ARBITRATORS, CATEGORY_RULES, DEFAULT_FEE_PERCENTAGE,
+        DISPUTE_PANELS,
+    },
+    types::{CategoryRules, DisputeCategory, DisputeData},
+};
+
+const MAX_FEE_BPS: u32 = 2000; // Same ceiling as ContractConfig::fee_percentage
+
+pub fn set_category_rules(
+    env: &Env,
+    admin: Address,
+    category: DisputeCategory,
+    rules: CategoryRules,
+) -> Result<(), Error> {
+    check_admin(env, &admin)?;
+
+    if rules.juror_count == 0 || rules.fee_bps > MAX_FEE_BPS {
+        return Err(Error::InvalidCategoryRules);
+    }
+
+    let mut all_rules = category_rules(env);
+    all_rules.set(category, rules.clone());
+    env.storage().instance().set(&CATEGORY_RULES, &all_rules);
+
+    env.events().publish(
+        (String::from_str(env, "category_rules_updated"), category),
+        (rules.juror_count, rules.fee_bps),
+    );
+
+    Ok(())
+}
+
+pub fn get_category_rules(env: &Env, category: DisputeCategory) -> Option<CategoryRules> {
+    category_rules(env).get(category)
+}
+
+/// Replaces the categories the arbitrator takes cases for. An empty list withdraws them
+/// from category routing entirely.
+pub fn register_categories(
+    env: &Env,
+    arbitrator: Address,
+    categories: Vec<DisputeCategory>,
+) -> Result<(), Error> {
+    arbitrator.require_auth();
+
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    if !categories.is_empty() && !is_eligible(env, &arbitrator) {
+        return Err(Error::InvalidArbitrator);
+    }
+
+    let mut rosters = category_rosters(env);
+    for category in get_arbitrator_categories(env, arbitrator.clone()).iter() {
+        let mut roster = rosters.get(category).unwrap_or_else(|| Vec::new(env));
+        if let Some(index) = roster.first_index_of(&arbitrator) {
+            roster.remove(index);
+        }
+        rosters.set(category, roster);
+    }
+
+    let mut registered = Vec::new(env);
+    for category in categories.iter() {
+        if registered.contains(category) {
+            continue;
+        }
+        let mut roster = rosters.get(category).unwrap_or_else(|| Vec::new(env));
+        roster.push_back(arbitrator.clone());
+        rosters.set(category, roster);
+        registered.push_back(category);
+    }
+
+    env.storage()
+        .instance()
+        .set(&CATEGORY_ARBITRATORS, &rosters);
+    env.storage()
+        .persistent()
+        .set(&(ARBITRATOR_CATEGORIES, arbitrator.clone()), &registered);
+
+    env.events().publish(
+        (
+            String::from_str(env, "arbitrator_categories_updated"),
+            arbitrator,
+        ),
+        registered,
+    );
+
+    Ok(())
+}
+
+pub fn get_arbitrator_categories(env: &Env, arbitrator: Address) -> Vec<DisputeCategory> {
+    env.storage()
+        .persistent()
+        .get(&(ARBITRATOR_CATEGORIES, arbitrator))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Eligible arbitrators registered for the category, in registration order.
+pub fn get_category_arbitrators(env: &Env, category: DisputeCategory) -> Vec<Address> {
+    let mut eligible = Vec::new(env);
+    for arbitrator in category_rosters(env)
+        .get(category)
+        .unwrap_or_else(|| Vec::new(env))
+        .iter()
+    {
+        if is_eligible(env, &arbitrator) {
+            eligible.push_back(arbitrator);
+        }
+    }
+    eligible
+}
+
+/// Draws the category's juror count from the arbitrators registered for it. The draw
+/// starts at an offset derived from the dispute id so consecutive disputes spread across
+/// the roster; the presiding arbitrator and mediator are never drawn.
+pub fn route_dispute(env: &Env, admin: Address, dispute_id: u32) -> Result<Vec<Address>, Error> {
+    check_admin(env, &admin)?;
+
+    let dispute = get_dispute(env, dispute_id);
+    if dispute.resolved {
+        return Err(Error::DisputeAlreadyResolved);
+    }
+
+    let rules = get_category_rules(env, dispute.category).ok_or(Error::CategoryRulesNotSet)?;
+
+    let mut candidates = Vec::new(env);
+    for arbitrator in get_category_arbitrators(env, dispute.category).iter() {
+        if dispute.arbitrator != Some(arbitrator.clone())
+            && dispute.mediator != Some(arbitrator.clone())
+        {
+            candidates.push_back(arbitrator);
+        }
+    }
+    if candidates.len() < rules.juror_count {
+        return Err(Error::InsufficientCategoryArbitrators);
+    }
+
+    let start = dispute_id % candidates.len();
+    let mut panel = Vec::new(env);
+    for i in 0..rules.juror_count {
+        panel.push_back(candidates.get_unchecked((start + i) % candidates.len()));
+    }
+
+    env.storage()
+        .persistent()
+        .set(&(DISPUTE_PANELS, dispute_id), &panel);
+
+    env.events().publish(
+        (String::from_str(env, "dispute_routed"), dispute_id),
+        panel.clone(),
+    );
+
+    Ok(panel)
+}
+
+pub fn get_dispute_panel(env: &Env, dispute_id: u32) -> Option<Vec<Address>> {
+    env.storage()
+        .persistent()
+        .get(&(DISPUTE_PANELS, dispute_id))
+}
+
+/// Whether the arbitrator may preside over or vote on disputes of this category. Categories
+/// without rules stay open to every eligible arbitrator.
+pub(crate) fn can_serve_category(
+    env: &Env,
+    arbitrator: &Address,
+    category: DisputeCategory,
+) -> bool {
+    if get_category_rules(env, category).is_none() {
+        return true;
+    }
+    category_rosters(env)
+        .get(category)
+        .is_some_and(|roster| roster.contains(arbitrator))
+}
+
+/// Resolution fee for the dispute, at its category's rate when one is configured.
+pub(crate) fn dispute_fee(env: &Env, dispute: &DisputeData) -> i128 {
+    let fee_bps = get_category_rules(env, dispute.category)
+        .map(|rules| rules.fee_bps as i128)
+        .unwrap_or(DEFAULT_FEE_PERCENTAGE);
+    (dispute.dispute_amount * fee_bps) / 10000
+}
+
+fn is_eligible(env: &Env, arbitrator: &Address) -> bool {
+    is_valid_arbitrator(env, arbitrator)
+        && (!staking_enabled(env) || is_staked_arbitrator(env, arbitrator))
+}
+
+fn category_rules(env: &Env) -> Map<DisputeCategory, CategoryRules> {
+    env.storage()
+        .instance()
+        .get(&CATEGORY_RULES)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+fn category_rosters(env: &Env) -> Map<DisputeCategory, Vec<Address>> {
+    env.storage()
+        .instance()
+        .get(&CATEGORY_ARBITRATORS)
+        .unwrap_or_else(|| Map::new(env))
+}
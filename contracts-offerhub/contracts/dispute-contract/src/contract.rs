@@ -1,3 +1,4 @@
+use offerhub_events::{ActionEvent, CONTRACT_INITIALIZED};
 use soroban_sdk::{panic_with_error, Address, Env, IntoVal, Map, String, Symbol, Vec};
 
 use crate::{
@@ -15,12 +16,22 @@ use crate::{
     },
 };
 use crate::{error::{handle_error, Error}};
+use crate::staking::{is_staked_arbitrator, staking_enabled};
+use crate::appeal::appeals_enabled;
+use crate::penalty::penalize_losing_party;
+use crate::storage::{get_party_disputes, index_party_dispute, PENDING_ESCROW_SETTLEMENT};
+use crate::types::{DisputeCategory, DisputeFiling};
+use crate::category::{can_serve_category, dispute_fee};
 
 // Escrow integration constants
 const ESCROW_RESOLVE_DISPUTE: &str = "resolve_dispute";
 const ESCROW_CLIENT_WINS: &str = "client_wins";
 const ESCROW_FREELANCER_WINS: &str = "freelancer_wins";
 const ESCROW_SPLIT: &str = "split";
+const ESCROW_APPLY_RESOLUTION: &str = "apply_resolution";
+pub(crate) const SPLIT_TOTAL_BPS: u32 = 10_000;
+
+const MAX_PARTY_PAGE_SIZE: u32 = 50;
 
 pub fn initialize(
     env: &Env,
@@ -79,58 +90,22 @@ pub fn initialize(
     set_total_disputes(env, 0);
 
     env.events().publish(
-        (String::from_str(env, "contract_initialized"), admin),
-        env.ledger().timestamp(),
+        (Symbol::new(env, CONTRACT_INITIALIZED), admin),
+        ActionEvent::now(env),
     );
 }
 
 // Function to check if contract is paused
 pub fn is_paused(env: &Env) -> bool {
-    env.storage().instance().get(&PAUSED).unwrap_or(false)
+    offerhub_pausable::is_paused(env)
 }
 
-// Function to pause the contract
-pub fn pause(env: &Env, admin: Address) -> Result<(), Error> {
-    admin.require_auth();
-    let stored_admin: Address = env.storage().instance().get(&ARBITRATOR).unwrap();
-    if stored_admin != admin {
-        return Err(Error::Unauthorized);
-    }
-    
-    if is_paused(env) {
-        return Err(Error::AlreadyPaused);
-    }
-    
-    env.storage().instance().set(&PAUSED, &true);
-    
-    env.events().publish(
-        (String::from_str(env, "contract_paused"), admin),
-        env.ledger().timestamp(),
-    );
-    
-    Ok(())
-}
-
-// Function to unpause the contract
-pub fn unpause(env: &Env, admin: Address) -> Result<(), Error> {
-    admin.require_auth();
-    let stored_admin: Address = env.storage().instance().get(&ARBITRATOR).unwrap();
-    if stored_admin != admin {
-        return Err(Error::Unauthorized);
-    }
-    
-    if !is_paused(env) {
-        return Err(Error::NotPaused);
-    }
-    
-    env.storage().instance().set(&PAUSED, &false);
-    
-    env.events().publish(
-        (String::from_str(env, "contract_unpaused"), admin),
-        env.ledger().timestamp(),
-    );
-    
-    Ok(())
+// The platform arbitrator administers pausing
+pub(crate) fn pause_admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&ARBITRATOR)
+        .unwrap_or_else(|| panic_with_error!(env, Error::NotInitialized))
 }
 
 
@@ -142,6 +117,45 @@ pub fn open_dispute(
     escrow_contract: Option<Address>,
     dispute_amount: i128,
 ) {
+    let filing = DisputeFiling {
+        job_id,
+        initiator,
+        reason,
+        escrow_contract,
+        dispute_amount,
+    };
+    create_dispute(env, filing, None, DisputeCategory::General);
+}
+
+/// Same as `open_dispute`, naming the other party so both sides can take part and
+/// filing under a category whose timeline the dispute then follows.
+pub fn open_dispute_against(
+    env: &Env,
+    filing: DisputeFiling,
+    respondent: Address,
+    category: DisputeCategory,
+) {
+    if respondent == filing.initiator {
+        handle_error(env, Error::InvalidAddress);
+    }
+
+    create_dispute(env, filing, Some(respondent), category);
+}
+
+fn create_dispute(
+    env: &Env,
+    filing: DisputeFiling,
+    respondent: Option<Address>,
+    category: DisputeCategory,
+) {
+    let DisputeFiling {
+        job_id,
+        initiator,
+        reason,
+        escrow_contract,
+        dispute_amount,
+    } = filing;
+
     initiator.require_auth();
 
     if is_paused(env) {
@@ -182,12 +196,20 @@ pub fn open_dispute(
 
     let fee_manager: Address = env.storage().instance().get(&FEE_MANAGER).unwrap();
 
+    index_party_dispute(env, &initiator, job_id);
+    if let Some(ref respondent) = respondent {
+        index_party_dispute(env, respondent, job_id);
+    }
+
     let dispute_data = DisputeData {
         initiator,
+        respondent,
+        category,
         reason,
         timestamp: env.ledger().timestamp(),
         resolved: false,
         outcome: DisputeOutcome::None,
+        freelancer_bps: None,
         state: DisputeState::Open,
         level: DisputeLevel::Mediation,
         fee_manager,
@@ -212,6 +234,53 @@ pub fn open_dispute(
     );
 }
 
+/// A party's disputes, newest first, optionally filtered by state. `offset` and `limit`
+/// apply after filtering.
+pub fn get_disputes_by_party(
+    env: &Env,
+    party: Address,
+    status_filter: Option<DisputeState>,
+    offset: u32,
+    limit: u32,
+) -> Vec<DisputeSummary> {
+    let disputes: Map<u32, DisputeData> = env
+        .storage()
+        .instance()
+        .get(&DISPUTES)
+        .unwrap_or_else(|| Map::new(env));
+    let limit = limit.min(MAX_PARTY_PAGE_SIZE);
+
+    let mut page = Vec::new(env);
+    let mut matched = 0u32;
+    for dispute_id in get_party_disputes(env, &party).iter().rev() {
+        if page.len() >= limit {
+            break;
+        }
+
+        let Some(dispute) = disputes.get(dispute_id) else {
+            continue;
+        };
+        if status_filter.is_some_and(|status| status != dispute.state) {
+            continue;
+        }
+
+        matched += 1;
+        if matched <= offset {
+            continue;
+        }
+
+        page.push_back(DisputeSummary {
+            dispute_id,
+            initiator: dispute.initiator,
+            status: dispute.state,
+            outcome: dispute.outcome,
+            dispute_amount: dispute.dispute_amount,
+            timestamp: dispute.timestamp,
+        });
+    }
+    page
+}
+
 pub fn get_dispute(env: &Env, job_id: u32) -> DisputeData {
     if !env.storage().instance().has(&ARBITRATOR) {
         handle_error(env, Error::NotInitialized);
@@ -309,6 +378,11 @@ pub fn escalate_to_arbitration(env: &Env, job_id: u32, mediator: Address, arbitr
         handle_error(env, Error::InvalidArbitrator);
     }
 
+    // Once staking is configured only economically committed arbitrators are eligible
+    if staking_enabled(env) && !is_staked_arbitrator(env, &arbitrator) {
+        handle_error(env, Error::InvalidArbitrator);
+    }
+
     let mut disputes: Map<u32, DisputeData> = env.storage().instance().get(&DISPUTES).unwrap();
     let mut dispute = disputes
         .get(job_id)
@@ -322,6 +396,11 @@ pub fn escalate_to_arbitration(env: &Env, job_id: u32, mediator: Address, arbitr
         handle_error(env, Error::Unauthorized);
     }
 
+    // Categories with routing rules only go to arbitrators registered for them
+    if !can_serve_category(env, &arbitrator, dispute.category) {
+        handle_error(env, Error::InvalidArbitrator);
+    }
+
     dispute.arbitrator = Some(arbitrator.clone());
     dispute.state = DisputeState::UnderReview(DisputeLevel::Arbitration);
     dispute.level = DisputeLevel::Arbitration;
@@ -338,6 +417,16 @@ pub fn escalate_to_arbitration(env: &Env, job_id: u32, mediator: Address, arbitr
 }
 
 pub fn resolve_dispute(env: &Env, job_id: u32, decision: DisputeOutcome) {
+    apply_decision(env, job_id, decision, true);
+}
+
+/// Settles a dispute with the outcome of a panel vote. The commit and reveal phases can
+/// outlast the dispute timeout, so a closed vote wins over the timeout default.
+pub(crate) fn resolve_voted_dispute(env: &Env, job_id: u32, decision: DisputeOutcome) {
+    apply_decision(env, job_id, decision, false);
+}
+
+fn apply_decision(env: &Env, job_id: u32, decision: DisputeOutcome, honor_timeout: bool) {
     if is_paused(env) {
         handle_error(env, Error::ContractPaused);
     }
@@ -355,7 +444,7 @@ pub fn resolve_dispute(env: &Env, job_id: u32, decision: DisputeOutcome) {
     }
 
     // Check timeout
-    if let Some(timeout) = dispute.timeout_timestamp {
+    if let Some(timeout) = dispute.timeout_timestamp.filter(|_| honor_timeout) {
         if env.ledger().timestamp() > timeout {
             dispute.state = DisputeState::Closed;
             dispute.resolved = true;
@@ -400,8 +489,7 @@ pub fn resolve_dispute(env: &Env, job_id: u32, decision: DisputeOutcome) {
     }
 
     // Calculate fees
-    let fee_percentage = 500; // 5% fee
-    let fee_amount = (dispute.dispute_amount * fee_percentage) / 10000;
+    let fee_amount = dispute_fee(env, &dispute);
     let _net_amount = dispute.dispute_amount - fee_amount;
 
     dispute.resolved = true;
@@ -412,13 +500,6 @@ pub fn resolve_dispute(env: &Env, job_id: u32, decision: DisputeOutcome) {
 
     // Integrate with escrow contract if available
     if let Some(escrow_contract) = dispute.escrow_contract.clone() {
-        let escrow_result = match decision {
-            DisputeOutcome::FavorClient => ESCROW_CLIENT_WINS,
-            DisputeOutcome::FavorFreelancer => ESCROW_FREELANCER_WINS,
-            DisputeOutcome::Split => ESCROW_SPLIT,
-            DisputeOutcome::None => handle_error(env, Error::InvalidOutcome),
-        };
-
         // Call the escrow contract to resolve the dispute
         // Note: In a production environment, you would need to pass the authorized caller
         // For now, we'll use the assigned mediator/arbitrator as the caller
@@ -427,11 +508,7 @@ pub fn resolve_dispute(env: &Env, job_id: u32, decision: DisputeOutcome) {
             DisputeLevel::Arbitration => dispute.arbitrator.clone().unwrap(),
         };
 
-        env.invoke_contract::<()>(
-            &escrow_contract,
-            &Symbol::new(env, ESCROW_RESOLVE_DISPUTE),
-            (escrow_caller, Symbol::new(env, escrow_result)).into_val(env),
-        );
+        settle_escrow(env, job_id, &escrow_contract, escrow_caller, decision, None);
     }
 
     disputes.set(job_id, dispute);
@@ -513,8 +590,7 @@ pub fn resolve_dispute_with_auth(
     }
 
     // Calculate fees
-    let fee_percentage = 500; // 5% fee
-    let fee_amount = (dispute.dispute_amount * fee_percentage) / 10000;
+    let fee_amount = dispute_fee(env, &dispute);
     let _net_amount = dispute.dispute_amount - fee_amount;
 
     dispute.resolved = true;
@@ -525,18 +601,79 @@ pub fn resolve_dispute_with_auth(
 
     // Integrate with escrow contract if available
     if let Some(escrow_contract) = dispute.escrow_contract.clone() {
-        let escrow_result = match decision {
-            DisputeOutcome::FavorClient => ESCROW_CLIENT_WINS,
-            DisputeOutcome::FavorFreelancer => ESCROW_FREELANCER_WINS,
-            DisputeOutcome::Split => ESCROW_SPLIT,
-            DisputeOutcome::None => handle_error(env, Error::InvalidOutcome),
-        };
+        settle_escrow(env, job_id, &escrow_contract, caller, decision, None);
+    }
 
-        // Call the escrow contract to resolve the dispute
-        env.invoke_contract::<()>(
+    disputes.set(job_id, dispute);
+    env.storage().instance().set(&DISPUTES, &disputes);
+
+    env.events().publish(
+        (String::from_str(env, "dispute_resolved"), decision),
+        env.ledger().timestamp(),
+    );
+}
+
+/// Resolves with an arbitrary division of the escrowed funds. `client_bps` and
+/// `freelancer_bps` must add up to 10_000; the escrow applies the split via `apply_resolution`.
+pub fn resolve_with_split(
+    env: &Env,
+    job_id: u32,
+    caller: Address,
+    client_bps: u32,
+    freelancer_bps: u32,
+) {
+    caller.require_auth();
+
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    if client_bps.checked_add(freelancer_bps) != Some(SPLIT_TOTAL_BPS) {
+        handle_error(env, Error::InvalidSplit);
+    }
+
+    let mut disputes: Map<u32, DisputeData> = env
+        .storage()
+        .instance()
+        .get(&DISPUTES)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    let mut dispute = disputes
+        .get(job_id)
+        .unwrap_or_else(|| handle_error(env, Error::DisputeNotFound));
+
+    if dispute.resolved {
+        handle_error(env, Error::DisputeAlreadyResolved);
+    }
+
+    let assigned = match dispute.level {
+        DisputeLevel::Mediation => dispute.mediator.clone(),
+        DisputeLevel::Arbitration => dispute.arbitrator.clone(),
+    };
+    if assigned != Some(caller.clone()) {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    let decision = match freelancer_bps {
+        0 => DisputeOutcome::FavorClient,
+        SPLIT_TOTAL_BPS => DisputeOutcome::FavorFreelancer,
+        _ => DisputeOutcome::Split,
+    };
+
+    dispute.fee_collected = dispute_fee(env, &dispute);
+    dispute.resolved = true;
+    dispute.outcome = decision;
+    dispute.freelancer_bps = Some(freelancer_bps);
+    dispute.state = DisputeState::Resolved;
+    dispute.resolution_timestamp = Some(env.ledger().timestamp());
+
+    if let Some(escrow_contract) = dispute.escrow_contract.clone() {
+        settle_escrow(
+            env,
+            job_id,
             &escrow_contract,
-            &Symbol::new(env, ESCROW_RESOLVE_DISPUTE),
-            (caller, Symbol::new(env, escrow_result)).into_val(env),
+            caller,
+            decision,
+            Some(freelancer_bps),
         );
     }
 
@@ -544,11 +681,79 @@ pub fn resolve_dispute_with_auth(
     env.storage().instance().set(&DISPUTES, &disputes);
 
     env.events().publish(
-        (String::from_str(env, "dispute_resolved"), decision),
-        env.ledger().timestamp(),
+        (String::from_str(env, "dispute_resolved_with_split"), job_id),
+        (client_bps, freelancer_bps, env.ledger().timestamp()),
     );
 }
 
+// While appeals are enabled the escrow is only settled once the outcome is final, so an
+// appeal can still change where the funds go.
+pub(crate) fn settle_escrow(
+    env: &Env,
+    job_id: u32,
+    escrow_contract: &Address,
+    caller: Address,
+    decision: DisputeOutcome,
+    freelancer_bps: Option<u32>,
+) {
+    if appeals_enabled(env) {
+        env.storage()
+            .persistent()
+            .set(&(PENDING_ESCROW_SETTLEMENT, job_id), &true);
+
+        env.events().publish(
+            (String::from_str(env, "escrow_settlement_deferred"), job_id),
+            env.ledger().timestamp(),
+        );
+        return;
+    }
+
+    invoke_escrow_resolution(
+        env,
+        job_id,
+        escrow_contract,
+        caller,
+        decision,
+        freelancer_bps,
+    );
+}
+
+// Explicit basis-point splits go through the escrow's `apply_resolution`, which pays out any
+// share; plain outcomes use the older win/lose/split entrypoint.
+pub(crate) fn invoke_escrow_resolution(
+    env: &Env,
+    job_id: u32,
+    escrow_contract: &Address,
+    caller: Address,
+    decision: DisputeOutcome,
+    freelancer_bps: Option<u32>,
+) {
+    if let Some(freelancer_bps) = freelancer_bps {
+        env.invoke_contract::<()>(
+            escrow_contract,
+            &Symbol::new(env, ESCROW_APPLY_RESOLUTION),
+            (env.current_contract_address(), job_id, freelancer_bps).into_val(env),
+        );
+        penalize_losing_party(env, job_id, escrow_contract, decision);
+        return;
+    }
+
+    let escrow_result = match decision {
+        DisputeOutcome::FavorClient => ESCROW_CLIENT_WINS,
+        DisputeOutcome::FavorFreelancer => ESCROW_FREELANCER_WINS,
+        DisputeOutcome::Split => ESCROW_SPLIT,
+        DisputeOutcome::None => handle_error(env, Error::InvalidOutcome),
+    };
+
+    env.invoke_contract::<()>(
+        escrow_contract,
+        &Symbol::new(env, ESCROW_RESOLVE_DISPUTE),
+        (caller, Symbol::new(env, escrow_result)).into_val(env),
+    );
+
+    penalize_losing_party(env, job_id, escrow_contract, decision);
+}
+
 pub fn check_timeout(env: &Env, job_id: u32) -> bool {
     let disputes: Map<u32, DisputeData> = env.storage().instance().get(&DISPUTES).unwrap();
     let dispute = disputes
@@ -1,3 +1,4 @@
+use offerhub_pausable::PauseError;
 use soroban_sdk::{contracterror, panic_with_error, Env};
 
 #[contracterror]
@@ -25,6 +26,45 @@ pub enum Error {
     AlreadyPaused = 19,
     NotPaused = 20,
     ContractPaused = 21,
+    StakingNotConfigured = 22, // Admin has not set the staking token and minimum
+    InsufficientStake = 23,    // Stake is below the configured minimum
+    ArbitratorNotRegistered = 24, // Address has no stake in the arbitrator registry
+    UnbondingActive = 25,      // Stake is still unbonding, or unbonding already started
+    EvidenceLimitReached = 26, // Dispute already holds the maximum evidence entries
+    EvidenceWindowClosed = 27, // Evidence submission deadline has passed
+    VoteAlreadyCast = 28,      // Arbitrator has already voted on this dispute
+    NoVotesCast = 29,          // Dispute has no votes to tally
+    InvalidVoteWeighting = 30, // Weighting would give votes no weight, or caps below the base
+    AppealsNotConfigured = 31, // Admin has not set the appeal bond token and panel size
+    InvalidAppealConfig = 32,  // Bond or panel size out of range
+    AppealWindowClosed = 33,   // Appeal deadline after resolution has passed
+    AppealWindowOpen = 34,     // Outcome can still be appealed
+    AppealAlreadyFiled = 35,   // Dispute has already been appealed
+    AppealNotFound = 36,       // Dispute has no open appeal
+    AppealPanelIncomplete = 37, // Panel too small, or not every juror has voted
+    InsufficientBond = 38,     // Appeal bond is below the configured minimum
+    InvalidPenaltyConfig = 39, // Reputation penalty must deduct at least one point
+    InvalidTimeline = 40,      // Every timeline window must be non-zero
+    PhaseNotActive = 41,       // Action is outside its phase of the dispute timeline
+    SlashingNotConfigured = 42, // Admin has not set the treasury and suspension period
+    SlashNotFound = 43,        // No slash recorded under this id
+    InvalidSlashStatus = 44,   // Slash is not in the state this action requires
+    VoteNotCommitted = 45,     // Arbitrator has no vote commitment to reveal
+    CommitmentMismatch = 46,   // Revealed vote and salt do not match the commitment
+    InvalidSplit = 47,         // Client and freelancer shares must add up to 10_000 bps
+    InvalidCategoryRules = 48, // Juror count must be non-zero and the fee within 20%
+    CategoryRulesNotSet = 49,  // Category has no routing rules
+    InsufficientCategoryArbitrators = 50, // Too few arbitrators registered for the category
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
 }
 
 pub fn handle_error(env: &Env, error: Error) -> ! {
@@ -0,0 +1,91 @@
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
+
+use crate::{
+    contract::{get_config, get_dispute, is_paused},
+    error::Error,
+    storage::EVIDENCE_HASHES,
+    timeline::evidence_deadline,
+    types::EvidenceCommitment,
+};
+
+const MAX_PAGE_SIZE: u32 = 50;
+
+/// Commits a piece of evidence by hash. Only the dispute's parties may submit, up to the
+/// configured per-dispute limit and before the evidence deadline.
+pub fn submit_evidence(
+    env: &Env,
+    party: Address,
+    dispute_id: u32,
+    evidence_hash: BytesN<32>,
+    description_hash: BytesN<32>,
+) -> Result<u32, Error> {
+    party.require_auth();
+
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let dispute = get_dispute(env, dispute_id);
+    if dispute.initiator != party && dispute.respondent != Some(party.clone()) {
+        return Err(Error::Unauthorized);
+    }
+
+    if dispute.resolved {
+        return Err(Error::DisputeAlreadyResolved);
+    }
+
+    if env.ledger().timestamp() > evidence_deadline(env, &dispute) {
+        return Err(Error::EvidenceWindowClosed);
+    }
+
+    let mut evidence = get_evidence_commitments(env, dispute_id);
+    if evidence.len() >= get_config(env).max_evidence_per_dispute {
+        return Err(Error::EvidenceLimitReached);
+    }
+
+    evidence.push_back(EvidenceCommitment {
+        submitter: party.clone(),
+        evidence_hash: evidence_hash.clone(),
+        description_hash,
+        submitted_at: env.ledger().timestamp(),
+    });
+    env.storage()
+        .persistent()
+        .set(&(EVIDENCE_HASHES, dispute_id), &evidence);
+
+    env.events().publish(
+        (String::from_str(env, "evidence_submitted"), dispute_id),
+        (party, evidence_hash, evidence.len()),
+    );
+
+    Ok(evidence.len())
+}
+
+pub fn get_evidence_deadline(env: &Env, dispute_id: u32) -> u64 {
+    evidence_deadline(env, &get_dispute(env, dispute_id))
+}
+
+pub fn get_evidence_page(
+    env: &Env,
+    dispute_id: u32,
+    offset: u32,
+    limit: u32,
+) -> Vec<EvidenceCommitment> {
+    let evidence = get_evidence_commitments(env, dispute_id);
+    let end = offset
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(evidence.len());
+
+    let mut page = Vec::new(env);
+    for i in offset..end {
+        page.push_back(evidence.get(i).unwrap());
+    }
+    page
+}
+
+fn get_evidence_commitments(env: &Env, dispute_id: u32) -> Vec<EvidenceCommitment> {
+    env.storage()
+        .persistent()
+        .get(&(EVIDENCE_HASHES, dispute_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
@@ -2,23 +2,40 @@
 
 mod access;
 mod contract;
+mod staking;
 mod storage;
 mod test;
 mod validation_test;
 mod types;
 mod validation;
 mod error;
+mod evidence;
+mod voting;
+mod appeal;
+mod penalty;
+mod timeline;
+mod slashing;
+mod category;
 
 // #[cfg(test)]
 // mod validation_test;
 
 use crate::types::{ArbitratorData, DisputeData, DisputeOutcome, Evidence, DisputeInfo};
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use crate::types::{EvidenceCommitment, StakedArbitrator, StakingConfig, VoteTally, VoteWeighting};
+use crate::types::{Appeal, AppealConfig, ReputationPenaltyConfig};
+use crate::types::{CategoryRules, DisputeCategory, DisputeFiling, DisputePhase, DisputeTimeline};
+use crate::types::{DisputeState, DisputeSummary};
+use crate::types::{Slash, SlashingConfig};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 use crate::error::Error;
 
 #[contract]
 pub struct DisputeResolutionContract;
 
+offerhub_pausable::impl_pausable!(DisputeResolutionContract, Error, |env, _| {
+    Ok(contract::pause_admin(env))
+});
+
 #[contractimpl]
 impl DisputeResolutionContract {
     pub fn initialize(
@@ -32,18 +49,6 @@ impl DisputeResolutionContract {
         Ok(())
     }
 
-    pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
-        contract::pause(&env, admin)
-    }
-
-    pub fn is_paused(env: Env) -> bool {
-        contract::is_paused(&env)
-    }
-
-    pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
-        contract::unpause(&env, admin)
-    }
-
     pub fn open_dispute(
         env: Env,
         job_id: u32,
@@ -63,6 +68,26 @@ impl DisputeResolutionContract {
         Ok(())
     }
 
+    pub fn open_dispute_against(
+        env: Env,
+        filing: DisputeFiling,
+        respondent: Address,
+        category: DisputeCategory,
+    ) -> Result<(), Error> {
+        contract::open_dispute_against(&env, filing, respondent, category);
+        Ok(())
+    }
+
+    pub fn get_disputes_by_party(
+        env: Env,
+        party: Address,
+        status_filter: Option<DisputeState>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<DisputeSummary> {
+        contract::get_disputes_by_party(&env, party, status_filter, offset, limit)
+    }
+
     pub fn get_dispute(env: Env, job_id: u32) -> Result<DisputeData, Error> {
         Ok(contract::get_dispute(&env, job_id))
     }
@@ -78,6 +103,29 @@ impl DisputeResolutionContract {
         Ok(())
     }
 
+    pub fn submit_evidence(
+        env: Env,
+        party: Address,
+        dispute_id: u32,
+        evidence_hash: BytesN<32>,
+        description_hash: BytesN<32>,
+    ) -> Result<u32, Error> {
+        evidence::submit_evidence(&env, party, dispute_id, evidence_hash, description_hash)
+    }
+
+    pub fn get_evidence_deadline(env: Env, dispute_id: u32) -> u64 {
+        evidence::get_evidence_deadline(&env, dispute_id)
+    }
+
+    pub fn get_evidence_commitments(
+        env: Env,
+        dispute_id: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EvidenceCommitment> {
+        evidence::get_evidence_page(&env, dispute_id, offset, limit)
+    }
+
     pub fn assign_mediator(
         env: Env,
         job_id: u32,
@@ -103,6 +151,17 @@ impl DisputeResolutionContract {
         Ok(())
     }
 
+    pub fn resolve_with_split(
+        env: Env,
+        job_id: u32,
+        caller: Address,
+        client_bps: u32,
+        freelancer_bps: u32,
+    ) -> Result<(), Error> {
+        contract::resolve_with_split(&env, job_id, caller, client_bps, freelancer_bps);
+        Ok(())
+    }
+
     pub fn resolve_dispute_with_auth(
         env: Env,
         job_id: u32,
@@ -164,6 +223,246 @@ impl DisputeResolutionContract {
         Ok(access::get_mediators(&env))
     }
 
+    // ===== Staked arbitrator registry =====
+    pub fn set_staking_config(
+        env: Env,
+        admin: Address,
+        config: StakingConfig,
+    ) -> Result<(), Error> {
+        staking::set_staking_config(&env, admin, config)
+    }
+
+    pub fn get_staking_config(env: Env) -> Option<StakingConfig> {
+        staking::get_staking_config(&env)
+    }
+
+    pub fn register_arbitrator(
+        env: Env,
+        arbitrator: Address,
+        stake_amount: i128,
+    ) -> Result<(), Error> {
+        staking::register_arbitrator(&env, arbitrator, stake_amount)
+    }
+
+    pub fn deregister(env: Env, arbitrator: Address) -> Result<u64, Error> {
+        staking::deregister(&env, arbitrator)
+    }
+
+    pub fn withdraw_stake(env: Env, arbitrator: Address) -> Result<i128, Error> {
+        staking::withdraw_stake(&env, arbitrator)
+    }
+
+    pub fn get_staked_arbitrator(env: Env, arbitrator: Address) -> Option<StakedArbitrator> {
+        staking::get_staked_arbitrator(&env, arbitrator)
+    }
+
+    pub fn get_active_arbitrators(env: Env, offset: u32, limit: u32) -> Vec<StakedArbitrator> {
+        staking::get_active_arbitrators(&env, offset, limit)
+    }
+
+    pub fn set_slashing_config(
+        env: Env,
+        admin: Address,
+        config: SlashingConfig,
+    ) -> Result<(), Error> {
+        slashing::set_slashing_config(&env, admin, config)
+    }
+
+    pub fn get_slashing_config(env: Env) -> Option<SlashingConfig> {
+        slashing::get_slashing_config(&env)
+    }
+
+    pub fn slash_arbitrator(
+        env: Env,
+        admin: Address,
+        arbitrator: Address,
+        amount: i128,
+        reason: String,
+    ) -> Result<u32, Error> {
+        slashing::slash_arbitrator(&env, admin, arbitrator, amount, reason)
+    }
+
+    pub fn appeal_slash(env: Env, arbitrator: Address, slash_id: u32) -> Result<(), Error> {
+        slashing::appeal_slash(&env, arbitrator, slash_id)
+    }
+
+    pub fn resolve_slash_appeal(
+        env: Env,
+        admin: Address,
+        slash_id: u32,
+        reverse: bool,
+    ) -> Result<(), Error> {
+        slashing::resolve_slash_appeal(&env, admin, slash_id, reverse)
+    }
+
+    pub fn execute_slash(env: Env, slash_id: u32) -> Result<(), Error> {
+        slashing::execute_slash(&env, slash_id)
+    }
+
+    pub fn get_slash(env: Env, slash_id: u32) -> Option<Slash> {
+        slashing::get_slash(&env, slash_id)
+    }
+
+    pub fn set_vote_weighting(
+        env: Env,
+        admin: Address,
+        weighting: VoteWeighting,
+    ) -> Result<(), Error> {
+        voting::set_vote_weighting(&env, admin, weighting)
+    }
+
+    pub fn get_vote_weighting(env: Env) -> Option<VoteWeighting> {
+        voting::get_vote_weighting(&env)
+    }
+
+    pub fn commit_vote(
+        env: Env,
+        arbitrator: Address,
+        dispute_id: u32,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        voting::commit_vote(&env, arbitrator, dispute_id, commitment)
+    }
+
+    pub fn reveal_vote(
+        env: Env,
+        arbitrator: Address,
+        dispute_id: u32,
+        vote: DisputeOutcome,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        voting::reveal_vote(&env, arbitrator, dispute_id, vote, salt)
+    }
+
+    pub fn vote_commitment(
+        env: Env,
+        arbitrator: Address,
+        vote: DisputeOutcome,
+        salt: BytesN<32>,
+    ) -> BytesN<32> {
+        voting::vote_commitment(&env, &arbitrator, vote, &salt)
+    }
+
+    pub fn tally_votes(env: Env, dispute_id: u32) -> VoteTally {
+        voting::tally_votes(&env, dispute_id)
+    }
+
+    pub fn resolve_by_vote(
+        env: Env,
+        admin: Address,
+        dispute_id: u32,
+    ) -> Result<DisputeOutcome, Error> {
+        voting::resolve_by_vote(&env, admin, dispute_id)
+    }
+
+    pub fn set_appeal_config(env: Env, admin: Address, config: AppealConfig) -> Result<(), Error> {
+        appeal::set_appeal_config(&env, admin, config)
+    }
+
+    pub fn get_appeal_config(env: Env) -> Option<AppealConfig> {
+        appeal::get_appeal_config(&env)
+    }
+
+    pub fn appeal(env: Env, party: Address, dispute_id: u32, bond: i128) -> Result<(), Error> {
+        appeal::appeal(&env, party, dispute_id, bond)
+    }
+
+    pub fn assign_appeal_jurors(
+        env: Env,
+        admin: Address,
+        dispute_id: u32,
+        jurors: Vec<Address>,
+    ) -> Result<(), Error> {
+        appeal::assign_appeal_jurors(&env, admin, dispute_id, jurors)
+    }
+
+    pub fn cast_appeal_vote(
+        env: Env,
+        juror: Address,
+        dispute_id: u32,
+        outcome: DisputeOutcome,
+    ) -> Result<(), Error> {
+        appeal::cast_appeal_vote(&env, juror, dispute_id, outcome)
+    }
+
+    pub fn finalize_appeal(env: Env, dispute_id: u32) -> Result<DisputeOutcome, Error> {
+        appeal::finalize_appeal(&env, dispute_id)
+    }
+
+    pub fn finalize_resolution(env: Env, dispute_id: u32) -> Result<(), Error> {
+        appeal::finalize_resolution(&env, dispute_id)
+    }
+
+    pub fn get_appeal(env: Env, dispute_id: u32) -> Option<Appeal> {
+        appeal::get_appeal(&env, dispute_id)
+    }
+
+    pub fn set_reputation_penalty(
+        env: Env,
+        admin: Address,
+        config: ReputationPenaltyConfig,
+    ) -> Result<(), Error> {
+        penalty::set_reputation_penalty(&env, admin, config)
+    }
+
+    pub fn get_reputation_penalty(env: Env) -> Option<ReputationPenaltyConfig> {
+        penalty::get_reputation_penalty(&env)
+    }
+
+    pub fn set_timeline(
+        env: Env,
+        admin: Address,
+        category: DisputeCategory,
+        timeline: DisputeTimeline,
+    ) -> Result<(), Error> {
+        timeline::set_timeline(&env, admin, category, timeline)
+    }
+
+    pub fn get_timeline(env: Env, category: DisputeCategory) -> DisputeTimeline {
+        timeline::get_timeline(&env, category)
+    }
+
+    pub fn get_dispute_phase(env: Env, dispute_id: u32) -> DisputePhase {
+        timeline::get_dispute_phase(&env, dispute_id)
+    }
+
+    pub fn set_category_rules(
+        env: Env,
+        admin: Address,
+        category: DisputeCategory,
+        rules: CategoryRules,
+    ) -> Result<(), Error> {
+        category::set_category_rules(&env, admin, category, rules)
+    }
+
+    pub fn get_category_rules(env: Env, category: DisputeCategory) -> Option<CategoryRules> {
+        category::get_category_rules(&env, category)
+    }
+
+    pub fn register_categories(
+        env: Env,
+        arbitrator: Address,
+        categories: Vec<DisputeCategory>,
+    ) -> Result<(), Error> {
+        category::register_categories(&env, arbitrator, categories)
+    }
+
+    pub fn get_arbitrator_categories(env: Env, arbitrator: Address) -> Vec<DisputeCategory> {
+        category::get_arbitrator_categories(&env, arbitrator)
+    }
+
+    pub fn get_category_arbitrators(env: Env, category: DisputeCategory) -> Vec<Address> {
+        category::get_category_arbitrators(&env, category)
+    }
+
+    pub fn route_dispute(env: Env, admin: Address, dispute_id: u32) -> Result<Vec<Address>, Error> {
+        category::route_dispute(&env, admin, dispute_id)
+    }
+
+    pub fn get_dispute_panel(env: Env, dispute_id: u32) -> Option<Vec<Address>> {
+        category::get_dispute_panel(&env, dispute_id)
+    }
+
     // ===== Rate limiting admin helpers =====
     pub fn set_rate_limit_bypass(
         env: Env,
@@ -0,0 +1,80 @@
+use soroban_sdk::{Address, Env, IntoVal, String, Symbol};
+
+use crate::{
+    access::check_admin,
+    error::Error,
+    storage::REPUTATION_PENALTY,
+    types::{DisputeOutcome, ReputationPenaltyConfig},
+};
+
+const ESCROW_GET_PARTIES: &str = "get_parties";
+const REPUTATION_APPLY_PENALTY: &str = "apply_reputation_penalty";
+
+pub fn set_reputation_penalty(
+    env: &Env,
+    admin: Address,
+    config: ReputationPenaltyConfig,
+) -> Result<(), Error> {
+    check_admin(env, &admin)?;
+
+    if config.penalty_points == 0 {
+        return Err(Error::InvalidPenaltyConfig);
+    }
+
+    env.storage().instance().set(&REPUTATION_PENALTY, &config);
+
+    env.events().publish(
+        (String::from_str(env, "reputation_penalty_updated"), admin),
+        (config.reputation_contract, config.penalty_points),
+    );
+
+    Ok(())
+}
+
+pub fn get_reputation_penalty(env: &Env) -> Option<ReputationPenaltyConfig> {
+    env.storage().instance().get(&REPUTATION_PENALTY)
+}
+
+/// Docks the reputation of the party the final outcome went against. Roles come from the
+/// linked escrow; split outcomes penalize nobody. This contract must be registered as a
+/// minter on the reputation NFT contract.
+pub(crate) fn penalize_losing_party(
+    env: &Env,
+    dispute_id: u32,
+    escrow_contract: &Address,
+    decision: DisputeOutcome,
+) {
+    let Some(config) = get_reputation_penalty(env) else {
+        return;
+    };
+
+    let (client, freelancer): (Address, Address) = env.invoke_contract(
+        escrow_contract,
+        &Symbol::new(env, ESCROW_GET_PARTIES),
+        ().into_val(env),
+    );
+    let losing_party = match decision {
+        DisputeOutcome::FavorClient => freelancer,
+        DisputeOutcome::FavorFreelancer => client,
+        DisputeOutcome::Split | DisputeOutcome::None => return,
+    };
+
+    let new_score: u32 = env.invoke_contract(
+        &config.reputation_contract,
+        &Symbol::new(env, REPUTATION_APPLY_PENALTY),
+        (
+            env.current_contract_address(),
+            losing_party.clone(),
+            config.penalty_points,
+        )
+            .into_val(env),
+    );
+
+    env.events().publish(
+        (
+            String::from_str(env, "reputation_penalty_applied"),
+            dispute_id,
+        ),
+        (losing_party, config.penalty_points, new_score),
+    );
+}
@@ -0,0 +1,236 @@
+use soroban_sdk::{Address, Env, IntoVal, String, Symbol};
+
+use crate::{
+    access::check_admin,
+    error::Error,
+    staking::{get_staking_config, staked_arbitrators, store_staked_arbitrator},
+    storage::{SLASHES, SLASH_COUNT, SLASHING_CONFIG},
+    types::{Slash, SlashStatus, SlashingConfig},
+};
+
+const TOKEN_TRANSFER: &str = "transfer";
+
+pub fn set_slashing_config(env: &Env, admin: Address, config: SlashingConfig) -> Result<(), Error> {
+    check_admin(env, &admin)?;
+
+    env.storage().instance().set(&SLASHING_CONFIG, &config);
+
+    env.events().publish(
+        (String::from_str(env, "slashing_config_updated"), admin),
+        (
+            config.treasury,
+            config.suspension_secs,
+            config.appeal_window_secs,
+            config.non_reveal_penalty,
+        ),
+    );
+
+    Ok(())
+}
+
+pub fn get_slashing_config(env: &Env) -> Option<SlashingConfig> {
+    env.storage().instance().get(&SLASHING_CONFIG)
+}
+
+/// Takes `amount` out of an arbitrator's stake and suspends them from selection. The
+/// slashed funds are held until the appeal window passes, then go to the treasury.
+pub fn slash_arbitrator(
+    env: &Env,
+    admin: Address,
+    arbitrator: Address,
+    amount: i128,
+    reason: String,
+) -> Result<u32, Error> {
+    check_admin(env, &admin)?;
+    record_slash(env, arbitrator, amount, reason)
+}
+
+/// Slashes an arbitrator who committed a vote but never revealed it. Does nothing when
+/// slashing is not configured or the arbitrator has no stake left.
+pub(crate) fn penalize_non_revealer(env: &Env, arbitrator: Address) -> Option<u32> {
+    let config = get_slashing_config(env)?;
+    let stake = staked_arbitrators(env).get(arbitrator.clone())?.stake;
+
+    let amount = config.non_reveal_penalty.min(stake);
+    if amount <= 0 {
+        return None;
+    }
+
+    record_slash(
+        env,
+        arbitrator,
+        amount,
+        String::from_str(env, "Committed vote was not revealed"),
+    )
+    .ok()
+}
+
+fn record_slash(
+    env: &Env,
+    arbitrator: Address,
+    amount: i128,
+    reason: String,
+) -> Result<u32, Error> {
+    let config = get_slashing_config(env).ok_or(Error::SlashingNotConfigured)?;
+    let mut staked = staked_arbitrators(env)
+        .get(arbitrator.clone())
+        .ok_or(Error::ArbitratorNotRegistered)?;
+
+    if amount <= 0 || amount > staked.stake {
+        return Err(Error::InsufficientStake);
+    }
+
+    let now = env.ledger().timestamp();
+    staked.stake -= amount;
+    staked.suspended_until = Some(now + config.suspension_secs);
+    store_staked_arbitrator(env, &staked);
+
+    let slash_id: u32 = env.storage().instance().get(&SLASH_COUNT).unwrap_or(0) + 1;
+    env.storage().instance().set(&SLASH_COUNT, &slash_id);
+
+    let slash = Slash {
+        arbitrator: arbitrator.clone(),
+        amount,
+        reason: reason.clone(),
+        slashed_at: now,
+        appeal_deadline: now + config.appeal_window_secs,
+        status: SlashStatus::Pending,
+    };
+    store_slash(env, slash_id, &slash);
+
+    env.events().publish(
+        (String::from_str(env, "arbitrator_slashed"), arbitrator),
+        (slash_id, amount, reason, staked.suspended_until),
+    );
+
+    Ok(slash_id)
+}
+
+pub fn appeal_slash(env: &Env, arbitrator: Address, slash_id: u32) -> Result<(), Error> {
+    arbitrator.require_auth();
+
+    let mut slash = get_slash(env, slash_id).ok_or(Error::SlashNotFound)?;
+    if slash.arbitrator != arbitrator {
+        return Err(Error::Unauthorized);
+    }
+    if slash.status != SlashStatus::Pending || env.ledger().timestamp() > slash.appeal_deadline {
+        return Err(Error::AppealWindowClosed);
+    }
+
+    slash.status = SlashStatus::Appealed;
+    store_slash(env, slash_id, &slash);
+
+    env.events().publish(
+        (String::from_str(env, "slash_appealed"), arbitrator),
+        slash_id,
+    );
+
+    Ok(())
+}
+
+/// Rules on an appealed slash. A reversal returns the funds to the stake and lifts the
+/// suspension; otherwise the slash is carried out.
+pub fn resolve_slash_appeal(
+    env: &Env,
+    admin: Address,
+    slash_id: u32,
+    reverse: bool,
+) -> Result<(), Error> {
+    check_admin(env, &admin)?;
+
+    let mut slash = get_slash(env, slash_id).ok_or(Error::SlashNotFound)?;
+    if slash.status != SlashStatus::Appealed {
+        return Err(Error::InvalidSlashStatus);
+    }
+
+    if reverse {
+        restore_stake(env, &slash)?;
+        slash.status = SlashStatus::Reversed;
+    } else {
+        send_to_treasury(env, &slash)?;
+        slash.status = SlashStatus::Executed;
+    }
+    store_slash(env, slash_id, &slash);
+
+    env.events().publish(
+        (
+            String::from_str(env, "slash_appeal_resolved"),
+            slash.arbitrator,
+        ),
+        (slash_id, reverse),
+    );
+
+    Ok(())
+}
+
+/// Sends an unappealed slash to the treasury once its appeal window has passed.
+pub fn execute_slash(env: &Env, slash_id: u32) -> Result<(), Error> {
+    let mut slash = get_slash(env, slash_id).ok_or(Error::SlashNotFound)?;
+    if slash.status != SlashStatus::Pending {
+        return Err(Error::InvalidSlashStatus);
+    }
+    if env.ledger().timestamp() <= slash.appeal_deadline {
+        return Err(Error::AppealWindowOpen);
+    }
+
+    send_to_treasury(env, &slash)?;
+    slash.status = SlashStatus::Executed;
+    store_slash(env, slash_id, &slash);
+
+    env.events().publish(
+        (String::from_str(env, "slash_executed"), slash.arbitrator),
+        (slash_id, slash.amount),
+    );
+
+    Ok(())
+}
+
+pub fn get_slash(env: &Env, slash_id: u32) -> Option<Slash> {
+    env.storage().persistent().get(&(SLASHES, slash_id))
+}
+
+fn store_slash(env: &Env, slash_id: u32, slash: &Slash) {
+    env.storage().persistent().set(&(SLASHES, slash_id), slash);
+}
+
+fn send_to_treasury(env: &Env, slash: &Slash) -> Result<(), Error> {
+    let staking = get_staking_config(env).ok_or(Error::StakingNotConfigured)?;
+    let config = get_slashing_config(env).ok_or(Error::SlashingNotConfigured)?;
+
+    env.invoke_contract::<()>(
+        &staking.token,
+        &Symbol::new(env, TOKEN_TRANSFER),
+        (
+            env.current_contract_address(),
+            config.treasury,
+            slash.amount,
+        )
+            .into_val(env),
+    );
+    Ok(())
+}
+
+// An arbitrator who has since withdrawn gets the funds back directly
+fn restore_stake(env: &Env, slash: &Slash) -> Result<(), Error> {
+    match staked_arbitrators(env).get(slash.arbitrator.clone()) {
+        Some(mut staked) => {
+            staked.stake += slash.amount;
+            staked.suspended_until = None;
+            store_staked_arbitrator(env, &staked);
+        }
+        None => {
+            let staking = get_staking_config(env).ok_or(Error::StakingNotConfigured)?;
+            env.invoke_contract::<()>(
+                &staking.token,
+                &Symbol::new(env, TOKEN_TRANSFER),
+                (
+                    env.current_contract_address(),
+                    slash.arbitrator.clone(),
+                    slash.amount,
+                )
+                    .into_val(env),
+            );
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,254 @@
+use soroban_sdk::{Address, Env, IntoVal, Map, String, Symbol, Vec};
+
+use crate::{
+    access::check_admin,
+    contract::is_paused,
+    error::Error,
+    storage::{STAKED_ARBITRATORS, STAKED_ORDER, STAKING_CONFIG},
+    types::{StakedArbitrator, StakingConfig},
+};
+
+const TOKEN_TRANSFER: &str = "transfer";
+const MAX_PAGE_SIZE: u32 = 100;
+
+pub fn set_staking_config(env: &Env, admin: Address, config: StakingConfig) -> Result<(), Error> {
+    check_admin(env, &admin)?;
+
+    if config.min_stake <= 0 {
+        return Err(Error::InsufficientStake);
+    }
+
+    env.storage().instance().set(&STAKING_CONFIG, &config);
+
+    env.events().publish(
+        (String::from_str(env, "staking_config_updated"), admin),
+        (config.token, config.min_stake, config.unbonding_period),
+    );
+
+    Ok(())
+}
+
+pub fn get_staking_config(env: &Env) -> Option<StakingConfig> {
+    env.storage().instance().get(&STAKING_CONFIG)
+}
+
+/// Locks `stake_amount` of the staking token and adds the caller to the arbitrator pool.
+/// Re-registering while unbonding tops up the stake and cancels the unbonding.
+pub fn register_arbitrator(
+    env: &Env,
+    arbitrator: Address,
+    stake_amount: i128,
+) -> Result<(), Error> {
+    arbitrator.require_auth();
+
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let config = get_staking_config(env).ok_or(Error::StakingNotConfigured)?;
+    let mut arbitrators = staked_arbitrators(env);
+    let existing = arbitrators.get(arbitrator.clone());
+
+    let total_stake = existing.as_ref().map(|a| a.stake).unwrap_or(0) + stake_amount;
+    if stake_amount <= 0 || total_stake < config.min_stake {
+        return Err(Error::InsufficientStake);
+    }
+
+    env.invoke_contract::<()>(
+        &config.token,
+        &Symbol::new(env, TOKEN_TRANSFER),
+        (
+            arbitrator.clone(),
+            env.current_contract_address(),
+            stake_amount,
+        )
+            .into_val(env),
+    );
+
+    let staked = match existing {
+        Some(mut staked) => {
+            staked.stake = total_stake;
+            staked.unbonding_until = None;
+            staked
+        }
+        None => {
+            let mut order: Vec<Address> = env
+                .storage()
+                .instance()
+                .get(&STAKED_ORDER)
+                .unwrap_or_else(|| Vec::new(env));
+            order.push_back(arbitrator.clone());
+            env.storage().instance().set(&STAKED_ORDER, &order);
+
+            StakedArbitrator {
+                address: arbitrator.clone(),
+                stake: total_stake,
+                registered_at: env.ledger().timestamp(),
+                unbonding_until: None,
+                suspended_until: None,
+            }
+        }
+    };
+    arbitrators.set(arbitrator.clone(), staked);
+    env.storage()
+        .instance()
+        .set(&STAKED_ARBITRATORS, &arbitrators);
+
+    env.events().publish(
+        (String::from_str(env, "arbitrator_registered"), arbitrator),
+        (stake_amount, total_stake),
+    );
+
+    Ok(())
+}
+
+/// Leaves the pool immediately; the stake stays locked for the unbonding period so
+/// misconduct discovered late can still be acted upon.
+pub fn deregister(env: &Env, arbitrator: Address) -> Result<u64, Error> {
+    arbitrator.require_auth();
+
+    let config = get_staking_config(env).ok_or(Error::StakingNotConfigured)?;
+    let mut arbitrators = staked_arbitrators(env);
+    let mut staked = arbitrators
+        .get(arbitrator.clone())
+        .ok_or(Error::ArbitratorNotRegistered)?;
+
+    if staked.unbonding_until.is_some() {
+        return Err(Error::UnbondingActive);
+    }
+
+    let unbonding_until = env.ledger().timestamp() + config.unbonding_period;
+    staked.unbonding_until = Some(unbonding_until);
+    arbitrators.set(arbitrator.clone(), staked);
+    env.storage()
+        .instance()
+        .set(&STAKED_ARBITRATORS, &arbitrators);
+
+    env.events().publish(
+        (String::from_str(env, "arbitrator_unbonding"), arbitrator),
+        unbonding_until,
+    );
+
+    Ok(unbonding_until)
+}
+
+/// Returns the stake once unbonding has finished and removes the arbitrator record.
+pub fn withdraw_stake(env: &Env, arbitrator: Address) -> Result<i128, Error> {
+    arbitrator.require_auth();
+
+    let config = get_staking_config(env).ok_or(Error::StakingNotConfigured)?;
+    let mut arbitrators = staked_arbitrators(env);
+    let staked = arbitrators
+        .get(arbitrator.clone())
+        .ok_or(Error::ArbitratorNotRegistered)?;
+
+    match staked.unbonding_until {
+        Some(until) if env.ledger().timestamp() >= until => {}
+        _ => return Err(Error::UnbondingActive),
+    }
+
+    env.invoke_contract::<()>(
+        &config.token,
+        &Symbol::new(env, TOKEN_TRANSFER),
+        (
+            env.current_contract_address(),
+            arbitrator.clone(),
+            staked.stake,
+        )
+            .into_val(env),
+    );
+
+    arbitrators.remove(arbitrator.clone());
+    env.storage()
+        .instance()
+        .set(&STAKED_ARBITRATORS, &arbitrators);
+
+    let mut order: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&STAKED_ORDER)
+        .unwrap_or_else(|| Vec::new(env));
+    if let Some(index) = order.first_index_of(&arbitrator) {
+        order.remove(index);
+        env.storage().instance().set(&STAKED_ORDER, &order);
+    }
+
+    env.events().publish(
+        (
+            String::from_str(env, "arbitrator_stake_withdrawn"),
+            arbitrator,
+        ),
+        staked.stake,
+    );
+
+    Ok(staked.stake)
+}
+
+pub fn get_staked_arbitrator(env: &Env, arbitrator: Address) -> Option<StakedArbitrator> {
+    staked_arbitrators(env).get(arbitrator)
+}
+
+/// Staked arbitrators not unbonding or suspended, in registration order.
+pub fn get_active_arbitrators(env: &Env, offset: u32, limit: u32) -> Vec<StakedArbitrator> {
+    let arbitrators = staked_arbitrators(env);
+    let order: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&STAKED_ORDER)
+        .unwrap_or_else(|| Vec::new(env));
+
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let mut page = Vec::new(env);
+    let mut skipped = 0;
+    for address in order.iter() {
+        if page.len() >= limit {
+            break;
+        }
+        let Some(staked) = arbitrators.get(address) else {
+            continue;
+        };
+        if !is_active(env, &staked) {
+            continue;
+        }
+        if skipped < offset {
+            skipped += 1;
+            continue;
+        }
+        page.push_back(staked);
+    }
+    page
+}
+
+pub fn is_staked_arbitrator(env: &Env, arbitrator: &Address) -> bool {
+    staked_arbitrators(env)
+        .get(arbitrator.clone())
+        .map(|staked| is_active(env, &staked))
+        .unwrap_or(false)
+}
+
+pub fn staking_enabled(env: &Env) -> bool {
+    env.storage().instance().has(&STAKING_CONFIG)
+}
+
+fn is_active(env: &Env, staked: &StakedArbitrator) -> bool {
+    let min_stake = get_staking_config(env).map(|c| c.min_stake).unwrap_or(0);
+    let suspended = staked
+        .suspended_until
+        .is_some_and(|until| env.ledger().timestamp() < until);
+    staked.unbonding_until.is_none() && !suspended && staked.stake >= min_stake
+}
+
+pub(crate) fn store_staked_arbitrator(env: &Env, staked: &StakedArbitrator) {
+    let mut arbitrators = staked_arbitrators(env);
+    arbitrators.set(staked.address.clone(), staked.clone());
+    env.storage()
+        .instance()
+        .set(&STAKED_ARBITRATORS, &arbitrators);
+}
+
+pub(crate) fn staked_arbitrators(env: &Env) -> Map<Address, StakedArbitrator> {
+    env.storage()
+        .instance()
+        .get(&STAKED_ARBITRATORS)
+        .unwrap_or_else(|| Map::new(env))
+}
@@ -1,5 +1,5 @@
 use crate::error::Error;
-use soroban_sdk::{contracttype, symbol_short, Symbol, Address, Env, String, log};
+use soroban_sdk::{contracttype, symbol_short, Symbol, Address, Env, String, Vec, log};
 
 pub const DISPUTES: Symbol = symbol_short!("DISPUTES");
 pub const ARBITRATOR: Symbol = symbol_short!("ARBITRTR");
@@ -13,7 +13,47 @@ pub const RATE_BYPASS: Symbol = symbol_short!("RLBYP");
 
 pub const CONTRACT_CONFIG: Symbol = symbol_short!("CONFIG");
 
-pub const PAUSED: Symbol = symbol_short!("PAUSED");
+pub use offerhub_pausable::PAUSED;
+pub const PARTY_DISPUTES: Symbol = symbol_short!("PARTYDSP");
+
+// Hashed evidence per dispute, accepted for a window after filing
+pub const EVIDENCE_HASHES: Symbol = symbol_short!("EVIDHASH");
+
+// Per-category dispute timelines; categories without one use the defaults
+pub const TIMELINES: Symbol = symbol_short!("TIMELINE");
+pub const DEFAULT_EVIDENCE_WINDOW_SECS: u64 = 3 * 24 * 3600; // 3 days
+pub const DEFAULT_VOTING_WINDOW_SECS: u64 = 3 * 24 * 3600; // 3 days
+pub const DEFAULT_REVEAL_WINDOW_SECS: u64 = 24 * 3600; // 1 day
+pub const DEFAULT_APPEAL_WINDOW_SECS: u64 = 2 * 24 * 3600; // 2 days
+
+// Per-category juror count and fee, and the arbitrators registered for each category
+pub const CATEGORY_RULES: Symbol = symbol_short!("CATRULES");
+pub const CATEGORY_ARBITRATORS: Symbol = symbol_short!("CATARBS");
+pub const ARBITRATOR_CATEGORIES: Symbol = symbol_short!("ARBCATS");
+pub const DISPUTE_PANELS: Symbol = symbol_short!("PANELS");
+
+// Reputation-weighted arbitrator voting
+pub const VOTE_WEIGHTING: Symbol = symbol_short!("VOTEWGHT");
+pub const DISPUTE_VOTES: Symbol = symbol_short!("VOTES");
+pub const VOTE_COMMITS: Symbol = symbol_short!("VOTECMTS");
+
+// Appeals; escrow settlement waits for the appeal window while appeals are enabled
+pub const APPEAL_CONFIG: Symbol = symbol_short!("APPEALCF");
+pub const APPEALS: Symbol = symbol_short!("APPEALS");
+pub const PENDING_ESCROW_SETTLEMENT: Symbol = symbol_short!("ESCPEND");
+
+// Reputation penalty for the losing party of an escrow-backed dispute
+pub const REPUTATION_PENALTY: Symbol = symbol_short!("REPPENLT");
+
+// Staked arbitrator registry
+pub const STAKING_CONFIG: Symbol = symbol_short!("STAKECFG");
+pub const STAKED_ARBITRATORS: Symbol = symbol_short!("STAKED");
+pub const STAKED_ORDER: Symbol = symbol_short!("STAKEORD");
+
+// Arbitrator slashing
+pub const SLASHING_CONFIG: Symbol = symbol_short!("SLASHCFG");
+pub const SLASHES: Symbol = symbol_short!("SLASHES");
+pub const SLASH_COUNT: Symbol = symbol_short!("SLASHCNT");
 
 // Default configuration values
 pub const DEFAULT_TIMEOUT_HOURS: u32 = 168;           // 7 days (168 hours)
@@ -94,6 +134,22 @@ pub fn reset_rate_limit(env: &Env, user: &Address, kind: &String) {
     env.storage().persistent().set(&rl_key(user, kind), &entry);
 }
 
+// Per-party dispute index, in filing order
+pub fn index_party_dispute(env: &Env, party: &Address, dispute_id: u32) {
+    let mut ids = get_party_disputes(env, party);
+    ids.push_back(dispute_id);
+    env.storage()
+        .persistent()
+        .set(&(PARTY_DISPUTES, party.clone()), &ids);
+}
+
+pub fn get_party_disputes(env: &Env, party: &Address) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&(PARTY_DISPUTES, party.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
 pub fn get_total_disputes(env: &Env) -> u64 {
     env.storage().persistent().get(&TOTAL_DISPUTES).unwrap_or(0)
 }
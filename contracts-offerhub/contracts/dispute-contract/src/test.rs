@@ -1,11 +1,21 @@
 #![cfg(test)]
 
 use crate::{
-    types::{DisputeLevel, DisputeOutcome, DisputeState},
+    error::Error,
+    types::{
+        AppealConfig, CategoryRules, DisputeCategory, DisputeFiling, DisputeLevel, DisputeOutcome, DisputePhase,
+        DisputeState, DisputeTimeline, ReputationPenaltyConfig, SlashStatus, SlashingConfig,
+        StakingConfig, VoteWeighting,
+    },
     DisputeResolutionContract, DisputeResolutionContractClient,
 };
+use escrow_contract::{EscrowContract, EscrowContractClient};
+use fee_manager_contract::{FeeManagerContract, FeeManagerContractClient};
 use soroban_sdk::{
-    testutils::{Address as _, Ledger}, Address, Env, String
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, BytesN, Env, String, Symbol, Val, Vec,
 };
 
 fn setup_env() -> Env {
@@ -720,6 +730,37 @@ fn test_pause_unpause_unauthorized() {
     assert_eq!(client.is_paused(), true);
 }
 
+#[test]
+fn test_pauser_can_set_paused() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeResolutionContract, ());
+    let client = DisputeResolutionContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let emergency = Address::generate(&env);
+    let escrow_contract = Address::generate(&env);
+    let fee_manager = Address::generate(&env);
+
+    client.initialize(&admin, &86400_u64, &escrow_contract, &fee_manager);
+
+    assert!(client.try_set_paused(&emergency, &true).is_err());
+    client.add_pauser(&admin, &emergency);
+    client.set_paused(&emergency, &true);
+    assert!(client.is_paused());
+
+    // Pushing the same state again is a no-op
+    client.set_paused(&emergency, &true);
+
+    // Lifting the pause is left to the admin
+    assert!(client.try_set_paused(&emergency, &false).is_err());
+    client.set_paused(&admin, &false);
+    assert!(!client.is_paused());
+
+    client.remove_pauser(&admin, &emergency);
+    assert!(!client.is_pauser(&emergency));
+}
+
 
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #21)")]
@@ -776,4 +817,1090 @@ fn test_assign_mediator_panic() {
 
     // Assign mediator
     client.assign_mediator(&job_id, &admin, &mediator);
-}
\ No newline at end of file
+}
+fn setup_staking(env: &Env, client: &DisputeResolutionContractClient, admin: &Address) -> Address {
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.set_staking_config(
+        admin,
+        &StakingConfig {
+            token: token.clone(),
+            min_stake: 1_000,
+            unbonding_period: 86_400,
+        },
+    );
+    token
+}
+
+#[test]
+fn test_staked_arbitrator_lifecycle() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let token = setup_staking(&env, &client, &admin);
+    let arbitrator = Address::generate(&env);
+    let other = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&arbitrator, &1_500);
+    StellarAssetClient::new(&env, &token).mint(&other, &1_000);
+
+    client.register_arbitrator(&arbitrator, &1_500);
+    client.register_arbitrator(&other, &1_000);
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&client.address), 2_500);
+
+    let active = client.get_active_arbitrators(&0, &10);
+    assert_eq!(active.len(), 2);
+    assert_eq!(active.get(0).unwrap().stake, 1_500);
+    let second_page = client.get_active_arbitrators(&1, &10);
+    assert_eq!(second_page.get(0).unwrap().address, other);
+
+    let unbonding_until = client.deregister(&arbitrator);
+    assert_eq!(unbonding_until, 1000 + 86_400);
+    assert_eq!(client.get_active_arbitrators(&0, &10).len(), 1);
+    assert!(client.try_withdraw_stake(&arbitrator).is_err());
+
+    env.ledger().with_mut(|l| l.timestamp = unbonding_until);
+    assert_eq!(client.withdraw_stake(&arbitrator), 1_500);
+    assert_eq!(token_client.balance(&arbitrator), 1_500);
+    assert_eq!(client.get_staked_arbitrator(&arbitrator), None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #23)")]
+fn test_register_arbitrator_below_min_stake() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let token = setup_staking(&env, &client, &admin);
+    let arbitrator = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&arbitrator, &500);
+
+    client.register_arbitrator(&arbitrator, &500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_escalation_requires_staked_arbitrator() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    setup_staking(&env, &client, &admin);
+    let mediator = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    client.add_mediator_access(&admin, &mediator);
+    client.add_arbitrator(&admin, &arbitrator, &String::from_str(&env, "Jane Smith"));
+
+    client.open_dispute(
+        &1,
+        &Address::generate(&env),
+        &String::from_str(&env, "Job not completed"),
+        &None,
+        &1000000,
+    );
+    client.assign_mediator(&1, &admin, &mediator);
+    client.escalate_to_arbitration(&1, &mediator, &arbitrator);
+}
+
+#[test]
+fn test_submit_evidence_by_both_parties() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, _, _, _) = create_contract(&env);
+    let initiator = Address::generate(&env);
+    let respondent = Address::generate(&env);
+
+    client.open_dispute_against(
+        &DisputeFiling {
+            job_id: 1,
+            initiator: initiator.clone(),
+            reason: String::from_str(&env, "Job not completed"),
+            escrow_contract: None,
+            dispute_amount: 1000000,
+        },
+        &respondent,
+        &DisputeCategory::General,
+    );
+
+    client.submit_evidence(
+        &initiator,
+        &1,
+        &BytesN::from_array(&env, &[1; 32]),
+        &BytesN::from_array(&env, &[2; 32]),
+    );
+    let count = client.submit_evidence(
+        &respondent,
+        &1,
+        &BytesN::from_array(&env, &[3; 32]),
+        &BytesN::from_array(&env, &[4; 32]),
+    );
+    assert_eq!(count, 2);
+
+    let page = client.get_evidence_commitments(&1, &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().submitter, respondent);
+    assert_eq!(client.get_evidence_deadline(&1), 1000 + 3 * 24 * 3600);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #27)")]
+fn test_submit_evidence_after_deadline() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, _, _, _) = create_contract(&env);
+    let initiator = Address::generate(&env);
+
+    client.open_dispute(
+        &1,
+        &initiator,
+        &String::from_str(&env, "Job not completed"),
+        &None,
+        &1000000,
+    );
+
+    let deadline = client.get_evidence_deadline(&1);
+    env.ledger().with_mut(|l| l.timestamp = deadline + 1);
+
+    client.submit_evidence(
+        &initiator,
+        &1,
+        &BytesN::from_array(&env, &[1; 32]),
+        &BytesN::from_array(&env, &[2; 32]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_submit_evidence_outsider_rejected() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, _, _, _) = create_contract(&env);
+
+    client.open_dispute(
+        &1,
+        &Address::generate(&env),
+        &String::from_str(&env, "Job not completed"),
+        &None,
+        &1000000,
+    );
+
+    client.submit_evidence(
+        &Address::generate(&env),
+        &1,
+        &BytesN::from_array(&env, &[1; 32]),
+        &BytesN::from_array(&env, &[2; 32]),
+    );
+}
+
+#[contract]
+pub struct MockReputationOracle;
+
+#[contractimpl]
+impl MockReputationOracle {
+    pub fn set_score(env: Env, user: Address, score: u32) {
+        env.storage().instance().set(&user, &score);
+    }
+
+    pub fn get_user_reputation_score(env: Env, user: Address) -> u32 {
+        env.storage().instance().get(&user).unwrap_or(0)
+    }
+
+    pub fn apply_reputation_penalty(env: Env, _caller: Address, user: Address, points: u32) -> u32 {
+        let score: u32 = env.storage().instance().get(&user).unwrap_or(0);
+        let new_score = score.saturating_sub(points);
+        env.storage().instance().set(&user, &new_score);
+        new_score
+    }
+}
+
+#[contract]
+pub struct MockEscrow;
+
+#[contractimpl]
+impl MockEscrow {
+    pub fn set_parties(env: Env, client: Address, freelancer: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("PARTIES"), &(client, freelancer));
+    }
+
+    pub fn get_parties(env: Env) -> (Address, Address) {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("PARTIES"))
+            .unwrap()
+    }
+
+    pub fn resolve_dispute(_env: Env, _caller: Address, _result: Symbol) {}
+
+    pub fn apply_resolution(
+        env: Env,
+        _dispute_contract: Address,
+        _escrow_id: u32,
+        freelancer_bps: u32,
+    ) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("SPLIT"), &freelancer_bps);
+    }
+
+    pub fn applied_split(env: Env) -> Option<u32> {
+        env.storage().instance().get(&symbol_short!("SPLIT"))
+    }
+}
+
+fn setup_arbitration_panel(
+    env: &Env,
+    client: &DisputeResolutionContractClient,
+    admin: &Address,
+) -> (Address, Address, Address) {
+    let mediator = Address::generate(env);
+    let senior = Address::generate(env);
+    let junior_a = Address::generate(env);
+    let junior_b = Address::generate(env);
+    client.add_mediator_access(admin, &mediator);
+    for arbitrator in [&senior, &junior_a, &junior_b] {
+        client.add_arbitrator(admin, arbitrator, &String::from_str(env, "Arbitrator"));
+    }
+
+    client.open_dispute(
+        &1,
+        &Address::generate(env),
+        &String::from_str(env, "Job not completed"),
+        &None,
+        &1000000,
+    );
+    client.assign_mediator(&1, admin, &mediator);
+    client.escalate_to_arbitration(&1, &mediator, &senior);
+
+    (senior, junior_a, junior_b)
+}
+
+fn commit(
+    env: &Env,
+    client: &DisputeResolutionContractClient,
+    arbitrator: &Address,
+    vote: DisputeOutcome,
+    salt: u8,
+) -> BytesN<32> {
+    let salt = BytesN::from_array(env, &[salt; 32]);
+    let commitment = client.vote_commitment(arbitrator, &vote, &salt);
+    client.commit_vote(arbitrator, &1, &commitment);
+    salt
+}
+
+fn advance_to_reveal(env: &Env, client: &DisputeResolutionContractClient) -> u64 {
+    let timeline = client.get_timeline(&DisputeCategory::General);
+    let reveal_opens = client.get_evidence_deadline(&1) + timeline.voting_window_secs + 1;
+    env.ledger().with_mut(|l| l.timestamp = reveal_opens);
+    reveal_opens + timeline.reveal_window_secs
+}
+
+#[test]
+fn test_reputation_weighted_vote() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (senior, junior_a, junior_b) = setup_arbitration_panel(&env, &client, &admin);
+
+    let oracle = env.register(MockReputationOracle, ());
+    MockReputationOracleClient::new(&env, &oracle).set_score(&senior, &450);
+    client.set_vote_weighting(
+        &admin,
+        &VoteWeighting {
+            reputation_contract: oracle,
+            base_weight: 1,
+            reputation_bps: 100,
+            max_weight: 10,
+        },
+    );
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = client.get_evidence_deadline(&1) + 1);
+    let senior_salt = commit(&env, &client, &senior, DisputeOutcome::FavorFreelancer, 1);
+    let salt_a = commit(&env, &client, &junior_a, DisputeOutcome::FavorClient, 2);
+    let salt_b = commit(&env, &client, &junior_b, DisputeOutcome::FavorClient, 3);
+
+    // Nothing is counted until votes are revealed
+    assert_eq!(client.tally_votes(&1).votes, 0);
+
+    let reveal_closes = advance_to_reveal(&env, &client);
+    client.reveal_vote(&senior, &1, &DisputeOutcome::FavorFreelancer, &senior_salt);
+    client.reveal_vote(&junior_a, &1, &DisputeOutcome::FavorClient, &salt_a);
+    client.reveal_vote(&junior_b, &1, &DisputeOutcome::FavorClient, &salt_b);
+
+    // The senior arbitrator's reputation outweighs the two unrated votes
+    let tally = client.tally_votes(&1);
+    assert_eq!(tally.favor_freelancer, 5);
+    assert_eq!(tally.favor_client, 2);
+    assert_eq!(tally.votes, 3);
+
+    env.ledger().with_mut(|l| l.timestamp = reveal_closes + 1);
+    let outcome = client.resolve_by_vote(&admin, &1);
+    assert_eq!(outcome, DisputeOutcome::FavorFreelancer);
+    assert_eq!(
+        client.get_dispute(&1).outcome,
+        DisputeOutcome::FavorFreelancer
+    );
+}
+
+#[test]
+fn test_vote_weight_falls_back_to_base_weight() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (senior, _, _) = setup_arbitration_panel(&env, &client, &admin);
+
+    // No reputation contract lives at this address, so every score lookup fails
+    client.set_vote_weighting(
+        &admin,
+        &VoteWeighting {
+            reputation_contract: Address::generate(&env),
+            base_weight: 2,
+            reputation_bps: 100,
+            max_weight: 10,
+        },
+    );
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = client.get_evidence_deadline(&1) + 1);
+    let salt = commit(&env, &client, &senior, DisputeOutcome::FavorFreelancer, 1);
+    advance_to_reveal(&env, &client);
+    client.reveal_vote(&senior, &1, &DisputeOutcome::FavorFreelancer, &salt);
+
+    let tally = client.tally_votes(&1);
+    assert_eq!(tally.favor_freelancer, 2);
+    assert_eq!(tally.votes, 1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #28)")]
+fn test_commit_vote_twice() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (senior, _, _) = setup_arbitration_panel(&env, &client, &admin);
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = client.get_evidence_deadline(&1) + 1);
+    commit(&env, &client, &senior, DisputeOutcome::FavorFreelancer, 1);
+    commit(&env, &client, &senior, DisputeOutcome::FavorClient, 2);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #46)")]
+fn test_reveal_vote_must_match_commitment() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (senior, _, _) = setup_arbitration_panel(&env, &client, &admin);
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = client.get_evidence_deadline(&1) + 1);
+    let salt = commit(&env, &client, &senior, DisputeOutcome::FavorFreelancer, 1);
+
+    advance_to_reveal(&env, &client);
+    client.reveal_vote(&senior, &1, &DisputeOutcome::FavorClient, &salt);
+}
+
+#[test]
+fn test_non_revealer_is_slashed() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (senior, junior_a, junior_b) = setup_arbitration_panel(&env, &client, &admin);
+
+    let token = setup_staking(&env, &client, &admin);
+    client.set_slashing_config(
+        &admin,
+        &SlashingConfig {
+            treasury: Address::generate(&env),
+            suspension_secs: 86_400,
+            appeal_window_secs: 86_400,
+            non_reveal_penalty: 200,
+        },
+    );
+    for arbitrator in [&senior, &junior_a, &junior_b] {
+        StellarAssetClient::new(&env, &token).mint(arbitrator, &1_000);
+        client.register_arbitrator(arbitrator, &1_000);
+    }
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = client.get_evidence_deadline(&1) + 1);
+    let senior_salt = commit(&env, &client, &senior, DisputeOutcome::FavorClient, 1);
+    let salt_a = commit(&env, &client, &junior_a, DisputeOutcome::FavorClient, 2);
+    commit(&env, &client, &junior_b, DisputeOutcome::FavorFreelancer, 3);
+
+    let reveal_closes = advance_to_reveal(&env, &client);
+    client.reveal_vote(&senior, &1, &DisputeOutcome::FavorClient, &senior_salt);
+    client.reveal_vote(&junior_a, &1, &DisputeOutcome::FavorClient, &salt_a);
+
+    env.ledger().with_mut(|l| l.timestamp = reveal_closes + 1);
+    assert_eq!(
+        client.resolve_by_vote(&admin, &1),
+        DisputeOutcome::FavorClient
+    );
+
+    assert_eq!(client.get_staked_arbitrator(&junior_b).unwrap().stake, 800);
+    assert_eq!(client.get_slash(&1).unwrap().arbitrator, junior_b);
+    assert_eq!(client.get_staked_arbitrator(&senior).unwrap().stake, 1_000);
+}
+
+#[test]
+fn test_unrevealed_vote_falls_back_to_split() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (senior, junior_a, _) = setup_arbitration_panel(&env, &client, &admin);
+
+    let token = setup_staking(&env, &client, &admin);
+    client.set_slashing_config(
+        &admin,
+        &SlashingConfig {
+            treasury: Address::generate(&env),
+            suspension_secs: 86_400,
+            appeal_window_secs: 86_400,
+            non_reveal_penalty: 200,
+        },
+    );
+    for arbitrator in [&senior, &junior_a] {
+        StellarAssetClient::new(&env, &token).mint(arbitrator, &1_000);
+        client.register_arbitrator(arbitrator, &1_000);
+    }
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = client.get_evidence_deadline(&1) + 1);
+    commit(&env, &client, &senior, DisputeOutcome::FavorClient, 1);
+    commit(&env, &client, &junior_a, DisputeOutcome::FavorFreelancer, 2);
+
+    // Nobody reveals, so the dispute splits instead of staying open
+    let reveal_closes = advance_to_reveal(&env, &client);
+    env.ledger().with_mut(|l| l.timestamp = reveal_closes + 1);
+    assert_eq!(client.resolve_by_vote(&admin, &1), DisputeOutcome::Split);
+    assert!(client.get_dispute(&1).resolved);
+
+    assert_eq!(client.get_staked_arbitrator(&senior).unwrap().stake, 800);
+    assert_eq!(client.get_staked_arbitrator(&junior_a).unwrap().stake, 800);
+
+    assert_eq!(
+        client.try_resolve_by_vote(&admin, &1),
+        Err(Ok(Error::DisputeAlreadyResolved))
+    );
+}
+
+fn setup_appealed_dispute(
+    env: &Env,
+    client: &DisputeResolutionContractClient,
+    admin: &Address,
+) -> (Address, Address, Vec<Address>) {
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.set_appeal_config(
+        admin,
+        &AppealConfig {
+            token: token.clone(),
+            min_bond: 100,
+            panel_size: 3,
+        },
+    );
+
+    let initiator = Address::generate(env);
+    let respondent = Address::generate(env);
+    let mediator = Address::generate(env);
+    let arbitrator = Address::generate(env);
+    client.add_mediator_access(admin, &mediator);
+    client.add_arbitrator(admin, &arbitrator, &String::from_str(env, "Arbitrator"));
+
+    client.open_dispute_against(
+        &DisputeFiling {
+            job_id: 1,
+            initiator,
+            reason: String::from_str(env, "Job not completed"),
+            escrow_contract: None,
+            dispute_amount: 1000000,
+        },
+        &respondent,
+        &DisputeCategory::General,
+    );
+    client.assign_mediator(&1, admin, &mediator);
+    client.escalate_to_arbitration(&1, &mediator, &arbitrator);
+    client.resolve_dispute(&1, &DisputeOutcome::FavorClient);
+
+    StellarAssetClient::new(env, &token).mint(&respondent, &100);
+    client.appeal(&respondent, &1, &100);
+
+    let mut jurors = Vec::new(env);
+    for _ in 0..3 {
+        let juror = Address::generate(env);
+        client.add_arbitrator(admin, &juror, &String::from_str(env, "Juror"));
+        jurors.push_back(juror);
+    }
+    client.assign_appeal_jurors(admin, &1, &jurors);
+
+    (token, respondent, jurors)
+}
+
+#[test]
+fn test_appeal_overturns_decision_and_returns_bond() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (token, respondent, jurors) = setup_appealed_dispute(&env, &client, &admin);
+    assert_eq!(TokenClient::new(&env, &token).balance(&respondent), 0);
+
+    client.cast_appeal_vote(
+        &jurors.get(0).unwrap(),
+        &1,
+        &DisputeOutcome::FavorFreelancer,
+    );
+    client.cast_appeal_vote(
+        &jurors.get(1).unwrap(),
+        &1,
+        &DisputeOutcome::FavorFreelancer,
+    );
+    client.cast_appeal_vote(&jurors.get(2).unwrap(), &1, &DisputeOutcome::FavorClient);
+
+    assert_eq!(client.finalize_appeal(&1), DisputeOutcome::FavorFreelancer);
+    assert_eq!(
+        client.get_dispute(&1).outcome,
+        DisputeOutcome::FavorFreelancer
+    );
+    assert_eq!(TokenClient::new(&env, &token).balance(&respondent), 100);
+}
+
+#[test]
+fn test_appeal_upheld_forfeits_bond() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let fee_manager = env.register(FeeManagerContract, ());
+    let fee_manager_client = FeeManagerContractClient::new(&env, &fee_manager);
+    fee_manager_client.initialize(&admin, &Address::generate(&env));
+    let client =
+        DisputeResolutionContractClient::new(&env, &env.register(DisputeResolutionContract, ()));
+    client.initialize(&admin, &86400_u64, &Address::generate(&env), &fee_manager);
+    let (token, respondent, jurors) = setup_appealed_dispute(&env, &client, &admin);
+    fee_manager_client.add_fee_asset(&admin, &token);
+
+    // No outcome reaches a majority, so the original decision stands
+    client.cast_appeal_vote(
+        &jurors.get(0).unwrap(),
+        &1,
+        &DisputeOutcome::FavorFreelancer,
+    );
+    client.cast_appeal_vote(&jurors.get(1).unwrap(), &1, &DisputeOutcome::Split);
+    client.cast_appeal_vote(&jurors.get(2).unwrap(), &1, &DisputeOutcome::FavorClient);
+
+    assert_eq!(client.finalize_appeal(&1), DisputeOutcome::FavorClient);
+    assert_eq!(TokenClient::new(&env, &token).balance(&respondent), 0);
+    assert_eq!(TokenClient::new(&env, &token).balance(&fee_manager), 100);
+    assert_eq!(fee_manager_client.get_accrued(&token), 100);
+}
+
+/// Stands in for the escrow factory's platform settings.
+#[contract]
+pub struct MockPlatform;
+
+#[contractimpl]
+impl MockPlatform {
+    pub fn set_dispute_contract(env: Env, dispute_contract: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("DISPUTE"), &dispute_contract);
+    }
+
+    pub fn get_dispute_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&symbol_short!("DISPUTE"))
+    }
+
+    pub fn get_emergency_contract(_env: Env) -> Option<Address> {
+        None
+    }
+
+    pub fn get_fee_manager(_env: Env) -> Option<Address> {
+        None
+    }
+
+    pub fn get_verification_policy(_env: Env) -> Option<Val> {
+        None
+    }
+
+    pub fn get_compliance_policy(_env: Env) -> Option<Val> {
+        None
+    }
+
+    pub fn get_referral_contract(_env: Env) -> Option<Address> {
+        None
+    }
+}
+
+#[test]
+fn test_deferred_outcome_settles_real_escrow() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.set_appeal_config(
+        &admin,
+        &AppealConfig {
+            token: token.clone(),
+            min_bond: 100,
+            panel_size: 3,
+        },
+    );
+
+    let platform = env.register(MockPlatform, ());
+    MockPlatformClient::new(&env, &platform).set_dispute_contract(&client.address);
+    let escrow_id = env.register(EscrowContract, ());
+    let escrow = EscrowContractClient::new(&env, &escrow_id);
+    let escrow_client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    escrow.set_platform(&platform);
+    escrow.init_contract_full(
+        &escrow_client,
+        &freelancer,
+        &Address::generate(&env),
+        &token,
+        &500,
+        &3600,
+    );
+    StellarAssetClient::new(&env, &token).mint(&escrow_client, &500);
+    escrow.deposit_funds(&escrow_client);
+    escrow.open_dispute(
+        &escrow_client,
+        &1,
+        &String::from_str(&env, "Job not completed"),
+    );
+
+    let mediator = Address::generate(&env);
+    client.add_mediator_access(&admin, &mediator);
+    client.assign_mediator(&1, &admin, &mediator);
+    client.resolve_dispute_with_auth(&1, &DisputeOutcome::FavorFreelancer, &mediator);
+    assert_eq!(TokenClient::new(&env, &token).balance(&escrow_id), 500);
+
+    // Nobody signs the settlement, the mediator's decision is long past
+    env.set_auths(&[]);
+    let timeline = client.get_timeline(&DisputeCategory::General);
+    env.ledger()
+        .with_mut(|l| l.timestamp += timeline.appeal_window_secs + 1);
+    client.finalize_resolution(&1);
+
+    assert_eq!(TokenClient::new(&env, &token).balance(&freelancer), 500);
+    assert_eq!(TokenClient::new(&env, &token).balance(&escrow_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #37)")]
+fn test_finalize_appeal_before_all_votes() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (_, _, jurors) = setup_appealed_dispute(&env, &client, &admin);
+
+    client.cast_appeal_vote(
+        &jurors.get(0).unwrap(),
+        &1,
+        &DisputeOutcome::FavorFreelancer,
+    );
+    client.finalize_appeal(&1);
+}
+
+fn setup_escrow_dispute(
+    env: &Env,
+    client: &DisputeResolutionContractClient,
+    admin: &Address,
+) -> (Address, Address, Address) {
+    let escrow_client = Address::generate(env);
+    let freelancer = Address::generate(env);
+    let escrow = env.register(MockEscrow, ());
+    MockEscrowClient::new(env, &escrow).set_parties(&escrow_client, &freelancer);
+
+    let oracle = env.register(MockReputationOracle, ());
+    let oracle_client = MockReputationOracleClient::new(env, &oracle);
+    oracle_client.set_score(&escrow_client, &400);
+    oracle_client.set_score(&freelancer, &400);
+    client.set_reputation_penalty(
+        admin,
+        &ReputationPenaltyConfig {
+            reputation_contract: oracle.clone(),
+            penalty_points: 50,
+        },
+    );
+
+    let mediator = Address::generate(env);
+    client.add_mediator_access(admin, &mediator);
+    client.open_dispute(
+        &1,
+        &escrow_client,
+        &String::from_str(env, "Job not completed"),
+        &Some(escrow),
+        &1000000,
+    );
+    client.assign_mediator(&1, admin, &mediator);
+
+    (oracle, escrow_client, freelancer)
+}
+
+#[test]
+fn test_losing_party_reputation_penalized() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (oracle, escrow_client, freelancer) = setup_escrow_dispute(&env, &client, &admin);
+
+    client.resolve_dispute(&1, &DisputeOutcome::FavorClient);
+
+    let oracle_client = MockReputationOracleClient::new(&env, &oracle);
+    assert_eq!(oracle_client.get_user_reputation_score(&freelancer), 350);
+    assert_eq!(oracle_client.get_user_reputation_score(&escrow_client), 400);
+}
+
+#[test]
+fn test_split_outcome_has_no_penalty() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (oracle, escrow_client, freelancer) = setup_escrow_dispute(&env, &client, &admin);
+
+    client.resolve_dispute(&1, &DisputeOutcome::Split);
+
+    let oracle_client = MockReputationOracleClient::new(&env, &oracle);
+    assert_eq!(oracle_client.get_user_reputation_score(&freelancer), 400);
+    assert_eq!(oracle_client.get_user_reputation_score(&escrow_client), 400);
+}
+
+#[test]
+fn test_resolve_with_split_applies_bps_to_escrow() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    setup_escrow_dispute(&env, &client, &admin);
+    let dispute = client.get_dispute(&1);
+    let escrow = dispute.escrow_contract.unwrap();
+
+    client.resolve_with_split(&1, &dispute.mediator.unwrap(), &3_500, &6_500);
+
+    let resolved = client.get_dispute(&1);
+    assert_eq!(resolved.outcome, DisputeOutcome::Split);
+    assert_eq!(resolved.freelancer_bps, Some(6_500));
+    assert_eq!(resolved.state, DisputeState::Resolved);
+    assert_eq!(
+        MockEscrowClient::new(&env, &escrow).applied_split(),
+        Some(6_500)
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #47)")]
+fn test_resolve_with_split_rejects_bad_total() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    setup_escrow_dispute(&env, &client, &admin);
+    let mediator = client.get_dispute(&1).mediator.unwrap();
+
+    client.resolve_with_split(&1, &mediator, &3_000, &6_000);
+}
+
+fn setup_category_routing(
+    env: &Env,
+    client: &DisputeResolutionContractClient,
+    admin: &Address,
+) -> (Vec<Address>, Address) {
+    client.set_category_rules(
+        admin,
+        &DisputeCategory::Quality,
+        &CategoryRules {
+            juror_count: 2,
+            fee_bps: 300,
+        },
+    );
+
+    let mut quality = Vec::new(env);
+    for _ in 0..3 {
+        let arbitrator = Address::generate(env);
+        client.add_arbitrator(admin, &arbitrator, &String::from_str(env, "Arbitrator"));
+        client.register_categories(
+            &arbitrator,
+            &Vec::from_array(env, [DisputeCategory::Quality]),
+        );
+        quality.push_back(arbitrator);
+    }
+    let payment_only = Address::generate(env);
+    client.add_arbitrator(admin, &payment_only, &String::from_str(env, "Arbitrator"));
+    client.register_categories(
+        &payment_only,
+        &Vec::from_array(env, [DisputeCategory::Payment]),
+    );
+
+    client.open_dispute_against(
+        &DisputeFiling {
+            job_id: 1,
+            initiator: Address::generate(env),
+            reason: String::from_str(env, "Job not completed"),
+            escrow_contract: None,
+            dispute_amount: 1000000,
+        },
+        &Address::generate(env),
+        &DisputeCategory::Quality,
+    );
+
+    (quality, payment_only)
+}
+
+#[test]
+fn test_route_dispute_draws_category_panel() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (quality, payment_only) = setup_category_routing(&env, &client, &admin);
+    assert_eq!(
+        client.get_category_arbitrators(&DisputeCategory::Quality),
+        quality
+    );
+
+    let panel = client.route_dispute(&admin, &1);
+    assert_eq!(panel.len(), 2);
+    for juror in panel.iter() {
+        assert!(quality.contains(&juror));
+    }
+    assert!(!panel.contains(&payment_only));
+    assert_eq!(client.get_dispute_panel(&1), Some(panel));
+
+    // The category fee replaces the default 5%
+    let mediator = Address::generate(&env);
+    client.add_mediator_access(&admin, &mediator);
+    client.assign_mediator(&1, &admin, &mediator);
+    client.resolve_dispute(&1, &DisputeOutcome::FavorClient);
+    assert_eq!(client.get_dispute(&1).fee_collected, 30_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_escalation_requires_category_registration() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (_, payment_only) = setup_category_routing(&env, &client, &admin);
+
+    let mediator = Address::generate(&env);
+    client.add_mediator_access(&admin, &mediator);
+    client.assign_mediator(&1, &admin, &mediator);
+    client.escalate_to_arbitration(&1, &mediator, &payment_only);
+}
+
+#[test]
+fn test_category_timeline_drives_phases() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    client.set_timeline(
+        &admin,
+        &DisputeCategory::Quality,
+        &DisputeTimeline {
+            evidence_window_secs: 100,
+            voting_window_secs: 200,
+            reveal_window_secs: 50,
+            appeal_window_secs: 300,
+        },
+    );
+
+    client.open_dispute_against(
+        &DisputeFiling {
+            job_id: 1,
+            initiator: Address::generate(&env),
+            reason: String::from_str(&env, "Job not completed"),
+            escrow_contract: None,
+            dispute_amount: 1000000,
+        },
+        &Address::generate(&env),
+        &DisputeCategory::Quality,
+    );
+
+    assert_eq!(client.get_evidence_deadline(&1), 1100);
+    assert_eq!(client.get_dispute_phase(&1), DisputePhase::Evidence);
+
+    env.ledger().with_mut(|l| l.timestamp = 1101);
+    assert_eq!(client.get_dispute_phase(&1), DisputePhase::Commit);
+
+    env.ledger().with_mut(|l| l.timestamp = 1301);
+    assert_eq!(client.get_dispute_phase(&1), DisputePhase::Reveal);
+
+    env.ledger().with_mut(|l| l.timestamp = 1351);
+    assert_eq!(
+        client.get_dispute_phase(&1),
+        DisputePhase::AwaitingResolution
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #41)")]
+fn test_commit_vote_during_evidence_phase() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (senior, _, _) = setup_arbitration_panel(&env, &client, &admin);
+
+    commit(&env, &client, &senior, DisputeOutcome::FavorFreelancer, 1);
+}
+
+#[test]
+fn test_get_disputes_by_party() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let party = Address::generate(&env);
+    let counterparty = Address::generate(&env);
+    let mediator = Address::generate(&env);
+    client.add_mediator_access(&admin, &mediator);
+
+    for job_id in 1..=3u32 {
+        client.open_dispute_against(
+            &DisputeFiling {
+                job_id,
+                initiator: party.clone(),
+                reason: String::from_str(&env, "Job not completed"),
+                escrow_contract: None,
+                dispute_amount: 1000000,
+            },
+            &counterparty,
+            &DisputeCategory::General,
+        );
+    }
+    client.open_dispute(
+        &4,
+        &Address::generate(&env),
+        &String::from_str(&env, "Job not completed"),
+        &None,
+        &1000000,
+    );
+    client.assign_mediator(&2, &admin, &mediator);
+    client.resolve_dispute(&2, &DisputeOutcome::FavorClient);
+
+    // Newest first, and the respondent is indexed too
+    let all = client.get_disputes_by_party(&counterparty, &None, &0, &10);
+    assert_eq!(all.len(), 3);
+    assert_eq!(all.get(0).unwrap().dispute_id, 3);
+
+    let open = client.get_disputes_by_party(&party, &Some(DisputeState::Open), &1, &10);
+    assert_eq!(open.len(), 1);
+    assert_eq!(open.get(0).unwrap().dispute_id, 1);
+
+    let resolved = client.get_disputes_by_party(&party, &Some(DisputeState::Resolved), &0, &10);
+    assert_eq!(resolved.len(), 1);
+    assert_eq!(resolved.get(0).unwrap().dispute_id, 2);
+}
+
+fn setup_slashing(
+    env: &Env,
+    client: &DisputeResolutionContractClient,
+    admin: &Address,
+) -> (Address, Address, Address) {
+    let token = setup_staking(env, client, admin);
+    let treasury = Address::generate(env);
+    client.set_slashing_config(
+        admin,
+        &SlashingConfig {
+            treasury: treasury.clone(),
+            suspension_secs: 7 * 86_400,
+            appeal_window_secs: 86_400,
+            non_reveal_penalty: 100,
+        },
+    );
+
+    let arbitrator = Address::generate(env);
+    StellarAssetClient::new(env, &token).mint(&arbitrator, &1_500);
+    client.register_arbitrator(&arbitrator, &1_500);
+
+    (token, treasury, arbitrator)
+}
+
+#[test]
+fn test_slash_arbitrator_executes_after_appeal_window() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (token, treasury, arbitrator) = setup_slashing(&env, &client, &admin);
+
+    let slash_id = client.slash_arbitrator(
+        &admin,
+        &arbitrator,
+        &500,
+        &String::from_str(&env, "Ruled on a case with a conflict of interest"),
+    );
+    assert_eq!(
+        client.get_staked_arbitrator(&arbitrator).unwrap().stake,
+        1_000
+    );
+    assert_eq!(client.get_active_arbitrators(&0, &10).len(), 0);
+    assert!(client.try_execute_slash(&slash_id).is_err());
+
+    env.ledger().with_mut(|l| l.timestamp = 1000 + 86_401);
+    client.execute_slash(&slash_id);
+    assert_eq!(TokenClient::new(&env, &token).balance(&treasury), 500);
+    assert_eq!(
+        client.get_slash(&slash_id).unwrap().status,
+        SlashStatus::Executed
+    );
+
+    // Eligible again once the suspension ends
+    env.ledger().with_mut(|l| l.timestamp = 1000 + 7 * 86_400);
+    assert_eq!(client.get_active_arbitrators(&0, &10).len(), 1);
+}
+
+#[test]
+fn test_reversed_slash_restores_stake() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let (client, admin, _, _) = create_contract(&env);
+    let (token, treasury, arbitrator) = setup_slashing(&env, &client, &admin);
+
+    let slash_id = client.slash_arbitrator(
+        &admin,
+        &arbitrator,
+        &500,
+        &String::from_str(&env, "Missed the voting deadline"),
+    );
+    client.appeal_slash(&arbitrator, &slash_id);
+    client.resolve_slash_appeal(&admin, &slash_id, &true);
+
+    let staked = client.get_staked_arbitrator(&arbitrator).unwrap();
+    assert_eq!(staked.stake, 1_500);
+    assert_eq!(staked.suspended_until, None);
+    assert_eq!(client.get_active_arbitrators(&0, &10).len(), 1);
+    assert_eq!(TokenClient::new(&env, &token).balance(&treasury), 0);
+    assert_eq!(
+        client.get_slash(&slash_id).unwrap().status,
+        SlashStatus::Reversed
+    );
+}
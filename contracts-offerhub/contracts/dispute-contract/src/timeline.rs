@@ -0,0 +1,105 @@
+use soroban_sdk::{Address, Env, Map, String};
+
+use crate::{
+    access::check_admin,
+    contract::get_dispute,
+    error::Error,
+    storage::{
+        DEFAULT_APPEAL_WINDOW_SECS, DEFAULT_EVIDENCE_WINDOW_SECS, DEFAULT_REVEAL_WINDOW_SECS,
+        DEFAULT_VOTING_WINDOW_SECS, TIMELINES,
+    },
+    types::{DisputeCategory, DisputeData, DisputePhase, DisputeTimeline},
+};
+
+pub fn set_timeline(
+    env: &Env,
+    admin: Address,
+    category: DisputeCategory,
+    timeline: DisputeTimeline,
+) -> Result<(), Error> {
+    check_admin(env, &admin)?;
+
+    if timeline.evidence_window_secs == 0
+        || timeline.voting_window_secs == 0
+        || timeline.reveal_window_secs == 0
+        || timeline.appeal_window_secs == 0
+    {
+        return Err(Error::InvalidTimeline);
+    }
+
+    let mut timelines = timelines(env);
+    timelines.set(category, timeline.clone());
+    env.storage().instance().set(&TIMELINES, &timelines);
+
+    env.events().publish(
+        (String::from_str(env, "timeline_updated"), category),
+        (
+            timeline.evidence_window_secs,
+            timeline.voting_window_secs,
+            timeline.reveal_window_secs,
+            timeline.appeal_window_secs,
+        ),
+    );
+
+    Ok(())
+}
+
+pub fn get_timeline(env: &Env, category: DisputeCategory) -> DisputeTimeline {
+    timelines(env).get(category).unwrap_or(DisputeTimeline {
+        evidence_window_secs: DEFAULT_EVIDENCE_WINDOW_SECS,
+        voting_window_secs: DEFAULT_VOTING_WINDOW_SECS,
+        reveal_window_secs: DEFAULT_REVEAL_WINDOW_SECS,
+        appeal_window_secs: DEFAULT_APPEAL_WINDOW_SECS,
+    })
+}
+
+/// Phase of a dispute at the current ledger timestamp.
+pub fn get_dispute_phase(env: &Env, dispute_id: u32) -> DisputePhase {
+    let dispute = get_dispute(env, dispute_id);
+    let now = env.ledger().timestamp();
+
+    if dispute.resolved {
+        return if now <= appeal_deadline(env, &dispute) {
+            DisputePhase::Appeal
+        } else {
+            DisputePhase::Final
+        };
+    }
+
+    if now <= evidence_deadline(env, &dispute) {
+        DisputePhase::Evidence
+    } else if now <= commit_deadline(env, &dispute) {
+        DisputePhase::Commit
+    } else if now <= reveal_deadline(env, &dispute) {
+        DisputePhase::Reveal
+    } else {
+        DisputePhase::AwaitingResolution
+    }
+}
+
+// Phases follow each other back to back: evidence from filing, then vote commits, then
+// reveals, and the appeal window from the resolution.
+
+pub(crate) fn evidence_deadline(env: &Env, dispute: &DisputeData) -> u64 {
+    dispute.timestamp + get_timeline(env, dispute.category).evidence_window_secs
+}
+
+pub(crate) fn commit_deadline(env: &Env, dispute: &DisputeData) -> u64 {
+    evidence_deadline(env, dispute) + get_timeline(env, dispute.category).voting_window_secs
+}
+
+pub(crate) fn reveal_deadline(env: &Env, dispute: &DisputeData) -> u64 {
+    commit_deadline(env, dispute) + get_timeline(env, dispute.category).reveal_window_secs
+}
+
+pub(crate) fn appeal_deadline(env: &Env, dispute: &DisputeData) -> u64 {
+    let resolved_at = dispute.resolution_timestamp.unwrap_or(dispute.timestamp);
+    resolved_at + get_timeline(env, dispute.category).appeal_window_secs
+}
+
+fn timelines(env: &Env) -> Map<DisputeCategory, DisputeTimeline> {
+    env.storage()
+        .instance()
+        .get(&TIMELINES)
+        .unwrap_or_else(|| Map::new(env))
+}
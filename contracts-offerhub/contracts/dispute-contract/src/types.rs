@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Map, String, Vec};
 
 #[contracttype]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -47,6 +47,49 @@ pub enum DisputeLevel {
     Arbitration,
 }
 
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum DisputeCategory {
+    General,
+    Quality,
+    NonDelivery,
+    Payment,
+    Scope,
+}
+
+/// Routing rules for one dispute category. Its phase lengths are set separately with
+/// `set_timeline`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CategoryRules {
+    pub juror_count: u32, // Arbitrators drawn from the category's roster
+    pub fee_bps: u32,     // Resolution fee, replaces the default 5%
+}
+
+/// Phase lengths for disputes of one category. Evidence runs from filing, vote commits and
+/// then reveals follow it, and the appeal window runs from resolution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeTimeline {
+    pub evidence_window_secs: u64,
+    pub voting_window_secs: u64, // Commit phase
+    pub reveal_window_secs: u64,
+    pub appeal_window_secs: u64,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum DisputePhase {
+    Evidence,
+    Commit,
+    Reveal,
+    AwaitingResolution,
+    Appeal,
+    Final,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Evidence {
@@ -56,14 +99,38 @@ pub struct Evidence {
     pub attachment_hash: Option<String>, // IPFS hash or similar
 }
 
+/// Evidence committed by hash; the documents themselves stay off-chain.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EvidenceCommitment {
+    pub submitter: Address,
+    pub evidence_hash: BytesN<32>,
+    pub description_hash: BytesN<32>,
+    pub submitted_at: u64,
+}
+
+/// What the initiator files to open a dispute over a job.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeFiling {
+    pub job_id: u32,
+    pub initiator: Address,
+    pub reason: String,
+    pub escrow_contract: Option<Address>,
+    pub dispute_amount: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DisputeData {
     pub initiator: Address,
+    pub respondent: Option<Address>,
+    pub category: DisputeCategory,
     pub reason: String,
     pub timestamp: u64,
     pub resolved: bool,
     pub outcome: DisputeOutcome,
+    pub freelancer_bps: Option<u32>, // Set when resolved with an explicit split
     pub state: DisputeState,
     pub level: DisputeLevel,
     pub fee_manager: Address,
@@ -88,6 +155,102 @@ pub struct ArbitratorData {
     pub added_at: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakingConfig {
+    pub token: Address,
+    pub min_stake: i128,
+    pub unbonding_period: u64, // Seconds a deregistered arbitrator's stake stays locked
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakedArbitrator {
+    pub address: Address,
+    pub stake: i128,
+    pub registered_at: u64,
+    pub unbonding_until: Option<u64>,
+    pub suspended_until: Option<u64>, // Set by a slash; not selectable until then
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashingConfig {
+    pub treasury: Address,       // Receives confiscated stake
+    pub suspension_secs: u64,    // How long a slashed arbitrator is excluded from selection
+    pub appeal_window_secs: u64, // Time the arbitrator has to contest a slash
+    pub non_reveal_penalty: i128, // Slashed from arbitrators who commit a vote but never reveal it
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum SlashStatus {
+    Pending,
+    Appealed,
+    Executed,
+    Reversed,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Slash {
+    pub arbitrator: Address,
+    pub amount: i128,
+    pub reason: String,
+    pub slashed_at: u64,
+    pub appeal_deadline: u64,
+    pub status: SlashStatus,
+}
+
+/// How arbitrator votes are weighted by the reputation NFT contract's score:
+/// `base_weight + score * reputation_bps / 10_000`, capped at `max_weight` (0 = uncapped).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteWeighting {
+    pub reputation_contract: Address,
+    pub base_weight: u32,
+    pub reputation_bps: u32,
+    pub max_weight: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteTally {
+    pub favor_freelancer: u64,
+    pub favor_client: u64,
+    pub split: u64,
+    pub votes: u32,
+    pub leading: DisputeOutcome,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AppealConfig {
+    pub token: Address, // Token the appeal bond is paid in
+    pub min_bond: i128,
+    pub panel_size: u32, // Minimum jurors on an appeal panel
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Appeal {
+    pub appellant: Address,
+    pub bond: i128,
+    pub original_outcome: DisputeOutcome,
+    pub jurors: Vec<Address>,
+    pub votes: Map<Address, DisputeOutcome>,
+    pub filed_at: u64,
+    pub final_outcome: DisputeOutcome, // None until the panel's decision is final
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationPenaltyConfig {
+    pub reputation_contract: Address,
+    pub penalty_points: u32, // Deducted from the losing party's reputation score
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ContractConfig {
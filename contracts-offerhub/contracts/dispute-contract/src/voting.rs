@@ -0,0 +1,308 @@
+use soroban_sdk::{xdr::ToXdr, Address, BytesN, Env, IntoVal, Map, String, Symbol};
+
+use crate::{
+    access::{check_admin, is_valid_arbitrator},
+    category::{can_serve_category, get_dispute_panel},
+    contract::{get_dispute, is_paused, resolve_voted_dispute},
+    error::Error,
+    slashing::penalize_non_revealer,
+    staking::{is_staked_arbitrator, staking_enabled},
+    storage::{DISPUTE_VOTES, VOTE_COMMITS, VOTE_WEIGHTING},
+    timeline::{commit_deadline, evidence_deadline, reveal_deadline},
+    types::{DisputeLevel, DisputeOutcome, VoteTally, VoteWeighting},
+};
+
+const REPUTATION_SCORE: &str = "get_user_reputation_score";
+
+pub fn set_vote_weighting(
+    env: &Env,
+    admin: Address,
+    weighting: VoteWeighting,
+) -> Result<(), Error> {
+    check_admin(env, &admin)?;
+
+    // Every vote has to count for something, otherwise a tally can come out empty
+    if weighting.base_weight == 0 && weighting.reputation_bps == 0 {
+        return Err(Error::InvalidVoteWeighting);
+    }
+    if weighting.max_weight != 0 && weighting.max_weight < weighting.base_weight {
+        return Err(Error::InvalidVoteWeighting);
+    }
+
+    env.storage().instance().set(&VOTE_WEIGHTING, &weighting);
+
+    env.events().publish(
+        (String::from_str(env, "vote_weighting_updated"), admin),
+        (
+            weighting.reputation_contract,
+            weighting.base_weight,
+            weighting.reputation_bps,
+            weighting.max_weight,
+        ),
+    );
+
+    Ok(())
+}
+
+pub fn get_vote_weighting(env: &Env) -> Option<VoteWeighting> {
+    env.storage().instance().get(&VOTE_WEIGHTING)
+}
+
+/// Commits to a vote without disclosing it. The hash is
+/// `sha256(arbitrator XDR || vote as big-endian u32 || salt)`, see `vote_commitment`.
+pub fn commit_vote(
+    env: &Env,
+    arbitrator: Address,
+    dispute_id: u32,
+    commitment: BytesN<32>,
+) -> Result<(), Error> {
+    arbitrator.require_auth();
+
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    if !is_valid_arbitrator(env, &arbitrator)
+        || (staking_enabled(env) && !is_staked_arbitrator(env, &arbitrator))
+    {
+        return Err(Error::InvalidArbitrator);
+    }
+
+    let dispute = get_dispute(env, dispute_id);
+    if dispute.resolved {
+        return Err(Error::DisputeAlreadyResolved);
+    }
+    if dispute.level != DisputeLevel::Arbitration {
+        return Err(Error::ArbitrationRequired);
+    }
+
+    // A routed dispute is voted on by its panel alone
+    let may_vote = match get_dispute_panel(env, dispute_id) {
+        Some(panel) => panel.contains(&arbitrator),
+        None => can_serve_category(env, &arbitrator, dispute.category),
+    };
+    if !may_vote {
+        return Err(Error::InvalidArbitrator);
+    }
+
+    // Commits are only accepted once evidence has closed and until the commit window ends
+    let now = env.ledger().timestamp();
+    if now <= evidence_deadline(env, &dispute) || now > commit_deadline(env, &dispute) {
+        return Err(Error::PhaseNotActive);
+    }
+
+    let mut commits = get_commits(env, dispute_id);
+    if commits.contains_key(arbitrator.clone()) {
+        return Err(Error::VoteAlreadyCast);
+    }
+
+    commits.set(arbitrator.clone(), commitment);
+    env.storage()
+        .persistent()
+        .set(&(VOTE_COMMITS, dispute_id), &commits);
+
+    env.events().publish(
+        (String::from_str(env, "vote_committed"), dispute_id),
+        arbitrator,
+    );
+
+    Ok(())
+}
+
+/// Opens a committed vote during the reveal phase. Only revealed votes are tallied.
+pub fn reveal_vote(
+    env: &Env,
+    arbitrator: Address,
+    dispute_id: u32,
+    vote: DisputeOutcome,
+    salt: BytesN<32>,
+) -> Result<(), Error> {
+    arbitrator.require_auth();
+
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    if vote == DisputeOutcome::None {
+        return Err(Error::InvalidOutcome);
+    }
+
+    let dispute = get_dispute(env, dispute_id);
+    if dispute.resolved {
+        return Err(Error::DisputeAlreadyResolved);
+    }
+    let now = env.ledger().timestamp();
+    if now <= commit_deadline(env, &dispute) || now > reveal_deadline(env, &dispute) {
+        return Err(Error::PhaseNotActive);
+    }
+
+    let commitment = get_commits(env, dispute_id)
+        .get(arbitrator.clone())
+        .ok_or(Error::VoteNotCommitted)?;
+    if vote_commitment(env, &arbitrator, vote, &salt) != commitment {
+        return Err(Error::CommitmentMismatch);
+    }
+
+    let mut votes = get_votes(env, dispute_id);
+    if votes.contains_key(arbitrator.clone()) {
+        return Err(Error::VoteAlreadyCast);
+    }
+
+    votes.set(arbitrator.clone(), vote);
+    env.storage()
+        .persistent()
+        .set(&(DISPUTE_VOTES, dispute_id), &votes);
+
+    env.events().publish(
+        (String::from_str(env, "vote_revealed"), dispute_id),
+        (arbitrator, vote),
+    );
+
+    Ok(())
+}
+
+pub fn vote_commitment(
+    env: &Env,
+    arbitrator: &Address,
+    vote: DisputeOutcome,
+    salt: &BytesN<32>,
+) -> BytesN<32> {
+    let mut payload = arbitrator.clone().to_xdr(env);
+    payload.extend_from_array(&(vote as u32).to_be_bytes());
+    payload.extend_from_array(&salt.to_array());
+    env.crypto().sha256(&payload).into()
+}
+
+/// Sums the votes revealed so far, each weighted by the voter's current reputation.
+pub fn tally_votes(env: &Env, dispute_id: u32) -> VoteTally {
+    let weighting = get_vote_weighting(env);
+
+    let mut tally = VoteTally {
+        favor_freelancer: 0,
+        favor_client: 0,
+        split: 0,
+        votes: 0,
+        leading: DisputeOutcome::None,
+    };
+    for (arbitrator, outcome) in get_votes(env, dispute_id).iter() {
+        let weight = vote_weight(env, &weighting, &arbitrator);
+        match outcome {
+            DisputeOutcome::FavorFreelancer => tally.favor_freelancer += weight,
+            DisputeOutcome::FavorClient => tally.favor_client += weight,
+            DisputeOutcome::Split => tally.split += weight,
+            DisputeOutcome::None => continue,
+        }
+        tally.votes += 1;
+    }
+
+    tally.leading = leading_outcome(&tally);
+    tally
+}
+
+/// Resolves the dispute with the weighted majority once the reveal phase has closed, and
+/// slashes arbitrators who committed without revealing. A tie between the leading outcomes
+/// splits, and so does a vote nobody revealed, so the dispute can't be left stuck.
+pub fn resolve_by_vote(
+    env: &Env,
+    admin: Address,
+    dispute_id: u32,
+) -> Result<DisputeOutcome, Error> {
+    check_admin(env, &admin)?;
+
+    let dispute = get_dispute(env, dispute_id);
+    if dispute.resolved {
+        return Err(Error::DisputeAlreadyResolved);
+    }
+    if env.ledger().timestamp() <= reveal_deadline(env, &dispute) {
+        return Err(Error::PhaseNotActive);
+    }
+
+    let revealed = get_votes(env, dispute_id);
+    for (arbitrator, _) in get_commits(env, dispute_id).iter() {
+        if revealed.contains_key(arbitrator.clone()) {
+            continue;
+        }
+        let slash_id = penalize_non_revealer(env, arbitrator.clone());
+        env.events().publish(
+            (String::from_str(env, "vote_not_revealed"), dispute_id),
+            (arbitrator, slash_id),
+        );
+    }
+
+    let tally = tally_votes(env, dispute_id);
+    let outcome = if tally.votes == 0 {
+        DisputeOutcome::Split
+    } else {
+        tally.leading
+    };
+
+    resolve_voted_dispute(env, dispute_id, outcome);
+
+    env.events().publish(
+        (
+            String::from_str(env, "dispute_resolved_by_vote"),
+            dispute_id,
+        ),
+        (
+            outcome,
+            tally.favor_freelancer,
+            tally.favor_client,
+            tally.split,
+        ),
+    );
+
+    Ok(outcome)
+}
+
+fn get_commits(env: &Env, dispute_id: u32) -> Map<Address, BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&(VOTE_COMMITS, dispute_id))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+fn get_votes(env: &Env, dispute_id: u32) -> Map<Address, DisputeOutcome> {
+    env.storage()
+        .persistent()
+        .get(&(DISPUTE_VOTES, dispute_id))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+// weight = base_weight + score * reputation_bps / 10_000, capped at max_weight when non-zero.
+// Without a weighting configured every vote counts once; a failed score lookup counts as 0,
+// so an unreachable reputation contract cannot block resolution.
+fn vote_weight(env: &Env, weighting: &Option<VoteWeighting>, arbitrator: &Address) -> u64 {
+    let Some(weighting) = weighting else {
+        return 1;
+    };
+
+    let score = match env.try_invoke_contract::<u32, Error>(
+        &weighting.reputation_contract,
+        &Symbol::new(env, REPUTATION_SCORE),
+        (arbitrator.clone(),).into_val(env),
+    ) {
+        Ok(Ok(score)) => score,
+        _ => 0,
+    };
+
+    let weight =
+        weighting.base_weight as u64 + (score as u64) * (weighting.reputation_bps as u64) / 10_000;
+    if weighting.max_weight != 0 {
+        weight.min(weighting.max_weight as u64)
+    } else {
+        weight
+    }
+}
+
+fn leading_outcome(tally: &VoteTally) -> DisputeOutcome {
+    if tally.votes == 0 {
+        return DisputeOutcome::None;
+    }
+    if tally.favor_freelancer > tally.favor_client && tally.favor_freelancer > tally.split {
+        DisputeOutcome::FavorFreelancer
+    } else if tally.favor_client > tally.favor_freelancer && tally.favor_client > tally.split {
+        DisputeOutcome::FavorClient
+    } else {
+        DisputeOutcome::Split
+    }
+}
@@ -1,9 +1,10 @@
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, vec, Address, Env, Symbol,
+    contract, contractimpl, contracttype, symbol_short, vec, Address, Env, IntoVal, Symbol,
     Vec
 };
 
 use crate::error::{EmergencyError};
+use crate::platform::{GuardianCouncil, PlatformStatus, RegisteredContract};
 
 // Emergency contract types
 #[contracttype]
@@ -46,11 +47,18 @@ const EMERGENCY_WITHDRAWAL: Symbol = symbol_short!("WITHDRAW");
 const STATUS_PENDING: Symbol = symbol_short!("PENDING");
 const STATUS_APPROVED: Symbol = symbol_short!("APPROVED");
 
+// Entry point every registered contract exposes to receive pause pushes
+const SET_PAUSED_FN: &str = "set_paused";
+
 
 // Emergency contract implementation
 #[contract]
 pub struct EmergencyContract;
 
+offerhub_pausable::impl_pausable!(@roles EmergencyContract, EmergencyError, |env, _| {
+    Ok(EmergencyContract::pause_admin(env))
+});
+
 #[contractimpl]
 impl EmergencyContract {
     // Initialize emergency contract
@@ -108,7 +116,7 @@ impl EmergencyContract {
         Self::log_emergency_action(env, EMERGENCY_UNPAUSE, symbol_short!("UNPAUSED"));
     }
 
-    // Check if contract is paused
+    // Check if contract is paused, either by the emergency admin or through the shared pause
     pub fn is_paused(env: &Env) -> bool {
         let state: EmergencyState = env
             .storage()
@@ -116,7 +124,7 @@ impl EmergencyContract {
             .get(&symbol_short!("STATE"))
             .unwrap_or_else(|| env.panic_with_error(EmergencyError::InvalidEmergencyAction));
 
-        state.is_paused
+        state.is_paused || offerhub_pausable::is_paused(env)
     }
 
     // Circuit breaker pattern for suspicious activity
@@ -170,7 +178,7 @@ impl EmergencyContract {
             .get(&symbol_short!("REQUESTS"))
             .unwrap_or_else(|| vec![env]);
 
-        let request_id = recovery_requests.len() as u32 + 1;
+        let request_id = recovery_requests.len() + 1;
         let recovery_request = RecoveryRequest {
             request_id,
             user_address : user_address.clone(),
@@ -184,7 +192,7 @@ impl EmergencyContract {
 
         env.storage().instance().set(&symbol_short!("REQUESTS"), &recovery_requests);
         
-        env.events().publish((Symbol::new(env, "created_recovery_req"), request_id.clone()), (user_address, amount, reason, env.ledger().timestamp()));
+        env.events().publish((Symbol::new(env, "created_recovery_req"), request_id), (user_address, amount, reason, env.ledger().timestamp()));
 
         request_id
     }
@@ -283,17 +291,15 @@ impl EmergencyContract {
     }
 
     fn check_contract_not_paused(env: &Env) {
-        let state: EmergencyState = env
-            .storage()
-            .instance()
-            .get(&symbol_short!("STATE"))
-            .unwrap_or_else(|| env.panic_with_error(EmergencyError::InvalidEmergencyAction));
-
-        if state.is_paused {
+        if Self::is_paused(env) {
             env.panic_with_error(EmergencyError::ContractPaused);
         }
     }
 
+    fn pause_admin(env: &Env) -> Address {
+        Self::get_emergency_state(env).emergency_admin
+    }
+
     fn log_emergency_action(env: &Env, action_type: Symbol, description: Symbol) {
         let mut actions: Vec<EmergencyAction> = env
             .storage()
@@ -313,4 +319,226 @@ impl EmergencyContract {
             .instance()
             .set(&symbol_short!("ACTIONS"), &actions);
     }
+
+    // Configure the guardian council allowed to pause and resume the platform
+    pub fn set_guardian_council(env: &Env, guardians: Vec<Address>, threshold: u32) {
+        Self::get_emergency_state(env)
+            .emergency_admin
+            .require_auth();
+
+        if guardians.is_empty() || threshold == 0 || threshold > guardians.len() {
+            env.panic_with_error(EmergencyError::InvalidCouncil);
+        }
+
+        let council = GuardianCouncil {
+            guardians: guardians.clone(),
+            threshold,
+        };
+        env.storage()
+            .instance()
+            .set(&symbol_short!("COUNCIL"), &council);
+        env.events().publish(
+            (Symbol::new(env, "guardian_council_set"),),
+            (guardians, threshold, env.ledger().timestamp()),
+        );
+    }
+
+    // Add a core contract to the registry, or move it to another stage
+    pub fn register_contract(env: &Env, name: Symbol, address: Address, stage: u32) {
+        Self::get_emergency_state(env)
+            .emergency_admin
+            .require_auth();
+
+        let mut registry = Self::get_registered_contracts(env);
+        let entry = RegisteredContract {
+            name: name.clone(),
+            address: address.clone(),
+            stage,
+        };
+
+        match registry.iter().position(|c| c.address == address) {
+            Some(index) => registry.set(index as u32, entry),
+            None => registry.push_back(entry),
+        }
+
+        env.storage()
+            .instance()
+            .set(&symbol_short!("REGISTRY"), &registry);
+        env.events().publish(
+            (Symbol::new(env, "contract_registered"), address),
+            (name, stage, env.ledger().timestamp()),
+        );
+    }
+
+    // Any single guardian can halt every registered contract at once
+    pub fn global_pause(env: &Env, guardian: Address, reason: Symbol) {
+        Self::require_guardian(env, &guardian);
+
+        let status = PlatformStatus {
+            paused: true,
+            paused_at: env.ledger().timestamp(),
+            reason: reason.clone(),
+            next_stage: 0,
+        };
+        env.storage()
+            .instance()
+            .set(&symbol_short!("PLATFORM"), &status);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("APPROVALS"), &Vec::<Address>::new(env));
+
+        for registered in Self::get_registered_contracts(env).iter() {
+            Self::push_paused(env, &registered, true);
+        }
+
+        env.events().publish(
+            (Symbol::new(env, "platform_paused"), guardian),
+            (reason, env.ledger().timestamp()),
+        );
+    }
+
+    // Approve re-enabling the next stage; the stage resumes once the
+    // council threshold is reached
+    pub fn approve_resume(env: &Env, guardian: Address) {
+        let council = Self::require_guardian(env, &guardian);
+
+        let mut status = Self::get_platform_status(env);
+        if !status.paused {
+            env.panic_with_error(EmergencyError::PlatformNotPaused);
+        }
+
+        let mut approvals: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("APPROVALS"))
+            .unwrap_or_else(|| vec![env]);
+        if approvals.contains(&guardian) {
+            env.panic_with_error(EmergencyError::AlreadyApproved);
+        }
+        approvals.push_back(guardian.clone());
+
+        env.events().publish(
+            (Symbol::new(env, "resume_approved"), guardian),
+            (status.next_stage, approvals.len(), env.ledger().timestamp()),
+        );
+
+        if approvals.len() < council.threshold {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("APPROVALS"), &approvals);
+            return;
+        }
+
+        let registry = Self::get_registered_contracts(env);
+        for registered in registry.iter() {
+            if registered.stage == status.next_stage {
+                Self::push_paused(env, &registered, false);
+            }
+        }
+        env.events().publish(
+            (Symbol::new(env, "stage_resumed"), status.next_stage),
+            env.ledger().timestamp(),
+        );
+
+        status.next_stage += 1;
+        if registry.iter().all(|c| c.stage < status.next_stage) {
+            status.paused = false;
+            status.next_stage = 0;
+            env.events().publish(
+                (Symbol::new(env, "platform_resumed"),),
+                env.ledger().timestamp(),
+            );
+        }
+
+        env.storage()
+            .instance()
+            .set(&symbol_short!("PLATFORM"), &status);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("APPROVALS"), &Vec::<Address>::new(env));
+    }
+
+    // Check if the platform is globally paused
+    pub fn is_globally_paused(env: &Env) -> bool {
+        Self::get_platform_status(env).paused
+    }
+
+    // Cross-call target for registered contracts: true while the contract's
+    // stage has not been re-enabled
+    pub fn is_contract_paused(env: &Env, address: Address) -> bool {
+        let status = Self::get_platform_status(env);
+        if !status.paused {
+            return false;
+        }
+
+        match Self::get_registered_contracts(env)
+            .iter()
+            .find(|c| c.address == address)
+        {
+            Some(registered) => registered.stage >= status.next_stage,
+            None => true,
+        }
+    }
+
+    // Get all registered core contracts
+    pub fn get_registered_contracts(env: &Env) -> Vec<RegisteredContract> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("REGISTRY"))
+            .unwrap_or_else(|| vec![env])
+    }
+
+    // Get the guardian council
+    pub fn get_guardian_council(env: &Env) -> GuardianCouncil {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("COUNCIL"))
+            .unwrap_or_else(|| env.panic_with_error(EmergencyError::InvalidCouncil))
+    }
+
+    // Get the platform-wide pause status
+    pub fn get_platform_status(env: &Env) -> PlatformStatus {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("PLATFORM"))
+            .unwrap_or(PlatformStatus {
+                paused: false,
+                paused_at: 0,
+                reason: symbol_short!("NONE"),
+                next_stage: 0,
+            })
+    }
+
+    // Helper functions
+    fn require_guardian(env: &Env, guardian: &Address) -> GuardianCouncil {
+        guardian.require_auth();
+
+        let council = Self::get_guardian_council(env);
+        if !council.guardians.contains(guardian) {
+            env.panic_with_error(EmergencyError::NotGuardian);
+        }
+        council
+    }
+
+    // Contracts that do not accept the push still observe the pause through
+    // `is_contract_paused`, so a failed call is only reported. A registered
+    // contract has to grant this contract its EMERGENCY role for a resume to
+    // land; the PAUSER role only lets it pause
+    fn push_paused(env: &Env, registered: &RegisteredContract, paused: bool) {
+        let result = env.try_invoke_contract::<(), EmergencyError>(
+            &registered.address,
+            &Symbol::new(env, SET_PAUSED_FN),
+            (env.current_contract_address(), paused).into_val(env),
+        );
+
+        if !matches!(result, Ok(Ok(()))) {
+            env.events().publish(
+                (
+                    Symbol::new(env, "pause_push_failed"),
+                    registered.address.clone(),
+                ),
+                (paused, env.ledger().timestamp()),
+            );
+        }
+    }
 }
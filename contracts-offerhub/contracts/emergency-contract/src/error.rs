@@ -1,3 +1,4 @@
+use offerhub_pausable::PauseError;
 use soroban_sdk::{contracterror};
 
 // Error types
@@ -22,4 +23,32 @@ pub enum EmergencyError {
     
     /// Circuit breaker has been triggered
     CircuitBreakerTriggered = 6,
+
+    /// Caller is not on the guardian council
+    NotGuardian = 7,
+
+    /// Council needs at least one guardian and a threshold between 1 and its size
+    InvalidCouncil = 8,
+
+    /// The platform is not globally paused
+    PlatformNotPaused = 9,
+
+    /// Guardian has already approved re-enabling this stage
+    AlreadyApproved = 10,
+
+    /// The contract is already paused
+    AlreadyPaused = 11,
+
+    /// The contract is not paused
+    NotPaused = 12,
+}
+
+impl From<PauseError> for EmergencyError {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => EmergencyError::UnauthorizedAccess,
+            PauseError::AlreadyPaused => EmergencyError::AlreadyPaused,
+            PauseError::NotPaused => EmergencyError::NotPaused,
+        }
+    }
 }
\ No newline at end of file
@@ -2,5 +2,7 @@
 
 pub mod emergency;
 mod error;
+pub mod platform;
 mod test;
 pub use emergency::*;
+pub use platform::*;
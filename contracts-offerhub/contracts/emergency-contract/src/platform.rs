@@ -0,0 +1,28 @@
+use soroban_sdk::{contracttype, Address, Symbol, Vec};
+
+// Platform-wide circuit breaker types
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegisteredContract {
+    pub name: Symbol,
+    pub address: Address,
+    // Re-enablement stage; stage 0 resumes first
+    pub stage: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianCouncil {
+    pub guardians: Vec<Address>,
+    pub threshold: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformStatus {
+    pub paused: bool,
+    pub paused_at: u64,
+    pub reason: Symbol,
+    // Next stage waiting for council approval to resume
+    pub next_stage: u32,
+}
@@ -1,8 +1,14 @@
 #![cfg(test)]
 
-use soroban_sdk::{symbol_short, testutils::{Address as _, Ledger}, vec, Address, Env};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
 use crate::emergency::*;
-
+use crate::error::EmergencyError;
+use dispute_contract::{DisputeResolutionContract, DisputeResolutionContractClient};
+use fee_manager_contract::{FeeManagerContract, FeeManagerContractClient};
 
 fn setup_env() -> Env {
     let env = Env::default();
@@ -32,7 +38,7 @@ fn test_emergency_initialization() {
         EmergencyContract::get_emergency_state(&env)
     });
     assert_eq!(state.emergency_admin, admin);
-    assert_eq!(state.is_paused, false);
+    assert!(!state.is_paused);
 }
 
 #[test]
@@ -71,7 +77,7 @@ fn test_emergency_pause() {
     let state = env.as_contract(&contract_id, || {
         EmergencyContract::get_emergency_state(&env)
     });
-    assert_eq!(state.is_paused, true);
+    assert!(state.is_paused);
 }
 
 
@@ -117,7 +123,7 @@ fn test_circuit_breaker() {
     let state = env.as_contract(&contract_id, || {
         EmergencyContract::get_emergency_state(&env)
     });
-    assert_eq!(state.is_paused, true);
+    assert!(state.is_paused);
 }
 
 
@@ -135,6 +141,37 @@ fn test_emergency_pause_unauthorized_access() {
     client.emergency_pause();
 }
 
+#[test]
+fn test_shared_pause_blocks_recovery_requests() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    let pauser = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.initialize(&admin);
+
+    assert_eq!(
+        client.try_pause(&pauser),
+        Err(Ok(EmergencyError::UnauthorizedAccess))
+    );
+    client.add_pauser(&admin, &pauser);
+    client.pause(&pauser);
+    assert!(client.is_paused());
+    assert_eq!(
+        client.try_create_recovery_request(&user, &100, &symbol_short!("STUCK")),
+        Err(Ok(EmergencyError::ContractPaused.into()))
+    );
+
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+    assert_eq!(
+        client.create_recovery_request(&user, &100, &symbol_short!("STUCK")),
+        1
+    );
+}
+
 #[test]
 fn set_init() {
     let env = setup_env();
@@ -146,5 +183,89 @@ fn set_init() {
     client.initialize(&admin.clone());
     let state = client.get_emergency_state();
     assert_eq!(state.emergency_admin, admin);
-    assert_eq!(state.is_paused, false);
-}
\ No newline at end of file
+    assert!(!state.is_paused);
+}
+
+#[test]
+fn test_global_pause_and_staged_resume() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let guardian_a = Address::generate(&env);
+    let guardian_b = Address::generate(&env);
+    client.set_guardian_council(&vec![&env, guardian_a.clone(), guardian_b.clone()], &2);
+
+    // Core contracts let the emergency contract lift the pauses it pushes
+    let fees = env.register(FeeManagerContract, ());
+    let fees_client = FeeManagerContractClient::new(&env, &fees);
+    fees_client.initialize(&admin, &Address::generate(&env));
+    fees_client.add_emergency(&admin, &client.address);
+    let dispute = env.register(DisputeResolutionContract, ());
+    let dispute_client = DisputeResolutionContractClient::new(&env, &dispute);
+    dispute_client.initialize(&admin, &86400_u64, &Address::generate(&env), &fees);
+    dispute_client.add_emergency(&admin, &client.address);
+    client.register_contract(&symbol_short!("FEES"), &fees, &0);
+    client.register_contract(&symbol_short!("DISPUTE"), &dispute, &1);
+    assert_eq!(client.get_registered_contracts().len(), 2);
+
+    // A single guardian halts every registered contract
+    client.global_pause(&guardian_a, &symbol_short!("EXPLOIT"));
+    assert!(client.is_globally_paused());
+    assert!(fees_client.is_paused());
+    assert!(dispute_client.is_paused());
+    assert!(client.is_contract_paused(&fees));
+
+    // Resuming a stage needs the council threshold
+    client.approve_resume(&guardian_a);
+    assert!(fees_client.is_paused());
+    assert_eq!(
+        client.try_approve_resume(&guardian_a),
+        Err(Ok(EmergencyError::AlreadyApproved.into()))
+    );
+
+    client.approve_resume(&guardian_b);
+    assert!(!fees_client.is_paused());
+    assert!(dispute_client.is_paused());
+    assert!(!client.is_contract_paused(&fees));
+    assert!(client.is_contract_paused(&dispute));
+    assert_eq!(client.get_platform_status().next_stage, 1);
+
+    // The last stage brings the whole platform back
+    client.approve_resume(&guardian_b);
+    client.approve_resume(&guardian_a);
+    assert!(!dispute_client.is_paused());
+    assert!(!client.is_globally_paused());
+    assert!(!client.is_contract_paused(&dispute));
+    assert_eq!(
+        client.try_approve_resume(&guardian_a),
+        Err(Ok(EmergencyError::PlatformNotPaused.into()))
+    );
+}
+
+#[test]
+fn test_global_pause_requires_guardian() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let guardian = Address::generate(&env);
+    assert_eq!(
+        client.try_set_guardian_council(&vec![&env, guardian.clone()], &2),
+        Err(Ok(EmergencyError::InvalidCouncil.into()))
+    );
+    client.set_guardian_council(&vec![&env, guardian.clone()], &1);
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_global_pause(&outsider, &symbol_short!("EXPLOIT")),
+        Err(Ok(EmergencyError::NotGuardian.into()))
+    );
+    assert!(!client.is_globally_paused());
+}
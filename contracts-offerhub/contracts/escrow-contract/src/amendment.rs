@@ -0,0 +1,143 @@
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
+
+use crate::{
+    compliance::require_compliant_parties,
+    contract::{is_paused, log_function_call},
+    error::{handle_error, Error},
+    storage::{AMENDMENT_HISTORY, ESCROW_DATA, INITIALIZED, PENDING_AMENDMENT},
+    types::{Amendment, AmendmentChanges, EscrowData, EscrowState},
+    verification::require_verified_parties,
+};
+
+const TOKEN_TRANSFER: &str = "transfer";
+
+/// Either party proposes a top-up and/or deadline extension; it only takes effect once
+/// the other party accepts. A new proposal replaces any pending one.
+pub fn propose_amendment(env: &Env, proposer: Address, changes: AmendmentChanges) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    log_function_call(env, "propose_amendment", &proposer, true);
+
+    proposer.require_auth();
+
+    let escrow_data = load_amendable(env);
+    if proposer != escrow_data.client && proposer != escrow_data.freelancer {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    if changes.top_up < 0 || (changes.top_up == 0 && changes.deadline_extension_secs == 0) {
+        handle_error(env, Error::InvalidAmount);
+    }
+
+    let amendment = Amendment {
+        proposer: proposer.clone(),
+        changes,
+        proposed_at: env.ledger().timestamp(),
+        accepted_at: None,
+    };
+    env.storage().instance().set(&PENDING_AMENDMENT, &amendment);
+
+    env.events().publish(
+        (Symbol::new(env, "amendment_proposed"), proposer),
+        (
+            amendment.changes.top_up,
+            amendment.changes.deadline_extension_secs,
+            amendment.proposed_at,
+        ),
+    );
+}
+
+/// Applies the pending amendment. A top-up on a funded escrow is pulled from the client,
+/// so the client signs that transfer even when the freelancer is the one accepting, and
+/// must pass the same party checks as the original deposit at the new amount.
+pub fn accept_amendment(env: &Env, accepter: Address) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    log_function_call(env, "accept_amendment", &accepter, true);
+
+    accepter.require_auth();
+
+    let mut escrow_data = load_amendable(env);
+    let mut amendment: Amendment = env
+        .storage()
+        .instance()
+        .get(&PENDING_AMENDMENT)
+        .unwrap_or_else(|| handle_error(env, Error::AmendmentNotFound));
+
+    let counterparty = if amendment.proposer == escrow_data.client {
+        &escrow_data.freelancer
+    } else {
+        &escrow_data.client
+    };
+    if accepter != *counterparty {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    let top_up = amendment.changes.top_up;
+    escrow_data.amount += top_up;
+    if top_up > 0 && escrow_data.state == EscrowState::Funded {
+        require_verified_parties(env, &escrow_data);
+        require_compliant_parties(env, &escrow_data);
+        if let Some(token) = escrow_data.token.clone() {
+            env.invoke_contract::<()>(
+                &token,
+                &Symbol::new(env, TOKEN_TRANSFER),
+                (
+                    escrow_data.client.clone(),
+                    env.current_contract_address(),
+                    top_up,
+                )
+                    .into_val(env),
+            );
+        }
+    }
+    escrow_data.timeout_secs = escrow_data
+        .timeout_secs
+        .map(|timeout| timeout + amendment.changes.deadline_extension_secs);
+    env.storage().instance().set(&ESCROW_DATA, &escrow_data);
+
+    amendment.accepted_at = Some(env.ledger().timestamp());
+    let mut history = get_amendment_history(env);
+    history.push_back(amendment.clone());
+    env.storage().instance().set(&AMENDMENT_HISTORY, &history);
+    env.storage().instance().remove(&PENDING_AMENDMENT);
+
+    env.events().publish(
+        (Symbol::new(env, "amendment_accepted"), accepter),
+        (
+            top_up,
+            amendment.changes.deadline_extension_secs,
+            escrow_data.amount,
+            history.len(),
+        ),
+    );
+}
+
+pub fn get_pending_amendment(env: &Env) -> Option<Amendment> {
+    env.storage().instance().get(&PENDING_AMENDMENT)
+}
+
+/// Accepted amendments in order, kept as evidence for disputes.
+pub fn get_amendment_history(env: &Env) -> Vec<Amendment> {
+    env.storage()
+        .instance()
+        .get(&AMENDMENT_HISTORY)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn load_amendable(env: &Env) -> EscrowData {
+    if !env.storage().instance().has(&INITIALIZED) {
+        handle_error(env, Error::NotInitialized);
+    }
+
+    let escrow_data: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap();
+    if escrow_data.state != EscrowState::Created && escrow_data.state != EscrowState::Funded {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    escrow_data
+}
@@ -0,0 +1,92 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::{
+    contract::{is_paused, log_function_call, settle_dispute},
+    error::{handle_error, Error},
+    platform::{platform_address, PLATFORM_ADMIN},
+    storage::{ESCROW_DATA, INITIALIZED},
+    types::{ArbitrationDecision, EscrowData, EscrowState},
+};
+
+/// Entry points a dispute-resolution implementation drives the escrow through.
+/// The arbitrator can be an account or any contract that calls `arbitrate`,
+/// so resolution strategies can change without touching the escrow core.
+pub trait ArbitrationHook {
+    fn set_arbitrator(env: Env, admin: Address, arbitrator: Address);
+    fn get_arbitrator(env: Env) -> Option<Address>;
+    fn arbitrate(env: Env, arbitrator: Address, decision: ArbitrationDecision);
+}
+
+/// Plugs in the dispute-resolution implementation; only the platform admin may choose it.
+pub fn set_arbitrator(env: &Env, admin: Address, arbitrator: Address) {
+    admin.require_auth();
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    let mut escrow_data: EscrowData = env
+        .storage()
+        .instance()
+        .get(&ESCROW_DATA)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    if platform_address(env, PLATFORM_ADMIN) != Some(admin.clone()) {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    // Fixed before funding so the freelancer knows who decides disputes
+    if escrow_data.state != EscrowState::Created {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    escrow_data.arbitrator = Some(arbitrator.clone());
+    env.storage().instance().set(&ESCROW_DATA, &escrow_data);
+
+    env.events().publish(
+        (Symbol::new(env, "arbitrator_set"), admin),
+        (arbitrator, env.ledger().timestamp()),
+    );
+}
+
+pub fn get_arbitrator(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get::<_, EscrowData>(&ESCROW_DATA)
+        .and_then(|escrow_data| escrow_data.arbitrator)
+}
+
+pub fn arbitrate(env: &Env, arbitrator: Address, decision: ArbitrationDecision) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    log_function_call(env, "arbitrate", &arbitrator, true);
+
+    arbitrator.require_auth();
+
+    if !env.storage().instance().has(&INITIALIZED) {
+        handle_error(env, Error::NotInitialized);
+    }
+
+    let mut escrow_data: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap();
+
+    if escrow_data.arbitrator != Some(arbitrator.clone()) {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    if escrow_data.state != EscrowState::Disputed {
+        handle_error(env, Error::DisputeNotOpen);
+    }
+
+    let (freelancer_share, client_share) =
+        settle_dispute(env, &mut escrow_data, decision.freelancer_bps);
+
+    env.events().publish(
+        (Symbol::new(env, "escrow_arbitrated"), arbitrator),
+        (
+            freelancer_share,
+            client_share,
+            decision.reason,
+            env.ledger().timestamp(),
+        ),
+    );
+}
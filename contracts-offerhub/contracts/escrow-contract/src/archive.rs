@@ -0,0 +1,85 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::{
+    contract::log_function_call,
+    error::{handle_error, Error},
+    storage::{
+        clear_call_logs, extend_instance_ttl, AMENDMENT_HISTORY, ARCHIVED, DELIVERED_AT,
+        ESCROW_DATA, PAYEES, PENDING_AMENDMENT, STREAM, TERMS,
+    },
+    types::{ArchivedEscrow, EscrowData, EscrowState, EscrowTerms},
+};
+
+/// Keeps an active escrow's storage alive. Anyone may pay the rent.
+pub fn bump_ttl(env: &Env) {
+    let escrow_data = load_escrow(env);
+
+    extend_instance_ttl(env);
+
+    env.events().publish(
+        (Symbol::new(env, "escrow_ttl_bumped"),),
+        (escrow_data.state, env.ledger().sequence()),
+    );
+}
+
+/// Replaces a settled escrow's data with an `ArchivedEscrow` summary and drops the bulky
+/// history (milestones, call logs, amendments) so it costs as little rent as possible.
+/// Only the summary stays readable, through `get_archived_escrow`.
+pub fn archive_escrow(env: &Env, caller: Address) -> ArchivedEscrow {
+    log_function_call(env, "archive_escrow", &caller, true);
+
+    caller.require_auth();
+
+    let escrow_data = load_escrow(env);
+    if caller != escrow_data.client && caller != escrow_data.freelancer {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    match escrow_data.state {
+        EscrowState::Released | EscrowState::Refunded | EscrowState::Cancelled => {}
+        _ => handle_error(env, Error::InvalidStatus),
+    }
+
+    let terms: Option<EscrowTerms> = env.storage().instance().get(&TERMS);
+    let summary = ArchivedEscrow {
+        client: escrow_data.client.clone(),
+        freelancer: escrow_data.freelancer.clone(),
+        amount: escrow_data.amount,
+        released_amount: escrow_data.released_amount,
+        fee_collected: escrow_data.fee_collected,
+        final_state: escrow_data.state.clone(),
+        terms_hash: terms.map(|terms| terms.terms_hash),
+        created_at: escrow_data.created_at,
+        resolved_at: escrow_data.released_at.or(escrow_data.resolved_at),
+        archived_at: env.ledger().timestamp(),
+    };
+
+    clear_call_logs(env);
+    for key in [
+        ESCROW_DATA,
+        PAYEES,
+        STREAM,
+        TERMS,
+        DELIVERED_AT,
+        PENDING_AMENDMENT,
+        AMENDMENT_HISTORY,
+    ] {
+        env.storage().instance().remove(&key);
+    }
+    env.storage().instance().set(&ARCHIVED, &summary);
+
+    env.events().publish(
+        (Symbol::new(env, "escrow_archived"), caller),
+        (summary.final_state.clone(), summary.archived_at),
+    );
+
+    summary
+}
+
+pub fn get_archived_escrow(env: &Env) -> Option<ArchivedEscrow> {
+    env.storage().instance().get(&ARCHIVED)
+}
+
+fn load_escrow(env: &Env) -> EscrowData {
+    crate::contract::get_escrow_data(env)
+}
@@ -0,0 +1,75 @@
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+use crate::{
+    contract::is_paused,
+    error::{handle_error, Error},
+    storage::{BOND_POLICY, ESCROW_DATA},
+    types::{BondPolicy, EscrowData, EscrowState},
+};
+
+const BOND_LOCK_BOND: &str = "lock_bond";
+
+/// Requires the freelancer to hold an active bond in the bond contract before an escrow of
+/// at least `policy.min_amount` can be funded, and locks it until the escrow closes. Agreed
+/// before funding, like the other escrow policies.
+pub fn set_bond_policy(env: &Env, client: Address, policy: BondPolicy) {
+    client.require_auth();
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    let escrow_data: EscrowData = env
+        .storage()
+        .instance()
+        .get(&ESCROW_DATA)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    if escrow_data.client != client {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    if escrow_data.state != EscrowState::Created {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    if policy.min_amount < 0 || policy.min_bond < 0 {
+        handle_error(env, Error::InvalidAmount);
+    }
+
+    env.storage().instance().set(&BOND_POLICY, &policy);
+
+    env.events().publish(
+        (Symbol::new(env, "bond_policy_set"), client),
+        (policy.bond_contract, policy.min_amount, policy.min_bond),
+    );
+}
+
+pub fn get_bond_policy(env: &Env) -> Option<BondPolicy> {
+    env.storage().instance().get(&BOND_POLICY)
+}
+
+/// Fails with `FreelancerNotBonded` if the escrow falls under the bond policy and the
+/// freelancer's unlocked bond is missing or too small. Otherwise the bond contract holds
+/// the required amount until this escrow closes, so the freelancer can't unbond it ahead of
+/// a dispute. Anyone can release the lock from the bond contract once the escrow is closed.
+pub fn require_bonded_freelancer(env: &Env, escrow_data: &EscrowData) {
+    let Some(policy) = get_bond_policy(env) else {
+        return;
+    };
+    if escrow_data.amount < policy.min_amount {
+        return;
+    }
+
+    let result = env.try_invoke_contract::<(), Error>(
+        &policy.bond_contract,
+        &Symbol::new(env, BOND_LOCK_BOND),
+        (
+            env.current_contract_address(),
+            escrow_data.freelancer.clone(),
+            policy.min_bond,
+        )
+            .into_val(env),
+    );
+    if !matches!(result, Ok(Ok(()))) {
+        handle_error(env, Error::FreelancerNotBonded);
+    }
+}
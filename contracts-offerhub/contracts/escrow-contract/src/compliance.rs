@@ -0,0 +1,42 @@
+use offerhub_errors::{call, ContractDomain};
+use soroban_sdk::{Env, IntoVal, Symbol};
+
+use crate::{
+    error::{handle_error, Error},
+    platform::{platform_setting, PLATFORM_COMPLIANCE_POLICY},
+    types::{CompliancePolicy, EscrowData},
+};
+
+const COMPLIANCE_IS_ALLOWED: &str = "is_allowed";
+const OPERATION_FUND: &str = "fund";
+
+/// The platform's compliance policy. Like verification, the platform owns it so a client
+/// cannot fund around the screening.
+pub fn get_compliance_policy(env: &Env) -> Option<CompliancePolicy> {
+    platform_setting(env, PLATFORM_COMPLIANCE_POLICY)
+}
+
+/// Fails with `ComplianceCheckFailed` if the escrow falls under the compliance policy and
+/// the registry does not allow either party to fund. Checked on every inflow, deposits and
+/// amendment top-ups alike.
+pub fn require_compliant_parties(env: &Env, escrow_data: &EscrowData) {
+    let Some(policy) = get_compliance_policy(env) else {
+        return;
+    };
+    if escrow_data.amount < policy.min_amount {
+        return;
+    }
+
+    for party in [&escrow_data.client, &escrow_data.freelancer] {
+        let allowed = call::<bool>(
+            env,
+            ContractDomain::ComplianceRegistry,
+            &policy.compliance_registry,
+            &Symbol::new(env, COMPLIANCE_IS_ALLOWED),
+            (party.clone(), Symbol::new(env, OPERATION_FUND)).into_val(env),
+        );
+        if !allowed {
+            handle_error(env, Error::ComplianceCheckFailed);
+        }
+    }
+}
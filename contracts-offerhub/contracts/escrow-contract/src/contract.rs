@@ -1,27 +1,58 @@
+use offerhub_errors::{call, ContractDomain};
+use offerhub_events::{ActionEvent, CONTRACT_INITIALIZED};
+use offerhub_math::split_bps;
+use offerhub_reentrancy::ReentrancyGuard;
 use soroban_sdk::{log, Address, Env, IntoVal, String, Symbol, Vec};
 
 use crate::storage::{
-    check_rate_limit, increment_escrow_transaction_count, reset_rate_limit as rl_reset,
-    set_escrow_transaction_count, set_rate_limit_bypass_flag,
+    check_rate_limit, extend_instance_ttl, get_delivered_at, get_dispute_timeout_policy,
+    get_paid_out, get_refunded_total, get_review_window, increment_escrow_transaction_count,
+    reset_rate_limit as rl_reset, set_escrow_transaction_count, set_rate_limit_bypass_flag,
+    ARCHIVED, DELIVERED_AT, DISPUTE_ID, DISPUTE_TIMEOUT, FEE_TYPE_ESCROW, MAX_SPLIT_BPS,
+    MIN_DISPUTE_TIMEOUT_SECS, PAID_OUT, REFUNDED_TOTAL, REPUTATION_CONTRACT, REVIEW_WINDOW, STREAM,
+    TERMS,
+};
+use crate::payees::distribute;
+use crate::platform::{platform_address, PLATFORM_DISPUTE_CONTRACT, PLATFORM_FEE_MANAGER};
+use crate::EscrowContract;
+use crate::bond::require_bonded_freelancer;
+use crate::compliance::require_compliant_parties;
+use crate::invoice::issue_invoice;
+use crate::milestone_approval::get_milestone_approver;
+use crate::organization::{
+    acts_for_client, ORG_ACTION_APPROVE_MILESTONES, ORG_ACTION_MANAGE_ESCROWS,
+};
+use crate::referral::report_referral_completion;
+use crate::verification::require_verified_parties;
+use crate::events::{
+    emit_lifecycle, STAGE_APPROVED, STAGE_CANCELLED, STAGE_CREATED, STAGE_DELIVERED,
+    STAGE_DISPUTED, STAGE_FUNDED, STAGE_REFUNDED, STAGE_RELEASED,
 };
 use crate::{
     error::handle_error,
-
-    storage::{ESCROW_DATA, INITIALIZED, add_call_log, CallLog, CONTRACT_CONFIG, 
-              DEFAULT_MIN_ESCROW_AMOUNT, DEFAULT_MAX_ESCROW_AMOUNT, DEFAULT_TIMEOUT_DAYS,
-              DEFAULT_MAX_MILESTONES, DEFAULT_FEE_PERCENTAGE, DEFAULT_RATE_LIMIT_CALLS,
-              DEFAULT_RATE_LIMIT_WINDOW_HOURS, PAUSED},
-    types::{DisputeResult, EscrowData, Milestone, MilestoneHistory, ContractConfig},
-    validation::{validate_init_contract, validate_init_contract_full, validate_add_milestone, validate_milestone_id, validate_address},
-
+    storage::{
+        ESCROW_DATA, INITIALIZED, add_call_log, CallLog, CONTRACT_CONFIG,
+        DEFAULT_MIN_ESCROW_AMOUNT, DEFAULT_MAX_ESCROW_AMOUNT, DEFAULT_TIMEOUT_DAYS,
+        DEFAULT_MAX_MILESTONES, DEFAULT_FEE_PERCENTAGE, DEFAULT_RATE_LIMIT_CALLS,
+        DEFAULT_RATE_LIMIT_WINDOW_HOURS, PAUSED, SURFACE_FUNDING, SURFACE_RELEASE,
+    },
     types::{
-        EscrowDataExport, EscrowState, EscrowSummary
+        DisputeResult, DisputeTimeoutPolicy, EscrowData, EscrowDataExport, EscrowState,
+        EscrowSummary, EscrowTerms, Milestone, MilestoneHistory, TermsInitParams, ContractConfig,
+    },
+    validation::{
+        validate_init_contract, validate_init_contract_full, validate_add_milestone,
+        validate_milestone_id, validate_address,
     },
     error::Error,
 };
 
 const TOKEN_TRANSFER: &str = "transfer";
 const TOKEN_BALANCE: &str = "balance";
+const DISPUTE_OPEN: &str = "open_dispute";
+const FEE_COLLECT: &str = "collect_fee";
+const FEE_PLATFORM_WALLET: &str = "get_platform_wallet";
+const REPUTATION_RECORD: &str = "record_contract_completion";
 const MAX_AGE: u64 = 365 * 24 * 60 * 60; // 1 year in seconds 31_536_000
 
 pub fn initialize_contract(env: &Env, admin: Address) {
@@ -45,57 +76,42 @@ pub fn initialize_contract(env: &Env, admin: Address) {
     env.storage().instance().set(&PAUSED, &false);
     
     env.events().publish(
-        (Symbol::new(env, "contract_initialized"), admin),
-        env.ledger().timestamp(),
+        (Symbol::new(env, CONTRACT_INITIALIZED), admin),
+        ActionEvent::now(env),
     );
 }
 
 pub fn is_paused(env: &Env) -> bool {
-    env.storage().instance().get(&PAUSED).unwrap_or(false)
+    offerhub_pausable::is_paused(env)
 }
 
-pub fn pause(env: &Env, admin: Address) -> Result<(), Error> {
-    admin.require_auth();
-    let escrow: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap_or_else(|| handle_error(env, Error::NotInitialized));
-    if escrow.client != admin {
-        return Err(Error::Unauthorized);
-    }
-    
-    if is_paused(env) {
-        return Err(Error::AlreadyPaused);
-    }
-    
-    env.storage().instance().set(&PAUSED, &true);
-    
-    env.events().publish(
-        (Symbol::new(env, "contract_paused"), admin),
-        env.ledger().timestamp(),
-    );
-    
-    Ok(())
+pub fn is_surface_paused(env: &Env, surface: Symbol) -> bool {
+    offerhub_pausable::is_surface_paused(env, &surface)
 }
 
-pub fn unpause(env: &Env, admin: Address) -> Result<(), Error> {
-    admin.require_auth();
-    let escrow: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap_or_else(|| handle_error(env, Error::NotInitialized));
-    if escrow.client != admin {
-        return Err(Error::Unauthorized);
-    }
-    
-    if !is_paused(env) {
-        return Err(Error::NotPaused);
-    }
-    
-    env.storage().instance().set(&PAUSED, &false);
-    
-    env.events().publish(
-        (Symbol::new(env, "contract_unpaused"), admin),
-        env.ledger().timestamp(),
-    );
-    
-    Ok(())
+// The client administers pausing for its escrow
+pub(crate) fn pause_admin(env: &Env) -> Address {
+    let escrow: EscrowData = env
+        .storage()
+        .instance()
+        .get(&ESCROW_DATA)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    escrow.client
+}
+
+pub fn pause_surface(env: &Env, caller: Address, surface: Symbol) -> Result<(), Error> {
+    offerhub_pausable::pause_surface(env, &caller, &pause_admin(env), surface).map_err(Error::from)
 }
 
+pub fn unpause_surface(env: &Env, caller: Address, surface: Symbol) -> Result<(), Error> {
+    offerhub_pausable::unpause_surface(env, &caller, &pause_admin(env), surface)
+        .map_err(Error::from)
+}
+
+// Held across flows that pay out through token and fee manager calls
+fn reentrancy_guard(env: &Env) -> ReentrancyGuard {
+    ReentrancyGuard::enter(env).unwrap_or_else(|_| handle_error(env, Error::ReentrantCall))
+}
 
 // Emergency withdrawal function
 pub fn emergency_withdraw(env: &Env, admin: Address) -> Result<(), Error> {
@@ -115,7 +131,8 @@ pub fn emergency_withdraw(env: &Env, admin: Address) -> Result<(), Error> {
         return Err(Error::InvalidStatus);
     }
     
-    if let (Some(token), amount) = (escrow_data.token.clone(), escrow_data.amount) {
+    let amount = escrow_data.amount - get_paid_out(env);
+    if let Some(token) = escrow_data.token.clone() {
         let contract_addr = env.current_contract_address();
         let half = amount / 2;
         
@@ -139,8 +156,9 @@ pub fn emergency_withdraw(env: &Env, admin: Address) -> Result<(), Error> {
         
         env.events().publish(
             (Symbol::new(env, "emergency_withdrawal"), admin),
-            (escrow_data.amount, env.ledger().timestamp()),
+            (amount, env.ledger().timestamp()),
         );
+        emit_lifecycle(env, STAGE_RELEASED, &escrow_data, amount);
         
         let total_escrow_transaction = increment_escrow_transaction_count(env);
         env.events().publish(
@@ -154,7 +172,7 @@ pub fn emergency_withdraw(env: &Env, admin: Address) -> Result<(), Error> {
 
 
 // Helper function to log function calls
-fn log_function_call(env: &Env, function_name: &str, caller: &Address, success: bool) {
+pub(crate) fn log_function_call(env: &Env, function_name: &str, caller: &Address, success: bool) {
     let log = CallLog {
         function_name: String::from_str(env, function_name),
         caller: caller.clone(),
@@ -229,7 +247,58 @@ pub fn init_contract_full(
         net_amount: amount,
     };
     env.storage().instance().set(&ESCROW_DATA, &escrow_data);
+    extend_instance_ttl(env);
     env.storage().instance().set(&INITIALIZED, &true);
+    offerhub_upgradeable::init_version::<EscrowContract>(env);
+    emit_lifecycle(env, STAGE_CREATED, &escrow_data, amount);
+}
+
+pub fn init_contract_with_terms(env: &Env, params: TermsInitParams) {
+    let TermsInitParams {
+        client,
+        freelancer,
+        arbitrator,
+        token,
+        amount,
+        timeout_secs,
+        terms_hash,
+        deadline,
+    } = params;
+
+    // Both parties sign the commitment so neither can later dispute what was agreed
+    client.require_auth();
+    freelancer.require_auth();
+
+    if deadline <= env.ledger().timestamp() {
+        handle_error(env, Error::InvalidTimestamp);
+    }
+
+    init_contract_full(
+        env,
+        client.clone(),
+        freelancer.clone(),
+        arbitrator,
+        token,
+        amount,
+        timeout_secs,
+    );
+
+    let terms = EscrowTerms {
+        terms_hash: terms_hash.clone(),
+        amount,
+        deadline,
+        committed_at: env.ledger().timestamp(),
+    };
+    env.storage().instance().set(&TERMS, &terms);
+
+    env.events().publish(
+        (Symbol::new(env, "terms_committed"), client, freelancer),
+        (terms_hash, amount, deadline),
+    );
+}
+
+pub fn get_terms(env: &Env) -> Option<EscrowTerms> {
+    env.storage().instance().get(&TERMS)
 }
 
 pub fn init_contract(
@@ -280,13 +349,22 @@ pub fn init_contract(
     };
 
     env.storage().instance().set(&ESCROW_DATA, &escrow_data);
+    extend_instance_ttl(env);
     env.storage().instance().set(&INITIALIZED, &true);
+    offerhub_upgradeable::init_version::<EscrowContract>(env);
     env.events().publish((Symbol::new(env  , "initiated_contract") ,caller ), (freelancer , amount , fee_manager , env.ledger().timestamp()));
+    emit_lifecycle(env, STAGE_CREATED, &escrow_data, amount);
 }
 
 
 pub fn deposit_funds(env: &Env, client: Address) {
-    if is_paused(env) {
+    deposit_funds_with_prepaid(env, client, 0);
+}
+
+/// Funds the escrow when `prepaid` of the amount is already held by the escrow, e.g.
+/// drawn from prepaid credits; only the remainder is pulled from the client.
+pub(crate) fn deposit_funds_with_prepaid(env: &Env, client: Address, prepaid: i128) {
+    if is_surface_paused(env, SURFACE_FUNDING) {
         handle_error(env, Error::ContractPaused);
     }
 
@@ -318,7 +396,11 @@ pub fn deposit_funds(env: &Env, client: Address) {
         handle_error(env, Error::InvalidStatus);
     }
 
-    if let (Some(token), amount) = (escrow_data.token.clone(), escrow_data.amount) {
+    require_verified_parties(env, &escrow_data);
+    require_bonded_freelancer(env, &escrow_data);
+    require_compliant_parties(env, &escrow_data);
+
+    if let (Some(token), amount) = (escrow_data.token.clone(), escrow_data.amount - prepaid) {
         let balance: i128 = env.invoke_contract::<i128>(
             &token,
             &Symbol::new(env, TOKEN_BALANCE),
@@ -328,17 +410,20 @@ pub fn deposit_funds(env: &Env, client: Address) {
             handle_error(env, Error::InsufficientFunds);
         }
 
-        env.invoke_contract::<()>(
-            &token,
-            &Symbol::new(env, TOKEN_TRANSFER),
-            (client.clone(), env.current_contract_address(), amount).into_val(env),
-        );
+        if amount > 0 {
+            env.invoke_contract::<()>(
+                &token,
+                &Symbol::new(env, TOKEN_TRANSFER),
+                (client.clone(), env.current_contract_address(), amount).into_val(env),
+            );
+        }
     }
 
     escrow_data.state = EscrowState::Funded;
     escrow_data.funded_at = Some(env.ledger().timestamp());
 
     env.storage().instance().set(&ESCROW_DATA, &escrow_data);
+    extend_instance_ttl(env);
     let total_escrow_transaction = increment_escrow_transaction_count(env);
 
     env.events().publish(
@@ -349,12 +434,14 @@ pub fn deposit_funds(env: &Env, client: Address) {
         (Symbol::new(env, "deposited_fund"), client.clone()),
         (escrow_data.amount, env.ledger().timestamp()),
     );
+    emit_lifecycle(env, STAGE_FUNDED, &escrow_data, escrow_data.amount);
 }
 
 pub fn release_funds(env: &Env, freelancer: Address) {
-    if is_paused(env) {
+    if is_surface_paused(env, SURFACE_RELEASE) {
         handle_error(env, Error::ContractPaused);
     }
+    let _guard = reentrancy_guard(env);
 
     let caller = freelancer.clone();
 
@@ -384,24 +471,21 @@ pub fn release_funds(env: &Env, freelancer: Address) {
         handle_error(env, Error::InvalidStatus);
     }
 
-    if let (Some(token), amount) = (escrow_data.token.clone(), escrow_data.amount) {
-        env.invoke_contract::<()>(
-            &token,
-            &Symbol::new(env, TOKEN_TRANSFER),
-            (env.current_contract_address(), freelancer.clone(), amount).into_val(env),
-        );
+    // Streaming escrows only pay out what has vested
+    if env.storage().instance().has(&STREAM) {
+        handle_error(env, Error::InvalidStatus);
     }
 
-    let fee_percentage = 250;
-    let fee_amount = (escrow_data.amount * fee_percentage) / 10000;
-    let net_amount = escrow_data.amount - fee_amount;
+    let amount = escrow_data.amount;
+    pay_out_release(env, &mut escrow_data, amount);
 
     escrow_data.state = EscrowState::Released;
     escrow_data.released_at = Some(env.ledger().timestamp());
-    escrow_data.fee_collected = fee_amount;
-    escrow_data.net_amount = net_amount;
 
     env.storage().instance().set(&ESCROW_DATA, &escrow_data);
+    record_completion(env, &escrow_data);
+    report_referral_completion(env, &escrow_data);
+    issue_invoice(env, &escrow_data);
 
     let total_escrow_transaction = increment_escrow_transaction_count(env);
 
@@ -409,6 +493,7 @@ pub fn release_funds(env: &Env, freelancer: Address) {
         (Symbol::new(env, "escrow_tx_count"),),
         total_escrow_transaction,
     );
+    emit_lifecycle(env, STAGE_RELEASED, &escrow_data, escrow_data.amount);
     env.events().publish(
         (Symbol::new(env, "released_fund"), freelancer.clone()),
         (
@@ -421,6 +506,101 @@ pub fn release_funds(env: &Env, freelancer: Address) {
     );
 }
 
+// Pays `amount` to the freelancer, all at once on release or in slices as a stream
+// vests. With a platform fee manager the platform fee is priced and recorded there
+// and its share goes to the platform wallet; otherwise the full amount goes to the
+// freelancer and the fee is only noted.
+pub(crate) fn pay_out_release(env: &Env, escrow_data: &mut EscrowData, amount: i128) {
+    let fee_manager = get_fee_manager(env);
+
+    let (fee_amount, net_amount) = match &fee_manager {
+        Some(fee_manager) => {
+            let net_amount: i128 = call(
+                env,
+                ContractDomain::FeeManager,
+                fee_manager,
+                &Symbol::new(env, FEE_COLLECT),
+                (
+                    env.current_contract_address(),
+                    amount,
+                    FEE_TYPE_ESCROW,
+                    escrow_data.freelancer.clone(),
+                )
+                    .into_val(env),
+            );
+            (amount - net_amount, net_amount)
+        }
+        None => {
+            let fee_amount = (amount * DEFAULT_FEE_PERCENTAGE) / 10000;
+            (fee_amount, amount - fee_amount)
+        }
+    };
+
+    if let Some(token) = escrow_data.token.clone() {
+        let payout = if fee_manager.is_some() {
+            net_amount
+        } else {
+            amount
+        };
+        distribute(env, &token, &escrow_data.freelancer, payout);
+
+        if let Some(fee_manager) = fee_manager.as_ref().filter(|_| fee_amount > 0) {
+            let platform_wallet: Address = call(
+                env,
+                ContractDomain::FeeManager,
+                fee_manager,
+                &Symbol::new(env, FEE_PLATFORM_WALLET),
+                ().into_val(env),
+            );
+            env.invoke_contract::<()>(
+                &token,
+                &Symbol::new(env, TOKEN_TRANSFER),
+                (
+                    env.current_contract_address(),
+                    platform_wallet.clone(),
+                    fee_amount,
+                )
+                    .into_val(env),
+            );
+            env.events().publish(
+                (Symbol::new(env, "platform_fee_paid"), platform_wallet),
+                (fee_amount, net_amount, env.ledger().timestamp()),
+            );
+        }
+        env.storage()
+            .instance()
+            .set(&PAID_OUT, &(get_paid_out(env) + amount));
+    }
+
+    escrow_data.fee_collected += fee_amount;
+    escrow_data.net_amount = escrow_data.amount - escrow_data.fee_collected;
+}
+
+// Lets the reputation NFT contract count the job in the same transaction as the payout.
+// The escrow must be registered there as a minter.
+fn record_completion(env: &Env, escrow_data: &EscrowData) {
+    if let Some(reputation_contract) = get_reputation_contract(env) {
+        let completed: u32 = call(
+            env,
+            ContractDomain::ReputationNft,
+            &reputation_contract,
+            &Symbol::new(env, REPUTATION_RECORD),
+            (
+                env.current_contract_address(),
+                escrow_data.freelancer.clone(),
+            )
+                .into_val(env),
+        );
+        env.events().publish(
+            (
+                Symbol::new(env, "completion_recorded"),
+                escrow_data.freelancer.clone(),
+            ),
+            (completed, env.ledger().timestamp()),
+        );
+    }
+}
+
 pub fn dispute(env: &Env, caller: Address) {
     if is_paused(env) {
         handle_error(env, Error::ContractPaused);
@@ -451,6 +631,7 @@ pub fn dispute(env: &Env, caller: Address) {
     escrow_data.disputed_at = Some(env.ledger().timestamp());
 
     env.storage().instance().set(&ESCROW_DATA, &escrow_data);
+    extend_instance_ttl(env);
 
     let total_escrow_transaction = increment_escrow_transaction_count(env);
 
@@ -462,12 +643,14 @@ pub fn dispute(env: &Env, caller: Address) {
         (Symbol::new(env, "escrow_disputed"), caller.clone()),
         env.ledger().timestamp(),
     );
+    emit_lifecycle(env, STAGE_DISPUTED, &escrow_data, escrow_data.amount);
 }
 
 pub fn resolve_dispute(env: &Env, caller: Address, result: Symbol) {
     if is_paused(env) {
         handle_error(env, Error::ContractPaused);
     }
+    let _guard = reentrancy_guard(env);
 
     let caller_addr = caller.clone();
 
@@ -504,7 +687,9 @@ pub fn resolve_dispute(env: &Env, caller: Address, result: Symbol) {
             handle_error(env, Error::InvalidDisputeResult);
         };
 
-    if let (Some(token), amount) = (escrow_data.token.clone(), escrow_data.amount) {
+    // Streamed payouts already left the escrow
+    let amount = escrow_data.amount - get_paid_out(env);
+    if let Some(token) = escrow_data.token.clone() {
         let contract_addr = env.current_contract_address();
         match dispute_result {
             DisputeResult::ClientWins => {
@@ -566,6 +751,386 @@ pub fn resolve_dispute(env: &Env, caller: Address, result: Symbol) {
         (Symbol::new(env, "escrow_resolved"), result.clone()),
         env.ledger().timestamp(),
     );
+    let stage = match dispute_result {
+        DisputeResult::ClientWins => STAGE_REFUNDED,
+        _ => STAGE_RELEASED,
+    };
+    emit_lifecycle(env, stage, &escrow_data, amount);
+}
+
+/// Mutually agreed refund of part of the escrowed amount back to the client.
+/// Requires the client plus either the freelancer or the arbitrator.
+pub fn refund_partial(env: &Env, client: Address, approver: Address, amount: i128) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+    let _guard = reentrancy_guard(env);
+
+    let caller = client.clone();
+
+    // Log function call start
+    log_function_call(env, "refund_partial", &caller, true);
+
+    client.require_auth();
+    approver.require_auth();
+
+    if !env.storage().instance().has(&INITIALIZED) {
+        handle_error(env, Error::NotInitialized);
+    }
+
+    let mut escrow_data: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap();
+
+    if escrow_data.client != client {
+        handle_error(env, Error::Unauthorized);
+    }
+    if escrow_data.freelancer != approver && escrow_data.arbitrator != Some(approver.clone()) {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    // Streams settle through `stop_stream`, which splits by what has vested
+    if escrow_data.state != EscrowState::Funded || env.storage().instance().has(&STREAM) {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    // Only what is still held and not owed to outstanding milestones can go back.
+    // Released milestones are only earmarked; nothing leaves until it is paid out.
+    let pending_milestones: i128 = escrow_data
+        .milestones
+        .iter()
+        .filter(|m| !m.released)
+        .map(|m| m.amount)
+        .sum();
+    let paid_out = get_paid_out(env);
+    let refundable = escrow_data.amount - paid_out - pending_milestones;
+    if amount <= 0 || amount > refundable {
+        handle_error(env, Error::InvalidAmount);
+    }
+
+    if let Some(token) = escrow_data.token.clone() {
+        env.invoke_contract::<()>(
+            &token,
+            &Symbol::new(env, TOKEN_TRANSFER),
+            (env.current_contract_address(), client.clone(), amount).into_val(env),
+        );
+    }
+
+    escrow_data.amount -= amount;
+    escrow_data.net_amount = escrow_data.amount;
+    if escrow_data.amount == paid_out {
+        escrow_data.state = EscrowState::Refunded;
+        escrow_data.resolved_at = Some(env.ledger().timestamp());
+    }
+    env.storage().instance().set(&ESCROW_DATA, &escrow_data);
+
+    let refunded_total = get_refunded_total(env) + amount;
+    env.storage()
+        .instance()
+        .set(&REFUNDED_TOTAL, &refunded_total);
+
+    let total_escrow_transaction = increment_escrow_transaction_count(env);
+
+    env.events().publish(
+        (Symbol::new(env, "escrow_tx_count"),),
+        total_escrow_transaction,
+    );
+    env.events().publish(
+        (Symbol::new(env, "escrow_refunded"), client),
+        (
+            amount,
+            escrow_data.amount,
+            refunded_total,
+            approver,
+            env.ledger().timestamp(),
+        ),
+    );
+    emit_lifecycle(env, STAGE_REFUNDED, &escrow_data, amount);
+}
+
+pub fn get_refunded_amount(env: &Env) -> i128 {
+    get_refunded_total(env)
+}
+
+pub fn set_reputation_contract(env: &Env, client: Address, reputation_contract: Address) {
+    client.require_auth();
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    let escrow_data: EscrowData = env
+        .storage()
+        .instance()
+        .get(&ESCROW_DATA)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    if escrow_data.client != client {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    if escrow_data.state != EscrowState::Created {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    env.storage()
+        .instance()
+        .set(&REPUTATION_CONTRACT, &reputation_contract);
+
+    env.events().publish(
+        (Symbol::new(env, "reputation_contract_set"), client),
+        (reputation_contract, env.ledger().timestamp()),
+    );
+}
+
+pub fn get_reputation_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&REPUTATION_CONTRACT)
+}
+
+/// The fee manager configured by the platform; neither party can pick its own.
+pub fn get_fee_manager(env: &Env) -> Option<Address> {
+    platform_address(env, PLATFORM_FEE_MANAGER)
+}
+
+/// The dispute contract configured by the platform; neither party can pick its own.
+pub fn get_dispute_contract(env: &Env) -> Option<Address> {
+    platform_address(env, PLATFORM_DISPUTE_CONTRACT)
+}
+
+pub fn open_dispute(env: &Env, party: Address, job_id: u32, reason: String) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    let caller = party.clone();
+
+    // Log function call start
+    log_function_call(env, "open_dispute", &caller, true);
+
+    party.require_auth();
+
+    if !env.storage().instance().has(&INITIALIZED) {
+        handle_error(env, Error::NotInitialized);
+    }
+
+    let mut escrow_data: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap();
+
+    if escrow_data.client != party && escrow_data.freelancer != party {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    if escrow_data.state != EscrowState::Funded {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    let dispute_contract = get_dispute_contract(env)
+        .unwrap_or_else(|| handle_error(env, Error::DisputeContractNotSet));
+
+    // Freeze funds: release paths require the Funded state
+    escrow_data.state = EscrowState::Disputed;
+    escrow_data.disputed_at = Some(env.ledger().timestamp());
+    env.storage().instance().set(&ESCROW_DATA, &escrow_data);
+    env.storage().instance().set(&DISPUTE_ID, &job_id);
+    extend_instance_ttl(env);
+
+    call::<()>(
+        env,
+        ContractDomain::Dispute,
+        &dispute_contract,
+        &Symbol::new(env, DISPUTE_OPEN),
+        (
+            job_id,
+            party.clone(),
+            reason,
+            Some(env.current_contract_address()),
+            escrow_data.amount,
+        )
+            .into_val(env),
+    );
+
+    let total_escrow_transaction = increment_escrow_transaction_count(env);
+
+    env.events().publish(
+        (Symbol::new(env, "escrow_tx_count"),),
+        total_escrow_transaction,
+    );
+    env.events().publish(
+        (Symbol::new(env, "escrow_disputed"), party),
+        (job_id, env.ledger().timestamp()),
+    );
+    emit_lifecycle(env, STAGE_DISPUTED, &escrow_data, escrow_data.amount);
+}
+
+/// Distributes disputed funds; `freelancer_bps` is the freelancer's share in basis points.
+pub fn apply_resolution(env: &Env, dispute_contract: Address, escrow_id: u32, freelancer_bps: u32) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    dispute_contract.require_auth();
+
+    if !env.storage().instance().has(&INITIALIZED) {
+        handle_error(env, Error::NotInitialized);
+    }
+
+    if get_dispute_contract(env) != Some(dispute_contract.clone()) {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    let mut escrow_data: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap();
+
+    // The resolution must be for the dispute this escrow opened
+    if escrow_data.state != EscrowState::Disputed
+        || env.storage().instance().get::<_, u32>(&DISPUTE_ID) != Some(escrow_id)
+    {
+        handle_error(env, Error::DisputeNotOpen);
+    }
+
+    let (freelancer_share, client_share) = settle_dispute(env, &mut escrow_data, freelancer_bps);
+
+    env.events().publish(
+        (
+            Symbol::new(env, "resolution_applied"),
+            dispute_contract,
+            escrow_id,
+        ),
+        (freelancer_share, client_share, env.ledger().timestamp()),
+    );
+}
+
+pub fn set_dispute_timeout(env: &Env, client: Address, policy: DisputeTimeoutPolicy) {
+    client.require_auth();
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    let escrow_data: EscrowData = env
+        .storage()
+        .instance()
+        .get(&ESCROW_DATA)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    if escrow_data.client != client {
+        handle_error(env, Error::Unauthorized);
+    }
+    escrow_data.freelancer.require_auth();
+
+    if escrow_data.state != EscrowState::Created {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    if policy.max_duration_secs < MIN_DISPUTE_TIMEOUT_SECS {
+        handle_error(env, Error::InvalidTimestamp);
+    }
+    if policy.freelancer_bps > MAX_SPLIT_BPS {
+        handle_error(env, Error::InvalidSplit);
+    }
+
+    env.storage().instance().set(&DISPUTE_TIMEOUT, &policy);
+
+    env.events().publish(
+        (Symbol::new(env, "dispute_timeout_set"), client),
+        (policy.max_duration_secs, policy.freelancer_bps),
+    );
+}
+
+pub fn get_dispute_timeout(env: &Env) -> DisputeTimeoutPolicy {
+    get_dispute_timeout_policy(env)
+}
+
+/// Settles a dispute nobody resolved in time with the default split both parties signed.
+/// Anyone may call it, so an inactive arbitrator can never lock the funds.
+pub fn resolve_by_timeout(env: &Env) -> (i128, i128) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    if !env.storage().instance().has(&INITIALIZED) {
+        handle_error(env, Error::NotInitialized);
+    }
+
+    let mut escrow_data: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap();
+    if escrow_data.state != EscrowState::Disputed {
+        handle_error(env, Error::DisputeNotOpen);
+    }
+
+    let policy = get_dispute_timeout_policy(env);
+    let disputed_at = escrow_data.disputed_at.unwrap_or(0);
+    if env.ledger().timestamp() < disputed_at + policy.max_duration_secs {
+        handle_error(env, Error::DisputeTimeoutNotReached);
+    }
+
+    let (freelancer_share, client_share) =
+        settle_dispute(env, &mut escrow_data, policy.freelancer_bps);
+
+    env.events().publish(
+        (Symbol::new(env, "dispute_timed_out"),),
+        (freelancer_share, client_share, env.ledger().timestamp()),
+    );
+
+    (freelancer_share, client_share)
+}
+
+/// Splits what is still held in escrow by `freelancer_bps` and closes the escrow.
+/// Shared by every resolution path so they all settle the same way.
+pub(crate) fn settle_dispute(
+    env: &Env,
+    escrow_data: &mut EscrowData,
+    freelancer_bps: u32,
+) -> (i128, i128) {
+    if freelancer_bps > MAX_SPLIT_BPS {
+        handle_error(env, Error::InvalidSplit);
+    }
+    let _guard = reentrancy_guard(env);
+
+    let amount = escrow_data.amount - get_paid_out(env);
+    let (freelancer_share, client_share) =
+        split_bps(amount, freelancer_bps).unwrap_or_else(|e| handle_error(env, e.into()));
+
+    if let Some(token) = escrow_data.token.clone() {
+        let contract_addr = env.current_contract_address();
+        if freelancer_share > 0 {
+            env.invoke_contract::<()>(
+                &token,
+                &Symbol::new(env, TOKEN_TRANSFER),
+                (
+                    contract_addr.clone(),
+                    escrow_data.freelancer.clone(),
+                    freelancer_share,
+                )
+                    .into_val(env),
+            );
+        }
+        if client_share > 0 {
+            env.invoke_contract::<()>(
+                &token,
+                &Symbol::new(env, TOKEN_TRANSFER),
+                (contract_addr, escrow_data.client.clone(), client_share).into_val(env),
+            );
+        }
+    }
+
+    let dispute_result = match freelancer_bps {
+        0 => DisputeResult::ClientWins,
+        MAX_SPLIT_BPS => DisputeResult::FreelancerWins,
+        _ => DisputeResult::Split,
+    };
+
+    escrow_data.state = EscrowState::Released;
+    escrow_data.dispute_result = dispute_result as u32;
+    escrow_data.resolved_at = Some(env.ledger().timestamp());
+
+    env.storage().instance().set(&ESCROW_DATA, escrow_data);
+
+    let total_escrow_transaction = increment_escrow_transaction_count(env);
+
+    env.events().publish(
+        (Symbol::new(env, "escrow_tx_count"),),
+        total_escrow_transaction,
+    );
+    if freelancer_share > 0 {
+        emit_lifecycle(env, STAGE_RELEASED, escrow_data, freelancer_share);
+    }
+    if client_share > 0 {
+        emit_lifecycle(env, STAGE_REFUNDED, escrow_data, client_share);
+    }
+
+    (freelancer_share, client_share)
 }
 
 pub fn add_milestone(env: &Env, client: Address, desc: String, amount: i128) -> u32 {
@@ -593,7 +1158,7 @@ pub fn add_milestone(env: &Env, client: Address, desc: String, amount: i128) ->
         handle_error(env, e);
     }
 
-    if escrow.client != client {
+    if !acts_for_client(env, &escrow, &client, ORG_ACTION_MANAGE_ESCROWS) {
         handle_error(env, Error::Unauthorized);
     }
 
@@ -688,7 +1253,12 @@ pub fn approve_milestone(env: &Env, client: Address, milestone_id: u32) {
     let mut escrow: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap();
     let ts = env.ledger().timestamp();
 
-    if escrow.client != client {
+    // With a milestone approver set, only its stakeholder vote can approve
+    let authorized = match get_milestone_approver(env) {
+        Some(approver) => approver == client,
+        None => acts_for_client(env, &escrow, &client, ORG_ACTION_APPROVE_MILESTONES),
+    };
+    if !authorized {
         handle_error(env, Error::Unauthorized);
     }
 
@@ -731,11 +1301,12 @@ pub fn approve_milestone(env: &Env, client: Address, milestone_id: u32) {
         ),
         (milestone_id, env.ledger().timestamp()),
     );
+    emit_lifecycle(env, STAGE_APPROVED, &escrow, milestone.amount);
 }
 
 // CORREGIDO: usar índice correcto (milestone_id - 1)
 pub fn release_milestone(env: &Env, freelancer: Address, milestone_id: u32) {
-    if is_paused(env) {
+    if is_surface_paused(env, SURFACE_RELEASE) {
         handle_error(env, Error::ContractPaused);
     }
 
@@ -801,57 +1372,82 @@ pub fn release_milestone(env: &Env, freelancer: Address, milestone_id: u32) {
         ),
         (milestone_id, milestone.amount, ts),
     );
+    emit_lifecycle(env, STAGE_RELEASED, &escrow, milestone.amount);
 }
 
 pub fn get_escrow_data(env: &Env) -> EscrowData {
     if !env.storage().instance().has(&INITIALIZED) {
         handle_error(env, Error::NotInitialized);
     }
+    if env.storage().instance().has(&ARCHIVED) {
+        handle_error(env, Error::EscrowArchived);
+    }
 
     env.storage().instance().get(&ESCROW_DATA).unwrap()
 }
 
+/// `(client, freelancer)`, for contracts that need the parties' roles without the full data.
+pub fn get_parties(env: &Env) -> (Address, Address) {
+    let escrow_data = get_escrow_data(env);
+    (escrow_data.client, escrow_data.freelancer)
+}
+
+/// Whether the engagement ended with the funds released to the freelancer.
+pub fn is_completed(env: &Env) -> bool {
+    get_escrow_data(env).state == EscrowState::Released
+}
+
+/// Whether the escrow reached a terminal state, however it got there: released, refunded
+/// (dispute settlements included) or cancelled.
+pub fn is_closed(env: &Env) -> bool {
+    matches!(
+        get_escrow_data(env).state,
+        EscrowState::Released | EscrowState::Refunded | EscrowState::Cancelled
+    )
+}
+
 pub fn auto_release(env: &Env) {
-    if is_paused(env) {
+    if is_surface_paused(env, SURFACE_RELEASE) {
         handle_error(env, Error::ContractPaused);
     }
+    let _guard = reentrancy_guard(env);
 
     if !env.storage().instance().has(&INITIALIZED) {
         handle_error(env, Error::NotInitialized);
     }
     let mut escrow_data: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap();
-    if escrow_data.state != EscrowState::Funded {
+    if escrow_data.state != EscrowState::Funded || env.storage().instance().has(&STREAM) {
         handle_error(env, Error::InvalidStatus);
     }
     let funded_at = escrow_data.funded_at.unwrap_or(0);
     let timeout = escrow_data.timeout_secs.unwrap_or(0);
     let now = env.ledger().timestamp();
-    
+
+    // Once work is delivered the client's review window governs the deadline
+    let deadline = match get_delivered_at(env) {
+        Some(delivered_at) => delivered_at + get_review_window(env),
+        None => funded_at + timeout,
+    };
+
     // Validate timestamp
-    if let Err(e) = validate_timestamp(env, funded_at + timeout) {
+    if let Err(e) = validate_timestamp(env, deadline) {
         handle_error(env, e);
     }
 
-    if now < funded_at + timeout {
+    if now < deadline {
         handle_error(env, Error::InvalidStatus);
     }
 
-    if let (Some(token), amount) = (escrow_data.token.clone(), escrow_data.amount) {
-        env.invoke_contract::<()>(
-            &token,
-            &Symbol::new(env, TOKEN_TRANSFER),
-            (
-                env.current_contract_address(),
-                escrow_data.freelancer.clone(),
-                amount,
-            )
-                .into_val(env),
-        );
-    }
+    let amount = escrow_data.amount;
+    pay_out_release(env, &mut escrow_data, amount);
+
     escrow_data.state = EscrowState::Released;
     escrow_data.released_at = Some(now);
 
     env.storage().instance().set(&ESCROW_DATA, &escrow_data);
+    record_completion(env, &escrow_data);
+    report_referral_completion(env, &escrow_data);
+    issue_invoice(env, &escrow_data);
 
     let total_escrow_transaction = increment_escrow_transaction_count(env);
 
@@ -866,11 +1462,122 @@ pub fn auto_release(env: &Env) {
         ),
         (escrow_data.amount, now),
     );
+    emit_lifecycle(env, STAGE_RELEASED, &escrow_data, escrow_data.amount);
+}
+
+pub fn cancel_escrow(env: &Env, client: Address) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    let caller = client.clone();
+
+    // Log function call start
+    log_function_call(env, "cancel_escrow", &caller, true);
+
+    client.require_auth();
+
+    if !env.storage().instance().has(&INITIALIZED) {
+        handle_error(env, Error::NotInitialized);
+    }
+
+    let mut escrow_data: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap();
+
+    if escrow_data.client != client {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    // Nothing has been deposited yet, so there is nothing to pay back
+    if !escrow_data.state.can_transition_to(&EscrowState::Cancelled) {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    escrow_data.state = EscrowState::Cancelled;
+    env.storage().instance().set(&ESCROW_DATA, &escrow_data);
+
+    env.events().publish(
+        (Symbol::new(env, "escrow_cancelled"), client),
+        env.ledger().timestamp(),
+    );
+    emit_lifecycle(env, STAGE_CANCELLED, &escrow_data, escrow_data.amount);
+}
+
+pub fn mark_delivered(env: &Env, freelancer: Address) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    let caller = freelancer.clone();
+
+    // Log function call start
+    log_function_call(env, "mark_delivered", &caller, true);
+
+    freelancer.require_auth();
+
+    if !env.storage().instance().has(&INITIALIZED) {
+        handle_error(env, Error::NotInitialized);
+    }
+
+    let escrow_data: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap();
+
+    if escrow_data.freelancer != freelancer {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    if escrow_data.state != EscrowState::Funded || get_delivered_at(env).is_some() {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    let now = env.ledger().timestamp();
+    env.storage().instance().set(&DELIVERED_AT, &now);
+
+    env.events().publish(
+        (Symbol::new(env, "work_delivered"), freelancer.clone()),
+        (now + get_review_window(env), now),
+    );
+    emit_lifecycle(env, STAGE_DELIVERED, &escrow_data, escrow_data.amount);
+}
+
+pub fn set_review_window(env: &Env, client: Address, review_window_secs: u64) {
+    client.require_auth();
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    let escrow_data: EscrowData = env
+        .storage()
+        .instance()
+        .get(&ESCROW_DATA)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    if escrow_data.client != client {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    // The window is part of the deal, so it can only change before funding
+    if escrow_data.state != EscrowState::Created {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    if review_window_secs == 0 || review_window_secs > MAX_AGE {
+        handle_error(env, Error::InvalidTimestamp);
+    }
+
+    env.storage()
+        .instance()
+        .set(&REVIEW_WINDOW, &review_window_secs);
+
+    env.events().publish(
+        (Symbol::new(env, "review_window_set"), client),
+        (review_window_secs, env.ledger().timestamp()),
+    );
+}
+
+pub fn get_review_deadline(env: &Env) -> Option<u64> {
+    get_delivered_at(env).map(|delivered_at| delivered_at + get_review_window(env))
 }
 
 pub fn get_milestones(env: &Env) -> Vec<Milestone> {
-    let escrow: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap();
-    escrow.milestones.clone()
+    get_escrow_data(env).milestones
 }
 
 pub fn get_milestone_history(env: &Env) -> Vec<MilestoneHistory> {
@@ -1074,6 +1781,7 @@ pub fn get_contract_status(env: &Env, contract_id: Address) -> EscrowSummary {
         EscrowState::Released => String::from_str(&env, "Released"),
         EscrowState::Disputed => String::from_str(&env, "Disputed"),
         EscrowState::Refunded => String::from_str(&env, "Resolved"),
+        EscrowState::Cancelled => String::from_str(env, "Cancelled"),
     };
 
     let summary = EscrowSummary {
@@ -0,0 +1,69 @@
+use offerhub_errors::{call, ContractDomain};
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+use crate::{
+    contract::deposit_funds_with_prepaid,
+    error::{handle_error, Error},
+    platform::platform_address,
+    storage::ESCROW_DATA,
+    types::EscrowData,
+};
+
+const CREDITS_DRAW: &str = "draw";
+const TOKEN_BALANCE: &str = "balance";
+const PLATFORM_CREDITS_CONTRACT: &str = "get_credits_contract";
+
+/// Funds the escrow partly or fully from the client's prepaid credits. The client pays
+/// the rest first, which authorizes the call, then the platform's credits contract moves
+/// `credit_amount` straight into the escrow. The escrow only counts as funded if its
+/// token balance rose by that much.
+pub fn deposit_funds_with_credits(env: &Env, client: Address, credit_amount: i128) {
+    let escrow_data: EscrowData = env
+        .storage()
+        .instance()
+        .get(&ESCROW_DATA)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    let token = escrow_data
+        .token
+        .clone()
+        .unwrap_or_else(|| handle_error(env, Error::InvalidStatus));
+
+    if credit_amount <= 0 || credit_amount > escrow_data.amount {
+        handle_error(env, Error::InvalidAmount);
+    }
+    let credits_contract = platform_address(env, PLATFORM_CREDITS_CONTRACT)
+        .unwrap_or_else(|| handle_error(env, Error::CreditsContractNotSet));
+
+    deposit_funds_with_prepaid(env, client.clone(), credit_amount);
+
+    let balance_before = token_balance(env, &token);
+    call::<i128>(
+        env,
+        ContractDomain::PrepaidCredits,
+        &credits_contract,
+        &Symbol::new(env, CREDITS_DRAW),
+        (
+            env.current_contract_address(),
+            client.clone(),
+            token.clone(),
+            credit_amount,
+        )
+            .into_val(env),
+    );
+    if token_balance(env, &token) - balance_before < credit_amount {
+        handle_error(env, Error::CreditsNotDelivered);
+    }
+
+    env.events().publish(
+        (Symbol::new(env, "funded_with_credits"), client),
+        (credits_contract, credit_amount, escrow_data.amount),
+    );
+}
+
+fn token_balance(env: &Env, token: &Address) -> i128 {
+    env.invoke_contract(
+        token,
+        &Symbol::new(env, TOKEN_BALANCE),
+        (env.current_contract_address(),).into_val(env),
+    )
+}
@@ -0,0 +1,135 @@
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+use crate::{
+    contract::log_function_call,
+    error::{handle_error, Error},
+    events::{emit_lifecycle, STAGE_REFUNDED},
+    platform::platform_address,
+    storage::{
+        get_paid_out, increment_escrow_transaction_count, EMERGENCY_DELAY_SECS,
+        EMERGENCY_REQUESTED_AT, ESCROW_DATA, INITIALIZED,
+    },
+    types::{EscrowData, EscrowState},
+};
+
+const BREAKER_ACTIVE: &str = "is_paused";
+const PLATFORM_EMERGENCY_CONTRACT: &str = "get_emergency_contract";
+const TOKEN_TRANSFER: &str = "transfer";
+
+/// The circuit breaker configured by the platform; neither party can pick its own.
+pub fn get_emergency_contract(env: &Env) -> Option<Address> {
+    platform_address(env, PLATFORM_EMERGENCY_CONTRACT)
+}
+
+// The withdrawal path deliberately skips the escrow's own pause check: it exists for
+// when the normal release path is broken.
+
+/// Starts the mandatory delay; only possible while the platform circuit breaker is active.
+pub fn request_emergency_withdrawal(env: &Env, client: Address) -> u64 {
+    log_function_call(env, "request_emergency_withdrawal", &client, true);
+
+    client.require_auth();
+
+    let escrow_data = load_unreleased(env, &client);
+    require_breaker_active(env);
+
+    if env.storage().instance().has(&EMERGENCY_REQUESTED_AT) {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    let now = env.ledger().timestamp();
+    env.storage().instance().set(&EMERGENCY_REQUESTED_AT, &now);
+
+    let available_at = now + EMERGENCY_DELAY_SECS;
+    env.events().publish(
+        (Symbol::new(env, "emergency_withdrawal_requested"), client),
+        (escrow_data.amount - get_paid_out(env), available_at),
+    );
+
+    available_at
+}
+
+/// Returns the unreleased funds to the client once the delay has passed and the breaker is still active.
+pub fn emergency_reclaim(env: &Env, client: Address) {
+    log_function_call(env, "emergency_reclaim", &client, true);
+
+    client.require_auth();
+
+    let mut escrow_data = load_unreleased(env, &client);
+    require_breaker_active(env);
+
+    let requested_at: u64 = env
+        .storage()
+        .instance()
+        .get(&EMERGENCY_REQUESTED_AT)
+        .unwrap_or_else(|| handle_error(env, Error::EmergencyNotRequested));
+    if env.ledger().timestamp() < requested_at + EMERGENCY_DELAY_SECS {
+        handle_error(env, Error::EmergencyDelayActive);
+    }
+
+    let unreleased = escrow_data.amount - get_paid_out(env);
+    if let Some(token) = escrow_data.token.clone() {
+        env.invoke_contract::<()>(
+            &token,
+            &Symbol::new(env, TOKEN_TRANSFER),
+            (env.current_contract_address(), client.clone(), unreleased).into_val(env),
+        );
+    }
+
+    escrow_data.state = EscrowState::Refunded;
+    escrow_data.resolved_at = Some(env.ledger().timestamp());
+    env.storage().instance().set(&ESCROW_DATA, &escrow_data);
+    env.storage().instance().remove(&EMERGENCY_REQUESTED_AT);
+
+    let total_escrow_transaction = increment_escrow_transaction_count(env);
+
+    env.events().publish(
+        (Symbol::new(env, "escrow_tx_count"),),
+        total_escrow_transaction,
+    );
+    env.events().publish(
+        (Symbol::new(env, "emergency_reclaimed"), client),
+        (unreleased, env.ledger().timestamp()),
+    );
+    emit_lifecycle(env, STAGE_REFUNDED, &escrow_data, unreleased);
+}
+
+pub fn get_emergency_withdrawal_time(env: &Env) -> Option<u64> {
+    env.storage()
+        .instance()
+        .get::<_, u64>(&EMERGENCY_REQUESTED_AT)
+        .map(|requested_at| requested_at + EMERGENCY_DELAY_SECS)
+}
+
+fn load_unreleased(env: &Env, client: &Address) -> EscrowData {
+    if !env.storage().instance().has(&INITIALIZED) {
+        handle_error(env, Error::NotInitialized);
+    }
+
+    let escrow_data: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap();
+    if escrow_data.client != *client {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    // Only undisputed funds still held by the escrow can be reclaimed; a dispute decides
+    // who disputed funds belong to
+    if escrow_data.state != EscrowState::Funded {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    escrow_data
+}
+
+fn require_breaker_active(env: &Env) {
+    let emergency_contract = get_emergency_contract(env)
+        .unwrap_or_else(|| handle_error(env, Error::EmergencyContractNotSet));
+
+    let active: bool = env.invoke_contract(
+        &emergency_contract,
+        &Symbol::new(env, BREAKER_ACTIVE),
+        ().into_val(env),
+    );
+    if !active {
+        handle_error(env, Error::EmergencyNotActive);
+    }
+}
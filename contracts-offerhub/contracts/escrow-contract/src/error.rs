@@ -1,3 +1,6 @@
+use offerhub_math::MathError;
+use offerhub_pausable::PauseError;
+use offerhub_upgradeable::UpgradeError;
 use soroban_sdk::{contracterror, panic_with_error, Env};
 
 #[contracterror]
@@ -20,7 +23,58 @@ pub enum Error {
     AlreadyPaused = 14,
     NotPaused = 15,
     ContractPaused = 16,
-    
+    DisputeContractNotSet = 17,
+    InvalidSplit = 18,
+    EmergencyContractNotSet = 19,
+    EmergencyNotActive = 20,
+    EmergencyNotRequested = 21,
+    EmergencyDelayActive = 22,
+    StreamNotSet = 23,
+    InvalidStreamWindow = 24,
+    AmendmentNotFound = 25,
+    EscrowArchived = 26,
+    DisputeTimeoutNotReached = 27,
+    NotInsured = 28,
+    ClaimAlreadyFiled = 29,
+    InsufficientVerification = 30,
+    FreelancerNotBonded = 31,
+    ComplianceCheckFailed = 32,
+    AlreadyMigrated = 33,
+    UnsupportedVersion = 34,
+    ReentrantCall = 35,
+    ArithmeticOverflow = 36,
+    InsurancePoolNotSet = 37,
+    NoInsuredLoss = 38,
+    CreditsContractNotSet = 39,
+    CreditsNotDelivered = 40,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
+
+impl From<MathError> for Error {
+    fn from(error: MathError) -> Self {
+        match error {
+            MathError::InvalidBps => Error::InvalidSplit,
+            MathError::Overflow | MathError::DivisionByZero => Error::ArithmeticOverflow,
+        }
+    }
+}
+
+impl From<UpgradeError> for Error {
+    fn from(error: UpgradeError) -> Self {
+        match error {
+            UpgradeError::AlreadyMigrated => Error::AlreadyMigrated,
+            UpgradeError::DowngradeNotSupported => Error::UnsupportedVersion,
+        }
+    }
 }
 
 pub fn handle_error(env: &Env, error: Error) -> ! {
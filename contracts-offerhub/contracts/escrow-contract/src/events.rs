@@ -0,0 +1,28 @@
+use offerhub_events::{LifecycleEvent, ESCROW_LIFECYCLE, SCHEMA_VERSION};
+use soroban_sdk::{Env, Symbol};
+
+use crate::types::EscrowData;
+
+pub const STAGE_CREATED: &str = "created";
+pub const STAGE_FUNDED: &str = "funded";
+pub const STAGE_DELIVERED: &str = "delivered";
+pub const STAGE_APPROVED: &str = "approved";
+pub const STAGE_RELEASED: &str = "released";
+pub const STAGE_DISPUTED: &str = "disputed";
+pub const STAGE_REFUNDED: &str = "refunded";
+pub const STAGE_CANCELLED: &str = "cancelled";
+
+/// Publishes `("escrow_lifecycle", stage)` with the parties and the amount moved
+/// by the transition, so indexers can rebuild a job timeline from events alone.
+pub fn emit_lifecycle(env: &Env, stage: &str, escrow_data: &EscrowData, amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, ESCROW_LIFECYCLE), Symbol::new(env, stage)),
+        LifecycleEvent {
+            version: SCHEMA_VERSION,
+            client: escrow_data.client.clone(),
+            freelancer: escrow_data.freelancer.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
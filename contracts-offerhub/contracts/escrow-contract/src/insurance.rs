@@ -0,0 +1,140 @@
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+use crate::{
+    contract::{deposit_funds, is_paused},
+    error::{handle_error, Error},
+    platform::platform_address,
+    storage::{get_paid_out, ESCROW_DATA, INSURANCE},
+    types::{ClaimStatus, EscrowData, EscrowState, InsurancePolicy},
+};
+
+const POOL_REGISTER: &str = "register_policy";
+const POOL_FILE_CLAIM: &str = "file_claim";
+const PLATFORM_INSURANCE_POOL: &str = "get_insurance_pool";
+
+/// Funds the escrow and buys cover for it in one step. The platform's insurance pool
+/// prices the cover for the escrowed amount and collects the premium from the client.
+pub fn deposit_funds_insured(env: &Env, client: Address) {
+    let insurance_pool = platform_address(env, PLATFORM_INSURANCE_POOL)
+        .unwrap_or_else(|| handle_error(env, Error::InsurancePoolNotSet));
+
+    deposit_funds(env, client.clone());
+
+    let escrow_data: EscrowData = env.storage().instance().get(&ESCROW_DATA).unwrap();
+    let token = escrow_data
+        .token
+        .clone()
+        .unwrap_or_else(|| handle_error(env, Error::InvalidStatus));
+    let premium = env.invoke_contract::<i128>(
+        &insurance_pool,
+        &Symbol::new(env, POOL_REGISTER),
+        (
+            env.current_contract_address(),
+            client.clone(),
+            token,
+            escrow_data.amount,
+        )
+            .into_val(env),
+    );
+
+    let policy = InsurancePolicy {
+        pool: insurance_pool,
+        premium,
+        coverage: escrow_data.amount,
+        claimed: 0,
+        insured_at: env.ledger().timestamp(),
+        claim_id: None,
+        claim_status: ClaimStatus::NotFiled,
+    };
+    env.storage().instance().set(&INSURANCE, &policy);
+
+    env.events().publish(
+        (Symbol::new(env, "escrow_insured"), client),
+        (policy.pool, premium, policy.coverage),
+    );
+}
+
+pub fn get_insurance(env: &Env) -> Option<InsurancePolicy> {
+    env.storage().instance().get(&INSURANCE)
+}
+
+/// Files a claim with the pool once the freelancer has defaulted, i.e. the escrow went
+/// into dispute. What the escrow still holds comes back through the dispute, released
+/// milestones included, so the claim covers only what was actually paid out to the
+/// freelancer, up to the coverage.
+pub fn file_insurance_claim(env: &Env, client: Address) -> u32 {
+    client.require_auth();
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    let escrow_data: EscrowData = env
+        .storage()
+        .instance()
+        .get(&ESCROW_DATA)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    if escrow_data.client != client {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    if escrow_data.state != EscrowState::Disputed {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    let mut policy = get_insurance(env).unwrap_or_else(|| handle_error(env, Error::NotInsured));
+    if policy.claim_status != ClaimStatus::NotFiled {
+        handle_error(env, Error::ClaimAlreadyFiled);
+    }
+
+    let loss = get_paid_out(env).min(policy.coverage);
+    if loss <= 0 {
+        handle_error(env, Error::NoInsuredLoss);
+    }
+
+    let claim_id: u32 = env.invoke_contract(
+        &policy.pool,
+        &Symbol::new(env, POOL_FILE_CLAIM),
+        (env.current_contract_address(), client.clone(), loss).into_val(env),
+    );
+
+    policy.claim_id = Some(claim_id);
+    policy.claimed = loss;
+    policy.claim_status = ClaimStatus::Filed;
+    env.storage().instance().set(&INSURANCE, &policy);
+
+    env.events().publish(
+        (Symbol::new(env, "insurance_claim_filed"), client),
+        (claim_id, loss, env.ledger().timestamp()),
+    );
+
+    claim_id
+}
+
+/// Called back by the pool once it has assessed the claim; any payout is made by the pool.
+pub fn record_claim_outcome(env: &Env, insurance_pool: Address, approved: bool, payout: i128) {
+    insurance_pool.require_auth();
+
+    let mut policy = get_insurance(env).unwrap_or_else(|| handle_error(env, Error::NotInsured));
+    if policy.pool != insurance_pool {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    if policy.claim_status != ClaimStatus::Filed {
+        handle_error(env, Error::InvalidStatus);
+    }
+    if payout < 0 || payout > policy.claimed {
+        handle_error(env, Error::InvalidAmount);
+    }
+
+    policy.claim_status = if approved {
+        ClaimStatus::Approved
+    } else {
+        ClaimStatus::Rejected
+    };
+    env.storage().instance().set(&INSURANCE, &policy);
+
+    env.events().publish(
+        (Symbol::new(env, "insurance_claim_settled"), insurance_pool),
+        (policy.claim_id, approved, payout, env.ledger().timestamp()),
+    );
+}
@@ -0,0 +1,72 @@
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+use crate::{
+    contract::{get_terms, is_paused},
+    error::{handle_error, Error},
+    storage::{ESCROW_DATA, INVOICE_CONTRACT},
+    types::{EscrowData, EscrowState},
+};
+
+const INVOICE_MINT: &str = "mint_invoice";
+
+/// Mints both parties an invoice NFT from this escrow once it completes. Agreed before
+/// funding, like the other escrow integrations.
+pub fn set_invoice_contract(env: &Env, client: Address, invoice_contract: Address) {
+    client.require_auth();
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    let escrow_data: EscrowData = env
+        .storage()
+        .instance()
+        .get(&ESCROW_DATA)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    if escrow_data.client != client {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    if escrow_data.state != EscrowState::Created {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    env.storage()
+        .instance()
+        .set(&INVOICE_CONTRACT, &invoice_contract);
+
+    env.events().publish(
+        (Symbol::new(env, "invoice_contract_set"), client),
+        (invoice_contract, env.ledger().timestamp()),
+    );
+}
+
+pub fn get_invoice_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&INVOICE_CONTRACT)
+}
+
+/// The invoice is a record of the payout, not a condition for it, so a failed mint is
+/// only logged.
+pub fn issue_invoice(env: &Env, escrow_data: &EscrowData) {
+    let Some(invoice_contract) = get_invoice_contract(env) else {
+        return;
+    };
+
+    let terms_hash = get_terms(env).map(|terms| terms.terms_hash);
+    let result = env.try_invoke_contract::<u64, Error>(
+        &invoice_contract,
+        &Symbol::new(env, INVOICE_MINT),
+        (
+            env.current_contract_address(),
+            escrow_data.client.clone(),
+            escrow_data.freelancer.clone(),
+            escrow_data.token.clone(),
+            escrow_data.amount,
+            terms_hash,
+        )
+            .into_val(env),
+    );
+    env.events().publish(
+        (Symbol::new(env, "invoice_issued"), invoice_contract),
+        (matches!(result, Ok(Ok(_))), env.ledger().timestamp()),
+    );
+}
@@ -1,13 +1,36 @@
 #![no_std]
-use crate::types::{EscrowSummary};
+pub use crate::arbitration::ArbitrationHook;
+use crate::types::{
+    Amendment, AmendmentChanges, ArbitrationDecision, ArchivedEscrow, BondPolicy, CompliancePolicy,
+    DisputeTimeoutPolicy, EscrowSummary, EscrowTerms, InsurancePolicy, OrganizationLink, Payee,
+    StreamConfig, TermsInitParams, VerificationPolicy,
+};
 use crate::error::Error;
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Symbol, Vec};
 
+mod amendment;
+mod arbitration;
+mod archive;
+mod bond;
+mod compliance;
 mod contract;
+mod credits;
+mod emergency;
 mod error;
+mod events;
+mod insurance;
+mod invoice;
+mod milestone_approval;
+mod organization;
+mod payees;
+mod platform;
+mod referral;
 mod storage;
+mod streaming;
 mod types;
+mod upgrade;
 mod validation;
+mod verification;
 
 // #[cfg(test)]
 // mod validation_test;
@@ -15,6 +38,8 @@ mod validation;
 #[contract]
 pub struct EscrowContract;
 
+offerhub_pausable::impl_pausable!(EscrowContract, Error, |env, _| Ok(contract::pause_admin(env)));
+
 #[contractimpl]
 impl EscrowContract {
     pub fn init_contract(
@@ -27,16 +52,33 @@ impl EscrowContract {
         contract::init_contract(&env, client, freelancer, amount, fee_manager);
     }
 
-    pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
-        contract::pause(&env, admin)
+    /// Pause one surface (`funding` or `release`) without pausing the whole escrow
+    pub fn pause_surface(env: Env, caller: Address, surface: Symbol) -> Result<(), Error> {
+        contract::pause_surface(&env, caller, surface)
+    }
+
+    pub fn unpause_surface(env: Env, caller: Address, surface: Symbol) -> Result<(), Error> {
+        contract::unpause_surface(&env, caller, surface)
+    }
+
+    pub fn is_surface_paused(env: Env, surface: Symbol) -> bool {
+        contract::is_surface_paused(&env, surface)
     }
 
-    pub fn is_paused(env: Env) -> bool {
-        contract::is_paused(&env)
+    /// Replace the escrow code; the client calls this and the freelancer co-signs. Call
+    /// `migrate` from the new code afterwards.
+    pub fn upgrade(env: Env, client: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        offerhub_upgradeable::upgrade::<EscrowContract>(&env, &client, new_wasm_hash)
     }
 
-    pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
-        contract::unpause(&env, admin)
+    /// Migrate storage to this code's version, with the same consent as `upgrade`
+    pub fn migrate(env: Env, client: Address) -> Result<(), Error> {
+        offerhub_upgradeable::migrate::<EscrowContract>(&env, &client)
+    }
+
+    /// Storage layout version
+    pub fn version(env: Env) -> u32 {
+        offerhub_upgradeable::version(&env)
     }
 
     pub fn emergency_withdraw(env: &Env, admin: Address) -> Result<(), Error> {
@@ -59,6 +101,87 @@ impl EscrowContract {
         contract::resolve_dispute(&env, caller, result);
     }
 
+    pub fn refund_partial(env: Env, client: Address, approver: Address, amount: i128) {
+        contract::refund_partial(&env, client, approver, amount);
+    }
+
+    pub fn get_refunded_amount(env: Env) -> i128 {
+        contract::get_refunded_amount(&env)
+    }
+
+    pub fn set_reputation_contract(env: Env, client: Address, reputation_contract: Address) {
+        contract::set_reputation_contract(&env, client, reputation_contract);
+    }
+
+    pub fn get_reputation_contract(env: Env) -> Option<Address> {
+        contract::get_reputation_contract(&env)
+    }
+
+    pub fn get_referral_contract(env: Env) -> Option<Address> {
+        referral::get_referral_contract(&env)
+    }
+
+    pub fn set_invoice_contract(env: Env, client: Address, invoice_contract: Address) {
+        invoice::set_invoice_contract(&env, client, invoice_contract);
+    }
+
+    pub fn get_invoice_contract(env: Env) -> Option<Address> {
+        invoice::get_invoice_contract(&env)
+    }
+
+    pub fn set_milestone_approver(env: Env, client: Address, approver: Address) {
+        milestone_approval::set_milestone_approver(&env, client, approver);
+    }
+
+    pub fn get_milestone_approver(env: Env) -> Option<Address> {
+        milestone_approval::get_milestone_approver(&env)
+    }
+
+    pub fn get_verification_policy(env: Env) -> Option<VerificationPolicy> {
+        verification::get_verification_policy(&env)
+    }
+
+    pub fn set_bond_policy(env: Env, client: Address, policy: BondPolicy) {
+        bond::set_bond_policy(&env, client, policy);
+    }
+
+    pub fn get_bond_policy(env: Env) -> Option<BondPolicy> {
+        bond::get_bond_policy(&env)
+    }
+
+    pub fn get_compliance_policy(env: Env) -> Option<CompliancePolicy> {
+        compliance::get_compliance_policy(&env)
+    }
+
+    pub fn set_organization(env: Env, client: Address, link: OrganizationLink) {
+        organization::set_organization(&env, client, link);
+    }
+
+    pub fn get_organization(env: Env) -> Option<OrganizationLink> {
+        organization::get_organization(&env)
+    }
+
+    pub fn get_fee_manager(env: Env) -> Option<Address> {
+        contract::get_fee_manager(&env)
+    }
+
+    pub fn get_dispute_contract(env: Env) -> Option<Address> {
+        contract::get_dispute_contract(&env)
+    }
+
+    pub fn open_dispute(env: Env, party: Address, job_id: u32, reason: String) {
+        contract::open_dispute(&env, party, job_id, reason);
+    }
+
+    pub fn apply_resolution(
+        env: Env,
+        dispute_contract: Address,
+        escrow_id: u32,
+        freelancer_bps: u32,
+    ) {
+        contract::apply_resolution(&env, dispute_contract, escrow_id, freelancer_bps);
+    }
+
     pub fn init_contract_full(
         env: Env,
         client: Address,
@@ -79,14 +202,162 @@ impl EscrowContract {
         );
     }
 
+    pub fn init_contract_with_terms(env: Env, params: TermsInitParams) {
+        contract::init_contract_with_terms(&env, params);
+    }
+
+    pub fn get_terms(env: Env) -> Option<EscrowTerms> {
+        contract::get_terms(&env)
+    }
+
+    pub fn set_platform(env: Env, platform: Address) {
+        platform::set_platform(&env, platform);
+    }
+
+    pub fn get_platform(env: Env) -> Option<Address> {
+        platform::get_platform(&env)
+    }
+
+    pub fn get_emergency_contract(env: Env) -> Option<Address> {
+        emergency::get_emergency_contract(&env)
+    }
+
+    pub fn request_emergency_withdrawal(env: Env, client: Address) -> u64 {
+        emergency::request_emergency_withdrawal(&env, client)
+    }
+
+    pub fn emergency_reclaim(env: Env, client: Address) {
+        emergency::emergency_reclaim(&env, client);
+    }
+
+    pub fn get_emergency_withdrawal_time(env: Env) -> Option<u64> {
+        emergency::get_emergency_withdrawal_time(&env)
+    }
+
+    pub fn set_payees(env: Env, payees: Vec<Payee>) {
+        payees::set_payees(&env, payees);
+    }
+
+    pub fn get_payees(env: Env) -> Option<Vec<Payee>> {
+        payees::get_payees(&env)
+    }
+
+    pub fn set_stream(env: Env, client: Address, start_ledger: u32, end_ledger: u32) {
+        streaming::set_stream(&env, client, start_ledger, end_ledger);
+    }
+
+    pub fn get_stream(env: Env) -> Option<StreamConfig> {
+        streaming::get_stream(&env)
+    }
+
+    pub fn get_vested_amount(env: Env) -> i128 {
+        streaming::get_vested_amount(&env)
+    }
+
+    pub fn withdraw_vested(env: Env, freelancer: Address) -> i128 {
+        streaming::withdraw_vested(&env, freelancer)
+    }
+
+    pub fn stop_stream(env: Env, client: Address) -> i128 {
+        streaming::stop_stream(&env, client)
+    }
+
+    pub fn propose_amendment(env: Env, proposer: Address, changes: AmendmentChanges) {
+        amendment::propose_amendment(&env, proposer, changes);
+    }
+
+    pub fn accept_amendment(env: Env, accepter: Address) {
+        amendment::accept_amendment(&env, accepter);
+    }
+
+    pub fn get_pending_amendment(env: Env) -> Option<Amendment> {
+        amendment::get_pending_amendment(&env)
+    }
+
+    pub fn get_amendment_history(env: Env) -> Vec<Amendment> {
+        amendment::get_amendment_history(&env)
+    }
+
+    pub fn bump_ttl(env: Env) {
+        archive::bump_ttl(&env);
+    }
+
+    pub fn archive_escrow(env: Env, caller: Address) -> ArchivedEscrow {
+        archive::archive_escrow(&env, caller)
+    }
+
+    pub fn get_archived_escrow(env: Env) -> Option<ArchivedEscrow> {
+        archive::get_archived_escrow(&env)
+    }
+
+    pub fn set_dispute_timeout(env: Env, client: Address, policy: DisputeTimeoutPolicy) {
+        contract::set_dispute_timeout(&env, client, policy);
+    }
+
+    pub fn get_dispute_timeout(env: Env) -> DisputeTimeoutPolicy {
+        contract::get_dispute_timeout(&env)
+    }
+
+    pub fn resolve_by_timeout(env: Env) -> (i128, i128) {
+        contract::resolve_by_timeout(&env)
+    }
+
+    pub fn deposit_funds_insured(env: Env, client: Address) {
+        insurance::deposit_funds_insured(&env, client);
+    }
+
+    pub fn deposit_funds_with_credits(env: Env, client: Address, credit_amount: i128) {
+        credits::deposit_funds_with_credits(&env, client, credit_amount);
+    }
+
+    pub fn get_insurance(env: Env) -> Option<InsurancePolicy> {
+        insurance::get_insurance(&env)
+    }
+
+    pub fn file_insurance_claim(env: Env, client: Address) -> u32 {
+        insurance::file_insurance_claim(&env, client)
+    }
+
+    pub fn record_claim_outcome(env: Env, insurance_pool: Address, approved: bool, payout: i128) {
+        insurance::record_claim_outcome(&env, insurance_pool, approved, payout);
+    }
+
+    pub fn cancel_escrow(env: Env, client: Address) {
+        contract::cancel_escrow(&env, client);
+    }
+
     pub fn auto_release(env: Env) {
         contract::auto_release(&env);
     }
 
+    pub fn mark_delivered(env: Env, freelancer: Address) {
+        contract::mark_delivered(&env, freelancer);
+    }
+
+    pub fn set_review_window(env: Env, client: Address, review_window_secs: u64) {
+        contract::set_review_window(&env, client, review_window_secs);
+    }
+
+    pub fn get_review_deadline(env: Env) -> Option<u64> {
+        contract::get_review_deadline(&env)
+    }
+
     pub fn get_escrow_data(env: Env) -> types::EscrowData {
         contract::get_escrow_data(&env)
     }
 
+    pub fn get_parties(env: Env) -> (Address, Address) {
+        contract::get_parties(&env)
+    }
+
+    pub fn is_completed(env: Env) -> bool {
+        contract::is_completed(&env)
+    }
+
+    pub fn is_closed(env: Env) -> bool {
+        contract::is_closed(&env)
+    }
+
     pub fn add_milestone(env: Env, client: Address, desc: String, amount: i128) -> u32 {
         contract::add_milestone(&env, client, desc, amount)
     }
@@ -149,5 +420,20 @@ impl EscrowContract {
 
 }
 
+#[contractimpl]
+impl ArbitrationHook for EscrowContract {
+    fn set_arbitrator(env: Env, admin: Address, arbitrator: Address) {
+        arbitration::set_arbitrator(&env, admin, arbitrator);
+    }
+
+    fn get_arbitrator(env: Env) -> Option<Address> {
+        arbitration::get_arbitrator(&env)
+    }
+
+    fn arbitrate(env: Env, arbitrator: Address, decision: ArbitrationDecision) {
+        arbitration::arbitrate(&env, arbitrator, decision);
+    }
+}
+
 #[cfg(test)]
 mod test;
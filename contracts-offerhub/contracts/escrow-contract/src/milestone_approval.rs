@@ -0,0 +1,41 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::{
+    contract::is_paused,
+    error::{handle_error, Error},
+    storage::{ESCROW_DATA, MILESTONE_APPROVER},
+    types::{EscrowData, EscrowState},
+};
+
+/// Hands milestone approval to a multi-stakeholder voting contract. Once set, the client
+/// can no longer approve milestones directly; only the approver's vote outcome can.
+pub fn set_milestone_approver(env: &Env, client: Address, approver: Address) {
+    client.require_auth();
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    let escrow_data: EscrowData = env
+        .storage()
+        .instance()
+        .get(&ESCROW_DATA)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    if escrow_data.client != client {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    if escrow_data.state != EscrowState::Created {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    env.storage().instance().set(&MILESTONE_APPROVER, &approver);
+
+    env.events().publish(
+        (Symbol::new(env, "milestone_approver_set"), client),
+        (approver, env.ledger().timestamp()),
+    );
+}
+
+pub fn get_milestone_approver(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&MILESTONE_APPROVER)
+}
@@ -0,0 +1,67 @@
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+use crate::{
+    contract::is_paused,
+    error::{handle_error, Error},
+    storage::{ESCROW_DATA, ORGANIZATION},
+    types::{EscrowData, EscrowState, OrganizationLink},
+};
+
+const ORG_HAS_PERMISSION: &str = "has_permission";
+
+// `OrgAction` discriminants in the organization registry
+pub const ORG_ACTION_MANAGE_ESCROWS: u32 = 1;
+pub const ORG_ACTION_APPROVE_MILESTONES: u32 = 2;
+
+/// Puts the escrow under an organization, letting members the registry permits act for the
+/// client. Agreed before funding, like the other escrow policies.
+pub fn set_organization(env: &Env, client: Address, link: OrganizationLink) {
+    client.require_auth();
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    let escrow_data: EscrowData = env
+        .storage()
+        .instance()
+        .get(&ESCROW_DATA)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    if escrow_data.client != client {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    if escrow_data.state != EscrowState::Created {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    if !org_permits(env, &link, &client, ORG_ACTION_MANAGE_ESCROWS) {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    env.storage().instance().set(&ORGANIZATION, &link);
+
+    env.events().publish(
+        (Symbol::new(env, "organization_set"), client),
+        (link.registry, link.org_id),
+    );
+}
+
+pub fn get_organization(env: &Env) -> Option<OrganizationLink> {
+    env.storage().instance().get(&ORGANIZATION)
+}
+
+/// True for the client, or for an organization member permitted to take `action`.
+pub fn acts_for_client(env: &Env, escrow_data: &EscrowData, caller: &Address, action: u32) -> bool {
+    if escrow_data.client == *caller {
+        return true;
+    }
+    get_organization(env).is_some_and(|link| org_permits(env, &link, caller, action))
+}
+
+fn org_permits(env: &Env, link: &OrganizationLink, address: &Address, action: u32) -> bool {
+    env.invoke_contract::<bool>(
+        &link.registry,
+        &Symbol::new(env, ORG_HAS_PERMISSION),
+        (link.org_id, address.clone(), action).into_val(env),
+    )
+}
@@ -0,0 +1,100 @@
+use offerhub_math::{bps_of, Rounding};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
+
+use crate::{
+    contract::{is_paused, log_function_call},
+    error::{handle_error, Error},
+    storage::{ESCROW_DATA, MAX_PAYEES, MAX_SPLIT_BPS, PAYEES},
+    types::{EscrowData, EscrowState, Payee},
+};
+
+const TOKEN_TRANSFER: &str = "transfer";
+
+/// Replaces the freelancer as sole recipient of the release payout. Both parties sign,
+/// since the split changes who the client is paying.
+pub fn set_payees(env: &Env, payees: Vec<Payee>) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    let escrow_data: EscrowData = env
+        .storage()
+        .instance()
+        .get(&ESCROW_DATA)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+
+    log_function_call(env, "set_payees", &escrow_data.client, true);
+
+    escrow_data.client.require_auth();
+    escrow_data.freelancer.require_auth();
+
+    if escrow_data.state != EscrowState::Created {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    if payees.is_empty() || payees.len() > MAX_PAYEES {
+        handle_error(env, Error::InvalidSplit);
+    }
+    let mut total_bps: u32 = 0;
+    for payee in payees.iter() {
+        if payee.bps == 0 {
+            handle_error(env, Error::InvalidSplit);
+        }
+        total_bps = total_bps.saturating_add(payee.bps);
+    }
+    if total_bps != MAX_SPLIT_BPS {
+        handle_error(env, Error::InvalidSplit);
+    }
+
+    env.storage().instance().set(&PAYEES, &payees);
+
+    env.events().publish(
+        (Symbol::new(env, "payees_set"), escrow_data.client),
+        (payees.len(), env.ledger().timestamp()),
+    );
+}
+
+pub fn get_payees(env: &Env) -> Option<Vec<Payee>> {
+    env.storage().instance().get(&PAYEES)
+}
+
+/// Pays `amount` out of the escrow, split across the payees when any are set.
+/// The last payee takes the rounding remainder so nothing is left behind.
+pub(crate) fn distribute(env: &Env, token: &Address, freelancer: &Address, amount: i128) {
+    let contract_addr = env.current_contract_address();
+    let payees = match get_payees(env) {
+        Some(payees) => payees,
+        None => {
+            env.invoke_contract::<()>(
+                token,
+                &Symbol::new(env, TOKEN_TRANSFER),
+                (contract_addr, freelancer.clone(), amount).into_val(env),
+            );
+            return;
+        }
+    };
+
+    let last = payees.len() - 1;
+    let mut paid: i128 = 0;
+    for (i, payee) in payees.iter().enumerate() {
+        let share = if i as u32 == last {
+            amount - paid
+        } else {
+            bps_of(amount, payee.bps, Rounding::Down)
+                .unwrap_or_else(|e| handle_error(env, e.into()))
+        };
+        paid += share;
+
+        if share > 0 {
+            env.invoke_contract::<()>(
+                token,
+                &Symbol::new(env, TOKEN_TRANSFER),
+                (contract_addr.clone(), payee.recipient.clone(), share).into_val(env),
+            );
+        }
+        env.events().publish(
+            (Symbol::new(env, "payee_paid"), payee.recipient),
+            (share, payee.bps, env.ledger().timestamp()),
+        );
+    }
+}
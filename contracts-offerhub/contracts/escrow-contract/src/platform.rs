@@ -0,0 +1,52 @@
+use soroban_sdk::{Address, Env, IntoVal, Symbol, TryFromVal, Val};
+
+use crate::{
+    contract::is_paused,
+    error::{handle_error, Error},
+    storage::{INITIALIZED, PLATFORM},
+};
+
+pub const PLATFORM_ADMIN: &str = "get_admin";
+pub const PLATFORM_DISPUTE_CONTRACT: &str = "get_dispute_contract";
+pub const PLATFORM_FEE_MANAGER: &str = "get_fee_manager";
+pub const PLATFORM_VERIFICATION_POLICY: &str = "get_verification_policy";
+pub const PLATFORM_COMPLIANCE_POLICY: &str = "get_compliance_policy";
+pub const PLATFORM_REFERRAL_CONTRACT: &str = "get_referral_contract";
+
+/// Records the factory that deployed this escrow. Settings the platform owns, such as
+/// the emergency circuit breaker, are read from it instead of being chosen per escrow.
+/// Only possible before the escrow is initialized, so only the deployer can link it in the
+/// deploying transaction.
+pub fn set_platform(env: &Env, platform: Address) {
+    platform.require_auth();
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    if env.storage().instance().has(&INITIALIZED) || env.storage().instance().has(&PLATFORM) {
+        handle_error(env, Error::AlreadyInitialized);
+    }
+
+    env.storage().instance().set(&PLATFORM, &platform);
+
+    env.events().publish(
+        (Symbol::new(env, "platform_set"), platform),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_platform(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&PLATFORM)
+}
+
+/// Reads an address setting through one of the platform's getters, e.g. `get_emergency_contract`.
+pub(crate) fn platform_address(env: &Env, getter: &str) -> Option<Address> {
+    platform_setting(env, getter)
+}
+
+/// Reads any setting through one of the platform's getters; `None` when the escrow has no
+/// platform or the platform has not set it.
+pub(crate) fn platform_setting<T: TryFromVal<Env, Val>>(env: &Env, getter: &str) -> Option<T> {
+    let platform = get_platform(env)?;
+    env.invoke_contract::<Option<T>>(&platform, &Symbol::new(env, getter), ().into_val(env))
+}
@@ -0,0 +1,38 @@
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+use crate::{
+    error::Error,
+    platform::{platform_address, PLATFORM_REFERRAL_CONTRACT},
+    types::EscrowData,
+};
+
+const REFERRAL_RECORD_COMPLETION: &str = "record_escrow_completion";
+
+/// The platform's referral contract. The platform owns it so every party's referrer
+/// accrues rewards, not only those of clients who opted in.
+pub fn get_referral_contract(env: &Env) -> Option<Address> {
+    platform_address(env, PLATFORM_REFERRAL_CONTRACT)
+}
+
+/// Referral rewards must never hold up a payout, so a rejected report is only logged.
+pub fn report_referral_completion(env: &Env, escrow_data: &EscrowData) {
+    let Some(referral_contract) = get_referral_contract(env) else {
+        return;
+    };
+
+    let result = env.try_invoke_contract::<i128, Error>(
+        &referral_contract,
+        &Symbol::new(env, REFERRAL_RECORD_COMPLETION),
+        (
+            env.current_contract_address(),
+            escrow_data.client.clone(),
+            escrow_data.freelancer.clone(),
+            escrow_data.amount,
+        )
+            .into_val(env),
+    );
+    env.events().publish(
+        (Symbol::new(env, "referral_reported"), referral_contract),
+        (matches!(result, Ok(Ok(_))), env.ledger().timestamp()),
+    );
+}
@@ -5,7 +5,11 @@ pub const ESCROW_DATA: Symbol = symbol_short!("ESCROW");
 pub const INITIALIZED: Symbol = symbol_short!("INIT");
 pub const CONTRACT_CONFIG: Symbol = symbol_short!("CONFIG");
 
-pub const PAUSED: Symbol = symbol_short!("PAUSED");
+pub use offerhub_pausable::PAUSED;
+
+// Surfaces that can be paused on their own
+pub const SURFACE_FUNDING: Symbol = symbol_short!("funding");
+pub const SURFACE_RELEASE: Symbol = symbol_short!("release");
 
 
 // Rate limit storage keys
@@ -29,6 +33,76 @@ pub const DEFAULT_RATE_LIMIT_WINDOW_HOURS: u32 = 1;     // 1 hour window
 
 pub const TOTAL_ESCROW_COUNT: Symbol = symbol_short!("ESCCOUNT");
 
+// Delivery review window
+pub const DELIVERED_AT: Symbol = symbol_short!("DELIV");
+pub const REVIEW_WINDOW: Symbol = symbol_short!("REVWIN");
+pub const DEFAULT_REVIEW_WINDOW_SECS: u64 = 7 * 24 * 60 * 60; // 7 days
+
+// Dispute contract integration: id the dispute contract tracks the open dispute under
+pub const DISPUTE_ID: Symbol = symbol_short!("DISPID");
+pub const MAX_SPLIT_BPS: u32 = offerhub_math::MAX_BPS;
+
+// Fallback applied when a dispute outlives its maximum duration
+pub const DISPUTE_TIMEOUT: Symbol = symbol_short!("DISPTMO");
+pub const DEFAULT_DISPUTE_TIMEOUT_SECS: u64 = 30 * 24 * 60 * 60; // 30 days
+pub const MIN_DISPUTE_TIMEOUT_SECS: u64 = 7 * 24 * 60 * 60; // Covers the arbitration windows
+pub const DEFAULT_TIMEOUT_FREELANCER_BPS: u32 = 5_000; // 50/50 split
+
+// Team payouts: release is split across these recipients by basis points
+pub const PAYEES: Symbol = symbol_short!("PAYEES");
+pub const MAX_PAYEES: u32 = 20;
+
+// Streaming mode: the amount vests linearly over a ledger window
+pub const STREAM: Symbol = symbol_short!("STREAM");
+
+// Amendments agreed by both parties after creation
+pub const PENDING_AMENDMENT: Symbol = symbol_short!("AMENDPND");
+pub const AMENDMENT_HISTORY: Symbol = symbol_short!("AMENDHST");
+
+// Instance TTL kept alive while the escrow is active
+pub const TTL_THRESHOLD: u32 = 518_400; // ~30 days
+pub const TTL_EXTEND_TO: u32 = 1_036_800; // ~60 days
+
+// Compact record left behind once a settled escrow is archived
+pub const ARCHIVED: Symbol = symbol_short!("ARCHIVED");
+
+// Optional cover bought from an insurance pool at funding time
+pub const INSURANCE: Symbol = symbol_short!("INSURE");
+
+// Bond the freelancer must hold in the staking contract to fund a high-value escrow
+pub const BOND_POLICY: Symbol = symbol_short!("BONDPOL");
+
+// Organization whose permitted members may act for the client
+pub const ORGANIZATION: Symbol = symbol_short!("ORG");
+
+// Total refunded to the client through partial refunds
+pub const REFUNDED_TOTAL: Symbol = symbol_short!("REFUNDED");
+
+// Total that has actually left the escrow for the freelancer. Released milestones only
+// earmark funds, so this can trail `released_amount`.
+pub const PAID_OUT: Symbol = symbol_short!("PAIDOUT");
+
+// Hash of the agreed terms document, with agreed amount and deadline
+pub const TERMS: Symbol = symbol_short!("TERMS");
+
+// Platform emergency contract whose circuit breaker unlocks emergency withdrawals
+// Factory that deployed the escrow and owns its platform-wide settings
+pub const PLATFORM: Symbol = symbol_short!("PLATFORM");
+pub const EMERGENCY_REQUESTED_AT: Symbol = symbol_short!("EMERGREQ");
+pub const EMERGENCY_DELAY_SECS: u64 = 3 * 24 * 60 * 60; // 3 days
+
+// Reputation NFT contract told about every successful payout
+pub const REPUTATION_CONTRACT: Symbol = symbol_short!("REPCTR");
+
+// Invoice NFT contract that mints both parties a payment record on completion
+pub const INVOICE_CONTRACT: Symbol = symbol_short!("INVCTR");
+
+// Contract whose stakeholder vote replaces the client's own milestone approval
+pub const MILESTONE_APPROVER: Symbol = symbol_short!("MSAPPR");
+
+// Fee type the platform fee manager prices releases under
+pub const FEE_TYPE_ESCROW: u32 = 1;
+
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -136,7 +210,7 @@ pub fn add_call_log(env: &Env, log: &CallLog) {
 
 #[allow(dead_code)]
 pub fn get_call_logs(env: &Env) -> Vec<CallLog> {
-    let count = env.storage().instance().get(&LOG_COUNT).unwrap_or(0);
+    let count: u32 = env.storage().instance().get(&LOG_COUNT).unwrap_or(0);
     let mut logs = Vec::new(env);
 
     for i in 0..count {
@@ -154,13 +228,13 @@ pub fn get_call_logs(env: &Env) -> Vec<CallLog> {
 
 #[allow(dead_code)]
 pub fn clear_call_logs(env: &Env) {
-    let count = env.storage().instance().get(&LOG_COUNT).unwrap_or(0);
+    let count: u32 = env.storage().instance().get(&LOG_COUNT).unwrap_or(0);
 
     for i in 0..count {
         env.storage().instance().remove(&(CALL_LOGS, i));
     }
 
-    env.storage().instance().set(&LOG_COUNT, &0);
+    env.storage().instance().set(&LOG_COUNT, &0u32);
 }
 
 pub fn get_total_transactions(env: &Env) -> u64 {
@@ -181,8 +255,43 @@ pub fn increment_escrow_transaction_count(env: &Env) -> u64 {
     new_escrow_count
 }
 
+pub fn get_review_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&REVIEW_WINDOW)
+        .unwrap_or(DEFAULT_REVIEW_WINDOW_SECS)
+}
+
+pub fn get_refunded_total(env: &Env) -> i128 {
+    env.storage().instance().get(&REFUNDED_TOTAL).unwrap_or(0)
+}
+
+pub fn get_paid_out(env: &Env) -> i128 {
+    env.storage().instance().get(&PAID_OUT).unwrap_or(0)
+}
+
+pub fn get_dispute_timeout_policy(env: &Env) -> DisputeTimeoutPolicy {
+    env.storage()
+        .instance()
+        .get(&DISPUTE_TIMEOUT)
+        .unwrap_or(DisputeTimeoutPolicy {
+            max_duration_secs: DEFAULT_DISPUTE_TIMEOUT_SECS,
+            freelancer_bps: DEFAULT_TIMEOUT_FREELANCER_BPS,
+        })
+}
+
+pub fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+pub fn get_delivered_at(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&DELIVERED_AT)
+}
+
 // --- Escrow state handling ---
-use crate::types::{EscrowData, EscrowState};
+use crate::types::{DisputeTimeoutPolicy, EscrowData, EscrowState};
 use crate::error::handle_error;
 
 // pub fn set_escrow_state(env: &Env, new_state: EscrowState) {
@@ -222,7 +331,7 @@ pub fn set_escrow_state(env: &Env, new_state: EscrowState) {
                 data.resolved_at = Some(now);
             }
             EscrowState::Disputed => data.disputed_at = Some(now),
-            EscrowState::Created => {}
+            EscrowState::Created | EscrowState::Cancelled => {}
         }
     
         data.state = new_state;
@@ -0,0 +1,200 @@
+use offerhub_math::{mul_div, Rounding};
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+use crate::{
+    contract::{is_paused, log_function_call, pay_out_release},
+    error::{handle_error, Error},
+    events::{emit_lifecycle, STAGE_REFUNDED, STAGE_RELEASED},
+    storage::{increment_escrow_transaction_count, ESCROW_DATA, INITIALIZED, STREAM},
+    types::{EscrowData, EscrowState, StreamConfig},
+};
+
+const TOKEN_TRANSFER: &str = "transfer";
+
+/// Turns the escrow into a stream: the funded amount vests linearly from `start_ledger`
+/// to `end_ledger` and is paid out through `withdraw_vested` instead of `release_funds`.
+pub fn set_stream(env: &Env, client: Address, start_ledger: u32, end_ledger: u32) {
+    client.require_auth();
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    let escrow_data = load_escrow(env);
+    if escrow_data.client != client {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    if escrow_data.state != EscrowState::Created {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    if end_ledger <= start_ledger || end_ledger <= env.ledger().sequence() {
+        handle_error(env, Error::InvalidStreamWindow);
+    }
+
+    let stream = StreamConfig {
+        start_ledger,
+        end_ledger,
+        stopped_at: None,
+    };
+    env.storage().instance().set(&STREAM, &stream);
+
+    env.events().publish(
+        (Symbol::new(env, "stream_set"), client),
+        (start_ledger, end_ledger, escrow_data.amount),
+    );
+}
+
+pub fn get_stream(env: &Env) -> Option<StreamConfig> {
+    env.storage().instance().get(&STREAM)
+}
+
+/// Total vested so far, including what the freelancer has already withdrawn.
+pub fn get_vested_amount(env: &Env) -> i128 {
+    let stream = get_stream(env).unwrap_or_else(|| handle_error(env, Error::StreamNotSet));
+    vested(env, &stream, &load_escrow(env))
+}
+
+pub fn withdraw_vested(env: &Env, freelancer: Address) -> i128 {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    log_function_call(env, "withdraw_vested", &freelancer, true);
+
+    freelancer.require_auth();
+
+    let mut escrow_data = load_escrow(env);
+    if escrow_data.freelancer != freelancer {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    if escrow_data.state != EscrowState::Funded {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    let stream = get_stream(env).unwrap_or_else(|| handle_error(env, Error::StreamNotSet));
+    let available = vested(env, &stream, &escrow_data) - escrow_data.released_amount;
+    if available <= 0 {
+        handle_error(env, Error::InsufficientFunds);
+    }
+
+    pay_out_release(env, &mut escrow_data, available);
+    escrow_data.released_amount += available;
+
+    if escrow_data.released_amount == escrow_data.amount {
+        escrow_data.state = EscrowState::Released;
+        escrow_data.released_at = Some(env.ledger().timestamp());
+        finish(env, STAGE_RELEASED, &escrow_data, escrow_data.amount);
+    }
+    env.storage().instance().set(&ESCROW_DATA, &escrow_data);
+
+    env.events().publish(
+        (Symbol::new(env, "vested_withdrawn"), freelancer),
+        (
+            available,
+            escrow_data.released_amount,
+            env.ledger().sequence(),
+        ),
+    );
+
+    available
+}
+
+/// Ends the stream at the current ledger. Whatever has vested but not been withdrawn goes
+/// to the freelancer; the unvested remainder goes back to the client.
+pub fn stop_stream(env: &Env, client: Address) -> i128 {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    log_function_call(env, "stop_stream", &client, true);
+
+    client.require_auth();
+
+    let mut escrow_data = load_escrow(env);
+    if escrow_data.client != client {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    if escrow_data.state != EscrowState::Funded {
+        handle_error(env, Error::InvalidStatus);
+    }
+
+    let mut stream = get_stream(env).unwrap_or_else(|| handle_error(env, Error::StreamNotSet));
+    stream.stopped_at = Some(env.ledger().sequence());
+
+    let vested_total = vested(env, &stream, &escrow_data);
+    let owed_to_freelancer = vested_total - escrow_data.released_amount;
+    let unvested = escrow_data.amount - vested_total;
+
+    if owed_to_freelancer > 0 {
+        pay_out_release(env, &mut escrow_data, owed_to_freelancer);
+    }
+    if unvested > 0 {
+        transfer(env, &escrow_data, &client, unvested);
+    }
+
+    escrow_data.released_amount = vested_total;
+    escrow_data.resolved_at = Some(env.ledger().timestamp());
+    if unvested > 0 {
+        escrow_data.state = EscrowState::Refunded;
+        finish(env, STAGE_REFUNDED, &escrow_data, unvested);
+    } else {
+        escrow_data.state = EscrowState::Released;
+        escrow_data.released_at = escrow_data.resolved_at;
+        finish(env, STAGE_RELEASED, &escrow_data, vested_total);
+    }
+
+    env.storage().instance().set(&ESCROW_DATA, &escrow_data);
+    env.storage().instance().set(&STREAM, &stream);
+
+    env.events().publish(
+        (Symbol::new(env, "stream_stopped"), client),
+        (owed_to_freelancer, unvested, env.ledger().sequence()),
+    );
+
+    unvested
+}
+
+fn vested(env: &Env, stream: &StreamConfig, escrow_data: &EscrowData) -> i128 {
+    let now = stream.stopped_at.unwrap_or(env.ledger().sequence());
+    if now <= stream.start_ledger {
+        return 0;
+    }
+    if now >= stream.end_ledger {
+        return escrow_data.amount;
+    }
+
+    let elapsed = (now - stream.start_ledger) as i128;
+    let duration = (stream.end_ledger - stream.start_ledger) as i128;
+    mul_div(escrow_data.amount, elapsed, duration, Rounding::Down)
+        .unwrap_or_else(|e| handle_error(env, e.into()))
+}
+
+fn transfer(env: &Env, escrow_data: &EscrowData, to: &Address, amount: i128) {
+    if let Some(token) = escrow_data.token.clone() {
+        env.invoke_contract::<()>(
+            &token,
+            &Symbol::new(env, TOKEN_TRANSFER),
+            (env.current_contract_address(), to.clone(), amount).into_val(env),
+        );
+    }
+}
+
+fn finish(env: &Env, stage: &str, escrow_data: &EscrowData, amount: i128) {
+    let total_escrow_transaction = increment_escrow_transaction_count(env);
+    env.events().publish(
+        (Symbol::new(env, "escrow_tx_count"),),
+        total_escrow_transaction,
+    );
+    emit_lifecycle(env, stage, escrow_data, amount);
+}
+
+fn load_escrow(env: &Env) -> EscrowData {
+    if !env.storage().instance().has(&INITIALIZED) {
+        handle_error(env, Error::NotInitialized);
+    }
+
+    env.storage().instance().get(&ESCROW_DATA).unwrap()
+}
@@ -1,9 +1,20 @@
 #![cfg(test)]
 
-use crate::types::EscrowState;
+use crate::error::Error;
+use crate::types::{
+    AmendmentChanges, ArbitrationDecision, BondPolicy, ClaimStatus, CompliancePolicy,
+    DisputeTimeoutPolicy, EscrowState, OrganizationLink, Payee, TermsInitParams, VerificationPolicy,
+};
 use crate::{EscrowContract, EscrowContractClient};
-use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{contract, contractimpl, log, Address, Env, String, Symbol};
+use offerhub_events::LifecycleEvent;
+use fee_manager_contract::{FeeManagerContract, FeeManagerContractClient};
+use user_registry_contract::types::VerificationLevel;
+use user_registry_contract::{Contract as UserRegistry, ContractClient as UserRegistryClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+use soroban_sdk::{
+    contract, contractimpl, log, vec, Address, BytesN, Env, String, Symbol, TryFromVal,
+};
 
 #[contract]
 pub struct MockTokenContract;
@@ -983,6 +994,112 @@ fn test_pause_unpause_unauthorized() {
     assert_eq!(contract.is_paused(), true);
 }
 
+#[test]
+fn test_migrate_requires_client_and_current_version() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    assert_eq!(contract.version(), 1);
+    assert!(contract.try_migrate(&freelancer).is_err());
+    assert!(contract.try_migrate(&client).is_err());
+
+    // Escrows created before version tracking migrate to v1
+    env.as_contract(&contract_id, || {
+        env.storage().instance().remove(&offerhub_upgradeable::VERSION);
+    });
+    assert_eq!(contract.version(), 0);
+    contract.migrate(&client);
+    assert_eq!(contract.version(), 1);
+}
+
+#[test]
+fn test_pauser_pushes_and_surface_pause() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let emergency = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+
+    assert!(contract.try_set_paused(&emergency, &true).is_err());
+    contract.add_pauser(&client, &emergency);
+    assert!(contract.is_pauser(&emergency));
+
+    contract.set_paused(&emergency, &true);
+    contract.set_paused(&emergency, &true);
+    assert!(contract.is_paused());
+    // Lifting the pause is left to the client
+    assert!(contract.try_set_paused(&emergency, &false).is_err());
+    contract.set_paused(&client, &false);
+    assert!(!contract.is_paused());
+
+    // Only funding is paused; other flows keep working
+    let funding = Symbol::new(&env, "funding");
+    contract.pause_surface(&emergency, &funding);
+    assert!(contract.is_surface_paused(&funding));
+    assert!(!contract.is_surface_paused(&Symbol::new(&env, "release")));
+    assert!(contract.try_deposit_funds(&client).is_err());
+    assert!(contract.try_unpause_surface(&emergency, &funding).is_err());
+    contract.unpause_surface(&client, &funding);
+
+    contract.remove_pauser(&client, &emergency);
+    assert!(contract.try_pause(&emergency).is_err());
+}
+
+#[test]
+fn test_payout_rejected_while_guard_held() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.deposit_funds(&client);
+
+    // Simulate a release re-entering while another payout is mid-flight
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&offerhub_reentrancy::LOCKED, &true)
+    });
+    assert_eq!(
+        contract.try_release_funds(&freelancer),
+        Err(Ok(Error::ReentrantCall.into()))
+    );
+
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .remove(&offerhub_reentrancy::LOCKED)
+    });
+    contract.release_funds(&freelancer);
+    let data = env.as_contract(&contract_id, || crate::contract::get_escrow_data(&env));
+    assert_eq!(data.state, EscrowState::Released);
+    assert!(!env.as_contract(&contract_id, || offerhub_reentrancy::is_locked(&env)));
+}
+
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #16)")]
 fn test_deposit_and_release_token_panic() {
@@ -1047,4 +1164,2273 @@ fn test_emergency_withdraw() {
     log!(&env, "ESCROW: {}", data);
     assert_eq!(data.state, EscrowState::Released);
     assert_eq!(data.dispute_result, 3);
-}
\ No newline at end of file
+}
+#[test]
+fn test_auto_release_after_review_window() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+    let amount = 500;
+    let timeout = 30 * 24 * 3600;
+    let review_window = 2 * 24 * 3600;
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &amount, &timeout);
+    contract.set_review_window(&client, &review_window);
+    contract.deposit_funds(&client);
+    assert_eq!(contract.get_review_deadline(), None);
+
+    contract.mark_delivered(&freelancer);
+    let delivered_at = env.ledger().timestamp();
+    assert_eq!(contract.get_review_deadline(), Some(delivered_at + review_window));
+
+    // Anyone can trigger the release once the review window lapses
+    env.ledger().with_mut(|l| l.timestamp += review_window);
+    contract.auto_release();
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Released);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_auto_release_panics_within_review_window() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+    let amount = 500;
+    let timeout = 3600;
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &amount, &timeout);
+    contract.deposit_funds(&client);
+    contract.mark_delivered(&freelancer);
+
+    // The original timeout has passed, but the client is still reviewing
+    env.ledger().with_mut(|l| l.timestamp += timeout + 1);
+    contract.auto_release();
+}
+
+#[contract]
+pub struct MockDisputeContract;
+
+#[contractimpl]
+impl MockDisputeContract {
+    pub fn open_dispute(
+        env: Env,
+        job_id: u32,
+        _initiator: Address,
+        _reason: String,
+        escrow_contract: Option<Address>,
+        dispute_amount: i128,
+    ) {
+        // Mirrors the dispute contract's DisputeAlreadyExists
+        if env.storage().instance().has(&job_id) {
+            soroban_sdk::panic_with_error!(&env, soroban_sdk::Error::from_contract_error(4));
+        }
+        env.storage()
+            .instance()
+            .set(&job_id, &(escrow_contract, dispute_amount));
+    }
+
+    pub fn get_dispute(env: Env, job_id: u32) -> Option<(Option<Address>, i128)> {
+        env.storage().instance().get(&job_id)
+    }
+}
+
+#[test]
+fn test_open_dispute_and_apply_resolution() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let dispute_id = env.register(MockDisputeContract, ());
+    let dispute = MockDisputeContractClient::new(&env, &dispute_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+    let amount = 500;
+    let timeout = 3600;
+
+    contract.set_platform(&platform_with_dispute(&env, &dispute_id));
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &amount, &timeout);
+    contract.deposit_funds(&client);
+
+    contract.open_dispute(
+        &freelancer,
+        &7,
+        &String::from_str(&env, "Client unresponsive"),
+    );
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Disputed);
+    assert_eq!(
+        dispute.get_dispute(&7),
+        Some((Some(contract_id.clone()), amount))
+    );
+
+    contract.apply_resolution(&dispute_id, &7, &7_000);
+    let data = contract.get_escrow_data();
+    assert_eq!(data.state, EscrowState::Released);
+    assert_eq!(data.dispute_result, 3);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_apply_resolution_rejects_unregistered_contract() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let dispute_id = env.register(MockDisputeContract, ());
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+    let amount = 500;
+    let timeout = 3600;
+
+    contract.set_platform(&platform_with_dispute(&env, &dispute_id));
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &amount, &timeout);
+    contract.deposit_funds(&client);
+    contract.open_dispute(&client, &1, &String::from_str(&env, "Work not delivered"));
+
+    let impostor = Address::generate(&env);
+    contract.apply_resolution(&impostor, &1, &0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_apply_resolution_rejects_other_escrow_id() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let dispute_id = env.register(MockDisputeContract, ());
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.set_platform(&platform_with_dispute(&env, &dispute_id));
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.deposit_funds(&client);
+    contract.open_dispute(&client, &1, &String::from_str(&env, "Work not delivered"));
+
+    contract.apply_resolution(&dispute_id, &2, &0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #304)")]
+fn test_dispute_contract_errors_are_namespaced() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let dispute_id = env.register(MockDisputeContract, ());
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+    MockDisputeContractClient::new(&env, &dispute_id).open_dispute(
+        &1,
+        &client,
+        &String::from_str(&env, "Earlier dispute"),
+        &None,
+        &0,
+    );
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    contract.set_platform(&platform_with_dispute(&env, &dispute_id));
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.deposit_funds(&client);
+
+    // The dispute contract's code 4 surfaces in the dispute range
+    contract.open_dispute(&client, &1, &String::from_str(&env, "Work not delivered"));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_release_blocked_while_disputed() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let dispute_id = env.register(MockDisputeContract, ());
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+    let amount = 500;
+    let timeout = 3600;
+
+    contract.set_platform(&platform_with_dispute(&env, &dispute_id));
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &amount, &timeout);
+    contract.deposit_funds(&client);
+    contract.open_dispute(&client, &1, &String::from_str(&env, "Work not delivered"));
+
+    contract.release_funds(&freelancer);
+}
+
+#[test]
+fn test_refund_partial() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+    let amount = 500;
+    let timeout = 3600;
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &amount, &timeout);
+    contract.deposit_funds(&client);
+
+    contract.refund_partial(&client, &freelancer, &200);
+    let data = contract.get_escrow_data();
+    assert_eq!(data.amount, 300);
+    assert_eq!(data.state, EscrowState::Funded);
+    assert_eq!(contract.get_refunded_amount(), 200);
+
+    // Arbitrator can co-sign as well; refunding the rest closes the escrow
+    contract.refund_partial(&client, &arbitrator, &300);
+    let data = contract.get_escrow_data();
+    assert_eq!(data.amount, 0);
+    assert_eq!(data.state, EscrowState::Refunded);
+    assert_eq!(contract.get_refunded_amount(), 500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_refund_partial_requires_counterparty() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+    let amount = 500;
+    let timeout = 3600;
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &amount, &timeout);
+    contract.deposit_funds(&client);
+
+    let stranger = Address::generate(&env);
+    contract.refund_partial(&client, &stranger, &100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_refund_partial_exceeding_balance() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+    let amount = 500;
+    let timeout = 3600;
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &amount, &timeout);
+    contract.deposit_funds(&client);
+    contract.refund_partial(&client, &freelancer, &501);
+}
+
+#[test]
+fn test_refund_partial_after_milestone_release() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&client, &500);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.deposit_funds(&client);
+
+    let released = contract.add_milestone(&client, &String::from_str(&env, "Design"), &200);
+    contract.approve_milestone(&client, &released);
+    contract.release_milestone(&freelancer, &released);
+    let build = contract.add_milestone(&client, &String::from_str(&env, "Build"), &100);
+
+    // The released 200 is still held; only the 100 owed to the pending milestone is not
+    assert_eq!(
+        contract.try_refund_partial(&client, &freelancer, &401),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    contract.refund_partial(&client, &freelancer, &300);
+
+    let data = contract.get_escrow_data();
+    assert_eq!(data.amount, 200);
+    assert_eq!(data.state, EscrowState::Funded);
+    assert_eq!(TokenClient::new(&env, &token).balance(&client), 300);
+
+    // Once nothing is pending, refunding the rest closes the escrow with nothing left
+    contract.approve_milestone(&client, &build);
+    contract.release_milestone(&freelancer, &build);
+    contract.refund_partial(&client, &freelancer, &200);
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Refunded);
+    assert_eq!(TokenClient::new(&env, &token).balance(&contract_id), 0);
+}
+
+// Platform whose fee manager accepts fees from `escrow`, as if the platform factory
+// deployed it. Returns the platform and its fee manager.
+fn setup_fee_manager(
+    env: &Env,
+    platform_wallet: &Address,
+    escrow: &Address,
+) -> (Address, Address) {
+    let fee_manager_id = env.register(FeeManagerContract, ());
+    let fee_manager = FeeManagerContractClient::new(env, &fee_manager_id);
+    let admin = Address::generate(env);
+    fee_manager.initialize(&admin, platform_wallet);
+
+    let platform_id = setup_platform(env, &admin);
+    let platform = MockPlatformClient::new(env, &platform_id);
+    platform.add_escrow(escrow);
+    platform.set_fee_manager(&fee_manager_id);
+    fee_manager.set_escrow_factory(&admin, &platform_id);
+    (platform_id, fee_manager_id)
+}
+
+#[test]
+fn test_release_collects_fee_through_fee_manager() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&client, &10_000);
+    let (platform_id, fee_manager_id) = setup_fee_manager(&env, &platform_wallet, &contract_id);
+    let amount = 10_000;
+
+    assert_eq!(contract.get_fee_manager(), None);
+    contract.set_platform(&platform_id);
+    assert_eq!(contract.get_fee_manager(), Some(fee_manager_id.clone()));
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &amount, &3600);
+    contract.deposit_funds(&client);
+    contract.release_funds(&freelancer);
+
+    // Default escrow fee is 2.5%
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&freelancer), 9_750);
+    assert_eq!(token_client.balance(&platform_wallet), 250);
+    assert_eq!(token_client.balance(&contract_id), 0);
+
+    let data = contract.get_escrow_data();
+    assert_eq!(data.fee_collected, 250);
+    assert_eq!(data.net_amount, 9_750);
+
+    let fee_manager = FeeManagerContractClient::new(&env, &fee_manager_id);
+    assert_eq!(fee_manager.get_platform_balance(), 250);
+}
+
+#[test]
+fn test_release_waives_fee_for_premium_freelancer() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&client, &10_000);
+    let (platform_id, fee_manager_id) = setup_fee_manager(&env, &platform_wallet, &contract_id);
+    FeeManagerContractClient::new(&env, &fee_manager_id).add_premium_user(&freelancer);
+
+    contract.set_platform(&platform_id);
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &10_000, &3600);
+    contract.deposit_funds(&client);
+    contract.release_funds(&freelancer);
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&freelancer), 10_000);
+    assert_eq!(token_client.balance(&platform_wallet), 0);
+    assert_eq!(contract.get_escrow_data().fee_collected, 0);
+}
+
+#[test]
+fn test_init_contract_with_terms() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+    let terms_hash = BytesN::from_array(&env, &[7u8; 32]);
+    let deadline = env.ledger().timestamp() + 14 * 24 * 3600;
+
+    assert_eq!(contract.get_terms(), None);
+    contract.init_contract_with_terms(&TermsInitParams {
+        client: client.clone(),
+        freelancer: freelancer.clone(),
+        arbitrator,
+        token,
+        amount: 500,
+        timeout_secs: 3600,
+        terms_hash: terms_hash.clone(),
+        deadline,
+    });
+
+    // Both parties had to sign the commitment
+    let auths = env.auths();
+    assert!(auths.iter().any(|(signer, _)| *signer == client));
+    assert!(auths.iter().any(|(signer, _)| *signer == freelancer));
+
+    let terms = contract.get_terms().unwrap();
+    assert_eq!(terms.terms_hash, terms_hash);
+    assert_eq!(terms.amount, 500);
+    assert_eq!(terms.deadline, deadline);
+    assert_eq!(terms.committed_at, env.ledger().timestamp());
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Created);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+fn test_init_contract_with_terms_past_deadline() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let token = setup_token(&env);
+    contract.init_contract_with_terms(&TermsInitParams {
+        client: Address::generate(&env),
+        freelancer: Address::generate(&env),
+        arbitrator: Address::generate(&env),
+        token,
+        amount: 500,
+        timeout_secs: 3600,
+        terms_hash: BytesN::from_array(&env, &[7u8; 32]),
+        deadline: env.ledger().timestamp(),
+    });
+}
+
+fn last_lifecycle_event(env: &Env) -> Option<(Symbol, LifecycleEvent)> {
+    let mut last = None;
+    for (_, topics, data) in env.events().all().iter() {
+        let is_lifecycle = topics.len() == 2
+            && Symbol::try_from_val(env, &topics.get(0).unwrap())
+                == Ok(Symbol::new(env, "escrow_lifecycle"));
+        if is_lifecycle {
+            let stage = Symbol::try_from_val(env, &topics.get(1).unwrap()).unwrap();
+            last = Some((stage, LifecycleEvent::try_from_val(env, &data).unwrap()));
+        }
+    }
+    last
+}
+
+#[test]
+fn test_lifecycle_events() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    let (stage, event) = last_lifecycle_event(&env).unwrap();
+    assert_eq!(stage, Symbol::new(&env, "created"));
+    assert_eq!(event.version, 1);
+    assert_eq!(event.client, client);
+    assert_eq!(event.freelancer, freelancer);
+    assert_eq!(event.amount, 500);
+
+    contract.deposit_funds(&client);
+    let (stage, _) = last_lifecycle_event(&env).unwrap();
+    assert_eq!(stage, Symbol::new(&env, "funded"));
+
+    contract.mark_delivered(&freelancer);
+    let (stage, _) = last_lifecycle_event(&env).unwrap();
+    assert_eq!(stage, Symbol::new(&env, "delivered"));
+
+    contract.refund_partial(&client, &freelancer, &100);
+    let (stage, event) = last_lifecycle_event(&env).unwrap();
+    assert_eq!(stage, Symbol::new(&env, "refunded"));
+    assert_eq!(event.amount, 100);
+
+    contract.release_funds(&freelancer);
+    let (stage, event) = last_lifecycle_event(&env).unwrap();
+    assert_eq!(stage, Symbol::new(&env, "released"));
+    assert_eq!(event.amount, 400);
+}
+
+#[test]
+fn test_cancel_escrow_before_funding() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.cancel_escrow(&client);
+
+    let (stage, _) = last_lifecycle_event(&env).unwrap();
+    assert_eq!(stage, Symbol::new(&env, "cancelled"));
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Cancelled);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_cancel_escrow_after_funding() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.deposit_funds(&client);
+    contract.cancel_escrow(&client);
+}
+
+/// Stands in for the escrow factory's platform settings.
+#[contract]
+pub struct MockPlatform;
+
+#[contractimpl]
+impl MockPlatform {
+    pub fn set_emergency_contract(env: Env, emergency_contract: Address) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "emergency"), &emergency_contract);
+    }
+
+    pub fn get_emergency_contract(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "emergency"))
+    }
+
+    pub fn set_dispute_contract(env: Env, dispute_contract: Address) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "dispute"), &dispute_contract);
+    }
+
+    pub fn get_dispute_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&Symbol::new(&env, "dispute"))
+    }
+
+    pub fn set_fee_manager(env: Env, fee_manager: Address) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "fee_mgr"), &fee_manager);
+    }
+
+    pub fn get_fee_manager(env: Env) -> Option<Address> {
+        env.storage().instance().get(&Symbol::new(&env, "fee_mgr"))
+    }
+
+    pub fn set_admin(env: Env, admin: Address) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "admin"), &admin);
+    }
+
+    pub fn get_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&Symbol::new(&env, "admin"))
+    }
+
+    pub fn add_escrow(env: Env, escrow: Address) {
+        env.storage().instance().set(&escrow, &1u32);
+    }
+
+    pub fn get_escrow_id_by_address(env: Env, escrow_address: Address) -> Option<u32> {
+        env.storage().instance().get(&escrow_address)
+    }
+
+    pub fn set_insurance_pool(env: Env, insurance_pool: Address) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "insurance"), &insurance_pool);
+    }
+
+    pub fn get_insurance_pool(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "insurance"))
+    }
+
+    pub fn set_credits_contract(env: Env, credits_contract: Address) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "credits"), &credits_contract);
+    }
+
+    pub fn get_credits_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&Symbol::new(&env, "credits"))
+    }
+
+    pub fn set_verification_policy(env: Env, policy: VerificationPolicy) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "verify"), &policy);
+    }
+
+    pub fn get_verification_policy(env: Env) -> Option<VerificationPolicy> {
+        env.storage().instance().get(&Symbol::new(&env, "verify"))
+    }
+
+    pub fn set_compliance_policy(env: Env, policy: CompliancePolicy) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "comply"), &policy);
+    }
+
+    pub fn get_compliance_policy(env: Env) -> Option<CompliancePolicy> {
+        env.storage().instance().get(&Symbol::new(&env, "comply"))
+    }
+
+    pub fn set_referral_contract(env: Env, referral_contract: Address) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "referral"), &referral_contract);
+    }
+
+    pub fn get_referral_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&Symbol::new(&env, "referral"))
+    }
+}
+
+fn setup_platform(env: &Env, admin: &Address) -> Address {
+    let platform_id = env.register(MockPlatform, ());
+    MockPlatformClient::new(env, &platform_id).set_admin(admin);
+    platform_id
+}
+
+fn platform_with_dispute(env: &Env, dispute_contract: &Address) -> Address {
+    let platform_id = env.register(MockPlatform, ());
+    MockPlatformClient::new(env, &platform_id).set_dispute_contract(dispute_contract);
+    platform_id
+}
+
+#[contract]
+pub struct MockEmergencyContract;
+
+#[contractimpl]
+impl MockEmergencyContract {
+    pub fn set_paused(env: Env, paused: bool) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "paused"), &paused);
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "paused"))
+            .unwrap_or(false)
+    }
+}
+
+fn setup_emergency_escrow(
+    env: &Env,
+) -> (EscrowContractClient<'_>, MockEmergencyContractClient<'_>, Address) {
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(env, &contract_id);
+    let emergency_id = env.register(MockEmergencyContract, ());
+    let emergency = MockEmergencyContractClient::new(env, &emergency_id);
+
+    let client = Address::generate(env);
+    let freelancer = Address::generate(env);
+    let arbitrator = Address::generate(env);
+    let token = setup_token(env);
+
+    let platform_id = env.register(MockPlatform, ());
+    MockPlatformClient::new(env, &platform_id).set_emergency_contract(&emergency_id);
+
+    contract.set_platform(&platform_id);
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.deposit_funds(&client);
+
+    (contract, emergency, client)
+}
+
+#[test]
+fn test_emergency_reclaim_after_delay() {
+    let env = setup_env();
+    env.mock_all_auths();
+    let (contract, emergency, client) = setup_emergency_escrow(&env);
+
+    emergency.set_paused(&true);
+    let available_at = contract.request_emergency_withdrawal(&client);
+    assert_eq!(available_at, env.ledger().timestamp() + 3 * 24 * 3600);
+    assert_eq!(contract.get_emergency_withdrawal_time(), Some(available_at));
+
+    env.ledger().with_mut(|l| l.timestamp = available_at);
+    contract.emergency_reclaim(&client);
+
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Refunded);
+    assert_eq!(contract.get_emergency_withdrawal_time(), None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #20)")]
+fn test_emergency_withdrawal_requires_active_breaker() {
+    let env = setup_env();
+    env.mock_all_auths();
+    let (contract, _, client) = setup_emergency_escrow(&env);
+
+    contract.request_emergency_withdrawal(&client);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_emergency_reclaim_before_delay() {
+    let env = setup_env();
+    env.mock_all_auths();
+    let (contract, emergency, client) = setup_emergency_escrow(&env);
+
+    emergency.set_paused(&true);
+    contract.request_emergency_withdrawal(&client);
+    env.ledger().with_mut(|l| l.timestamp += 3600);
+    contract.emergency_reclaim(&client);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_emergency_withdrawal_excludes_disputed_funds() {
+    let env = setup_env();
+    env.mock_all_auths();
+    let (contract, emergency, client) = setup_emergency_escrow(&env);
+
+    contract.dispute(&client);
+    emergency.set_paused(&true);
+    contract.request_emergency_withdrawal(&client);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_platform_is_set_once() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let platform = Address::generate(&env);
+
+    contract.set_platform(&platform);
+    contract.init_contract_full(
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &setup_token(&env),
+        &500,
+        &3600,
+    );
+    assert_eq!(contract.get_platform(), Some(platform));
+
+    // Once initialized, nobody can relink the escrow
+    contract.set_platform(&Address::generate(&env));
+}
+
+#[test]
+fn test_pluggable_arbitrator_settles_dispute() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let panel = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.set_platform(&setup_platform(&env, &admin));
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+
+    // Only the platform admin picks the arbitrator
+    assert!(contract.try_set_arbitrator(&client, &client).is_err());
+    contract.set_arbitrator(&admin, &panel);
+    assert_eq!(contract.get_arbitrator(), Some(panel.clone()));
+
+    contract.deposit_funds(&client);
+    contract.dispute(&freelancer);
+    contract.arbitrate(
+        &panel,
+        &ArbitrationDecision {
+            freelancer_bps: 6_000,
+            reason: String::from_str(&env, "partial delivery"),
+        },
+    );
+
+    let data = contract.get_escrow_data();
+    assert_eq!(data.state, EscrowState::Released);
+    assert_eq!(data.dispute_result, 3);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_arbitrate_rejects_replaced_arbitrator() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    let admin = Address::generate(&env);
+    contract.set_platform(&setup_platform(&env, &admin));
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.set_arbitrator(&admin, &Address::generate(&env));
+    contract.deposit_funds(&client);
+    contract.dispute(&client);
+    contract.arbitrate(
+        &arbitrator,
+        &ArbitrationDecision {
+            freelancer_bps: 0,
+            reason: String::from_str(&env, "no delivery"),
+        },
+    );
+}
+
+#[contract]
+pub struct MockReputationContract;
+
+#[contractimpl]
+impl MockReputationContract {
+    pub fn record_contract_completion(env: Env, caller: Address, user: Address) -> u32 {
+        caller.require_auth();
+        let completed: u32 = env.storage().instance().get(&user).unwrap_or(0) + 1;
+        env.storage().instance().set(&user, &completed);
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "recorder"), &caller);
+        completed
+    }
+
+    pub fn get_completed(env: Env, user: Address) -> u32 {
+        env.storage().instance().get(&user).unwrap_or(0)
+    }
+
+    pub fn get_recorder(env: Env) -> Option<Address> {
+        env.storage().instance().get(&Symbol::new(&env, "recorder"))
+    }
+}
+
+#[test]
+fn test_release_records_completion_in_reputation_contract() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let reputation_id = env.register(MockReputationContract, ());
+    let reputation = MockReputationContractClient::new(&env, &reputation_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.set_reputation_contract(&client, &reputation_id);
+    assert_eq!(
+        contract.get_reputation_contract(),
+        Some(reputation_id.clone())
+    );
+    contract.deposit_funds(&client);
+    assert_eq!(reputation.get_completed(&freelancer), 0);
+    assert!(!contract.is_completed());
+    assert!(!contract.is_closed());
+
+    contract.release_funds(&freelancer);
+    assert!(contract.is_completed());
+    assert!(contract.is_closed());
+    assert_eq!(reputation.get_completed(&freelancer), 1);
+    assert_eq!(reputation.get_recorder(), Some(contract_id));
+}
+
+#[test]
+fn test_dispute_refund_does_not_record_completion() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let reputation_id = env.register(MockReputationContract, ());
+    let reputation = MockReputationContractClient::new(&env, &reputation_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.set_reputation_contract(&client, &reputation_id);
+    contract.deposit_funds(&client);
+    contract.dispute(&client);
+    contract.resolve_dispute(&arbitrator, &Symbol::new(&env, "client_wins"));
+
+    assert_eq!(reputation.get_completed(&freelancer), 0);
+}
+
+#[test]
+fn test_release_splits_payout_across_payees() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let designer = Address::generate(&env);
+    let tester = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&client, &10_001);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &10_001, &3600);
+    let payees = vec![
+        &env,
+        Payee {
+            recipient: freelancer.clone(),
+            bps: 5_000,
+        },
+        Payee {
+            recipient: designer.clone(),
+            bps: 3_000,
+        },
+        Payee {
+            recipient: tester.clone(),
+            bps: 2_000,
+        },
+    ];
+    contract.set_payees(&payees);
+    assert_eq!(contract.get_payees(), Some(payees));
+
+    contract.deposit_funds(&client);
+    contract.release_funds(&freelancer);
+
+    // The last payee absorbs the rounding remainder
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&freelancer), 5_000);
+    assert_eq!(token_client.balance(&designer), 3_000);
+    assert_eq!(token_client.balance(&tester), 2_001);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #18)")]
+fn test_set_payees_rejects_split_not_summing_to_whole() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.set_payees(&vec![
+        &env,
+        Payee {
+            recipient: freelancer.clone(),
+            bps: 6_000,
+        },
+        Payee {
+            recipient: Address::generate(&env),
+            bps: 3_000,
+        },
+    ]);
+}
+
+fn setup_streaming_escrow(
+    env: &Env,
+) -> (
+    EscrowContractClient<'_>,
+    Address,
+    Address,
+    Address,
+    TokenClient<'_>,
+) {
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(env, &contract_id);
+
+    let client = Address::generate(env);
+    let freelancer = Address::generate(env);
+    let arbitrator = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &token).mint(&client, &10_000);
+
+    env.ledger().with_mut(|l| l.sequence_number = 100);
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &10_000, &3600);
+    contract.set_stream(&client, &100, &200);
+    contract.deposit_funds(&client);
+
+    (
+        contract,
+        contract_id,
+        client,
+        freelancer,
+        TokenClient::new(env, &token),
+    )
+}
+
+#[test]
+fn test_withdraw_vested_pays_linearly() {
+    let env = setup_env();
+    env.mock_all_auths();
+    let (contract, contract_id, _client, freelancer, token) = setup_streaming_escrow(&env);
+
+    env.ledger().with_mut(|l| l.sequence_number = 125);
+    assert_eq!(contract.get_vested_amount(), 2_500);
+    assert_eq!(contract.withdraw_vested(&freelancer), 2_500);
+    assert_eq!(token.balance(&freelancer), 2_500);
+
+    env.ledger().with_mut(|l| l.sequence_number = 150);
+    assert_eq!(contract.withdraw_vested(&freelancer), 2_500);
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Funded);
+
+    env.ledger().with_mut(|l| l.sequence_number = 250);
+    assert_eq!(contract.withdraw_vested(&freelancer), 5_000);
+    assert_eq!(token.balance(&freelancer), 10_000);
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Released);
+}
+
+#[test]
+fn test_stop_stream_returns_unvested_to_client() {
+    let env = setup_env();
+    env.mock_all_auths();
+    let (contract, contract_id, client, freelancer, token) = setup_streaming_escrow(&env);
+
+    env.ledger().with_mut(|l| l.sequence_number = 120);
+    contract.withdraw_vested(&freelancer);
+
+    env.ledger().with_mut(|l| l.sequence_number = 140);
+    assert_eq!(contract.stop_stream(&client), 6_000);
+
+    assert_eq!(token.balance(&freelancer), 4_000);
+    assert_eq!(token.balance(&client), 6_000);
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Refunded);
+    assert_eq!(contract.get_stream().unwrap().stopped_at, Some(140));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_release_funds_blocked_for_streaming_escrow() {
+    let env = setup_env();
+    env.mock_all_auths();
+    let (contract, _contract_id, _client, freelancer, _token) = setup_streaming_escrow(&env);
+
+    contract.release_funds(&freelancer);
+}
+
+#[test]
+fn test_disputed_stream_settles_unreleased_balance() {
+    let env = setup_env();
+    env.mock_all_auths();
+    let (contract, contract_id, client, freelancer, token) = setup_streaming_escrow(&env);
+
+    env.ledger().with_mut(|l| l.sequence_number = 125);
+    contract.withdraw_vested(&freelancer);
+    contract.dispute(&client);
+
+    // Only the 7,500 still held is split; the withdrawn 2,500 is not paid twice
+    env.ledger().with_mut(|l| l.timestamp += 30 * 24 * 60 * 60);
+    assert_eq!(contract.resolve_by_timeout(), (3_750, 3_750));
+    assert_eq!(token.balance(&freelancer), 6_250);
+    assert_eq!(token.balance(&client), 3_750);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_dispute_after_milestone_release_settles_full_balance() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&client, &500);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.deposit_funds(&client);
+    let milestone = contract.add_milestone(&client, &String::from_str(&env, "Design"), &200);
+    contract.approve_milestone(&client, &milestone);
+    contract.release_milestone(&freelancer, &milestone);
+
+    // The released milestone never left the escrow, so the whole balance is settled
+    contract.dispute(&client);
+    contract.resolve_dispute(&arbitrator, &Symbol::new(&env, "split"));
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&client), 250);
+    assert_eq!(token_client.balance(&freelancer), 250);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_withdraw_vested_collects_fee_through_fee_manager() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&client, &10_000);
+    let (platform_id, _) = setup_fee_manager(&env, &platform_wallet, &contract_id);
+
+    env.ledger().with_mut(|l| l.sequence_number = 100);
+    contract.set_platform(&platform_id);
+    contract.init_contract_full(
+        &client,
+        &freelancer,
+        &Address::generate(&env),
+        &token,
+        &10_000,
+        &3600,
+    );
+    contract.set_stream(&client, &100, &200);
+    contract.deposit_funds(&client);
+
+    // Each vested slice pays the default 2.5% platform fee
+    env.ledger().with_mut(|l| l.sequence_number = 150);
+    contract.withdraw_vested(&freelancer);
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&freelancer), 4_875);
+    assert_eq!(token_client.balance(&platform_wallet), 125);
+
+    env.ledger().with_mut(|l| l.sequence_number = 200);
+    contract.withdraw_vested(&freelancer);
+    assert_eq!(token_client.balance(&freelancer), 9_750);
+    assert_eq!(token_client.balance(&platform_wallet), 250);
+
+    let data = contract.get_escrow_data();
+    assert_eq!(data.state, EscrowState::Released);
+    assert_eq!(data.fee_collected, 250);
+    assert_eq!(data.net_amount, 9_750);
+}
+
+#[test]
+fn test_amendment_tops_up_funded_escrow() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&client, &15_000);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &10_000, &3600);
+    contract.deposit_funds(&client);
+
+    let changes = AmendmentChanges {
+        top_up: 5_000,
+        deadline_extension_secs: 1800,
+    };
+    contract.propose_amendment(&freelancer, &changes);
+    assert_eq!(contract.get_pending_amendment().unwrap().changes, changes);
+    assert_eq!(contract.get_escrow_data().amount, 10_000);
+
+    contract.accept_amendment(&client);
+
+    let data = contract.get_escrow_data();
+    assert_eq!(data.amount, 15_000);
+    assert_eq!(data.timeout_secs, Some(5400));
+    assert_eq!(TokenClient::new(&env, &token).balance(&contract_id), 15_000);
+    assert_eq!(contract.get_pending_amendment(), None);
+
+    let history = contract.get_amendment_history();
+    assert_eq!(history.len(), 1);
+    let recorded = history.get(0).unwrap();
+    assert_eq!(recorded.proposer, freelancer);
+    assert_eq!(recorded.accepted_at, Some(1000));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_proposer_cannot_accept_own_amendment() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.propose_amendment(
+        &client,
+        &AmendmentChanges {
+            top_up: 0,
+            deadline_extension_secs: 600,
+        },
+    );
+    contract.accept_amendment(&client);
+}
+
+#[test]
+fn test_archive_settled_escrow_keeps_summary() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.add_milestone(&client, &String::from_str(&env, "Design"), &200);
+    contract.deposit_funds(&client);
+    contract.bump_ttl();
+    contract.release_funds(&freelancer);
+
+    let summary = contract.archive_escrow(&client);
+    assert_eq!(summary.final_state, EscrowState::Released);
+    assert_eq!(summary.amount, 500);
+    assert_eq!(summary.terms_hash, None);
+    assert_eq!(contract.get_archived_escrow(), Some(summary));
+
+    assert_eq!(
+        contract.try_get_escrow_data(),
+        Err(Ok(Error::EscrowArchived.into()))
+    );
+    assert_eq!(
+        contract.try_get_milestones(),
+        Err(Ok(Error::EscrowArchived.into()))
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_archive_active_escrow_fails() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.deposit_funds(&client);
+    contract.archive_escrow(&freelancer);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #26)")]
+fn test_bump_ttl_rejects_archived_escrow() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.cancel_escrow(&client);
+    contract.archive_escrow(&client);
+    contract.bump_ttl();
+}
+
+#[test]
+fn test_resolve_by_timeout_applies_default_policy() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&client, &10_000);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &10_000, &3600);
+    assert_eq!(contract.get_dispute_timeout().freelancer_bps, 5_000);
+    contract.set_dispute_timeout(
+        &client,
+        &DisputeTimeoutPolicy {
+            max_duration_secs: 7 * 24 * 60 * 60,
+            freelancer_bps: 3_000,
+        },
+    );
+    let auths = env.auths();
+    assert!(auths.iter().any(|(signer, _)| *signer == client));
+    assert!(auths.iter().any(|(signer, _)| *signer == freelancer));
+    contract.deposit_funds(&client);
+    contract.dispute(&freelancer);
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = 1000 + 7 * 24 * 60 * 60);
+    assert_eq!(contract.resolve_by_timeout(), (3_000, 7_000));
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&freelancer), 3_000);
+    assert_eq!(token_client.balance(&client), 7_000);
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Released);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #27)")]
+fn test_resolve_by_timeout_before_deadline_fails() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.deposit_funds(&client);
+    contract.dispute(&client);
+
+    env.ledger().with_mut(|l| l.timestamp = 1000 + 24 * 60 * 60);
+    contract.resolve_by_timeout();
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+fn test_set_dispute_timeout_below_minimum_fails() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.set_dispute_timeout(
+        &client,
+        &DisputeTimeoutPolicy {
+            max_duration_secs: 60,
+            freelancer_bps: 0,
+        },
+    );
+}
+
+#[contract]
+pub struct MockInsurancePool;
+
+#[contractimpl]
+impl MockInsurancePool {
+    /// Prices cover at 2% and collects the premium from the client, like the real pool
+    pub fn register_policy(
+        env: Env,
+        escrow: Address,
+        client: Address,
+        token: Address,
+        coverage: i128,
+    ) -> i128 {
+        let premium = coverage / 50;
+        TokenClient::new(&env, &token).transfer(&client, &env.current_contract_address(), &premium);
+        env.storage().instance().set(&escrow, &(coverage, premium));
+        premium
+    }
+
+    pub fn file_claim(env: Env, _escrow: Address, _client: Address, coverage: i128) -> u32 {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "claimed"), &coverage);
+        7
+    }
+
+    pub fn get_claimed(env: Env) -> Option<i128> {
+        env.storage().instance().get(&Symbol::new(&env, "claimed"))
+    }
+
+    pub fn get_policy(env: Env, escrow: Address) -> Option<(i128, i128)> {
+        env.storage().instance().get(&escrow)
+    }
+}
+
+#[test]
+fn test_insured_escrow_claim_lifecycle() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let pool_id = env.register(MockInsurancePool, ());
+    let pool = MockInsurancePoolClient::new(&env, &pool_id);
+    let platform_id = env.register(MockPlatform, ());
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&client, &10_200);
+
+    env.ledger().with_mut(|l| l.sequence_number = 100);
+    contract.set_platform(&platform_id);
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &10_000, &3600);
+    contract.set_stream(&client, &100, &200);
+
+    // Cover comes from the platform's pool, so it can't be bought until there is one
+    assert_eq!(
+        contract.try_deposit_funds_insured(&client),
+        Err(Ok(Error::InsurancePoolNotSet.into()))
+    );
+    MockPlatformClient::new(&env, &platform_id).set_insurance_pool(&pool_id);
+    contract.deposit_funds_insured(&client);
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&contract_id), 10_000);
+    assert_eq!(token_client.balance(&pool_id), 200);
+    assert_eq!(pool.get_policy(&contract_id), Some((10_000, 200)));
+
+    let policy = contract.get_insurance().unwrap();
+    assert_eq!(policy.coverage, 10_000);
+    assert_eq!(policy.claim_status, ClaimStatus::NotFiled);
+
+    env.ledger().with_mut(|l| l.sequence_number = 125);
+    contract.withdraw_vested(&freelancer);
+    contract.dispute(&client);
+
+    // The 7,500 still held returns through the dispute; only the 2,500 paid out is a loss
+    assert_eq!(contract.file_insurance_claim(&client), 7);
+    assert_eq!(pool.get_claimed(), Some(2_500));
+    let policy = contract.get_insurance().unwrap();
+    assert_eq!(policy.claimed, 2_500);
+    assert_eq!(policy.claim_status, ClaimStatus::Filed);
+
+    assert_eq!(
+        contract.try_record_claim_outcome(&pool_id, &true, &10_000),
+        Err(Ok(Error::InvalidAmount.into()))
+    );
+    contract.record_claim_outcome(&pool_id, &true, &2_500);
+    let policy = contract.get_insurance().unwrap();
+    assert_eq!(policy.claim_id, Some(7));
+    assert_eq!(policy.claim_status, ClaimStatus::Approved);
+}
+
+#[test]
+fn test_insurance_claim_requires_unrecovered_loss() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let pool_id = env.register(MockInsurancePool, ());
+    let platform_id = env.register(MockPlatform, ());
+    MockPlatformClient::new(&env, &platform_id).set_insurance_pool(&pool_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&client, &10_200);
+
+    contract.set_platform(&platform_id);
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &10_000, &3600);
+    contract.deposit_funds_insured(&client);
+    let milestone = contract.add_milestone(&client, &String::from_str(&env, "Design"), &4_000);
+    contract.approve_milestone(&client, &milestone);
+    contract.release_milestone(&freelancer, &milestone);
+    contract.dispute(&client);
+
+    // Everything, the released milestone included, is still held in escrow, so there is
+    // nothing the pool should cover
+    assert_eq!(
+        contract.try_file_insurance_claim(&client),
+        Err(Ok(Error::NoInsuredLoss.into()))
+    );
+
+    // A refunded client has recovered the funds and can't claim them again
+    contract.resolve_dispute(&arbitrator, &Symbol::new(&env, "client_wins"));
+    assert_eq!(
+        contract.try_file_insurance_claim(&client),
+        Err(Ok(Error::InvalidStatus.into()))
+    );
+    // The escrow is closed either way, so the pool can release the cover
+    assert!(contract.is_closed());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #28)")]
+fn test_uninsured_escrow_cannot_claim() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.deposit_funds(&client);
+    contract.dispute(&client);
+    contract.file_insurance_claim(&client);
+}
+
+#[test]
+fn test_high_value_escrow_requires_verified_parties() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let registry_id = env.register(UserRegistry, ());
+    let registry = UserRegistryClient::new(&env, &registry_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let registry_admin = Address::generate(&env);
+    let token = setup_token(&env);
+
+    registry.initialize_admin(&registry_admin);
+    registry.set_verification_level(&registry_admin, &client, &VerificationLevel::Premium, &0);
+    registry.set_verification_level(&registry_admin, &freelancer, &VerificationLevel::Basic, &0);
+
+    let platform_id = env.register(MockPlatform, ());
+    let policy = VerificationPolicy {
+        user_registry: registry_id.clone(),
+        min_amount: 500,
+        min_level: VerificationLevel::Verified as u32,
+    };
+    MockPlatformClient::new(&env, &platform_id).set_verification_policy(&policy);
+    contract.set_platform(&platform_id);
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    assert_eq!(contract.get_verification_policy(), Some(policy));
+
+    // The freelancer is below the required level
+    assert!(contract.try_deposit_funds(&client).is_err());
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Created);
+
+    registry.set_verification_level(
+        &registry_admin,
+        &freelancer,
+        &VerificationLevel::Verified,
+        &0,
+    );
+    contract.deposit_funds(&client);
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Funded);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #30)")]
+fn test_unverified_client_cannot_fund_high_value_escrow() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let registry_id = env.register(UserRegistry, ());
+    let registry = UserRegistryClient::new(&env, &registry_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    registry.initialize_admin(&Address::generate(&env));
+
+    let platform_id = env.register(MockPlatform, ());
+    MockPlatformClient::new(&env, &platform_id).set_verification_policy(&VerificationPolicy {
+        user_registry: registry_id,
+        min_amount: 100,
+        min_level: VerificationLevel::Basic as u32,
+    });
+    contract.set_platform(&platform_id);
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.deposit_funds(&client);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #30)")]
+fn test_top_up_over_threshold_requires_verified_parties() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let registry_id = env.register(UserRegistry, ());
+    let registry = UserRegistryClient::new(&env, &registry_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&client, &1_000);
+
+    registry.initialize_admin(&Address::generate(&env));
+
+    let platform_id = env.register(MockPlatform, ());
+    MockPlatformClient::new(&env, &platform_id).set_verification_policy(&VerificationPolicy {
+        user_registry: registry_id,
+        min_amount: 800,
+        min_level: VerificationLevel::Basic as u32,
+    });
+    contract.set_platform(&platform_id);
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+
+    // Below the threshold, unverified parties may fund
+    contract.deposit_funds(&client);
+
+    contract.propose_amendment(
+        &freelancer,
+        &AmendmentChanges {
+            top_up: 500,
+            deadline_extension_secs: 0,
+        },
+    );
+    contract.accept_amendment(&client);
+}
+
+#[contract]
+pub struct MockBondContract;
+
+#[contractimpl]
+impl MockBondContract {
+    pub fn set_bond(env: Env, user: Address, amount: i128) {
+        env.storage().instance().set(&user, &amount);
+    }
+
+    pub fn lock_bond(
+        env: Env,
+        escrow: Address,
+        user: Address,
+        amount: i128,
+    ) -> Result<(), crate::error::Error> {
+        escrow.require_auth();
+        let bond: i128 = env.storage().instance().get(&user).unwrap_or(0);
+        let amount = amount.max(100);
+        if bond < amount {
+            return Err(crate::error::Error::FreelancerNotBonded);
+        }
+        env.storage().instance().set(&escrow, &(user, amount));
+        Ok(())
+    }
+
+    pub fn get_lock(env: Env, escrow: Address) -> Option<(Address, i128)> {
+        env.storage().instance().get(&escrow)
+    }
+}
+
+#[test]
+fn test_high_value_escrow_requires_freelancer_bond() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let bond_id = env.register(MockBondContract, ());
+    let bond = MockBondContractClient::new(&env, &bond_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    let policy = BondPolicy {
+        bond_contract: bond_id.clone(),
+        min_amount: 500,
+        min_bond: 250,
+    };
+    contract.set_bond_policy(&client, &policy);
+    assert_eq!(contract.get_bond_policy(), Some(policy));
+
+    // The freelancer's bond is below the policy minimum
+    bond.set_bond(&freelancer, &200);
+    assert!(contract.try_deposit_funds(&client).is_err());
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Created);
+
+    bond.set_bond(&freelancer, &250);
+    contract.deposit_funds(&client);
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Funded);
+
+    // The bond is held for the escrow rather than only checked
+    assert_eq!(bond.get_lock(&contract_id), Some((freelancer, 250)));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #31)")]
+fn test_unbonded_freelancer_blocks_funding() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let bond_id = env.register(MockBondContract, ());
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.set_bond_policy(
+        &client,
+        &BondPolicy {
+            bond_contract: bond_id,
+            min_amount: 100,
+            min_bond: 0,
+        },
+    );
+    contract.deposit_funds(&client);
+}
+
+#[contract]
+pub struct MockComplianceRegistry;
+
+#[contractimpl]
+impl MockComplianceRegistry {
+    pub fn set_allowed(env: Env, address: Address, allowed: bool) {
+        env.storage().instance().set(&address, &allowed);
+    }
+
+    pub fn is_allowed(env: Env, address: Address, _operation: Symbol) -> bool {
+        env.storage().instance().get(&address).unwrap_or(false)
+    }
+}
+
+#[test]
+fn test_high_value_escrow_requires_compliant_parties() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let registry_id = env.register(MockComplianceRegistry, ());
+    let registry = MockComplianceRegistryClient::new(&env, &registry_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    let platform_id = env.register(MockPlatform, ());
+    let policy = CompliancePolicy {
+        compliance_registry: registry_id.clone(),
+        min_amount: 500,
+    };
+    MockPlatformClient::new(&env, &platform_id).set_compliance_policy(&policy);
+    contract.set_platform(&platform_id);
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    assert_eq!(contract.get_compliance_policy(), Some(policy));
+
+    // The freelancer has not been screened
+    registry.set_allowed(&client, &true);
+    assert!(contract.try_deposit_funds(&client).is_err());
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Created);
+
+    registry.set_allowed(&freelancer, &true);
+    contract.deposit_funds(&client);
+    assert_eq!(contract.get_escrow_data().state, EscrowState::Funded);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #32)")]
+fn test_disallowed_party_blocks_funding() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let registry_id = env.register(MockComplianceRegistry, ());
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    let platform_id = env.register(MockPlatform, ());
+    MockPlatformClient::new(&env, &platform_id).set_compliance_policy(&CompliancePolicy {
+        compliance_registry: registry_id,
+        min_amount: 100,
+    });
+    contract.set_platform(&platform_id);
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.deposit_funds(&client);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #32)")]
+fn test_top_up_over_threshold_requires_compliant_parties() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let registry_id = env.register(MockComplianceRegistry, ());
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&client, &1_000);
+
+    let platform_id = env.register(MockPlatform, ());
+    MockPlatformClient::new(&env, &platform_id).set_compliance_policy(&CompliancePolicy {
+        compliance_registry: registry_id,
+        min_amount: 800,
+    });
+    contract.set_platform(&platform_id);
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+
+    // Below the threshold, unscreened parties may fund
+    contract.deposit_funds(&client);
+
+    contract.propose_amendment(
+        &client,
+        &AmendmentChanges {
+            top_up: 500,
+            deadline_extension_secs: 0,
+        },
+    );
+    contract.accept_amendment(&freelancer);
+}
+
+#[contract]
+pub struct MockReferralContract;
+
+#[contractimpl]
+impl MockReferralContract {
+    pub fn record_escrow_completion(
+        env: Env,
+        escrow: Address,
+        client: Address,
+        freelancer: Address,
+        amount: i128,
+    ) -> i128 {
+        escrow.require_auth();
+        env.storage()
+            .instance()
+            .set(&escrow, &(client, freelancer, amount));
+        amount / 100
+    }
+
+    pub fn get_report(env: Env, escrow: Address) -> Option<(Address, Address, i128)> {
+        env.storage().instance().get(&escrow)
+    }
+}
+
+#[test]
+fn test_release_reports_completion_to_referral_contract() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let referral_id = env.register(MockReferralContract, ());
+    let referral = MockReferralContractClient::new(&env, &referral_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    let platform_id = env.register(MockPlatform, ());
+    MockPlatformClient::new(&env, &platform_id).set_referral_contract(&referral_id);
+    contract.set_platform(&platform_id);
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    assert_eq!(contract.get_referral_contract(), Some(referral_id.clone()));
+    contract.deposit_funds(&client);
+    assert_eq!(referral.get_report(&contract_id), None);
+
+    contract.release_funds(&freelancer);
+    assert_eq!(
+        referral.get_report(&contract_id),
+        Some((client, freelancer, 500))
+    );
+}
+
+#[test]
+fn test_rejected_referral_report_does_not_block_release() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    // The token mock has no referral entry point, so the report fails
+    let platform_id = env.register(MockPlatform, ());
+    MockPlatformClient::new(&env, &platform_id).set_referral_contract(&token);
+    contract.set_platform(&platform_id);
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.deposit_funds(&client);
+    contract.release_funds(&freelancer);
+    assert!(contract.is_completed());
+}
+
+#[contract]
+pub struct MockInvoiceContract;
+
+#[contractimpl]
+impl MockInvoiceContract {
+    pub fn mint_invoice(
+        env: Env,
+        escrow: Address,
+        _client: Address,
+        freelancer: Address,
+        asset: Option<Address>,
+        amount: i128,
+        _terms_hash: Option<BytesN<32>>,
+    ) -> u64 {
+        escrow.require_auth();
+        env.storage()
+            .instance()
+            .set(&escrow, &(freelancer, asset, amount));
+        1
+    }
+
+    pub fn get_minted(env: Env, escrow: Address) -> Option<(Address, Option<Address>, i128)> {
+        env.storage().instance().get(&escrow)
+    }
+}
+
+#[test]
+fn test_release_mints_invoice() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let invoice_id = env.register(MockInvoiceContract, ());
+    let invoices = MockInvoiceContractClient::new(&env, &invoice_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.set_invoice_contract(&client, &invoice_id);
+    assert_eq!(contract.get_invoice_contract(), Some(invoice_id.clone()));
+    contract.deposit_funds(&client);
+    assert_eq!(invoices.get_minted(&contract_id), None);
+
+    contract.release_funds(&freelancer);
+    assert_eq!(
+        invoices.get_minted(&contract_id),
+        Some((freelancer, Some(token), 500))
+    );
+}
+
+#[contract]
+pub struct MockCreditsContract;
+
+#[contractimpl]
+impl MockCreditsContract {
+    /// A stub accepts the draw without moving any tokens
+    pub fn set_stub(env: Env) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "stub"), &true);
+    }
+
+    pub fn draw(env: Env, escrow: Address, client: Address, token: Address, amount: i128) -> i128 {
+        escrow.require_auth();
+        client.require_auth();
+        if !env.storage().instance().has(&Symbol::new(&env, "stub")) {
+            TokenClient::new(&env, &token).transfer(
+                &env.current_contract_address(),
+                &escrow,
+                &amount,
+            );
+        }
+        0
+    }
+}
+
+fn setup_credits_escrow<'a>(
+    env: &'a Env,
+    credits_id: &Address,
+) -> (EscrowContractClient<'a>, Address, Address) {
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(env, &contract_id);
+    let platform_id = env.register(MockPlatform, ());
+    MockPlatformClient::new(env, &platform_id).set_credits_contract(credits_id);
+
+    let client = Address::generate(env);
+    let freelancer = Address::generate(env);
+    let arbitrator = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &token).mint(&client, &300);
+    StellarAssetClient::new(env, &token).mint(credits_id, &200);
+
+    contract.set_platform(&platform_id);
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    (contract, client, token)
+}
+
+#[test]
+fn test_deposit_funds_with_credits() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let credits_id = env.register(MockCreditsContract, ());
+    let (contract, client, token) = setup_credits_escrow(&env, &credits_id);
+    contract.deposit_funds_with_credits(&client, &200);
+
+    assert_eq!(TokenClient::new(&env, &token).balance(&contract.address), 500);
+    let data = env.as_contract(&contract.address, || crate::contract::get_escrow_data(&env));
+    assert_eq!(data.state, EscrowState::Funded);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #40)")]
+fn test_deposit_funds_with_credits_rejects_undelivered_draw() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let credits_id = env.register(MockCreditsContract, ());
+    MockCreditsContractClient::new(&env, &credits_id).set_stub();
+    let (contract, client, _) = setup_credits_escrow(&env, &credits_id);
+    contract.deposit_funds_with_credits(&client, &200);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #39)")]
+fn test_deposit_funds_with_credits_requires_platform_credits() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = setup_token(&env);
+
+    contract.set_platform(&env.register(MockPlatform, ()));
+    contract.init_contract_full(&client, &freelancer, &arbitrator, &token, &500, &3600);
+    contract.deposit_funds_with_credits(&client, &200);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_deposit_funds_with_credits_above_amount() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let credits_id = env.register(MockCreditsContract, ());
+    let (contract, client, _) = setup_credits_escrow(&env, &credits_id);
+    contract.deposit_funds_with_credits(&client, &600);
+}
+
+#[test]
+fn test_milestone_approver_replaces_client_approval() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let fee_manager = Address::generate(&env);
+    let approver = Address::generate(&env);
+
+    contract.init_contract(&client, &freelancer, &1000, &fee_manager);
+    contract.set_milestone_approver(&client, &approver);
+    assert_eq!(contract.get_milestone_approver(), Some(approver.clone()));
+    contract.deposit_funds(&client);
+
+    let milestone_id = contract.add_milestone(&client, &String::from_str(&env, "Design"), &500);
+    assert!(contract
+        .try_approve_milestone(&client, &milestone_id)
+        .is_err());
+
+    contract.approve_milestone(&approver, &milestone_id);
+    contract.release_milestone(&freelancer, &milestone_id);
+    assert!(contract.get_milestones().get(0).unwrap().released);
+}
+
+#[contract]
+pub struct MockOrganizationRegistry;
+
+#[contractimpl]
+impl MockOrganizationRegistry {
+    pub fn grant(env: Env, address: Address, action: u32) {
+        env.storage().instance().set(&(address, action), &true);
+    }
+
+    pub fn has_permission(env: Env, _org_id: u32, address: Address, action: u32) -> bool {
+        env.storage().instance().has(&(address, action))
+    }
+}
+
+#[test]
+fn test_organization_members_act_for_client() {
+    let env = setup_env();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EscrowContract, ());
+    let contract = EscrowContractClient::new(&env, &contract_id);
+    let registry_id = env.register(MockOrganizationRegistry, ());
+    let registry = MockOrganizationRegistryClient::new(&env, &registry_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let fee_manager = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let reviewer = Address::generate(&env);
+
+    contract.init_contract(&client, &freelancer, &1000, &fee_manager);
+    let link = OrganizationLink {
+        registry: registry_id.clone(),
+        org_id: 1,
+    };
+    // The client must itself be allowed to manage the organization's escrows
+    assert!(contract.try_set_organization(&client, &link).is_err());
+    registry.grant(&client, &1);
+    contract.set_organization(&client, &link);
+    assert_eq!(contract.get_organization(), Some(link));
+    contract.deposit_funds(&client);
+
+    registry.grant(&manager, &1);
+    registry.grant(&reviewer, &2);
+    let milestone_id = contract.add_milestone(&manager, &String::from_str(&env, "Design"), &500);
+    assert!(contract
+        .try_add_milestone(&reviewer, &String::from_str(&env, "Build"), &500)
+        .is_err());
+    assert!(contract
+        .try_approve_milestone(&manager, &milestone_id)
+        .is_err());
+
+    contract.approve_milestone(&reviewer, &milestone_id);
+    assert!(contract.get_milestones().get(0).unwrap().approved);
+}
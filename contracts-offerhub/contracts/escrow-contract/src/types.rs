@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -9,20 +9,22 @@ pub enum EscrowState {
     Released,
     Refunded,
     Disputed,
+    Cancelled,
 }
 
 impl EscrowState {
     pub fn can_transition_to(&self, next: &EscrowState) -> bool {
         use EscrowState::*;
-        match (self, next) {
-            (Created, Funded) => true,
-            (Funded, Released) => true,
-            (Funded, Refunded) => true,
-            (Funded, Disputed) => true,
-            (Disputed, Released) => true,
-            (Disputed, Refunded) => true,
-            _ => false,
-        }
+        matches!(
+            (self, next),
+            (Created, Funded)
+                | (Created, Cancelled)
+                | (Funded, Released)
+                | (Funded, Refunded)
+                | (Funded, Disputed)
+                | (Disputed, Released)
+                | (Disputed, Refunded)
+        )
     }
 }
 
@@ -81,6 +83,21 @@ pub struct EscrowData {
     pub net_amount: i128,     // Amount after fees
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Payee {
+    pub recipient: Address,
+    pub bps: u32, // Share of the release payout, out of 10_000
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamConfig {
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+    pub stopped_at: Option<u32>, // Ledger the client stopped the stream at
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ContractConfig {
@@ -114,3 +131,135 @@ pub struct EscrowSummary {
     pub created_at: u64,
     pub milestone_count: u32,
 }
+
+/// A full escrow setup plus the terms both parties commit to when creating it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TermsInitParams {
+    pub client: Address,
+    pub freelancer: Address,
+    pub arbitrator: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub timeout_secs: u64,
+    pub terms_hash: BytesN<32>,
+    pub deadline: u64,
+}
+
+/// What both parties signed off on when the escrow was created.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowTerms {
+    pub terms_hash: BytesN<32>,
+    pub amount: i128,
+    pub deadline: u64,
+    pub committed_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmendmentChanges {
+    pub top_up: i128,
+    pub deadline_extension_secs: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Amendment {
+    pub proposer: Address,
+    pub changes: AmendmentChanges,
+    pub proposed_at: u64,
+    pub accepted_at: Option<u64>,
+}
+
+/// What remains of a settled escrow after `archive_escrow`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivedEscrow {
+    pub client: Address,
+    pub freelancer: Address,
+    pub amount: i128,
+    pub released_amount: i128,
+    pub fee_collected: i128,
+    pub final_state: EscrowState,
+    pub terms_hash: Option<BytesN<32>>,
+    pub created_at: u64,
+    pub resolved_at: Option<u64>,
+    pub archived_at: u64,
+}
+
+/// How a dispute is settled if nobody resolves it within `max_duration_secs`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeTimeoutPolicy {
+    pub max_duration_secs: u64,
+    pub freelancer_bps: u32, // 0 refunds the client in full
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ClaimStatus {
+    NotFiled,
+    Filed,
+    Approved,
+    Rejected,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsurancePolicy {
+    pub pool: Address,
+    pub premium: i128,
+    pub coverage: i128,
+    pub claimed: i128,
+    pub insured_at: u64,
+    pub claim_id: Option<u32>,
+    pub claim_status: ClaimStatus,
+}
+
+/// Verification both parties need in the user registry to fund an escrow of at least
+/// `min_amount`. `min_level` is the registry's `VerificationLevel` discriminant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationPolicy {
+    pub user_registry: Address,
+    pub min_amount: i128,
+    pub min_level: u32,
+}
+
+/// Active bond the freelancer needs in the bond contract to fund an escrow of at least
+/// `min_amount`, locked until the escrow closes. A `min_bond` of 0 defers to the bond
+/// contract's own minimum.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondPolicy {
+    pub bond_contract: Address,
+    pub min_amount: i128,
+    pub min_bond: i128,
+}
+
+/// Compliance registry both parties must be allowed to `fund` by for an escrow of at least
+/// `min_amount`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompliancePolicy {
+    pub compliance_registry: Address,
+    pub min_amount: i128,
+}
+
+/// Organization in the registry at `registry` that owns the escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizationLink {
+    pub registry: Address,
+    pub org_id: u32,
+}
+
+/// Outcome handed to the escrow by whichever arbitration implementation is plugged in.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitrationDecision {
+    pub freelancer_bps: u32,
+    pub reason: String,
+}
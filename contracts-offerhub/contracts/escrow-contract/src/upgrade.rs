@@ -0,0 +1,37 @@
+use offerhub_upgradeable::{Migratable, UpgradeError, Upgradeable};
+use soroban_sdk::{Address, Env};
+
+use crate::{error::Error, storage::ESCROW_DATA, types::EscrowData, EscrowContract};
+
+/// Storage layout version of this build
+pub const CONTRACT_VERSION: u32 = 1;
+
+impl Upgradeable for EscrowContract {
+    type Error = Error;
+    const VERSION: u32 = CONTRACT_VERSION;
+
+    // The code holds both parties' funds, so the client proposes and the freelancer co-signs
+    fn authorize_upgrade(env: &Env, caller: &Address) -> Result<(), Error> {
+        caller.require_auth();
+        let escrow_data: EscrowData = env
+            .storage()
+            .instance()
+            .get(&ESCROW_DATA)
+            .ok_or(Error::NotInitialized)?;
+        if escrow_data.client != *caller {
+            return Err(Error::Unauthorized);
+        }
+        escrow_data.freelancer.require_auth();
+        Ok(())
+    }
+}
+
+impl Migratable for EscrowContract {
+    fn migrate_from(_env: &Env, from_version: u32) -> Result<(), Error> {
+        match from_version {
+            // Escrows from before version tracking already use the v1 layout
+            offerhub_upgradeable::UNVERSIONED => Ok(()),
+            _ => Err(UpgradeError::DowngradeNotSupported.into()),
+        }
+    }
+}
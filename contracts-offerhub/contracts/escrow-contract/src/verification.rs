@@ -0,0 +1,38 @@
+use soroban_sdk::{Env, IntoVal, Symbol};
+
+use crate::{
+    error::{handle_error, Error},
+    platform::{platform_setting, PLATFORM_VERIFICATION_POLICY},
+    types::{EscrowData, VerificationPolicy},
+};
+
+const REGISTRY_REQUIRE_MIN_VERIFICATION: &str = "require_min_verification";
+
+/// The platform's verification policy. The platform owns the threshold and registry so a
+/// client cannot opt its escrow out of the check.
+pub fn get_verification_policy(env: &Env) -> Option<VerificationPolicy> {
+    platform_setting(env, PLATFORM_VERIFICATION_POLICY)
+}
+
+/// Fails with `InsufficientVerification` if the escrow falls under the verification policy
+/// and either party lacks the required level. Checked on every inflow, including amendment
+/// top-ups that lift the escrow over the threshold.
+pub fn require_verified_parties(env: &Env, escrow_data: &EscrowData) {
+    let Some(policy) = get_verification_policy(env) else {
+        return;
+    };
+    if escrow_data.amount < policy.min_amount {
+        return;
+    }
+
+    for party in [&escrow_data.client, &escrow_data.freelancer] {
+        let result = env.try_invoke_contract::<(), Error>(
+            &policy.user_registry,
+            &Symbol::new(env, REGISTRY_REQUIRE_MIN_VERIFICATION),
+            (party.clone(), policy.min_level).into_val(env),
+        );
+        if !matches!(result, Ok(Ok(()))) {
+            handle_error(env, Error::InsufficientVerification);
+        }
+    }
+}
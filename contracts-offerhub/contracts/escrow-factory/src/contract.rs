@@ -1,19 +1,27 @@
 use crate::error::handle_error;
 use crate::storage;
 use crate::types::{
-    EscrowCreateParams, MilestoneCreateParams, MilestoneCreateResult, MilestoneParams,
+    CompliancePolicy, DisputeTimeoutPolicy, EscrowCreateParams, EscrowTemplate, JobEscrowParams,
+    MilestoneCreateParams, MilestoneCreateResult, MilestoneParams, TemplateEscrowParams,
+    VerificationPolicy,
 };
 
 use crate::{error::Error, types::DisputeParams};
-use soroban_sdk::{BytesN, Symbol, symbol_short};
+use offerhub_events::{ActionEvent, CONTRACT_INITIALIZED};
+use offerhub_pausable::PAUSED;
+use soroban_sdk::{xdr::ToXdr, Bytes, BytesN, IntoVal, Symbol, symbol_short};
 use soroban_sdk::{Address, Env, Vec};
 
 const MAX_BATCH_SIZE: u32 = 100;
-const PAUSED: Symbol = symbol_short!("PAUSED");
-#[allow(dead_code)]
+const MAX_PAGE_SIZE: u32 = 100;
+const ESCROW_INIT_FULL: &str = "init_contract_full";
+const ESCROW_SET_REVIEW_WINDOW: &str = "set_review_window";
+const ESCROW_GET_DISPUTE_TIMEOUT: &str = "get_dispute_timeout";
+const ESCROW_SET_DISPUTE_TIMEOUT: &str = "set_dispute_timeout";
+const ESCROW_SET_PLATFORM: &str = "set_platform";
+const REGISTRY_REGISTER: &str = "register_instance";
 const ADMIN: Symbol = symbol_short!("ADMIN");
 
-#[allow(dead_code)]
 pub fn initialize(env: &Env, admin: Address) {
     if env.storage().instance().has(&ADMIN) {
         handle_error(env, Error::AlreadyInitialized);
@@ -26,57 +34,21 @@ pub fn initialize(env: &Env, admin: Address) {
     env.storage().instance().set(&PAUSED, &false);
     
     env.events().publish(
-        (Symbol::new(env, "contract_initialized"), admin),
-        env.ledger().timestamp(),
+        (Symbol::new(env, CONTRACT_INITIALIZED), admin),
+        ActionEvent::now(env),
     );
 }
 
 pub fn is_paused(env: &Env) -> bool {
-    env.storage().instance().get(&PAUSED).unwrap_or(false)
-}
-
-#[allow(dead_code)]
-pub fn pause(env: &Env, admin: Address) -> Result<(), Error> {
-    admin.require_auth();
-    let stored_admin: Address = env.storage().instance().get(&ADMIN).unwrap_or_else(|| handle_error(env, Error::NotInitialized));
-    if stored_admin != admin {
-        return Err(Error::Unauthorized);
-    }
-    
-    if is_paused(env) {
-        return Err(Error::AlreadyPaused);
-    }
-    
-    env.storage().instance().set(&PAUSED, &true);
-    
-    env.events().publish(
-        (Symbol::new(env, "contract_paused"), admin),
-        env.ledger().timestamp(),
-    );
-    
-    Ok(())
+    offerhub_pausable::is_paused(env)
 }
 
-#[allow(dead_code)]
-pub fn unpause(env: &Env, admin: Address) -> Result<(), Error> {
-    admin.require_auth();
-    let stored_admin: Address = env.storage().instance().get(&ADMIN).unwrap_or_else(|| handle_error(env, Error::NotInitialized));
-    if stored_admin != admin {
-        return Err(Error::Unauthorized);
-    }
-    
-    if !is_paused(env) {
-        return Err(Error::NotPaused);
-    }
-    
-    env.storage().instance().set(&PAUSED, &false);
-    
-    env.events().publish(
-        (Symbol::new(env, "contract_unpaused"), admin),
-        env.ledger().timestamp(),
-    );
-    
-    Ok(())
+// The factory admin administers pausing
+pub(crate) fn pause_admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&ADMIN)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized))
 }
 
 pub fn upload_escrow_wasm(env: Env, wasm_hash: BytesN<32>) {
@@ -111,10 +83,11 @@ pub fn deploy_new_escrow(env: Env, create_params: EscrowCreateParams) -> Address
     let next_escrow_id = storage::next_escrow_id(&env);
 
     // Deploy escrow
+    let wasm_hash = wasm_hash.unwrap();
     let escrow_address = env
         .deployer()
         .with_current_contract(create_params.salt)
-        .deploy_v2(wasm_hash.unwrap(), ());
+        .deploy_v2(wasm_hash.clone(), ());
 
     // TODO: Initialize escrow contract (commented out due to WASM import issues)
     // let escrow_client = crate::escrow_contract::Client::new(&env, &escrow_address);
@@ -127,12 +100,523 @@ pub fn deploy_new_escrow(env: Env, create_params: EscrowCreateParams) -> Address
 
     storage::store_escrow(&env, &next_escrow_id, &escrow_address);
     storage::set_next_escrow_id(&env, next_escrow_id + 1);
+    register_with_registry(
+        &env,
+        &escrow_address,
+        wasm_hash,
+        &create_params.client,
+        &create_params.freelancer,
+    );
 
     env.events().publish((Symbol::new(&env ,"deployed_new_escrow") ,escrow_address.clone()), env.ledger().timestamp());
 
     escrow_address
 }
 
+pub fn update_escrow_wasm(env: Env, admin: Address, wasm_hash: BytesN<32>) {
+    require_admin(&env, &admin);
+
+    storage::store_escrow_wasm(&env, wasm_hash.clone());
+
+    env.events().publish(
+        (Symbol::new(&env, "escrow_wasm_updated"), admin),
+        (wasm_hash, env.ledger().timestamp()),
+    );
+}
+
+pub fn set_registry(env: Env, admin: Address, registry: Address) {
+    require_admin(&env, &admin);
+
+    storage::store_registry(&env, &registry);
+
+    env.events().publish(
+        (Symbol::new(&env, "registry_set"), registry),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_registry(env: Env) -> Option<Address> {
+    storage::get_registry(&env)
+}
+
+pub fn get_admin(env: Env) -> Option<Address> {
+    env.storage().instance().get(&ADMIN)
+}
+
+pub fn set_emergency_contract(env: Env, admin: Address, emergency_contract: Address) {
+    require_admin(&env, &admin);
+
+    storage::store_emergency_contract(&env, &emergency_contract);
+
+    env.events().publish(
+        (
+            Symbol::new(&env, "emergency_contract_set"),
+            emergency_contract,
+        ),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_emergency_contract(env: Env) -> Option<Address> {
+    storage::get_emergency_contract(&env)
+}
+
+pub fn set_dispute_contract(env: Env, admin: Address, dispute_contract: Address) {
+    require_admin(&env, &admin);
+
+    storage::store_dispute_contract(&env, &dispute_contract);
+
+    env.events().publish(
+        (Symbol::new(&env, "dispute_contract_set"), dispute_contract),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_dispute_contract(env: Env) -> Option<Address> {
+    storage::get_dispute_contract(&env)
+}
+
+pub fn set_insurance_pool(env: Env, admin: Address, insurance_pool: Address) {
+    require_admin(&env, &admin);
+
+    storage::store_insurance_pool(&env, &insurance_pool);
+
+    env.events().publish(
+        (Symbol::new(&env, "insurance_pool_set"), insurance_pool),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_insurance_pool(env: Env) -> Option<Address> {
+    storage::get_insurance_pool(&env)
+}
+
+pub fn set_fee_manager(env: Env, admin: Address, fee_manager: Address) {
+    require_admin(&env, &admin);
+
+    storage::store_fee_manager(&env, &fee_manager);
+
+    env.events().publish(
+        (Symbol::new(&env, "fee_manager_set"), fee_manager),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_fee_manager(env: Env) -> Option<Address> {
+    storage::get_fee_manager(&env)
+}
+
+pub fn set_credits_contract(env: Env, admin: Address, credits_contract: Address) {
+    require_admin(&env, &admin);
+
+    storage::store_credits_contract(&env, &credits_contract);
+
+    env.events().publish(
+        (Symbol::new(&env, "credits_contract_set"), credits_contract),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_credits_contract(env: Env) -> Option<Address> {
+    storage::get_credits_contract(&env)
+}
+
+/// Escrows read this when funded or topped up, so a change applies to live escrows too.
+pub fn set_verification_policy(env: Env, admin: Address, policy: VerificationPolicy) {
+    require_admin(&env, &admin);
+
+    if policy.min_amount < 0 {
+        handle_error(&env, Error::InvalidAmountSet)
+    }
+    storage::store_verification_policy(&env, &policy);
+
+    env.events().publish(
+        (
+            Symbol::new(&env, "verification_policy_set"),
+            policy.user_registry,
+        ),
+        (policy.min_amount, policy.min_level, env.ledger().timestamp()),
+    );
+}
+
+pub fn get_verification_policy(env: Env) -> Option<VerificationPolicy> {
+    storage::get_verification_policy(&env)
+}
+
+/// Like the verification policy, escrows read this on every deposit and top-up.
+pub fn set_compliance_policy(env: Env, admin: Address, policy: CompliancePolicy) {
+    require_admin(&env, &admin);
+
+    if policy.min_amount < 0 {
+        handle_error(&env, Error::InvalidAmountSet)
+    }
+    storage::store_compliance_policy(&env, &policy);
+
+    env.events().publish(
+        (
+            Symbol::new(&env, "compliance_policy_set"),
+            policy.compliance_registry,
+        ),
+        (policy.min_amount, env.ledger().timestamp()),
+    );
+}
+
+pub fn get_compliance_policy(env: Env) -> Option<CompliancePolicy> {
+    storage::get_compliance_policy(&env)
+}
+
+/// Escrows report their completion here, so every party's referrer accrues rewards
+/// regardless of which client funded the escrow.
+pub fn set_referral_contract(env: Env, admin: Address, referral_contract: Address) {
+    require_admin(&env, &admin);
+
+    storage::store_referral_contract(&env, &referral_contract);
+
+    env.events().publish(
+        (
+            Symbol::new(&env, "referral_contract_set"),
+            referral_contract,
+        ),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_referral_contract(env: Env) -> Option<Address> {
+    storage::get_referral_contract(&env)
+}
+
+/// Links a freshly deployed escrow to this factory so it reads platform settings from here.
+/// Must happen before the escrow is initialized.
+fn link_platform(env: &Env, escrow_address: &Address) {
+    env.invoke_contract::<()>(
+        escrow_address,
+        &Symbol::new(env, ESCROW_SET_PLATFORM),
+        (env.current_contract_address(),).into_val(env),
+    );
+}
+
+/// Records a deployment in the escrow registry, when one is set, under the wasm it runs.
+fn register_with_registry(
+    env: &Env,
+    escrow_address: &Address,
+    wasm_hash: BytesN<32>,
+    client: &Address,
+    freelancer: &Address,
+) {
+    if let Some(registry) = storage::get_registry(env) {
+        env.invoke_contract::<u32>(
+            &registry,
+            &Symbol::new(env, REGISTRY_REGISTER),
+            (
+                escrow_address.clone(),
+                wasm_hash,
+                client.clone(),
+                freelancer.clone(),
+            )
+                .into_val(env),
+        );
+    }
+}
+
+/// Salt for an engagement's escrow, so its address can be known before deployment.
+fn job_salt(env: &Env, client: &Address, freelancer: &Address, job_id: u32) -> BytesN<32> {
+    let mut preimage = Bytes::new(env);
+    preimage.append(&client.clone().to_xdr(env));
+    preimage.append(&freelancer.clone().to_xdr(env));
+    preimage.extend_from_array(&job_id.to_be_bytes());
+    env.crypto().sha256(&preimage).into()
+}
+
+pub fn predict_job_escrow_address(
+    env: Env,
+    client: Address,
+    freelancer: Address,
+    job_id: u32,
+) -> Address {
+    env.deployer()
+        .with_current_contract(job_salt(&env, &client, &freelancer, job_id))
+        .deployed_address()
+}
+
+/// Validates an engagement and deploys its (uninitialized) escrow at the predicted address.
+fn deploy_job_contract(
+    env: &Env,
+    client: &Address,
+    freelancer: &Address,
+    job_id: u32,
+    amount: i128,
+) -> Address {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+
+    client.require_auth();
+
+    if amount <= 0 {
+        handle_error(env, Error::InvalidAmountSet)
+    }
+
+    if client == freelancer {
+        handle_error(env, Error::AddressesShouldNotMatch)
+    }
+
+    if storage::escrow_addr_by_job(env, client, freelancer, job_id).is_some() {
+        handle_error(env, Error::JobEscrowExists)
+    }
+
+    // Deployed escrows pay fees to the fee manager they read from this factory
+    if storage::get_fee_manager(env).is_none() {
+        handle_error(env, Error::FeeManagerNotSet)
+    }
+
+    let wasm_hash =
+        storage::get_escrow_wasm(env).unwrap_or_else(|| handle_error(env, Error::WasmKeyError));
+
+    env.deployer()
+        .with_current_contract(job_salt(env, client, freelancer, job_id))
+        .deploy_v2(wasm_hash, ())
+}
+
+/// Registers a deployed engagement escrow under its id, job and parties.
+fn index_job_escrow(
+    env: &Env,
+    client: &Address,
+    freelancer: &Address,
+    job_id: u32,
+    escrow_address: &Address,
+) {
+    let escrow_id = storage::next_escrow_id(env);
+    storage::store_escrow(env, &escrow_id, escrow_address);
+    storage::set_next_escrow_id(env, escrow_id + 1);
+    storage::store_job_escrow(env, client, freelancer, job_id, escrow_address);
+    if let Some(wasm_hash) = storage::get_escrow_wasm(env) {
+        register_with_registry(env, escrow_address, wasm_hash, client, freelancer);
+    }
+
+    env.events().publish(
+        (
+            Symbol::new(env, "job_escrow_deployed"),
+            escrow_address.clone(),
+        ),
+        (
+            client.clone(),
+            freelancer.clone(),
+            job_id,
+            env.ledger().timestamp(),
+        ),
+    );
+}
+
+pub fn deploy_job_escrow(env: Env, params: JobEscrowParams) -> Address {
+    let escrow_address = deploy_job_contract(
+        &env,
+        &params.client,
+        &params.freelancer,
+        params.job_id,
+        params.amount,
+    );
+    link_platform(&env, &escrow_address);
+    init_job_escrow(&env, &escrow_address, &params);
+
+    index_job_escrow(
+        &env,
+        &params.client,
+        &params.freelancer,
+        params.job_id,
+        &escrow_address,
+    );
+
+    escrow_address
+}
+
+/// Initializes a linked escrow with the deal's token; fees go to the factory's fee manager.
+pub(crate) fn init_job_escrow(env: &Env, escrow_address: &Address, params: &JobEscrowParams) {
+    env.invoke_contract::<()>(
+        escrow_address,
+        &Symbol::new(env, ESCROW_INIT_FULL),
+        (
+            params.client.clone(),
+            params.freelancer.clone(),
+            params.arbitrator.clone(),
+            params.token.clone(),
+            params.amount,
+            params.timeout_secs,
+        )
+            .into_val(env),
+    );
+}
+
+fn require_admin(env: &Env, admin: &Address) {
+    admin.require_auth();
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&ADMIN)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    if stored_admin != *admin {
+        handle_error(env, Error::Unauthorized);
+    }
+}
+
+pub fn set_template(env: Env, admin: Address, name: Symbol, template: EscrowTemplate) {
+    require_admin(&env, &admin);
+
+    storage::store_template(&env, &name, &template);
+
+    env.events().publish(
+        (Symbol::new(&env, "template_set"), name),
+        (
+            template.review_window_secs,
+            template.dispute_window_secs,
+            env.ledger().timestamp(),
+        ),
+    );
+}
+
+pub fn remove_template(env: Env, admin: Address, name: Symbol) {
+    require_admin(&env, &admin);
+
+    if storage::get_template(&env, &name).is_none() {
+        handle_error(&env, Error::TemplateNotFound)
+    }
+    storage::remove_template(&env, &name);
+
+    env.events().publish(
+        (Symbol::new(&env, "template_removed"), name),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_template(env: Env, name: Symbol) -> Option<EscrowTemplate> {
+    storage::get_template(&env, &name)
+}
+
+/// Deploys an engagement escrow configured from the current defaults of a named template.
+pub fn deploy_escrow_from_template(env: Env, params: TemplateEscrowParams) -> Address {
+    let template = storage::get_template(&env, &params.template)
+        .unwrap_or_else(|| handle_error(&env, Error::TemplateNotFound));
+
+    if !template.allowed_assets.is_empty() && !template.allowed_assets.contains(&params.token) {
+        handle_error(&env, Error::AssetNotAllowed)
+    }
+
+    let escrow_address = deploy_job_contract(
+        &env,
+        &params.client,
+        &params.freelancer,
+        params.job_id,
+        params.amount,
+    );
+    link_platform(&env, &escrow_address);
+    init_template_escrow(&env, &escrow_address, &params, &template);
+
+    index_job_escrow(
+        &env,
+        &params.client,
+        &params.freelancer,
+        params.job_id,
+        &escrow_address,
+    );
+
+    env.events().publish(
+        (
+            Symbol::new(&env, "template_escrow_deployed"),
+            escrow_address.clone(),
+        ),
+        (params.template, env.ledger().timestamp()),
+    );
+
+    escrow_address
+}
+
+/// Initializes a linked escrow and applies the template's review and dispute windows.
+/// The dispute window replaces only the timeout duration; the escrow's default split stays.
+pub(crate) fn init_template_escrow(
+    env: &Env,
+    escrow_address: &Address,
+    params: &TemplateEscrowParams,
+    template: &EscrowTemplate,
+) {
+    env.invoke_contract::<()>(
+        escrow_address,
+        &Symbol::new(env, ESCROW_INIT_FULL),
+        (
+            params.client.clone(),
+            params.freelancer.clone(),
+            params.arbitrator.clone(),
+            params.token.clone(),
+            params.amount,
+            params.timeout_secs,
+        )
+            .into_val(env),
+    );
+    env.invoke_contract::<()>(
+        escrow_address,
+        &Symbol::new(env, ESCROW_SET_REVIEW_WINDOW),
+        (params.client.clone(), template.review_window_secs).into_val(env),
+    );
+
+    let mut policy: DisputeTimeoutPolicy = env.invoke_contract(
+        escrow_address,
+        &Symbol::new(env, ESCROW_GET_DISPUTE_TIMEOUT),
+        Vec::new(env),
+    );
+    policy.max_duration_secs = template.dispute_window_secs;
+    env.invoke_contract::<()>(
+        escrow_address,
+        &Symbol::new(env, ESCROW_SET_DISPUTE_TIMEOUT),
+        (params.client.clone(), policy).into_val(env),
+    );
+}
+
+pub fn get_job_escrow(
+    env: Env,
+    client: Address,
+    freelancer: Address,
+    job_id: u32,
+) -> Option<Address> {
+    storage::escrow_addr_by_job(&env, &client, &freelancer, job_id)
+}
+
+fn paginate(env: &Env, escrows: Vec<Address>, offset: u32, limit: u32) -> Vec<Address> {
+    let end = offset
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(escrows.len());
+
+    let mut page = Vec::new(env);
+    for i in offset..end {
+        page.push_back(escrows.get(i).unwrap());
+    }
+    page
+}
+
+pub fn get_escrows_by_party(env: Env, party: Address, offset: u32, limit: u32) -> Vec<Address> {
+    paginate(&env, storage::escrows_by_party(&env, &party), offset, limit)
+}
+
+pub fn get_escrows_by_client(env: Env, client: Address, offset: u32, limit: u32) -> Vec<Address> {
+    paginate(
+        &env,
+        storage::escrows_by_client(&env, &client),
+        offset,
+        limit,
+    )
+}
+
+pub fn get_escrows_by_freelancer(
+    env: Env,
+    freelancer: Address,
+    offset: u32,
+    limit: u32,
+) -> Vec<Address> {
+    paginate(
+        &env,
+        storage::escrows_by_freelancer(&env, &freelancer),
+        offset,
+        limit,
+    )
+}
+
 pub fn batch_deploy(env: Env, params: Vec<EscrowCreateParams>) -> Vec<Address> {
     if is_paused(&env) {
         handle_error(&env, Error::ContractPaused);
@@ -352,6 +836,7 @@ pub fn batch_archive_escrows(env: Env, escrow_ids: Vec<u32>) -> Vec<u32> {
 
         // For now, archive all escrows
         archived_escrows.push_back(escrow_id.clone());
+        storage::unindex_closed_escrow(&env, &escrow_address);
         storage::archive_escrow(&env, escrow_id, escrow_address);
     }
 
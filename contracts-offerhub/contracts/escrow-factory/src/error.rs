@@ -1,3 +1,4 @@
+use offerhub_pausable::PauseError;
 use soroban_sdk::{contracterror, panic_with_error, Env};
 
 #[contracterror]
@@ -16,6 +17,20 @@ pub enum Error {
     ContractPaused = 11,
     AlreadyPaused = 12,
     NotPaused = 13,
+    JobEscrowExists = 14,
+    TemplateNotFound = 15,
+    AssetNotAllowed = 16,
+    FeeManagerNotSet = 17,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
 }
 
 pub fn handle_error(env: &Env, error: Error) -> ! {
@@ -5,10 +5,12 @@ mod error;
 mod storage;
 mod types;
 
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+use error::Error;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Symbol, Vec};
 use types::{
-    DisputeParams, EscrowCreateParams, EscrowData, EscrowStatus, MilestoneCreateParams,
-    MilestoneCreateResult, MilestoneParams,
+    CompliancePolicy, DisputeParams, EscrowCreateParams, EscrowData, EscrowStatus, EscrowTemplate,
+    JobEscrowParams, MilestoneCreateParams, MilestoneCreateResult, MilestoneParams,
+    TemplateEscrowParams, VerificationPolicy,
 };
 
 // TODO: Fix WASM import issues
@@ -21,16 +23,162 @@ use types::{
 #[contract]
 pub struct EscrowFactory;
 
+offerhub_pausable::impl_pausable!(EscrowFactory, Error, |env, _| Ok(contract::pause_admin(env)));
+
 #[contractimpl]
 impl EscrowFactory {
     pub fn __constructor(env: Env, wasm_hash: BytesN<32>) {
         contract::upload_escrow_wasm(env, wasm_hash);
     }
 
+    pub fn initialize(env: Env, admin: Address) {
+        contract::initialize(&env, admin);
+    }
+
+    pub fn update_escrow_wasm(env: Env, admin: Address, wasm_hash: BytesN<32>) {
+        contract::update_escrow_wasm(env, admin, wasm_hash);
+    }
+
+    pub fn set_registry(env: Env, admin: Address, registry: Address) {
+        contract::set_registry(env, admin, registry);
+    }
+
+    pub fn get_registry(env: Env) -> Option<Address> {
+        contract::get_registry(env)
+    }
+
+    pub fn get_admin(env: Env) -> Option<Address> {
+        contract::get_admin(env)
+    }
+
+    pub fn set_emergency_contract(env: Env, admin: Address, emergency_contract: Address) {
+        contract::set_emergency_contract(env, admin, emergency_contract);
+    }
+
+    pub fn get_emergency_contract(env: Env) -> Option<Address> {
+        contract::get_emergency_contract(env)
+    }
+
+    pub fn set_dispute_contract(env: Env, admin: Address, dispute_contract: Address) {
+        contract::set_dispute_contract(env, admin, dispute_contract);
+    }
+
+    pub fn get_dispute_contract(env: Env) -> Option<Address> {
+        contract::get_dispute_contract(env)
+    }
+
+    pub fn set_insurance_pool(env: Env, admin: Address, insurance_pool: Address) {
+        contract::set_insurance_pool(env, admin, insurance_pool);
+    }
+
+    pub fn get_insurance_pool(env: Env) -> Option<Address> {
+        contract::get_insurance_pool(env)
+    }
+
+    pub fn set_fee_manager(env: Env, admin: Address, fee_manager: Address) {
+        contract::set_fee_manager(env, admin, fee_manager);
+    }
+
+    pub fn get_fee_manager(env: Env) -> Option<Address> {
+        contract::get_fee_manager(env)
+    }
+
+    pub fn set_credits_contract(env: Env, admin: Address, credits_contract: Address) {
+        contract::set_credits_contract(env, admin, credits_contract);
+    }
+
+    pub fn get_credits_contract(env: Env) -> Option<Address> {
+        contract::get_credits_contract(env)
+    }
+
+    pub fn set_verification_policy(env: Env, admin: Address, policy: VerificationPolicy) {
+        contract::set_verification_policy(env, admin, policy);
+    }
+
+    pub fn get_verification_policy(env: Env) -> Option<VerificationPolicy> {
+        contract::get_verification_policy(env)
+    }
+
+    pub fn set_compliance_policy(env: Env, admin: Address, policy: CompliancePolicy) {
+        contract::set_compliance_policy(env, admin, policy);
+    }
+
+    pub fn get_compliance_policy(env: Env) -> Option<CompliancePolicy> {
+        contract::get_compliance_policy(env)
+    }
+
+    pub fn set_referral_contract(env: Env, admin: Address, referral_contract: Address) {
+        contract::set_referral_contract(env, admin, referral_contract);
+    }
+
+    pub fn get_referral_contract(env: Env) -> Option<Address> {
+        contract::get_referral_contract(env)
+    }
+
     pub fn deploy_new_escrow(env: Env, create_params: EscrowCreateParams) -> Address {
         contract::deploy_new_escrow(env, create_params)
     }
 
+    pub fn deploy_job_escrow(env: Env, params: JobEscrowParams) -> Address {
+        contract::deploy_job_escrow(env, params)
+    }
+
+    pub fn predict_job_escrow_address(
+        env: Env,
+        client: Address,
+        freelancer: Address,
+        job_id: u32,
+    ) -> Address {
+        contract::predict_job_escrow_address(env, client, freelancer, job_id)
+    }
+
+    pub fn get_job_escrow(
+        env: Env,
+        client: Address,
+        freelancer: Address,
+        job_id: u32,
+    ) -> Option<Address> {
+        contract::get_job_escrow(env, client, freelancer, job_id)
+    }
+
+    pub fn get_escrows_by_party(env: Env, party: Address, offset: u32, limit: u32) -> Vec<Address> {
+        contract::get_escrows_by_party(env, party, offset, limit)
+    }
+
+    pub fn set_template(env: Env, admin: Address, name: Symbol, template: EscrowTemplate) {
+        contract::set_template(env, admin, name, template);
+    }
+
+    pub fn remove_template(env: Env, admin: Address, name: Symbol) {
+        contract::remove_template(env, admin, name);
+    }
+
+    pub fn get_template(env: Env, name: Symbol) -> Option<EscrowTemplate> {
+        contract::get_template(env, name)
+    }
+
+    pub fn deploy_escrow_from_template(env: Env, params: TemplateEscrowParams) -> Address {
+        contract::deploy_escrow_from_template(env, params)
+    }
+
+    pub fn get_escrows_by_client(
+        env: Env,
+        client: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Address> {
+        contract::get_escrows_by_client(env, client, offset, limit)
+    }
+
+    pub fn get_escrows_by_freelancer(
+        env: Env,
+        freelancer: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Address> {
+        contract::get_escrows_by_freelancer(env, freelancer, offset, limit)
+    }
+
     pub fn batch_deploy(env: Env, params: Vec<EscrowCreateParams>) -> Vec<Address> {
         contract::batch_deploy(env, params)
     }
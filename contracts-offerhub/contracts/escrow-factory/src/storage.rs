@@ -1,5 +1,6 @@
 // use soroban_sdk::{Address, BytesN, ConversionError, Env, IntoVal, TryFromVal, Val};
-use soroban_sdk::{contracttype, Address, BytesN, Env};
+use crate::types::{CompliancePolicy, EscrowTemplate, VerificationPolicy};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Symbol, Vec};
 
 /// Keys for contract storage.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -15,6 +16,36 @@ pub enum DataKey {
     EscrowIdByAddr(Address),
     /// ArchivedEscrows
     Archives(u32),
+    /// (client, freelancer, job_id) -> escrow contract address
+    EscrowByJob(Address, Address, u32),
+    /// party address -> escrow contract addresses it takes part in
+    EscrowsByParty(Address),
+    /// template name -> escrow defaults
+    Template(Symbol),
+    /// client address -> its open escrows
+    EscrowsByClient(Address),
+    /// freelancer address -> its open escrows
+    EscrowsByFreelancer(Address),
+    /// escrow contract address -> (client, freelancer)
+    EscrowParties(Address),
+    /// Registry recording each deployment's wasm version
+    Registry,
+    /// Platform circuit breaker escrows consult for emergency withdrawals
+    EmergencyContract,
+    /// Dispute-resolution contract allowed to settle disputed escrows
+    DisputeContract,
+    /// Insurance pool escrows buy cover from
+    InsurancePool,
+    /// Fee manager pricing the platform fee on every escrow release
+    FeeManager,
+    /// Prepaid credits contract escrows draw funding from
+    CreditsContract,
+    /// Verification escrows require of both parties above a funding threshold
+    VerificationPolicy,
+    /// Compliance screening escrows require of both parties above a funding threshold
+    CompliancePolicy,
+    /// Referral contract escrows report completions to
+    ReferralContract,
 }
 
 /// Read next ID (defaults to 0 if unset).
@@ -45,6 +76,132 @@ pub fn get_escrow_wasm(e: &Env) -> Option<BytesN<32>> {
         .instance()
         .get::<DataKey, BytesN<32>>(&DataKey::EscrowWasm)
 }
+/// Get the escrow registry, if one is set.
+pub fn get_registry(e: &Env) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::Registry)
+}
+
+/// Set the escrow registry.
+pub fn store_registry(e: &Env, registry: &Address) {
+    e.storage()
+        .instance()
+        .set::<DataKey, Address>(&DataKey::Registry, registry);
+}
+
+/// Get the platform emergency contract, if one is set.
+pub fn get_emergency_contract(e: &Env) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::EmergencyContract)
+}
+
+/// Set the platform emergency contract.
+pub fn store_emergency_contract(e: &Env, emergency_contract: &Address) {
+    e.storage()
+        .instance()
+        .set::<DataKey, Address>(&DataKey::EmergencyContract, emergency_contract);
+}
+
+/// Get the platform dispute contract, if one is set.
+pub fn get_dispute_contract(e: &Env) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::DisputeContract)
+}
+
+/// Set the platform dispute contract.
+pub fn store_dispute_contract(e: &Env, dispute_contract: &Address) {
+    e.storage()
+        .instance()
+        .set::<DataKey, Address>(&DataKey::DisputeContract, dispute_contract);
+}
+
+/// Get the platform insurance pool, if one is set.
+pub fn get_insurance_pool(e: &Env) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::InsurancePool)
+}
+
+/// Set the platform insurance pool.
+pub fn store_insurance_pool(e: &Env, insurance_pool: &Address) {
+    e.storage()
+        .instance()
+        .set::<DataKey, Address>(&DataKey::InsurancePool, insurance_pool);
+}
+
+/// Get the platform fee manager, if one is set.
+pub fn get_fee_manager(e: &Env) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::FeeManager)
+}
+
+/// Set the platform fee manager.
+pub fn store_fee_manager(e: &Env, fee_manager: &Address) {
+    e.storage()
+        .instance()
+        .set::<DataKey, Address>(&DataKey::FeeManager, fee_manager);
+}
+
+/// Get the platform prepaid credits contract, if one is set.
+pub fn get_credits_contract(e: &Env) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::CreditsContract)
+}
+
+/// Set the platform prepaid credits contract.
+pub fn store_credits_contract(e: &Env, credits_contract: &Address) {
+    e.storage()
+        .instance()
+        .set::<DataKey, Address>(&DataKey::CreditsContract, credits_contract);
+}
+
+/// Get the platform verification policy, if one is set.
+pub fn get_verification_policy(e: &Env) -> Option<VerificationPolicy> {
+    e.storage()
+        .instance()
+        .get::<DataKey, VerificationPolicy>(&DataKey::VerificationPolicy)
+}
+
+/// Set the platform verification policy.
+pub fn store_verification_policy(e: &Env, policy: &VerificationPolicy) {
+    e.storage()
+        .instance()
+        .set::<DataKey, VerificationPolicy>(&DataKey::VerificationPolicy, policy);
+}
+
+/// Get the platform compliance policy, if one is set.
+pub fn get_compliance_policy(e: &Env) -> Option<CompliancePolicy> {
+    e.storage()
+        .instance()
+        .get::<DataKey, CompliancePolicy>(&DataKey::CompliancePolicy)
+}
+
+/// Set the platform compliance policy.
+pub fn store_compliance_policy(e: &Env, policy: &CompliancePolicy) {
+    e.storage()
+        .instance()
+        .set::<DataKey, CompliancePolicy>(&DataKey::CompliancePolicy, policy);
+}
+
+/// Get the platform referral contract, if one is set.
+pub fn get_referral_contract(e: &Env) -> Option<Address> {
+    e.storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::ReferralContract)
+}
+
+/// Set the platform referral contract.
+pub fn store_referral_contract(e: &Env, referral_contract: &Address) {
+    e.storage()
+        .instance()
+        .set::<DataKey, Address>(&DataKey::ReferralContract, referral_contract);
+}
+
 /// Set next ID.
 pub fn set_next_escrow_id(e: &Env, next: u32) {
     e.storage()
@@ -78,3 +235,125 @@ pub fn is_archived(e: &Env, id: u32) -> bool {
         .get::<DataKey, Address>(&DataKey::Archives(id))
         .is_some()
 }
+
+/// Lookup the escrow deployed for an engagement.
+pub fn escrow_addr_by_job(
+    e: &Env,
+    client: &Address,
+    freelancer: &Address,
+    job_id: u32,
+) -> Option<Address> {
+    e.storage()
+        .persistent()
+        .get::<DataKey, Address>(&DataKey::EscrowByJob(
+            client.clone(),
+            freelancer.clone(),
+            job_id,
+        ))
+}
+
+/// Save the engagement mapping and index the escrow under both parties and their roles.
+pub fn store_job_escrow(
+    e: &Env,
+    client: &Address,
+    freelancer: &Address,
+    job_id: u32,
+    addr: &Address,
+) {
+    let s = e.storage().persistent();
+    s.set::<DataKey, Address>(
+        &DataKey::EscrowByJob(client.clone(), freelancer.clone(), job_id),
+        addr,
+    );
+
+    for party in [client, freelancer] {
+        let key = DataKey::EscrowsByParty(party.clone());
+        let mut escrows = s
+            .get::<DataKey, Vec<Address>>(&key)
+            .unwrap_or_else(|| Vec::new(e));
+        escrows.push_back(addr.clone());
+        s.set::<DataKey, Vec<Address>>(&key, &escrows);
+    }
+
+    for key in [
+        DataKey::EscrowsByClient(client.clone()),
+        DataKey::EscrowsByFreelancer(freelancer.clone()),
+    ] {
+        let mut escrows = s
+            .get::<DataKey, Vec<Address>>(&key)
+            .unwrap_or_else(|| Vec::new(e));
+        escrows.push_back(addr.clone());
+        s.set::<DataKey, Vec<Address>>(&key, &escrows);
+    }
+    s.set::<DataKey, (Address, Address)>(
+        &DataKey::EscrowParties(addr.clone()),
+        &(client.clone(), freelancer.clone()),
+    );
+}
+
+/// Drop a closed escrow from its parties' role indexes.
+pub fn unindex_closed_escrow(e: &Env, addr: &Address) {
+    let s = e.storage().persistent();
+    let Some((client, freelancer)) =
+        s.get::<DataKey, (Address, Address)>(&DataKey::EscrowParties(addr.clone()))
+    else {
+        return;
+    };
+
+    for key in [
+        DataKey::EscrowsByClient(client),
+        DataKey::EscrowsByFreelancer(freelancer),
+    ] {
+        let mut escrows = s
+            .get::<DataKey, Vec<Address>>(&key)
+            .unwrap_or_else(|| Vec::new(e));
+        if let Some(index) = escrows.first_index_of(addr) {
+            escrows.remove(index);
+            s.set::<DataKey, Vec<Address>>(&key, &escrows);
+        }
+    }
+}
+
+/// Open escrows where the address is the client, in deployment order.
+pub fn escrows_by_client(e: &Env, client: &Address) -> Vec<Address> {
+    e.storage()
+        .persistent()
+        .get::<DataKey, Vec<Address>>(&DataKey::EscrowsByClient(client.clone()))
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// Open escrows where the address is the freelancer, in deployment order.
+pub fn escrows_by_freelancer(e: &Env, freelancer: &Address) -> Vec<Address> {
+    e.storage()
+        .persistent()
+        .get::<DataKey, Vec<Address>>(&DataKey::EscrowsByFreelancer(freelancer.clone()))
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// All escrows a party takes part in, in deployment order.
+pub fn escrows_by_party(e: &Env, party: &Address) -> Vec<Address> {
+    e.storage()
+        .persistent()
+        .get::<DataKey, Vec<Address>>(&DataKey::EscrowsByParty(party.clone()))
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// Lookup a named escrow template.
+pub fn get_template(e: &Env, name: &Symbol) -> Option<EscrowTemplate> {
+    e.storage()
+        .persistent()
+        .get::<DataKey, EscrowTemplate>(&DataKey::Template(name.clone()))
+}
+
+/// Create or replace a named escrow template.
+pub fn store_template(e: &Env, name: &Symbol, template: &EscrowTemplate) {
+    e.storage()
+        .persistent()
+        .set::<DataKey, EscrowTemplate>(&DataKey::Template(name.clone()), template);
+}
+
+pub fn remove_template(e: &Env, name: &Symbol) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Template(name.clone()));
+}
@@ -1,10 +1,16 @@
 #![cfg(test)]
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, Vec};
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, Symbol, Vec};
 
 use rand::rngs::OsRng;
 use rand::RngCore;
 
-use crate::types::EscrowCreateParams;
+use crate::contract;
+use crate::storage;
+use crate::types::{
+    CompliancePolicy, EscrowCreateParams, EscrowTemplate, JobEscrowParams, TemplateEscrowParams,
+    VerificationPolicy,
+};
+use escrow_contract::{EscrowContract, EscrowContractClient};
 use crate::EscrowFactory;
 use crate::EscrowFactoryClient;
 
@@ -108,6 +114,428 @@ fn test_batch_operations_structure() {
     assert_eq!(batch_params.get(2).unwrap().amount, 1200);
 }
 
+#[test]
+fn test_predict_job_escrow_address_is_deterministic() {
+    let env = Env::default();
+    let contract_id = env.register(EscrowFactory, (BytesN::from_array(&env, &[0u8; 32]),));
+    let factory = EscrowFactoryClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+
+    let first = factory.predict_job_escrow_address(&client, &freelancer, &7);
+    assert_eq!(
+        first,
+        factory.predict_job_escrow_address(&client, &freelancer, &7)
+    );
+    assert_ne!(
+        first,
+        factory.predict_job_escrow_address(&client, &freelancer, &8)
+    );
+    assert_ne!(
+        first,
+        factory.predict_job_escrow_address(&freelancer, &client, &7)
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+fn test_deploy_job_escrow_rejects_existing_job() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowFactory, (BytesN::from_array(&env, &[0u8; 32]),));
+    let factory = EscrowFactoryClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let existing = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        storage::store_job_escrow(&env, &client, &freelancer, 1, &existing);
+    });
+    assert_eq!(
+        factory.get_job_escrow(&client, &freelancer, &1),
+        Some(existing)
+    );
+
+    factory.deploy_job_escrow(&JobEscrowParams {
+        client,
+        freelancer,
+        arbitrator: Address::generate(&env),
+        token: Address::generate(&env),
+        job_id: 1,
+        amount: 1000,
+        timeout_secs: 3600,
+    });
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_deploy_job_escrow_rejects_matching_parties() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowFactory, (BytesN::from_array(&env, &[0u8; 32]),));
+    let factory = EscrowFactoryClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    factory.deploy_job_escrow(&JobEscrowParams {
+        client: client.clone(),
+        freelancer: client,
+        arbitrator: Address::generate(&env),
+        token: Address::generate(&env),
+        job_id: 1,
+        amount: 1000,
+        timeout_secs: 3600,
+    });
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #17)")]
+fn test_deploy_job_escrow_requires_fee_manager() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowFactory, (BytesN::from_array(&env, &[0u8; 32]),));
+    let factory = EscrowFactoryClient::new(&env, &contract_id);
+
+    factory.deploy_job_escrow(&JobEscrowParams {
+        client: Address::generate(&env),
+        freelancer: Address::generate(&env),
+        arbitrator: Address::generate(&env),
+        token: Address::generate(&env),
+        job_id: 1,
+        amount: 1000,
+        timeout_secs: 3600,
+    });
+}
+
+#[test]
+fn test_job_escrow_holds_token_and_platform_fee_manager() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowFactory, (BytesN::from_array(&env, &[0u8; 32]),));
+    let factory = EscrowFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    factory.initialize(&admin);
+    let fee_manager = Address::generate(&env);
+    factory.set_fee_manager(&admin, &fee_manager);
+
+    // Stands in for the deployed wasm, already linked to the factory
+    let escrow = EscrowContractClient::new(&env, &env.register(EscrowContract, ()));
+    escrow.set_platform(&contract_id);
+
+    let token = Address::generate(&env);
+    let params = JobEscrowParams {
+        client: Address::generate(&env),
+        freelancer: Address::generate(&env),
+        arbitrator: Address::generate(&env),
+        token: token.clone(),
+        job_id: 1,
+        amount: 1000,
+        timeout_secs: 3600,
+    };
+    env.as_contract(&contract_id, || {
+        contract::init_job_escrow(&env, &escrow.address, &params);
+    });
+
+    assert_eq!(escrow.get_escrow_data().token, Some(token));
+    assert_eq!(escrow.get_fee_manager(), Some(fee_manager));
+}
+
+#[test]
+fn test_get_escrows_by_party_paginates() {
+    let env = Env::default();
+    let contract_id = env.register(EscrowFactory, (BytesN::from_array(&env, &[0u8; 32]),));
+    let factory = EscrowFactoryClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let mut escrows = Vec::new(&env);
+    for job_id in 0..5 {
+        let escrow = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            storage::store_job_escrow(&env, &client, &freelancer, job_id, &escrow);
+        });
+        escrows.push_back(escrow);
+    }
+
+    let page = factory.get_escrows_by_party(&client, &0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap(), escrows.get(0).unwrap());
+    assert_eq!(page.get(1).unwrap(), escrows.get(1).unwrap());
+
+    let last = factory.get_escrows_by_party(&freelancer, &4, &2);
+    assert_eq!(last.len(), 1);
+    assert_eq!(last.get(0).unwrap(), escrows.get(4).unwrap());
+
+    assert_eq!(factory.get_escrows_by_party(&client, &10, &2).len(), 0);
+    assert_eq!(
+        factory
+            .get_escrows_by_party(&Address::generate(&env), &0, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_get_escrows_by_role_drops_archived() {
+    let env = Env::default();
+    let contract_id = env.register(EscrowFactory, (BytesN::from_array(&env, &[0u8; 32]),));
+    let factory = EscrowFactoryClient::new(&env, &contract_id);
+
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let mut escrows = Vec::new(&env);
+    for job_id in 0..3 {
+        let escrow = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            storage::store_escrow(&env, &job_id, &escrow);
+            storage::store_job_escrow(&env, &client, &freelancer, job_id, &escrow);
+        });
+        escrows.push_back(escrow);
+    }
+
+    assert_eq!(factory.get_escrows_by_client(&client, &0, &10), escrows);
+    assert_eq!(
+        factory.get_escrows_by_freelancer(&freelancer, &0, &10),
+        escrows
+    );
+    assert_eq!(factory.get_escrows_by_client(&freelancer, &0, &10).len(), 0);
+    assert_eq!(factory.get_escrows_by_freelancer(&client, &0, &10).len(), 0);
+
+    factory.batch_archive_escrows(&vec![&env, 1]);
+
+    let open = factory.get_escrows_by_client(&client, &0, &10);
+    assert_eq!(open.len(), 2);
+    assert_eq!(open.get(0).unwrap(), escrows.get(0).unwrap());
+    assert_eq!(open.get(1).unwrap(), escrows.get(2).unwrap());
+
+    let page = factory.get_escrows_by_freelancer(&freelancer, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap(), escrows.get(2).unwrap());
+
+    // The party index keeps the full history
+    assert_eq!(factory.get_escrows_by_party(&client, &0, &10).len(), 3);
+}
+
+fn sample_template(env: &Env, allowed_asset: &Address) -> EscrowTemplate {
+    EscrowTemplate {
+        review_window_secs: 3 * 24 * 60 * 60,
+        dispute_window_secs: 14 * 24 * 60 * 60,
+        allowed_assets: vec![env, allowed_asset.clone()],
+    }
+}
+
+#[test]
+fn test_admin_manages_templates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowFactory, (BytesN::from_array(&env, &[0u8; 32]),));
+    let factory = EscrowFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    factory.initialize(&admin);
+
+    let name = Symbol::new(&env, "retainer");
+    let template = sample_template(&env, &Address::generate(&env));
+    factory.set_template(&admin, &name, &template);
+    assert_eq!(factory.get_template(&name), Some(template.clone()));
+
+    let mut tuned = template;
+    tuned.review_window_secs = 24 * 60 * 60;
+    factory.set_template(&admin, &name, &tuned);
+    assert_eq!(factory.get_template(&name), Some(tuned));
+
+    factory.remove_template(&admin, &name);
+    assert_eq!(factory.get_template(&name), None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+fn test_set_template_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowFactory, (BytesN::from_array(&env, &[0u8; 32]),));
+    let factory = EscrowFactoryClient::new(&env, &contract_id);
+
+    factory.initialize(&Address::generate(&env));
+    factory.set_template(
+        &Address::generate(&env),
+        &Symbol::new(&env, "retainer"),
+        &sample_template(&env, &Address::generate(&env)),
+    );
+}
+
+#[test]
+fn test_template_escrow_gets_template_dispute_window() {
+    let env = Env::default();
+    // The parties authorize the escrow calls below the factory frame
+    env.mock_all_auths_allowing_non_root_auth();
+    let contract_id = env.register(EscrowFactory, (BytesN::from_array(&env, &[0u8; 32]),));
+    let token = Address::generate(&env);
+    let template = sample_template(&env, &token);
+
+    // Stands in for the deployed wasm, already linked to the factory
+    let escrow = EscrowContractClient::new(&env, &env.register(EscrowContract, ()));
+    escrow.set_platform(&contract_id);
+
+    let params = TemplateEscrowParams {
+        template: Symbol::new(&env, "retainer"),
+        client: Address::generate(&env),
+        freelancer: Address::generate(&env),
+        arbitrator: Address::generate(&env),
+        token,
+        job_id: 1,
+        amount: 1000,
+        timeout_secs: 3600,
+    };
+    env.as_contract(&contract_id, || {
+        contract::init_template_escrow(&env, &escrow.address, &params, &template);
+    });
+
+    let policy = escrow.get_dispute_timeout();
+    assert_eq!(policy.max_duration_secs, template.dispute_window_secs);
+    assert_eq!(policy.freelancer_bps, 5_000);
+    assert_eq!(escrow.get_escrow_data().timeout_secs, Some(3600));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_deploy_from_template_rejects_disallowed_asset() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowFactory, (BytesN::from_array(&env, &[0u8; 32]),));
+    let factory = EscrowFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    factory.initialize(&admin);
+    let name = Symbol::new(&env, "fixed");
+    factory.set_template(
+        &admin,
+        &name,
+        &sample_template(&env, &Address::generate(&env)),
+    );
+
+    factory.deploy_escrow_from_template(&TemplateEscrowParams {
+        template: name,
+        client: Address::generate(&env),
+        freelancer: Address::generate(&env),
+        arbitrator: Address::generate(&env),
+        token: Address::generate(&env),
+        job_id: 1,
+        amount: 1000,
+        timeout_secs: 3600,
+    });
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #15)")]
+fn test_deploy_from_unknown_template_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowFactory, (BytesN::from_array(&env, &[0u8; 32]),));
+    let factory = EscrowFactoryClient::new(&env, &contract_id);
+
+    factory.deploy_escrow_from_template(&TemplateEscrowParams {
+        template: Symbol::new(&env, "missing"),
+        client: Address::generate(&env),
+        freelancer: Address::generate(&env),
+        arbitrator: Address::generate(&env),
+        token: Address::generate(&env),
+        job_id: 1,
+        amount: 1000,
+        timeout_secs: 3600,
+    });
+}
+
+#[test]
+fn test_admin_sets_registry_and_escrow_wasm() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowFactory, (BytesN::from_array(&env, &[0u8; 32]),));
+    let factory = EscrowFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    factory.initialize(&admin);
+    assert_eq!(factory.get_admin(), Some(admin.clone()));
+    assert_eq!(factory.get_registry(), None);
+
+    let registry = Address::generate(&env);
+    factory.set_registry(&admin, &registry);
+    assert_eq!(factory.get_registry(), Some(registry));
+
+    let emergency = Address::generate(&env);
+    assert_eq!(factory.get_emergency_contract(), None);
+    factory.set_emergency_contract(&admin, &emergency);
+    assert_eq!(factory.get_emergency_contract(), Some(emergency));
+
+    let dispute = Address::generate(&env);
+    factory.set_dispute_contract(&admin, &dispute);
+    assert_eq!(factory.get_dispute_contract(), Some(dispute));
+
+    let insurance_pool = Address::generate(&env);
+    factory.set_insurance_pool(&admin, &insurance_pool);
+    assert_eq!(factory.get_insurance_pool(), Some(insurance_pool));
+
+    let fee_manager = Address::generate(&env);
+    factory.set_fee_manager(&admin, &fee_manager);
+    assert_eq!(factory.get_fee_manager(), Some(fee_manager));
+
+    let credits_contract = Address::generate(&env);
+    factory.set_credits_contract(&admin, &credits_contract);
+    assert_eq!(factory.get_credits_contract(), Some(credits_contract));
+
+    let referral_contract = Address::generate(&env);
+    factory.set_referral_contract(&admin, &referral_contract);
+    assert_eq!(factory.get_referral_contract(), Some(referral_contract));
+
+    let verification_policy = VerificationPolicy {
+        user_registry: Address::generate(&env),
+        min_amount: 5000,
+        min_level: 1,
+    };
+    assert_eq!(factory.get_verification_policy(), None);
+    factory.set_verification_policy(&admin, &verification_policy);
+    assert_eq!(factory.get_verification_policy(), Some(verification_policy));
+
+    let compliance_policy = CompliancePolicy {
+        compliance_registry: Address::generate(&env),
+        min_amount: 5000,
+    };
+    assert_eq!(factory.get_compliance_policy(), None);
+    factory.set_compliance_policy(&admin, &compliance_policy);
+    assert_eq!(factory.get_compliance_policy(), Some(compliance_policy));
+
+    let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+    factory.update_escrow_wasm(&admin, &wasm_hash);
+    env.as_contract(&contract_id, || {
+        assert_eq!(storage::get_escrow_wasm(&env), Some(wasm_hash));
+    });
+}
+
+#[test]
+fn test_factory_pause_via_pauser() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EscrowFactory, (BytesN::from_array(&env, &[0u8; 32]),));
+    let factory = EscrowFactoryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let emergency = Address::generate(&env);
+    factory.initialize(&admin);
+
+    factory.pause(&admin);
+    assert!(factory.is_paused());
+    factory.unpause(&admin);
+
+    assert!(factory.try_set_paused(&emergency, &true).is_err());
+    factory.add_pauser(&admin, &emergency);
+    factory.set_paused(&emergency, &true);
+    assert!(factory.is_paused());
+    assert!(factory.try_set_paused(&emergency, &false).is_err());
+    factory.set_paused(&admin, &false);
+    assert!(!factory.is_paused());
+}
+
 // Helper function to setup test environment
 #[allow(dead_code)]
 fn setup_env() -> (Env, Address, Address, Address, Address, BytesN<32>) {
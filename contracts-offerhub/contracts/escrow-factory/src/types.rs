@@ -10,6 +10,68 @@ pub struct EscrowCreateParams {
     pub salt: BytesN<32>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct JobEscrowParams {
+    pub client: Address,
+    pub freelancer: Address,
+    pub arbitrator: Address,
+    pub token: Address,
+    pub job_id: u32,
+    pub amount: i128,
+    pub timeout_secs: u64,
+}
+
+/// Admin-tuned defaults applied to escrows created from a named template.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EscrowTemplate {
+    pub review_window_secs: u64,
+    pub dispute_window_secs: u64,
+    /// Tokens escrows from this template may hold; empty allows any.
+    pub allowed_assets: Vec<Address>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TemplateEscrowParams {
+    pub template: Symbol,
+    pub client: Address,
+    pub freelancer: Address,
+    pub arbitrator: Address,
+    pub token: Address,
+    pub job_id: u32,
+    pub amount: i128,
+    pub timeout_secs: u64,
+}
+
+/// Mirrors the escrow's dispute timeout policy so the factory can apply a template's window.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DisputeTimeoutPolicy {
+    pub max_duration_secs: u64,
+    pub freelancer_bps: u32,
+}
+
+/// Mirrors the escrow's verification policy: both parties need `min_level` in the user
+/// registry to fund an escrow of at least `min_amount`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VerificationPolicy {
+    pub user_registry: Address,
+    pub min_amount: i128,
+    pub min_level: u32,
+}
+
+/// Mirrors the escrow's compliance policy: both parties must be allowed to `fund` by
+/// `compliance_registry` for an escrow of at least `min_amount`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CompliancePolicy {
+    pub compliance_registry: Address,
+    pub min_amount: i128,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct DisputeParams {
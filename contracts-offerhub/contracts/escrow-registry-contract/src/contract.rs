@@ -0,0 +1,188 @@
+use crate::error::Error;
+use crate::event::{instance_migrated, instance_registered, registry_initialized, version_added};
+use crate::storage::{
+    add_instance_entry, add_version_entry, get_admin, get_factory, get_instance,
+    get_instance_count, get_instance_entry, get_latest_version, get_version_count,
+    get_version_entry, get_version_of, get_wasm_of, set_admin, set_factory, set_instance,
+    version_for,
+};
+use crate::types::EscrowInstance;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+const MAX_PAGE_SIZE: u32 = 50;
+
+pub struct EscrowRegistryContract;
+
+impl EscrowRegistryContract {
+    pub fn initialize(env: Env, admin: Address, factory: Address) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        set_factory(&env, &factory);
+        registry_initialized(&env, admin, factory);
+        Ok(())
+    }
+
+    pub fn get_factory(env: Env) -> Result<Address, Error> {
+        get_factory(&env).ok_or(Error::NotInitialized)
+    }
+
+    /// Record an escrow the factory just deployed and return its version. Only the
+    /// factory can register.
+    pub fn register_instance(
+        env: Env,
+        escrow: Address,
+        wasm_hash: BytesN<32>,
+        client: Address,
+        freelancer: Address,
+    ) -> Result<u32, Error> {
+        Self::require_not_paused(&env)?;
+        let factory = get_factory(&env).ok_or(Error::NotInitialized)?;
+        factory.require_auth();
+
+        if get_instance(&env, &escrow).is_some() {
+            return Err(Error::InstanceAlreadyRegistered);
+        }
+
+        let version = Self::resolve_version(&env, &wasm_hash);
+        set_instance(
+            &env,
+            &EscrowInstance {
+                escrow: escrow.clone(),
+                version,
+                wasm_hash,
+                client: client.clone(),
+                freelancer: freelancer.clone(),
+                created_ledger: env.ledger().sequence(),
+                created_at: env.ledger().timestamp(),
+                migrated_at: None,
+            },
+        );
+        add_instance_entry(&env, &escrow);
+        add_version_entry(&env, version, &escrow);
+
+        instance_registered(&env, escrow, version, client, freelancer);
+        Ok(version)
+    }
+
+    /// Move an upgraded escrow to the version of the wasm it now runs (admin only)
+    pub fn record_migration(
+        env: Env,
+        admin: Address,
+        escrow: Address,
+        wasm_hash: BytesN<32>,
+    ) -> Result<u32, Error> {
+        Self::require_not_paused(&env)?;
+        Self::require_admin(&env, &admin)?;
+
+        let mut instance = get_instance(&env, &escrow).ok_or(Error::InstanceNotFound)?;
+        if instance.wasm_hash == wasm_hash {
+            return Err(Error::AlreadyOnVersion);
+        }
+
+        let from_version = instance.version;
+        instance.version = Self::resolve_version(&env, &wasm_hash);
+        instance.wasm_hash = wasm_hash;
+        instance.migrated_at = Some(env.ledger().timestamp());
+        set_instance(&env, &instance);
+        add_version_entry(&env, instance.version, &escrow);
+
+        instance_migrated(&env, escrow, from_version, instance.version);
+        Ok(instance.version)
+    }
+
+    pub fn get_instance(env: Env, escrow: Address) -> Result<EscrowInstance, Error> {
+        get_instance(&env, &escrow).ok_or(Error::InstanceNotFound)
+    }
+
+    pub fn latest_version(env: Env) -> u32 {
+        get_latest_version(&env)
+    }
+
+    pub fn get_version(env: Env, wasm_hash: BytesN<32>) -> Option<u32> {
+        get_version_of(&env, &wasm_hash)
+    }
+
+    pub fn get_wasm_hash(env: Env, version: u32) -> Option<BytesN<32>> {
+        get_wasm_of(&env, version)
+    }
+
+    /// Page through every registered escrow, oldest first
+    pub fn get_instances(env: Env, offset: u32, limit: u32) -> Vec<EscrowInstance> {
+        let end = offset
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(get_instance_count(&env));
+
+        let mut instances = Vec::new(&env);
+        for position in offset..end {
+            if let Some(instance) =
+                get_instance_entry(&env, position).and_then(|escrow| get_instance(&env, &escrow))
+            {
+                instances.push_back(instance);
+            }
+        }
+        instances
+    }
+
+    /// Page through the escrows listed under a version, oldest first. Escrows that have
+    /// since migrated away keep their slot in the listing but are left out of the page,
+    /// so a page can come back shorter than `limit`.
+    pub fn get_instances_by_version(
+        env: Env,
+        version: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowInstance> {
+        let end = offset
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(get_version_count(&env, version));
+
+        let mut instances = Vec::new(&env);
+        for position in offset..end {
+            if let Some(instance) = get_version_entry(&env, version, position)
+                .and_then(|escrow| get_instance(&env, &escrow))
+                .filter(|i| i.version == version)
+            {
+                instances.push_back(instance);
+            }
+        }
+        instances
+    }
+
+    /// Number of listing slots under a version, for paging with `get_instances_by_version`
+    pub fn count_by_version(env: Env, version: u32) -> u32 {
+        get_version_count(&env, version)
+    }
+
+    fn resolve_version(env: &Env, wasm_hash: &BytesN<32>) -> u32 {
+        let known = get_version_of(env, wasm_hash).is_some();
+        let version = version_for(env, wasm_hash);
+        if !known {
+            version_added(env, version, wasm_hash.clone());
+        }
+        version
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        admin.require_auth();
+        let stored = get_admin(env).ok_or(Error::NotInitialized)?;
+        if *admin != stored {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// Escrow instance is already registered
+    InstanceAlreadyRegistered = 4,
+    /// Escrow instance is not registered
+    InstanceNotFound = 5,
+    /// Escrow instance already runs this wasm
+    AlreadyOnVersion = 6,
+    /// Contract is already paused
+    AlreadyPaused = 7,
+    /// Contract is not paused
+    NotPaused = 8,
+    /// Contract is paused
+    ContractPaused = 9,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+pub fn registry_initialized(e: &Env, admin: Address, factory: Address) {
+    let topics = (Symbol::new(e, "registry_initialized"), admin);
+    e.events().publish(topics, factory);
+}
+
+pub fn version_added(e: &Env, version: u32, wasm_hash: BytesN<32>) {
+    let topics = (Symbol::new(e, "version_added"), version);
+    e.events().publish(topics, wasm_hash);
+}
+
+pub fn instance_registered(
+    e: &Env,
+    escrow: Address,
+    version: u32,
+    client: Address,
+    freelancer: Address,
+) {
+    let topics = (Symbol::new(e, "instance_registered"), escrow);
+    e.events().publish(topics, (version, client, freelancer));
+}
+
+pub fn instance_migrated(e: &Env, escrow: Address, from_version: u32, to_version: u32) {
+    let topics = (Symbol::new(e, "instance_migrated"), escrow);
+    e.events().publish(topics, (from_version, to_version));
+}
@@ -0,0 +1,95 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::EscrowRegistryContract;
+pub use crate::error::Error;
+pub use crate::types::EscrowInstance;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| {
+    EscrowRegistryContract::pause_admin(env)
+});
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the registry with an admin and the escrow factory allowed to register
+    pub fn initialize(env: Env, admin: Address, factory: Address) -> Result<(), Error> {
+        EscrowRegistryContract::initialize(env, admin, factory)
+    }
+
+    /// Get the escrow factory address
+    pub fn get_factory(env: Env) -> Result<Address, Error> {
+        EscrowRegistryContract::get_factory(env)
+    }
+
+    /// Record a newly deployed escrow and return its version (factory only)
+    pub fn register_instance(
+        env: Env,
+        escrow: Address,
+        wasm_hash: BytesN<32>,
+        client: Address,
+        freelancer: Address,
+    ) -> Result<u32, Error> {
+        EscrowRegistryContract::register_instance(env, escrow, wasm_hash, client, freelancer)
+    }
+
+    /// Record that an escrow was upgraded to new wasm (admin only)
+    pub fn record_migration(
+        env: Env,
+        admin: Address,
+        escrow: Address,
+        wasm_hash: BytesN<32>,
+    ) -> Result<u32, Error> {
+        EscrowRegistryContract::record_migration(env, admin, escrow, wasm_hash)
+    }
+
+    /// Get a registered escrow
+    pub fn get_instance(env: Env, escrow: Address) -> Result<EscrowInstance, Error> {
+        EscrowRegistryContract::get_instance(env, escrow)
+    }
+
+    /// Get the highest version number assigned so far
+    pub fn latest_version(env: Env) -> u32 {
+        EscrowRegistryContract::latest_version(env)
+    }
+
+    /// Get the version number of a wasm hash
+    pub fn get_version(env: Env, wasm_hash: BytesN<32>) -> Option<u32> {
+        EscrowRegistryContract::get_version(env, wasm_hash)
+    }
+
+    /// Get the wasm hash of a version number
+    pub fn get_wasm_hash(env: Env, version: u32) -> Option<BytesN<32>> {
+        EscrowRegistryContract::get_wasm_hash(env, version)
+    }
+
+    /// Page through every registered escrow
+    pub fn get_instances(env: Env, offset: u32, limit: u32) -> Vec<EscrowInstance> {
+        EscrowRegistryContract::get_instances(env, offset, limit)
+    }
+
+    /// Page through the escrows currently running a version
+    pub fn get_instances_by_version(
+        env: Env,
+        version: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowInstance> {
+        EscrowRegistryContract::get_instances_by_version(env, version, offset, limit)
+    }
+
+    /// Get the number of listing slots under a version
+    pub fn count_by_version(env: Env, version: u32) -> u32 {
+        EscrowRegistryContract::count_by_version(env, version)
+    }
+}
@@ -0,0 +1,129 @@
+use crate::types::EscrowInstance;
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Factory,
+    LatestVersion,
+    /// wasm hash -> version number
+    VersionOf(BytesN<32>),
+    /// version number -> wasm hash
+    WasmOf(u32),
+    Instance(Address),
+    InstanceCount,
+    /// position -> escrow, in registration order
+    InstanceEntry(u32),
+    /// version -> number of escrows that have been listed under it
+    VersionCount(u32),
+    /// (version, position) -> escrow
+    VersionEntry(u32, u32),
+}
+
+/// Get the admin address
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_factory(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Factory)
+}
+
+pub fn set_factory(env: &Env, factory: &Address) {
+    env.storage().instance().set(&DataKey::Factory, factory);
+}
+
+pub fn get_latest_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::LatestVersion)
+        .unwrap_or(0)
+}
+
+pub fn get_version_of(env: &Env, wasm_hash: &BytesN<32>) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VersionOf(wasm_hash.clone()))
+}
+
+pub fn get_wasm_of(env: &Env, version: u32) -> Option<BytesN<32>> {
+    env.storage().persistent().get(&DataKey::WasmOf(version))
+}
+
+/// Version number of a wasm hash, assigning the next one if it has not been seen yet
+pub fn version_for(env: &Env, wasm_hash: &BytesN<32>) -> u32 {
+    if let Some(version) = get_version_of(env, wasm_hash) {
+        return version;
+    }
+
+    let version = get_latest_version(env) + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::LatestVersion, &version);
+    let storage = env.storage().persistent();
+    storage.set(&DataKey::VersionOf(wasm_hash.clone()), &version);
+    storage.set(&DataKey::WasmOf(version), wasm_hash);
+    version
+}
+
+pub fn get_instance(env: &Env, escrow: &Address) -> Option<EscrowInstance> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Instance(escrow.clone()))
+}
+
+pub fn set_instance(env: &Env, instance: &EscrowInstance) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Instance(instance.escrow.clone()), instance);
+}
+
+/// Add a newly registered escrow to the listing of all instances
+pub fn add_instance_entry(env: &Env, escrow: &Address) {
+    let count = get_instance_count(env);
+    let storage = env.storage().persistent();
+    storage.set(&DataKey::InstanceEntry(count), escrow);
+    env.storage()
+        .instance()
+        .set(&DataKey::InstanceCount, &(count + 1));
+}
+
+pub fn get_instance_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::InstanceCount)
+        .unwrap_or(0)
+}
+
+pub fn get_instance_entry(env: &Env, position: u32) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InstanceEntry(position))
+}
+
+/// List an escrow under a version
+pub fn add_version_entry(env: &Env, version: u32, escrow: &Address) {
+    let count = get_version_count(env, version);
+    let storage = env.storage().persistent();
+    storage.set(&DataKey::VersionEntry(version, count), escrow);
+    storage.set(&DataKey::VersionCount(version), &(count + 1));
+}
+
+pub fn get_version_count(env: &Env, version: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VersionCount(version))
+        .unwrap_or(0)
+}
+
+pub fn get_version_entry(env: &Env, version: u32, position: u32) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VersionEntry(version, position))
+}
@@ -0,0 +1,136 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Env};
+
+fn setup(env: &Env) -> (ContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| {
+        l.sequence_number = 100;
+        l.timestamp = 1_000;
+    });
+    let client = ContractClient::new(env, &env.register(Contract, ()));
+    let admin = Address::generate(env);
+    let factory = Address::generate(env);
+    client.initialize(&admin, &factory);
+    (client, admin, factory)
+}
+
+fn register(env: &Env, client: &ContractClient, wasm: u8) -> Address {
+    let escrow = Address::generate(env);
+    client.register_instance(
+        &escrow,
+        &BytesN::from_array(env, &[wasm; 32]),
+        &Address::generate(env),
+        &Address::generate(env),
+    );
+    escrow
+}
+
+#[test]
+fn test_instances_are_grouped_by_wasm_version() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    let first = register(&env, &client, 1);
+    let second = register(&env, &client, 1);
+    let third = register(&env, &client, 2);
+
+    assert_eq!(client.latest_version(), 2);
+    assert_eq!(
+        client.get_version(&BytesN::from_array(&env, &[2; 32])),
+        Some(2)
+    );
+    assert_eq!(
+        client.get_wasm_hash(&1),
+        Some(BytesN::from_array(&env, &[1; 32]))
+    );
+
+    let instance = client.get_instance(&first);
+    assert_eq!(instance.version, 1);
+    assert_eq!(instance.created_ledger, 100);
+
+    let v1 = client.get_instances_by_version(&1, &0, &10);
+    assert_eq!(v1.len(), 2);
+    assert_eq!(v1.get(1).unwrap().escrow, second);
+    assert_eq!(client.get_instances_by_version(&1, &1, &10).len(), 1);
+    assert_eq!(
+        client
+            .get_instances_by_version(&2, &0, &10)
+            .get(0)
+            .unwrap()
+            .escrow,
+        third
+    );
+    assert_eq!(client.get_instances(&0, &10).len(), 3);
+
+    assert_eq!(
+        client.try_register_instance(
+            &first,
+            &BytesN::from_array(&env, &[1; 32]),
+            &Address::generate(&env),
+            &Address::generate(&env),
+        ),
+        Err(Ok(Error::InstanceAlreadyRegistered))
+    );
+}
+
+#[test]
+fn test_migrated_instances_move_to_the_new_version() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+    let escrow = register(&env, &client, 1);
+    let stays = register(&env, &client, 1);
+    let new_wasm = BytesN::from_array(&env, &[3; 32]);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_record_migration(&stranger, &escrow, &new_wasm),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_record_migration(&admin, &escrow, &BytesN::from_array(&env, &[1; 32])),
+        Err(Ok(Error::AlreadyOnVersion))
+    );
+
+    assert_eq!(client.record_migration(&admin, &escrow, &new_wasm), 2);
+    let v1 = client.get_instances_by_version(&1, &0, &10);
+    assert_eq!(v1.len(), 1);
+    assert_eq!(v1.get(0).unwrap().escrow, stays);
+    assert_eq!(client.count_by_version(&1), 2);
+
+    let v2 = client.get_instances_by_version(&2, &0, &10);
+    assert_eq!(v2.get(0).unwrap().escrow, escrow);
+    assert_eq!(v2.get(0).unwrap().migrated_at, Some(1_000));
+}
+
+#[test]
+#[should_panic]
+fn test_only_factory_registers() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+    env.mock_auths(&[]);
+    register(&env, &client, 1);
+}
+
+#[test]
+fn test_pause_blocks_registration() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+
+    client.set_paused(&admin, &true);
+    assert_eq!(
+        client.try_register_instance(
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &[1; 32]),
+            &Address::generate(&env),
+            &Address::generate(&env),
+        ),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    client.set_paused(&admin, &false);
+    register(&env, &client, 1);
+    assert_eq!(client.latest_version(), 1);
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+/// An escrow deployed by the factory. `version` is the registry's number for the wasm
+/// the instance currently runs; versions are assigned in the order wasm hashes are
+/// first seen, starting at 1.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowInstance {
+    pub escrow: Address,
+    pub version: u32,
+    pub wasm_hash: BytesN<32>,
+    pub client: Address,
+    pub freelancer: Address,
+    pub created_ledger: u32,
+    pub created_at: u64,
+    pub migrated_at: Option<u64>,
+}
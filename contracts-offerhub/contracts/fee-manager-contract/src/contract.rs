@@ -1,18 +1,37 @@
-use soroban_sdk::{Address, Env, String, Symbol, Vec};
+use offerhub_math::{apply_discount_bps, mul_div, Rounding, MAX_BPS};
+use soroban_sdk::{Address, Bytes, BytesN, Env, IntoVal, Map, Symbol, Vec};
 
 use crate::{
     error::{handle_error, Error},
+    events::{
+        emit_config_change, emit_fee_collected, CHANGE_ASSET_ADDED, CHANGE_ASSET_REMOVED,
+        CHANGE_EXEMPTION_ADDED, CHANGE_EXEMPTION_REMOVED, CHANGE_PREMIUM_ADDED,
+        CHANGE_PREMIUM_REMOVED, CHANGE_RATES, CHANGE_TIERS,
+    },
     storage::{
-        DEFAULT_ARBITRATOR_FEE_PERCENTAGE, DEFAULT_DISPUTE_FEE_PERCENTAGE,
-        DEFAULT_ESCROW_FEE_PERCENTAGE, FEE_CONFIG, FEE_HISTORY, FEE_STATS, PLATFORM_BALANCE, PREMIUM_USERS,
-        CONTRACT_CONFIG, DEFAULT_PLATFORM_FEE_PERCENTAGE, DEFAULT_ESCROW_TIMEOUT_DAYS, DEFAULT_MAX_RATING_PER_DAY,
-        DEFAULT_MIN_ESCROW_AMOUNT, DEFAULT_MAX_ESCROW_AMOUNT, DEFAULT_DISPUTE_TIMEOUT_HOURS,
-        DEFAULT_RATE_LIMIT_WINDOW_HOURS, DEFAULT_MAX_RATE_LIMIT_CALLS, TOTAL_FESS_COLLECTED, PAUSED
+        ACCRUED_FEES, ACTIVE_PROMOS, ARBITRATOR_RESERVE, ARBITRATOR_RESOLUTIONS,
+        ARBITRATOR_REWARDS, ARBITRATOR_REWARD_CONFIG, CONTRACT_CONFIG,
+        DEFAULT_ARBITRATOR_FEE_PERCENTAGE,
+        DEFAULT_DISPUTE_FEE_PERCENTAGE, DEFAULT_DISPUTE_TIMEOUT_HOURS,
+        DEFAULT_ESCROW_FEE_PERCENTAGE, DEFAULT_ESCROW_TIMEOUT_DAYS, DEFAULT_MAX_ESCROW_AMOUNT,
+        DEFAULT_MAX_RATE_LIMIT_CALLS, DEFAULT_MAX_RATING_PER_DAY, DEFAULT_MIN_ESCROW_AMOUNT,
+        DEFAULT_PLATFORM_FEE_PERCENTAGE, DEFAULT_RATE_LIMIT_WINDOW_HOURS, DISTRIBUTED_EPOCHS,
+        EPOCH_FEES, ESCROW_FACTORY, FEE_ASSETS, FEE_CONFIG, FEE_EXEMPTIONS, FEE_HISTORY,
+        FEE_HISTORY_COUNT, FEE_TIERS, FEE_STATS, FEE_TIMELOCK, KEEPER_BUDGETS, KEEPER_REWARDS,
+        PAUSED, PAYER_FEE_TOTALS, PENDING_FEE_CHANGE, PENDING_FEE_TIERS, PLATFORM_BALANCE,
+        PREMIUM_USERS, PROMOS,
+        PROMO_REDEMPTIONS, REFERRAL_EARNINGS, REFERRAL_SHARE, REWARD_SOURCES, TOTAL_FESS_COLLECTED,
+        TREASURY,
     },
-    types::{FeeCalculation, FeeConfig, FeeRecord, FeeStats, PremiumUser, FEE_TYPE_ESCROW, FEE_TYPE_DISPUTE, PlatformStats, ContractConfig},
-    validation::{validate_initialization, validate_fee_rates, validate_fee_calculation, validate_withdrawal_amount, validate_fee_type, validate_address},
+    types::{ArbitratorRewardConfig, KeeperBudget, FeeCalculation, FeeConfig, FeeRecord, FeeStats, PremiumUser, FEE_TYPE_ESCROW, FEE_TYPE_DISPUTE, PlatformStats, ContractConfig, ReputationFeeTiers, FeeExemption, Promo, PendingFeeChange, PendingFeeTiers},
+    validation::{validate_initialization, validate_fee_rates, validate_fee_calculation, validate_withdrawal_amount, validate_fee_type, validate_address, validate_fee_tiers, validate_amount},
 };
 
+const FACTORY_GET_ESCROW_ID: &str = "get_escrow_id_by_address";
+
+const MAX_EXEMPTION_PAGE_SIZE: u32 = 100;
+const MAX_HISTORY_PAGE_SIZE: u32 = 100;
+
 pub fn initialize(env: &Env, admin: Address, platform_wallet: Address) {
     if env.storage().instance().has(&FEE_CONFIG) {
         handle_error(env, Error::AlreadyInitialized);
@@ -54,12 +73,12 @@ pub fn initialize(env: &Env, admin: Address, platform_wallet: Address) {
     env.storage().instance().set(&FEE_CONFIG, &fee_config);
     env.storage().instance().set(&PLATFORM_BALANCE, &0i128);
     env.storage().instance().set(&FEE_STATS, &fee_stats);
-    env.storage().instance().set(&FEE_HISTORY, &Vec::<FeeRecord>::new(env));
-    env.storage().instance().set(&PREMIUM_USERS, &Vec::<PremiumUser>::new(env));
-    env.storage().instance().set(&CONTRACT_CONFIG, &contract_config);
     env.storage()
         .instance()
-        .set(&FEE_HISTORY, &Vec::<FeeRecord>::new(env));
+        .set(&PREMIUM_USERS, &Vec::<PremiumUser>::new(env));
+    env.storage()
+        .instance()
+        .set(&CONTRACT_CONFIG, &contract_config);
     env.storage()
         .instance()
         .set(&PREMIUM_USERS, &Vec::<PremiumUser>::new(env));
@@ -73,49 +92,17 @@ pub fn initialize(env: &Env, admin: Address, platform_wallet: Address) {
 }
 
 pub fn is_paused(env: &Env) -> bool {
-    env.storage().instance().get(&PAUSED).unwrap_or(false)
-}
-
-pub fn pause(env: &Env, admin: Address) -> Result<(), Error> {
-    admin.require_auth();
-    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap_or_else(|| handle_error(env, Error::NotInitialized));
-    if fee_config.admin != admin {
-        return Err(Error::Unauthorized);
-    }
-    
-    if is_paused(env) {
-        return Err(Error::AlreadyPaused);
-    }
-    
-    env.storage().instance().set(&PAUSED, &true);
-    
-    env.events().publish(
-        (Symbol::new(env, "contract_paused"), admin),
-        env.ledger().timestamp(),
-    );
-    
-    Ok(())
+    offerhub_pausable::is_paused(env)
 }
 
-pub fn unpause(env: &Env, admin: Address) -> Result<(), Error> {
-    admin.require_auth();
-    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap_or_else(|| handle_error(env, Error::NotInitialized));
-    if fee_config.admin != admin {
-        return Err(Error::Unauthorized);
-    }
-    
-    if !is_paused(env) {
-        return Err(Error::NotPaused);
-    }
-    
-    env.storage().instance().set(&PAUSED, &false);
-    
-    env.events().publish(
-        (Symbol::new(env, "contract_unpaused"), admin),
-        env.ledger().timestamp(),
-    );
-    
-    Ok(())
+// The fee config admin administers pausing
+pub(crate) fn pause_admin(env: &Env) -> Address {
+    let fee_config: FeeConfig = env
+        .storage()
+        .instance()
+        .get(&FEE_CONFIG)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    fee_config.admin
 }
 
 
@@ -134,6 +121,11 @@ pub fn set_fee_rates(
     // Only admin can set fee rates
     fee_config.admin.require_auth();
 
+    // Once a timelock is configured rates can only change through schedule/execute
+    if get_fee_timelock(env) > 0 {
+        handle_error(env, Error::TimelockActive);
+    }
+
     // Input validation
     if let Err(e) = validate_fee_rates(
         escrow_fee_percentage,
@@ -143,11 +135,138 @@ pub fn set_fee_rates(
         handle_error(env, e);
     }
 
+    apply_fee_rates(
+        env,
+        &mut fee_config,
+        escrow_fee_percentage,
+        dispute_fee_percentage,
+        arbitrator_fee_percentage,
+    );
+}
+
+/// Sets the delay fee-rate changes wait out. It can only be raised, so an admin can't skip
+/// the current delay by shortening it first.
+pub fn set_fee_timelock(env: &Env, admin: Address, delay_secs: u64) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    if delay_secs < get_fee_timelock(env) {
+        return Err(Error::TimelockDecrease);
+    }
+
+    env.storage().instance().set(&FEE_TIMELOCK, &delay_secs);
+
+    env.events().publish(
+        (Symbol::new(env, "fee_timelock_updated"), admin),
+        (delay_secs, env.ledger().timestamp()),
+    );
+
+    Ok(())
+}
+
+pub fn get_fee_timelock(env: &Env) -> u64 {
+    env.storage().instance().get(&FEE_TIMELOCK).unwrap_or(0)
+}
+
+/// Queues new fee rates that take effect no earlier than the timelock delay from now.
+/// Scheduling again replaces the pending change. Returns the earliest execution time.
+pub fn schedule_fee_change(
+    env: &Env,
+    admin: Address,
+    escrow_fee_percentage: i128,
+    dispute_fee_percentage: i128,
+    arbitrator_fee_percentage: i128,
+) -> Result<u64, Error> {
+    require_admin(env, &admin)?;
+
+    validate_fee_rates(
+        escrow_fee_percentage,
+        dispute_fee_percentage,
+        arbitrator_fee_percentage,
+    )?;
+
+    let now = env.ledger().timestamp();
+    let change = PendingFeeChange {
+        escrow_fee_percentage,
+        dispute_fee_percentage,
+        arbitrator_fee_percentage,
+        scheduled_at: now,
+        eta: now + get_fee_timelock(env),
+    };
+    env.storage().instance().set(&PENDING_FEE_CHANGE, &change);
+
+    env.events().publish(
+        (Symbol::new(env, "fee_change_scheduled"), admin),
+        (
+            escrow_fee_percentage,
+            dispute_fee_percentage,
+            arbitrator_fee_percentage,
+            change.eta,
+        ),
+    );
+
+    Ok(change.eta)
+}
+
+/// Applies the pending change once its eta has passed. Anyone may execute it.
+pub fn execute_fee_change(env: &Env) -> Result<(), Error> {
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let change = get_pending_fee_change(env).ok_or(Error::NoPendingFeeChange)?;
+    if env.ledger().timestamp() < change.eta {
+        return Err(Error::FeeChangeNotReady);
+    }
+
+    let mut fee_config: FeeConfig = env
+        .storage()
+        .instance()
+        .get(&FEE_CONFIG)
+        .ok_or(Error::NotInitialized)?;
+    env.storage().instance().remove(&PENDING_FEE_CHANGE);
+    apply_fee_rates(
+        env,
+        &mut fee_config,
+        change.escrow_fee_percentage,
+        change.dispute_fee_percentage,
+        change.arbitrator_fee_percentage,
+    );
+
+    Ok(())
+}
+
+pub fn cancel_fee_change(env: &Env, admin: Address) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    if get_pending_fee_change(env).is_none() {
+        return Err(Error::NoPendingFeeChange);
+    }
+    env.storage().instance().remove(&PENDING_FEE_CHANGE);
+
+    env.events().publish(
+        (Symbol::new(env, "fee_change_cancelled"), admin),
+        env.ledger().timestamp(),
+    );
+
+    Ok(())
+}
+
+pub fn get_pending_fee_change(env: &Env) -> Option<PendingFeeChange> {
+    env.storage().instance().get(&PENDING_FEE_CHANGE)
+}
+
+fn apply_fee_rates(
+    env: &Env,
+    fee_config: &mut FeeConfig,
+    escrow_fee_percentage: i128,
+    dispute_fee_percentage: i128,
+    arbitrator_fee_percentage: i128,
+) {
     fee_config.escrow_fee_percentage = escrow_fee_percentage;
     fee_config.dispute_fee_percentage = dispute_fee_percentage;
     fee_config.arbitrator_fee_percentage = arbitrator_fee_percentage;
 
-    env.storage().instance().set(&FEE_CONFIG, &fee_config);
+    env.storage().instance().set(&FEE_CONFIG, fee_config);
 
     env.events().publish(
         (
@@ -160,258 +279,1436 @@ pub fn set_fee_rates(
             arbitrator_fee_percentage,
         ),
     );
+    emit_config_change(env, CHANGE_RATES, &fee_config.admin, None);
 }
 
-pub fn add_premium_user(env: &Env, user: Address) {
+/// Replaces the flat escrow fee with a rate picked by the user's reputation score: the
+/// highest tier whose `min_score` the user reaches. Users below every tier pay the flat rate.
+/// Like the rates, tiers go through schedule/execute once a timelock is configured.
+pub fn set_fee_tiers(env: &Env, fee_tiers: ReputationFeeTiers) {
     if is_paused(env) {
         handle_error(env, Error::ContractPaused);
     }
-    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap();
+    let fee_config: FeeConfig = env
+        .storage()
+        .instance()
+        .get(&FEE_CONFIG)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
     fee_config.admin.require_auth();
 
-    // Input validation
-    if let Err(_) = validate_address(&user) {
-        handle_error(env, Error::Unauthorized);
+    if get_fee_timelock(env) > 0 {
+        handle_error(env, Error::TimelockActive);
     }
 
-    let mut premium_users: Vec<PremiumUser> = env.storage().instance().get(&PREMIUM_USERS).unwrap();
+    if let Err(e) = validate_fee_tiers(&fee_tiers.tiers) {
+        handle_error(env, e);
+    }
 
-    // Check if user is already premium
-    for premium_user in premium_users.iter() {
-        if premium_user.address == user {
-            handle_error(env, Error::PremiumUserAlreadyExists);
+    apply_fee_tiers(env, &fee_config, fee_tiers);
+}
+
+/// Queues new fee tiers behind the same timelock delay as fee-rate changes. Scheduling
+/// again replaces the pending tiers. Returns the earliest execution time.
+pub fn schedule_fee_tiers_change(
+    env: &Env,
+    admin: Address,
+    fee_tiers: ReputationFeeTiers,
+) -> Result<u64, Error> {
+    require_admin(env, &admin)?;
+
+    validate_fee_tiers(&fee_tiers.tiers)?;
+
+    let now = env.ledger().timestamp();
+    let change = PendingFeeTiers {
+        fee_tiers,
+        scheduled_at: now,
+        eta: now + get_fee_timelock(env),
+    };
+    env.storage().instance().set(&PENDING_FEE_TIERS, &change);
+
+    env.events().publish(
+        (Symbol::new(env, "fee_tiers_scheduled"), admin),
+        (
+            change.fee_tiers.reputation_contract.clone(),
+            change.fee_tiers.tiers.len(),
+            change.eta,
+        ),
+    );
+
+    Ok(change.eta)
+}
+
+/// Applies the pending tiers once their eta has passed. Anyone may execute them.
+pub fn execute_fee_tiers_change(env: &Env) -> Result<(), Error> {
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let change = get_pending_fee_tiers(env).ok_or(Error::NoPendingFeeChange)?;
+    if env.ledger().timestamp() < change.eta {
+        return Err(Error::FeeChangeNotReady);
+    }
+
+    let fee_config: FeeConfig = env
+        .storage()
+        .instance()
+        .get(&FEE_CONFIG)
+        .ok_or(Error::NotInitialized)?;
+    env.storage().instance().remove(&PENDING_FEE_TIERS);
+    apply_fee_tiers(env, &fee_config, change.fee_tiers);
+
+    Ok(())
+}
+
+pub fn cancel_fee_tiers_change(env: &Env, admin: Address) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    if get_pending_fee_tiers(env).is_none() {
+        return Err(Error::NoPendingFeeChange);
+    }
+    env.storage().instance().remove(&PENDING_FEE_TIERS);
+
+    env.events().publish(
+        (Symbol::new(env, "fee_tiers_cancelled"), admin),
+        env.ledger().timestamp(),
+    );
+
+    Ok(())
+}
+
+pub fn get_pending_fee_tiers(env: &Env) -> Option<PendingFeeTiers> {
+    env.storage().instance().get(&PENDING_FEE_TIERS)
+}
+
+fn apply_fee_tiers(env: &Env, fee_config: &FeeConfig, fee_tiers: ReputationFeeTiers) {
+    env.storage().instance().set(&FEE_TIERS, &fee_tiers);
+
+    emit_config_change(
+        env,
+        CHANGE_TIERS,
+        &fee_config.admin,
+        Some(fee_tiers.reputation_contract.clone()),
+    );
+    env.events().publish(
+        (
+            Symbol::new(env, "fee_tiers_updated"),
+            fee_config.admin.clone(),
+        ),
+        (fee_tiers.reputation_contract, fee_tiers.tiers.len()),
+    );
+}
+
+pub fn get_fee_tiers(env: &Env) -> Option<ReputationFeeTiers> {
+    env.storage().instance().get(&FEE_TIERS)
+}
+
+/// Waives every fee for `address` until `expires_at`. Granting again replaces the expiry.
+pub fn add_fee_exemption(
+    env: &Env,
+    admin: Address,
+    address: Address,
+    expires_at: u64,
+) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    if expires_at <= env.ledger().timestamp() {
+        return Err(Error::InvalidExpiry);
+    }
+
+    let mut exemptions = fee_exemptions(env);
+    exemptions.set(
+        address.clone(),
+        FeeExemption {
+            address: address.clone(),
+            expires_at,
+            added_at: env.ledger().timestamp(),
+        },
+    );
+    env.storage().instance().set(&FEE_EXEMPTIONS, &exemptions);
+
+    emit_config_change(env, CHANGE_EXEMPTION_ADDED, &admin, Some(address.clone()));
+    env.events().publish(
+        (Symbol::new(env, "fee_exemption_added"), admin),
+        (address, expires_at),
+    );
+
+    Ok(())
+}
+
+pub fn remove_fee_exemption(env: &Env, admin: Address, address: Address) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    let mut exemptions = fee_exemptions(env);
+    if exemptions.remove(address.clone()).is_none() {
+        return Err(Error::ExemptionNotFound);
+    }
+    env.storage().instance().set(&FEE_EXEMPTIONS, &exemptions);
+
+    emit_config_change(env, CHANGE_EXEMPTION_REMOVED, &admin, Some(address.clone()));
+    env.events().publish(
+        (Symbol::new(env, "fee_exemption_removed"), admin),
+        (address, env.ledger().timestamp()),
+    );
+
+    Ok(())
+}
+
+pub fn is_fee_exempt(env: &Env, address: Address) -> bool {
+    fee_exemptions(env)
+        .get(address)
+        .is_some_and(|exemption| env.ledger().timestamp() < exemption.expires_at)
+}
+
+/// Exemptions that have not expired yet. `offset` and `limit` apply after expired
+/// entries are skipped.
+pub fn get_fee_exemptions(env: &Env, offset: u32, limit: u32) -> Vec<FeeExemption> {
+    let now = env.ledger().timestamp();
+    let limit = limit.min(MAX_EXEMPTION_PAGE_SIZE);
+    let mut page = Vec::new(env);
+    let mut skipped = 0;
+    for (_, exemption) in fee_exemptions(env).iter() {
+        if page.len() >= limit {
+            break;
+        }
+        if now >= exemption.expires_at {
+            continue;
         }
+        if skipped < offset {
+            skipped += 1;
+            continue;
+        }
+        page.push_back(exemption);
     }
+    page
+}
 
-    let new_premium_user = PremiumUser {
-        address: user.clone(),
-        added_at: env.ledger().timestamp(),
-        added_by: fee_config.admin.clone(),
-    };
+pub fn set_treasury(env: &Env, admin: Address, treasury: Address) -> Result<(), Error> {
+    require_admin(env, &admin)?;
 
-    premium_users.push_back(new_premium_user);
-    env.storage().instance().set(&PREMIUM_USERS, &premium_users);
+    env.storage().instance().set(&TREASURY, &treasury);
+
+    env.events().publish(
+        (Symbol::new(env, "treasury_set"), admin),
+        (treasury, env.ledger().timestamp()),
+    );
+
+    Ok(())
+}
+
+pub fn get_treasury(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&TREASURY)
+}
+
+/// Escrows deployed by `factory` may collect fees alongside the admin.
+pub fn set_escrow_factory(env: &Env, admin: Address, factory: Address) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    env.storage().instance().set(&ESCROW_FACTORY, &factory);
+
+    env.events().publish(
+        (Symbol::new(env, "escrow_factory_set"), admin),
+        (factory, env.ledger().timestamp()),
+    );
+
+    Ok(())
+}
+
+pub fn get_escrow_factory(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ESCROW_FACTORY)
+}
+
+// Collecting credits the platform balance and redeems the user's promo, so only the
+// admin or an escrow the configured factory deployed may do it
+fn require_fee_collector(env: &Env, caller: &Address) {
+    caller.require_auth();
+
+    let fee_config: FeeConfig = env
+        .storage()
+        .instance()
+        .get(&FEE_CONFIG)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    if *caller == fee_config.admin {
+        return;
+    }
+
+    let deployed = get_escrow_factory(env).is_some_and(|factory| {
+        let escrow_id: Option<u32> = env.invoke_contract(
+            &factory,
+            &Symbol::new(env, FACTORY_GET_ESCROW_ID),
+            (caller.clone(),).into_val(env),
+        );
+        escrow_id.is_some()
+    });
+    if !deployed {
+        handle_error(env, Error::Unauthorized);
+    }
+}
+
+/// Moves `amount` of `asset` from `payer` into the fee manager and adds it to that
+/// asset's accrued balance. Only whitelisted fee assets are accepted.
+pub fn deposit_fee(env: &Env, payer: Address, asset: Address, amount: i128) -> i128 {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+    payer.require_auth();
+
+    if let Err(e) = validate_amount(amount) {
+        handle_error(env, e);
+    }
+    if !is_fee_asset(env, asset.clone()) {
+        handle_error(env, Error::AssetNotAccepted);
+    }
+
+    accrue_asset_fee(env, &payer, &asset, amount)
+}
+
+/// Prices the fee exactly like `collect_fee` and takes it from `payer` in `asset`, so
+/// escrows in any whitelisted asset can pay without converting first.
+pub fn collect_fee_in_asset(
+    env: &Env,
+    caller: Address,
+    payer: Address,
+    asset: Address,
+    amount: i128,
+    fee_type: u32,
+) -> i128 {
+    require_fee_collector(env, &caller);
+    payer.require_auth();
+    charge_fee_in_asset(env, &caller, &payer, &asset, amount, fee_type)
+}
+
+// Prices the fee like `charge_fee` and moves it from `payer` into the asset's accrued
+// balance, returning the net amount
+fn charge_fee_in_asset(
+    env: &Env,
+    caller: &Address,
+    payer: &Address,
+    asset: &Address,
+    amount: i128,
+    fee_type: u32,
+) -> i128 {
+    if !is_fee_asset(env, asset.clone()) {
+        handle_error(env, Error::AssetNotAccepted);
+    }
+
+    let net_amount = charge_fee(
+        env,
+        caller,
+        amount,
+        fee_type,
+        payer.clone(),
+        Some(asset.clone()),
+    );
+    let fee_amount = amount - net_amount;
+    if fee_amount > 0 {
+        accrue_asset_fee(env, payer, asset, fee_amount);
+    }
+
+    net_amount
+}
+
+pub fn add_fee_asset(env: &Env, admin: Address, asset: Address) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    let mut assets = get_fee_assets(env);
+    if assets.contains(&asset) {
+        return Err(Error::AssetAlreadyAccepted);
+    }
+    assets.push_back(asset.clone());
+    env.storage().instance().set(&FEE_ASSETS, &assets);
+
+    emit_config_change(env, CHANGE_ASSET_ADDED, &admin, Some(asset.clone()));
+    env.events().publish(
+        (Symbol::new(env, "fee_asset_added"), admin),
+        (asset, env.ledger().timestamp()),
+    );
+
+    Ok(())
+}
+
+/// Stops accepting `asset`. Fees already accrued in it stay withdrawable.
+pub fn remove_fee_asset(env: &Env, admin: Address, asset: Address) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    let mut assets = get_fee_assets(env);
+    let index = assets
+        .first_index_of(&asset)
+        .ok_or(Error::AssetNotAccepted)?;
+    assets.remove(index);
+    env.storage().instance().set(&FEE_ASSETS, &assets);
+
+    emit_config_change(env, CHANGE_ASSET_REMOVED, &admin, Some(asset.clone()));
+    env.events().publish(
+        (Symbol::new(env, "fee_asset_removed"), admin),
+        (asset, env.ledger().timestamp()),
+    );
+
+    Ok(())
+}
+
+pub fn get_fee_assets(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&FEE_ASSETS)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn is_fee_asset(env: &Env, asset: Address) -> bool {
+    get_fee_assets(env).contains(&asset)
+}
+
+fn accrue_asset_fee(env: &Env, payer: &Address, asset: &Address, amount: i128) -> i128 {
+    env.invoke_contract::<()>(
+        asset,
+        &Symbol::new(env, "transfer"),
+        (payer.clone(), env.current_contract_address(), amount).into_val(env),
+    );
+
+    let mut accrued = accrued_fees(env);
+    let total = accrued.get(asset.clone()).unwrap_or(0) + amount;
+    accrued.set(asset.clone(), total);
+    env.storage().instance().set(&ACCRUED_FEES, &accrued);
+
+    env.events().publish(
+        (
+            Symbol::new(env, "fee_deposited"),
+            payer.clone(),
+            asset.clone(),
+        ),
+        (amount, total, env.ledger().timestamp()),
+    );
+
+    total
+}
+
+/// Sends accrued fees of `asset` to the configured treasury; no other destination is
+/// possible.
+pub fn withdraw_to_treasury(
+    env: &Env,
+    admin: Address,
+    asset: Address,
+    amount: i128,
+) -> Result<i128, Error> {
+    require_admin(env, &admin)?;
+
+    let treasury = get_treasury(env).ok_or(Error::TreasuryNotSet)?;
+    let remaining = pay_out_accrued(env, &asset, &treasury, amount)?;
+
+    env.events().publish(
+        (Symbol::new(env, "treasury_withdrawal"), treasury, asset),
+        (amount, remaining, env.ledger().timestamp()),
+    );
+
+    Ok(remaining)
+}
+
+pub fn get_accrued(env: &Env, asset: Address) -> i128 {
+    accrued_fees(env).get(asset).unwrap_or(0)
+}
+
+/// Debits `amount` from the fees accrued in `asset` and transfers it to `recipient`,
+/// returning what remains accrued.
+fn pay_out_accrued(
+    env: &Env,
+    asset: &Address,
+    recipient: &Address,
+    amount: i128,
+) -> Result<i128, Error> {
+    let remaining = debit_accrued(env, asset, amount)?;
+    transfer_out(env, asset, recipient, amount);
+    Ok(remaining)
+}
+
+/// Debits `amount` from the fees accrued in `asset`, e.g. to set it aside for a payout
+/// that is claimed later, returning what remains accrued.
+fn debit_accrued(env: &Env, asset: &Address, amount: i128) -> Result<i128, Error> {
+    let mut accrued = accrued_fees(env);
+    let balance = accrued.get(asset.clone()).unwrap_or(0);
+    validate_withdrawal_amount(amount, balance)?;
+
+    let remaining = balance - amount;
+    accrued.set(asset.clone(), remaining);
+    env.storage().instance().set(&ACCRUED_FEES, &accrued);
+    Ok(remaining)
+}
+
+fn transfer_out(env: &Env, asset: &Address, recipient: &Address, amount: i128) {
+    env.invoke_contract::<()>(
+        asset,
+        &Symbol::new(env, "transfer"),
+        (env.current_contract_address(), recipient.clone(), amount).into_val(env),
+    );
+}
+
+fn accrued_fees(env: &Env) -> Map<Address, i128> {
+    env.storage()
+        .instance()
+        .get(&ACCRUED_FEES)
+        .unwrap_or(Map::new(env))
+}
+
+fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+    admin.require_auth();
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+    let fee_config: FeeConfig = env
+        .storage()
+        .instance()
+        .get(&FEE_CONFIG)
+        .ok_or(Error::NotInitialized)?;
+    if fee_config.admin != *admin {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
+
+fn fee_exemptions(env: &Env) -> Map<Address, FeeExemption> {
+    env.storage()
+        .instance()
+        .get(&FEE_EXEMPTIONS)
+        .unwrap_or(Map::new(env))
+}
+
+/// Registers a promo code by its sha256 hash so the code itself never appears on chain
+/// until a user redeems it.
+pub fn create_promo(
+    env: &Env,
+    admin: Address,
+    code_hash: BytesN<32>,
+    discount_bps: u32,
+    max_uses: u32,
+    expires_at: u64,
+) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    if discount_bps == 0 || discount_bps > MAX_BPS || max_uses == 0 {
+        return Err(Error::InvalidPromo);
+    }
+    if expires_at <= env.ledger().timestamp() {
+        return Err(Error::InvalidExpiry);
+    }
+
+    let mut promos = promos(env);
+    if promos.contains_key(code_hash.clone()) {
+        return Err(Error::InvalidPromo);
+    }
+    promos.set(
+        code_hash.clone(),
+        Promo {
+            discount_bps,
+            max_uses,
+            uses: 0,
+            expires_at,
+            created_at: env.ledger().timestamp(),
+        },
+    );
+    env.storage().instance().set(&PROMOS, &promos);
+
+    env.events().publish(
+        (Symbol::new(env, "promo_created"), admin),
+        (code_hash, discount_bps, max_uses, expires_at),
+    );
+
+    Ok(())
+}
+
+/// Attaches a promo to the user's next fee. The discount is consumed, and counted against
+/// `max_uses`, when that fee is collected; each user can redeem a promo once.
+pub fn apply_promo(env: &Env, user: Address, code: Bytes) -> Result<(), Error> {
+    user.require_auth();
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+    let promo = promos(env)
+        .get(code_hash.clone())
+        .ok_or(Error::PromoNotFound)?;
+    if env.ledger().timestamp() >= promo.expires_at {
+        return Err(Error::PromoExpired);
+    }
+    if promo.uses >= promo.max_uses {
+        return Err(Error::PromoExhausted);
+    }
+    if env
+        .storage()
+        .persistent()
+        .has(&(PROMO_REDEMPTIONS, code_hash.clone(), user.clone()))
+    {
+        return Err(Error::PromoAlreadyUsed);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&(ACTIVE_PROMOS, user.clone()), &code_hash);
+
+    env.events().publish(
+        (Symbol::new(env, "promo_applied"), user),
+        (code_hash, promo.discount_bps),
+    );
+
+    Ok(())
+}
+
+pub fn get_promo(env: &Env, code_hash: BytesN<32>) -> Option<Promo> {
+    promos(env).get(code_hash)
+}
+
+pub fn get_active_promo(env: &Env, user: Address) -> Option<BytesN<32>> {
+    env.storage().persistent().get(&(ACTIVE_PROMOS, user))
+}
+
+// Discounts the rate by the user's pending promo. With `consume` the promo is redeemed:
+// its use is counted and it can't be applied by this user again.
+fn apply_promo_discount(env: &Env, user: &Address, fee_percentage: i128, consume: bool) -> i128 {
+    if fee_percentage == 0 {
+        return 0;
+    }
+    let Some(code_hash) = get_active_promo(env, user.clone()) else {
+        return fee_percentage;
+    };
+    let mut promos = promos(env);
+    let Some(mut promo) = promos.get(code_hash.clone()) else {
+        return fee_percentage;
+    };
+    if env.ledger().timestamp() >= promo.expires_at || promo.uses >= promo.max_uses {
+        return fee_percentage;
+    }
+
+    if consume {
+        promo.uses += 1;
+        promos.set(code_hash.clone(), promo.clone());
+        env.storage().instance().set(&PROMOS, &promos);
+        env.storage()
+            .persistent()
+            .set(&(PROMO_REDEMPTIONS, code_hash.clone(), user.clone()), &true);
+        env.storage()
+            .persistent()
+            .remove(&(ACTIVE_PROMOS, user.clone()));
+
+        env.events().publish(
+            (Symbol::new(env, "promo_redeemed"), user.clone()),
+            (code_hash, promo.uses),
+        );
+    }
+
+    apply_discount_bps(fee_percentage, promo.discount_bps)
+        .unwrap_or_else(|e| handle_error(env, e.into()))
+}
+
+fn promos(env: &Env) -> Map<BytesN<32>, Promo> {
+    env.storage()
+        .instance()
+        .get(&PROMOS)
+        .unwrap_or(Map::new(env))
+}
+
+pub fn add_premium_user(env: &Env, user: Address) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap();
+    fee_config.admin.require_auth();
+
+    // Input validation
+    if let Err(_) = validate_address(&user) {
+        handle_error(env, Error::Unauthorized);
+    }
+
+    let mut premium_users: Vec<PremiumUser> = env.storage().instance().get(&PREMIUM_USERS).unwrap();
+
+    // Check if user is already premium
+    for premium_user in premium_users.iter() {
+        if premium_user.address == user {
+            handle_error(env, Error::PremiumUserAlreadyExists);
+        }
+    }
+
+    let new_premium_user = PremiumUser {
+        address: user.clone(),
+        added_at: env.ledger().timestamp(),
+        added_by: fee_config.admin.clone(),
+    };
+
+    premium_users.push_back(new_premium_user);
+    env.storage().instance().set(&PREMIUM_USERS, &premium_users);
+
+    emit_config_change(
+        env,
+        CHANGE_PREMIUM_ADDED,
+        &fee_config.admin,
+        Some(user.clone()),
+    );
+    env.events().publish(
+        (
+            Symbol::new(env, "premium_user_added"),
+            fee_config.admin.clone(),
+        ),
+        (user, env.ledger().timestamp()),
+    );
+}
+
+pub fn remove_premium_user(env: &Env, user: Address) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap();
+    fee_config.admin.require_auth();
+
+    let mut premium_users: Vec<PremiumUser> = env.storage().instance().get(&PREMIUM_USERS).unwrap();
+    let mut found = false;
+
+    for i in 0..premium_users.len() {
+        if premium_users.get(i).unwrap().address == user {
+            premium_users.remove(i);
+            found = true;
+            break;
+        }
+    }
+
+    if !found {
+        handle_error(env, Error::PremiumUserNotFound);
+    }
+
+    env.storage().instance().set(&PREMIUM_USERS, &premium_users);
+
+    emit_config_change(
+        env,
+        CHANGE_PREMIUM_REMOVED,
+        &fee_config.admin,
+        Some(user.clone()),
+    );
+    env.events().publish(
+        (
+            Symbol::new(env, "premium_user_removed"),
+            fee_config.admin.clone(),
+        ),
+        (user, env.ledger().timestamp()),
+    );
+}
+
+pub fn calculate_escrow_fee(env: &Env, amount: i128, user: Address) -> FeeCalculation {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+    // Input validation
+    if let Err(e) = validate_fee_calculation(amount, &user) {
+        handle_error(env, e);
+    }
+
+    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap();
+    let is_premium = is_premium_user(env, user.clone());
+    let is_exempt = is_fee_exempt(env, user.clone());
+
+    let fee_percentage = if is_premium || is_exempt {
+        0
+    } else {
+        escrow_fee_percentage_for(env, &fee_config, &user)
+    };
+    let fee_percentage = apply_promo_discount(env, &user, fee_percentage, false);
+    let fee_amount = calculate_fee_amount(env, amount, fee_percentage);
+    let net_amount = amount - fee_amount;
+
+    FeeCalculation {
+        original_amount: amount,
+        fee_amount,
+        net_amount,
+        fee_percentage,
+        is_premium,
+    }
+}
+
+pub fn calculate_dispute_fee(env: &Env, amount: i128, user: Address) -> FeeCalculation {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+    // Input validation
+    if let Err(e) = validate_fee_calculation(amount, &user) {
+        handle_error(env, e);
+    }
+
+    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap();
+    let is_premium = is_premium_user(env, user.clone());
+    let is_exempt = is_fee_exempt(env, user.clone());
+
+    let fee_percentage = if is_premium || is_exempt {
+        0
+    } else {
+        fee_config.dispute_fee_percentage
+    };
+    let fee_percentage = apply_promo_discount(env, &user, fee_percentage, false);
+    let fee_amount = calculate_fee_amount(env, amount, fee_percentage);
+    let net_amount = amount - fee_amount;
+
+    FeeCalculation {
+        original_amount: amount,
+        fee_amount,
+        net_amount,
+        fee_percentage,
+        is_premium,
+    }
+}
+
+pub fn collect_fee(env: &Env, caller: Address, amount: i128, fee_type: u32, user: Address) -> i128 {
+    require_fee_collector(env, &caller);
+    charge_fee(env, &caller, amount, fee_type, user, None)
+}
+
+// `caller` is the contract collecting the fee, e.g. an escrow, and is recorded with it
+fn charge_fee(
+    env: &Env,
+    caller: &Address,
+    amount: i128,
+    fee_type: u32,
+    user: Address,
+    asset: Option<Address>,
+) -> i128 {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+    // Input validation
+    if let Err(e) = validate_fee_calculation(amount, &user) {
+        handle_error(env, e);
+    }
+    if let Err(e) = validate_fee_type(fee_type) {
+        handle_error(env, e);
+    }
+
+    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap();
+    let is_premium = is_premium_user(env, user.clone());
+    let is_exempt = is_fee_exempt(env, user.clone());
+    let fee_percentage = match fee_type {
+        FEE_TYPE_ESCROW => {
+            if is_premium || is_exempt {
+                0
+            } else {
+                escrow_fee_percentage_for(env, &fee_config, &user)
+            }
+        }
+        FEE_TYPE_DISPUTE => {
+            if is_premium || is_exempt {
+                0
+            } else {
+                fee_config.dispute_fee_percentage
+            }
+        }
+        _ => 0,
+    };
+    let fee_percentage = apply_promo_discount(env, &user, fee_percentage, true);
+
+    let fee_amount = calculate_fee_amount(env, amount, fee_percentage);
+
+    let net_amount = amount - fee_amount;
+
+    // Fees paid in an asset are held and tracked in that asset's accrued balance; only
+    // the others are booked against the platform balance
+    if asset.is_none() {
+        let mut platform_balance: i128 =
+            env.storage().instance().get(&PLATFORM_BALANCE).unwrap();
+
+        platform_balance += fee_amount;
+        env.storage()
+            .instance()
+            .set(&PLATFORM_BALANCE, &platform_balance);
+    }
+
+    // Update total fees collected
+    let mut total_fees: i128 = env
+        .storage()
+        .instance()
+        .get(&TOTAL_FESS_COLLECTED)
+        .unwrap_or(0);
+    total_fees += fee_amount;
+    env.storage()
+        .instance()
+        .set(&TOTAL_FESS_COLLECTED, &total_fees);
+
+    // Track the epoch's fees per asset so a share of what the contract holds can go to
+    // arbitrators
+    if let (Some(reward_config), Some(asset)) = (get_arbitrator_reward_config(env), &asset) {
+        let epoch = env.ledger().timestamp() / reward_config.epoch_secs;
+        let mut epoch_fees: Map<(u64, Address), i128> = env
+            .storage()
+            .instance()
+            .get(&EPOCH_FEES)
+            .unwrap_or(Map::new(env));
+        let key = (epoch, asset.clone());
+        epoch_fees.set(key.clone(), epoch_fees.get(key).unwrap_or(0) + fee_amount);
+        env.storage().instance().set(&EPOCH_FEES, &epoch_fees);
+    }
+
+    // Update fee stats
+    let mut fee_stats: FeeStats = env.storage().instance().get(&FEE_STATS).unwrap();
+    fee_stats.total_fees_collected += fee_amount;
+    fee_stats.total_transactions += 1;
+
+    match fee_type {
+        FEE_TYPE_ESCROW => fee_stats.total_escrow_fees += fee_amount,
+        FEE_TYPE_DISPUTE => fee_stats.total_dispute_fees += fee_amount,
+        _ => {}
+    }
+
+    if is_premium {
+        fee_stats.total_premium_exemptions += fee_amount;
+    }
+
+    env.storage().instance().set(&FEE_STATS, &fee_stats);
+
+    // Record fee transaction
+    record_fee(env, &user, caller, fee_type, asset.clone(), fee_amount);
+    emit_fee_collected(
+        env,
+        &user,
+        fee_type,
+        &asset,
+        amount,
+        fee_amount,
+        fee_percentage,
+        total_fees,
+    );
+
+    env.events().publish(
+        (Symbol::new(env, "fee_collected"), user),
+        (fee_amount, net_amount, env.ledger().timestamp(), total_fees),
+    );
+
+    net_amount
+}
+
+/// Same as `collect_fee_in_asset`, then credits the configured referral share of the fee
+/// to the referrer's earnings in `asset`, claimable out of the fees accrued in it.
+pub fn collect_fee_with_referrer(
+    env: &Env,
+    caller: Address,
+    amount: i128,
+    fee_type: u32,
+    user: Address,
+    referrer: Address,
+    asset: Address,
+) -> i128 {
+    if referrer == user {
+        handle_error(env, Error::SelfReferral);
+    }
+
+    require_fee_collector(env, &caller);
+    user.require_auth();
+    let net_amount = charge_fee_in_asset(env, &caller, &user, &asset, amount, fee_type);
+    let fee_amount = amount - net_amount;
+
+    let referral_bps: u32 = env.storage().instance().get(&REFERRAL_SHARE).unwrap_or(0);
+    let referral_fee = calculate_fee_amount(env, fee_amount, referral_bps as i128);
+    if referral_fee > 0 {
+        // The referrer's share is set aside now, so treasury withdrawals can't spend it
+        if let Err(e) = debit_accrued(env, &asset, referral_fee) {
+            handle_error(env, e);
+        }
+
+        let mut earnings = referral_earnings(env);
+        let key = (referrer.clone(), asset.clone());
+        let accrued = earnings.get(key.clone()).unwrap_or(0) + referral_fee;
+        earnings.set(key, accrued);
+        env.storage().instance().set(&REFERRAL_EARNINGS, &earnings);
+
+        env.events().publish(
+            (Symbol::new(env, "referral_fee_credited"), referrer, user),
+            (asset, referral_fee, accrued, env.ledger().timestamp()),
+        );
+    }
+
+    net_amount
+}
+
+pub fn set_referral_share(env: &Env, admin: Address, share_bps: u32) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    if share_bps > MAX_BPS {
+        return Err(Error::InvalidFeePercentage);
+    }
+
+    env.storage().instance().set(&REFERRAL_SHARE, &share_bps);
+
+    env.events().publish(
+        (Symbol::new(env, "referral_share_updated"), admin),
+        (share_bps, env.ledger().timestamp()),
+    );
+
+    Ok(())
+}
+
+pub fn get_referral_share(env: &Env) -> u32 {
+    env.storage().instance().get(&REFERRAL_SHARE).unwrap_or(0)
+}
+
+/// Pays the referrer's earnings in `asset`; they were set aside from the accrued fees when
+/// credited.
+pub fn claim_referral_earnings(
+    env: &Env,
+    referrer: Address,
+    asset: Address,
+) -> Result<i128, Error> {
+    if is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+    referrer.require_auth();
+
+    let mut earnings = referral_earnings(env);
+    let key = (referrer.clone(), asset.clone());
+    let amount = earnings.get(key.clone()).unwrap_or(0);
+    if amount <= 0 {
+        return Err(Error::NoRewardsToClaim);
+    }
+
+    earnings.remove(key);
+    env.storage().instance().set(&REFERRAL_EARNINGS, &earnings);
+    transfer_out(env, &asset, &referrer, amount);
+
+    env.events().publish(
+        (
+            Symbol::new(env, "referral_earnings_claimed"),
+            referrer,
+            asset,
+        ),
+        (amount, env.ledger().timestamp()),
+    );
+
+    Ok(amount)
+}
+
+pub fn get_referral_earnings(env: &Env, referrer: Address, asset: Address) -> i128 {
+    referral_earnings(env).get((referrer, asset)).unwrap_or(0)
+}
+
+fn referral_earnings(env: &Env) -> Map<(Address, Address), i128> {
+    env.storage()
+        .instance()
+        .get(&REFERRAL_EARNINGS)
+        .unwrap_or(Map::new(env))
+}
+
+pub fn withdraw_platform_fees(env: &Env, amount: i128) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap();
+    fee_config.admin.require_auth();
+
+    let mut platform_balance: i128 = env.storage().instance().get(&PLATFORM_BALANCE).unwrap();
+
+    // Input validation
+    if let Err(e) = validate_withdrawal_amount(amount, platform_balance) {
+        handle_error(env, e);
+    }
+
+    platform_balance -= amount;
+    env.storage()
+        .instance()
+        .set(&PLATFORM_BALANCE, &platform_balance);
 
     env.events().publish(
         (
-            Symbol::new(env, "premium_user_added"),
+            Symbol::new(env, "platform_fees_withdrawn"),
             fee_config.admin.clone(),
         ),
-        (user, env.ledger().timestamp()),
+        (amount, platform_balance, env.ledger().timestamp()),
     );
 }
 
-pub fn remove_premium_user(env: &Env, user: Address) {
+pub fn add_reward_source(env: &Env, source: Address) {
     if is_paused(env) {
         handle_error(env, Error::ContractPaused);
     }
-    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap();
+    let fee_config: FeeConfig = env
+        .storage()
+        .instance()
+        .get(&FEE_CONFIG)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
     fee_config.admin.require_auth();
 
-    let mut premium_users: Vec<PremiumUser> = env.storage().instance().get(&PREMIUM_USERS).unwrap();
-    let mut found = false;
-
-    for i in 0..premium_users.len() {
-        if premium_users.get(i).unwrap().address == user {
-            premium_users.remove(i);
-            found = true;
-            break;
-        }
-    }
-
-    if !found {
-        handle_error(env, Error::PremiumUserNotFound);
+    let mut sources: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&REWARD_SOURCES)
+        .unwrap_or(Vec::new(env));
+    if !sources.contains(&source) {
+        sources.push_back(source.clone());
+        env.storage().instance().set(&REWARD_SOURCES, &sources);
     }
 
-    env.storage().instance().set(&PREMIUM_USERS, &premium_users);
-
     env.events().publish(
         (
-            Symbol::new(env, "premium_user_removed"),
+            Symbol::new(env, "reward_source_added"),
             fee_config.admin.clone(),
         ),
-        (user, env.ledger().timestamp()),
+        (source, env.ledger().timestamp()),
     );
 }
 
-pub fn calculate_escrow_fee(env: &Env, amount: i128, user: Address) -> FeeCalculation {
-    if is_paused(env) {
-        handle_error(env, Error::ContractPaused);
-    }
-    // Input validation
-    if let Err(e) = validate_fee_calculation(amount, &user) {
-        handle_error(env, e);
-    }
+pub fn is_reward_source(env: &Env, source: Address) -> bool {
+    let sources: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&REWARD_SOURCES)
+        .unwrap_or(Vec::new(env));
+    sources.contains(&source)
+}
 
-    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap();
-    let is_premium = is_premium_user(env, user);
+/// Moves `amount` of `asset` from the admin into the keeper budget and sets the most a
+/// single reward may credit. Keeper rewards are only ever paid out of this budget, never
+/// out of accrued fees. Returns the remaining budget.
+pub fn fund_keeper_budget(
+    env: &Env,
+    admin: Address,
+    asset: Address,
+    amount: i128,
+    max_reward: i128,
+) -> Result<i128, Error> {
+    require_admin(env, &admin)?;
+    validate_amount(amount)?;
+    validate_amount(max_reward)?;
+
+    env.invoke_contract::<()>(
+        &asset,
+        &Symbol::new(env, "transfer"),
+        (admin.clone(), env.current_contract_address(), amount).into_val(env),
+    );
 
-    let fee_percentage = if is_premium {
-        0
-    } else {
-        fee_config.escrow_fee_percentage
-    };
-    let fee_amount = calculate_fee_amount(amount, fee_percentage);
-    let net_amount = amount - fee_amount;
+    let mut budgets = keeper_budgets(env);
+    let remaining = budgets
+        .get(asset.clone())
+        .map_or(0, |budget| budget.remaining)
+        + amount;
+    budgets.set(
+        asset.clone(),
+        KeeperBudget {
+            remaining,
+            max_reward,
+        },
+    );
+    env.storage().instance().set(&KEEPER_BUDGETS, &budgets);
 
-    FeeCalculation {
-        original_amount: amount,
-        fee_amount,
-        net_amount,
-        fee_percentage,
-        is_premium,
-    }
+    env.events().publish(
+        (Symbol::new(env, "keeper_budget_funded"), admin, asset),
+        (amount, remaining, max_reward, env.ledger().timestamp()),
+    );
+
+    Ok(remaining)
 }
 
-pub fn calculate_dispute_fee(env: &Env, amount: i128, user: Address) -> FeeCalculation {
+pub fn get_keeper_budget(env: &Env, asset: Address) -> Option<KeeperBudget> {
+    keeper_budgets(env).get(asset)
+}
+
+/// Credits `keeper` with up to `amount` of `asset` out of the keeper budget. A payment is
+/// clipped to the budget's per-reward cap and to what is left, so an exhausted budget pays
+/// nothing rather than failing the caller. Returns the amount credited.
+pub fn pay_keeper_reward(
+    env: &Env,
+    source: Address,
+    keeper: Address,
+    asset: Address,
+    amount: i128,
+) -> i128 {
     if is_paused(env) {
         handle_error(env, Error::ContractPaused);
     }
-    // Input validation
-    if let Err(e) = validate_fee_calculation(amount, &user) {
-        handle_error(env, e);
+    source.require_auth();
+
+    if !is_reward_source(env, source.clone()) {
+        handle_error(env, Error::RewardSourceNotFound);
     }
 
-    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap();
-    let is_premium = is_premium_user(env, user);
+    if let Err(e) = validate_amount(amount) {
+        handle_error(env, e);
+    }
 
-    let fee_percentage = if is_premium {
-        0
-    } else {
-        fee_config.dispute_fee_percentage
+    let mut budgets = keeper_budgets(env);
+    let Some(mut budget) = budgets.get(asset.clone()) else {
+        return 0;
     };
-    let fee_amount = calculate_fee_amount(amount, fee_percentage);
-    let net_amount = amount - fee_amount;
-
-    FeeCalculation {
-        original_amount: amount,
-        fee_amount,
-        net_amount,
-        fee_percentage,
-        is_premium,
+    let paid = amount.min(budget.max_reward).min(budget.remaining);
+    if paid == 0 {
+        return 0;
     }
+    budget.remaining -= paid;
+    budgets.set(asset.clone(), budget);
+    env.storage().instance().set(&KEEPER_BUDGETS, &budgets);
+
+    let mut rewards = keeper_rewards(env);
+    let key = (keeper.clone(), asset.clone());
+    let accrued = rewards.get(key.clone()).unwrap_or(0) + paid;
+    rewards.set(key, accrued);
+    env.storage().instance().set(&KEEPER_REWARDS, &rewards);
+
+    env.events().publish(
+        (Symbol::new(env, "keeper_reward_paid"), source, keeper),
+        (asset, paid, accrued, env.ledger().timestamp()),
+    );
+
+    paid
 }
 
-pub fn collect_fee(env: &Env, amount: i128, fee_type: u32, user: Address) -> i128 {
+pub fn get_keeper_rewards(env: &Env, keeper: Address, asset: Address) -> i128 {
+    keeper_rewards(env).get((keeper, asset)).unwrap_or(0)
+}
+
+/// Pays a keeper's rewards in `asset`; they were set aside from the keeper budget when
+/// credited.
+pub fn claim_keeper_rewards(env: &Env, keeper: Address, asset: Address) -> Result<i128, Error> {
     if is_paused(env) {
-        handle_error(env, Error::ContractPaused);
-    }
-    // Input validation
-    if let Err(e) = validate_fee_calculation(amount, &user) {
-        handle_error(env, e);
+        return Err(Error::ContractPaused);
     }
-    if let Err(e) = validate_fee_type(fee_type) {
-        handle_error(env, e);
+    keeper.require_auth();
+
+    let mut rewards = keeper_rewards(env);
+    let key = (keeper.clone(), asset.clone());
+    let amount = rewards.get(key.clone()).unwrap_or(0);
+    if amount <= 0 {
+        return Err(Error::NoRewardsToClaim);
     }
 
-    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap();
-    let is_premium = is_premium_user(env, user.clone());
-    let fee_percentage = match fee_type {
-        FEE_TYPE_ESCROW => {
-            if is_premium {
-                0
-            } else {
-                fee_config.escrow_fee_percentage
-            }
-        }
-        FEE_TYPE_DISPUTE => {
-            if is_premium {
-                0
-            } else {
-                fee_config.dispute_fee_percentage
-            }
-        }
-        _ => 0,
-    };
+    rewards.remove(key);
+    env.storage().instance().set(&KEEPER_REWARDS, &rewards);
+    transfer_out(env, &asset, &keeper, amount);
 
-    let fee_amount = calculate_fee_amount(amount, fee_percentage);
+    env.events().publish(
+        (Symbol::new(env, "keeper_rewards_claimed"), keeper, asset),
+        (amount, env.ledger().timestamp()),
+    );
 
-    let net_amount = amount - fee_amount;
+    Ok(amount)
+}
 
-    // Update platform balance
-    let mut platform_balance: i128 = env.storage().instance().get(&PLATFORM_BALANCE).unwrap();
+fn keeper_budgets(env: &Env) -> Map<Address, KeeperBudget> {
+    env.storage()
+        .instance()
+        .get(&KEEPER_BUDGETS)
+        .unwrap_or(Map::new(env))
+}
 
-    platform_balance += fee_amount;
+fn keeper_rewards(env: &Env) -> Map<(Address, Address), i128> {
     env.storage()
         .instance()
-        .set(&PLATFORM_BALANCE, &platform_balance);
+        .get(&KEEPER_REWARDS)
+        .unwrap_or(Map::new(env))
+}
 
-    // Update total fees collected
-    let mut total_fees: i128 = env
+pub fn set_arbitrator_reward_config(env: &Env, config: ArbitratorRewardConfig) {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+    let fee_config: FeeConfig = env
         .storage()
         .instance()
-        .get(&TOTAL_FESS_COLLECTED)
-        .unwrap_or(0);
-    total_fees += fee_amount;
+        .get(&FEE_CONFIG)
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized));
+    fee_config.admin.require_auth();
+
+    if config.share_bps > MAX_BPS {
+        handle_error(env, Error::InvalidFeePercentage);
+    }
+    if config.epoch_secs == 0 {
+        handle_error(env, Error::InvalidAmount);
+    }
+
     env.storage()
         .instance()
-        .set(&TOTAL_FESS_COLLECTED, &total_fees);
+        .set(&ARBITRATOR_REWARD_CONFIG, &config);
 
-    // Update fee stats
-    let mut fee_stats: FeeStats = env.storage().instance().get(&FEE_STATS).unwrap();
-    fee_stats.total_fees_collected += fee_amount;
-    fee_stats.total_transactions += 1;
+    env.events().publish(
+        (
+            Symbol::new(env, "arbitrator_rewards_configured"),
+            fee_config.admin.clone(),
+        ),
+        (config.share_bps, config.epoch_secs),
+    );
+}
 
-    match fee_type {
-        FEE_TYPE_ESCROW => fee_stats.total_escrow_fees += fee_amount,
-        FEE_TYPE_DISPUTE => fee_stats.total_dispute_fees += fee_amount,
-        _ => {}
-    }
+pub fn get_arbitrator_reward_config(env: &Env) -> Option<ArbitratorRewardConfig> {
+    env.storage().instance().get(&ARBITRATOR_REWARD_CONFIG)
+}
 
-    if is_premium {
-        fee_stats.total_premium_exemptions += fee_amount;
+/// Credits `arbitrator` with a resolved dispute in the current epoch. Only approved
+/// reward sources (the dispute contract) may report resolutions.
+pub fn record_arbitrator_resolution(env: &Env, source: Address, arbitrator: Address) -> u32 {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
     }
+    source.require_auth();
 
-    env.storage().instance().set(&FEE_STATS, &fee_stats);
+    if !is_reward_source(env, source.clone()) {
+        handle_error(env, Error::RewardSourceNotFound);
+    }
 
-    // Record fee transaction
-    let mut fee_history: Vec<FeeRecord> = env.storage().instance().get(&FEE_HISTORY).unwrap();
-    let user_clone = user.clone();
+    let reward_config = get_arbitrator_reward_config(env)
+        .unwrap_or_else(|| handle_error(env, Error::RewardConfigNotSet));
+    let epoch = env.ledger().timestamp() / reward_config.epoch_secs;
 
-    let fee_record = FeeRecord {
-        timestamp: env.ledger().timestamp(),
-        fee_type: fee_type,
-        amount: fee_amount,
-        user: user_clone,
-        transaction_id: String::from_str(env, "tx"),
-    };
-    fee_history.push_back(fee_record);
-    env.storage().instance().set(&FEE_HISTORY, &fee_history);
+    let mut resolutions = get_epoch_resolutions(env, epoch);
+    let count = resolutions.get(arbitrator.clone()).unwrap_or(0) + 1;
+    resolutions.set(arbitrator.clone(), count);
+    env.storage()
+        .persistent()
+        .set(&(ARBITRATOR_RESOLUTIONS, epoch), &resolutions);
 
     env.events().publish(
-        (Symbol::new(env, "fee_collected"), user),
-        (fee_amount, net_amount, env.ledger().timestamp(), total_fees),
+        (
+            Symbol::new(env, "arbitrator_resolution_recorded"),
+            arbitrator,
+        ),
+        (epoch, count),
     );
 
-    net_amount
+    count
 }
 
-pub fn withdraw_platform_fees(env: &Env, amount: i128) {
+/// Splits the configured share of a finished epoch's fees in `asset` between the
+/// arbitrators who resolved disputes in it, in proportion to their resolutions. Anyone may
+/// trigger it; the amounts become claimable with `claim_arbitrator_rewards`. Returns the
+/// total paid out.
+pub fn distribute_arbitrator_rewards(env: &Env, epoch: u64, asset: Address) -> i128 {
     if is_paused(env) {
         handle_error(env, Error::ContractPaused);
     }
-    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap();
-    fee_config.admin.require_auth();
 
-    let mut platform_balance: i128 = env.storage().instance().get(&PLATFORM_BALANCE).unwrap();
+    let reward_config = get_arbitrator_reward_config(env)
+        .unwrap_or_else(|| handle_error(env, Error::RewardConfigNotSet));
+    if env.ledger().timestamp() / reward_config.epoch_secs <= epoch {
+        handle_error(env, Error::EpochNotEnded);
+    }
 
-    // Input validation
-    if let Err(e) = validate_withdrawal_amount(amount, platform_balance) {
-        handle_error(env, e);
+    let mut distributed: Map<(u64, Address), i128> = env
+        .storage()
+        .instance()
+        .get(&DISTRIBUTED_EPOCHS)
+        .unwrap_or(Map::new(env));
+    let key = (epoch, asset.clone());
+    if distributed.contains_key(key.clone()) {
+        handle_error(env, Error::EpochAlreadyDistributed);
     }
 
-    platform_balance -= amount;
+    let resolutions = get_epoch_resolutions(env, epoch);
+    let total_resolutions: i128 = resolutions.values().iter().map(|c| c as i128).sum();
+
+    let epoch_fees: Map<(u64, Address), i128> = env
+        .storage()
+        .instance()
+        .get(&EPOCH_FEES)
+        .unwrap_or(Map::new(env));
+
+    // Fees already withdrawn can't be paid out again
+    let pool = calculate_fee_amount(
+        env,
+        epoch_fees.get(key.clone()).unwrap_or(0),
+        reward_config.share_bps as i128,
+    )
+    .min(get_accrued(env, asset.clone()));
+
+    let mut paid = 0i128;
+    if pool > 0 && total_resolutions > 0 {
+        let mut rewards = arbitrator_rewards(env);
+        for (arbitrator, count) in resolutions.iter() {
+            // Rounding dust stays with the accrued fees
+            let share = mul_div(pool, count as i128, total_resolutions, Rounding::Down)
+                .unwrap_or_else(|e| handle_error(env, e.into()));
+            let reward_key = (arbitrator, asset.clone());
+            let accrued = rewards.get(reward_key.clone()).unwrap_or(0) + share;
+            rewards.set(reward_key, accrued);
+            paid += share;
+        }
+        env.storage().instance().set(&ARBITRATOR_REWARDS, &rewards);
+    }
+
+    // The distributed pool moves into the reserve so treasury withdrawals can't spend it
+    if paid > 0 {
+        if let Err(e) = debit_accrued(env, &asset, paid) {
+            handle_error(env, e);
+        }
+        let mut reserve = arbitrator_reserve(env);
+        let reserved = reserve.get(asset.clone()).unwrap_or(0) + paid;
+        reserve.set(asset.clone(), reserved);
+        env.storage().instance().set(&ARBITRATOR_RESERVE, &reserve);
+    }
+
+    distributed.set(key, paid);
     env.storage()
         .instance()
-        .set(&PLATFORM_BALANCE, &platform_balance);
+        .set(&DISTRIBUTED_EPOCHS, &distributed);
+
+    env.events().publish(
+        (Symbol::new(env, "arbitrator_rewards_distributed"), epoch, asset),
+        (paid, resolutions.len(), env.ledger().timestamp()),
+    );
+
+    paid
+}
+
+/// Pays the arbitrator's rewards in `asset` out of the arbitrator reward reserve.
+pub fn claim_arbitrator_rewards(env: &Env, arbitrator: Address, asset: Address) -> i128 {
+    if is_paused(env) {
+        handle_error(env, Error::ContractPaused);
+    }
+    arbitrator.require_auth();
+
+    let mut rewards = arbitrator_rewards(env);
+    let key = (arbitrator.clone(), asset.clone());
+    let amount = rewards.get(key.clone()).unwrap_or(0);
+    if amount <= 0 {
+        handle_error(env, Error::NoRewardsToClaim);
+    }
+
+    let mut reserve = arbitrator_reserve(env);
+    let reserved = reserve.get(asset.clone()).unwrap_or(0);
+    if let Err(e) = validate_withdrawal_amount(amount, reserved) {
+        handle_error(env, e);
+    }
+    reserve.set(asset.clone(), reserved - amount);
+    env.storage().instance().set(&ARBITRATOR_RESERVE, &reserve);
+    rewards.remove(key);
+    env.storage().instance().set(&ARBITRATOR_REWARDS, &rewards);
+    transfer_out(env, &asset, &arbitrator, amount);
 
     env.events().publish(
         (
-            Symbol::new(env, "platform_fees_withdrawn"),
-            fee_config.admin.clone(),
+            Symbol::new(env, "arbitrator_rewards_claimed"),
+            arbitrator,
+            asset,
         ),
-        (amount, platform_balance, env.ledger().timestamp()),
+        (amount, env.ledger().timestamp()),
     );
+
+    amount
+}
+
+pub fn get_arbitrator_rewards(env: &Env, arbitrator: Address, asset: Address) -> i128 {
+    arbitrator_rewards(env)
+        .get((arbitrator, asset))
+        .unwrap_or(0)
+}
+
+pub fn get_arbitrator_reserve(env: &Env, asset: Address) -> i128 {
+    arbitrator_reserve(env).get(asset).unwrap_or(0)
+}
+
+pub fn get_epoch_resolutions(env: &Env, epoch: u64) -> Map<Address, u32> {
+    env.storage()
+        .persistent()
+        .get(&(ARBITRATOR_RESOLUTIONS, epoch))
+        .unwrap_or(Map::new(env))
+}
+
+fn arbitrator_reserve(env: &Env) -> Map<Address, i128> {
+    env.storage()
+        .instance()
+        .get(&ARBITRATOR_RESERVE)
+        .unwrap_or(Map::new(env))
+}
+
+fn arbitrator_rewards(env: &Env) -> Map<(Address, Address), i128> {
+    env.storage()
+        .instance()
+        .get(&ARBITRATOR_REWARDS)
+        .unwrap_or(Map::new(env))
 }
 
 pub fn get_fee_config(env: &Env) -> FeeConfig {
@@ -421,6 +1718,10 @@ pub fn get_fee_config(env: &Env) -> FeeConfig {
     env.storage().instance().get(&FEE_CONFIG).unwrap()
 }
 
+pub fn get_platform_wallet(env: &Env) -> Address {
+    get_fee_config(env).platform_wallet
+}
+
 pub fn is_premium_user(env: &Env, user: Address) -> bool {
     let premium_users: Vec<PremiumUser> = env.storage().instance().get(&PREMIUM_USERS).unwrap();
 
@@ -440,12 +1741,65 @@ pub fn get_platform_balance(env: &Env) -> i128 {
     }
 }
 
-pub fn get_fee_history(env: &Env) -> Vec<FeeRecord> {
-    if env.storage().instance().has(&FEE_HISTORY) {
-        env.storage().instance().get(&FEE_HISTORY).unwrap()
-    } else {
-        Vec::new(env)
+/// Collected fees in collection order. Entries live in persistent storage, one per
+/// collection, so the log can grow without bloating the instance.
+pub fn get_fee_history(env: &Env, offset: u32, limit: u32) -> Vec<FeeRecord> {
+    let count = get_fee_history_count(env);
+    let end = offset
+        .saturating_add(limit.min(MAX_HISTORY_PAGE_SIZE))
+        .min(count);
+    let mut page = Vec::new(env);
+    for index in offset..end {
+        if let Some(record) = env.storage().persistent().get(&(FEE_HISTORY, index)) {
+            page.push_back(record);
+        }
     }
+    page
+}
+
+pub fn get_fee_history_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&FEE_HISTORY_COUNT)
+        .unwrap_or(0)
+}
+
+pub fn get_payer_fee_total(env: &Env, payer: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(PAYER_FEE_TOTALS, payer))
+        .unwrap_or(0)
+}
+
+fn record_fee(
+    env: &Env,
+    payer: &Address,
+    source: &Address,
+    fee_type: u32,
+    asset: Option<Address>,
+    amount: i128,
+) {
+    let index = get_fee_history_count(env);
+    let record = FeeRecord {
+        timestamp: env.ledger().timestamp(),
+        ledger: env.ledger().sequence(),
+        fee_type,
+        asset,
+        amount,
+        user: payer.clone(),
+        transaction_id: source.to_string(),
+    };
+    env.storage()
+        .persistent()
+        .set(&(FEE_HISTORY, index), &record);
+    env.storage()
+        .instance()
+        .set(&FEE_HISTORY_COUNT, &(index + 1));
+
+    let total = get_payer_fee_total(env, payer.clone()) + amount;
+    env.storage()
+        .persistent()
+        .set(&(PAYER_FEE_TOTALS, payer.clone()), &total);
 }
 
 pub fn get_fee_stats(env: &Env) -> FeeStats {
@@ -538,22 +1892,39 @@ pub fn get_platform_stats(env: &Env) -> Result<PlatformStats, Error> {
     Ok(stats)
 }
 
+// Escrow fee rate for a non-premium user, after reputation tiers
+fn escrow_fee_percentage_for(env: &Env, fee_config: &FeeConfig, user: &Address) -> i128 {
+    let Some(fee_tiers) = get_fee_tiers(env) else {
+        return fee_config.escrow_fee_percentage;
+    };
+
+    let score: u32 = env.invoke_contract(
+        &fee_tiers.reputation_contract,
+        &Symbol::new(env, "get_user_reputation_score"),
+        (user.clone(),).into_val(env),
+    );
+
+    let mut fee_percentage = fee_config.escrow_fee_percentage;
+    for tier in fee_tiers.tiers.iter() {
+        if score >= tier.min_score {
+            fee_percentage = tier.fee_percentage;
+        }
+    }
+    fee_percentage
+}
+
 // Helper function to calculate fee amount with precision
-fn calculate_fee_amount(amount: i128, fee_percentage: i128) -> i128 {
+fn calculate_fee_amount(env: &Env, amount: i128, fee_percentage: i128) -> i128 {
     if fee_percentage == 0 {
         return 0;
     }
 
-    // Calculate fee: (amount * fee_percentage) / 10000
-    // This ensures precision and handles basis points correctly (100 = 1%)
-    let fee_amount = (amount * fee_percentage) / 10000;
+    // Fee percentages are basis points (100 = 1%), rounded down in the payer's favour
+    let fee_amount = mul_div(amount, fee_percentage, MAX_BPS as i128, Rounding::Down)
+        .unwrap_or_else(|e| handle_error(env, e.into()));
 
     // Ensure fee doesn't exceed the original amount
-    if fee_amount > amount {
-        amount
-    } else {
-        fee_amount
-    }
+    fee_amount.min(amount)
 }
 
 
@@ -1,3 +1,5 @@
+use offerhub_math::MathError;
+use offerhub_pausable::PauseError;
 use soroban_sdk::{contracterror, Env};
 
 #[contracterror]
@@ -18,6 +20,43 @@ pub enum Error {
     AlreadyPaused = 12,
     NotPaused = 13,
     ContractPaused = 14,
+    RewardSourceNotFound = 15,    // Caller is not an approved keeper reward source
+    RewardConfigNotSet = 16,      // Arbitrator rewards have not been configured
+    EpochNotEnded = 17,           // Epoch is still running
+    EpochAlreadyDistributed = 18, // Rewards for this epoch were already distributed
+    NoRewardsToClaim = 19,        // Arbitrator or keeper has no claimable rewards
+    InvalidFeeTiers = 20,         // Tiers must be non-empty, ascending and within fee limits
+    InvalidExpiry = 21,           // Expiry must be in the future
+    ExemptionNotFound = 22,       // Address has no fee exemption
+    TreasuryNotSet = 23,          // Admin has not configured a treasury address
+    SelfReferral = 24,            // Users cannot refer themselves
+    AssetNotAccepted = 25,        // Asset is not on the fee asset list
+    AssetAlreadyAccepted = 26,    // Asset is already on the fee asset list
+    PromoNotFound = 27,           // No promo matches the code
+    PromoExpired = 28,            // Promo is past its expiry
+    PromoExhausted = 29,          // Promo has reached its maximum uses
+    PromoAlreadyUsed = 30,        // User has already redeemed this promo
+    InvalidPromo = 31,            // Discount or use limit out of range, or code already exists
+    TimelockActive = 32,          // Fee rates and tiers must be changed through schedule/execute
+    NoPendingFeeChange = 33,      // No fee change is scheduled
+    FeeChangeNotReady = 34,       // Timelock delay has not elapsed yet
+    TimelockDecrease = 35,        // Timelock delay can only be raised
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
+
+impl From<MathError> for Error {
+    fn from(_: MathError) -> Self {
+        Error::FeeCalculationError
+    }
 }
 
 pub fn handle_error(env: &Env, error: Error) -> ! {
@@ -0,0 +1,69 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::{
+    storage::FEE_CONFIG,
+    types::{FeeCollectedEvent, FeeConfig, FeeConfigEvent},
+};
+
+/// Bumped whenever a fee event payload changes shape.
+pub const FEE_EVENT_VERSION: u32 = 1;
+
+pub const CHANGE_RATES: &str = "rates";
+pub const CHANGE_TIERS: &str = "tiers";
+pub const CHANGE_EXEMPTION_ADDED: &str = "exemption_added";
+pub const CHANGE_EXEMPTION_REMOVED: &str = "exemption_removed";
+pub const CHANGE_PREMIUM_ADDED: &str = "premium_added";
+pub const CHANGE_PREMIUM_REMOVED: &str = "premium_removed";
+pub const CHANGE_ASSET_ADDED: &str = "asset_added";
+pub const CHANGE_ASSET_REMOVED: &str = "asset_removed";
+
+/// Publishes `("fee_event", "collected")` for every fee charged, so revenue can be
+/// aggregated per payer, fee type and asset from the event stream alone.
+#[allow(clippy::too_many_arguments)]
+pub fn emit_fee_collected(
+    env: &Env,
+    payer: &Address,
+    fee_type: u32,
+    asset: &Option<Address>,
+    amount: i128,
+    fee_amount: i128,
+    fee_percentage: i128,
+    total_collected: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "fee_event"), Symbol::new(env, "collected")),
+        FeeCollectedEvent {
+            version: FEE_EVENT_VERSION,
+            payer: payer.clone(),
+            fee_type,
+            asset: asset.clone(),
+            amount,
+            fee_amount,
+            fee_percentage,
+            total_collected,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+/// Publishes `("fee_config_event", change)` with the rates in force after the change and
+/// the account or asset it touched, if any.
+pub fn emit_config_change(env: &Env, change: &str, actor: &Address, subject: Option<Address>) {
+    let fee_config: FeeConfig = env.storage().instance().get(&FEE_CONFIG).unwrap();
+
+    env.events().publish(
+        (
+            Symbol::new(env, "fee_config_event"),
+            Symbol::new(env, change),
+        ),
+        FeeConfigEvent {
+            version: FEE_EVENT_VERSION,
+            actor: actor.clone(),
+            subject,
+            escrow_fee_percentage: fee_config.escrow_fee_percentage,
+            dispute_fee_percentage: fee_config.dispute_fee_percentage,
+            arbitrator_fee_percentage: fee_config.arbitrator_fee_percentage,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
@@ -1,8 +1,9 @@
 #![no_std]
 use crate::error::Error;
-use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Map, Vec};
 mod contract;
 mod error;
+mod events;
 mod storage;
 mod types;
 mod validation;
@@ -13,36 +14,196 @@ mod test;
 #[contract]
 pub struct FeeManagerContract;
 
+offerhub_pausable::impl_pausable!(FeeManagerContract, Error, |env, _| {
+    Ok(contract::pause_admin(env))
+});
+
 #[contractimpl]
 impl FeeManagerContract {
     pub fn initialize(env: Env, admin: Address, platform_wallet: Address) {
         contract::initialize(&env, admin, platform_wallet);
     }
 
-    pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
-        contract::pause(&env, admin)
+    pub fn set_fee_rates(
+        env: Env,
+        escrow_fee_percentage: i128,
+        dispute_fee_percentage: i128,
+        arbitrator_fee_percentage: i128,
+    ) {
+        contract::set_fee_rates(
+            &env,
+            escrow_fee_percentage,
+            dispute_fee_percentage,
+            arbitrator_fee_percentage,
+        );
     }
 
-    pub fn is_paused(env: Env) -> bool {
-        contract::is_paused(&env)
+    pub fn set_fee_tiers(env: Env, fee_tiers: types::ReputationFeeTiers) {
+        contract::set_fee_tiers(&env, fee_tiers);
     }
 
-    pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
-        contract::unpause(&env, admin)
+    pub fn get_fee_tiers(env: Env) -> Option<types::ReputationFeeTiers> {
+        contract::get_fee_tiers(&env)
     }
 
-    pub fn set_fee_rates(
+    pub fn add_fee_exemption(
+        env: Env,
+        admin: Address,
+        address: Address,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        contract::add_fee_exemption(&env, admin, address, expires_at)
+    }
+
+    pub fn remove_fee_exemption(env: Env, admin: Address, address: Address) -> Result<(), Error> {
+        contract::remove_fee_exemption(&env, admin, address)
+    }
+
+    pub fn is_fee_exempt(env: Env, address: Address) -> bool {
+        contract::is_fee_exempt(&env, address)
+    }
+
+    pub fn get_fee_exemptions(env: Env, offset: u32, limit: u32) -> Vec<types::FeeExemption> {
+        contract::get_fee_exemptions(&env, offset, limit)
+    }
+
+    pub fn set_treasury(env: Env, admin: Address, treasury: Address) -> Result<(), Error> {
+        contract::set_treasury(&env, admin, treasury)
+    }
+
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        contract::get_treasury(&env)
+    }
+
+    pub fn set_escrow_factory(env: Env, admin: Address, factory: Address) -> Result<(), Error> {
+        contract::set_escrow_factory(&env, admin, factory)
+    }
+
+    pub fn get_escrow_factory(env: Env) -> Option<Address> {
+        contract::get_escrow_factory(&env)
+    }
+
+    pub fn deposit_fee(env: Env, payer: Address, asset: Address, amount: i128) -> i128 {
+        contract::deposit_fee(&env, payer, asset, amount)
+    }
+
+    pub fn collect_fee_in_asset(
         env: Env,
+        caller: Address,
+        payer: Address,
+        asset: Address,
+        amount: i128,
+        fee_type: u32,
+    ) -> i128 {
+        contract::collect_fee_in_asset(&env, caller, payer, asset, amount, fee_type)
+    }
+
+    pub fn add_fee_asset(env: Env, admin: Address, asset: Address) -> Result<(), Error> {
+        contract::add_fee_asset(&env, admin, asset)
+    }
+
+    pub fn remove_fee_asset(env: Env, admin: Address, asset: Address) -> Result<(), Error> {
+        contract::remove_fee_asset(&env, admin, asset)
+    }
+
+    pub fn get_fee_assets(env: Env) -> Vec<Address> {
+        contract::get_fee_assets(&env)
+    }
+
+    pub fn is_fee_asset(env: Env, asset: Address) -> bool {
+        contract::is_fee_asset(&env, asset)
+    }
+
+    pub fn withdraw_to_treasury(
+        env: Env,
+        admin: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        contract::withdraw_to_treasury(&env, admin, asset, amount)
+    }
+
+    pub fn get_accrued(env: Env, asset: Address) -> i128 {
+        contract::get_accrued(&env, asset)
+    }
+
+    pub fn create_promo(
+        env: Env,
+        admin: Address,
+        code_hash: BytesN<32>,
+        discount_bps: u32,
+        max_uses: u32,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        contract::create_promo(&env, admin, code_hash, discount_bps, max_uses, expires_at)
+    }
+
+    pub fn apply_promo(env: Env, user: Address, code: Bytes) -> Result<(), Error> {
+        contract::apply_promo(&env, user, code)
+    }
+
+    pub fn get_promo(env: Env, code_hash: BytesN<32>) -> Option<types::Promo> {
+        contract::get_promo(&env, code_hash)
+    }
+
+    pub fn get_active_promo(env: Env, user: Address) -> Option<BytesN<32>> {
+        contract::get_active_promo(&env, user)
+    }
+
+    pub fn set_fee_timelock(env: Env, admin: Address, delay_secs: u64) -> Result<(), Error> {
+        contract::set_fee_timelock(&env, admin, delay_secs)
+    }
+
+    pub fn get_fee_timelock(env: Env) -> u64 {
+        contract::get_fee_timelock(&env)
+    }
+
+    pub fn schedule_fee_change(
+        env: Env,
+        admin: Address,
         escrow_fee_percentage: i128,
         dispute_fee_percentage: i128,
         arbitrator_fee_percentage: i128,
-    ) {
-        contract::set_fee_rates(
+    ) -> Result<u64, Error> {
+        contract::schedule_fee_change(
             &env,
+            admin,
             escrow_fee_percentage,
             dispute_fee_percentage,
             arbitrator_fee_percentage,
-        );
+        )
+    }
+
+    pub fn execute_fee_change(env: Env) -> Result<(), Error> {
+        contract::execute_fee_change(&env)
+    }
+
+    pub fn cancel_fee_change(env: Env, admin: Address) -> Result<(), Error> {
+        contract::cancel_fee_change(&env, admin)
+    }
+
+    pub fn get_pending_fee_change(env: Env) -> Option<types::PendingFeeChange> {
+        contract::get_pending_fee_change(&env)
+    }
+
+    pub fn schedule_fee_tiers_change(
+        env: Env,
+        admin: Address,
+        fee_tiers: types::ReputationFeeTiers,
+    ) -> Result<u64, Error> {
+        contract::schedule_fee_tiers_change(&env, admin, fee_tiers)
+    }
+
+    pub fn execute_fee_tiers_change(env: Env) -> Result<(), Error> {
+        contract::execute_fee_tiers_change(&env)
+    }
+
+    pub fn cancel_fee_tiers_change(env: Env, admin: Address) -> Result<(), Error> {
+        contract::cancel_fee_tiers_change(&env, admin)
+    }
+
+    pub fn get_pending_fee_tiers(env: Env) -> Option<types::PendingFeeTiers> {
+        contract::get_pending_fee_tiers(&env)
     }
 
     pub fn add_premium_user(env: Env, user: Address) {
@@ -61,22 +222,136 @@ impl FeeManagerContract {
         contract::calculate_dispute_fee(&env, amount, user)
     }
 
-    pub fn collect_fee(env: Env, amount: i128, fee_type: u32, user: Address) -> i128 {
-        contract::collect_fee(&env, amount, fee_type, user)
+    pub fn collect_fee(
+        env: Env,
+        caller: Address,
+        amount: i128,
+        fee_type: u32,
+        user: Address,
+    ) -> i128 {
+        contract::collect_fee(&env, caller, amount, fee_type, user)
     }
 
-    pub fn collect(env: Env, amount: i128, fee_type: u32, user: Address) -> i128 {
-        contract::collect_fee(&env, amount, fee_type, user)
+    pub fn collect(env: Env, caller: Address, amount: i128, fee_type: u32, user: Address) -> i128 {
+        contract::collect_fee(&env, caller, amount, fee_type, user)
+    }
+
+    pub fn collect_fee_with_referrer(
+        env: Env,
+        caller: Address,
+        amount: i128,
+        fee_type: u32,
+        user: Address,
+        referrer: Address,
+        asset: Address,
+    ) -> i128 {
+        contract::collect_fee_with_referrer(&env, caller, amount, fee_type, user, referrer, asset)
+    }
+
+    pub fn set_referral_share(env: Env, admin: Address, share_bps: u32) -> Result<(), Error> {
+        contract::set_referral_share(&env, admin, share_bps)
+    }
+
+    pub fn get_referral_share(env: Env) -> u32 {
+        contract::get_referral_share(&env)
+    }
+
+    pub fn claim_referral_earnings(
+        env: Env,
+        referrer: Address,
+        asset: Address,
+    ) -> Result<i128, Error> {
+        contract::claim_referral_earnings(&env, referrer, asset)
+    }
+
+    pub fn get_referral_earnings(env: Env, referrer: Address, asset: Address) -> i128 {
+        contract::get_referral_earnings(&env, referrer, asset)
     }
 
     pub fn withdraw_platform_fees(env: Env, amount: i128) {
         contract::withdraw_platform_fees(&env, amount);
     }
 
+    pub fn add_reward_source(env: Env, source: Address) {
+        contract::add_reward_source(&env, source);
+    }
+
+    pub fn is_reward_source(env: Env, source: Address) -> bool {
+        contract::is_reward_source(&env, source)
+    }
+
+    pub fn fund_keeper_budget(
+        env: Env,
+        admin: Address,
+        asset: Address,
+        amount: i128,
+        max_reward: i128,
+    ) -> Result<i128, Error> {
+        contract::fund_keeper_budget(&env, admin, asset, amount, max_reward)
+    }
+
+    pub fn get_keeper_budget(env: Env, asset: Address) -> Option<types::KeeperBudget> {
+        contract::get_keeper_budget(&env, asset)
+    }
+
+    pub fn pay_keeper_reward(
+        env: Env,
+        source: Address,
+        keeper: Address,
+        asset: Address,
+        amount: i128,
+    ) -> i128 {
+        contract::pay_keeper_reward(&env, source, keeper, asset, amount)
+    }
+
+    pub fn get_keeper_rewards(env: Env, keeper: Address, asset: Address) -> i128 {
+        contract::get_keeper_rewards(&env, keeper, asset)
+    }
+
+    pub fn claim_keeper_rewards(env: Env, keeper: Address, asset: Address) -> Result<i128, Error> {
+        contract::claim_keeper_rewards(&env, keeper, asset)
+    }
+
+    pub fn set_arbitrator_reward_config(env: Env, config: types::ArbitratorRewardConfig) {
+        contract::set_arbitrator_reward_config(&env, config);
+    }
+
+    pub fn get_arbitrator_reward_config(env: Env) -> Option<types::ArbitratorRewardConfig> {
+        contract::get_arbitrator_reward_config(&env)
+    }
+
+    pub fn record_arbitrator_resolution(env: Env, source: Address, arbitrator: Address) -> u32 {
+        contract::record_arbitrator_resolution(&env, source, arbitrator)
+    }
+
+    pub fn distribute_arbitrator_rewards(env: Env, epoch: u64, asset: Address) -> i128 {
+        contract::distribute_arbitrator_rewards(&env, epoch, asset)
+    }
+
+    pub fn claim_arbitrator_rewards(env: Env, arbitrator: Address, asset: Address) -> i128 {
+        contract::claim_arbitrator_rewards(&env, arbitrator, asset)
+    }
+
+    pub fn get_arbitrator_rewards(env: Env, arbitrator: Address, asset: Address) -> i128 {
+        contract::get_arbitrator_rewards(&env, arbitrator, asset)
+    }
+
+    pub fn get_arbitrator_reserve(env: Env, asset: Address) -> i128 {
+        contract::get_arbitrator_reserve(&env, asset)
+    }
+
+    pub fn get_epoch_resolutions(env: Env, epoch: u64) -> Map<Address, u32> {
+        contract::get_epoch_resolutions(&env, epoch)
+    }
+
     pub fn get_fee_config(env: Env) -> types::FeeConfig {
         contract::get_fee_config(&env)
     }
 
+    pub fn get_platform_wallet(env: Env) -> Address {
+        contract::get_platform_wallet(&env)
+    }
+
     pub fn is_premium_user(env: Env, user: Address) -> bool {
         contract::is_premium_user(&env, user)
     }
@@ -85,8 +360,16 @@ impl FeeManagerContract {
         contract::get_platform_balance(&env)
     }
 
-    pub fn get_fee_history(env: Env) -> Vec<types::FeeRecord> {
-        contract::get_fee_history(&env)
+    pub fn get_fee_history(env: Env, offset: u32, limit: u32) -> Vec<types::FeeRecord> {
+        contract::get_fee_history(&env, offset, limit)
+    }
+
+    pub fn get_fee_history_count(env: Env) -> u32 {
+        contract::get_fee_history_count(&env)
+    }
+
+    pub fn get_payer_fee_total(env: Env, payer: Address) -> i128 {
+        contract::get_payer_fee_total(&env, payer)
     }
 
     pub fn get_fee_stats(env: Env) -> types::FeeStats {
@@ -3,15 +3,61 @@ use soroban_sdk::{symbol_short, Symbol};
 // Storage keys for fee configuration
 pub const FEE_CONFIG: Symbol = symbol_short!("FEE_CFG");
 pub const PLATFORM_BALANCE: Symbol = symbol_short!("PLAT_BAL");
-pub const FEE_HISTORY: Symbol = symbol_short!("FEE_HIST");
+pub const FEE_HISTORY: Symbol = symbol_short!("FEE_HIST"); // (FEE_HISTORY, index) -> FeeRecord
+pub const FEE_HISTORY_COUNT: Symbol = symbol_short!("FEE_HISTN");
+pub const PAYER_FEE_TOTALS: Symbol = symbol_short!("PAYER_TOT");
 pub const FEE_STATS: Symbol = symbol_short!("FEE_STAT");
 pub const TOTAL_FESS_COLLECTED: Symbol = symbol_short!("FEE_TOTAL");
 
-pub const PAUSED: Symbol = symbol_short!("PAUSED");
+pub use offerhub_pausable::PAUSED;
 
 // Storage keys for premium users
 pub const PREMIUM_USERS: Symbol = symbol_short!("PREM_USR");
 
+// Storage keys for keeper rewards, paid out of an admin-funded budget per asset
+pub const REWARD_SOURCES: Symbol = symbol_short!("RWD_SRC");
+pub const KEEPER_REWARDS: Symbol = symbol_short!("KEEP_RWD");
+pub const KEEPER_BUDGETS: Symbol = symbol_short!("KEEP_BUD");
+
+// Storage keys for arbitrator rewards; resolutions are tracked per epoch, fees and rewards
+// per epoch and asset
+pub const ARBITRATOR_REWARD_CONFIG: Symbol = symbol_short!("ARB_RCFG");
+pub const ARBITRATOR_RESOLUTIONS: Symbol = symbol_short!("ARB_RES");
+pub const EPOCH_FEES: Symbol = symbol_short!("EPOCH_FEE");
+pub const DISTRIBUTED_EPOCHS: Symbol = symbol_short!("ARB_DIST");
+pub const ARBITRATOR_REWARDS: Symbol = symbol_short!("ARB_RWD");
+// Distributed but unclaimed arbitrator rewards per asset, held apart from accrued fees
+pub const ARBITRATOR_RESERVE: Symbol = symbol_short!("ARB_RSV");
+
+// Storage key for reputation-tiered escrow fees
+pub const FEE_TIERS: Symbol = symbol_short!("FEE_TIER");
+
+// Storage key for fee exemptions (partner programs, internal accounts)
+pub const FEE_EXEMPTIONS: Symbol = symbol_short!("FEE_EXMP");
+
+// Storage keys for per-asset fee accrual and the treasury it is withdrawn to
+pub const ACCRUED_FEES: Symbol = symbol_short!("ACCRUED");
+pub const TREASURY: Symbol = symbol_short!("TREASURY");
+
+// Storage key for the assets fees may be paid in
+pub const FEE_ASSETS: Symbol = symbol_short!("FEE_ASSET");
+
+// Storage keys for referral fee splits
+pub const REFERRAL_SHARE: Symbol = symbol_short!("REF_SHARE");
+pub const REFERRAL_EARNINGS: Symbol = symbol_short!("REF_EARN");
+
+// Storage keys for promotional discounts, keyed by the sha256 of the promo code
+pub const PROMOS: Symbol = symbol_short!("PROMOS");
+pub const ACTIVE_PROMOS: Symbol = symbol_short!("PROMO_ACT");
+pub const PROMO_REDEMPTIONS: Symbol = symbol_short!("PROMO_RED");
+
+// Storage keys for timelocked fee-rate and fee-tier changes
+pub const FEE_TIMELOCK: Symbol = symbol_short!("FEE_TLOCK");
+pub const PENDING_FEE_CHANGE: Symbol = symbol_short!("FEE_PEND");
+pub const PENDING_FEE_TIERS: Symbol = symbol_short!("TIER_PEND");
+
+// Storage key for the escrow factory whose escrows may collect fees
+pub const ESCROW_FACTORY: Symbol = symbol_short!("ESC_FACT");
 
 // Storage key for contract configuration
 pub const CONTRACT_CONFIG: Symbol = symbol_short!("CONFIG");
@@ -3,10 +3,48 @@
 use super::*;
 
 use soroban_sdk::{
-    testutils::Address as _,
-    Address, Env,
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, Bytes, BytesN, Env, Symbol, TryFromVal, Val,
 };
-use crate::types::ContractConfig;
+use crate::types::{
+    ArbitratorRewardConfig, ContractConfig, FeeCollectedEvent, FeeConfigEvent, FeeTier,
+    ReputationFeeTiers,
+};
+
+#[contract]
+pub struct MockReputation;
+
+#[contractimpl]
+impl MockReputation {
+    pub fn set_score(env: Env, user: Address, score: u32) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("SCORE"), user), &score);
+    }
+
+    pub fn get_user_reputation_score(env: Env, user: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("SCORE"), user))
+            .unwrap_or(0)
+    }
+}
+
+#[contract]
+pub struct MockEscrowFactory;
+
+#[contractimpl]
+impl MockEscrowFactory {
+    pub fn add_escrow(env: Env, escrow: Address) {
+        env.storage().instance().set(&escrow, &1u32);
+    }
+
+    pub fn get_escrow_id_by_address(env: Env, escrow_address: Address) -> Option<u32> {
+        env.storage().instance().get(&escrow_address)
+    }
+}
 
 #[test]
 fn test_initialize() {
@@ -25,6 +63,7 @@ fn test_initialize() {
     assert_eq!(fee_config.dispute_fee_percentage, 500); // 5.0%
     assert_eq!(fee_config.arbitrator_fee_percentage, 300); // 3.0%
     assert!(fee_config.initialized);
+    assert_eq!(client.get_platform_wallet(), platform_wallet);
 }
 
 #[test]
@@ -372,6 +411,41 @@ fn test_collect_fee_premium_user() {
     assert!(fee_calc.is_premium);
 }
 
+#[test]
+fn test_collect_fee_requires_fee_collector() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let user = Address::generate(&env);
+    let escrow = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    // Strangers can't credit the platform balance or burn a user's promo
+    assert_eq!(
+        client.try_collect_fee(&user, &1000000i128, &1, &user),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+
+    // Escrows are trusted once the configured factory vouches for them
+    let factory_id = env.register(MockEscrowFactory, ());
+    client.set_escrow_factory(&admin, &factory_id);
+    assert_eq!(
+        client.try_collect_fee(&escrow, &1000000i128, &1, &user),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+    MockEscrowFactoryClient::new(&env, &factory_id).add_escrow(&escrow);
+    assert_eq!(client.collect_fee(&escrow, &1000000i128, &1, &user), 975000);
+    assert_eq!(client.get_platform_balance(), 25000);
+
+    // The history points back at the escrow the fee came from
+    let record = client.get_fee_history(&0, &1).get(0).unwrap();
+    assert_eq!(record.transaction_id, escrow.to_string());
+}
+
 #[test]
 fn test_withdraw_platform_fees() {
     let env = Env::default();
@@ -538,17 +612,17 @@ fn test_get_total_fees() {
 
     // Add user1 as premium to test zero fees
     client.add_premium_user(&user1);
-    client.collect_fee(&1000000i128, &1, &user1); // 0 fee (premium)
+    client.collect_fee(&admin, &1000000i128, &1, &user1); // 0 fee (premium)
     let fees_after_premium = client.get_total_fees();
     assert_eq!(fees_after_premium, 0);
 
     // Collect dispute fee for user2 (non-premium)
-    client.collect_fee(&2000000i128, &2, &user2); // 100,000 fee (5%)
+    client.collect_fee(&admin, &2000000i128, &2, &user2); // 100,000 fee (5%)
     let fees_after_non_premium = client.get_total_fees();
     assert_eq!(fees_after_non_premium, 100_000);
 
     // Collect dispute fee for user2 (non-premium)
-    client.collect_fee(&2000000i128, &1, &user2); // 100,000 fee (5%)
+    client.collect_fee(&admin, &2000000i128, &1, &user2); // 100,000 fee (5%)
     let fees_after_second_non_premium = client.get_total_fees();
     assert_eq!(fees_after_second_non_premium, 150_000);
 }
@@ -580,12 +654,12 @@ fn test_get_total_fees_reset() {
 
     // Add user1 as premium to test zero fees
     client.add_premium_user(&user1);
-    client.collect_fee(&1000000i128, &1, &user1); // 0 fee (premium)
+    client.collect_fee(&admin, &1000000i128, &1, &user1); // 0 fee (premium)
     let fees_after_premium = client.get_total_fees();
     assert_eq!(fees_after_premium, 0);
 
     // Collect dispute fee for user2 (non-premium)
-    client.collect_fee(&2000000i128, &2, &user2); // 100,000 fee (5%)
+    client.collect_fee(&admin, &2000000i128, &2, &user2); // 100,000 fee (5%)
     let fees_after_non_premium = client.get_total_fees();
     assert_eq!(fees_after_non_premium, 100_000);
 
@@ -623,12 +697,12 @@ fn test_get_total_fees_reset_failed() {
 
     // Add user1 as premium to test zero fees
     client.add_premium_user(&user1);
-    client.collect_fee(&1000000i128, &1, &user1); // 0 fee (premium)
+    client.collect_fee(&admin, &1000000i128, &1, &user1); // 0 fee (premium)
     let fees_after_premium = client.get_total_fees();
     assert_eq!(fees_after_premium, 0);
 
     // Collect dispute fee for user2 (non-premium)
-    client.collect_fee(&2000000i128, &2, &user2); // 100,000 fee (5%)
+    client.collect_fee(&admin, &2000000i128, &2, &user2); // 100,000 fee (5%)
     let fees_after_non_premium = client.get_total_fees();
     assert_eq!(fees_after_non_premium, 100_000);
 
@@ -664,13 +738,13 @@ fn test_get_platform_stats() {
 
     // Add user1 as premium to test zero fees
     client.add_premium_user(&user1);
-    client.collect_fee(&1000000i128, &1, &user1); // 0 fee (premium)
+    client.collect_fee(&admin, &1000000i128, &1, &user1); // 0 fee (premium)
 
     // Collect dispute fee for user2 (non-premium)
-    client.collect_fee(&2000000i128, &2, &user2); // 100,000 fee (5%)
+    client.collect_fee(&admin, &2000000i128, &2, &user2); // 100,000 fee (5%)
 
     // Collect dispute fee for user2 (non-premium)
-    client.collect_fee(&2000000i128, &1, &user2); // 100,000 fee (5%)
+    client.collect_fee(&admin, &2000000i128, &1, &user2); // 100,000 fee (5%)
     let fees_after_second_non_premium = client.get_total_fees();
     assert_eq!(fees_after_second_non_premium, 150_000);
 
@@ -781,4 +855,857 @@ fn test_pause_unpause_unauthorized() {
     
     // Test pause
     client.pause(&unauthorized.clone());
-}
\ No newline at end of file
+}
+#[test]
+fn test_pay_keeper_reward() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let source = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &asset).mint(&user, &10000);
+    StellarAssetClient::new(&env, &asset).mint(&admin, &2500);
+    client.add_fee_asset(&admin, &asset);
+    client.deposit_fee(&user, &asset, &2500);
+    client.add_reward_source(&source);
+    assert!(client.is_reward_source(&source));
+
+    // Without a budget nothing is paid, whatever fees have accrued
+    assert_eq!(
+        client.pay_keeper_reward(&source, &keeper, &asset, &1000i128),
+        0
+    );
+
+    assert_eq!(
+        client.fund_keeper_budget(&admin, &asset, &2500, &1000),
+        2500
+    );
+    let paid = client.pay_keeper_reward(&source, &keeper, &asset, &1000i128);
+    assert_eq!(paid, 1000);
+    assert_eq!(client.get_keeper_rewards(&keeper, &asset), 1000);
+    assert_eq!(client.get_keeper_rewards(&keeper, &Address::generate(&env)), 0);
+
+    // Payments are capped per reward and stop once the budget runs out
+    assert_eq!(
+        client.pay_keeper_reward(&source, &keeper, &asset, &5000i128),
+        1000
+    );
+    assert_eq!(
+        client.pay_keeper_reward(&source, &keeper, &asset, &1000i128),
+        500
+    );
+    assert_eq!(
+        client.pay_keeper_reward(&source, &keeper, &asset, &1000i128),
+        0
+    );
+    assert_eq!(client.get_keeper_budget(&asset).unwrap().remaining, 0);
+    assert_eq!(client.get_keeper_rewards(&keeper, &asset), 2500);
+    assert_eq!(client.get_accrued(&asset), 2500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #15)")]
+fn test_pay_keeper_reward_unknown_source() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let keeper = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    client.pay_keeper_reward(
+        &Address::generate(&env),
+        &keeper,
+        &Address::generate(&env),
+        &1000i128,
+    );
+}
+
+#[test]
+fn test_claim_keeper_rewards_transfers_tokens() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let source = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &asset).mint(&payer, &10000);
+    StellarAssetClient::new(&env, &asset).mint(&admin, &1000);
+    client.add_fee_asset(&admin, &asset);
+    client.deposit_fee(&payer, &asset, &2500);
+    client.fund_keeper_budget(&admin, &asset, &1000, &1000);
+
+    client.add_reward_source(&source);
+    client.pay_keeper_reward(&source, &keeper, &asset, &1000i128);
+
+    // Rewards are only paid in the asset they were credited in
+    let other_asset = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    assert_eq!(
+        client.try_claim_keeper_rewards(&keeper, &other_asset),
+        Err(Ok(Error::NoRewardsToClaim))
+    );
+
+    assert_eq!(client.claim_keeper_rewards(&keeper, &asset), 1000);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&keeper), 1000);
+    assert_eq!(client.get_accrued(&asset), 2500);
+    assert_eq!(client.get_keeper_rewards(&keeper, &asset), 0);
+
+    // Nothing left to claim
+    assert_eq!(
+        client.try_claim_keeper_rewards(&keeper, &asset),
+        Err(Ok(Error::NoRewardsToClaim))
+    );
+}
+
+#[test]
+fn test_distribute_arbitrator_rewards() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let source = Address::generate(&env);
+    let arbitrator_a = Address::generate(&env);
+    let arbitrator_b = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &asset).mint(&user, &100000);
+    client.add_fee_asset(&admin, &asset);
+
+    client.set_arbitrator_reward_config(&ArbitratorRewardConfig {
+        share_bps: 2000, // 20%
+        epoch_secs: 1000,
+    });
+    client.add_reward_source(&source);
+
+    client.collect_fee_in_asset(&admin, &user, &asset, &1000000i128, &1); // 25,000 fee (2.5%)
+    client.record_arbitrator_resolution(&source, &arbitrator_a);
+    client.record_arbitrator_resolution(&source, &arbitrator_a);
+    client.record_arbitrator_resolution(&source, &arbitrator_b);
+    assert_eq!(
+        client.get_epoch_resolutions(&0).get(arbitrator_a.clone()),
+        Some(2)
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    let paid = client.distribute_arbitrator_rewards(&0, &asset);
+
+    // 5,000 pool split 2:1, rounding dust stays with the accrued fees
+    assert_eq!(paid, 4999);
+    assert_eq!(client.get_arbitrator_rewards(&arbitrator_a, &asset), 3333);
+    assert_eq!(client.get_arbitrator_rewards(&arbitrator_b, &asset), 1666);
+    assert_eq!(client.get_arbitrator_reserve(&asset), 4999);
+    assert_eq!(client.get_accrued(&asset), 20001);
+
+    // Draining the accrued fees leaves the distributed rewards claimable
+    client.set_treasury(&admin, &Address::generate(&env));
+    client.withdraw_to_treasury(&admin, &asset, &20001);
+
+    assert_eq!(client.claim_arbitrator_rewards(&arbitrator_a, &asset), 3333);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&arbitrator_a), 3333);
+    assert_eq!(client.get_arbitrator_reserve(&asset), 1666);
+    assert_eq!(client.get_arbitrator_rewards(&arbitrator_a, &asset), 0);
+    assert_eq!(client.claim_arbitrator_rewards(&arbitrator_b, &asset), 1666);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&contract_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #17)")]
+fn test_distribute_arbitrator_rewards_before_epoch_end() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    client.set_arbitrator_reward_config(&ArbitratorRewardConfig {
+        share_bps: 2000,
+        epoch_secs: 1000,
+    });
+    client.distribute_arbitrator_rewards(&0, &Address::generate(&env));
+}
+
+fn setup_fee_tiers(env: &Env, client: &FeeManagerContractClient) -> MockReputationClient<'static> {
+    let reputation = env.register(MockReputation, ());
+    let mut tiers = Vec::new(env);
+    tiers.push_back(FeeTier {
+        min_score: 0,
+        fee_percentage: 1000, // 10% default
+    });
+    tiers.push_back(FeeTier {
+        min_score: 400,
+        fee_percentage: 700, // 7% Top Rated
+    });
+    tiers.push_back(FeeTier {
+        min_score: 450,
+        fee_percentage: 500, // 5% Veteran
+    });
+    client.set_fee_tiers(&ReputationFeeTiers {
+        reputation_contract: reputation.clone(),
+        tiers,
+    });
+    MockReputationClient::new(env, &reputation)
+}
+
+#[test]
+fn test_reputation_tiered_escrow_fee() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let newcomer = Address::generate(&env);
+    let top_rated = Address::generate(&env);
+    let veteran = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    let reputation = setup_fee_tiers(&env, &client);
+    reputation.set_score(&top_rated, &420);
+    reputation.set_score(&veteran, &470);
+
+    assert_eq!(
+        client
+            .calculate_escrow_fee(&1000000i128, &newcomer)
+            .fee_amount,
+        100000
+    );
+    assert_eq!(
+        client
+            .calculate_escrow_fee(&1000000i128, &top_rated)
+            .fee_amount,
+        70000
+    );
+    assert_eq!(
+        client
+            .calculate_escrow_fee(&1000000i128, &veteran)
+            .fee_amount,
+        50000
+    );
+
+    // Collection uses the same tier
+    assert_eq!(client.collect_fee(&admin, &1000000i128, &1, &veteran), 950000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #20)")]
+fn test_fee_tiers_must_ascend() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    let mut tiers = Vec::new(&env);
+    tiers.push_back(FeeTier {
+        min_score: 450,
+        fee_percentage: 500,
+    });
+    tiers.push_back(FeeTier {
+        min_score: 400,
+        fee_percentage: 700,
+    });
+    client.set_fee_tiers(&ReputationFeeTiers {
+        reputation_contract: Address::generate(&env),
+        tiers,
+    });
+}
+
+#[test]
+fn test_fee_exemption_until_expiry() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let expired = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    client.add_fee_exemption(&admin, &partner, &5000);
+    client.add_fee_exemption(&admin, &expired, &2000);
+
+    assert_eq!(
+        client
+            .calculate_escrow_fee(&1000000i128, &partner)
+            .fee_amount,
+        0
+    );
+    assert_eq!(
+        client
+            .calculate_dispute_fee(&1000000i128, &partner)
+            .fee_amount,
+        0
+    );
+    assert_eq!(client.collect_fee(&admin, &1000000i128, &1, &partner), 1000000);
+    assert_eq!(client.get_fee_exemptions(&0, &10).len(), 2);
+
+    env.ledger().with_mut(|l| l.timestamp = 2000);
+    assert!(!client.is_fee_exempt(&expired));
+    assert_eq!(
+        client
+            .calculate_escrow_fee(&1000000i128, &expired)
+            .fee_amount,
+        25000
+    );
+
+    let current = client.get_fee_exemptions(&0, &10);
+    assert_eq!(current.len(), 1);
+    assert_eq!(current.get(0).unwrap().address, partner);
+
+    client.remove_fee_exemption(&admin, &partner);
+    assert!(!client.is_fee_exempt(&partner));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #21)")]
+fn test_fee_exemption_expiry_in_past() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    client.add_fee_exemption(&admin, &Address::generate(&env), &1000);
+}
+
+#[test]
+fn test_withdraw_accrued_fees_to_treasury() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &asset).mint(&payer, &10000);
+    client.add_fee_asset(&admin, &asset);
+
+    client.deposit_fee(&payer, &asset, &2500);
+    client.deposit_fee(&payer, &asset, &1500);
+    assert_eq!(client.get_accrued(&asset), 4000);
+
+    client.set_treasury(&admin, &treasury);
+    let remaining = client.withdraw_to_treasury(&admin, &asset, &3000);
+
+    assert_eq!(remaining, 1000);
+    assert_eq!(client.get_accrued(&asset), 1000);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&treasury), 3000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #23)")]
+fn test_withdraw_to_treasury_requires_treasury() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    client.withdraw_to_treasury(&admin, &Address::generate(&env), &100);
+}
+
+#[test]
+fn test_referral_fee_split() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let user = Address::generate(&env);
+    let referrer = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    let asset = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &asset).mint(&user, &25000);
+    client.add_fee_asset(&admin, &asset);
+
+    // Only fee collectors can credit referral earnings
+    assert_eq!(
+        client.try_collect_fee_with_referrer(
+            &referrer,
+            &1000000i128,
+            &1,
+            &user,
+            &referrer,
+            &asset
+        ),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+
+    client.set_referral_share(&admin, &2000); // 20% of the platform fee
+    // 25,000 fee
+    let net =
+        client.collect_fee_with_referrer(&admin, &1000000i128, &1, &user, &referrer, &asset);
+
+    assert_eq!(net, 975000);
+    assert_eq!(client.get_referral_earnings(&referrer, &asset), 5000);
+    assert_eq!(client.get_accrued(&asset), 20000);
+
+    // Draining the accrued fees to the treasury leaves the referrer's share in place
+    let treasury = Address::generate(&env);
+    client.set_treasury(&admin, &treasury);
+    assert_eq!(
+        client.try_withdraw_to_treasury(&admin, &asset, &20001),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    client.withdraw_to_treasury(&admin, &asset, &20000);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&treasury), 20000);
+
+    // Earnings are only paid in the asset the fee was collected in
+    let other_asset = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    assert_eq!(
+        client.try_claim_referral_earnings(&referrer, &other_asset),
+        Err(Ok(Error::NoRewardsToClaim))
+    );
+
+    assert_eq!(client.claim_referral_earnings(&referrer, &asset), 5000);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&referrer), 5000);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&contract_id), 0);
+    assert_eq!(client.get_accrued(&asset), 0);
+    assert_eq!(client.get_referral_earnings(&referrer, &asset), 0);
+    assert_eq!(
+        client.try_claim_referral_earnings(&referrer, &asset),
+        Err(Ok(Error::NoRewardsToClaim))
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #24)")]
+fn test_self_referral_rejected() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    client.collect_fee_with_referrer(
+        &admin,
+        &1000000i128,
+        &1,
+        &user,
+        &user,
+        &Address::generate(&env),
+    );
+}
+
+#[test]
+fn test_fee_history_pagination_and_payer_totals() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let payer = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.sequence_number = 42);
+
+    client.collect_fee(&admin, &1000000i128, &1, &payer); // 25,000
+    client.collect_fee(&admin, &1000000i128, &2, &payer); // 50,000
+    client.collect_fee(&admin, &1000000i128, &1, &other); // 25,000
+
+    assert_eq!(client.get_fee_history_count(), 3);
+    let page = client.get_fee_history(&1, &10);
+    assert_eq!(page.len(), 2);
+    let record = page.get(0).unwrap();
+    assert_eq!(record.user, payer);
+    assert_eq!(record.fee_type, 2);
+    assert_eq!(record.amount, 50000);
+    assert_eq!(record.ledger, 42);
+
+    assert_eq!(client.get_fee_history(&3, &10).len(), 0);
+    assert_eq!(client.get_payer_fee_total(&payer), 75000);
+    assert_eq!(client.get_payer_fee_total(&other), 25000);
+}
+
+#[test]
+fn test_collect_fee_in_whitelisted_assets() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let payer = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    let usdc = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let eurc = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &usdc).mint(&payer, &100000);
+    StellarAssetClient::new(&env, &eurc).mint(&payer, &100000);
+    client.add_fee_asset(&admin, &usdc);
+    client.add_fee_asset(&admin, &eurc);
+
+    // Only the admin or a factory-deployed escrow may collect
+    assert_eq!(
+        client.try_collect_fee_in_asset(&payer, &payer, &usdc, &1000000i128, &1),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+
+    assert_eq!(
+        client.collect_fee_in_asset(&admin, &payer, &usdc, &1000000i128, &1),
+        975000
+    );
+    assert_eq!(
+        client.collect_fee_in_asset(&admin, &payer, &eurc, &1000000i128, &2),
+        950000
+    );
+
+    // Each fee is credited to its asset only, not to the platform balance as well
+    assert_eq!(client.get_accrued(&usdc), 25000);
+    assert_eq!(client.get_accrued(&eurc), 50000);
+    assert_eq!(client.get_platform_balance(), 0);
+    assert_eq!(client.get_fee_stats().total_fees_collected, 75000);
+    assert_eq!(TokenClient::new(&env, &usdc).balance(&contract_id), 25000);
+    assert_eq!(
+        client.get_fee_history(&0, &10).get(1).unwrap().asset,
+        Some(eurc.clone())
+    );
+
+    client.remove_fee_asset(&admin, &eurc);
+    assert_eq!(client.get_fee_assets().len(), 1);
+    assert!(!client.is_fee_asset(&eurc));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #25)")]
+fn test_collect_fee_in_unlisted_asset() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    client.collect_fee_in_asset(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &1000000i128,
+        &1,
+    );
+}
+
+fn create_launch_promo(
+    env: &Env,
+    client: &FeeManagerContractClient,
+    admin: &Address,
+    max_uses: u32,
+) -> Bytes {
+    let code = Bytes::from_slice(env, b"LAUNCH50");
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+    client.create_promo(admin, &code_hash, &5000, &max_uses, &5000); // 50% off
+    code
+}
+
+#[test]
+fn test_promo_discount_consumed_on_collection() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let code = create_launch_promo(&env, &client, &admin, 2);
+    client.apply_promo(&user, &code);
+
+    // Quoting doesn't use the promo up
+    assert_eq!(
+        client.calculate_escrow_fee(&1000000i128, &user).fee_amount,
+        12500
+    );
+    assert_eq!(
+        client.calculate_escrow_fee(&1000000i128, &user).fee_amount,
+        12500
+    );
+
+    assert_eq!(client.collect_fee(&admin, &1000000i128, &1, &user), 987500);
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+    assert_eq!(client.get_promo(&code_hash).unwrap().uses, 1);
+    assert_eq!(client.get_active_promo(&user), None);
+
+    // Full fee again once redeemed
+    assert_eq!(client.collect_fee(&admin, &1000000i128, &1, &user), 975000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #30)")]
+fn test_promo_single_use_per_user() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let code = create_launch_promo(&env, &client, &admin, 10);
+    client.apply_promo(&user, &code);
+    client.collect_fee(&admin, &1000000i128, &1, &user);
+    client.apply_promo(&user, &code);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #29)")]
+fn test_promo_max_uses() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let first = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let code = create_launch_promo(&env, &client, &admin, 1);
+    client.apply_promo(&first, &code);
+    client.collect_fee(&admin, &1000000i128, &1, &first);
+    client.apply_promo(&Address::generate(&env), &code);
+}
+
+#[test]
+fn test_timelocked_fee_change() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    client.set_fee_timelock(&admin, &86400);
+    // The delay can't be shortened to rush a change through
+    assert_eq!(
+        client.try_set_fee_timelock(&admin, &0),
+        Err(Ok(Error::TimelockDecrease))
+    );
+    let eta = client.schedule_fee_change(&admin, &300, &400, &200);
+    assert_eq!(eta, 87400);
+    assert_eq!(client.get_pending_fee_change().unwrap().eta, eta);
+
+    assert_eq!(
+        client.try_execute_fee_change(),
+        Err(Ok(Error::FeeChangeNotReady))
+    );
+    assert_eq!(client.get_fee_config().escrow_fee_percentage, 250);
+
+    env.ledger().with_mut(|l| l.timestamp = eta);
+    client.execute_fee_change();
+
+    let fee_config = client.get_fee_config();
+    assert_eq!(fee_config.escrow_fee_percentage, 300);
+    assert_eq!(fee_config.dispute_fee_percentage, 400);
+    assert_eq!(fee_config.arbitrator_fee_percentage, 200);
+    assert!(client.get_pending_fee_change().is_none());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #32)")]
+fn test_set_fee_rates_blocked_by_timelock() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    client.set_fee_timelock(&admin, &86400);
+    client.set_fee_rates(&300, &400, &200);
+}
+
+#[test]
+fn test_fee_tiers_wait_out_timelock() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    client.set_fee_timelock(&admin, &86400);
+
+    let reputation = env.register(MockReputation, ());
+    MockReputationClient::new(&env, &reputation).set_score(&user, &500);
+    let mut tiers = Vec::new(&env);
+    tiers.push_back(FeeTier {
+        min_score: 450,
+        fee_percentage: 100,
+    });
+    let fee_tiers = ReputationFeeTiers {
+        reputation_contract: reputation,
+        tiers,
+    };
+
+    // Tiers change the effective escrow rate, so they can't skip the delay either
+    assert_eq!(
+        client.try_set_fee_tiers(&fee_tiers),
+        Err(Ok(Error::TimelockActive.into()))
+    );
+    let eta = client.schedule_fee_tiers_change(&admin, &fee_tiers);
+    assert_eq!(eta, 87400);
+    assert_eq!(
+        client.try_execute_fee_tiers_change(),
+        Err(Ok(Error::FeeChangeNotReady))
+    );
+    assert!(client.get_fee_tiers().is_none());
+
+    env.ledger().with_mut(|l| l.timestamp = eta);
+    client.execute_fee_tiers_change();
+    assert!(client.get_pending_fee_tiers().is_none());
+    assert_eq!(
+        client.calculate_escrow_fee(&1000000i128, &user).fee_amount,
+        10000
+    );
+    assert_eq!(
+        client.try_cancel_fee_tiers_change(&admin),
+        Err(Ok(Error::NoPendingFeeChange))
+    );
+}
+
+fn last_fee_event<T: TryFromVal<Env, Val>>(env: &Env, namespace: &str) -> Option<(Symbol, T)> {
+    let mut last = None;
+    for (_, topics, data) in env.events().all().iter() {
+        let in_namespace = topics.len() == 2
+            && Symbol::try_from_val(env, &topics.get(0).unwrap())
+                == Ok(Symbol::new(env, namespace));
+        if in_namespace {
+            let kind = Symbol::try_from_val(env, &topics.get(1).unwrap()).unwrap();
+            last = Some((kind, T::try_from_val(env, &data).ok().unwrap()));
+        }
+    }
+    last
+}
+
+#[test]
+fn test_structured_fee_events() {
+    let env = Env::default();
+    let contract_id = env.register(FeeManagerContract, ());
+    let client = FeeManagerContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    client.initialize(&admin, &platform_wallet);
+    env.mock_all_auths();
+
+    client.collect_fee(&admin, &1000000i128, &1, &user);
+    let (kind, event) = last_fee_event::<FeeCollectedEvent>(&env, "fee_event").unwrap();
+    assert_eq!(kind, Symbol::new(&env, "collected"));
+    assert_eq!(event.version, 1);
+    assert_eq!(event.payer, user);
+    assert_eq!(event.fee_type, 1);
+    assert_eq!(event.asset, None);
+    assert_eq!(event.amount, 1000000);
+    assert_eq!(event.fee_amount, 25000);
+    assert_eq!(event.fee_percentage, 250);
+    assert_eq!(event.total_collected, 25000);
+
+    client.set_fee_rates(&300, &400, &200);
+    let (kind, event) = last_fee_event::<FeeConfigEvent>(&env, "fee_config_event").unwrap();
+    assert_eq!(kind, Symbol::new(&env, "rates"));
+    assert_eq!(event.actor, admin);
+    assert_eq!(event.subject, None);
+    assert_eq!(event.escrow_fee_percentage, 300);
+
+    client.add_fee_exemption(&admin, &user, &1000);
+    let (kind, event) = last_fee_event::<FeeConfigEvent>(&env, "fee_config_event").unwrap();
+    assert_eq!(kind, Symbol::new(&env, "exemption_added"));
+    assert_eq!(event.subject, Some(user.clone()));
+
+    let asset = Address::generate(&env);
+    client.add_fee_asset(&admin, &asset);
+    let (kind, event) = last_fee_event::<FeeConfigEvent>(&env, "fee_config_event").unwrap();
+    assert_eq!(kind, Symbol::new(&env, "asset_added"));
+    assert_eq!(event.subject, Some(asset));
+}
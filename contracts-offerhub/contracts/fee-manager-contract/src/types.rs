@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, String, Vec};
 
 // Fee type constants
 pub const FEE_TYPE_ESCROW: u32 = 1;
@@ -28,6 +28,70 @@ pub struct ContractConfig {
     pub max_rate_limit_calls: u32,        // Maximum calls per rate limit window
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperBudget {
+    pub remaining: i128,  // Tokens still available for keeper rewards
+    pub max_reward: i128, // Most a single reward payment may credit
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ArbitratorRewardConfig {
+    pub share_bps: u32,  // Share of an epoch's collected fees paid to arbitrators
+    pub epoch_secs: u64, // Epoch length; epoch n covers [n * epoch_secs, (n + 1) * epoch_secs)
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct FeeTier {
+    pub min_score: u32,       // Lowest reputation score that qualifies for the tier
+    pub fee_percentage: i128, // Escrow fee for the tier (in basis points)
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ReputationFeeTiers {
+    pub reputation_contract: Address, // Reputation NFT contract queried for user scores
+    pub tiers: Vec<FeeTier>,          // Ascending by min_score
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct FeeExemption {
+    pub address: Address, // Exempted account
+    pub expires_at: u64,  // Fees apply again from this timestamp
+    pub added_at: u64,    // When the exemption was granted
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Promo {
+    pub discount_bps: u32, // Reduction of the fee rate, out of 10_000
+    pub max_uses: u32,     // Total redemptions allowed across all users
+    pub uses: u32,         // Redemptions so far
+    pub expires_at: u64,   // Not redeemable from this timestamp
+    pub created_at: u64,   // When the promo was created
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PendingFeeChange {
+    pub escrow_fee_percentage: i128,     // New escrow fee (in basis points)
+    pub dispute_fee_percentage: i128,    // New dispute fee (in basis points)
+    pub arbitrator_fee_percentage: i128, // New arbitrator fee (in basis points)
+    pub scheduled_at: u64,               // When the change was scheduled
+    pub eta: u64,                        // Earliest time it can be executed
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PendingFeeTiers {
+    pub fee_tiers: ReputationFeeTiers, // Tiers that replace the current ones
+    pub scheduled_at: u64,             // When the change was scheduled
+    pub eta: u64,                      // Earliest time it can be executed
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct FeeCalculation {
@@ -42,10 +106,40 @@ pub struct FeeCalculation {
 #[derive(Clone)]
 pub struct FeeRecord {
     pub timestamp: u64,         // When the fee was collected
+    pub ledger: u32,            // Ledger sequence of the collection
     pub fee_type: u32,          // Type of fee (1 = escrow, 2 = dispute)
+    pub asset: Option<Address>, // Asset the fee was paid in, when known
     pub amount: i128,           // Fee amount collected
     pub user: Address,          // User who paid the fee
-    pub transaction_id: String, // Associated transaction ID
+    pub transaction_id: String, // Contract that collected the fee, e.g. the escrow
+}
+
+/// Payload of the versioned `("fee_event", "collected")` event.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCollectedEvent {
+    pub version: u32,
+    pub payer: Address,
+    pub fee_type: u32,
+    pub asset: Option<Address>,
+    pub amount: i128,
+    pub fee_amount: i128,
+    pub fee_percentage: i128,
+    pub total_collected: i128,
+    pub timestamp: u64,
+}
+
+/// Payload of the versioned `("fee_config_event", change)` event.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfigEvent {
+    pub version: u32,
+    pub actor: Address,
+    pub subject: Option<Address>,
+    pub escrow_fee_percentage: i128,
+    pub dispute_fee_percentage: i128,
+    pub arbitrator_fee_percentage: i128,
+    pub timestamp: u64,
 }
 
 #[contracttype]
@@ -1,5 +1,6 @@
 use crate::error::Error;
-use soroban_sdk::{Address, Env};
+use crate::types::FeeTier;
+use soroban_sdk::{Address, Env, Vec};
 
 // Validation constants
 const MIN_FEE_PERCENTAGE: i128 = 0; // 0%
@@ -94,6 +95,22 @@ pub fn validate_fee_rates(
     Ok(())
 }
 
+/// Validate reputation fee tiers: ascending thresholds, each rate within limits
+pub fn validate_fee_tiers(tiers: &Vec<FeeTier>) -> Result<(), Error> {
+    if tiers.is_empty() {
+        return Err(Error::InvalidFeeTiers);
+    }
+    let mut previous: Option<u32> = None;
+    for tier in tiers.iter() {
+        if previous.is_some_and(|min_score| tier.min_score <= min_score) {
+            return Err(Error::InvalidFeeTiers);
+        }
+        validate_fee_percentage(tier.fee_percentage).map_err(|_| Error::InvalidFeeTiers)?;
+        previous = Some(tier.min_score);
+    }
+    Ok(())
+}
+
 /// Comprehensive validation for fee calculation
 pub fn validate_fee_calculation(amount: i128, user: &Address) -> Result<(), Error> {
     validate_amount(amount)?;
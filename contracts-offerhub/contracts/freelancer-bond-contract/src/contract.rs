@@ -0,0 +1,332 @@
+use crate::error::Error;
+use crate::event::{
+    admin_initialized, bond_locked, bond_unlocked, bonded, config_set, slashed, slasher_set,
+    unbond_requested, withdrawn,
+};
+use crate::storage::{
+    get_admin, get_bond, get_config, get_lock, is_slasher, remove_lock, set_admin, set_bond,
+    set_config, set_lock, set_slasher,
+};
+use crate::types::{Bond, BondConfig, BondLock};
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+const FACTORY_GET_ESCROW_ID: &str = "get_escrow_id_by_address";
+const ESCROW_IS_CLOSED: &str = "is_closed";
+const TOKEN_TRANSFER: &str = "transfer";
+const MAX_BPS: u32 = 10_000;
+
+pub struct FreelancerBondContract;
+
+impl FreelancerBondContract {
+    /// Initialize the contract with an admin address and bond configuration
+    pub fn initialize(env: Env, admin: Address, config: BondConfig) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+        Self::validate_config(&config)?;
+
+        set_admin(&env, &admin);
+        set_config(&env, &config);
+        admin_initialized(&env, admin);
+        config_set(&env, config);
+        Ok(())
+    }
+
+    pub fn set_config(env: Env, admin: Address, config: BondConfig) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        Self::validate_config(&config)?;
+
+        set_config(&env, &config);
+        config_set(&env, config);
+        Ok(())
+    }
+
+    pub fn get_config(env: Env) -> Option<BondConfig> {
+        get_config(&env)
+    }
+
+    /// Allow or revoke a contract, typically the dispute contract, to slash bonds
+    pub fn set_slasher(
+        env: Env,
+        admin: Address,
+        slasher: Address,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        set_slasher(&env, &slasher, allowed);
+        slasher_set(&env, slasher, allowed);
+        Ok(())
+    }
+
+    pub fn is_slasher(env: Env, slasher: Address) -> bool {
+        is_slasher(&env, &slasher)
+    }
+
+    /// Lock `amount` of the bond token. Topping up does not cancel a pending unbonding.
+    pub fn bond(env: Env, user: Address, amount: i128) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        user.require_auth();
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        Self::transfer(
+            &env,
+            &config.token,
+            &user,
+            &env.current_contract_address(),
+            amount,
+        );
+
+        let mut bond = get_bond(&env, &user).unwrap_or(Bond {
+            user: user.clone(),
+            amount: 0,
+            escrow_locked: 0,
+            unbonding_amount: 0,
+            unbonding_until: 0,
+            bonded_at: env.ledger().timestamp(),
+            slashed_total: 0,
+        });
+        bond.amount += amount;
+        set_bond(&env, &bond);
+
+        bonded(&env, user, amount, bond.amount);
+        Ok(bond.amount)
+    }
+
+    /// Start unbonding `amount`. It stops counting for gating right away but stays
+    /// slashable until the unbonding period has passed. Bond held by open escrows can't
+    /// be unbonded.
+    pub fn request_unbond(env: Env, user: Address, amount: i128) -> Result<u64, Error> {
+        Self::require_not_paused(&env)?;
+        user.require_auth();
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        let mut bond = get_bond(&env, &user).ok_or(Error::BondNotFound)?;
+
+        if bond.unbonding_amount > 0 {
+            return Err(Error::UnbondingActive);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if amount > bond.amount {
+            return Err(Error::InsufficientBond);
+        }
+        if amount > bond.amount - bond.escrow_locked {
+            return Err(Error::BondLocked);
+        }
+
+        bond.amount -= amount;
+        bond.unbonding_amount = amount;
+        bond.unbonding_until = env.ledger().timestamp() + config.unbonding_period;
+        set_bond(&env, &bond);
+
+        unbond_requested(&env, user, amount, bond.unbonding_until);
+        Ok(bond.unbonding_until)
+    }
+
+    /// Withdraw the unbonding amount once the delay has passed
+    pub fn withdraw(env: Env, user: Address) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        user.require_auth();
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        let mut bond = get_bond(&env, &user).ok_or(Error::BondNotFound)?;
+
+        if bond.unbonding_amount <= 0 {
+            return Err(Error::NoUnbonding);
+        }
+        if env.ledger().timestamp() < bond.unbonding_until {
+            return Err(Error::UnbondingNotReady);
+        }
+
+        let amount = bond.unbonding_amount;
+        bond.unbonding_amount = 0;
+        bond.unbonding_until = 0;
+        set_bond(&env, &bond);
+
+        Self::transfer(
+            &env,
+            &config.token,
+            &env.current_contract_address(),
+            &user,
+            amount,
+        );
+
+        withdrawn(&env, user, amount);
+        Ok(amount)
+    }
+
+    /// Take `share_bps` of a user's bond, active and unbonding, and send it to the
+    /// treasury. Only callable by an authorized slasher.
+    pub fn slash(
+        env: Env,
+        slasher: Address,
+        user: Address,
+        share_bps: u32,
+        dispute_id: u32,
+    ) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        slasher.require_auth();
+        if !is_slasher(&env, &slasher) {
+            return Err(Error::Unauthorized);
+        }
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        if share_bps == 0 || share_bps > config.max_slash_bps {
+            return Err(Error::InvalidSlash);
+        }
+        let mut bond = get_bond(&env, &user).ok_or(Error::BondNotFound)?;
+
+        let total = bond.amount + bond.unbonding_amount;
+        let amount = total * share_bps as i128 / MAX_BPS as i128;
+        if amount > 0 {
+            let from_active = amount.min(bond.amount);
+            bond.amount -= from_active;
+            bond.escrow_locked = bond.escrow_locked.min(bond.amount);
+            bond.unbonding_amount -= amount - from_active;
+            if bond.unbonding_amount == 0 {
+                bond.unbonding_until = 0;
+            }
+            bond.slashed_total += amount;
+            set_bond(&env, &bond);
+
+            Self::transfer(
+                &env,
+                &config.token,
+                &env.current_contract_address(),
+                &config.treasury,
+                amount,
+            );
+        }
+
+        slashed(&env, user, slasher, dispute_id, amount);
+        Ok(amount)
+    }
+
+    /// Called by an escrow deployed through the configured factory when it is funded.
+    /// Holds the larger of the configured minimum and `amount` out of the user's unlocked
+    /// bond until the escrow closes, so it is still there to slash if a dispute is lost.
+    /// Locking again for the same escrow keeps the existing lock.
+    pub fn lock_bond(env: Env, escrow: Address, user: Address, amount: i128) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        escrow.require_auth();
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        if get_lock(&env, &escrow).is_some() {
+            return Ok(());
+        }
+
+        let escrow_id: Option<u32> = env.invoke_contract(
+            &config.escrow_factory,
+            &Symbol::new(&env, FACTORY_GET_ESCROW_ID),
+            (escrow.clone(),).into_val(&env),
+        );
+        if escrow_id.is_none() {
+            return Err(Error::UnknownEscrow);
+        }
+
+        let mut bond = get_bond(&env, &user).ok_or(Error::BondNotFound)?;
+        let amount = config.min_bond.max(amount);
+        if bond.amount - bond.escrow_locked < amount {
+            return Err(Error::InsufficientBond);
+        }
+        bond.escrow_locked += amount;
+        set_bond(&env, &bond);
+        set_lock(
+            &env,
+            &BondLock {
+                escrow: escrow.clone(),
+                user: user.clone(),
+                amount,
+            },
+        );
+
+        bond_locked(&env, user, escrow, amount);
+        Ok(())
+    }
+
+    /// Release an escrow's lock once the escrow has closed. Anyone can call it.
+    pub fn release_lock(env: Env, escrow: Address) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        let lock = get_lock(&env, &escrow).ok_or(Error::LockNotFound)?;
+
+        let closed: bool = env.invoke_contract(
+            &escrow,
+            &Symbol::new(&env, ESCROW_IS_CLOSED),
+            ().into_val(&env),
+        );
+        if !closed {
+            return Err(Error::EscrowOpen);
+        }
+
+        remove_lock(&env, &escrow);
+        if let Some(mut bond) = get_bond(&env, &lock.user) {
+            bond.escrow_locked = (bond.escrow_locked - lock.amount).max(0);
+            set_bond(&env, &bond);
+        }
+
+        bond_unlocked(&env, lock.user, escrow, lock.amount);
+        Ok(lock.amount)
+    }
+
+    pub fn get_lock(env: Env, escrow: Address) -> Option<BondLock> {
+        get_lock(&env, &escrow)
+    }
+
+    pub fn get_bond(env: Env, user: Address) -> Option<Bond> {
+        get_bond(&env, &user)
+    }
+
+    /// Whether the user's active bond meets both the configured minimum and `min_amount`
+    pub fn has_active_bond(env: Env, user: Address, min_amount: i128) -> bool {
+        Self::require_bond(env, user, min_amount).is_ok()
+    }
+
+    /// Fails with `InsufficientBond` unless the user's active bond meets both the
+    /// configured minimum and `min_amount`. Meant to be cross-called for gating.
+    pub fn require_bond(env: Env, user: Address, min_amount: i128) -> Result<(), Error> {
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        let bond = get_bond(&env, &user).ok_or(Error::BondNotFound)?;
+
+        if bond.amount < config.min_bond.max(min_amount) {
+            return Err(Error::InsufficientBond);
+        }
+        Ok(())
+    }
+
+    fn validate_config(config: &BondConfig) -> Result<(), Error> {
+        if config.min_bond <= 0 || config.max_slash_bps == 0 || config.max_slash_bps > MAX_BPS {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+        caller.require_auth();
+        let admin = get_admin(env).ok_or(Error::NotInitialized)?;
+        if *caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn transfer(env: &Env, token: &Address, from: &Address, to: &Address, amount: i128) {
+        env.invoke_contract::<()>(
+            token,
+            &Symbol::new(env, TOKEN_TRANSFER),
+            (from.clone(), to.clone(), amount).into_val(env),
+        );
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
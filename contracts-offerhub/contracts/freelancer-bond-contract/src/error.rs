@@ -0,0 +1,54 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// Amount must be positive
+    InvalidAmount = 4,
+    /// Bond configuration is out of range
+    InvalidConfig = 5,
+    /// User has never bonded
+    BondNotFound = 6,
+    /// Active bond is below the required amount
+    InsufficientBond = 7,
+    /// An unbonding request is already pending
+    UnbondingActive = 8,
+    /// No unbonding request is pending
+    NoUnbonding = 9,
+    /// The unbonding delay has not passed yet
+    UnbondingNotReady = 10,
+    /// Slash share exceeds the configured maximum
+    InvalidSlash = 11,
+    /// Contract is already paused
+    AlreadyPaused = 12,
+    /// Contract is not paused
+    NotPaused = 13,
+    /// Contract is paused
+    ContractPaused = 14,
+    /// Escrow was not deployed by the configured factory
+    UnknownEscrow = 15,
+    /// Escrow holds no bond lock
+    LockNotFound = 16,
+    /// Escrow is still open, so its lock stays
+    EscrowOpen = 17,
+    /// Amount is held by open escrows
+    BondLocked = 18,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
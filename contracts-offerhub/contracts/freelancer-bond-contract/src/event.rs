@@ -0,0 +1,47 @@
+use crate::types::BondConfig;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub fn admin_initialized(e: &Env, admin: Address) {
+    let topics = (Symbol::new(e, "admin_initialized"),);
+    e.events().publish(topics, admin);
+}
+
+pub fn config_set(e: &Env, config: BondConfig) {
+    let topics = (Symbol::new(e, "bond_config_set"),);
+    e.events().publish(topics, config);
+}
+
+pub fn slasher_set(e: &Env, slasher: Address, allowed: bool) {
+    let topics = (Symbol::new(e, "slasher_set"), slasher);
+    e.events().publish(topics, allowed);
+}
+
+pub fn bonded(e: &Env, user: Address, amount: i128, total: i128) {
+    let topics = (Symbol::new(e, "bonded"), user);
+    e.events().publish(topics, (amount, total));
+}
+
+pub fn unbond_requested(e: &Env, user: Address, amount: i128, unbonding_until: u64) {
+    let topics = (Symbol::new(e, "unbond_requested"), user);
+    e.events().publish(topics, (amount, unbonding_until));
+}
+
+pub fn bond_locked(e: &Env, user: Address, escrow: Address, amount: i128) {
+    let topics = (Symbol::new(e, "bond_locked"), user, escrow);
+    e.events().publish(topics, amount);
+}
+
+pub fn bond_unlocked(e: &Env, user: Address, escrow: Address, amount: i128) {
+    let topics = (Symbol::new(e, "bond_unlocked"), user, escrow);
+    e.events().publish(topics, amount);
+}
+
+pub fn withdrawn(e: &Env, user: Address, amount: i128) {
+    let topics = (Symbol::new(e, "bond_withdrawn"), user);
+    e.events().publish(topics, amount);
+}
+
+pub fn slashed(e: &Env, user: Address, slasher: Address, dispute_id: u32, amount: i128) {
+    let topics = (Symbol::new(e, "bond_slashed"), user, slasher);
+    e.events().publish(topics, (dispute_id, amount));
+}
@@ -0,0 +1,110 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::FreelancerBondContract;
+pub use crate::error::Error;
+pub use crate::types::{Bond, BondConfig, BondLock};
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| {
+    FreelancerBondContract::pause_admin(env)
+});
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the contract with an admin address and bond configuration
+    pub fn initialize(env: Env, admin: Address, config: BondConfig) -> Result<(), Error> {
+        FreelancerBondContract::initialize(env, admin, config)
+    }
+
+    /// Update the bond configuration (admin only)
+    pub fn set_config(env: Env, admin: Address, config: BondConfig) -> Result<(), Error> {
+        FreelancerBondContract::set_config(env, admin, config)
+    }
+
+    /// Get the bond configuration
+    pub fn get_config(env: Env) -> Option<BondConfig> {
+        FreelancerBondContract::get_config(env)
+    }
+
+    /// Allow or revoke a contract to slash bonds (admin only)
+    pub fn set_slasher(
+        env: Env,
+        admin: Address,
+        slasher: Address,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        FreelancerBondContract::set_slasher(env, admin, slasher, allowed)
+    }
+
+    /// Check whether an address may slash bonds
+    pub fn is_slasher(env: Env, slasher: Address) -> bool {
+        FreelancerBondContract::is_slasher(env, slasher)
+    }
+
+    /// Lock tokens as a good-behavior bond and return the new active amount
+    pub fn bond(env: Env, user: Address, amount: i128) -> Result<i128, Error> {
+        FreelancerBondContract::bond(env, user, amount)
+    }
+
+    /// Start unbonding part of the bond and return when it can be withdrawn
+    pub fn request_unbond(env: Env, user: Address, amount: i128) -> Result<u64, Error> {
+        FreelancerBondContract::request_unbond(env, user, amount)
+    }
+
+    /// Withdraw an unbonding amount after the delay
+    pub fn withdraw(env: Env, user: Address) -> Result<i128, Error> {
+        FreelancerBondContract::withdraw(env, user)
+    }
+
+    /// Slash a share of a user's bond after a lost dispute (authorized slashers only)
+    pub fn slash(
+        env: Env,
+        slasher: Address,
+        user: Address,
+        share_bps: u32,
+        dispute_id: u32,
+    ) -> Result<i128, Error> {
+        FreelancerBondContract::slash(env, slasher, user, share_bps, dispute_id)
+    }
+
+    /// Hold part of a user's bond until the calling escrow closes
+    pub fn lock_bond(env: Env, escrow: Address, user: Address, amount: i128) -> Result<(), Error> {
+        FreelancerBondContract::lock_bond(env, escrow, user, amount)
+    }
+
+    /// Release a closed escrow's bond lock and return the amount freed
+    pub fn release_lock(env: Env, escrow: Address) -> Result<i128, Error> {
+        FreelancerBondContract::release_lock(env, escrow)
+    }
+
+    /// Get the bond an escrow holds
+    pub fn get_lock(env: Env, escrow: Address) -> Option<BondLock> {
+        FreelancerBondContract::get_lock(env, escrow)
+    }
+
+    /// Get a user's bond
+    pub fn get_bond(env: Env, user: Address) -> Option<Bond> {
+        FreelancerBondContract::get_bond(env, user)
+    }
+
+    /// Check whether a user holds an active bond of at least `min_amount`
+    pub fn has_active_bond(env: Env, user: Address, min_amount: i128) -> bool {
+        FreelancerBondContract::has_active_bond(env, user, min_amount)
+    }
+
+    /// Fail unless a user holds an active bond of at least `min_amount`
+    pub fn require_bond(env: Env, user: Address, min_amount: i128) -> Result<(), Error> {
+        FreelancerBondContract::require_bond(env, user, min_amount)
+    }
+}
@@ -0,0 +1,75 @@
+use crate::types::{Bond, BondConfig, BondLock};
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Config,
+    Bond(Address),
+    /// Contracts allowed to slash bonds, e.g. the dispute contract
+    Slasher(Address),
+    /// escrow -> bond it holds while open
+    Lock(Address),
+}
+
+/// Get the admin address
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_config(env: &Env) -> Option<BondConfig> {
+    env.storage().instance().get(&DataKey::Config)
+}
+
+pub fn set_config(env: &Env, config: &BondConfig) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+pub fn get_bond(env: &Env, user: &Address) -> Option<Bond> {
+    env.storage().persistent().get(&DataKey::Bond(user.clone()))
+}
+
+pub fn set_bond(env: &Env, bond: &Bond) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Bond(bond.user.clone()), bond);
+}
+
+pub fn get_lock(env: &Env, escrow: &Address) -> Option<BondLock> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Lock(escrow.clone()))
+}
+
+pub fn set_lock(env: &Env, lock: &BondLock) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Lock(lock.escrow.clone()), lock);
+}
+
+pub fn remove_lock(env: &Env, escrow: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Lock(escrow.clone()));
+}
+
+pub fn is_slasher(env: &Env, slasher: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::Slasher(slasher.clone()))
+}
+
+pub fn set_slasher(env: &Env, slasher: &Address, allowed: bool) {
+    let key = DataKey::Slasher(slasher.clone());
+    if allowed {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
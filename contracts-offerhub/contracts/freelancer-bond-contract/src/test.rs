@@ -0,0 +1,232 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contract]
+pub struct MockEscrowFactory;
+
+#[contractimpl]
+impl MockEscrowFactory {
+    pub fn add_escrow(env: Env, escrow: Address, id: u32) {
+        env.storage().instance().set(&escrow, &id);
+    }
+
+    pub fn get_escrow_id_by_address(env: Env, escrow_address: Address) -> Option<u32> {
+        env.storage().instance().get(&escrow_address)
+    }
+}
+
+#[contract]
+pub struct MockEscrow;
+
+#[contractimpl]
+impl MockEscrow {
+    pub fn set_closed(env: Env, closed: bool) {
+        env.storage().instance().set(&(), &closed);
+    }
+
+    pub fn is_closed(env: Env) -> bool {
+        env.storage().instance().get(&()).unwrap_or(false)
+    }
+}
+
+struct Setup<'a> {
+    client: ContractClient<'a>,
+    admin: Address,
+    factory: MockEscrowFactoryClient<'a>,
+    token: TokenClient<'a>,
+    treasury: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let treasury = Address::generate(env);
+    let factory_id = env.register(MockEscrowFactory, ());
+    let client = ContractClient::new(env, &env.register(Contract, ()));
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &BondConfig {
+            token: token.clone(),
+            min_bond: 500,
+            unbonding_period: 7 * 24 * 60 * 60,
+            max_slash_bps: 5_000,
+            treasury: treasury.clone(),
+            escrow_factory: factory_id.clone(),
+        },
+    );
+    Setup {
+        client,
+        admin,
+        factory: MockEscrowFactoryClient::new(env, &factory_id),
+        token: TokenClient::new(env, &token),
+        treasury,
+    }
+}
+
+fn fund(env: &Env, setup: &Setup, user: &Address, amount: i128) {
+    StellarAssetClient::new(env, &setup.token.address).mint(user, &amount);
+}
+
+#[test]
+fn test_bond_and_delayed_unbond() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let freelancer = Address::generate(&env);
+    fund(&env, &setup, &freelancer, 1_000);
+
+    assert!(!setup.client.has_active_bond(&freelancer, &0));
+    assert_eq!(setup.client.bond(&freelancer, &800), 800);
+    assert_eq!(setup.token.balance(&freelancer), 200);
+    assert!(setup.client.has_active_bond(&freelancer, &0));
+    assert!(setup.client.has_active_bond(&freelancer, &800));
+    assert!(!setup.client.has_active_bond(&freelancer, &900));
+
+    // Unbonding stops counting right away but can't be withdrawn yet
+    let until = setup.client.request_unbond(&freelancer, &400);
+    assert_eq!(
+        setup.client.try_require_bond(&freelancer, &0),
+        Err(Ok(Error::InsufficientBond))
+    );
+    assert_eq!(
+        setup.client.try_request_unbond(&freelancer, &100),
+        Err(Ok(Error::UnbondingActive))
+    );
+    assert_eq!(
+        setup.client.try_withdraw(&freelancer),
+        Err(Ok(Error::UnbondingNotReady))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = until);
+    assert_eq!(setup.client.withdraw(&freelancer), 400);
+    assert_eq!(setup.token.balance(&freelancer), 600);
+
+    let bond = setup.client.get_bond(&freelancer).unwrap();
+    assert_eq!(bond.amount, 400);
+    assert_eq!(bond.unbonding_amount, 0);
+    assert_eq!(
+        setup.client.try_withdraw(&freelancer),
+        Err(Ok(Error::NoUnbonding))
+    );
+}
+
+#[test]
+fn test_slash_takes_share_of_bond() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let freelancer = Address::generate(&env);
+    let dispute_contract = Address::generate(&env);
+    fund(&env, &setup, &freelancer, 1_000);
+    setup.client.bond(&freelancer, &1_000);
+    setup.client.request_unbond(&freelancer, &600);
+
+    // Only authorized slashers, within the configured cap
+    assert_eq!(
+        setup
+            .client
+            .try_slash(&dispute_contract, &freelancer, &2_000, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    setup
+        .client
+        .set_slasher(&setup.admin, &dispute_contract, &true);
+    assert_eq!(
+        setup
+            .client
+            .try_slash(&dispute_contract, &freelancer, &6_000, &1),
+        Err(Ok(Error::InvalidSlash))
+    );
+
+    // 50% of 1_000 drains the active 400 first, then 100 of the unbonding amount
+    assert_eq!(
+        setup
+            .client
+            .slash(&dispute_contract, &freelancer, &5_000, &1),
+        500
+    );
+    let bond = setup.client.get_bond(&freelancer).unwrap();
+    assert_eq!(bond.amount, 0);
+    assert_eq!(bond.unbonding_amount, 500);
+    assert_eq!(bond.slashed_total, 500);
+    assert_eq!(setup.token.balance(&setup.treasury), 500);
+}
+
+#[test]
+fn test_escrow_lock_holds_bond_until_close() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let freelancer = Address::generate(&env);
+    fund(&env, &setup, &freelancer, 1_000);
+    setup.client.bond(&freelancer, &1_000);
+
+    let escrow_id = env.register(MockEscrow, ());
+    let escrow = MockEscrowClient::new(&env, &escrow_id);
+    assert_eq!(
+        setup.client.try_lock_bond(&escrow_id, &freelancer, &600),
+        Err(Ok(Error::UnknownEscrow))
+    );
+
+    setup.factory.add_escrow(&escrow_id, &1);
+    setup.client.lock_bond(&escrow_id, &freelancer, &600);
+    assert_eq!(
+        setup.client.get_bond(&freelancer).unwrap().escrow_locked,
+        600
+    );
+
+    // Locking again for the same escrow keeps the one lock
+    setup.client.lock_bond(&escrow_id, &freelancer, &600);
+    assert_eq!(setup.client.get_lock(&escrow_id).unwrap().amount, 600);
+
+    // Only the unlocked part can be unbonded or held by another escrow
+    let second = Address::generate(&env);
+    setup.factory.add_escrow(&second, &2);
+    assert_eq!(
+        setup.client.try_lock_bond(&second, &freelancer, &500),
+        Err(Ok(Error::InsufficientBond))
+    );
+    assert_eq!(
+        setup.client.try_request_unbond(&freelancer, &500),
+        Err(Ok(Error::BondLocked))
+    );
+    setup.client.request_unbond(&freelancer, &400);
+
+    assert_eq!(
+        setup.client.try_release_lock(&escrow_id),
+        Err(Ok(Error::EscrowOpen))
+    );
+    escrow.set_closed(&true);
+    assert_eq!(setup.client.release_lock(&escrow_id), 600);
+    assert_eq!(setup.client.get_lock(&escrow_id), None);
+    assert_eq!(setup.client.get_bond(&freelancer).unwrap().escrow_locked, 0);
+    assert_eq!(
+        setup.client.try_release_lock(&escrow_id),
+        Err(Ok(Error::LockNotFound))
+    );
+}
+
+#[test]
+fn test_pause_blocks_bonding() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let freelancer = Address::generate(&env);
+    fund(&env, &setup, &freelancer, 1_000);
+
+    setup.client.pause(&setup.admin);
+    assert_eq!(
+        setup.client.try_bond(&freelancer, &800),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        setup.client.try_pause(&setup.admin),
+        Err(Ok(Error::AlreadyPaused))
+    );
+
+    setup.client.unpause(&setup.admin);
+    assert_eq!(setup.client.bond(&freelancer, &800), 800);
+}
@@ -0,0 +1,43 @@
+use soroban_sdk::{contracttype, Address};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondConfig {
+    /// Token bonds are locked in
+    pub token: Address,
+    /// Smallest active bond that counts for gating
+    pub min_bond: i128,
+    /// Seconds between an unbond request and the withdrawal
+    pub unbonding_period: u64,
+    /// Largest share of a bond a single slash can take, in basis points
+    pub max_slash_bps: u32,
+    /// Receives slashed funds
+    pub treasury: Address,
+    /// Factory whose escrows may lock bonds for their lifetime
+    pub escrow_factory: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bond {
+    pub user: Address,
+    /// Locked amount that counts towards gating
+    pub amount: i128,
+    /// Part of `amount` held by open escrows; can't be unbonded until they close
+    pub escrow_locked: i128,
+    /// Amount waiting out the unbonding delay; still slashable
+    pub unbonding_amount: i128,
+    /// When the unbonding amount can be withdrawn, 0 if none is pending
+    pub unbonding_until: u64,
+    pub bonded_at: u64,
+    pub slashed_total: i128,
+}
+
+/// Bond an open escrow holds until it closes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondLock {
+    pub escrow: Address,
+    pub user: Address,
+    pub amount: i128,
+}
@@ -0,0 +1,258 @@
+use crate::error::Error;
+use crate::event::{
+    admin_initialized, config_set, proposal_created, proposal_status_changed, vote_cast,
+};
+use crate::storage::{
+    get_admin, get_config, get_proposal, get_vote, next_proposal_id, set_admin, set_config,
+    set_proposal, set_vote,
+};
+use crate::types::{GovernanceConfig, Proposal, ProposalStatus, ReputationReport, VoteRecord};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+const ORACLE_GET_REPUTATION: &str = "get_reputation";
+
+pub struct GovernanceContract;
+
+impl GovernanceContract {
+    /// Initialize the contract with an admin address and governance configuration
+    pub fn initialize(env: Env, admin: Address, config: GovernanceConfig) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+        Self::validate_config(&config)?;
+
+        set_admin(&env, &admin);
+        set_config(&env, &config);
+        admin_initialized(&env, admin);
+        config_set(&env, config);
+        Ok(())
+    }
+
+    pub fn set_config(env: Env, admin: Address, config: GovernanceConfig) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        Self::validate_config(&config)?;
+
+        set_config(&env, &config);
+        config_set(&env, config);
+        Ok(())
+    }
+
+    pub fn get_config(env: Env) -> Option<GovernanceConfig> {
+        get_config(&env)
+    }
+
+    /// Voting power is the user's reputation score plus a fixed weight per soulbound
+    /// achievement, both from the reputation oracle's report. Transferable badges don't
+    /// count, since they could vote, move to another address and vote again. An unreachable
+    /// oracle counts as 0.
+    pub fn voting_power(env: Env, user: Address) -> Result<u64, Error> {
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+
+        let report = match env.try_invoke_contract::<ReputationReport, Error>(
+            &config.reputation_oracle,
+            &Symbol::new(&env, ORACLE_GET_REPUTATION),
+            (env.current_contract_address(), user).into_val(&env),
+        ) {
+            Ok(Ok(report)) => report,
+            _ => return Ok(0),
+        };
+
+        let achievements: u64 = report
+            .badge_counts
+            .iter()
+            .filter(|(kind, _)| kind.is_soulbound())
+            .map(|(_, count)| count as u64)
+            .sum();
+
+        Ok(report.score as u64 + achievements * config.achievement_weight as u64)
+    }
+
+    /// Create a proposal to call `function` on `target` with `args` once passed and
+    /// through the timelock. The proposer needs at least the proposal threshold.
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        target: Address,
+        function: Symbol,
+        args: Vec<Val>,
+        description_hash: BytesN<32>,
+    ) -> Result<u64, Error> {
+        Self::require_not_paused(&env)?;
+        proposer.require_auth();
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+
+        if Self::voting_power(env.clone(), proposer.clone())? < config.proposal_threshold {
+            return Err(Error::InsufficientVotingPower);
+        }
+
+        let now = env.ledger().timestamp();
+        let proposal = Proposal {
+            id: next_proposal_id(&env),
+            proposer: proposer.clone(),
+            target: target.clone(),
+            function: function.clone(),
+            args,
+            description_hash,
+            created_at: now,
+            voting_ends_at: now + config.voting_period,
+            for_votes: 0,
+            against_votes: 0,
+            status: ProposalStatus::Active,
+            eta: 0,
+        };
+        set_proposal(&env, &proposal);
+
+        proposal_created(
+            &env,
+            proposer,
+            proposal.id,
+            target,
+            function,
+            proposal.voting_ends_at,
+        );
+        Ok(proposal.id)
+    }
+
+    /// Cast a vote weighted by the voter's current voting power
+    pub fn vote(env: Env, voter: Address, id: u64, support: bool) -> Result<u64, Error> {
+        Self::require_not_paused(&env)?;
+        voter.require_auth();
+        let mut proposal = get_proposal(&env, id).ok_or(Error::ProposalNotFound)?;
+
+        if proposal.status != ProposalStatus::Active {
+            return Err(Error::InvalidProposalState);
+        }
+        if env.ledger().timestamp() >= proposal.voting_ends_at {
+            return Err(Error::VotingClosed);
+        }
+        if get_vote(&env, id, &voter).is_some() {
+            return Err(Error::AlreadyVoted);
+        }
+
+        let power = Self::voting_power(env.clone(), voter.clone())?;
+        if power == 0 {
+            return Err(Error::InsufficientVotingPower);
+        }
+
+        if support {
+            proposal.for_votes += power;
+        } else {
+            proposal.against_votes += power;
+        }
+        set_proposal(&env, &proposal);
+        set_vote(&env, id, &voter, &VoteRecord { support, power });
+
+        vote_cast(&env, voter, id, support, power);
+        Ok(power)
+    }
+
+    /// Tally a proposal after voting ends. A passed proposal is queued behind the
+    /// timelock; anything else is defeated.
+    pub fn queue(env: Env, id: u64) -> Result<ProposalStatus, Error> {
+        Self::require_not_paused(&env)?;
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        let mut proposal = get_proposal(&env, id).ok_or(Error::ProposalNotFound)?;
+
+        if proposal.status != ProposalStatus::Active {
+            return Err(Error::InvalidProposalState);
+        }
+        let now = env.ledger().timestamp();
+        if now < proposal.voting_ends_at {
+            return Err(Error::VotingNotEnded);
+        }
+
+        let turnout = proposal.for_votes + proposal.against_votes;
+        if proposal.for_votes > proposal.against_votes && turnout >= config.quorum {
+            proposal.status = ProposalStatus::Queued;
+            proposal.eta = now + config.timelock_delay;
+        } else {
+            proposal.status = ProposalStatus::Defeated;
+        }
+        set_proposal(&env, &proposal);
+
+        proposal_status_changed(&env, id, proposal.status, proposal.eta);
+        Ok(proposal.status)
+    }
+
+    /// Execute a queued proposal once its timelock has elapsed. The target sees this
+    /// contract as the caller, so it must hold the relevant admin role there.
+    pub fn execute(env: Env, id: u64) -> Result<Val, Error> {
+        Self::require_not_paused(&env)?;
+        let mut proposal = get_proposal(&env, id).ok_or(Error::ProposalNotFound)?;
+
+        if proposal.status != ProposalStatus::Queued {
+            return Err(Error::InvalidProposalState);
+        }
+        if env.ledger().timestamp() < proposal.eta {
+            return Err(Error::TimelockNotExpired);
+        }
+
+        proposal.status = ProposalStatus::Executed;
+        set_proposal(&env, &proposal);
+
+        let result =
+            env.invoke_contract::<Val>(&proposal.target, &proposal.function, proposal.args.clone());
+
+        proposal_status_changed(&env, id, proposal.status, proposal.eta);
+        Ok(result)
+    }
+
+    /// Cancel a proposal that has not been executed (proposer or admin)
+    pub fn cancel(env: Env, caller: Address, id: u64) -> Result<(), Error> {
+        caller.require_auth();
+        let mut proposal = get_proposal(&env, id).ok_or(Error::ProposalNotFound)?;
+
+        let admin = get_admin(&env).ok_or(Error::NotInitialized)?;
+        if caller != proposal.proposer && caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        if !matches!(
+            proposal.status,
+            ProposalStatus::Active | ProposalStatus::Queued
+        ) {
+            return Err(Error::InvalidProposalState);
+        }
+
+        proposal.status = ProposalStatus::Canceled;
+        set_proposal(&env, &proposal);
+
+        proposal_status_changed(&env, id, proposal.status, proposal.eta);
+        Ok(())
+    }
+
+    pub fn get_proposal(env: Env, id: u64) -> Option<Proposal> {
+        get_proposal(&env, id)
+    }
+
+    pub fn get_vote(env: Env, id: u64, voter: Address) -> Option<VoteRecord> {
+        get_vote(&env, id, &voter)
+    }
+
+    fn validate_config(config: &GovernanceConfig) -> Result<(), Error> {
+        if config.voting_period == 0 || config.quorum == 0 {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+        caller.require_auth();
+        let admin = get_admin(env).ok_or(Error::NotInitialized)?;
+        if *caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// Governance configuration is out of range
+    InvalidConfig = 4,
+    /// Proposal not found
+    ProposalNotFound = 5,
+    /// Proposer's voting power is below the proposal threshold, or voter has none
+    InsufficientVotingPower = 6,
+    /// Voting period has ended
+    VotingClosed = 7,
+    /// Voter has already voted on this proposal
+    AlreadyVoted = 8,
+    /// Voting period has not ended yet
+    VotingNotEnded = 9,
+    /// Proposal is not in the state required for this action
+    InvalidProposalState = 10,
+    /// Timelock delay has not elapsed yet
+    TimelockNotExpired = 11,
+    /// Contract is already paused
+    AlreadyPaused = 12,
+    /// Contract is not paused
+    NotPaused = 13,
+    /// Contract is paused
+    ContractPaused = 14,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::types::{GovernanceConfig, ProposalStatus};
+use soroban_sdk::{Address, Env, Symbol};
+
+pub fn admin_initialized(e: &Env, admin: Address) {
+    let topics = (Symbol::new(e, "admin_initialized"),);
+    e.events().publish(topics, admin);
+}
+
+pub fn config_set(e: &Env, config: GovernanceConfig) {
+    let topics = (Symbol::new(e, "governance_config_set"),);
+    e.events().publish(topics, config);
+}
+
+pub fn proposal_created(
+    e: &Env,
+    proposer: Address,
+    id: u64,
+    target: Address,
+    function: Symbol,
+    voting_ends_at: u64,
+) {
+    let topics = (Symbol::new(e, "proposal_created"), proposer);
+    e.events()
+        .publish(topics, (id, target, function, voting_ends_at));
+}
+
+pub fn vote_cast(e: &Env, voter: Address, id: u64, support: bool, power: u64) {
+    let topics = (Symbol::new(e, "vote_cast"), voter);
+    e.events().publish(topics, (id, support, power));
+}
+
+pub fn proposal_status_changed(e: &Env, id: u64, status: ProposalStatus, eta: u64) {
+    let topics = (Symbol::new(e, "proposal_status_changed"), id);
+    e.events().publish(topics, (status, eta));
+}
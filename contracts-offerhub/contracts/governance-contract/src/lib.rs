@@ -0,0 +1,84 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::GovernanceContract;
+pub use crate::error::Error;
+pub use crate::types::{GovernanceConfig, Proposal, ProposalStatus, VoteRecord};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Symbol, Val, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| GovernanceContract::pause_admin(env));
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the contract with an admin address and governance configuration
+    pub fn initialize(env: Env, admin: Address, config: GovernanceConfig) -> Result<(), Error> {
+        GovernanceContract::initialize(env, admin, config)
+    }
+
+    /// Update the governance configuration (admin only)
+    pub fn set_config(env: Env, admin: Address, config: GovernanceConfig) -> Result<(), Error> {
+        GovernanceContract::set_config(env, admin, config)
+    }
+
+    /// Get the governance configuration
+    pub fn get_config(env: Env) -> Option<GovernanceConfig> {
+        GovernanceContract::get_config(env)
+    }
+
+    /// Get a user's voting power from reputation score and soulbound achievements
+    pub fn voting_power(env: Env, user: Address) -> Result<u64, Error> {
+        GovernanceContract::voting_power(env, user)
+    }
+
+    /// Create a proposal for a parameter change and return its id
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        target: Address,
+        function: Symbol,
+        args: Vec<Val>,
+        description_hash: BytesN<32>,
+    ) -> Result<u64, Error> {
+        GovernanceContract::propose(env, proposer, target, function, args, description_hash)
+    }
+
+    /// Vote for or against an active proposal and return the voting power used
+    pub fn vote(env: Env, voter: Address, id: u64, support: bool) -> Result<u64, Error> {
+        GovernanceContract::vote(env, voter, id, support)
+    }
+
+    /// Tally a proposal after voting ends and queue it if it passed
+    pub fn queue(env: Env, id: u64) -> Result<ProposalStatus, Error> {
+        GovernanceContract::queue(env, id)
+    }
+
+    /// Execute a queued proposal after the timelock
+    pub fn execute(env: Env, id: u64) -> Result<Val, Error> {
+        GovernanceContract::execute(env, id)
+    }
+
+    /// Cancel a proposal that has not been executed (proposer or admin)
+    pub fn cancel(env: Env, caller: Address, id: u64) -> Result<(), Error> {
+        GovernanceContract::cancel(env, caller, id)
+    }
+
+    /// Get a proposal by id
+    pub fn get_proposal(env: Env, id: u64) -> Option<Proposal> {
+        GovernanceContract::get_proposal(env, id)
+    }
+
+    /// Get the vote a voter cast on a proposal
+    pub fn get_vote(env: Env, id: u64, voter: Address) -> Option<VoteRecord> {
+        GovernanceContract::get_vote(env, id, voter)
+    }
+}
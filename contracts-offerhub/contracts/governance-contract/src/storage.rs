@@ -0,0 +1,65 @@
+use crate::types::{GovernanceConfig, Proposal, VoteRecord};
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Config,
+    NextProposalId,
+    Proposal(u64),
+    /// (proposal id, voter) -> vote cast
+    Vote(u64, Address),
+}
+
+/// Get the admin address
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_config(env: &Env) -> Option<GovernanceConfig> {
+    env.storage().instance().get(&DataKey::Config)
+}
+
+pub fn set_config(env: &Env, config: &GovernanceConfig) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+/// Allocate the next proposal id, starting at 1
+pub fn next_proposal_id(env: &Env) -> u64 {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::NextProposalId)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&DataKey::NextProposalId, &id);
+    id
+}
+
+pub fn get_proposal(env: &Env, id: u64) -> Option<Proposal> {
+    env.storage().persistent().get(&DataKey::Proposal(id))
+}
+
+pub fn set_proposal(env: &Env, proposal: &Proposal) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Proposal(proposal.id), proposal);
+}
+
+pub fn get_vote(env: &Env, id: u64, voter: &Address) -> Option<VoteRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Vote(id, voter.clone()))
+}
+
+pub fn set_vote(env: &Env, id: u64, voter: &Address, vote: &VoteRecord) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Vote(id, voter.clone()), vote);
+}
@@ -0,0 +1,226 @@
+#![cfg(test)]
+
+use super::*;
+use crate::types::BadgeKind;
+use reputation_oracle_contract::{Contract as Oracle, ContractClient as OracleClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{
+    contract, contractimpl, map, symbol_short, vec, Address, BytesN, Env, IntoVal, Map, String,
+    Symbol, Vec,
+};
+
+#[contract]
+pub struct MockReputationNft;
+
+#[contractimpl]
+impl MockReputationNft {
+    pub fn set_reputation(env: Env, user: Address, score: u32, achievements: u32) {
+        env.storage()
+            .instance()
+            .set(&user, &(score, achievements, 0u32));
+    }
+
+    pub fn set_transferable_badges(env: Env, user: Address, badges: u32) {
+        let (score, achievements, _) = Self::stored(&env, &user);
+        env.storage()
+            .instance()
+            .set(&user, &(score, achievements, badges));
+    }
+
+    pub fn get_user_reputation_score(env: Env, user: Address) -> u32 {
+        Self::stored(&env, &user).0
+    }
+
+    pub fn get_achievement_counts(env: Env, user: Address) -> Map<BadgeKind, u32> {
+        let (_, achievements, transferable) = Self::stored(&env, &user);
+        map![
+            &env,
+            (BadgeKind::Reputation, achievements),
+            (BadgeKind::Standard, transferable)
+        ]
+    }
+
+    pub fn get_completed_contracts(_env: Env, _user: Address) -> u32 {
+        0
+    }
+}
+
+impl MockReputationNft {
+    fn stored(env: &Env, user: &Address) -> (u32, u32, u32) {
+        env.storage().instance().get(user).unwrap_or((0, 0, 0))
+    }
+}
+
+// Stand-in for a parameter owner such as the dispute contract
+#[contract]
+pub struct MockParameters;
+
+#[contractimpl]
+impl MockParameters {
+    pub fn set_dispute_window(env: Env, secs: u64) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("WINDOW"), &secs);
+    }
+
+    pub fn get_dispute_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("WINDOW"))
+            .unwrap_or(0)
+    }
+}
+
+const VOTING_PERIOD: u64 = 3 * 24 * 60 * 60;
+const TIMELOCK_DELAY: u64 = 2 * 24 * 60 * 60;
+
+fn setup(env: &Env) -> (ContractClient<'_>, Address, MockReputationNftClient<'_>) {
+    env.mock_all_auths();
+    let nft_id = env.register(MockReputationNft, ());
+    let oracle = OracleClient::new(env, &env.register(Oracle, ()));
+    let client = ContractClient::new(env, &env.register(Contract, ()));
+    let admin = Address::generate(env);
+    oracle.initialize(&admin, &nft_id);
+    oracle.register_consumer(
+        &admin,
+        &client.address,
+        &String::from_str(env, "Governance"),
+        &100,
+        &3_600,
+    );
+    client.initialize(
+        &admin,
+        &GovernanceConfig {
+            reputation_oracle: oracle.address.clone(),
+            voting_period: VOTING_PERIOD,
+            timelock_delay: TIMELOCK_DELAY,
+            quorum: 200,
+            proposal_threshold: 50,
+            achievement_weight: 10,
+        },
+    );
+    (client, admin, MockReputationNftClient::new(env, &nft_id))
+}
+
+fn propose_window(env: &Env, client: &ContractClient, proposer: &Address, target: &Address) -> u64 {
+    client.propose(
+        proposer,
+        target,
+        &Symbol::new(env, "set_dispute_window"),
+        &vec![env, 86_400u64.into_val(env)],
+        &BytesN::from_array(env, &[7; 32]),
+    )
+}
+
+#[test]
+fn test_proposal_lifecycle_through_timelock() {
+    let env = Env::default();
+    let (client, _, nft) = setup(&env);
+    let target = env.register(MockParameters, ());
+    let proposer = Address::generate(&env);
+    let voter = Address::generate(&env);
+    let opponent = Address::generate(&env);
+
+    // Score plus 10 per soulbound achievement; transferable badges add nothing
+    nft.set_reputation(&proposer, &80, &2);
+    nft.set_reputation(&voter, &60, &4);
+    nft.set_reputation(&opponent, &90, &0);
+    nft.set_transferable_badges(&opponent, &5);
+    assert_eq!(client.voting_power(&proposer), 100);
+    assert_eq!(client.voting_power(&opponent), 90);
+
+    let id = propose_window(&env, &client, &proposer, &target);
+    assert_eq!(client.vote(&proposer, &id, &true), 100);
+    assert_eq!(client.vote(&voter, &id, &true), 100);
+    assert_eq!(client.vote(&opponent, &id, &false), 90);
+    assert_eq!(
+        client.try_vote(&voter, &id, &true),
+        Err(Ok(Error::AlreadyVoted))
+    );
+    assert_eq!(client.try_queue(&id), Err(Ok(Error::VotingNotEnded)));
+
+    env.ledger().with_mut(|l| l.timestamp += VOTING_PERIOD);
+    assert_eq!(
+        client.try_vote(&Address::generate(&env), &id, &true),
+        Err(Ok(Error::VotingClosed))
+    );
+    assert_eq!(client.queue(&id), ProposalStatus::Queued);
+    assert!(matches!(
+        client.try_execute(&id),
+        Err(Ok(Error::TimelockNotExpired))
+    ));
+
+    env.ledger().with_mut(|l| l.timestamp += TIMELOCK_DELAY);
+    client.execute(&id);
+    assert_eq!(
+        MockParametersClient::new(&env, &target).get_dispute_window(),
+        86_400
+    );
+
+    let proposal = client.get_proposal(&id).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Executed);
+    assert_eq!(proposal.for_votes, 200);
+    assert_eq!(proposal.against_votes, 90);
+    assert!(matches!(
+        client.try_execute(&id),
+        Err(Ok(Error::InvalidProposalState))
+    ));
+}
+
+#[test]
+fn test_proposal_needs_threshold_and_quorum() {
+    let env = Env::default();
+    let (client, _, nft) = setup(&env);
+    let target = env.register(MockParameters, ());
+    let newcomer = Address::generate(&env);
+    let proposer = Address::generate(&env);
+
+    nft.set_reputation(&newcomer, &20, &1);
+    nft.set_reputation(&proposer, &120, &0);
+
+    assert_eq!(
+        client.try_propose(
+            &newcomer,
+            &target,
+            &Symbol::new(&env, "set_dispute_window"),
+            &Vec::new(&env),
+            &BytesN::from_array(&env, &[7; 32]),
+        ),
+        Err(Ok(Error::InsufficientVotingPower))
+    );
+
+    // A majority below quorum is defeated
+    let id = propose_window(&env, &client, &proposer, &target);
+    client.vote(&proposer, &id, &true);
+    env.ledger().with_mut(|l| l.timestamp += VOTING_PERIOD);
+    assert_eq!(client.queue(&id), ProposalStatus::Defeated);
+    assert!(matches!(
+        client.try_execute(&id),
+        Err(Ok(Error::InvalidProposalState))
+    ));
+}
+
+#[test]
+fn test_pause_blocks_proposals() {
+    let env = Env::default();
+    let (client, admin, nft) = setup(&env);
+    let target = env.register(MockParameters, ());
+    let proposer = Address::generate(&env);
+    nft.set_reputation(&proposer, &120, &0);
+
+    client.pause(&admin);
+    assert_eq!(
+        client.try_propose(
+            &proposer,
+            &target,
+            &Symbol::new(&env, "set_dispute_window"),
+            &Vec::new(&env),
+            &BytesN::from_array(&env, &[7; 32]),
+        ),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    client.unpause(&admin);
+    let id = propose_window(&env, &client, &proposer, &target);
+    assert_eq!(client.vote(&proposer, &id, &true), 120);
+}
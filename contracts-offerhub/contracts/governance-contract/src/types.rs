@@ -0,0 +1,101 @@
+use soroban_sdk::{contracttype, Address, BytesN, Map, Symbol, Val, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceConfig {
+    /// Reputation oracle whose `get_reputation` reports voting power is read from; this
+    /// contract must be registered as one of its consumers
+    pub reputation_oracle: Address,
+    /// Seconds a proposal stays open for voting
+    pub voting_period: u64,
+    /// Seconds between queueing a passed proposal and executing it
+    pub timelock_delay: u64,
+    /// Total votes cast needed for a proposal to pass
+    pub quorum: u64,
+    /// Voting power needed to create a proposal
+    pub proposal_threshold: u64,
+    /// Voting power each soulbound reputation achievement adds on top of the score
+    pub achievement_weight: u32,
+}
+
+/// Mirrors the reputation oracle's `ReputationTier` so its reports decode without
+/// depending on that crate.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ReputationTier {
+    Unrated = 0,
+    Bronze = 1,
+    Silver = 2,
+    Gold = 3,
+    Platinum = 4,
+}
+
+/// Mirrors the reputation oracle's `BadgeKind`.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BadgeKind {
+    Standard,
+    Reputation,
+    ProjectMilestone,
+    RatingMilestone,
+    CustomAchievement,
+}
+
+impl BadgeKind {
+    /// Whether the reputation NFT refuses to transfer badges of this kind
+    pub fn is_soulbound(&self) -> bool {
+        !matches!(self, BadgeKind::Standard | BadgeKind::CustomAchievement)
+    }
+}
+
+/// Mirrors the reputation oracle's `ReputationReport`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationReport {
+    pub version: u32,
+    pub user: Address,
+    pub score: u32,
+    pub tier: ReputationTier,
+    pub total_badges: u32,
+    pub badge_counts: Map<BadgeKind, u32>,
+    pub completed_contracts: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ProposalStatus {
+    Active = 0,
+    Defeated = 1,
+    Queued = 2,
+    Executed = 3,
+    Canceled = 4,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Address,
+    /// Contract and function called on execution, e.g. a fee manager rate setter
+    pub target: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+    pub description_hash: BytesN<32>,
+    pub created_at: u64,
+    pub voting_ends_at: u64,
+    pub for_votes: u64,
+    pub against_votes: u64,
+    pub status: ProposalStatus,
+    /// Earliest execution time once queued, 0 before that
+    pub eta: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteRecord {
+    pub support: bool,
+    pub power: u64,
+}
@@ -0,0 +1,397 @@
+use crate::error::Error;
+use crate::event::{
+    admin_initialized, assessor_set, claim_assessed, claim_filed, claim_paid, config_set,
+    policy_closed, policy_registered, surplus_withdrawn, topped_up,
+};
+use crate::storage::{
+    get_admin, get_claim, get_config, get_policy, get_state, is_assessor, next_claim_id, set_admin,
+    set_assessor, set_claim, set_config, set_policy, set_state,
+};
+use crate::types::{Claim, ClaimStatus, Policy, PoolConfig, PoolState};
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+const BPS_DENOMINATOR: i128 = 10_000;
+const TOKEN_TRANSFER: &str = "transfer";
+const FACTORY_GET_ESCROW_ID: &str = "get_escrow_id_by_address";
+const ESCROW_IS_CLOSED: &str = "is_closed";
+const ESCROW_CLAIM_OUTCOME: &str = "record_claim_outcome";
+
+pub struct InsurancePoolContract;
+
+impl InsurancePoolContract {
+    pub fn initialize(env: Env, admin: Address, config: PoolConfig) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+        Self::validate_config(&config)?;
+
+        set_admin(&env, &admin);
+        set_config(&env, &config);
+        admin_initialized(&env, admin);
+        config_set(&env, config);
+        Ok(())
+    }
+
+    pub fn set_config(env: Env, admin: Address, config: PoolConfig) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        Self::validate_config(&config)?;
+
+        set_config(&env, &config);
+        config_set(&env, config);
+        Ok(())
+    }
+
+    pub fn get_config(env: Env) -> Option<PoolConfig> {
+        get_config(&env)
+    }
+
+    /// Grant or revoke the claims-assessor role. Registering the governance contract as an
+    /// assessor lets claims be settled by a governance vote.
+    pub fn set_assessor(
+        env: Env,
+        admin: Address,
+        assessor: Address,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        set_assessor(&env, &assessor, allowed);
+        assessor_set(&env, assessor, allowed);
+        Ok(())
+    }
+
+    pub fn is_assessor(env: Env, assessor: Address) -> bool {
+        is_assessor(&env, &assessor)
+    }
+
+    /// Add funds to the pool, e.g. a treasury top-up
+    pub fn top_up(env: Env, from: Address, amount: i128) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        from.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+
+        Self::transfer(
+            &env,
+            &config.token,
+            &from,
+            &env.current_contract_address(),
+            amount,
+        );
+
+        let mut state = get_state(&env);
+        state.balance += amount;
+        state.top_ups += amount;
+        set_state(&env, &state);
+
+        topped_up(&env, from, amount, state.balance);
+        Ok(state.balance)
+    }
+
+    /// Withdraw funds above the reserve requirement (admin only)
+    pub fn withdraw_surplus(
+        env: Env,
+        admin: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        Self::require_admin(&env, &admin)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+
+        let mut state = get_state(&env);
+        if amount > state.balance {
+            return Err(Error::InsufficientReserve);
+        }
+        state.balance -= amount;
+        Self::check_reserve(&config, &state)?;
+        set_state(&env, &state);
+
+        Self::transfer(
+            &env,
+            &config.token,
+            &env.current_contract_address(),
+            &to,
+            amount,
+        );
+
+        surplus_withdrawn(&env, to, amount, state.balance);
+        Ok(state.balance)
+    }
+
+    /// Called by an escrow from `deposit_funds_insured`. The pool prices the cover at
+    /// `premium_rate_bps` of `coverage` and collects the premium from the client. Fails, and
+    /// with it the insured deposit, if the escrow holds another token or the cover would
+    /// breach the reserve ratio.
+    pub fn register_policy(
+        env: Env,
+        escrow: Address,
+        client: Address,
+        token: Address,
+        coverage: i128,
+    ) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        escrow.require_auth();
+        client.require_auth();
+        if coverage <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        if token != config.token {
+            return Err(Error::TokenMismatch);
+        }
+        let premium = coverage * config.premium_rate_bps as i128 / BPS_DENOMINATOR;
+        if premium <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let escrow_id: Option<u32> = env.invoke_contract(
+            &config.escrow_factory,
+            &Symbol::new(&env, FACTORY_GET_ESCROW_ID),
+            (escrow.clone(),).into_val(&env),
+        );
+        if escrow_id.is_none() {
+            return Err(Error::UnknownEscrow);
+        }
+        if get_policy(&env, &escrow).is_some() {
+            return Err(Error::PolicyExists);
+        }
+
+        let mut state = get_state(&env);
+        state.balance += premium;
+        state.premiums_collected += premium;
+        state.exposure += coverage;
+        Self::check_reserve(&config, &state)?;
+        set_state(&env, &state);
+
+        Self::transfer(
+            &env,
+            &config.token,
+            &client,
+            &env.current_contract_address(),
+            premium,
+        );
+
+        set_policy(
+            &env,
+            &Policy {
+                escrow: escrow.clone(),
+                client: client.clone(),
+                coverage,
+                premium,
+                registered_at: env.ledger().timestamp(),
+                claim_id: None,
+                closed: false,
+            },
+        );
+
+        policy_registered(&env, escrow, client, coverage, premium);
+        Ok(premium)
+    }
+
+    /// Called by an escrow once it has gone into dispute or been refunded. Returns the
+    /// claim id for the assessors to settle.
+    pub fn file_claim(
+        env: Env,
+        escrow: Address,
+        client: Address,
+        coverage: i128,
+    ) -> Result<u32, Error> {
+        Self::require_not_paused(&env)?;
+        escrow.require_auth();
+
+        let mut policy = get_policy(&env, &escrow)
+            .filter(|p| !p.closed)
+            .ok_or(Error::PolicyNotFound)?;
+        if policy.client != client {
+            return Err(Error::Unauthorized);
+        }
+        if policy.claim_id.is_some() {
+            return Err(Error::ClaimExists);
+        }
+        if coverage <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let claim = Claim {
+            id: next_claim_id(&env),
+            escrow: escrow.clone(),
+            client: client.clone(),
+            amount: coverage.min(policy.coverage),
+            status: ClaimStatus::Pending,
+            filed_at: env.ledger().timestamp(),
+            assessed_by: None,
+            payout: 0,
+        };
+        set_claim(&env, &claim);
+
+        policy.claim_id = Some(claim.id);
+        set_policy(&env, &policy);
+
+        claim_filed(&env, claim.id, escrow, client, claim.amount);
+        Ok(claim.id)
+    }
+
+    /// Settle a pending claim. An approved claim pays the client up to the claimed amount;
+    /// either way the outcome is reported back to the escrow.
+    pub fn assess_claim(
+        env: Env,
+        assessor: Address,
+        claim_id: u32,
+        approved: bool,
+        payout: i128,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        assessor.require_auth();
+        if !is_assessor(&env, &assessor) {
+            return Err(Error::Unauthorized);
+        }
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+
+        let mut claim = get_claim(&env, claim_id).ok_or(Error::ClaimNotFound)?;
+        if claim.status != ClaimStatus::Pending {
+            return Err(Error::ClaimNotPending);
+        }
+        let policy = get_policy(&env, &claim.escrow).ok_or(Error::PolicyNotFound)?;
+
+        let mut state = get_state(&env);
+        let payout = if approved { payout } else { 0 };
+        if payout < 0 || payout > claim.amount || payout > state.balance {
+            return Err(Error::InvalidPayout);
+        }
+
+        state.balance -= payout;
+        state.payouts += payout;
+        state.exposure -= policy.coverage;
+        set_state(&env, &state);
+
+        claim.status = if approved {
+            ClaimStatus::Approved
+        } else {
+            ClaimStatus::Rejected
+        };
+        claim.assessed_by = Some(assessor.clone());
+        claim.payout = payout;
+        set_claim(&env, &claim);
+
+        if payout > 0 {
+            Self::transfer(
+                &env,
+                &config.token,
+                &env.current_contract_address(),
+                &claim.client,
+                payout,
+            );
+            claim_paid(&env, claim_id, claim.client.clone(), payout);
+        }
+
+        env.invoke_contract::<()>(
+            &claim.escrow,
+            &Symbol::new(&env, ESCROW_CLAIM_OUTCOME),
+            (env.current_contract_address(), approved, payout).into_val(&env),
+        );
+
+        claim_assessed(&env, claim_id, assessor, approved, payout);
+        Ok(())
+    }
+
+    /// Release the cover of an escrow that closed without a claim, freeing reserve. Any
+    /// terminal state counts: released, refunded, cancelled or settled through a dispute.
+    pub fn close_policy(env: Env, escrow: Address) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        let mut policy = get_policy(&env, &escrow)
+            .filter(|p| !p.closed && p.claim_id.is_none())
+            .ok_or(Error::PolicyNotFound)?;
+
+        let closed: bool = env.invoke_contract(
+            &escrow,
+            &Symbol::new(&env, ESCROW_IS_CLOSED),
+            ().into_val(&env),
+        );
+        if !closed {
+            return Err(Error::PolicyActive);
+        }
+
+        policy.closed = true;
+        set_policy(&env, &policy);
+
+        let mut state = get_state(&env);
+        state.exposure -= policy.coverage;
+        set_state(&env, &state);
+
+        policy_closed(&env, escrow, policy.coverage);
+        Ok(())
+    }
+
+    pub fn get_policy(env: Env, escrow: Address) -> Option<Policy> {
+        get_policy(&env, &escrow)
+    }
+
+    pub fn get_claim(env: Env, claim_id: u32) -> Result<Claim, Error> {
+        get_claim(&env, claim_id).ok_or(Error::ClaimNotFound)
+    }
+
+    pub fn get_pool_state(env: Env) -> PoolState {
+        get_state(&env)
+    }
+
+    /// Current pool balance relative to outstanding coverage, in basis points
+    pub fn reserve_ratio_bps(env: Env) -> u32 {
+        let state = get_state(&env);
+        if state.exposure <= 0 {
+            return u32::MAX;
+        }
+        (state.balance * BPS_DENOMINATOR / state.exposure).min(u32::MAX as i128) as u32
+    }
+
+    fn check_reserve(config: &PoolConfig, state: &PoolState) -> Result<(), Error> {
+        if state.balance * BPS_DENOMINATOR < state.exposure * config.min_reserve_bps as i128 {
+            return Err(Error::InsufficientReserve);
+        }
+        Ok(())
+    }
+
+    fn validate_config(config: &PoolConfig) -> Result<(), Error> {
+        if config.min_reserve_bps == 0
+            || config.premium_rate_bps == 0
+            || config.premium_rate_bps as i128 > BPS_DENOMINATOR
+        {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+        caller.require_auth();
+        let admin = get_admin(env).ok_or(Error::NotInitialized)?;
+        if *caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn transfer(env: &Env, token: &Address, from: &Address, to: &Address, amount: i128) {
+        env.invoke_contract::<()>(
+            token,
+            &Symbol::new(env, TOKEN_TRANSFER),
+            (from.clone(), to.clone(), amount).into_val(env),
+        );
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
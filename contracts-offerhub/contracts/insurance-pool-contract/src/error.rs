@@ -0,0 +1,54 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// Amount must be positive
+    InvalidAmount = 4,
+    /// Pool configuration is out of range
+    InvalidConfig = 5,
+    /// Escrow was not deployed by the configured factory
+    UnknownEscrow = 6,
+    /// Escrow already holds a policy
+    PolicyExists = 7,
+    /// Escrow has no policy
+    PolicyNotFound = 8,
+    /// A claim has already been filed for this policy
+    ClaimExists = 9,
+    /// Claim does not exist
+    ClaimNotFound = 10,
+    /// Claim has already been assessed
+    ClaimNotPending = 11,
+    /// Operation would push the pool below its reserve ratio
+    InsufficientReserve = 12,
+    /// Payout exceeds the claimed coverage or the pool balance
+    InvalidPayout = 13,
+    /// Escrow has not closed yet, so its cover is still in force
+    PolicyActive = 14,
+    /// Contract is already paused
+    AlreadyPaused = 15,
+    /// Contract is not paused
+    NotPaused = 16,
+    /// Contract is paused
+    ContractPaused = 17,
+    /// Escrow holds a different token than the pool
+    TokenMismatch = 18,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
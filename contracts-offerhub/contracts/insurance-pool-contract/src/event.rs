@@ -0,0 +1,52 @@
+use crate::types::PoolConfig;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub fn admin_initialized(e: &Env, admin: Address) {
+    let topics = (Symbol::new(e, "admin_initialized"),);
+    e.events().publish(topics, admin);
+}
+
+pub fn config_set(e: &Env, config: PoolConfig) {
+    let topics = (Symbol::new(e, "pool_config_set"),);
+    e.events().publish(topics, config);
+}
+
+pub fn assessor_set(e: &Env, assessor: Address, allowed: bool) {
+    let topics = (Symbol::new(e, "assessor_set"), assessor);
+    e.events().publish(topics, allowed);
+}
+
+pub fn topped_up(e: &Env, from: Address, amount: i128, balance: i128) {
+    let topics = (Symbol::new(e, "pool_topped_up"), from);
+    e.events().publish(topics, (amount, balance));
+}
+
+pub fn surplus_withdrawn(e: &Env, to: Address, amount: i128, balance: i128) {
+    let topics = (Symbol::new(e, "surplus_withdrawn"), to);
+    e.events().publish(topics, (amount, balance));
+}
+
+pub fn policy_registered(e: &Env, escrow: Address, client: Address, coverage: i128, premium: i128) {
+    let topics = (Symbol::new(e, "policy_registered"), escrow, client);
+    e.events().publish(topics, (coverage, premium));
+}
+
+pub fn policy_closed(e: &Env, escrow: Address, coverage: i128) {
+    let topics = (Symbol::new(e, "policy_closed"), escrow);
+    e.events().publish(topics, coverage);
+}
+
+pub fn claim_filed(e: &Env, claim_id: u32, escrow: Address, client: Address, amount: i128) {
+    let topics = (Symbol::new(e, "claim_filed"), escrow, client);
+    e.events().publish(topics, (claim_id, amount));
+}
+
+pub fn claim_assessed(e: &Env, claim_id: u32, assessor: Address, approved: bool, payout: i128) {
+    let topics = (Symbol::new(e, "claim_assessed"), assessor);
+    e.events().publish(topics, (claim_id, approved, payout));
+}
+
+pub fn claim_paid(e: &Env, claim_id: u32, client: Address, payout: i128) {
+    let topics = (Symbol::new(e, "claim_paid"), client);
+    e.events().publish(topics, (claim_id, payout));
+}
@@ -0,0 +1,127 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::InsurancePoolContract;
+pub use crate::error::Error;
+pub use crate::types::{Claim, ClaimStatus, Policy, PoolConfig, PoolState};
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| {
+    InsurancePoolContract::pause_admin(env)
+});
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the pool with an admin address and configuration
+    pub fn initialize(env: Env, admin: Address, config: PoolConfig) -> Result<(), Error> {
+        InsurancePoolContract::initialize(env, admin, config)
+    }
+
+    /// Update the pool configuration (admin only)
+    pub fn set_config(env: Env, admin: Address, config: PoolConfig) -> Result<(), Error> {
+        InsurancePoolContract::set_config(env, admin, config)
+    }
+
+    /// Get the pool configuration
+    pub fn get_config(env: Env) -> Option<PoolConfig> {
+        InsurancePoolContract::get_config(env)
+    }
+
+    /// Grant or revoke the claims-assessor role (admin only)
+    pub fn set_assessor(
+        env: Env,
+        admin: Address,
+        assessor: Address,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        InsurancePoolContract::set_assessor(env, admin, assessor, allowed)
+    }
+
+    /// Check whether an address may assess claims
+    pub fn is_assessor(env: Env, assessor: Address) -> bool {
+        InsurancePoolContract::is_assessor(env, assessor)
+    }
+
+    /// Add funds to the pool and return the new balance
+    pub fn top_up(env: Env, from: Address, amount: i128) -> Result<i128, Error> {
+        InsurancePoolContract::top_up(env, from, amount)
+    }
+
+    /// Withdraw funds above the reserve requirement (admin only)
+    pub fn withdraw_surplus(
+        env: Env,
+        admin: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        InsurancePoolContract::withdraw_surplus(env, admin, to, amount)
+    }
+
+    /// Register cover for an escrow, collect the premium from its client and return it;
+    /// called by the escrow
+    pub fn register_policy(
+        env: Env,
+        escrow: Address,
+        client: Address,
+        token: Address,
+        coverage: i128,
+    ) -> Result<i128, Error> {
+        InsurancePoolContract::register_policy(env, escrow, client, token, coverage)
+    }
+
+    /// File a claim for a disputed or refunded escrow and return the claim id
+    pub fn file_claim(
+        env: Env,
+        escrow: Address,
+        client: Address,
+        coverage: i128,
+    ) -> Result<u32, Error> {
+        InsurancePoolContract::file_claim(env, escrow, client, coverage)
+    }
+
+    /// Approve or reject a pending claim and report the outcome to the escrow (assessors only)
+    pub fn assess_claim(
+        env: Env,
+        assessor: Address,
+        claim_id: u32,
+        approved: bool,
+        payout: i128,
+    ) -> Result<(), Error> {
+        InsurancePoolContract::assess_claim(env, assessor, claim_id, approved, payout)
+    }
+
+    /// Release the cover of a closed escrow that never claimed
+    pub fn close_policy(env: Env, escrow: Address) -> Result<(), Error> {
+        InsurancePoolContract::close_policy(env, escrow)
+    }
+
+    /// Get the policy held by an escrow
+    pub fn get_policy(env: Env, escrow: Address) -> Option<Policy> {
+        InsurancePoolContract::get_policy(env, escrow)
+    }
+
+    /// Get a claim by id
+    pub fn get_claim(env: Env, claim_id: u32) -> Result<Claim, Error> {
+        InsurancePoolContract::get_claim(env, claim_id)
+    }
+
+    /// Get the pool balance, outstanding coverage and running totals
+    pub fn get_pool_state(env: Env) -> PoolState {
+        InsurancePoolContract::get_pool_state(env)
+    }
+
+    /// Get the current reserve ratio in basis points
+    pub fn reserve_ratio_bps(env: Env) -> u32 {
+        InsurancePoolContract::reserve_ratio_bps(env)
+    }
+}
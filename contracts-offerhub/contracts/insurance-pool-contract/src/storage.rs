@@ -0,0 +1,93 @@
+use crate::types::{Claim, Policy, PoolConfig, PoolState};
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Config,
+    State,
+    /// Addresses allowed to assess claims, e.g. a claims committee or the governance contract
+    Assessor(Address),
+    Policy(Address),
+    NextClaimId,
+    Claim(u32),
+}
+
+/// Get the admin address
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_config(env: &Env) -> Option<PoolConfig> {
+    env.storage().instance().get(&DataKey::Config)
+}
+
+pub fn set_config(env: &Env, config: &PoolConfig) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+pub fn get_state(env: &Env) -> PoolState {
+    env.storage()
+        .instance()
+        .get(&DataKey::State)
+        .unwrap_or_default()
+}
+
+pub fn set_state(env: &Env, state: &PoolState) {
+    env.storage().instance().set(&DataKey::State, state);
+}
+
+pub fn is_assessor(env: &Env, assessor: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::Assessor(assessor.clone()))
+}
+
+pub fn set_assessor(env: &Env, assessor: &Address, allowed: bool) {
+    let key = DataKey::Assessor(assessor.clone());
+    if allowed {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+pub fn get_policy(env: &Env, escrow: &Address) -> Option<Policy> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Policy(escrow.clone()))
+}
+
+pub fn set_policy(env: &Env, policy: &Policy) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Policy(policy.escrow.clone()), policy);
+}
+
+/// Allocate the next claim id, starting at 1
+pub fn next_claim_id(env: &Env) -> u32 {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u32>(&DataKey::NextClaimId)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&DataKey::NextClaimId, &id);
+    id
+}
+
+pub fn get_claim(env: &Env, id: u32) -> Option<Claim> {
+    env.storage().persistent().get(&DataKey::Claim(id))
+}
+
+pub fn set_claim(env: &Env, claim: &Claim) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Claim(claim.id), claim);
+}
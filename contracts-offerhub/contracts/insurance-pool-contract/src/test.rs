@@ -0,0 +1,255 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+#[contract]
+pub struct MockEscrowFactory;
+
+#[contractimpl]
+impl MockEscrowFactory {
+    pub fn add_escrow(env: Env, escrow: Address, id: u32) {
+        env.storage().instance().set(&escrow, &id);
+    }
+
+    pub fn get_escrow_id_by_address(env: Env, escrow_address: Address) -> Option<u32> {
+        env.storage().instance().get(&escrow_address)
+    }
+}
+
+#[contract]
+pub struct MockEscrow;
+
+#[contractimpl]
+impl MockEscrow {
+    pub fn set_closed(env: Env, closed: bool) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("done"), &closed);
+    }
+
+    pub fn is_closed(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("done"))
+            .unwrap_or(false)
+    }
+
+    pub fn record_claim_outcome(env: Env, insurance_pool: Address, approved: bool, payout: i128) {
+        insurance_pool.require_auth();
+        env.storage()
+            .instance()
+            .set(&symbol_short!("outcome"), &(approved, payout));
+    }
+
+    pub fn get_outcome(env: Env) -> Option<(bool, i128)> {
+        env.storage().instance().get(&symbol_short!("outcome"))
+    }
+}
+
+struct Setup<'a> {
+    pool: ContractClient<'a>,
+    admin: Address,
+    token: TokenClient<'a>,
+    factory: MockEscrowFactoryClient<'a>,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let factory_id = env.register(MockEscrowFactory, ());
+    let pool = ContractClient::new(env, &env.register(Contract, ()));
+    let admin = Address::generate(env);
+    pool.initialize(
+        &admin,
+        &PoolConfig {
+            token: token.clone(),
+            escrow_factory: factory_id.clone(),
+            min_reserve_bps: 2_000,
+            premium_rate_bps: 500,
+        },
+    );
+    Setup {
+        pool,
+        admin,
+        token: TokenClient::new(env, &token),
+        factory: MockEscrowFactoryClient::new(env, &factory_id),
+    }
+}
+
+/// Deploy a factory-known escrow and buy cover for it like the escrow's
+/// `deposit_funds_insured` does
+fn insure(env: &Env, setup: &Setup, id: u32, client: &Address, coverage: i128) -> Address {
+    let escrow = env.register(MockEscrow, ());
+    setup.factory.add_escrow(&escrow, &id);
+    StellarAssetClient::new(env, &setup.token.address).mint(client, &(coverage / 20));
+    setup
+        .pool
+        .register_policy(&escrow, client, &setup.token.address, &coverage);
+    escrow
+}
+
+#[test]
+fn test_reserve_ratio_limits_cover_and_withdrawals() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let client = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    StellarAssetClient::new(&env, &setup.token.address).mint(&treasury, &1_000);
+
+    let token = setup.token.address.clone();
+    let unknown = env.register(MockEscrow, ());
+    assert_eq!(
+        setup
+            .pool
+            .try_register_policy(&unknown, &client, &token, &1_000),
+        Err(Ok(Error::UnknownEscrow))
+    );
+
+    // The 50 premium on 1_000 cover is a 5% reserve, below the 20% minimum
+    let escrow = env.register(MockEscrow, ());
+    setup.factory.add_escrow(&escrow, &1);
+    StellarAssetClient::new(&env, &token).mint(&client, &50);
+    assert_eq!(
+        setup
+            .pool
+            .try_register_policy(&escrow, &client, &token, &1_000),
+        Err(Ok(Error::InsufficientReserve))
+    );
+
+    setup.pool.top_up(&treasury, &150);
+    let escrow = insure(&env, &setup, 2, &client, 1_000);
+    assert_eq!(setup.token.balance(&client), 50);
+    assert_eq!(setup.pool.get_policy(&escrow).unwrap().premium, 50);
+    assert_eq!(setup.pool.reserve_ratio_bps(), 2_000);
+    assert_eq!(setup.pool.get_pool_state().exposure, 1_000);
+    assert_eq!(
+        setup.pool.try_withdraw_surplus(&setup.admin, &treasury, &1),
+        Err(Ok(Error::InsufficientReserve))
+    );
+
+    // Cover is released once the escrow closes without a claim
+    assert_eq!(
+        setup.pool.try_close_policy(&escrow),
+        Err(Ok(Error::PolicyActive))
+    );
+    MockEscrowClient::new(&env, &escrow).set_closed(&true);
+    setup.pool.close_policy(&escrow);
+    assert_eq!(setup.pool.get_pool_state().exposure, 0);
+
+    setup.pool.withdraw_surplus(&setup.admin, &treasury, &200);
+    assert_eq!(setup.token.balance(&treasury), 1_050);
+    assert_eq!(setup.pool.get_pool_state().balance, 0);
+}
+
+#[test]
+fn test_cover_requires_pool_token() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let client = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    StellarAssetClient::new(&env, &setup.token.address).mint(&treasury, &1_000);
+    setup.pool.top_up(&treasury, &1_000);
+
+    let escrow = env.register(MockEscrow, ());
+    setup.factory.add_escrow(&escrow, &1);
+    let other_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    assert_eq!(
+        setup
+            .pool
+            .try_register_policy(&escrow, &client, &other_token, &1_000),
+        Err(Ok(Error::TokenMismatch))
+    );
+
+    // Cover too small to carry a premium is refused rather than given away
+    assert_eq!(
+        setup
+            .pool
+            .try_register_policy(&escrow, &client, &setup.token.address, &19),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(setup.pool.get_pool_state().exposure, 0);
+}
+
+#[test]
+fn test_claim_assessment_pays_client_and_reports_to_escrow() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let client = Address::generate(&env);
+    let assessor = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    StellarAssetClient::new(&env, &setup.token.address).mint(&treasury, &1_000);
+    setup.pool.top_up(&treasury, &1_000);
+
+    let escrow = insure(&env, &setup, 1, &client, 500);
+    assert_eq!(
+        setup
+            .pool
+            .try_file_claim(&escrow, &Address::generate(&env), &500),
+        Err(Ok(Error::Unauthorized))
+    );
+    let claim_id = setup.pool.file_claim(&escrow, &client, &500);
+    assert_eq!(
+        setup.pool.try_file_claim(&escrow, &client, &500),
+        Err(Ok(Error::ClaimExists))
+    );
+
+    assert_eq!(
+        setup
+            .pool
+            .try_assess_claim(&assessor, &claim_id, &true, &400),
+        Err(Ok(Error::Unauthorized))
+    );
+    setup.pool.set_assessor(&setup.admin, &assessor, &true);
+    assert_eq!(
+        setup
+            .pool
+            .try_assess_claim(&assessor, &claim_id, &true, &600),
+        Err(Ok(Error::InvalidPayout))
+    );
+    setup.pool.assess_claim(&assessor, &claim_id, &true, &400);
+
+    assert_eq!(setup.token.balance(&client), 400);
+    assert_eq!(
+        MockEscrowClient::new(&env, &escrow).get_outcome(),
+        Some((true, 400))
+    );
+    let claim = setup.pool.get_claim(&claim_id);
+    assert_eq!(claim.status, ClaimStatus::Approved);
+    assert_eq!(claim.assessed_by, Some(assessor.clone()));
+
+    let state = setup.pool.get_pool_state();
+    assert_eq!(state.balance, 625);
+    assert_eq!(state.payouts, 400);
+    assert_eq!(state.exposure, 0);
+    assert_eq!(
+        setup
+            .pool
+            .try_assess_claim(&assessor, &claim_id, &false, &0),
+        Err(Ok(Error::ClaimNotPending))
+    );
+}
+
+#[test]
+fn test_pause_blocks_new_cover() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let treasury = Address::generate(&env);
+    StellarAssetClient::new(&env, &setup.token.address).mint(&treasury, &1_000);
+
+    setup.pool.set_paused(&setup.admin, &true);
+    assert_eq!(
+        setup.pool.try_top_up(&treasury, &150),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    setup.pool.set_paused(&setup.admin, &false);
+    setup.pool.top_up(&treasury, &150);
+    assert_eq!(setup.token.balance(&setup.pool.address), 150);
+}
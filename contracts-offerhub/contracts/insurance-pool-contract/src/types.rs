@@ -0,0 +1,59 @@
+use soroban_sdk::{contracttype, Address};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolConfig {
+    pub token: Address,
+    /// Factory whose escrows may buy cover and file claims
+    pub escrow_factory: Address,
+    /// Minimum pool balance relative to outstanding coverage, in basis points
+    pub min_reserve_bps: u32,
+    /// Premium charged on each unit of coverage, in basis points
+    pub premium_rate_bps: u32,
+}
+
+/// Running totals; `exposure` is the coverage of policies that can still claim
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PoolState {
+    pub balance: i128,
+    pub exposure: i128,
+    pub premiums_collected: i128,
+    pub top_ups: i128,
+    pub payouts: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Policy {
+    pub escrow: Address,
+    pub client: Address,
+    pub coverage: i128,
+    pub premium: i128,
+    pub registered_at: u64,
+    pub claim_id: Option<u32>,
+    /// Set once the escrow closed without a claim and the cover was released
+    pub closed: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ClaimStatus {
+    Pending = 0,
+    Approved = 1,
+    Rejected = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Claim {
+    pub id: u32,
+    pub escrow: Address,
+    pub client: Address,
+    pub amount: i128,
+    pub status: ClaimStatus,
+    pub filed_at: u64,
+    pub assessed_by: Option<Address>,
+    pub payout: i128,
+}
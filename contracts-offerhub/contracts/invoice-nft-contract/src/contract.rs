@@ -0,0 +1,122 @@
+use crate::error::Error;
+use crate::event::{admin_initialized, invoice_minted};
+use crate::storage::{
+    get_admin, get_escrow_factory, get_escrow_invoice, get_invoice, get_party_count,
+    get_party_entry, next_invoice_id, save_invoice, set_admin, set_escrow_factory,
+};
+use crate::types::Invoice;
+use soroban_sdk::{Address, BytesN, Env, IntoVal, Symbol, Vec};
+
+const FACTORY_GET_ESCROW_ID: &str = "get_escrow_id_by_address";
+const MAX_PAGE_SIZE: u32 = 50;
+
+pub struct InvoiceNFTContract;
+
+impl InvoiceNFTContract {
+    /// Initialize the contract with an admin and the factory whose escrows may mint
+    pub fn initialize(env: Env, admin: Address, escrow_factory: Address) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        set_escrow_factory(&env, &escrow_factory);
+        admin_initialized(&env, admin, escrow_factory);
+        Ok(())
+    }
+
+    /// Called by a completed escrow to mint its invoice. Only escrows deployed by the
+    /// configured factory can mint, and only once each.
+    pub fn mint_invoice(
+        env: Env,
+        escrow: Address,
+        client: Address,
+        freelancer: Address,
+        asset: Option<Address>,
+        amount: i128,
+        terms_hash: Option<BytesN<32>>,
+    ) -> Result<u64, Error> {
+        Self::require_not_paused(&env)?;
+        escrow.require_auth();
+        let factory = get_escrow_factory(&env).ok_or(Error::NotInitialized)?;
+
+        let escrow_id: Option<u32> = env.invoke_contract(
+            &factory,
+            &Symbol::new(&env, FACTORY_GET_ESCROW_ID),
+            (escrow.clone(),).into_val(&env),
+        );
+        if escrow_id.is_none() {
+            return Err(Error::UnknownEscrow);
+        }
+        if get_escrow_invoice(&env, &escrow).is_some() {
+            return Err(Error::AlreadyIssued);
+        }
+
+        let invoice = Invoice {
+            id: next_invoice_id(&env),
+            escrow: escrow.clone(),
+            client: client.clone(),
+            freelancer: freelancer.clone(),
+            asset,
+            amount,
+            terms_hash,
+            ledger: env.ledger().sequence(),
+            issued_at: env.ledger().timestamp(),
+        };
+        save_invoice(&env, &invoice);
+
+        invoice_minted(&env, invoice.id, escrow, client, freelancer, amount);
+        Ok(invoice.id)
+    }
+
+    /// Invoices are soulbound; this always fails
+    pub fn transfer(_env: Env, from: Address, _to: Address, _id: u64) -> Result<(), Error> {
+        from.require_auth();
+        Err(Error::NonTransferable)
+    }
+
+    pub fn get_invoice(env: Env, id: u64) -> Result<Invoice, Error> {
+        get_invoice(&env, id).ok_or(Error::InvoiceNotFound)
+    }
+
+    pub fn get_invoice_by_escrow(env: Env, escrow: Address) -> Option<Invoice> {
+        get_escrow_invoice(&env, &escrow).and_then(|id| get_invoice(&env, id))
+    }
+
+    pub fn get_invoice_count(env: Env, party: Address) -> u32 {
+        get_party_count(&env, &party)
+    }
+
+    /// Page through the invoices a party holds, oldest first
+    pub fn get_invoices_by_party(
+        env: Env,
+        party: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Invoice> {
+        let count = get_party_count(&env, &party);
+        let end = offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+        let mut invoices = Vec::new(&env);
+        for position in offset..end {
+            if let Some(invoice) =
+                get_party_entry(&env, &party, position).and_then(|id| get_invoice(&env, id))
+            {
+                invoices.push_back(invoice);
+            }
+        }
+        invoices
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// Escrow was not deployed by the configured factory
+    UnknownEscrow = 4,
+    /// An invoice has already been issued for this escrow
+    AlreadyIssued = 5,
+    /// Invoice not found
+    InvoiceNotFound = 6,
+    /// Invoices are permanent records and can't change hands
+    NonTransferable = 7,
+    /// Contract is already paused
+    AlreadyPaused = 8,
+    /// Contract is not paused
+    NotPaused = 9,
+    /// Contract is paused
+    ContractPaused = 10,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
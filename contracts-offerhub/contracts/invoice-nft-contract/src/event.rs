@@ -0,0 +1,18 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub fn admin_initialized(e: &Env, admin: Address, escrow_factory: Address) {
+    let topics = (Symbol::new(e, "admin_initialized"),);
+    e.events().publish(topics, (admin, escrow_factory));
+}
+
+pub fn invoice_minted(
+    e: &Env,
+    id: u64,
+    escrow: Address,
+    client: Address,
+    freelancer: Address,
+    amount: i128,
+) {
+    let topics = (Symbol::new(e, "invoice_minted"), client, freelancer);
+    e.events().publish(topics, (id, escrow, amount));
+}
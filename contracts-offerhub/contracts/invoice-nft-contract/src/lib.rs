@@ -0,0 +1,70 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::InvoiceNFTContract;
+pub use crate::error::Error;
+pub use crate::types::Invoice;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| InvoiceNFTContract::pause_admin(env));
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the contract with an admin and the escrow factory
+    pub fn initialize(env: Env, admin: Address, escrow_factory: Address) -> Result<(), Error> {
+        InvoiceNFTContract::initialize(env, admin, escrow_factory)
+    }
+
+    /// Mint the invoice for a completed escrow (called by the escrow itself)
+    pub fn mint_invoice(
+        env: Env,
+        escrow: Address,
+        client: Address,
+        freelancer: Address,
+        asset: Option<Address>,
+        amount: i128,
+        terms_hash: Option<BytesN<32>>,
+    ) -> Result<u64, Error> {
+        InvoiceNFTContract::mint_invoice(env, escrow, client, freelancer, asset, amount, terms_hash)
+    }
+
+    /// Always fails: invoices are non-transferable
+    pub fn transfer(env: Env, from: Address, to: Address, id: u64) -> Result<(), Error> {
+        InvoiceNFTContract::transfer(env, from, to, id)
+    }
+
+    /// Get an invoice by id
+    pub fn get_invoice(env: Env, id: u64) -> Result<Invoice, Error> {
+        InvoiceNFTContract::get_invoice(env, id)
+    }
+
+    /// Get the invoice issued for an escrow
+    pub fn get_invoice_by_escrow(env: Env, escrow: Address) -> Option<Invoice> {
+        InvoiceNFTContract::get_invoice_by_escrow(env, escrow)
+    }
+
+    /// Get how many invoices a party holds
+    pub fn get_invoice_count(env: Env, party: Address) -> u32 {
+        InvoiceNFTContract::get_invoice_count(env, party)
+    }
+
+    /// Page through the invoices a party holds
+    pub fn get_invoices_by_party(
+        env: Env,
+        party: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Invoice> {
+        InvoiceNFTContract::get_invoices_by_party(env, party, offset, limit)
+    }
+}
@@ -0,0 +1,85 @@
+use crate::types::Invoice;
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    EscrowFactory,
+    NextInvoiceId,
+    Invoice(u64),
+    /// escrow -> invoice id
+    EscrowInvoice(Address),
+    /// party -> number of invoices it holds
+    PartyCount(Address),
+    /// (party, position) -> invoice id
+    PartyEntry(Address, u32),
+}
+
+/// Get the admin address
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_escrow_factory(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::EscrowFactory)
+}
+
+pub fn set_escrow_factory(env: &Env, factory: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::EscrowFactory, factory);
+}
+
+/// Allocate the next invoice id, starting at 1
+pub fn next_invoice_id(env: &Env) -> u64 {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::NextInvoiceId)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&DataKey::NextInvoiceId, &id);
+    id
+}
+
+pub fn get_invoice(env: &Env, id: u64) -> Option<Invoice> {
+    env.storage().persistent().get(&DataKey::Invoice(id))
+}
+
+pub fn get_escrow_invoice(env: &Env, escrow: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EscrowInvoice(escrow.clone()))
+}
+
+/// Store a new invoice and add it to both parties' listings
+pub fn save_invoice(env: &Env, invoice: &Invoice) {
+    let storage = env.storage().persistent();
+    storage.set(&DataKey::Invoice(invoice.id), invoice);
+    storage.set(&DataKey::EscrowInvoice(invoice.escrow.clone()), &invoice.id);
+
+    for party in [&invoice.client, &invoice.freelancer] {
+        let count = get_party_count(env, party);
+        storage.set(&DataKey::PartyEntry(party.clone(), count), &invoice.id);
+        storage.set(&DataKey::PartyCount(party.clone()), &(count + 1));
+    }
+}
+
+pub fn get_party_count(env: &Env, party: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PartyCount(party.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_party_entry(env: &Env, party: &Address, position: u32) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PartyEntry(party.clone(), position))
+}
@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+
+#[contract]
+pub struct MockEscrowFactory;
+
+#[contractimpl]
+impl MockEscrowFactory {
+    pub fn add_escrow(env: Env, escrow: Address, id: u32) {
+        env.storage().instance().set(&escrow, &id);
+    }
+
+    pub fn get_escrow_id_by_address(env: Env, escrow_address: Address) -> Option<u32> {
+        env.storage().instance().get(&escrow_address)
+    }
+}
+
+fn setup(env: &Env) -> (ContractClient<'_>, MockEscrowFactoryClient<'_>) {
+    env.mock_all_auths();
+    let factory_id = env.register(MockEscrowFactory, ());
+    let client = ContractClient::new(env, &env.register(Contract, ()));
+    client.initialize(&Address::generate(env), &factory_id);
+    (client, MockEscrowFactoryClient::new(env, &factory_id))
+}
+
+#[test]
+fn test_invoice_minted_once_per_escrow_for_both_parties() {
+    let env = Env::default();
+    env.ledger().with_mut(|l| l.sequence_number = 42);
+    let (invoices, factory) = setup(&env);
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let terms_hash = BytesN::from_array(&env, &[3; 32]);
+
+    let escrow = Address::generate(&env);
+    assert_eq!(
+        invoices.try_mint_invoice(&escrow, &client, &freelancer, &None, &500, &None),
+        Err(Ok(Error::UnknownEscrow))
+    );
+
+    factory.add_escrow(&escrow, &1);
+    let id = invoices.mint_invoice(
+        &escrow,
+        &client,
+        &freelancer,
+        &Some(asset.clone()),
+        &500,
+        &Some(terms_hash.clone()),
+    );
+    assert_eq!(
+        invoices.try_mint_invoice(&escrow, &client, &freelancer, &None, &500, &None),
+        Err(Ok(Error::AlreadyIssued))
+    );
+
+    let invoice = invoices.get_invoice(&id);
+    assert_eq!(invoice.amount, 500);
+    assert_eq!(invoice.asset, Some(asset));
+    assert_eq!(invoice.terms_hash, Some(terms_hash));
+    assert_eq!(invoice.ledger, 42);
+    assert_eq!(invoices.get_invoice_by_escrow(&escrow), Some(invoice));
+
+    // Both sides hold the record and neither can hand it on
+    assert_eq!(invoices.get_invoice_count(&client), 1);
+    assert_eq!(
+        invoices.get_invoices_by_party(&freelancer, &0, &10).len(),
+        1
+    );
+    assert_eq!(
+        invoices.try_transfer(&client, &Address::generate(&env), &id),
+        Err(Ok(Error::NonTransferable))
+    );
+}
+
+#[test]
+fn test_invoices_by_party_are_paginated() {
+    let env = Env::default();
+    let (invoices, factory) = setup(&env);
+    let freelancer = Address::generate(&env);
+
+    for i in 0..3u32 {
+        let escrow = Address::generate(&env);
+        factory.add_escrow(&escrow, &i);
+        invoices.mint_invoice(
+            &escrow,
+            &Address::generate(&env),
+            &freelancer,
+            &None,
+            &(100 * (i as i128 + 1)),
+            &None,
+        );
+    }
+
+    assert_eq!(invoices.get_invoice_count(&freelancer), 3);
+    let page = invoices.get_invoices_by_party(&freelancer, &1, &5);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().amount, 200);
+    assert_eq!(page.get(1).unwrap().amount, 300);
+    assert_eq!(invoices.get_invoices_by_party(&freelancer, &3, &5).len(), 0);
+}
+
+#[test]
+fn test_pause_blocks_minting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let factory = MockEscrowFactoryClient::new(&env, &env.register(MockEscrowFactory, ()));
+    let invoices = ContractClient::new(&env, &env.register(Contract, ()));
+    let admin = Address::generate(&env);
+    invoices.initialize(&admin, &factory.address);
+    let escrow = Address::generate(&env);
+    factory.add_escrow(&escrow, &1);
+
+    invoices.pause(&admin);
+    assert_eq!(
+        invoices.try_mint_invoice(
+            &escrow,
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &None,
+            &500,
+            &None
+        ),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    invoices.unpause(&admin);
+    invoices.mint_invoice(
+        &escrow,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &None,
+        &500,
+        &None,
+    );
+    assert!(invoices.get_invoice_by_escrow(&escrow).is_some());
+}
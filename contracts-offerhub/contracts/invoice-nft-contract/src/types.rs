@@ -0,0 +1,19 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+/// Non-transferable payment record issued once per completed escrow and held by both
+/// parties.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Invoice {
+    pub id: u64,
+    pub escrow: Address,
+    pub client: Address,
+    pub freelancer: Address,
+    /// Token the escrow paid out in, if any
+    pub asset: Option<Address>,
+    pub amount: i128,
+    /// Hash of the terms both parties agreed to, when the escrow recorded them
+    pub terms_hash: Option<BytesN<32>>,
+    pub ledger: u32,
+    pub issued_at: u64,
+}
@@ -0,0 +1,216 @@
+use crate::error::Error;
+use crate::event::{approval_initialized, panel_configured, round_closed, round_opened, vote_cast};
+use crate::storage::{get_admin, set_admin, get_panel, get_round, set_panel, set_round};
+use crate::types::{Panel, Round, RoundStatus};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
+
+const ESCROW_GET_PARTIES: &str = "get_parties";
+const ESCROW_APPROVE_MILESTONE: &str = "approve_milestone";
+
+pub struct MilestoneApprovalContract;
+
+impl MilestoneApprovalContract {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        approval_initialized(&env, admin);
+        Ok(())
+    }
+
+    /// Set the stakeholder panel for an escrow. Only the escrow's client can configure
+    /// it; the escrow must also name this contract as its milestone approver.
+    pub fn configure_panel(
+        env: Env,
+        client: Address,
+        escrow: Address,
+        stakeholders: Vec<Address>,
+        threshold: u32,
+        voting_period: u64,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        client.require_auth();
+        let (escrow_client, _) = Self::parties(&env, &escrow);
+        if escrow_client != client {
+            return Err(Error::Unauthorized);
+        }
+
+        if stakeholders.is_empty()
+            || threshold == 0
+            || threshold > stakeholders.len()
+            || voting_period == 0
+        {
+            return Err(Error::InvalidPanel);
+        }
+        for (i, stakeholder) in stakeholders.iter().enumerate() {
+            if stakeholders
+                .iter()
+                .skip(i + 1)
+                .any(|other| other == stakeholder)
+            {
+                return Err(Error::InvalidPanel);
+            }
+        }
+
+        set_panel(
+            &env,
+            &Panel {
+                escrow: escrow.clone(),
+                stakeholders: stakeholders.clone(),
+                threshold,
+                voting_period,
+            },
+        );
+        panel_configured(&env, escrow, stakeholders, threshold);
+        Ok(())
+    }
+
+    pub fn get_panel(env: Env, escrow: Address) -> Option<Panel> {
+        get_panel(&env, &escrow)
+    }
+
+    /// Open a vote on a milestone. The freelancer asks for approval, or a stakeholder
+    /// opens it; a rejected milestone can be put to a new vote.
+    pub fn open_round(
+        env: Env,
+        opener: Address,
+        escrow: Address,
+        milestone_id: u32,
+    ) -> Result<Round, Error> {
+        Self::require_not_paused(&env)?;
+        opener.require_auth();
+        let panel = get_panel(&env, &escrow).ok_or(Error::PanelNotFound)?;
+        let (_, freelancer) = Self::parties(&env, &escrow);
+        if opener != freelancer && !panel.stakeholders.contains(&opener) {
+            return Err(Error::Unauthorized);
+        }
+
+        if let Some(previous) = get_round(&env, &escrow, milestone_id) {
+            if Self::effective_status(&env, &previous) != RoundStatus::Rejected {
+                return Err(Error::RoundExists);
+            }
+        }
+
+        let now = env.ledger().timestamp();
+        let round = Round {
+            escrow: escrow.clone(),
+            milestone_id,
+            stakeholders: panel.stakeholders,
+            threshold: panel.threshold,
+            opened_at: now,
+            deadline: now.saturating_add(panel.voting_period),
+            approvals: Vec::new(&env),
+            rejections: Vec::new(&env),
+            status: RoundStatus::Open,
+        };
+        set_round(&env, &round);
+
+        round_opened(&env, escrow, milestone_id, opener, round.deadline);
+        Ok(round)
+    }
+
+    /// Cast a stakeholder vote. Reaching the threshold approves the milestone on the
+    /// escrow right away; once the threshold can no longer be reached the round is
+    /// rejected.
+    pub fn vote(
+        env: Env,
+        stakeholder: Address,
+        escrow: Address,
+        milestone_id: u32,
+        approve: bool,
+    ) -> Result<RoundStatus, Error> {
+        Self::require_not_paused(&env)?;
+        stakeholder.require_auth();
+        let mut round = get_round(&env, &escrow, milestone_id).ok_or(Error::RoundNotFound)?;
+        if Self::effective_status(&env, &round) != RoundStatus::Open {
+            return Err(Error::VotingClosed);
+        }
+        if !round.stakeholders.contains(&stakeholder) {
+            return Err(Error::NotStakeholder);
+        }
+        if round.approvals.contains(&stakeholder) || round.rejections.contains(&stakeholder) {
+            return Err(Error::AlreadyVoted);
+        }
+
+        if approve {
+            round.approvals.push_back(stakeholder.clone());
+        } else {
+            round.rejections.push_back(stakeholder.clone());
+        }
+        vote_cast(&env, escrow.clone(), milestone_id, stakeholder, approve);
+
+        if round.approvals.len() >= round.threshold {
+            round.status = RoundStatus::Approved;
+        } else if round.stakeholders.len() - round.rejections.len() < round.threshold {
+            round.status = RoundStatus::Rejected;
+        }
+        set_round(&env, &round);
+
+        if round.status == RoundStatus::Approved {
+            env.invoke_contract::<()>(
+                &escrow,
+                &Symbol::new(&env, ESCROW_APPROVE_MILESTONE),
+                (env.current_contract_address(), milestone_id).into_val(&env),
+            );
+        }
+        if round.status != RoundStatus::Open {
+            round_closed(&env, escrow, milestone_id, round.status);
+        }
+        Ok(round.status)
+    }
+
+    /// Record the automatic rejection of a round whose deadline passed. Anyone can call.
+    pub fn finalize(env: Env, escrow: Address, milestone_id: u32) -> Result<RoundStatus, Error> {
+        Self::require_not_paused(&env)?;
+        let mut round = get_round(&env, &escrow, milestone_id).ok_or(Error::RoundNotFound)?;
+        if round.status != RoundStatus::Open {
+            return Err(Error::VotingClosed);
+        }
+        if env.ledger().timestamp() <= round.deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        round.status = RoundStatus::Rejected;
+        set_round(&env, &round);
+
+        round_closed(&env, escrow, milestone_id, round.status);
+        Ok(round.status)
+    }
+
+    /// Latest round for a milestone, reported as rejected once its deadline has passed
+    pub fn get_round(env: Env, escrow: Address, milestone_id: u32) -> Result<Round, Error> {
+        let mut round = get_round(&env, &escrow, milestone_id).ok_or(Error::RoundNotFound)?;
+        round.status = Self::effective_status(&env, &round);
+        Ok(round)
+    }
+
+    fn effective_status(env: &Env, round: &Round) -> RoundStatus {
+        if round.status == RoundStatus::Open && env.ledger().timestamp() > round.deadline {
+            RoundStatus::Rejected
+        } else {
+            round.status
+        }
+    }
+
+    fn parties(env: &Env, escrow: &Address) -> (Address, Address) {
+        env.invoke_contract(
+            escrow,
+            &Symbol::new(env, ESCROW_GET_PARTIES),
+            ().into_val(env),
+        )
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Caller is not authorized to perform this action
+    Unauthorized = 1,
+    /// Stakeholders are empty or duplicated, threshold is out of range, or the voting
+    /// period is zero
+    InvalidPanel = 2,
+    /// Escrow has no stakeholder panel
+    PanelNotFound = 3,
+    /// Address is not on the round's panel
+    NotStakeholder = 4,
+    /// A vote is already open for this milestone, or it was already approved
+    RoundExists = 5,
+    /// No vote has been opened for this milestone
+    RoundNotFound = 6,
+    /// Round is no longer open
+    VotingClosed = 7,
+    /// Stakeholder has already voted in this round
+    AlreadyVoted = 8,
+    /// Voting deadline has not passed yet
+    DeadlineNotReached = 9,
+    /// Contract is already initialized
+    AlreadyInitialized = 10,
+    /// Contract is not initialized
+    NotInitialized = 11,
+    /// Contract is already paused
+    AlreadyPaused = 12,
+    /// Contract is not paused
+    NotPaused = 13,
+    /// Contract is paused
+    ContractPaused = 14,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
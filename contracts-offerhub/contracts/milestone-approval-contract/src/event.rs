@@ -0,0 +1,27 @@
+use crate::types::RoundStatus;
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+pub fn panel_configured(e: &Env, escrow: Address, stakeholders: Vec<Address>, threshold: u32) {
+    let topics = (Symbol::new(e, "panel_configured"), escrow);
+    e.events().publish(topics, (stakeholders, threshold));
+}
+
+pub fn round_opened(e: &Env, escrow: Address, milestone_id: u32, opener: Address, deadline: u64) {
+    let topics = (Symbol::new(e, "approval_round_opened"), escrow, opener);
+    e.events().publish(topics, (milestone_id, deadline));
+}
+
+pub fn vote_cast(e: &Env, escrow: Address, milestone_id: u32, stakeholder: Address, approve: bool) {
+    let topics = (Symbol::new(e, "milestone_vote_cast"), escrow, stakeholder);
+    e.events().publish(topics, (milestone_id, approve));
+}
+
+pub fn round_closed(e: &Env, escrow: Address, milestone_id: u32, status: RoundStatus) {
+    let topics = (Symbol::new(e, "approval_round_closed"), escrow);
+    e.events().publish(topics, (milestone_id, status));
+}
+
+pub fn approval_initialized(e: &Env, admin: Address) {
+    let topics = (Symbol::new(e, "approval_initialized"),);
+    e.events().publish(topics, admin);
+}
@@ -0,0 +1,84 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::MilestoneApprovalContract;
+pub use crate::error::Error;
+pub use crate::types::{Panel, Round, RoundStatus};
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| {
+    MilestoneApprovalContract::pause_admin(env)
+});
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the contract with the admin that manages pausing
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        MilestoneApprovalContract::initialize(env, admin)
+    }
+
+    /// Set the m-of-n stakeholder panel for an escrow (escrow client only)
+    pub fn configure_panel(
+        env: Env,
+        client: Address,
+        escrow: Address,
+        stakeholders: Vec<Address>,
+        threshold: u32,
+        voting_period: u64,
+    ) -> Result<(), Error> {
+        MilestoneApprovalContract::configure_panel(
+            env,
+            client,
+            escrow,
+            stakeholders,
+            threshold,
+            voting_period,
+        )
+    }
+
+    /// Get an escrow's stakeholder panel
+    pub fn get_panel(env: Env, escrow: Address) -> Option<Panel> {
+        MilestoneApprovalContract::get_panel(env, escrow)
+    }
+
+    /// Open a vote on a milestone (freelancer or stakeholder)
+    pub fn open_round(
+        env: Env,
+        opener: Address,
+        escrow: Address,
+        milestone_id: u32,
+    ) -> Result<Round, Error> {
+        MilestoneApprovalContract::open_round(env, opener, escrow, milestone_id)
+    }
+
+    /// Vote on a milestone and return the round status after the vote
+    pub fn vote(
+        env: Env,
+        stakeholder: Address,
+        escrow: Address,
+        milestone_id: u32,
+        approve: bool,
+    ) -> Result<RoundStatus, Error> {
+        MilestoneApprovalContract::vote(env, stakeholder, escrow, milestone_id, approve)
+    }
+
+    /// Reject a round whose deadline has passed
+    pub fn finalize(env: Env, escrow: Address, milestone_id: u32) -> Result<RoundStatus, Error> {
+        MilestoneApprovalContract::finalize(env, escrow, milestone_id)
+    }
+
+    /// Get the latest round for a milestone
+    pub fn get_round(env: Env, escrow: Address, milestone_id: u32) -> Result<Round, Error> {
+        MilestoneApprovalContract::get_round(env, escrow, milestone_id)
+    }
+}
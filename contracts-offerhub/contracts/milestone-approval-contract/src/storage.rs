@@ -0,0 +1,44 @@
+use crate::types::{Panel, Round};
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Panel(Address),
+    /// (escrow, milestone id) -> latest round
+    Round(Address, u32),
+}
+
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_panel(env: &Env, escrow: &Address) -> Option<Panel> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Panel(escrow.clone()))
+}
+
+pub fn set_panel(env: &Env, panel: &Panel) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Panel(panel.escrow.clone()), panel);
+}
+
+pub fn get_round(env: &Env, escrow: &Address, milestone_id: u32) -> Option<Round> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Round(escrow.clone(), milestone_id))
+}
+
+pub fn set_round(env: &Env, round: &Round) {
+    env.storage().persistent().set(
+        &DataKey::Round(round.escrow.clone(), round.milestone_id),
+        round,
+    );
+}
@@ -0,0 +1,186 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, symbol_short, vec, Address, Env};
+
+const DAY: u64 = 24 * 60 * 60;
+
+#[contract]
+pub struct MockEscrow;
+
+#[contractimpl]
+impl MockEscrow {
+    pub fn set_parties(env: Env, client: Address, freelancer: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("parties"), &(client, freelancer));
+    }
+
+    pub fn get_parties(env: Env) -> (Address, Address) {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("parties"))
+            .unwrap()
+    }
+
+    pub fn approve_milestone(env: Env, client: Address, milestone_id: u32) {
+        client.require_auth();
+        env.storage().instance().set(&milestone_id, &client);
+    }
+
+    pub fn approved_by(env: Env, milestone_id: u32) -> Option<Address> {
+        env.storage().instance().get(&milestone_id)
+    }
+}
+
+struct Setup<'a> {
+    approvals: ContractClient<'a>,
+    escrow: MockEscrowClient<'a>,
+    freelancer: Address,
+    stakeholders: [Address; 3],
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let approvals = ContractClient::new(env, &env.register(Contract, ()));
+    let escrow = MockEscrowClient::new(env, &env.register(MockEscrow, ()));
+    let client = Address::generate(env);
+    let freelancer = Address::generate(env);
+    escrow.set_parties(&client, &freelancer);
+
+    let stakeholders = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    assert_eq!(
+        approvals.try_configure_panel(
+            &freelancer,
+            &escrow.address,
+            &vec![env, stakeholders[0].clone()],
+            &1,
+            &DAY
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    approvals.configure_panel(
+        &client,
+        &escrow.address,
+        &vec![
+            env,
+            stakeholders[0].clone(),
+            stakeholders[1].clone(),
+            stakeholders[2].clone(),
+        ],
+        &2,
+        &DAY,
+    );
+    Setup {
+        approvals,
+        escrow,
+        freelancer,
+        stakeholders,
+    }
+}
+
+#[test]
+fn test_two_of_three_approval_releases_milestone() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let escrow = &setup.escrow.address;
+    let [alice, bob, carol] = &setup.stakeholders;
+
+    setup.approvals.open_round(&setup.freelancer, escrow, &1);
+    assert_eq!(
+        setup.approvals.try_open_round(alice, escrow, &1),
+        Err(Ok(Error::RoundExists))
+    );
+
+    assert_eq!(
+        setup.approvals.vote(alice, escrow, &1, &true),
+        RoundStatus::Open
+    );
+    assert_eq!(
+        setup.approvals.try_vote(alice, escrow, &1, &true),
+        Err(Ok(Error::AlreadyVoted))
+    );
+    assert_eq!(setup.escrow.approved_by(&1), None);
+
+    assert_eq!(
+        setup.approvals.vote(bob, escrow, &1, &true),
+        RoundStatus::Approved
+    );
+    assert_eq!(
+        setup.escrow.approved_by(&1),
+        Some(setup.approvals.address.clone())
+    );
+    assert_eq!(
+        setup.approvals.try_vote(carol, escrow, &1, &false),
+        Err(Ok(Error::VotingClosed))
+    );
+}
+
+#[test]
+fn test_rejection_and_timeout() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let escrow = &setup.escrow.address;
+    let [alice, bob, carol] = &setup.stakeholders;
+
+    // Two rejections leave the threshold out of reach
+    setup.approvals.open_round(alice, escrow, &1);
+    setup.approvals.vote(alice, escrow, &1, &false);
+    assert_eq!(
+        setup.approvals.vote(bob, escrow, &1, &false),
+        RoundStatus::Rejected
+    );
+
+    // A new round that nobody finishes is rejected at the deadline
+    setup.approvals.open_round(&setup.freelancer, escrow, &1);
+    setup.approvals.vote(carol, escrow, &1, &true);
+    assert_eq!(
+        setup.approvals.try_finalize(escrow, &1),
+        Err(Ok(Error::DeadlineNotReached))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000 + DAY + 1);
+    assert_eq!(
+        setup.approvals.get_round(escrow, &1).status,
+        RoundStatus::Rejected
+    );
+    assert_eq!(
+        setup.approvals.try_vote(alice, escrow, &1, &true),
+        Err(Ok(Error::VotingClosed))
+    );
+    assert_eq!(setup.approvals.finalize(escrow, &1), RoundStatus::Rejected);
+    assert_eq!(setup.escrow.approved_by(&1), None);
+}
+
+#[test]
+fn test_pause_blocks_voting() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let escrow = &setup.escrow.address;
+    let [alice, _, _] = &setup.stakeholders;
+    let admin = Address::generate(&env);
+    setup.approvals.open_round(alice, escrow, &1);
+
+    assert_eq!(
+        setup.approvals.try_pause(&admin),
+        Err(Ok(Error::NotInitialized))
+    );
+    setup.approvals.initialize(&admin);
+    setup.approvals.pause(&admin);
+    assert_eq!(
+        setup.approvals.try_vote(alice, escrow, &1, &true),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    setup.approvals.unpause(&admin);
+    assert_eq!(
+        setup.approvals.vote(alice, escrow, &1, &true),
+        RoundStatus::Open
+    );
+}
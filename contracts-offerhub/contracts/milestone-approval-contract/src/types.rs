@@ -0,0 +1,38 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+/// Client-side stakeholders who approve an escrow's milestones
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Panel {
+    pub escrow: Address,
+    pub stakeholders: Vec<Address>,
+    /// Approvals needed to release a milestone
+    pub threshold: u32,
+    /// Seconds a round stays open
+    pub voting_period: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum RoundStatus {
+    Open = 0,
+    Approved = 1,
+    Rejected = 2,
+}
+
+/// One vote on one milestone. The panel is copied in when the round opens, so later
+/// panel changes do not affect it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Round {
+    pub escrow: Address,
+    pub milestone_id: u32,
+    pub stakeholders: Vec<Address>,
+    pub threshold: u32,
+    pub opened_at: u64,
+    pub deadline: u64,
+    pub approvals: Vec<Address>,
+    pub rejections: Vec<Address>,
+    pub status: RoundStatus,
+}
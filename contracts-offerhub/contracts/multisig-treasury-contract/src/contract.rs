@@ -0,0 +1,252 @@
+use crate::error::Error;
+use crate::event::{confirmed, executed, initialized, owners_changed, revoked, submitted};
+use crate::storage::{
+    get_config, get_daily_spend, get_owners, get_transaction, next_transaction_id, set_config,
+    set_daily_spend, set_owners, set_transaction,
+};
+use crate::types::{DailySpend, Transaction, TreasuryConfig, TxAction};
+use soroban_sdk::{token, vec, Address, Env, IntoVal, Val, Vec};
+
+pub struct MultisigTreasuryContract;
+
+impl MultisigTreasuryContract {
+    /// Install the owners, thresholds and daily spend limit. Runs as the constructor, so
+    /// only the deployer sets them, in the deploying transaction.
+    pub fn initialize(env: Env, owners: Vec<Address>, config: TreasuryConfig) -> Result<(), Error> {
+        if get_owners(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        Self::validate(&owners, &config)?;
+
+        set_owners(&env, &owners);
+        set_config(&env, &config);
+        initialized(&env, owners, config);
+        Ok(())
+    }
+
+    /// Submit a transaction; the submitter's confirmation is recorded with it
+    pub fn submit(env: Env, owner: Address, action: TxAction) -> Result<u32, Error> {
+        Self::require_owner(&env, &owner)?;
+        if let TxAction::Transfer(_, _, amount) = action {
+            if amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+        }
+
+        let transaction = Transaction {
+            id: next_transaction_id(&env),
+            submitter: owner.clone(),
+            action: action.clone(),
+            confirmations: vec![&env, owner.clone()],
+            executed: false,
+            created_at: env.ledger().timestamp(),
+        };
+        set_transaction(&env, &transaction);
+
+        submitted(&env, owner, transaction.id, action);
+        Ok(transaction.id)
+    }
+
+    pub fn confirm(env: Env, owner: Address, id: u32) -> Result<(), Error> {
+        Self::require_owner(&env, &owner)?;
+        let mut transaction = Self::pending_transaction(&env, id)?;
+
+        if transaction.confirmations.contains(&owner) {
+            return Err(Error::AlreadyConfirmed);
+        }
+        transaction.confirmations.push_back(owner.clone());
+        set_transaction(&env, &transaction);
+
+        confirmed(&env, owner, id);
+        Ok(())
+    }
+
+    pub fn revoke(env: Env, owner: Address, id: u32) -> Result<(), Error> {
+        Self::require_owner(&env, &owner)?;
+        let mut transaction = Self::pending_transaction(&env, id)?;
+
+        let index = transaction
+            .confirmations
+            .first_index_of(&owner)
+            .ok_or(Error::NotConfirmed)?;
+        transaction.confirmations.remove(index);
+        set_transaction(&env, &transaction);
+
+        revoked(&env, owner, id);
+        Ok(())
+    }
+
+    /// Execute a transaction once enough current owners have confirmed it. Transfers that
+    /// keep today's spend within the daily limit only need the low threshold. While the
+    /// treasury is paused only owner and threshold changes execute.
+    pub fn execute(env: Env, owner: Address, id: u32) -> Result<Val, Error> {
+        Self::require_owner(&env, &owner)?;
+        let mut transaction = Self::pending_transaction(&env, id)?;
+
+        let confirmations = Self::confirmation_count(&env, &transaction);
+        if confirmations < Self::required_confirmations(env.clone(), id)? {
+            return Err(Error::InsufficientConfirmations);
+        }
+
+        transaction.executed = true;
+        set_transaction(&env, &transaction);
+
+        let result = match transaction.action {
+            TxAction::Transfer(asset, to, amount) => {
+                Self::require_not_paused(&env)?;
+                let mut spend = get_daily_spend(&env);
+                spend.spent += amount;
+                set_daily_spend(&env, &spend);
+                token::Client::new(&env, &asset).transfer(
+                    &env.current_contract_address(),
+                    &to,
+                    &amount,
+                );
+                ().into_val(&env)
+            }
+            TxAction::Call(target, function, args) => {
+                Self::require_not_paused(&env)?;
+                env.invoke_contract::<Val>(&target, &function, args)
+            }
+            action => {
+                Self::apply_owner_change(&env, action)?;
+                ().into_val(&env)
+            }
+        };
+
+        executed(&env, owner, id, confirmations);
+        Ok(result)
+    }
+
+    /// Confirmations a pending transaction needs if executed now
+    pub fn required_confirmations(env: Env, id: u32) -> Result<u32, Error> {
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        let transaction = get_transaction(&env, id).ok_or(Error::TransactionNotFound)?;
+
+        let within_limit = match transaction.action {
+            TxAction::Transfer(_, _, amount) => {
+                get_daily_spend(&env).spent + amount <= config.daily_limit
+            }
+            _ => false,
+        };
+        Ok(if within_limit {
+            config.low_threshold
+        } else {
+            config.threshold
+        })
+    }
+
+    pub fn get_transaction(env: Env, id: u32) -> Option<Transaction> {
+        get_transaction(&env, id)
+    }
+
+    pub fn get_owners(env: Env) -> Vec<Address> {
+        get_owners(&env).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    pub fn get_config(env: Env) -> Option<TreasuryConfig> {
+        get_config(&env)
+    }
+
+    pub fn get_daily_spend(env: Env) -> DailySpend {
+        get_daily_spend(&env)
+    }
+
+    fn apply_owner_change(env: &Env, action: TxAction) -> Result<(), Error> {
+        let mut owners = get_owners(env).ok_or(Error::NotInitialized)?;
+        let mut config = get_config(env).ok_or(Error::NotInitialized)?;
+
+        match action {
+            TxAction::AddOwner(owner) => {
+                if owners.contains(&owner) {
+                    return Err(Error::OwnerExists);
+                }
+                owners.push_back(owner);
+            }
+            TxAction::RemoveOwner(owner) => {
+                let index = owners.first_index_of(&owner).ok_or(Error::OwnerNotFound)?;
+                owners.remove(index);
+            }
+            TxAction::ReplaceOwner(old, new) => {
+                if owners.contains(&new) {
+                    return Err(Error::OwnerExists);
+                }
+                let index = owners.first_index_of(&old).ok_or(Error::OwnerNotFound)?;
+                owners.set(index, new);
+            }
+            TxAction::ChangeThresholds(threshold, low_threshold) => {
+                config.threshold = threshold;
+                config.low_threshold = low_threshold;
+            }
+            TxAction::ChangeDailyLimit(daily_limit) => {
+                config.daily_limit = daily_limit;
+            }
+            TxAction::Transfer(..) | TxAction::Call(..) => {}
+        }
+
+        Self::validate(&owners, &config)?;
+        set_owners(env, &owners);
+        set_config(env, &config);
+        owners_changed(env, owners, config);
+        Ok(())
+    }
+
+    /// Confirmations from addresses that are still owners
+    fn confirmation_count(env: &Env, transaction: &Transaction) -> u32 {
+        let owners = get_owners(env).unwrap_or_else(|| Vec::new(env));
+        transaction
+            .confirmations
+            .iter()
+            .filter(|owner| owners.contains(owner))
+            .count() as u32
+    }
+
+    fn pending_transaction(env: &Env, id: u32) -> Result<Transaction, Error> {
+        let transaction = get_transaction(env, id).ok_or(Error::TransactionNotFound)?;
+        if transaction.executed {
+            return Err(Error::AlreadyExecuted);
+        }
+        Ok(transaction)
+    }
+
+    fn validate(owners: &Vec<Address>, config: &TreasuryConfig) -> Result<(), Error> {
+        if config.threshold == 0
+            || config.threshold > owners.len()
+            || config.low_threshold == 0
+            || config.low_threshold > config.threshold
+        {
+            return Err(Error::InvalidThreshold);
+        }
+        if config.daily_limit < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    fn require_owner(env: &Env, owner: &Address) -> Result<(), Error> {
+        owner.require_auth();
+        let owners = get_owners(env).ok_or(Error::NotInitialized)?;
+        if !owners.contains(owner) {
+            return Err(Error::NotOwner);
+        }
+        Ok(())
+    }
+
+    /// Each owner acts as the pause admin for its own calls. Pausing only holds back
+    /// outgoing calls, so it does not wait for the threshold. Anyone else is checked
+    /// against the treasury's own address, which leaves just the PAUSER role.
+    pub(crate) fn pause_admin(env: &Env, caller: &Address) -> Result<Address, Error> {
+        let owners = get_owners(env).ok_or(Error::NotInitialized)?;
+        if owners.contains(caller) {
+            return Ok(caller.clone());
+        }
+        Ok(env.current_contract_address())
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
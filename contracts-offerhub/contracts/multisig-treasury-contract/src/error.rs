@@ -0,0 +1,48 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not one of the owners
+    NotOwner = 3,
+    /// Thresholds must be between 1 and the number of owners, low not above full
+    InvalidThreshold = 4,
+    /// Transaction not found
+    TransactionNotFound = 5,
+    /// Owner has already confirmed this transaction
+    AlreadyConfirmed = 6,
+    /// Owner has not confirmed this transaction
+    NotConfirmed = 7,
+    /// Transaction has already been executed
+    AlreadyExecuted = 8,
+    /// Not enough owners have confirmed the transaction
+    InsufficientConfirmations = 9,
+    /// Amounts and limits must not be negative
+    InvalidAmount = 10,
+    /// Address is already an owner
+    OwnerExists = 11,
+    /// Address is not an owner
+    OwnerNotFound = 12,
+    /// Contract is already paused
+    AlreadyPaused = 13,
+    /// Contract is not paused
+    NotPaused = 14,
+    /// Contract is paused
+    ContractPaused = 15,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::NotOwner,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
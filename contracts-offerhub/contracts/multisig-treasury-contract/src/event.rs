@@ -0,0 +1,32 @@
+use crate::types::{TreasuryConfig, TxAction};
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+pub fn initialized(e: &Env, owners: Vec<Address>, config: TreasuryConfig) {
+    let topics = (Symbol::new(e, "treasury_initialized"),);
+    e.events().publish(topics, (owners, config));
+}
+
+pub fn submitted(e: &Env, owner: Address, id: u32, action: TxAction) {
+    let topics = (Symbol::new(e, "transaction_submitted"), owner);
+    e.events().publish(topics, (id, action));
+}
+
+pub fn confirmed(e: &Env, owner: Address, id: u32) {
+    let topics = (Symbol::new(e, "transaction_confirmed"), owner);
+    e.events().publish(topics, id);
+}
+
+pub fn revoked(e: &Env, owner: Address, id: u32) {
+    let topics = (Symbol::new(e, "confirmation_revoked"), owner);
+    e.events().publish(topics, id);
+}
+
+pub fn executed(e: &Env, owner: Address, id: u32, confirmations: u32) {
+    let topics = (Symbol::new(e, "transaction_executed"), owner);
+    e.events().publish(topics, (id, confirmations));
+}
+
+pub fn owners_changed(e: &Env, owners: Vec<Address>, config: TreasuryConfig) {
+    let topics = (Symbol::new(e, "owners_changed"),);
+    e.events().publish(topics, (owners, config));
+}
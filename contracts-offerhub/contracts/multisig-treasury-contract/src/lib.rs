@@ -0,0 +1,74 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::MultisigTreasuryContract;
+pub use crate::error::Error;
+pub use crate::types::{DailySpend, Transaction, TreasuryConfig, TxAction};
+use soroban_sdk::{contract, contractimpl, panic_with_error, Address, Env, Val, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, MultisigTreasuryContract::pause_admin);
+
+#[contractimpl]
+impl Contract {
+    /// Set up the treasury with its owners, thresholds and daily spend limit at deploy
+    pub fn __constructor(env: Env, owners: Vec<Address>, config: TreasuryConfig) {
+        if let Err(error) = MultisigTreasuryContract::initialize(env.clone(), owners, config) {
+            panic_with_error!(&env, error);
+        }
+    }
+
+    /// Submit a transaction, confirmed by the submitter, and return its id
+    pub fn submit(env: Env, owner: Address, action: TxAction) -> Result<u32, Error> {
+        MultisigTreasuryContract::submit(env, owner, action)
+    }
+
+    /// Confirm a pending transaction
+    pub fn confirm(env: Env, owner: Address, id: u32) -> Result<(), Error> {
+        MultisigTreasuryContract::confirm(env, owner, id)
+    }
+
+    /// Withdraw a confirmation from a pending transaction
+    pub fn revoke(env: Env, owner: Address, id: u32) -> Result<(), Error> {
+        MultisigTreasuryContract::revoke(env, owner, id)
+    }
+
+    /// Execute a transaction that has enough confirmations
+    pub fn execute(env: Env, owner: Address, id: u32) -> Result<Val, Error> {
+        MultisigTreasuryContract::execute(env, owner, id)
+    }
+
+    /// Get the confirmations a transaction needs if executed now
+    pub fn required_confirmations(env: Env, id: u32) -> Result<u32, Error> {
+        MultisigTreasuryContract::required_confirmations(env, id)
+    }
+
+    /// Get a transaction by id
+    pub fn get_transaction(env: Env, id: u32) -> Option<Transaction> {
+        MultisigTreasuryContract::get_transaction(env, id)
+    }
+
+    /// Get the current owners
+    pub fn get_owners(env: Env) -> Vec<Address> {
+        MultisigTreasuryContract::get_owners(env)
+    }
+
+    /// Get the thresholds and daily limit
+    pub fn get_config(env: Env) -> Option<TreasuryConfig> {
+        MultisigTreasuryContract::get_config(env)
+    }
+
+    /// Get how much has been spent today
+    pub fn get_daily_spend(env: Env) -> DailySpend {
+        MultisigTreasuryContract::get_daily_spend(env)
+    }
+}
@@ -0,0 +1,66 @@
+use crate::types::{DailySpend, Transaction, TreasuryConfig};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Owners,
+    Config,
+    DailySpend,
+    NextTransactionId,
+    Transaction(u32),
+}
+
+pub fn get_owners(env: &Env) -> Option<Vec<Address>> {
+    env.storage().instance().get(&DataKey::Owners)
+}
+
+pub fn set_owners(env: &Env, owners: &Vec<Address>) {
+    env.storage().instance().set(&DataKey::Owners, owners);
+}
+
+pub fn get_config(env: &Env) -> Option<TreasuryConfig> {
+    env.storage().instance().get(&DataKey::Config)
+}
+
+pub fn set_config(env: &Env, config: &TreasuryConfig) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+/// Get today's spend, resetting it when the day has rolled over
+pub fn get_daily_spend(env: &Env) -> DailySpend {
+    let day = env.ledger().timestamp() / 86_400;
+    env.storage()
+        .instance()
+        .get::<_, DailySpend>(&DataKey::DailySpend)
+        .filter(|spend| spend.day == day)
+        .unwrap_or(DailySpend { day, spent: 0 })
+}
+
+pub fn set_daily_spend(env: &Env, spend: &DailySpend) {
+    env.storage().instance().set(&DataKey::DailySpend, spend);
+}
+
+/// Allocate the next transaction id, starting at 1
+pub fn next_transaction_id(env: &Env) -> u32 {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u32>(&DataKey::NextTransactionId)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextTransactionId, &id);
+    id
+}
+
+pub fn get_transaction(env: &Env, id: u32) -> Option<Transaction> {
+    env.storage().persistent().get(&DataKey::Transaction(id))
+}
+
+pub fn set_transaction(env: &Env, transaction: &Transaction) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Transaction(transaction.id), transaction);
+}
@@ -0,0 +1,200 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol};
+
+struct Setup<'a> {
+    client: ContractClient<'a>,
+    owners: Vec<Address>,
+    token: TokenClient<'a>,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let owners = vec![
+        env,
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    let config = TreasuryConfig {
+        threshold: 2,
+        low_threshold: 1,
+        daily_limit: 1_000,
+    };
+    let client = ContractClient::new(env, &env.register(Contract, (owners.clone(), config)));
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &token).mint(&client.address, &10_000);
+    Setup {
+        client,
+        owners,
+        token: TokenClient::new(env, &token),
+    }
+}
+
+fn transfer(setup: &Setup, to: &Address, amount: i128) -> TxAction {
+    TxAction::Transfer(setup.token.address.clone(), to.clone(), amount)
+}
+
+/// A token transfer made through a raw call rather than a `Transfer` action
+fn call_transfer(env: &Env, setup: &Setup, to: &Address, amount: i128) -> TxAction {
+    TxAction::Call(
+        setup.token.address.clone(),
+        Symbol::new(env, "transfer"),
+        vec![
+            env,
+            setup.client.address.into_val(env),
+            to.into_val(env),
+            amount.into_val(env),
+        ],
+    )
+}
+
+#[test]
+fn test_daily_limit_lowers_required_confirmations() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let (alice, bob) = (setup.owners.get(0).unwrap(), setup.owners.get(1).unwrap());
+    let vendor = Address::generate(&env);
+
+    // Within the daily limit one confirmation is enough
+    let small = setup.client.submit(&alice, &transfer(&setup, &vendor, 400));
+    assert_eq!(setup.client.required_confirmations(&small), 1);
+    setup.client.execute(&alice, &small);
+    assert_eq!(setup.token.balance(&vendor), 400);
+    assert_eq!(setup.client.get_daily_spend().spent, 400);
+
+    // Going over the limit needs the full threshold
+    let large = setup.client.submit(&alice, &transfer(&setup, &vendor, 700));
+    assert_eq!(setup.client.required_confirmations(&large), 2);
+    assert!(matches!(
+        setup.client.try_execute(&alice, &large),
+        Err(Ok(Error::InsufficientConfirmations))
+    ));
+    setup.client.confirm(&bob, &large);
+    setup.client.execute(&bob, &large);
+    assert_eq!(setup.token.balance(&vendor), 1_100);
+    assert!(matches!(
+        setup.client.try_execute(&bob, &large),
+        Err(Ok(Error::AlreadyExecuted))
+    ));
+
+    // The limit resets the next day
+    env.ledger().with_mut(|l| l.timestamp += 86_400);
+    assert_eq!(setup.client.get_daily_spend().spent, 0);
+}
+
+#[test]
+fn test_calls_need_full_threshold() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let (alice, bob) = (setup.owners.get(0).unwrap(), setup.owners.get(1).unwrap());
+    let vendor = Address::generate(&env);
+
+    // A raw call can move anything, so it never qualifies for the low threshold
+    let drain = setup
+        .client
+        .submit(&alice, &call_transfer(&env, &setup, &vendor, 10_000));
+    assert_eq!(setup.client.required_confirmations(&drain), 2);
+    assert!(matches!(
+        setup.client.try_execute(&alice, &drain),
+        Err(Ok(Error::InsufficientConfirmations))
+    ));
+    setup.client.confirm(&bob, &drain);
+    setup.client.execute(&bob, &drain);
+    assert_eq!(setup.token.balance(&vendor), 10_000);
+    assert_eq!(setup.client.get_daily_spend().spent, 0);
+
+    assert_eq!(
+        setup
+            .client
+            .try_submit(&alice, &transfer(&setup, &vendor, 0)),
+        Err(Ok(Error::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_revoke_and_owner_rotation() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let (alice, bob, carol) = (
+        setup.owners.get(0).unwrap(),
+        setup.owners.get(1).unwrap(),
+        setup.owners.get(2).unwrap(),
+    );
+    let dave = Address::generate(&env);
+
+    // Owner changes always need the full threshold
+    let rotate = setup
+        .client
+        .submit(&alice, &TxAction::ReplaceOwner(carol.clone(), dave.clone()));
+    assert_eq!(setup.client.required_confirmations(&rotate), 2);
+    setup.client.confirm(&bob, &rotate);
+    setup.client.revoke(&bob, &rotate);
+    assert_eq!(
+        setup.client.try_revoke(&bob, &rotate),
+        Err(Ok(Error::NotConfirmed))
+    );
+    assert!(matches!(
+        setup.client.try_execute(&alice, &rotate),
+        Err(Ok(Error::InsufficientConfirmations))
+    ));
+
+    setup.client.confirm(&carol, &rotate);
+    setup.client.execute(&alice, &rotate);
+    let owners = setup.client.get_owners();
+    assert!(owners.contains(&dave));
+    assert!(!owners.contains(&carol));
+    assert_eq!(
+        setup
+            .client
+            .try_submit(&carol, &TxAction::ChangeDailyLimit(0)),
+        Err(Ok(Error::NotOwner))
+    );
+
+    // Thresholds can't exceed the number of owners
+    let raise = setup
+        .client
+        .submit(&dave, &TxAction::ChangeThresholds(4, 1));
+    setup.client.confirm(&alice, &raise);
+    assert!(matches!(
+        setup.client.try_execute(&alice, &raise),
+        Err(Ok(Error::InvalidThreshold))
+    ));
+}
+
+#[test]
+fn test_pause_holds_back_outgoing_calls() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let alice = setup.owners.get(0).unwrap();
+    let guardian = Address::generate(&env);
+    let vendor = Address::generate(&env);
+
+    assert_eq!(setup.client.try_pause(&guardian), Err(Ok(Error::NotOwner)));
+    setup.client.add_pauser(&alice, &guardian);
+    setup.client.pause(&guardian);
+
+    let spend = setup.client.submit(&alice, &transfer(&setup, &vendor, 400));
+    assert!(matches!(
+        setup.client.try_execute(&alice, &spend),
+        Err(Ok(Error::ContractPaused))
+    ));
+    // Owner changes still go through
+    let newcomer = Address::generate(&env);
+    let add = setup
+        .client
+        .submit(&alice, &TxAction::AddOwner(newcomer.clone()));
+    setup.client.confirm(&setup.owners.get(1).unwrap(), &add);
+    setup.client.execute(&alice, &add);
+    assert!(setup.client.get_owners().contains(&newcomer));
+
+    setup.client.unpause(&alice);
+    setup.client.execute(&alice, &spend);
+    assert_eq!(setup.token.balance(&vendor), 400);
+}
@@ -0,0 +1,49 @@
+use soroban_sdk::{contracttype, Address, Symbol, Val, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryConfig {
+    /// Confirmations needed for calls, owner changes and transfers above the daily limit
+    pub threshold: u32,
+    /// Confirmations needed for transfers that keep the day's spend within the limit
+    pub low_threshold: u32,
+    /// Amount the treasury may transfer per day on low-threshold confirmations
+    pub daily_limit: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TxAction {
+    /// Send an amount of a token to an address; the only action counted against the
+    /// daily limit
+    Transfer(Address, Address, i128),
+    /// Call `function` on a contract with `args`; always needs the full threshold
+    Call(Address, Symbol, Vec<Val>),
+    AddOwner(Address),
+    RemoveOwner(Address),
+    /// Swap the first owner for the second
+    ReplaceOwner(Address, Address),
+    /// New full and low thresholds
+    ChangeThresholds(u32, u32),
+    /// New daily spend limit
+    ChangeDailyLimit(i128),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transaction {
+    pub id: u32,
+    pub submitter: Address,
+    pub action: TxAction,
+    pub confirmations: Vec<Address>,
+    pub executed: bool,
+    pub created_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DailySpend {
+    /// Days since the unix epoch
+    pub day: u64,
+    pub spent: i128,
+}
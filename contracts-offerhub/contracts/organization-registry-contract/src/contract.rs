@@ -0,0 +1,352 @@
+use crate::error::Error;
+use crate::event::{
+    org_registry_initialized, asset_registered, member_removed, member_set, organization_created,
+    ownership_transferred,
+};
+use crate::storage::{
+    get_admin, set_admin, add_asset, get_asset_entry, get_asset_owner, get_member, get_members,
+    get_organization, next_organization_id, remove_member, set_member, set_members,
+    set_organization,
+};
+use crate::types::{
+    AssetKind, Member, MemberRole, OrgAction, OrgAsset, Organization, OrganizationLink,
+};
+use soroban_sdk::{Address, Env, IntoVal, String, Symbol, Val, Vec};
+
+const MAX_MEMBERS: u32 = 100;
+const MAX_PAGE_SIZE: u32 = 50;
+const ESCROW_GET_ORGANIZATION: &str = "get_organization";
+const PUBLICATION_GET: &str = "get_publication";
+
+pub struct OrganizationRegistryContract;
+
+impl OrganizationRegistryContract {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        org_registry_initialized(&env, admin);
+        Ok(())
+    }
+
+    pub fn create_organization(env: Env, owner: Address, name: String) -> Result<u32, Error> {
+        Self::require_not_paused(&env)?;
+        owner.require_auth();
+
+        let organization = Organization {
+            id: next_organization_id(&env),
+            owner: owner.clone(),
+            name: name.clone(),
+            created_at: env.ledger().timestamp(),
+            member_count: 0,
+            asset_count: 0,
+        };
+        set_organization(&env, &organization);
+
+        organization_created(&env, organization.id, owner, name);
+        Ok(organization.id)
+    }
+
+    /// Hand the organization to a new owner. A member becoming owner loses their
+    /// member entry, since the owner holds every permission anyway.
+    pub fn transfer_ownership(
+        env: Env,
+        owner: Address,
+        org_id: u32,
+        new_owner: Address,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        owner.require_auth();
+        let mut organization = Self::load(&env, org_id)?;
+        if organization.owner != owner {
+            return Err(Error::Unauthorized);
+        }
+
+        if get_member(&env, org_id, &new_owner).is_some() {
+            Self::drop_member(&env, &mut organization, &new_owner);
+        }
+        organization.owner = new_owner.clone();
+        set_organization(&env, &organization);
+
+        ownership_transferred(&env, org_id, owner, new_owner);
+        Ok(())
+    }
+
+    /// Add a member or change their role. Needs `ManageMembers`; only the owner can
+    /// grant the Admin role or change an Admin's role.
+    pub fn set_member(
+        env: Env,
+        caller: Address,
+        org_id: u32,
+        member: Address,
+        role: MemberRole,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        caller.require_auth();
+        let mut organization = Self::load(&env, org_id)?;
+        if member == organization.owner {
+            return Err(Error::OwnerIsNotMember);
+        }
+        if !Self::permitted(&env, &organization, &caller, OrgAction::ManageMembers) {
+            return Err(Error::Unauthorized);
+        }
+
+        let existing = get_member(&env, org_id, &member);
+        let touches_admin = role == MemberRole::Admin
+            || existing
+                .as_ref()
+                .is_some_and(|m| m.role == MemberRole::Admin);
+        if touches_admin && caller != organization.owner {
+            return Err(Error::Unauthorized);
+        }
+
+        if existing.is_none() {
+            if organization.member_count >= MAX_MEMBERS {
+                return Err(Error::TooManyMembers);
+            }
+            let mut members = get_members(&env, org_id);
+            members.push_back(member.clone());
+            set_members(&env, org_id, &members);
+            organization.member_count += 1;
+            set_organization(&env, &organization);
+        }
+
+        set_member(
+            &env,
+            org_id,
+            &Member {
+                address: member.clone(),
+                role,
+                added_by: caller,
+                added_at: existing
+                    .map(|m| m.added_at)
+                    .unwrap_or(env.ledger().timestamp()),
+            },
+        );
+        member_set(&env, org_id, member, role);
+        Ok(())
+    }
+
+    /// Remove a member. Needs `ManageMembers` (owner only for Admins); members can
+    /// always remove themselves.
+    pub fn remove_member(
+        env: Env,
+        caller: Address,
+        org_id: u32,
+        member: Address,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        caller.require_auth();
+        let mut organization = Self::load(&env, org_id)?;
+        let record = get_member(&env, org_id, &member).ok_or(Error::MemberNotFound)?;
+
+        if caller != member {
+            if !Self::permitted(&env, &organization, &caller, OrgAction::ManageMembers) {
+                return Err(Error::Unauthorized);
+            }
+            if record.role == MemberRole::Admin && caller != organization.owner {
+                return Err(Error::Unauthorized);
+            }
+        }
+
+        Self::drop_member(&env, &mut organization, &member);
+        member_removed(&env, org_id, member);
+        Ok(())
+    }
+
+    pub fn get_organization(env: Env, org_id: u32) -> Result<Organization, Error> {
+        Self::load(&env, org_id)
+    }
+
+    pub fn get_member(env: Env, org_id: u32, member: Address) -> Option<Member> {
+        get_member(&env, org_id, &member)
+    }
+
+    pub fn get_members(env: Env, org_id: u32) -> Vec<Member> {
+        let mut members = Vec::new(&env);
+        for address in get_members(&env, org_id).iter() {
+            if let Some(member) = get_member(&env, org_id, &address) {
+                members.push_back(member);
+            }
+        }
+        members
+    }
+
+    /// Whether an address may take an action for the organization. The owner may take
+    /// every action; members are limited by their role.
+    pub fn has_permission(env: Env, org_id: u32, address: Address, action: OrgAction) -> bool {
+        get_organization(&env, org_id)
+            .is_some_and(|organization| Self::permitted(&env, &organization, &address, action))
+    }
+
+    /// Record that an escrow belongs to the organization. The escrow must already be
+    /// linked to this registry and organization, and the caller needs `ManageEscrows`.
+    pub fn register_escrow(
+        env: Env,
+        caller: Address,
+        org_id: u32,
+        escrow: Address,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        caller.require_auth();
+        let mut organization = Self::load(&env, org_id)?;
+        if !Self::permitted(&env, &organization, &caller, OrgAction::ManageEscrows) {
+            return Err(Error::Unauthorized);
+        }
+
+        let link: Option<OrganizationLink> = env.invoke_contract(
+            &escrow,
+            &Symbol::new(&env, ESCROW_GET_ORGANIZATION),
+            Vec::new(&env),
+        );
+        let expected = OrganizationLink {
+            registry: env.current_contract_address(),
+            org_id,
+        };
+        if link != Some(expected) {
+            return Err(Error::EscrowNotLinked);
+        }
+
+        Self::register_asset(
+            &env,
+            &mut organization,
+            caller,
+            AssetKind::Escrow,
+            escrow,
+            None,
+            0,
+        )
+    }
+
+    /// Record that one of the caller's publications belongs to the organization. The
+    /// caller needs `Publish`.
+    pub fn register_publication(
+        env: Env,
+        caller: Address,
+        org_id: u32,
+        publication_contract: Address,
+        publication_id: u32,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        caller.require_auth();
+        let mut organization = Self::load(&env, org_id)?;
+        if !Self::permitted(&env, &organization, &caller, OrgAction::Publish) {
+            return Err(Error::Unauthorized);
+        }
+
+        let publication: Val = env.invoke_contract(
+            &publication_contract,
+            &Symbol::new(&env, PUBLICATION_GET),
+            (caller.clone(), publication_id).into_val(&env),
+        );
+        if publication.is_void() {
+            return Err(Error::PublicationNotFound);
+        }
+
+        Self::register_asset(
+            &env,
+            &mut organization,
+            caller.clone(),
+            AssetKind::Publication,
+            publication_contract,
+            Some(caller),
+            publication_id,
+        )
+    }
+
+    /// Organization owning an escrow (no author, `item_id` 0) or an author's publication
+    pub fn get_asset_owner(
+        env: Env,
+        contract: Address,
+        author: Option<Address>,
+        item_id: u32,
+    ) -> Option<u32> {
+        get_asset_owner(&env, &contract, &author, item_id)
+    }
+
+    /// Page through an organization's assets, oldest first
+    pub fn get_assets(env: Env, org_id: u32, offset: u32, limit: u32) -> Vec<OrgAsset> {
+        let count = get_organization(&env, org_id)
+            .map(|o| o.asset_count)
+            .unwrap_or(0);
+        let end = offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+        let mut assets = Vec::new(&env);
+        for position in offset..end {
+            if let Some(asset) = get_asset_entry(&env, org_id, position) {
+                assets.push_back(asset);
+            }
+        }
+        assets
+    }
+
+    fn register_asset(
+        env: &Env,
+        organization: &mut Organization,
+        caller: Address,
+        kind: AssetKind,
+        contract: Address,
+        author: Option<Address>,
+        item_id: u32,
+    ) -> Result<(), Error> {
+        if get_asset_owner(env, &contract, &author, item_id).is_some() {
+            return Err(Error::AssetAlreadyOwned);
+        }
+
+        add_asset(
+            env,
+            organization,
+            &OrgAsset {
+                kind,
+                contract: contract.clone(),
+                author: author.clone(),
+                item_id,
+                registered_by: caller,
+                registered_at: env.ledger().timestamp(),
+            },
+        );
+        set_organization(env, organization);
+
+        asset_registered(env, organization.id, kind, contract, author, item_id);
+        Ok(())
+    }
+
+    fn drop_member(env: &Env, organization: &mut Organization, member: &Address) {
+        remove_member(env, organization.id, member);
+        let mut members = get_members(env, organization.id);
+        if let Some(index) = members.first_index_of(member) {
+            members.remove(index);
+            set_members(env, organization.id, &members);
+        }
+        organization.member_count -= 1;
+        set_organization(env, organization);
+    }
+
+    fn permitted(
+        env: &Env,
+        organization: &Organization,
+        address: &Address,
+        action: OrgAction,
+    ) -> bool {
+        *address == organization.owner
+            || get_member(env, organization.id, address).is_some_and(|m| m.role.permits(action))
+    }
+
+    fn load(env: &Env, org_id: u32) -> Result<Organization, Error> {
+        get_organization(env, org_id).ok_or(Error::OrganizationNotFound)
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Organization does not exist
+    OrganizationNotFound = 1,
+    /// Caller is not permitted to perform this action
+    Unauthorized = 2,
+    /// Address is already a member of the organization
+    AlreadyMember = 3,
+    /// Address is not a member of the organization
+    MemberNotFound = 4,
+    /// Organization has reached its member limit
+    TooManyMembers = 5,
+    /// Asset is already owned by an organization
+    AssetAlreadyOwned = 6,
+    /// Escrow is not linked to the organization
+    EscrowNotLinked = 7,
+    /// Publication does not exist for the caller
+    PublicationNotFound = 8,
+    /// The owner cannot be added, removed or re-roled as a member
+    OwnerIsNotMember = 9,
+    /// Contract is already initialized
+    AlreadyInitialized = 10,
+    /// Contract is not initialized
+    NotInitialized = 11,
+    /// Contract is already paused
+    AlreadyPaused = 12,
+    /// Contract is not paused
+    NotPaused = 13,
+    /// Contract is paused
+    ContractPaused = 14,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
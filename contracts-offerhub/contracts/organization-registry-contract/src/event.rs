@@ -0,0 +1,39 @@
+use crate::types::{AssetKind, MemberRole};
+use soroban_sdk::{Address, Env, String, Symbol};
+
+pub fn organization_created(e: &Env, id: u32, owner: Address, name: String) {
+    let topics = (Symbol::new(e, "organization_created"), id, owner);
+    e.events().publish(topics, name);
+}
+
+pub fn ownership_transferred(e: &Env, id: u32, from: Address, to: Address) {
+    let topics = (Symbol::new(e, "org_ownership_transferred"), id);
+    e.events().publish(topics, (from, to));
+}
+
+pub fn member_set(e: &Env, id: u32, member: Address, role: MemberRole) {
+    let topics = (Symbol::new(e, "org_member_set"), id, member);
+    e.events().publish(topics, role);
+}
+
+pub fn member_removed(e: &Env, id: u32, member: Address) {
+    let topics = (Symbol::new(e, "org_member_removed"), id, member);
+    e.events().publish(topics, e.ledger().timestamp());
+}
+
+pub fn asset_registered(
+    e: &Env,
+    id: u32,
+    kind: AssetKind,
+    contract: Address,
+    author: Option<Address>,
+    item_id: u32,
+) {
+    let topics = (Symbol::new(e, "org_asset_registered"), id, contract);
+    e.events().publish(topics, (kind, author, item_id));
+}
+
+pub fn org_registry_initialized(e: &Env, admin: Address) {
+    let topics = (Symbol::new(e, "org_registry_initialized"),);
+    e.events().publish(topics, admin);
+}
@@ -0,0 +1,129 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::OrganizationRegistryContract;
+pub use crate::error::Error;
+pub use crate::types::{
+    AssetKind, Member, MemberRole, OrgAction, OrgAsset, Organization, OrganizationLink,
+};
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| {
+    OrganizationRegistryContract::pause_admin(env)
+});
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the contract with the admin that manages pausing
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        OrganizationRegistryContract::initialize(env, admin)
+    }
+
+    /// Register a new organization owned by the caller and return its id
+    pub fn create_organization(env: Env, owner: Address, name: String) -> Result<u32, Error> {
+        OrganizationRegistryContract::create_organization(env, owner, name)
+    }
+
+    /// Hand an organization to a new owner (owner only)
+    pub fn transfer_ownership(
+        env: Env,
+        owner: Address,
+        org_id: u32,
+        new_owner: Address,
+    ) -> Result<(), Error> {
+        OrganizationRegistryContract::transfer_ownership(env, owner, org_id, new_owner)
+    }
+
+    /// Add a member or change their role
+    pub fn set_member(
+        env: Env,
+        caller: Address,
+        org_id: u32,
+        member: Address,
+        role: MemberRole,
+    ) -> Result<(), Error> {
+        OrganizationRegistryContract::set_member(env, caller, org_id, member, role)
+    }
+
+    /// Remove a member, or leave an organization
+    pub fn remove_member(
+        env: Env,
+        caller: Address,
+        org_id: u32,
+        member: Address,
+    ) -> Result<(), Error> {
+        OrganizationRegistryContract::remove_member(env, caller, org_id, member)
+    }
+
+    /// Get an organization by id
+    pub fn get_organization(env: Env, org_id: u32) -> Result<Organization, Error> {
+        OrganizationRegistryContract::get_organization(env, org_id)
+    }
+
+    /// Get a member's role in an organization
+    pub fn get_member(env: Env, org_id: u32, member: Address) -> Option<Member> {
+        OrganizationRegistryContract::get_member(env, org_id, member)
+    }
+
+    /// Get an organization's members
+    pub fn get_members(env: Env, org_id: u32) -> Vec<Member> {
+        OrganizationRegistryContract::get_members(env, org_id)
+    }
+
+    /// Check whether an address may take an action for an organization
+    pub fn has_permission(env: Env, org_id: u32, address: Address, action: OrgAction) -> bool {
+        OrganizationRegistryContract::has_permission(env, org_id, address, action)
+    }
+
+    /// Record an escrow linked to an organization as owned by it
+    pub fn register_escrow(
+        env: Env,
+        caller: Address,
+        org_id: u32,
+        escrow: Address,
+    ) -> Result<(), Error> {
+        OrganizationRegistryContract::register_escrow(env, caller, org_id, escrow)
+    }
+
+    /// Record one of the caller's publications as owned by an organization
+    pub fn register_publication(
+        env: Env,
+        caller: Address,
+        org_id: u32,
+        publication_contract: Address,
+        publication_id: u32,
+    ) -> Result<(), Error> {
+        OrganizationRegistryContract::register_publication(
+            env,
+            caller,
+            org_id,
+            publication_contract,
+            publication_id,
+        )
+    }
+
+    /// Get the organization owning an escrow (no author) or an author's publication
+    pub fn get_asset_owner(
+        env: Env,
+        contract: Address,
+        author: Option<Address>,
+        item_id: u32,
+    ) -> Option<u32> {
+        OrganizationRegistryContract::get_asset_owner(env, contract, author, item_id)
+    }
+
+    /// Page through an organization's escrows and publications
+    pub fn get_assets(env: Env, org_id: u32, offset: u32, limit: u32) -> Vec<OrgAsset> {
+        OrganizationRegistryContract::get_assets(env, org_id, offset, limit)
+    }
+}
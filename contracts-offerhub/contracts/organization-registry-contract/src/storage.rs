@@ -0,0 +1,113 @@
+use crate::types::{Member, OrgAsset, Organization};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    NextOrganizationId,
+    Organization(u32),
+    Member(u32, Address),
+    /// org id -> member addresses, in the order they joined
+    Members(u32),
+    /// (contract, author, item id) -> owning org id; escrows have no author
+    AssetOwner(Address, Option<Address>, u32),
+    /// (org id, position) -> asset
+    AssetEntry(u32, u32),
+}
+
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Allocate the next organization id, starting at 1
+pub fn next_organization_id(env: &Env) -> u32 {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u32>(&DataKey::NextOrganizationId)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextOrganizationId, &id);
+    id
+}
+
+pub fn get_organization(env: &Env, id: u32) -> Option<Organization> {
+    env.storage().persistent().get(&DataKey::Organization(id))
+}
+
+pub fn set_organization(env: &Env, organization: &Organization) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Organization(organization.id), organization);
+}
+
+pub fn get_member(env: &Env, org_id: u32, address: &Address) -> Option<Member> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Member(org_id, address.clone()))
+}
+
+pub fn set_member(env: &Env, org_id: u32, member: &Member) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Member(org_id, member.address.clone()), member);
+}
+
+pub fn remove_member(env: &Env, org_id: u32, address: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Member(org_id, address.clone()));
+}
+
+pub fn get_members(env: &Env, org_id: u32) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Members(org_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_members(env: &Env, org_id: u32, members: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Members(org_id), members);
+}
+
+pub fn get_asset_owner(
+    env: &Env,
+    contract: &Address,
+    author: &Option<Address>,
+    item_id: u32,
+) -> Option<u32> {
+    env.storage().persistent().get(&DataKey::AssetOwner(
+        contract.clone(),
+        author.clone(),
+        item_id,
+    ))
+}
+
+/// Record an organization's ownership of an asset at the next position in its listing
+pub fn add_asset(env: &Env, organization: &mut Organization, asset: &OrgAsset) {
+    let storage = env.storage().persistent();
+    storage.set(
+        &DataKey::AssetOwner(asset.contract.clone(), asset.author.clone(), asset.item_id),
+        &organization.id,
+    );
+    storage.set(
+        &DataKey::AssetEntry(organization.id, organization.asset_count),
+        asset,
+    );
+    organization.asset_count += 1;
+}
+
+pub fn get_asset_entry(env: &Env, org_id: u32, position: u32) -> Option<OrgAsset> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AssetEntry(org_id, position))
+}
@@ -0,0 +1,171 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String};
+
+#[contract]
+pub struct MockEscrow;
+
+#[contractimpl]
+impl MockEscrow {
+    pub fn link(env: Env, registry: Address, org_id: u32) {
+        env.storage().instance().set(
+            &symbol_short!("org"),
+            &OrganizationLink { registry, org_id },
+        );
+    }
+
+    pub fn get_organization(env: Env) -> Option<OrganizationLink> {
+        env.storage().instance().get(&symbol_short!("org"))
+    }
+}
+
+#[contract]
+pub struct MockPublications;
+
+#[contractimpl]
+impl MockPublications {
+    pub fn publish(env: Env, user: Address, id: u32) {
+        env.storage().instance().set(&(user, id), &id);
+    }
+
+    pub fn get_publication(env: Env, user: Address, id: u32) -> Option<u32> {
+        env.storage().instance().get(&(user, id))
+    }
+}
+
+fn setup(env: &Env) -> (ContractClient<'_>, Address, u32) {
+    env.mock_all_auths();
+    let client = ContractClient::new(env, &env.register(Contract, ()));
+    let owner = Address::generate(env);
+    let org_id = client.create_organization(&owner, &String::from_str(env, "Acme Studio"));
+    (client, owner, org_id)
+}
+
+#[test]
+fn test_roles_limit_member_actions() {
+    let env = Env::default();
+    let (client, owner, org_id) = setup(&env);
+    let admin = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let member = Address::generate(&env);
+
+    client.set_member(&owner, &org_id, &admin, &MemberRole::Admin);
+    client.set_member(&admin, &org_id, &manager, &MemberRole::Manager);
+    // Managers cannot manage members, and only the owner touches Admins
+    assert_eq!(
+        client.try_set_member(&manager, &org_id, &member, &MemberRole::Member),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_member(&admin, &org_id, &member, &MemberRole::Admin),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_member(&owner, &org_id, &owner, &MemberRole::Admin),
+        Err(Ok(Error::OwnerIsNotMember))
+    );
+    client.set_member(&admin, &org_id, &member, &MemberRole::Member);
+    assert_eq!(client.get_organization(&org_id).member_count, 3);
+
+    assert!(client.has_permission(&org_id, &owner, &OrgAction::ManageMembers));
+    assert!(client.has_permission(&org_id, &manager, &OrgAction::ApproveMilestones));
+    assert!(!client.has_permission(&org_id, &manager, &OrgAction::ManageMembers));
+    assert!(client.has_permission(&org_id, &member, &OrgAction::Publish));
+    assert!(!client.has_permission(&org_id, &member, &OrgAction::ManageEscrows));
+
+    assert_eq!(
+        client.try_remove_member(&manager, &org_id, &admin),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.remove_member(&owner, &org_id, &admin);
+    client.remove_member(&member, &org_id, &member);
+    assert!(!client.has_permission(&org_id, &member, &OrgAction::Publish));
+    assert_eq!(client.get_members(&org_id).len(), 1);
+
+    client.transfer_ownership(&owner, &org_id, &manager);
+    assert_eq!(client.get_organization(&org_id).member_count, 0);
+    assert!(!client.has_permission(&org_id, &owner, &OrgAction::Publish));
+}
+
+#[test]
+fn test_organizations_own_linked_escrows_and_publications() {
+    let env = Env::default();
+    let (client, owner, org_id) = setup(&env);
+    let member = Address::generate(&env);
+    client.set_member(&owner, &org_id, &member, &MemberRole::Member);
+
+    let escrow = MockEscrowClient::new(&env, &env.register(MockEscrow, ()));
+    assert_eq!(
+        client.try_register_escrow(&owner, &org_id, &escrow.address),
+        Err(Ok(Error::EscrowNotLinked))
+    );
+    escrow.link(&client.address, &org_id);
+    assert_eq!(
+        client.try_register_escrow(&member, &org_id, &escrow.address),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.register_escrow(&owner, &org_id, &escrow.address);
+    assert_eq!(
+        client.get_asset_owner(&escrow.address, &None, &0),
+        Some(org_id)
+    );
+    assert_eq!(
+        client.try_register_escrow(&owner, &org_id, &escrow.address),
+        Err(Ok(Error::AssetAlreadyOwned))
+    );
+
+    let publications = MockPublicationsClient::new(&env, &env.register(MockPublications, ()));
+    assert_eq!(
+        client.try_register_publication(&member, &org_id, &publications.address, &7),
+        Err(Ok(Error::PublicationNotFound))
+    );
+    publications.publish(&member, &7);
+    client.register_publication(&member, &org_id, &publications.address, &7);
+
+    // Publication ids are per author, so another author's #7 is a different asset
+    publications.publish(&owner, &7);
+    client.register_publication(&owner, &org_id, &publications.address, &7);
+    assert_eq!(
+        client.try_register_publication(&owner, &org_id, &publications.address, &7),
+        Err(Ok(Error::AssetAlreadyOwned))
+    );
+    assert_eq!(
+        client.get_asset_owner(&publications.address, &Some(member.clone()), &7),
+        Some(org_id)
+    );
+
+    let assets = client.get_assets(&org_id, &0, &10);
+    assert_eq!(assets.len(), 3);
+    assert_eq!(assets.get(1).unwrap().kind, AssetKind::Publication);
+    assert_eq!(assets.get(1).unwrap().registered_by, member);
+    assert_eq!(assets.get(2).unwrap().author, Some(owner));
+}
+
+#[test]
+fn test_pause_blocks_member_changes() {
+    let env = Env::default();
+    let (client, owner, org_id) = setup(&env);
+    let admin = Address::generate(&env);
+    let member = Address::generate(&env);
+    client.initialize(&admin);
+    assert_eq!(
+        client.try_initialize(&admin),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+
+    client.pause(&admin);
+    assert_eq!(
+        client.try_set_member(&owner, &org_id, &member, &MemberRole::Member),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_create_organization(&member, &String::from_str(&env, "Beta")),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    client.unpause(&admin);
+    client.set_member(&owner, &org_id, &member, &MemberRole::Member);
+    assert_eq!(client.get_organization(&org_id).member_count, 1);
+}
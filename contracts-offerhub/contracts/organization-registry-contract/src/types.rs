@@ -0,0 +1,82 @@
+use soroban_sdk::{contracttype, Address, String};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Organization {
+    pub id: u32,
+    pub owner: Address,
+    pub name: String,
+    pub created_at: u64,
+    pub member_count: u32,
+    pub asset_count: u32,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum MemberRole {
+    Admin = 0,
+    Manager = 1,
+    Member = 2,
+}
+
+/// Actions a member can be permitted to take for the organization. Escrows check these by
+/// discriminant, so existing values must not change.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum OrgAction {
+    ManageMembers = 0,
+    ManageEscrows = 1,
+    ApproveMilestones = 2,
+    Publish = 3,
+}
+
+impl MemberRole {
+    pub fn permits(&self, action: OrgAction) -> bool {
+        match self {
+            MemberRole::Admin => true,
+            MemberRole::Manager => action != OrgAction::ManageMembers,
+            MemberRole::Member => action == OrgAction::Publish,
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Member {
+    pub address: Address,
+    pub role: MemberRole,
+    pub added_by: Address,
+    pub added_at: u64,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AssetKind {
+    Escrow = 0,
+    Publication = 1,
+}
+
+/// An escrow or publication owned by an organization. Escrows are identified by their
+/// contract alone (no author, `item_id` 0); publications by their contract, author and
+/// id, since publication ids are only unique per author.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgAsset {
+    pub kind: AssetKind,
+    pub contract: Address,
+    pub author: Option<Address>,
+    pub item_id: u32,
+    pub registered_by: Address,
+    pub registered_at: u64,
+}
+
+/// Mirrors the escrow contract's `OrganizationLink`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizationLink {
+    pub registry: Address,
+    pub org_id: u32,
+}
@@ -0,0 +1,222 @@
+use crate::error::Error;
+use crate::event::{asset_allowed, payment_received, payment_released, splitter_initialized};
+use crate::storage::{
+    add_released, get_admin, get_assets, get_payees, get_released, get_total_released, set_admin,
+    set_assets, set_payees,
+};
+use crate::types::Payee;
+use soroban_sdk::{Address, Env, IntoVal, Map, Symbol, Vec};
+
+const MAX_SPLIT_BPS: u32 = 10_000;
+const MAX_PAYEES: u32 = 20;
+const TOKEN_TRANSFER: &str = "transfer";
+const TOKEN_BALANCE: &str = "balance";
+
+pub struct PaymentSplitterContract;
+
+impl PaymentSplitterContract {
+    /// Set up the payees and the assets the splitter accepts. Shares are fixed for the
+    /// life of the splitter so that pending amounts never change retroactively.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        payees: Vec<Payee>,
+        assets: Vec<Address>,
+    ) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+        Self::validate_payees(&payees)?;
+
+        let mut unique = Vec::new(&env);
+        for asset in assets.iter() {
+            if !unique.contains(&asset) {
+                unique.push_back(asset);
+            }
+        }
+
+        set_admin(&env, &admin);
+        set_payees(&env, &payees);
+        set_assets(&env, &unique);
+        splitter_initialized(&env, admin, payees);
+        Ok(())
+    }
+
+    /// Add or remove an asset from the whitelist (admin only). Removing an asset stops
+    /// releases in it until it is allowed again.
+    pub fn set_asset_allowed(
+        env: Env,
+        admin: Address,
+        asset: Address,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let mut assets = get_assets(&env);
+        match (assets.first_index_of(&asset), allowed) {
+            (None, true) => assets.push_back(asset.clone()),
+            (Some(index), false) => {
+                assets.remove(index);
+            }
+            _ => {}
+        }
+        set_assets(&env, &assets);
+
+        asset_allowed(&env, asset, allowed);
+        Ok(())
+    }
+
+    pub fn get_assets(env: Env) -> Vec<Address> {
+        get_assets(&env)
+    }
+
+    pub fn get_payees(env: Env) -> Vec<Payee> {
+        get_payees(&env)
+    }
+
+    /// Pay a whitelisted asset into the splitter. Plain token transfers to the splitter
+    /// are split the same way; this entry point only adds the check and an event.
+    pub fn deposit(env: Env, from: Address, asset: Address, amount: i128) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        from.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Self::require_allowed_asset(&env, &asset)?;
+
+        env.invoke_contract::<()>(
+            &asset,
+            &Symbol::new(&env, TOKEN_TRANSFER),
+            (from.clone(), env.current_contract_address(), amount).into_val(&env),
+        );
+
+        payment_received(&env, from, asset, amount);
+        Ok(())
+    }
+
+    /// Amount of `asset` a payee can release right now
+    pub fn pending(env: Env, payee: Address, asset: Address) -> Result<i128, Error> {
+        let share = Self::share_of(&env, &payee)?;
+        Ok(Self::pending_amount(&env, &payee, share, &asset))
+    }
+
+    /// Pay a payee its accumulated share of every whitelisted asset. Anyone can trigger a
+    /// release; the funds always go to the payee.
+    pub fn release(env: Env, payee: Address) -> Result<Map<Address, i128>, Error> {
+        Self::require_not_paused(&env)?;
+        let share = Self::share_of(&env, &payee)?;
+
+        let mut released = Map::new(&env);
+        for asset in get_assets(&env).iter() {
+            let amount = Self::release_one(&env, &payee, share, &asset);
+            if amount > 0 {
+                released.set(asset, amount);
+            }
+        }
+        Ok(released)
+    }
+
+    /// Pay a payee its accumulated share of a single whitelisted asset
+    pub fn release_asset(env: Env, payee: Address, asset: Address) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        let share = Self::share_of(&env, &payee)?;
+        Self::require_allowed_asset(&env, &asset)?;
+        Ok(Self::release_one(&env, &payee, share, &asset))
+    }
+
+    pub fn get_released(env: Env, payee: Address, asset: Address) -> i128 {
+        get_released(&env, &asset, &payee)
+    }
+
+    pub fn get_total_released(env: Env, asset: Address) -> i128 {
+        get_total_released(&env, &asset)
+    }
+
+    fn release_one(env: &Env, payee: &Address, share: u32, asset: &Address) -> i128 {
+        let amount = Self::pending_amount(env, payee, share, asset);
+        if amount <= 0 {
+            return 0;
+        }
+
+        add_released(env, asset, payee, amount);
+        env.invoke_contract::<()>(
+            asset,
+            &Symbol::new(env, TOKEN_TRANSFER),
+            (env.current_contract_address(), payee.clone(), amount).into_val(env),
+        );
+
+        payment_released(env, payee.clone(), asset.clone(), amount);
+        amount
+    }
+
+    /// A payee's cut of everything ever received in `asset`, minus what it already took
+    fn pending_amount(env: &Env, payee: &Address, share: u32, asset: &Address) -> i128 {
+        let balance: i128 = env.invoke_contract(
+            asset,
+            &Symbol::new(env, TOKEN_BALANCE),
+            (env.current_contract_address(),).into_val(env),
+        );
+        let total_received = balance + get_total_released(env, asset);
+        total_received * share as i128 / MAX_SPLIT_BPS as i128 - get_released(env, asset, payee)
+    }
+
+    fn share_of(env: &Env, payee: &Address) -> Result<u32, Error> {
+        get_payees(env)
+            .iter()
+            .find(|p| p.recipient == *payee)
+            .map(|p| p.bps)
+            .ok_or(Error::NotPayee)
+    }
+
+    fn require_allowed_asset(env: &Env, asset: &Address) -> Result<(), Error> {
+        if !get_assets(env).contains(asset) {
+            return Err(Error::AssetNotAllowed);
+        }
+        Ok(())
+    }
+
+    fn validate_payees(payees: &Vec<Payee>) -> Result<(), Error> {
+        if payees.is_empty() || payees.len() > MAX_PAYEES {
+            return Err(Error::InvalidShares);
+        }
+        let mut total_bps: u32 = 0;
+        for (i, payee) in payees.iter().enumerate() {
+            if payee.bps == 0 {
+                return Err(Error::InvalidShares);
+            }
+            if payees
+                .iter()
+                .skip(i + 1)
+                .any(|other| other.recipient == payee.recipient)
+            {
+                return Err(Error::InvalidShares);
+            }
+            total_bps = total_bps.saturating_add(payee.bps);
+        }
+        if total_bps != MAX_SPLIT_BPS {
+            return Err(Error::InvalidShares);
+        }
+        Ok(())
+    }
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+        caller.require_auth();
+        let admin = get_admin(env).ok_or(Error::NotInitialized)?;
+        if *caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
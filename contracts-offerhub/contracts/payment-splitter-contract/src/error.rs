@@ -0,0 +1,38 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// Payee shares are empty, zero, duplicated or do not sum to 10_000 bps
+    InvalidShares = 4,
+    /// Address is not a payee
+    NotPayee = 5,
+    /// Asset is not on the whitelist
+    AssetNotAllowed = 6,
+    /// Amount must be positive
+    InvalidAmount = 7,
+    /// Contract is already paused
+    AlreadyPaused = 8,
+    /// Contract is not paused
+    NotPaused = 9,
+    /// Contract is paused
+    ContractPaused = 10,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::types::Payee;
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+pub fn splitter_initialized(e: &Env, admin: Address, payees: Vec<Payee>) {
+    let topics = (Symbol::new(e, "splitter_initialized"), admin);
+    e.events().publish(topics, payees);
+}
+
+pub fn asset_allowed(e: &Env, asset: Address, allowed: bool) {
+    let topics = (Symbol::new(e, "asset_allowed"), asset);
+    e.events().publish(topics, allowed);
+}
+
+pub fn payment_received(e: &Env, from: Address, asset: Address, amount: i128) {
+    let topics = (Symbol::new(e, "payment_received"), from, asset);
+    e.events().publish(topics, amount);
+}
+
+pub fn payment_released(e: &Env, payee: Address, asset: Address, amount: i128) {
+    let topics = (Symbol::new(e, "payment_released"), payee, asset);
+    e.events().publish(topics, amount);
+}
@@ -0,0 +1,84 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::PaymentSplitterContract;
+pub use crate::error::Error;
+pub use crate::types::Payee;
+use soroban_sdk::{contract, contractimpl, Address, Env, Map, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| {
+    PaymentSplitterContract::pause_admin(env)
+});
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the splitter with its payees and whitelisted assets
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        payees: Vec<Payee>,
+        assets: Vec<Address>,
+    ) -> Result<(), Error> {
+        PaymentSplitterContract::initialize(env, admin, payees, assets)
+    }
+
+    /// Allow or disallow an asset (admin only)
+    pub fn set_asset_allowed(
+        env: Env,
+        admin: Address,
+        asset: Address,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        PaymentSplitterContract::set_asset_allowed(env, admin, asset, allowed)
+    }
+
+    /// Get the whitelisted assets
+    pub fn get_assets(env: Env) -> Vec<Address> {
+        PaymentSplitterContract::get_assets(env)
+    }
+
+    /// Get the payees and their shares
+    pub fn get_payees(env: Env) -> Vec<Payee> {
+        PaymentSplitterContract::get_payees(env)
+    }
+
+    /// Pay a whitelisted asset into the splitter
+    pub fn deposit(env: Env, from: Address, asset: Address, amount: i128) -> Result<(), Error> {
+        PaymentSplitterContract::deposit(env, from, asset, amount)
+    }
+
+    /// Get the amount of an asset a payee can release
+    pub fn pending(env: Env, payee: Address, asset: Address) -> Result<i128, Error> {
+        PaymentSplitterContract::pending(env, payee, asset)
+    }
+
+    /// Release a payee's share of every whitelisted asset, returning the amounts paid
+    pub fn release(env: Env, payee: Address) -> Result<Map<Address, i128>, Error> {
+        PaymentSplitterContract::release(env, payee)
+    }
+
+    /// Release a payee's share of one asset
+    pub fn release_asset(env: Env, payee: Address, asset: Address) -> Result<i128, Error> {
+        PaymentSplitterContract::release_asset(env, payee, asset)
+    }
+
+    /// Get the total a payee has released in an asset
+    pub fn get_released(env: Env, payee: Address, asset: Address) -> i128 {
+        PaymentSplitterContract::get_released(env, payee, asset)
+    }
+
+    /// Get the total released to all payees in an asset
+    pub fn get_total_released(env: Env, asset: Address) -> i128 {
+        PaymentSplitterContract::get_total_released(env, asset)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::types::Payee;
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Payees,
+    /// Whitelisted assets, in the order they were allowed
+    Assets,
+    /// asset -> total paid out to all payees
+    TotalReleased(Address),
+    /// (asset, payee) -> total paid out to the payee
+    Released(Address, Address),
+}
+
+/// Get the admin address
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_payees(env: &Env) -> Vec<Payee> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Payees)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_payees(env: &Env, payees: &Vec<Payee>) {
+    env.storage().instance().set(&DataKey::Payees, payees);
+}
+
+pub fn get_assets(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Assets)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_assets(env: &Env, assets: &Vec<Address>) {
+    env.storage().instance().set(&DataKey::Assets, assets);
+}
+
+pub fn get_total_released(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TotalReleased(asset.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_released(env: &Env, asset: &Address, payee: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Released(asset.clone(), payee.clone()))
+        .unwrap_or(0)
+}
+
+/// Record a payout to a payee in both the payee's and the asset's totals
+pub fn add_released(env: &Env, asset: &Address, payee: &Address, amount: i128) {
+    let storage = env.storage().persistent();
+    storage.set(
+        &DataKey::Released(asset.clone(), payee.clone()),
+        &(get_released(env, asset, payee) + amount),
+    );
+    storage.set(
+        &DataKey::TotalReleased(asset.clone()),
+        &(get_total_released(env, asset) + amount),
+    );
+}
@@ -0,0 +1,129 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+use soroban_sdk::{vec, Address, Env};
+
+fn new_token(env: &Env) -> Address {
+    env.register_stellar_asset_contract_v2(Address::generate(env))
+        .address()
+}
+
+fn payee(recipient: &Address, bps: u32) -> Payee {
+    Payee {
+        recipient: recipient.clone(),
+        bps,
+    }
+}
+
+#[test]
+fn test_initialize_rejects_invalid_shares() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let splitter = ContractClient::new(&env, &env.register(Contract, ()));
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    assert_eq!(
+        splitter.try_initialize(&admin, &vec![&env, payee(&alice, 6_000)], &vec![&env]),
+        Err(Ok(Error::InvalidShares))
+    );
+    assert_eq!(
+        splitter.try_initialize(
+            &admin,
+            &vec![&env, payee(&alice, 5_000), payee(&alice, 5_000)],
+            &vec![&env]
+        ),
+        Err(Ok(Error::InvalidShares))
+    );
+    splitter.initialize(
+        &admin,
+        &vec![&env, payee(&alice, 6_000), payee(&bob, 4_000)],
+        &vec![&env],
+    );
+    assert_eq!(splitter.get_payees().len(), 2);
+}
+
+#[test]
+fn test_payees_release_accumulated_shares_per_asset() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let splitter = ContractClient::new(&env, &env.register(Contract, ()));
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let usdc = new_token(&env);
+    let xlm = new_token(&env);
+    let other = new_token(&env);
+    splitter.initialize(
+        &admin,
+        &vec![&env, payee(&alice, 7_000), payee(&bob, 3_000)],
+        &vec![&env, usdc.clone()],
+    );
+
+    let payer = Address::generate(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&payer, &2_000);
+    StellarAssetClient::new(&env, &xlm).mint(&payer, &1_000);
+    StellarAssetClient::new(&env, &other).mint(&payer, &1_000);
+
+    assert_eq!(
+        splitter.try_deposit(&payer, &other, &100),
+        Err(Ok(Error::AssetNotAllowed))
+    );
+    splitter.deposit(&payer, &usdc, &1_000);
+    splitter.set_asset_allowed(&admin, &xlm, &true);
+    splitter.deposit(&payer, &xlm, &1_000);
+
+    let released = splitter.release(&alice);
+    assert_eq!(released.get(usdc.clone()), Some(700));
+    assert_eq!(released.get(xlm.clone()), Some(700));
+    assert_eq!(splitter.release(&alice).len(), 0);
+
+    // A later payment accrues on top of what was already released
+    TokenClient::new(&env, &usdc).transfer(&payer, &splitter.address, &1_000);
+    assert_eq!(splitter.pending(&alice, &usdc), 700);
+    assert_eq!(splitter.pending(&bob, &usdc), 600);
+    assert_eq!(splitter.release_asset(&bob, &usdc), 600);
+    assert_eq!(splitter.release_asset(&alice, &usdc), 700);
+
+    assert_eq!(TokenClient::new(&env, &usdc).balance(&alice), 1_400);
+    assert_eq!(TokenClient::new(&env, &usdc).balance(&bob), 600);
+    assert_eq!(splitter.get_total_released(&usdc), 2_000);
+    assert_eq!(
+        splitter.try_release(&Address::generate(&env)),
+        Err(Ok(Error::NotPayee))
+    );
+}
+
+#[test]
+fn test_pause_blocks_deposits_and_releases() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let splitter = ContractClient::new(&env, &env.register(Contract, ()));
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let usdc = new_token(&env);
+    splitter.initialize(
+        &admin,
+        &vec![&env, payee(&alice, 10_000)],
+        &vec![&env, usdc.clone()],
+    );
+    let payer = Address::generate(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&payer, &1_000);
+    splitter.deposit(&payer, &usdc, &500);
+
+    splitter.pause(&admin);
+    assert_eq!(
+        splitter.try_deposit(&payer, &usdc, &500),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        splitter.try_release_asset(&alice, &usdc),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    splitter.unpause(&admin);
+    assert_eq!(splitter.release_asset(&alice, &usdc), 500);
+}
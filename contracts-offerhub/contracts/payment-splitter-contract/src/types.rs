@@ -0,0 +1,8 @@
+use soroban_sdk::{contracttype, Address};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Payee {
+    pub recipient: Address,
+    pub bps: u32, // Share of everything the splitter receives, out of 10_000
+}
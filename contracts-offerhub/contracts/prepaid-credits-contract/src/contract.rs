@@ -0,0 +1,329 @@
+use crate::error::Error;
+use crate::event::{
+    admin_initialized, config_set, credits_drawn, credits_purchased, credits_refunded,
+    credits_swept, gift_purchased, gift_reclaimed, gift_redeemed,
+};
+use crate::storage::{
+    get_account, get_admin, get_config, get_gift, remove_gift, set_account, set_admin, set_config,
+    set_gift,
+};
+use crate::types::{CreditAccount, CreditsConfig, Gift};
+use soroban_sdk::{Address, Bytes, BytesN, Env, IntoVal, Symbol};
+
+const TOKEN_TRANSFER: &str = "transfer";
+const FACTORY_GET_ESCROW_ID: &str = "get_escrow_id_by_address";
+
+pub struct PrepaidCreditsContract;
+
+impl PrepaidCreditsContract {
+    pub fn initialize(env: Env, admin: Address, config: CreditsConfig) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+        Self::validate_config(&config)?;
+
+        set_admin(&env, &admin);
+        set_config(&env, &config);
+        admin_initialized(&env, admin);
+        config_set(&env, config);
+        Ok(())
+    }
+
+    pub fn set_config(env: Env, admin: Address, config: CreditsConfig) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        Self::validate_config(&config)?;
+
+        set_config(&env, &config);
+        config_set(&env, config);
+        Ok(())
+    }
+
+    pub fn get_config(env: Env) -> Option<CreditsConfig> {
+        get_config(&env)
+    }
+
+    /// Buy credits for yourself. Topping up pushes the expiry of the whole balance out
+    /// to a full `credit_ttl` from now; an expired balance has to be swept first.
+    pub fn purchase(env: Env, buyer: Address, amount: i128) -> Result<CreditAccount, Error> {
+        Self::require_not_paused(&env)?;
+        buyer.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        Self::transfer(
+            &env,
+            &config.token,
+            &buyer,
+            &env.current_contract_address(),
+            amount,
+        );
+
+        let account = Self::credit(&env, &config, &buyer, amount, amount)?;
+        credits_purchased(&env, buyer, amount, account.expires_at);
+        Ok(account)
+    }
+
+    /// Buy credits for someone else. Only the hash of the redeem code is stored; the
+    /// buyer passes the code itself to the recipient off-chain.
+    pub fn purchase_gift(
+        env: Env,
+        buyer: Address,
+        amount: i128,
+        code_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        buyer.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if get_gift(&env, &code_hash).is_some() {
+            return Err(Error::GiftExists);
+        }
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        Self::transfer(
+            &env,
+            &config.token,
+            &buyer,
+            &env.current_contract_address(),
+            amount,
+        );
+
+        set_gift(
+            &env,
+            &Gift {
+                code_hash: code_hash.clone(),
+                buyer: buyer.clone(),
+                amount,
+                expires_at: env.ledger().timestamp().saturating_add(config.credit_ttl),
+            },
+        );
+        gift_purchased(&env, buyer, code_hash, amount);
+        Ok(())
+    }
+
+    /// Redeem a gift code into the recipient's balance. Gifted credits can be spent on
+    /// escrows but not refunded.
+    pub fn redeem_gift(env: Env, recipient: Address, code: Bytes) -> Result<CreditAccount, Error> {
+        Self::require_not_paused(&env)?;
+        recipient.require_auth();
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+
+        let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+        let gift = get_gift(&env, &code_hash).ok_or(Error::GiftNotFound)?;
+        if env.ledger().timestamp() >= gift.expires_at {
+            return Err(Error::GiftExpired);
+        }
+        remove_gift(&env, &code_hash);
+
+        let account = Self::credit(&env, &config, &recipient, gift.amount, 0)?;
+        gift_redeemed(&env, recipient, code_hash, gift.amount);
+        Ok(account)
+    }
+
+    /// Return an expired, unredeemed gift to its buyer
+    pub fn reclaim_gift(env: Env, buyer: Address, code_hash: BytesN<32>) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        buyer.require_auth();
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+
+        let gift = get_gift(&env, &code_hash).ok_or(Error::GiftNotFound)?;
+        if gift.buyer != buyer {
+            return Err(Error::Unauthorized);
+        }
+        if env.ledger().timestamp() < gift.expires_at {
+            return Err(Error::GiftNotExpired);
+        }
+        remove_gift(&env, &code_hash);
+
+        Self::transfer(
+            &env,
+            &config.token,
+            &env.current_contract_address(),
+            &buyer,
+            gift.amount,
+        );
+        gift_reclaimed(&env, buyer, code_hash, gift.amount);
+        Ok(gift.amount)
+    }
+
+    /// Called by an escrow at funding time to pull `amount` of the client's credits into
+    /// the escrow. Gifted credits are spent before refundable ones.
+    pub fn draw(
+        env: Env,
+        escrow: Address,
+        client: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        escrow.require_auth();
+        client.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        if token != config.token {
+            return Err(Error::AssetMismatch);
+        }
+
+        let escrow_id: Option<u32> = env.invoke_contract(
+            &config.escrow_factory,
+            &Symbol::new(&env, FACTORY_GET_ESCROW_ID),
+            (escrow.clone(),).into_val(&env),
+        );
+        if escrow_id.is_none() {
+            return Err(Error::UnknownEscrow);
+        }
+
+        let mut account = get_account(&env, &client);
+        if env.ledger().timestamp() >= account.expires_at {
+            return Err(Error::CreditsExpired);
+        }
+        if account.balance < amount {
+            return Err(Error::InsufficientCredits);
+        }
+        account.balance -= amount;
+        account.refundable = account.refundable.min(account.balance);
+        set_account(&env, &account);
+
+        Self::transfer(
+            &env,
+            &config.token,
+            &env.current_contract_address(),
+            &escrow,
+            amount,
+        );
+        credits_drawn(&env, client, escrow, amount);
+        Ok(account.balance)
+    }
+
+    /// Refund the owner's unexpired, self-purchased credits
+    pub fn refund(env: Env, owner: Address) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        owner.require_auth();
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+
+        let mut account = get_account(&env, &owner);
+        if env.ledger().timestamp() >= account.expires_at {
+            return Err(Error::CreditsExpired);
+        }
+        let amount = account.refundable;
+        if amount <= 0 {
+            return Err(Error::InsufficientCredits);
+        }
+        account.balance -= amount;
+        account.refundable = 0;
+        set_account(&env, &account);
+
+        Self::transfer(
+            &env,
+            &config.token,
+            &env.current_contract_address(),
+            &owner,
+            amount,
+        );
+        credits_refunded(&env, owner, amount);
+        Ok(amount)
+    }
+
+    /// Move an expired balance to the treasury. Anyone can sweep.
+    pub fn sweep_expired(env: Env, owner: Address) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+
+        let mut account = get_account(&env, &owner);
+        if env.ledger().timestamp() < account.expires_at {
+            return Err(Error::CreditsNotExpired);
+        }
+        let amount = account.balance;
+        if amount <= 0 {
+            return Err(Error::InsufficientCredits);
+        }
+        account.balance = 0;
+        account.refundable = 0;
+        set_account(&env, &account);
+
+        Self::transfer(
+            &env,
+            &config.token,
+            &env.current_contract_address(),
+            &config.treasury,
+            amount,
+        );
+        credits_swept(&env, owner, config.treasury, amount);
+        Ok(amount)
+    }
+
+    pub fn get_account(env: Env, owner: Address) -> CreditAccount {
+        get_account(&env, &owner)
+    }
+
+    /// Spendable balance; 0 once the credits have expired
+    pub fn balance(env: Env, owner: Address) -> i128 {
+        let account = get_account(&env, &owner);
+        if env.ledger().timestamp() >= account.expires_at {
+            return 0;
+        }
+        account.balance
+    }
+
+    pub fn get_gift(env: Env, code_hash: BytesN<32>) -> Option<Gift> {
+        get_gift(&env, &code_hash)
+    }
+
+    fn credit(
+        env: &Env,
+        config: &CreditsConfig,
+        owner: &Address,
+        amount: i128,
+        refundable: i128,
+    ) -> Result<CreditAccount, Error> {
+        let mut account = get_account(env, owner);
+        let now = env.ledger().timestamp();
+        if account.balance > 0 && now >= account.expires_at {
+            return Err(Error::CreditsExpired);
+        }
+        account.balance += amount;
+        account.refundable += refundable;
+        account.expires_at = now.saturating_add(config.credit_ttl);
+        set_account(env, &account);
+        Ok(account)
+    }
+
+    fn validate_config(config: &CreditsConfig) -> Result<(), Error> {
+        if config.credit_ttl == 0 {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+        caller.require_auth();
+        let admin = get_admin(env).ok_or(Error::NotInitialized)?;
+        if *caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn transfer(env: &Env, token: &Address, from: &Address, to: &Address, amount: i128) {
+        env.invoke_contract::<()>(
+            token,
+            &Symbol::new(env, TOKEN_TRANSFER),
+            (from.clone(), to.clone(), amount).into_val(env),
+        );
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// Amount must be positive
+    InvalidAmount = 4,
+    /// Credit lifetime must be positive
+    InvalidConfig = 5,
+    /// Balance is lower than the requested amount
+    InsufficientCredits = 6,
+    /// Credits have expired and can only be swept to the treasury
+    CreditsExpired = 7,
+    /// Credits have not expired yet
+    CreditsNotExpired = 8,
+    /// A gift with this code hash already exists
+    GiftExists = 9,
+    /// No unredeemed gift matches the code
+    GiftNotFound = 10,
+    /// Gift has expired and can only be reclaimed by its buyer
+    GiftExpired = 11,
+    /// Gift can still be redeemed
+    GiftNotExpired = 12,
+    /// Escrow was not deployed by the configured factory
+    UnknownEscrow = 13,
+    /// Escrow is funded in a different token
+    AssetMismatch = 14,
+    /// Contract is already paused
+    AlreadyPaused = 15,
+    /// Contract is not paused
+    NotPaused = 16,
+    /// Contract is paused
+    ContractPaused = 17,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
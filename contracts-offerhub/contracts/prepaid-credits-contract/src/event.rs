@@ -0,0 +1,47 @@
+use crate::types::CreditsConfig;
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+pub fn admin_initialized(e: &Env, admin: Address) {
+    let topics = (Symbol::new(e, "admin_initialized"),);
+    e.events().publish(topics, admin);
+}
+
+pub fn config_set(e: &Env, config: CreditsConfig) {
+    let topics = (Symbol::new(e, "credits_config_set"),);
+    e.events().publish(topics, config);
+}
+
+pub fn credits_purchased(e: &Env, owner: Address, amount: i128, expires_at: u64) {
+    let topics = (Symbol::new(e, "credits_purchased"), owner);
+    e.events().publish(topics, (amount, expires_at));
+}
+
+pub fn gift_purchased(e: &Env, buyer: Address, code_hash: BytesN<32>, amount: i128) {
+    let topics = (Symbol::new(e, "gift_purchased"), buyer);
+    e.events().publish(topics, (code_hash, amount));
+}
+
+pub fn gift_redeemed(e: &Env, recipient: Address, code_hash: BytesN<32>, amount: i128) {
+    let topics = (Symbol::new(e, "gift_redeemed"), recipient);
+    e.events().publish(topics, (code_hash, amount));
+}
+
+pub fn gift_reclaimed(e: &Env, buyer: Address, code_hash: BytesN<32>, amount: i128) {
+    let topics = (Symbol::new(e, "gift_reclaimed"), buyer);
+    e.events().publish(topics, (code_hash, amount));
+}
+
+pub fn credits_drawn(e: &Env, owner: Address, escrow: Address, amount: i128) {
+    let topics = (Symbol::new(e, "credits_drawn"), owner, escrow);
+    e.events().publish(topics, amount);
+}
+
+pub fn credits_refunded(e: &Env, owner: Address, amount: i128) {
+    let topics = (Symbol::new(e, "credits_refunded"), owner);
+    e.events().publish(topics, amount);
+}
+
+pub fn credits_swept(e: &Env, owner: Address, treasury: Address, amount: i128) {
+    let topics = (Symbol::new(e, "credits_swept"), owner, treasury);
+    e.events().publish(topics, amount);
+}
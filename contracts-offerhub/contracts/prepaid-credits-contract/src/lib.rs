@@ -0,0 +1,100 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::PrepaidCreditsContract;
+pub use crate::error::Error;
+pub use crate::types::{CreditAccount, CreditsConfig, Gift};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| {
+    PrepaidCreditsContract::pause_admin(env)
+});
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the contract with an admin address and configuration
+    pub fn initialize(env: Env, admin: Address, config: CreditsConfig) -> Result<(), Error> {
+        PrepaidCreditsContract::initialize(env, admin, config)
+    }
+
+    /// Update the configuration (admin only)
+    pub fn set_config(env: Env, admin: Address, config: CreditsConfig) -> Result<(), Error> {
+        PrepaidCreditsContract::set_config(env, admin, config)
+    }
+
+    /// Get the configuration
+    pub fn get_config(env: Env) -> Option<CreditsConfig> {
+        PrepaidCreditsContract::get_config(env)
+    }
+
+    /// Buy prepaid credits for yourself
+    pub fn purchase(env: Env, buyer: Address, amount: i128) -> Result<CreditAccount, Error> {
+        PrepaidCreditsContract::purchase(env, buyer, amount)
+    }
+
+    /// Buy credits as a gift redeemable with the code behind `code_hash`
+    pub fn purchase_gift(
+        env: Env,
+        buyer: Address,
+        amount: i128,
+        code_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        PrepaidCreditsContract::purchase_gift(env, buyer, amount, code_hash)
+    }
+
+    /// Redeem a gift code into the recipient's balance
+    pub fn redeem_gift(env: Env, recipient: Address, code: Bytes) -> Result<CreditAccount, Error> {
+        PrepaidCreditsContract::redeem_gift(env, recipient, code)
+    }
+
+    /// Return an expired, unredeemed gift to its buyer
+    pub fn reclaim_gift(env: Env, buyer: Address, code_hash: BytesN<32>) -> Result<i128, Error> {
+        PrepaidCreditsContract::reclaim_gift(env, buyer, code_hash)
+    }
+
+    /// Move a client's credits into an escrow at funding time (escrows only)
+    pub fn draw(
+        env: Env,
+        escrow: Address,
+        client: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        PrepaidCreditsContract::draw(env, escrow, client, token, amount)
+    }
+
+    /// Refund unexpired, self-purchased credits
+    pub fn refund(env: Env, owner: Address) -> Result<i128, Error> {
+        PrepaidCreditsContract::refund(env, owner)
+    }
+
+    /// Sweep an expired balance to the treasury
+    pub fn sweep_expired(env: Env, owner: Address) -> Result<i128, Error> {
+        PrepaidCreditsContract::sweep_expired(env, owner)
+    }
+
+    /// Get an owner's credit account
+    pub fn get_account(env: Env, owner: Address) -> CreditAccount {
+        PrepaidCreditsContract::get_account(env, owner)
+    }
+
+    /// Get an owner's spendable balance
+    pub fn balance(env: Env, owner: Address) -> i128 {
+        PrepaidCreditsContract::balance(env, owner)
+    }
+
+    /// Get an unredeemed gift by its code hash
+    pub fn get_gift(env: Env, code_hash: BytesN<32>) -> Option<Gift> {
+        PrepaidCreditsContract::get_gift(env, code_hash)
+    }
+}
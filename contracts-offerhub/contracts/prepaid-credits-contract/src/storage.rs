@@ -0,0 +1,65 @@
+use crate::types::{CreditAccount, CreditsConfig, Gift};
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Config,
+    Account(Address),
+    Gift(BytesN<32>),
+}
+
+/// Get the admin address
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_config(env: &Env) -> Option<CreditsConfig> {
+    env.storage().instance().get(&DataKey::Config)
+}
+
+pub fn set_config(env: &Env, config: &CreditsConfig) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+pub fn get_account(env: &Env, owner: &Address) -> CreditAccount {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Account(owner.clone()))
+        .unwrap_or(CreditAccount {
+            owner: owner.clone(),
+            balance: 0,
+            refundable: 0,
+            expires_at: 0,
+        })
+}
+
+pub fn set_account(env: &Env, account: &CreditAccount) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Account(account.owner.clone()), account);
+}
+
+pub fn get_gift(env: &Env, code_hash: &BytesN<32>) -> Option<Gift> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Gift(code_hash.clone()))
+}
+
+pub fn set_gift(env: &Env, gift: &Gift) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Gift(gift.code_hash.clone()), gift);
+}
+
+pub fn remove_gift(env: &Env, code_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Gift(code_hash.clone()));
+}
@@ -0,0 +1,177 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+
+const TTL: u64 = 90 * 24 * 60 * 60;
+
+#[contract]
+pub struct MockEscrowFactory;
+
+#[contractimpl]
+impl MockEscrowFactory {
+    pub fn add_escrow(env: Env, escrow: Address, id: u32) {
+        env.storage().instance().set(&escrow, &id);
+    }
+
+    pub fn get_escrow_id_by_address(env: Env, escrow_address: Address) -> Option<u32> {
+        env.storage().instance().get(&escrow_address)
+    }
+}
+
+struct Setup<'a> {
+    credits: ContractClient<'a>,
+    admin: Address,
+    token: TokenClient<'a>,
+    factory: MockEscrowFactoryClient<'a>,
+    treasury: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let factory_id = env.register(MockEscrowFactory, ());
+    let treasury = Address::generate(env);
+    let credits = ContractClient::new(env, &env.register(Contract, ()));
+    let admin = Address::generate(env);
+    credits.initialize(
+        &admin,
+        &CreditsConfig {
+            token: token.clone(),
+            escrow_factory: factory_id.clone(),
+            treasury: treasury.clone(),
+            credit_ttl: TTL,
+        },
+    );
+    Setup {
+        credits,
+        admin,
+        token: TokenClient::new(env, &token),
+        factory: MockEscrowFactoryClient::new(env, &factory_id),
+        treasury,
+    }
+}
+
+fn fund(env: &Env, setup: &Setup, user: &Address, amount: i128) {
+    StellarAssetClient::new(env, &setup.token.address).mint(user, &amount);
+}
+
+#[test]
+fn test_gift_redeem_and_escrow_draw() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let buyer = Address::generate(&env);
+    let client = Address::generate(&env);
+    fund(&env, &setup, &buyer, 500);
+    fund(&env, &setup, &client, 300);
+
+    let code = Bytes::from_slice(&env, b"HAPPY-BIRTHDAY");
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+    setup.credits.purchase_gift(&buyer, &500, &code_hash);
+    assert_eq!(
+        setup
+            .credits
+            .try_redeem_gift(&client, &Bytes::from_slice(&env, b"WRONG")),
+        Err(Ok(Error::GiftNotFound))
+    );
+    setup.credits.redeem_gift(&client, &code);
+    setup.credits.purchase(&client, &300);
+
+    let account = setup.credits.get_account(&client);
+    assert_eq!(account.balance, 800);
+    assert_eq!(account.refundable, 300);
+
+    let escrow = Address::generate(&env);
+    assert_eq!(
+        setup
+            .credits
+            .try_draw(&escrow, &client, &setup.token.address, &100),
+        Err(Ok(Error::UnknownEscrow))
+    );
+    setup.factory.add_escrow(&escrow, &1);
+    assert_eq!(
+        setup
+            .credits
+            .try_draw(&escrow, &client, &Address::generate(&env), &100),
+        Err(Ok(Error::AssetMismatch))
+    );
+
+    // Gifted credits are used up first, so the purchased ones stay refundable
+    assert_eq!(
+        setup
+            .credits
+            .draw(&escrow, &client, &setup.token.address, &600),
+        200
+    );
+    assert_eq!(setup.token.balance(&escrow), 600);
+    assert_eq!(setup.credits.refund(&client), 200);
+    assert_eq!(setup.token.balance(&client), 200);
+}
+
+#[test]
+fn test_expired_credits_and_gifts() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let buyer = Address::generate(&env);
+    fund(&env, &setup, &buyer, 1_000);
+
+    let code_hash = BytesN::from_array(&env, &[7; 32]);
+    setup.credits.purchase_gift(&buyer, &400, &code_hash);
+    setup.credits.purchase(&buyer, &600);
+    assert_eq!(
+        setup.credits.try_reclaim_gift(&buyer, &code_hash),
+        Err(Ok(Error::GiftNotExpired))
+    );
+    assert_eq!(
+        setup.credits.try_sweep_expired(&buyer),
+        Err(Ok(Error::CreditsNotExpired))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000 + TTL);
+    assert_eq!(setup.credits.balance(&buyer), 0);
+    assert_eq!(
+        setup.credits.try_refund(&buyer),
+        Err(Ok(Error::CreditsExpired))
+    );
+    assert_eq!(setup.credits.reclaim_gift(&buyer, &code_hash), 400);
+    assert_eq!(setup.credits.sweep_expired(&buyer), 600);
+
+    assert_eq!(setup.token.balance(&buyer), 400);
+    assert_eq!(setup.token.balance(&setup.treasury), 600);
+}
+
+#[test]
+fn test_pause_blocks_purchases_and_draws() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let client = Address::generate(&env);
+    let escrow = Address::generate(&env);
+    setup.factory.add_escrow(&escrow, &1);
+    fund(&env, &setup, &client, 300);
+    setup.credits.purchase(&client, &200);
+
+    setup.credits.pause(&setup.admin);
+    assert_eq!(
+        setup.credits.try_purchase(&client, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        setup
+            .credits
+            .try_draw(&escrow, &client, &setup.token.address, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    setup.credits.unpause(&setup.admin);
+    assert_eq!(
+        setup
+            .credits
+            .draw(&escrow, &client, &setup.token.address, &100),
+        100
+    );
+}
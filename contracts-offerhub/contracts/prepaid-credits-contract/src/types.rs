@@ -0,0 +1,34 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreditsConfig {
+    pub token: Address,
+    /// Factory whose escrows may draw credits at funding time
+    pub escrow_factory: Address,
+    /// Receives credits swept after expiry
+    pub treasury: Address,
+    /// Seconds credits stay usable after the latest top-up
+    pub credit_ttl: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreditAccount {
+    pub owner: Address,
+    pub balance: i128,
+    /// Part of `balance` the owner bought itself and may refund; redeemed gifts are
+    /// spendable but not refundable
+    pub refundable: i128,
+    pub expires_at: u64,
+}
+
+/// Credits bought for someone else, claimable by whoever presents the code
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Gift {
+    pub code_hash: BytesN<32>,
+    pub buyer: Address,
+    pub amount: i128,
+    pub expires_at: u64,
+}
@@ -0,0 +1,190 @@
+use crate::error::Error;
+use crate::event::{
+    featured_set, profile_burned, profile_initialized, profile_minted, profile_refreshed,
+};
+use crate::storage::{
+    get_admin, get_config, get_owner, get_profile, next_token_id, remove_profile, set_admin,
+    set_config, set_profile,
+};
+use crate::types::{Profile, ProfileConfig, MAX_FEATURED};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
+
+const REGISTRY_IS_VERIFIED: &str = "is_verified";
+const REGISTRY_GET_LEVEL: &str = "get_verification_level";
+const ORACLE_GET_TIER: &str = "get_tier";
+const NFT_GET_OWNER: &str = "get_owner";
+
+pub struct ProfileNftContract;
+
+impl ProfileNftContract {
+    pub fn initialize(env: Env, admin: Address, config: ProfileConfig) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        set_config(&env, &config);
+        profile_initialized(&env, admin);
+        Ok(())
+    }
+
+    pub fn get_config(env: Env) -> Result<ProfileConfig, Error> {
+        get_config(&env).ok_or(Error::NotInitialized)
+    }
+
+    /// Mint the user's profile token. Only registry-verified users can mint, and each
+    /// user holds at most one.
+    pub fn mint(env: Env, user: Address) -> Result<Profile, Error> {
+        Self::require_not_paused(&env)?;
+        user.require_auth();
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+
+        if get_profile(&env, &user).is_some() {
+            return Err(Error::ProfileExists);
+        }
+        let verified: bool = env.invoke_contract(
+            &config.user_registry,
+            &Symbol::new(&env, REGISTRY_IS_VERIFIED),
+            (user.clone(),).into_val(&env),
+        );
+        if !verified {
+            return Err(Error::NotVerified);
+        }
+
+        let now = env.ledger().timestamp();
+        let profile = Profile {
+            token_id: next_token_id(&env),
+            owner: user.clone(),
+            verification_level: 0,
+            reputation_tier: 0,
+            featured: Vec::new(&env),
+            minted_at: now,
+            refreshed_at: now,
+        };
+        profile_minted(&env, user, profile.token_id);
+        Ok(Self::update_profile(&env, &config, profile))
+    }
+
+    /// Pull the owner's current verification level and reputation tier, and drop featured
+    /// achievements the owner no longer holds. Anyone can refresh a profile.
+    pub fn refresh(env: Env, user: Address) -> Result<Profile, Error> {
+        Self::require_not_paused(&env)?;
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        let profile = get_profile(&env, &user).ok_or(Error::ProfileNotFound)?;
+        Ok(Self::update_profile(&env, &config, profile))
+    }
+
+    /// Choose which reputation NFT achievements the profile shows, in display order
+    pub fn set_featured(env: Env, user: Address, featured: Vec<u64>) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        user.require_auth();
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        let mut profile = get_profile(&env, &user).ok_or(Error::ProfileNotFound)?;
+
+        if featured.len() > MAX_FEATURED {
+            return Err(Error::TooManyFeatured);
+        }
+        for token_id in featured.iter() {
+            if !Self::owns_achievement(&env, &config, &user, token_id) {
+                return Err(Error::AchievementNotOwned);
+            }
+        }
+
+        profile.featured = featured.clone();
+        set_profile(&env, &profile);
+        featured_set(&env, user, featured);
+        Ok(())
+    }
+
+    pub fn get_profile(env: Env, user: Address) -> Result<Profile, Error> {
+        get_profile(&env, &user).ok_or(Error::ProfileNotFound)
+    }
+
+    pub fn owner_of(env: Env, token_id: u32) -> Result<Address, Error> {
+        get_owner(&env, token_id).ok_or(Error::ProfileNotFound)
+    }
+
+    /// Profile tokens are soulbound
+    pub fn transfer(_env: Env, from: Address, _to: Address, _token_id: u32) -> Result<(), Error> {
+        from.require_auth();
+        Err(Error::NonTransferable)
+    }
+
+    /// Burn a profile token (its owner or the admin)
+    pub fn burn(env: Env, caller: Address, user: Address) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        caller.require_auth();
+        if caller != user && Some(caller.clone()) != get_admin(&env) {
+            return Err(Error::Unauthorized);
+        }
+
+        let profile = get_profile(&env, &user).ok_or(Error::ProfileNotFound)?;
+        remove_profile(&env, &profile);
+        profile_burned(&env, user, profile.token_id);
+        Ok(())
+    }
+
+    /// Refresh a profile's aggregated fields from the registry, oracle and NFT contract.
+    /// The tier is left as it was if the oracle refuses the query, e.g. when this
+    /// contract's rate limit is used up.
+    fn update_profile(env: &Env, config: &ProfileConfig, mut profile: Profile) -> Profile {
+        let level: Option<u32> = env.invoke_contract(
+            &config.user_registry,
+            &Symbol::new(env, REGISTRY_GET_LEVEL),
+            (profile.owner.clone(),).into_val(env),
+        );
+        let verified: bool = env.invoke_contract(
+            &config.user_registry,
+            &Symbol::new(env, REGISTRY_IS_VERIFIED),
+            (profile.owner.clone(),).into_val(env),
+        );
+        profile.verification_level = if verified { level.unwrap_or(0) } else { 0 };
+
+        if let Ok(Ok(tier)) = env.try_invoke_contract::<u32, Error>(
+            &config.reputation_oracle,
+            &Symbol::new(env, ORACLE_GET_TIER),
+            (env.current_contract_address(), profile.owner.clone()).into_val(env),
+        ) {
+            profile.reputation_tier = tier;
+        }
+
+        let mut featured = Vec::new(env);
+        for token_id in profile.featured.iter() {
+            if Self::owns_achievement(env, config, &profile.owner, token_id) {
+                featured.push_back(token_id);
+            }
+        }
+        profile.featured = featured;
+        profile.refreshed_at = env.ledger().timestamp();
+
+        set_profile(env, &profile);
+        profile_refreshed(
+            env,
+            profile.owner.clone(),
+            profile.verification_level,
+            profile.reputation_tier,
+        );
+        profile
+    }
+
+    fn owns_achievement(env: &Env, config: &ProfileConfig, user: &Address, token_id: u64) -> bool {
+        let owner = env.try_invoke_contract::<Address, Error>(
+            &config.reputation_nft,
+            &Symbol::new(env, NFT_GET_OWNER),
+            (token_id,).into_val(env),
+        );
+        matches!(owner, Ok(Ok(owner)) if owner == *user)
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// User is not verified in the user registry
+    NotVerified = 4,
+    /// User already holds a profile token
+    ProfileExists = 5,
+    /// User holds no profile token
+    ProfileNotFound = 6,
+    /// Profile tokens cannot be transferred
+    NonTransferable = 7,
+    /// Too many featured achievements
+    TooManyFeatured = 8,
+    /// Featured achievement is not owned by the user
+    AchievementNotOwned = 9,
+    /// Contract is already paused
+    AlreadyPaused = 10,
+    /// Contract is not paused
+    NotPaused = 11,
+    /// Contract is paused
+    ContractPaused = 12,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
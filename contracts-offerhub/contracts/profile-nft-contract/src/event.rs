@@ -0,0 +1,27 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+pub fn profile_initialized(e: &Env, admin: Address) {
+    let topics = (Symbol::new(e, "profile_initialized"),);
+    e.events().publish(topics, admin);
+}
+
+pub fn profile_minted(e: &Env, owner: Address, token_id: u32) {
+    let topics = (Symbol::new(e, "profile_minted"), owner);
+    e.events().publish(topics, token_id);
+}
+
+pub fn profile_refreshed(e: &Env, owner: Address, verification_level: u32, reputation_tier: u32) {
+    let topics = (Symbol::new(e, "profile_refreshed"), owner);
+    e.events()
+        .publish(topics, (verification_level, reputation_tier));
+}
+
+pub fn featured_set(e: &Env, owner: Address, featured: Vec<u64>) {
+    let topics = (Symbol::new(e, "featured_set"), owner);
+    e.events().publish(topics, featured);
+}
+
+pub fn profile_burned(e: &Env, owner: Address, token_id: u32) {
+    let topics = (Symbol::new(e, "profile_burned"), owner);
+    e.events().publish(topics, token_id);
+}
@@ -0,0 +1,67 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::ProfileNftContract;
+pub use crate::error::Error;
+pub use crate::types::{Profile, ProfileConfig, MAX_FEATURED};
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| ProfileNftContract::pause_admin(env));
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the contract with an admin and the contracts profiles aggregate
+    pub fn initialize(env: Env, admin: Address, config: ProfileConfig) -> Result<(), Error> {
+        ProfileNftContract::initialize(env, admin, config)
+    }
+
+    /// Get the contracts profiles aggregate
+    pub fn get_config(env: Env) -> Result<ProfileConfig, Error> {
+        ProfileNftContract::get_config(env)
+    }
+
+    /// Mint the caller's profile token (verified users only, one per user)
+    pub fn mint(env: Env, user: Address) -> Result<Profile, Error> {
+        ProfileNftContract::mint(env, user)
+    }
+
+    /// Update a profile's verification level, reputation tier and featured achievements
+    pub fn refresh(env: Env, user: Address) -> Result<Profile, Error> {
+        ProfileNftContract::refresh(env, user)
+    }
+
+    /// Choose the achievements a profile features (profile owner only)
+    pub fn set_featured(env: Env, user: Address, featured: Vec<u64>) -> Result<(), Error> {
+        ProfileNftContract::set_featured(env, user, featured)
+    }
+
+    /// Get a user's profile
+    pub fn get_profile(env: Env, user: Address) -> Result<Profile, Error> {
+        ProfileNftContract::get_profile(env, user)
+    }
+
+    /// Get the owner of a profile token
+    pub fn owner_of(env: Env, token_id: u32) -> Result<Address, Error> {
+        ProfileNftContract::owner_of(env, token_id)
+    }
+
+    /// Always fails: profile tokens are non-transferable
+    pub fn transfer(env: Env, from: Address, to: Address, token_id: u32) -> Result<(), Error> {
+        ProfileNftContract::transfer(env, from, to, token_id)
+    }
+
+    /// Burn a profile token (its owner or the admin)
+    pub fn burn(env: Env, caller: Address, user: Address) -> Result<(), Error> {
+        ProfileNftContract::burn(env, caller, user)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::types::{Profile, ProfileConfig};
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Config,
+    NextTokenId,
+    Profile(Address),
+    /// token id -> owner
+    Owner(u32),
+}
+
+/// Get the admin address
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_config(env: &Env) -> Option<ProfileConfig> {
+    env.storage().instance().get(&DataKey::Config)
+}
+
+pub fn set_config(env: &Env, config: &ProfileConfig) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+/// Allocate the next token id, starting at 1
+pub fn next_token_id(env: &Env) -> u32 {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u32>(&DataKey::NextTokenId)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&DataKey::NextTokenId, &id);
+    id
+}
+
+pub fn get_profile(env: &Env, owner: &Address) -> Option<Profile> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Profile(owner.clone()))
+}
+
+pub fn set_profile(env: &Env, profile: &Profile) {
+    let storage = env.storage().persistent();
+    storage.set(&DataKey::Profile(profile.owner.clone()), profile);
+    storage.set(&DataKey::Owner(profile.token_id), &profile.owner);
+}
+
+pub fn remove_profile(env: &Env, profile: &Profile) {
+    let storage = env.storage().persistent();
+    storage.remove(&DataKey::Profile(profile.owner.clone()));
+    storage.remove(&DataKey::Owner(profile.token_id));
+}
+
+pub fn get_owner(env: &Env, token_id: u32) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::Owner(token_id))
+}
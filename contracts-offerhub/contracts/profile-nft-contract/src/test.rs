@@ -0,0 +1,174 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, symbol_short, vec, Address, Env};
+
+#[contract]
+pub struct MockUserRegistry;
+
+#[contractimpl]
+impl MockUserRegistry {
+    pub fn set_level(env: Env, user: Address, level: u32) {
+        env.storage().instance().set(&user, &level);
+    }
+
+    pub fn is_verified(env: Env, user: Address) -> bool {
+        env.storage().instance().get::<_, u32>(&user).unwrap_or(0) > 0
+    }
+
+    pub fn get_verification_level(env: Env, user: Address) -> Option<u32> {
+        env.storage().instance().get(&user)
+    }
+}
+
+#[contract]
+pub struct MockReputationOracle;
+
+#[contractimpl]
+impl MockReputationOracle {
+    pub fn set_tier(env: Env, tier: u32) {
+        env.storage().instance().set(&symbol_short!("tier"), &tier);
+    }
+
+    pub fn get_tier(env: Env, consumer: Address, _user: Address) -> u32 {
+        consumer.require_auth();
+        env.storage()
+            .instance()
+            .get(&symbol_short!("tier"))
+            .unwrap_or(0)
+    }
+}
+
+#[contract]
+pub struct MockReputationNft;
+
+#[contractimpl]
+impl MockReputationNft {
+    pub fn set_owner(env: Env, token_id: u64, owner: Address) {
+        env.storage().instance().set(&token_id, &owner);
+    }
+
+    pub fn get_owner(env: Env, token_id: u64) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&token_id)
+            .ok_or(Error::AchievementNotOwned)
+    }
+}
+
+struct Setup<'a> {
+    client: ContractClient<'a>,
+    registry: MockUserRegistryClient<'a>,
+    oracle: MockReputationOracleClient<'a>,
+    nft: MockReputationNftClient<'a>,
+    admin: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let registry = MockUserRegistryClient::new(env, &env.register(MockUserRegistry, ()));
+    let oracle = MockReputationOracleClient::new(env, &env.register(MockReputationOracle, ()));
+    let nft = MockReputationNftClient::new(env, &env.register(MockReputationNft, ()));
+    let client = ContractClient::new(env, &env.register(Contract, ()));
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &ProfileConfig {
+            user_registry: registry.address.clone(),
+            reputation_nft: nft.address.clone(),
+            reputation_oracle: oracle.address.clone(),
+        },
+    );
+    Setup {
+        client,
+        registry,
+        oracle,
+        nft,
+        admin,
+    }
+}
+
+#[test]
+fn test_one_soulbound_profile_per_verified_user() {
+    let env = Env::default();
+    let s = setup(&env);
+    let user = Address::generate(&env);
+
+    assert_eq!(s.client.try_mint(&user), Err(Ok(Error::NotVerified)));
+
+    s.registry.set_level(&user, &2);
+    s.oracle.set_tier(&3);
+    let profile = s.client.mint(&user);
+    assert_eq!(profile.token_id, 1);
+    assert_eq!(profile.verification_level, 2);
+    assert_eq!(profile.reputation_tier, 3);
+    assert_eq!(s.client.owner_of(&1), user);
+    assert_eq!(s.client.try_mint(&user), Err(Ok(Error::ProfileExists)));
+
+    assert_eq!(
+        s.client.try_transfer(&user, &Address::generate(&env), &1),
+        Err(Ok(Error::NonTransferable))
+    );
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        s.client.try_burn(&stranger, &user),
+        Err(Ok(Error::Unauthorized))
+    );
+    s.client.burn(&s.admin, &user);
+    assert_eq!(
+        s.client.try_get_profile(&user),
+        Err(Ok(Error::ProfileNotFound))
+    );
+    assert_eq!(s.client.try_owner_of(&1), Err(Ok(Error::ProfileNotFound)));
+}
+
+#[test]
+fn test_refresh_tracks_registry_oracle_and_achievements() {
+    let env = Env::default();
+    let s = setup(&env);
+    let user = Address::generate(&env);
+    s.registry.set_level(&user, &1);
+    s.client.mint(&user);
+
+    s.nft.set_owner(&10, &user);
+    s.nft.set_owner(&11, &user);
+    s.nft.set_owner(&12, &Address::generate(&env));
+    assert_eq!(
+        s.client.try_set_featured(&user, &vec![&env, 10, 12]),
+        Err(Ok(Error::AchievementNotOwned))
+    );
+    assert_eq!(
+        s.client
+            .try_set_featured(&user, &vec![&env, 1, 2, 3, 4, 5, 6]),
+        Err(Ok(Error::TooManyFeatured))
+    );
+    s.client.set_featured(&user, &vec![&env, 11, 10]);
+
+    // The user was upgraded, climbed a tier and parted with one featured achievement
+    s.registry.set_level(&user, &3);
+    s.oracle.set_tier(&2);
+    s.nft.set_owner(&11, &Address::generate(&env));
+    let profile = s.client.refresh(&user);
+    assert_eq!(profile.verification_level, 3);
+    assert_eq!(profile.reputation_tier, 2);
+    assert_eq!(profile.featured, vec![&env, 10]);
+
+    s.registry.set_level(&user, &0);
+    assert_eq!(s.client.refresh(&user).verification_level, 0);
+}
+
+#[test]
+fn test_pause_blocks_minting() {
+    let env = Env::default();
+    let s = setup(&env);
+    let user = Address::generate(&env);
+    s.registry.set_level(&user, &2);
+
+    s.client.pause(&s.admin);
+    assert_eq!(s.client.try_mint(&user), Err(Ok(Error::ContractPaused)));
+
+    s.client.unpause(&s.admin);
+    assert_eq!(s.client.mint(&user).token_id, 1);
+}
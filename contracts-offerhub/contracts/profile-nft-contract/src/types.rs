@@ -0,0 +1,28 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+/// Most achievements a profile can feature.
+pub const MAX_FEATURED: u32 = 5;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProfileConfig {
+    pub user_registry: Address,
+    pub reputation_nft: Address,
+    /// Reputation oracle the profile reads tiers from; this contract must be one of its
+    /// registered consumers.
+    pub reputation_oracle: Address,
+}
+
+/// The one profile token a user holds. Levels and tiers are the registry's and oracle's
+/// discriminants, as of `refreshed_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Profile {
+    pub token_id: u32,
+    pub owner: Address,
+    pub verification_level: u32, // 0 once the registry no longer verifies the owner
+    pub reputation_tier: u32,
+    pub featured: Vec<u64>, // Reputation NFT token ids
+    pub minted_at: u64,
+    pub refreshed_at: u64,
+}
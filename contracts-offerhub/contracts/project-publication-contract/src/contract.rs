@@ -39,6 +39,7 @@ impl ProjectPublicationContract {
         project_id: String,
         timestamp: u64,
     ) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
         // Require authentication
         caller.require_auth();
 
@@ -85,4 +86,15 @@ impl ProjectPublicationContract {
     pub fn get_project_record(env: Env, project_id: String) -> Option<ProjectRecord> {
         get_project_record_storage(&env, &project_id)
     }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
 }
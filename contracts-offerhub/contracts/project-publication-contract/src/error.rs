@@ -1,3 +1,4 @@
+use offerhub_pausable::PauseError;
 use soroban_sdk::contracterror;
 
 #[contracterror]
@@ -18,4 +19,20 @@ pub enum Error {
     ProjectAlreadyRecorded = 6,
     /// Invalid timestamp provided
     InvalidTimestamp = 7,
+    /// Contract is already paused
+    AlreadyPaused = 8,
+    /// Contract is not paused
+    NotPaused = 9,
+    /// Contract is paused
+    ContractPaused = 10,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
 }
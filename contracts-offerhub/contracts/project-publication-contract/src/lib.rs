@@ -17,6 +17,10 @@ use soroban_sdk::{contract, contractimpl, Address, Env, String};
 #[contract]
 pub struct Contract;
 
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| {
+    ProjectPublicationContract::pause_admin(env)
+});
+
 #[contractimpl]
 impl Contract {
     /// Initialize the contract with an admin address
@@ -301,3 +301,23 @@ fn test_recorded_at_timestamp() {
     }).unwrap();
     assert!(record.recorded_at >= timestamp);
 }
+
+#[test]
+fn test_pause_blocks_recording() {
+    let test = ProjectPublicationTest::setup();
+    test.contract.initialize(&test.admin);
+    let project_id = String::from_str(&test.env, "project-123");
+    let timestamp = test.env.ledger().timestamp();
+
+    test.contract.pause(&test.admin);
+    assert_eq!(
+        test.contract
+            .try_record_project(&test.admin, &test.client, &project_id, &timestamp),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    test.contract.unpause(&test.admin);
+    test.contract
+        .record_project(&test.admin, &test.client, &project_id, &timestamp);
+    assert!(test.contract.get_project_record(&project_id).is_some());
+}
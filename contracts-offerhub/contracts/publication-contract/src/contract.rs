@@ -35,6 +35,23 @@ impl PublicationContract {
         }
     }
 
+    /// Sets the admin that administers pausing. Publishing does not require it.
+    pub fn initialize_internal(env: Env, admin: Address) -> Result<(), ContractError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(ContractError::AlreadyInitialized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        Ok(())
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, ContractError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ContractError::NotInitialized)
+    }
+
     /// Publishes a new service or project on-chain.
     /// This function must be called before the data is stored in any off-chain database.
     pub fn publish_internal(
@@ -46,6 +63,9 @@ impl PublicationContract {
         amount: i128,
         timestamp: u64,
     ) -> Result<u32, ContractError> {
+        if offerhub_pausable::is_paused(&env) {
+            return Err(ContractError::ContractPaused);
+        }
         user.require_auth();
 
         // Comprehensive input validation
@@ -99,9 +119,6 @@ impl PublicationContract {
         Ok(new_id)
     }
 
-    /// This is a helper function primarily for verification and testing.
-    // Removed duplicate function to avoid compilation error
-
     /// Retrieves a specific publication for a user and checks expiration
     pub fn get_publication_internal(env: Env, user: Address, id: u32) -> Option<PublicationData> {
         let key = DataKey::Publication(user, id);
@@ -1,3 +1,4 @@
+use offerhub_pausable::PauseError;
 use soroban_sdk::contracterror;
 
 #[contracterror]
@@ -17,4 +18,28 @@ pub enum ContractError {
     ValidationError = 4,
     InvalidTimestamp = 5,
     TimestampTooOld = 6,
+
+    /// The admin has already been set
+    AlreadyInitialized = 7,
+
+    /// No admin has been set
+    NotInitialized = 8,
+
+    /// Caller is not authorized to perform this action
+    Unauthorized = 9,
+    AlreadyPaused = 10,
+    NotPaused = 11,
+
+    /// Publishing is paused
+    ContractPaused = 12,
+}
+
+impl From<PauseError> for ContractError {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => ContractError::Unauthorized,
+            PauseError::AlreadyPaused => ContractError::AlreadyPaused,
+            PauseError::NotPaused => ContractError::NotPaused,
+        }
+    }
 }
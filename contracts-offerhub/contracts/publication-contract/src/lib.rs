@@ -20,8 +20,17 @@ use soroban_sdk::{contract, contractimpl, Address, Env, String, Symbol};
 #[contract]
 pub struct Contract;
 
+offerhub_pausable::impl_pausable!(Contract, ContractError, |env, _| {
+    PublicationContract::pause_admin(env)
+});
+
 #[contractimpl]
 impl Contract {
+    /// Sets the admin that can pause publishing.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), ContractError> {
+        PublicationContract::initialize_internal(env, admin)
+    }
+
     /// Publishes a new service or project on-chain.
     pub fn publish(
         env: Env,
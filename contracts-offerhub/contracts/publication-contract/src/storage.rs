@@ -18,4 +18,6 @@ pub struct PublicationData {
 pub enum DataKey {
     UserPostCount(Address),
     Publication(Address, u32),
+    /// Administers pausing; publishing works without it
+    Admin,
 }
@@ -339,3 +339,32 @@ fn test_expiration_triggers_expired_state() {
     let updated_publication = test.contract.get_publication(&user.clone(), &id).unwrap();
     assert_eq!(updated_publication.state, PublicationState::Expired);
 }
+
+#[test]
+fn test_pause_blocks_publishing() {
+    let test = PublicationTest::setup();
+    let client = ContractClient::new(&test.env, &test.contract.address);
+    let admin = Address::generate(&test.env);
+    let pub_type = Symbol::new(&test.env, "service");
+    let title = String::from_str(&test.env, "Build a Website");
+    let category = String::from_str(&test.env, "Web Development");
+    let timestamp = test.env.ledger().timestamp();
+
+    // Pausing needs an admin
+    assert_eq!(
+        client.try_pause(&admin),
+        Err(Ok(ContractError::NotInitialized))
+    );
+    client.initialize(&admin);
+    client.pause(&admin);
+    assert_eq!(
+        client.try_publish(&test.user1, &pub_type, &title, &category, &1000, &timestamp),
+        Err(Ok(ContractError::ContractPaused))
+    );
+
+    client.unpause(&admin);
+    assert_eq!(
+        client.publish(&test.user1, &pub_type, &title, &category, &1000, &timestamp),
+        1
+    );
+}
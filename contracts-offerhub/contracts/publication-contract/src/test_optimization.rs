@@ -164,7 +164,4 @@ fn test_performance_improvements() {
     for _ in 0..50 {
         let _compressed = StorageOptimizer::compress_string(&env, &test_string);
     }
-
-    // If we reach here, performance is acceptable
-    assert!(true);
 }
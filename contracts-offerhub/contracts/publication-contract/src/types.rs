@@ -15,14 +15,14 @@ pub enum PublicationState {
 impl PublicationState {
     pub fn can_transition_to(&self, next: &PublicationState) -> bool {
         use PublicationState::*;
-        match (self, next) {
-            (Draft, Published) => true,
-            (Draft, Cancelled) => true,
-            (Published, InProgress) => true,
-            (Published, Cancelled) => true,
-            (InProgress, Completed) => true,
-            (InProgress, Cancelled) => true,
-            _ => false,
-        }
+        matches!(
+            (self, next),
+            (Draft, Published)
+                | (Draft, Cancelled)
+                | (Published, InProgress)
+                | (Published, Cancelled)
+                | (InProgress, Completed)
+                | (InProgress, Cancelled)
+        )
     }
 }
\ No newline at end of file
@@ -18,7 +18,7 @@ impl ValidationHelpers {
 
     /// Validates string length is within specified bounds
     pub fn validate_string_length(text: &String, min: u32, max: u32) -> bool {
-        let len = text.len() as u32;
+        let len = text.len();
         len >= min && len <= max
     }
 
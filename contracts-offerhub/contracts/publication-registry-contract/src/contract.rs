@@ -0,0 +1,228 @@
+use crate::error::Error;
+use crate::event::{
+    admin_initialized, publication_created, publication_state_changed, requirements_set,
+};
+use crate::storage::{
+    get_admin, get_category_count, get_category_entry, get_owner_count, get_owner_entry,
+    get_publication, get_requirements, index_publication, next_publication_id, set_admin,
+    set_publication, set_requirements,
+};
+use crate::types::{Publication, PublicationState, PublishingRequirements};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, String, Symbol, Vec};
+
+const MAX_METADATA_URI_LENGTH: u32 = 256;
+const MAX_PAGE_SIZE: u32 = 50;
+
+const REGISTRY_REQUIRE_MIN_VERIFICATION: &str = "require_min_verification";
+const REPUTATION_SCORE: &str = "get_user_reputation_score";
+
+pub struct PublicationRegistryContract;
+
+impl PublicationRegistryContract {
+    /// Initialize the contract with an admin address
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        admin_initialized(&env, admin);
+        Ok(())
+    }
+
+    /// Set the verification and reputation an owner needs to create publications
+    pub fn set_requirements(
+        env: Env,
+        admin: Address,
+        requirements: PublishingRequirements,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        set_requirements(&env, &requirements);
+        requirements_set(&env, requirements);
+        Ok(())
+    }
+
+    pub fn get_requirements(env: Env) -> Option<PublishingRequirements> {
+        get_requirements(&env)
+    }
+
+    /// Create a publication in the Draft state. The owner must meet the publishing
+    /// requirements, which are checked against the user registry and reputation contract.
+    pub fn create_publication(
+        env: Env,
+        owner: Address,
+        title_hash: BytesN<32>,
+        category: Symbol,
+        price: i128,
+        metadata_uri: String,
+    ) -> Result<u64, Error> {
+        Self::require_not_paused(&env)?;
+        owner.require_auth();
+        if get_admin(&env).is_none() {
+            return Err(Error::NotInitialized);
+        }
+        if price < 0 {
+            return Err(Error::InvalidPrice);
+        }
+        if metadata_uri.is_empty() || metadata_uri.len() > MAX_METADATA_URI_LENGTH {
+            return Err(Error::InvalidMetadataUri);
+        }
+        Self::check_requirements(&env, &owner)?;
+
+        let now = env.ledger().timestamp();
+        let publication = Publication {
+            id: next_publication_id(&env),
+            owner: owner.clone(),
+            title_hash,
+            category: category.clone(),
+            price,
+            metadata_uri,
+            state: PublicationState::Draft,
+            created_at: now,
+            updated_at: now,
+        };
+        set_publication(&env, &publication);
+        index_publication(&env, &publication);
+
+        publication_created(&env, owner, publication.id, category, price);
+        Ok(publication.id)
+    }
+
+    /// Open a draft or paused publication to applicants
+    pub fn activate_publication(env: Env, owner: Address, id: u64) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        Self::transition(&env, &owner, id, PublicationState::Active)
+    }
+
+    /// Temporarily stop taking applicants
+    pub fn pause_publication(env: Env, owner: Address, id: u64) -> Result<(), Error> {
+        Self::transition(&env, &owner, id, PublicationState::Paused)
+    }
+
+    /// Close a publication for good
+    pub fn close_publication(env: Env, owner: Address, id: u64) -> Result<(), Error> {
+        Self::transition(&env, &owner, id, PublicationState::Closed)
+    }
+
+    pub fn get_publication(env: Env, id: u64) -> Option<Publication> {
+        get_publication(&env, id)
+    }
+
+    /// Page through an owner's publications, oldest first
+    pub fn get_publications_by_owner(
+        env: Env,
+        owner: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Publication> {
+        let end = Self::page_end(offset, limit, get_owner_count(&env, &owner));
+        let mut publications = Vec::new(&env);
+        for position in offset..end {
+            if let Some(publication) =
+                get_owner_entry(&env, &owner, position).and_then(|id| get_publication(&env, id))
+            {
+                publications.push_back(publication);
+            }
+        }
+        publications
+    }
+
+    /// Page through the publications in a category, oldest first. Every state is listed so
+    /// pages stay stable; callers filter on `state`.
+    pub fn get_publications_by_category(
+        env: Env,
+        category: Symbol,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Publication> {
+        let end = Self::page_end(offset, limit, get_category_count(&env, &category));
+        let mut publications = Vec::new(&env);
+        for position in offset..end {
+            if let Some(publication) = get_category_entry(&env, &category, position)
+                .and_then(|id| get_publication(&env, id))
+            {
+                publications.push_back(publication);
+            }
+        }
+        publications
+    }
+
+    fn transition(
+        env: &Env,
+        owner: &Address,
+        id: u64,
+        next: PublicationState,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        let mut publication = get_publication(env, id).ok_or(Error::PublicationNotFound)?;
+        if publication.owner != *owner {
+            return Err(Error::Unauthorized);
+        }
+        if !publication.state.can_transition_to(&next) {
+            return Err(Error::InvalidStateTransition);
+        }
+
+        let previous = publication.state;
+        publication.state = next;
+        publication.updated_at = env.ledger().timestamp();
+        set_publication(env, &publication);
+
+        publication_state_changed(env, owner.clone(), id, previous, next);
+        Ok(())
+    }
+
+    fn check_requirements(env: &Env, owner: &Address) -> Result<(), Error> {
+        let Some(requirements) = get_requirements(env) else {
+            return Ok(());
+        };
+
+        if let Some(user_registry) = requirements.user_registry {
+            let verified = env.try_invoke_contract::<(), Error>(
+                &user_registry,
+                &Symbol::new(env, REGISTRY_REQUIRE_MIN_VERIFICATION),
+                (owner.clone(), requirements.min_verification_level).into_val(env),
+            );
+            if !matches!(verified, Ok(Ok(()))) {
+                return Err(Error::InsufficientVerification);
+            }
+        }
+
+        if let Some(reputation_contract) = requirements.reputation_contract {
+            let score: u32 = env.invoke_contract(
+                &reputation_contract,
+                &Symbol::new(env, REPUTATION_SCORE),
+                (owner.clone(),).into_val(env),
+            );
+            if score < requirements.min_reputation_score {
+                return Err(Error::InsufficientReputation);
+            }
+        }
+        Ok(())
+    }
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+        caller.require_auth();
+        let admin = get_admin(env).ok_or(Error::NotInitialized)?;
+        if *caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn page_end(offset: u32, limit: u32, count: u32) -> u32 {
+        offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count)
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
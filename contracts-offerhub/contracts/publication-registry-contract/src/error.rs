@@ -0,0 +1,42 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// Publication not found
+    PublicationNotFound = 4,
+    /// Price must not be negative
+    InvalidPrice = 5,
+    /// Metadata URI is empty or too long
+    InvalidMetadataUri = 6,
+    /// The publication can't move to the requested state
+    InvalidStateTransition = 7,
+    /// Owner lacks the verification level required to publish
+    InsufficientVerification = 8,
+    /// Owner's reputation score is below the minimum required to publish
+    InsufficientReputation = 9,
+    /// Contract is already paused
+    AlreadyPaused = 10,
+    /// Contract is not paused
+    NotPaused = 11,
+    /// Contract is paused
+    ContractPaused = 12,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::types::{PublicationState, PublishingRequirements};
+use soroban_sdk::{Address, Env, Symbol};
+
+pub fn admin_initialized(e: &Env, admin: Address) {
+    let topics = (Symbol::new(e, "admin_initialized"),);
+    e.events().publish(topics, admin);
+}
+
+pub fn requirements_set(e: &Env, requirements: PublishingRequirements) {
+    let topics = (Symbol::new(e, "requirements_set"),);
+    e.events().publish(topics, requirements);
+}
+
+pub fn publication_created(e: &Env, owner: Address, id: u64, category: Symbol, price: i128) {
+    let topics = (Symbol::new(e, "publication_created"), owner, category);
+    e.events().publish(topics, (id, price));
+}
+
+pub fn publication_state_changed(
+    e: &Env,
+    owner: Address,
+    id: u64,
+    from: PublicationState,
+    to: PublicationState,
+) {
+    let topics = (Symbol::new(e, "publication_state_changed"), owner);
+    e.events().publish(topics, (id, from, to));
+}
@@ -0,0 +1,102 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::PublicationRegistryContract;
+pub use crate::error::Error;
+pub use crate::types::{Publication, PublicationState, PublishingRequirements};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Symbol, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| {
+    PublicationRegistryContract::pause_admin(env)
+});
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the contract with an admin address
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        PublicationRegistryContract::initialize(env, admin)
+    }
+
+    /// Set the verification level and reputation score owners need to publish (admin only)
+    pub fn set_requirements(
+        env: Env,
+        admin: Address,
+        requirements: PublishingRequirements,
+    ) -> Result<(), Error> {
+        PublicationRegistryContract::set_requirements(env, admin, requirements)
+    }
+
+    /// Get the current publishing requirements
+    pub fn get_requirements(env: Env) -> Option<PublishingRequirements> {
+        PublicationRegistryContract::get_requirements(env)
+    }
+
+    /// Create a draft publication and return its id
+    pub fn create_publication(
+        env: Env,
+        owner: Address,
+        title_hash: BytesN<32>,
+        category: Symbol,
+        price: i128,
+        metadata_uri: String,
+    ) -> Result<u64, Error> {
+        PublicationRegistryContract::create_publication(
+            env,
+            owner,
+            title_hash,
+            category,
+            price,
+            metadata_uri,
+        )
+    }
+
+    /// Move a draft or paused publication to Active
+    pub fn activate_publication(env: Env, owner: Address, id: u64) -> Result<(), Error> {
+        PublicationRegistryContract::activate_publication(env, owner, id)
+    }
+
+    /// Move an active publication to Paused
+    pub fn pause_publication(env: Env, owner: Address, id: u64) -> Result<(), Error> {
+        PublicationRegistryContract::pause_publication(env, owner, id)
+    }
+
+    /// Close a publication
+    pub fn close_publication(env: Env, owner: Address, id: u64) -> Result<(), Error> {
+        PublicationRegistryContract::close_publication(env, owner, id)
+    }
+
+    /// Get a publication by id
+    pub fn get_publication(env: Env, id: u64) -> Option<Publication> {
+        PublicationRegistryContract::get_publication(env, id)
+    }
+
+    /// Page through an owner's publications
+    pub fn get_publications_by_owner(
+        env: Env,
+        owner: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Publication> {
+        PublicationRegistryContract::get_publications_by_owner(env, owner, offset, limit)
+    }
+
+    /// Page through the publications in a category
+    pub fn get_publications_by_category(
+        env: Env,
+        category: Symbol,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Publication> {
+        PublicationRegistryContract::get_publications_by_category(env, category, offset, limit)
+    }
+}
@@ -0,0 +1,114 @@
+use crate::types::{Publication, PublishingRequirements};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Requirements,
+    NextPublicationId,
+    Publication(u64),
+    /// owner -> number of publications it has created
+    OwnerCount(Address),
+    /// (owner, position) -> publication id
+    OwnerEntry(Address, u32),
+    /// category -> number of publications in it
+    CategoryCount(Symbol),
+    /// (category, position) -> publication id
+    CategoryEntry(Symbol, u32),
+}
+
+/// Get the admin address
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_requirements(env: &Env) -> Option<PublishingRequirements> {
+    env.storage().instance().get(&DataKey::Requirements)
+}
+
+pub fn set_requirements(env: &Env, requirements: &PublishingRequirements) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Requirements, requirements);
+}
+
+/// Allocate the next publication id, starting at 1
+pub fn next_publication_id(env: &Env) -> u64 {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::NextPublicationId)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextPublicationId, &id);
+    id
+}
+
+pub fn get_publication(env: &Env, id: u64) -> Option<Publication> {
+    env.storage().persistent().get(&DataKey::Publication(id))
+}
+
+pub fn set_publication(env: &Env, publication: &Publication) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Publication(publication.id), publication);
+}
+
+/// Add a new publication to its owner's and category's listings
+pub fn index_publication(env: &Env, publication: &Publication) {
+    let storage = env.storage().persistent();
+
+    let owner_count = get_owner_count(env, &publication.owner);
+    storage.set(
+        &DataKey::OwnerEntry(publication.owner.clone(), owner_count),
+        &publication.id,
+    );
+    storage.set(
+        &DataKey::OwnerCount(publication.owner.clone()),
+        &(owner_count + 1),
+    );
+
+    let category_count = get_category_count(env, &publication.category);
+    storage.set(
+        &DataKey::CategoryEntry(publication.category.clone(), category_count),
+        &publication.id,
+    );
+    storage.set(
+        &DataKey::CategoryCount(publication.category.clone()),
+        &(category_count + 1),
+    );
+}
+
+pub fn get_owner_count(env: &Env, owner: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnerCount(owner.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_owner_entry(env: &Env, owner: &Address, position: u32) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnerEntry(owner.clone(), position))
+}
+
+pub fn get_category_count(env: &Env, category: &Symbol) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CategoryCount(category.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_category_entry(env: &Env, category: &Symbol, position: u32) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CategoryEntry(category.clone(), position))
+}
@@ -0,0 +1,203 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, Address, BytesN, Env, String,
+};
+use user_registry_contract::types::VerificationLevel;
+use user_registry_contract::{Contract as UserRegistry, ContractClient as UserRegistryClient};
+
+#[contract]
+pub struct MockReputationOracle;
+
+#[contractimpl]
+impl MockReputationOracle {
+    pub fn set_score(env: Env, user: Address, score: u32) {
+        env.storage().instance().set(&user, &score);
+    }
+
+    pub fn get_user_reputation_score(env: Env, user: Address) -> u32 {
+        env.storage().instance().get(&user).unwrap_or(0)
+    }
+}
+
+fn setup(env: &Env) -> (ContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let client = ContractClient::new(env, &env.register(Contract, ()));
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+fn create(env: &Env, client: &ContractClient, owner: &Address, category: Symbol) -> u64 {
+    client.create_publication(
+        owner,
+        &BytesN::from_array(env, &[1; 32]),
+        &category,
+        &2_500,
+        &String::from_str(env, "ipfs://gig"),
+    )
+}
+
+#[test]
+fn test_create_publication_lifecycle() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let owner = Address::generate(&env);
+
+    let id = create(&env, &client, &owner, symbol_short!("design"));
+    assert_eq!(id, 1);
+    let publication = client.get_publication(&id).unwrap();
+    assert_eq!(publication.owner, owner);
+    assert_eq!(publication.price, 2_500);
+    assert_eq!(publication.state, PublicationState::Draft);
+
+    // Drafts can't be paused
+    assert_eq!(
+        client.try_pause_publication(&owner, &id),
+        Err(Ok(Error::InvalidStateTransition))
+    );
+    client.activate_publication(&owner, &id);
+    client.pause_publication(&owner, &id);
+    client.activate_publication(&owner, &id);
+    client.close_publication(&owner, &id);
+    assert_eq!(
+        client.get_publication(&id).unwrap().state,
+        PublicationState::Closed
+    );
+    assert_eq!(
+        client.try_activate_publication(&owner, &id),
+        Err(Ok(Error::InvalidStateTransition))
+    );
+
+    // Only the owner manages a publication
+    let id = create(&env, &client, &owner, symbol_short!("design"));
+    assert_eq!(
+        client.try_activate_publication(&Address::generate(&env), &id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_create_publication(
+            &owner,
+            &BytesN::from_array(&env, &[1; 32]),
+            &symbol_short!("design"),
+            &-1,
+            &String::from_str(&env, "ipfs://gig"),
+        ),
+        Err(Ok(Error::InvalidPrice))
+    );
+}
+
+#[test]
+fn test_publications_by_owner_and_category() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    create(&env, &client, &alice, symbol_short!("design"));
+    create(&env, &client, &bob, symbol_short!("design"));
+    create(&env, &client, &alice, symbol_short!("dev"));
+    create(&env, &client, &alice, symbol_short!("design"));
+
+    let alices = client.get_publications_by_owner(&alice, &0, &10);
+    assert_eq!(alices.len(), 3);
+    assert_eq!(alices.get(1).unwrap().id, 3);
+    assert_eq!(client.get_publications_by_owner(&alice, &1, &1).len(), 1);
+
+    let design = client.get_publications_by_category(&symbol_short!("design"), &1, &10);
+    assert_eq!(design.len(), 2);
+    assert_eq!(design.get(0).unwrap().owner, bob);
+    assert_eq!(design.get(1).unwrap().id, 4);
+    assert_eq!(
+        client
+            .get_publications_by_category(&symbol_short!("writing"), &0, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_publishing_requirements() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let owner = Address::generate(&env);
+
+    let registry_id = env.register(UserRegistry, ());
+    let registry = UserRegistryClient::new(&env, &registry_id);
+    let registry_admin = Address::generate(&env);
+    registry.initialize_admin(&registry_admin);
+    let oracle = env.register(MockReputationOracle, ());
+
+    client.set_requirements(
+        &admin,
+        &PublishingRequirements {
+            user_registry: Some(registry_id),
+            min_verification_level: VerificationLevel::Verified as u32,
+            reputation_contract: Some(oracle.clone()),
+            min_reputation_score: 300,
+        },
+    );
+    let try_create = || {
+        client.try_create_publication(
+            &owner,
+            &BytesN::from_array(&env, &[1; 32]),
+            &symbol_short!("dev"),
+            &100,
+            &String::from_str(&env, "ipfs://gig"),
+        )
+    };
+
+    assert_eq!(try_create(), Err(Ok(Error::InsufficientVerification)));
+    registry.set_verification_level(&registry_admin, &owner, &VerificationLevel::Verified, &0);
+    assert_eq!(try_create(), Err(Ok(Error::InsufficientReputation)));
+    MockReputationOracleClient::new(&env, &oracle).set_score(&owner, &300);
+    assert_eq!(try_create(), Ok(Ok(1)));
+
+    assert_eq!(
+        client.try_set_requirements(
+            &owner,
+            &PublishingRequirements {
+                user_registry: None,
+                min_verification_level: 0,
+                reputation_contract: None,
+                min_reputation_score: 0,
+            },
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_pause_blocks_new_publications() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let owner = Address::generate(&env);
+    let id = create(&env, &client, &owner, symbol_short!("design"));
+
+    client.pause(&admin);
+    assert_eq!(
+        client.try_create_publication(
+            &owner,
+            &BytesN::from_array(&env, &[1; 32]),
+            &symbol_short!("design"),
+            &2_500,
+            &String::from_str(&env, "ipfs://gig"),
+        ),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_activate_publication(&owner, &id),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    // Owners can still take live publications down
+    client.unpause(&admin);
+    client.activate_publication(&owner, &id);
+    client.pause(&admin);
+    client.close_publication(&owner, &id);
+    assert_eq!(
+        client.get_publication(&id).unwrap().state,
+        PublicationState::Closed
+    );
+}
@@ -0,0 +1,54 @@
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol};
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum PublicationState {
+    Draft = 0,
+    Active = 1,
+    Paused = 2,
+    Closed = 3,
+}
+
+impl PublicationState {
+    pub fn can_transition_to(&self, next: &PublicationState) -> bool {
+        use PublicationState::*;
+        matches!(
+            (self, next),
+            (Draft, Active)
+                | (Active, Paused)
+                | (Paused, Active)
+                | (Draft, Closed)
+                | (Active, Closed)
+                | (Paused, Closed)
+        )
+    }
+}
+
+/// A gig or project listing. The title is kept off-chain and committed to by `title_hash`;
+/// `metadata_uri` points at the full listing.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Publication {
+    pub id: u64,
+    pub owner: Address,
+    pub title_hash: BytesN<32>,
+    pub category: Symbol,
+    pub price: i128,
+    pub metadata_uri: String,
+    pub state: PublicationState,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+/// Who may publish. Owners must hold `min_verification_level` in `user_registry` and a
+/// reputation score (average x 100) of at least `min_reputation_score` in
+/// `reputation_contract`. Either check is skipped when its contract isn't set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublishingRequirements {
+    pub user_registry: Option<Address>,
+    pub min_verification_level: u32,
+    pub reputation_contract: Option<Address>,
+    pub min_reputation_score: u32,
+}
@@ -3,33 +3,57 @@ use crate::access::{
     transfer_admin as transfer_admin_impl,
 };
 use crate::analytics::{generate_user_rating_data, get_platform_analytics};
-use crate::events::{emit_feedback_submitted, emit_rating_stats_updated, emit_rating_submitted};
+use crate::events::{
+    emit_achievement_earned, emit_escrow_rating_submitted, emit_feedback_revealed,
+    emit_feedback_submitted, emit_rating_aggregate_updated, emit_rating_stats_updated,
+    emit_rating_submitted,
+};
 use crate::incentives::{check_rating_incentives, claim_incentive_reward as claim_incentive_impl};
 use crate::moderation::{
-    auto_moderate_feedback, moderate_feedback as moderate_feedback_impl,
-    report_feedback as report_feedback_impl,
+    auto_moderate_feedback, flag_rating as flag_rating_impl, get_moderation_log,
+    moderate_feedback as moderate_feedback_impl, report_feedback as report_feedback_impl,
+    resolve_flag as resolve_flag_impl,
 };
 use crate::restrictions::{
     check_and_apply_restrictions, check_restriction_status, get_user_privileges,
 };
 use crate::storage::{
-    add_user_feedback_id, check_rate_limit, get_admin, get_feedback, get_reputation_contract,
-    get_user_feedback_ids, get_user_rating_history, get_user_rating_stats, increment_platform_stat,
-    increment_rating_count, reset_rate_limit, save_admin, save_feedback, save_rating,
-    save_rating_threshold, save_reputation_contract, save_user_rating_stats, set_rate_limit_bypass,
-    set_total_rating, 
+    add_user_feedback_id, check_rate_limit, get_admin, get_category_ratings, get_escrow_factory,
+    get_escrow_rating, get_escrow_rating_by, get_feedback, get_milestone_config,
+    get_rating_aggregate, get_rating_categories, get_rating_index_count, get_rating_index_entry,
+    get_rating_flag, get_reputation_contract, get_user_feedback_ids, get_user_rating_history,
+    get_user_rating_stats, increment_platform_stat, increment_rating_count, index_escrow_rating,
+    is_milestone_minted, next_escrow_rating_id, reset_rate_limit, save_admin,
+    save_category_ratings, save_escrow_factory, save_escrow_rating, save_feedback,
+    save_milestone_config, save_rating, save_rating_aggregate, save_rating_categories,
+    save_rating_threshold, save_reputation_contract, save_user_rating_stats, set_escrow_rating_by,
+    set_milestone_minted, set_rate_limit_bypass, set_total_rating,
 };
 use crate::error::Error;
 use crate::types::{
-    require_auth, AllRatingDataExport, Feedback, Rating,
-    RatingStats, UserRatingData, RatingThreshold,
-    ContractConfig, CONTRACT_CONFIG, DEFAULT_MAX_RATING_PER_DAY, DEFAULT_MAX_FEEDBACK_LENGTH, MAX_RATING_AGE,
-    DEFAULT_MIN_RATING, DEFAULT_MAX_RATING, DEFAULT_RATE_LIMIT_CALLS, DEFAULT_RATE_LIMIT_WINDOW_HOURS,
+    require_auth, AllRatingDataExport, CategoryRatings, CategoryScores, EscrowRating, Feedback,
+    FlagResolution, MilestoneConfig, ModerationLogEntry, Rating, RatingAggregate, RatingFlag,
+    RatingStats, UserRatingData, RatingThreshold, ContractConfig, CONTRACT_CONFIG,
+    DEFAULT_MAX_RATING_PER_DAY, DEFAULT_MAX_FEEDBACK_LENGTH, MAX_RATING_AGE, DEFAULT_MIN_RATING,
+    DEFAULT_MAX_RATING, DEFAULT_RATE_LIMIT_CALLS, DEFAULT_RATE_LIMIT_WINDOW_HOURS,
     DEFAULT_AUTO_MODERATION_ENABLED, DEFAULT_RESTRICTION_THRESHOLD, DEFAULT_WARNING_THRESHOLD,
-    DEFAULT_TOP_RATED_THRESHOLD, RatingDataExport, UserRatingSummary, PAUSED
+    DEFAULT_TOP_RATED_THRESHOLD, RatingDataExport, UserRatingSummary, PAUSED, RATER_BASE_WEIGHT,
+    RATINGS_GIVEN, RATINGS_RECEIVED,
+};
+use crate::validation::{
+    validate_category_scores, validate_feedback_uri, validate_report_feedback,
+    validate_submit_escrow_rating, validate_submit_rating,
 };
-use crate::validation::{validate_report_feedback, validate_submit_rating};
-use soroban_sdk::{Address, Env, IntoVal, String, Symbol, Vec, Bytes};
+use soroban_sdk::{Address, Env, IntoVal, String, Symbol, Vec, Bytes, BytesN};
+
+const ESCROW_GET_PARTIES: &str = "get_parties";
+const ESCROW_IS_COMPLETED: &str = "is_completed";
+const FACTORY_GET_ESCROW_ID: &str = "get_escrow_id_by_address";
+const REPUTATION_SCORE: &str = "get_user_reputation_score";
+const REPUTATION_UPDATE_SCORE: &str = "update_reputation_score";
+const REPUTATION_MINT_ACHIEVEMENT: &str = "mint_rating_achievement";
+const REPUTATION_UPDATE_SKILL: &str = "update_skill_score";
+const MAX_RATINGS_PAGE: u32 = 50;
 
 pub struct RatingContract;
 
@@ -83,51 +107,7 @@ impl RatingContract {
     }
 
     pub fn is_paused(env: &Env) -> bool {
-        env.storage().instance().get(&PAUSED).unwrap_or(false)
-    }
-
-    // Function to pause the contract
-    pub fn pause(env: &Env, admin: Address) -> Result<(), Error> {
-        admin.require_auth();
-        let stored_admin = get_admin(env);
-        if stored_admin != admin {
-            return Err(Error::Unauthorized);
-        }
-        
-        if Self::is_paused(env) {
-            return Err(Error::AlreadyPaused);
-        }
-        
-        env.storage().instance().set(&PAUSED, &true);
-        
-        env.events().publish(
-            (Symbol::new(env, "contract_paused"), admin),
-            env.ledger().timestamp(),
-        );
-        
-        Ok(())
-    }
-
-    // Function to unpause the contract
-    pub fn unpause(env: &Env, admin: Address) -> Result<(), Error> {
-        admin.require_auth();
-        let stored_admin = get_admin(env);
-        if stored_admin != admin {
-            return Err(Error::Unauthorized);
-        }
-        
-        if !Self::is_paused(env) {
-            return Err(Error::NotPaused);
-        }
-        
-        env.storage().instance().set(&PAUSED, &false);
-        
-        env.events().publish(
-            (Symbol::new(env, "contract_unpaused"), admin),
-            env.ledger().timestamp(),
-        );
-        
-        Ok(())
+        offerhub_pausable::is_paused(env)
     }
 
     pub fn submit_rating(
@@ -233,6 +213,175 @@ impl RatingContract {
         Ok(())
     }
 
+    /// Rate the counterparty of a completed escrow. The escrow is asked whether it completed
+    /// and who its parties are, so only a client and freelancer who finished an engagement
+    /// can rate each other. Returns the new rating's id.
+    pub fn submit_escrow_rating(
+        env: Env,
+        rater: Address,
+        ratee: Address,
+        escrow: Address,
+        score: u32,
+        feedback_hash: BytesN<32>,
+        categories: Option<CategoryScores>,
+    ) -> Result<u64, Error> {
+        require_auth(&rater)?;
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        // Shares the submit_rating budget: max 5 per hour per user
+        let limit_type = String::from_str(&env, "submit_rating");
+        check_rate_limit(&env, &rater, &limit_type, 5, 3600)?;
+
+        validate_submit_escrow_rating(&env, &rater, &ratee, score)?;
+        if let Some(categories) = &categories {
+            validate_category_scores(categories)?;
+        }
+        // Each party rates the counterparty once per engagement
+        if get_escrow_rating_by(&env, &rater, &escrow).is_some() {
+            return Err(Error::AlreadyRated);
+        }
+        Self::verify_engagement(&env, &rater, &ratee, &escrow)?;
+
+        let weight = Self::rater_weight(&env, &rater);
+        let rating = EscrowRating {
+            id: next_escrow_rating_id(&env),
+            rater: rater.clone(),
+            ratee: ratee.clone(),
+            escrow: escrow.clone(),
+            score,
+            feedback_hash: feedback_hash.clone(),
+            feedback_uri: None,
+            weight,
+            timestamp: env.ledger().timestamp(),
+            ledger: env.ledger().sequence(),
+            removed: false,
+        };
+        save_escrow_rating(&env, &rating);
+        if let Some(categories) = &categories {
+            save_rating_categories(&env, rating.id, categories);
+        }
+        set_escrow_rating_by(&env, &rater, &escrow, rating.id);
+        index_escrow_rating(&env, &rating);
+
+        Self::update_user_statistics(&env, &ratee, score)?;
+        Self::update_aggregate(&env, &rating, true);
+        check_and_apply_restrictions(&env, &ratee)?;
+        increment_platform_stat(&env, &String::from_str(&env, "total_ratings"));
+        increment_rating_count(&env);
+
+        emit_escrow_rating_submitted(
+            &env,
+            rating.id,
+            &rater,
+            &ratee,
+            &escrow,
+            score,
+            &feedback_hash,
+        );
+        Ok(rating.id)
+    }
+
+    pub fn get_escrow_rating(env: Env, rating_id: u64) -> Result<EscrowRating, Error> {
+        get_escrow_rating(&env, rating_id)
+    }
+
+    /// Category scores left with an escrow-bound rating, if the rater gave any
+    pub fn get_rating_categories(env: Env, rating_id: u64) -> Option<CategoryScores> {
+        get_rating_categories(&env, rating_id)
+    }
+
+    /// Publish where the review text committed to by `feedback_hash` can be read. The URI is
+    /// set once, so the platform can check the text it displays against the commitment.
+    pub fn reveal_feedback(env: Env, rating_id: u64, text_uri: String) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        let mut rating = get_escrow_rating(&env, rating_id)?;
+        rating.rater.require_auth();
+        validate_feedback_uri(&text_uri)?;
+        if rating.removed {
+            return Err(Error::RatingRemoved);
+        }
+        if rating.feedback_uri.is_some() {
+            return Err(Error::FeedbackAlreadyRevealed);
+        }
+
+        rating.feedback_uri = Some(text_uri.clone());
+        save_escrow_rating(&env, &rating);
+
+        emit_feedback_revealed(&env, rating_id, &rating.feedback_hash, &text_uri);
+        Ok(())
+    }
+
+    /// Get a user's per-category averages from escrow-bound ratings that carried category scores
+    pub fn get_category_ratings(env: Env, user: Address) -> CategoryRatings {
+        get_category_ratings(&env, &user)
+    }
+
+    /// Page through the escrow-bound ratings a user has received, oldest first. Ratings removed
+    /// by moderation stay in the list with `removed` set so pages don't shift.
+    pub fn get_ratings_for(env: Env, user: Address, offset: u32, limit: u32) -> Vec<EscrowRating> {
+        Self::ratings_page(&env, RATINGS_RECEIVED, &user, offset, limit)
+    }
+
+    /// Page through the escrow-bound ratings a user has given, oldest first
+    pub fn get_ratings_by(env: Env, rater: Address, offset: u32, limit: u32) -> Vec<EscrowRating> {
+        Self::ratings_page(&env, RATINGS_GIVEN, &rater, offset, limit)
+    }
+
+    /// Whether the rater has already rated their counterparty for this escrow. A rating removed
+    /// by moderation still counts.
+    pub fn has_rated(env: Env, rater: Address, escrow_id: Address) -> bool {
+        get_escrow_rating_by(&env, &rater, &escrow_id).is_some()
+    }
+
+    /// Get the aggregate of a user's escrow-bound ratings, with raw and reputation-weighted
+    /// averages
+    pub fn get_rating_summary(env: Env, user: Address) -> RatingAggregate {
+        get_rating_aggregate(&env, &user)
+    }
+
+    /// Escrow ratings are only accepted for escrows deployed by `factory` (admin only)
+    pub fn set_escrow_factory(env: Env, caller: Address, factory: Address) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        crate::access::check_admin(&env, &caller)?;
+        save_escrow_factory(&env, &factory);
+        Ok(())
+    }
+
+    pub fn get_escrow_factory(env: Env) -> Option<Address> {
+        get_escrow_factory(&env)
+    }
+
+    /// Set the aggregate thresholds for automatic milestone minting (admin only)
+    pub fn set_milestone_config(
+        env: Env,
+        caller: Address,
+        config: MilestoneConfig,
+    ) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        crate::access::check_admin(&env, &caller)?;
+        if config.ten_ratings_count == 0
+            || config.top_rated_count == 0
+            || config.top_rated_average_bps < 10_000
+            || config.top_rated_average_bps > 50_000
+        {
+            return Err(Error::InvalidMilestoneConfig);
+        }
+        save_milestone_config(&env, &config);
+        Ok(())
+    }
+
+    pub fn get_milestone_config(env: Env) -> MilestoneConfig {
+        get_milestone_config(&env)
+    }
+
     // Admin: toggle rate limit bypass for a user
     pub fn set_rate_limit_bypass(
         env: Env,
@@ -384,6 +533,38 @@ impl RatingContract {
         moderate_feedback_impl(&env, &caller, &feedback_id, &action, &reason)
     }
 
+    pub fn flag_rating(
+        env: Env,
+        reporter: Address,
+        rating_id: u64,
+        reason: String,
+    ) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        flag_rating_impl(&env, &reporter, rating_id, &reason)
+    }
+
+    pub fn resolve_flag(
+        env: Env,
+        moderator: Address,
+        rating_id: u64,
+        resolution: FlagResolution,
+    ) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        resolve_flag_impl(&env, &moderator, rating_id, resolution)
+    }
+
+    pub fn get_rating_flag(env: Env, rating_id: u64) -> Option<RatingFlag> {
+        get_rating_flag(&env, rating_id)
+    }
+
+    pub fn get_moderation_log(env: Env, offset: u32, limit: u32) -> Vec<ModerationLogEntry> {
+        get_moderation_log(&env, offset, limit)
+    }
+
     pub fn get_platform_analytics(env: Env) -> Result<Vec<(String, String)>, Error> {
         Ok(get_platform_analytics(&env))
     }
@@ -621,6 +802,244 @@ impl RatingContract {
         String::from_str(env, "feedback_id")
     }
 
+    fn verify_engagement(
+        env: &Env,
+        rater: &Address,
+        ratee: &Address,
+        escrow: &Address,
+    ) -> Result<(), Error> {
+        // Fail closed: an escrow the factory can't vouch for could report anything
+        let factory = get_escrow_factory(env).ok_or(Error::EscrowFactoryNotSet)?;
+        let escrow_id: Option<u32> = env.invoke_contract(
+            &factory,
+            &Symbol::new(env, FACTORY_GET_ESCROW_ID),
+            (escrow.clone(),).into_val(env),
+        );
+        if escrow_id.is_none() {
+            return Err(Error::UnknownEscrow);
+        }
+
+        let completed: bool = env.invoke_contract(
+            escrow,
+            &Symbol::new(env, ESCROW_IS_COMPLETED),
+            Vec::new(env),
+        );
+        if !completed {
+            return Err(Error::EngagementNotCompleted);
+        }
+
+        let (client, freelancer): (Address, Address) =
+            env.invoke_contract(escrow, &Symbol::new(env, ESCROW_GET_PARTIES), Vec::new(env));
+        let is_party = (*rater == client && *ratee == freelancer)
+            || (*rater == freelancer && *ratee == client);
+        if !is_party {
+            return Err(Error::NotEngagementParty);
+        }
+        Ok(())
+    }
+
+    fn ratings_page(
+        env: &Env,
+        index: &[u8],
+        user: &Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowRating> {
+        let mut ratings = Vec::new(env);
+        let end = offset
+            .saturating_add(limit.min(MAX_RATINGS_PAGE))
+            .min(get_rating_index_count(env, index, user));
+        for position in offset..end {
+            if let Some(rating_id) = get_rating_index_entry(env, index, user, position) {
+                if let Ok(rating) = get_escrow_rating(env, rating_id) {
+                    ratings.push_back(rating);
+                }
+            }
+        }
+        ratings
+    }
+
+    // Add a rating to the user's aggregate, or take a removed one back out
+    pub(crate) fn update_aggregate(env: &Env, rating: &EscrowRating, added: bool) {
+        let user = &rating.ratee;
+        let score = rating.score;
+        let weighted = score as u64 * rating.weight as u64;
+        let mut aggregate = get_rating_aggregate(env, user);
+        let index = score - 1;
+        let stars = aggregate.histogram.get(index).unwrap_or(0);
+        if added {
+            aggregate.histogram.set(index, stars + 1);
+            aggregate.count += 1;
+            aggregate.sum += score;
+            aggregate.weighted_sum += weighted;
+            aggregate.weight_total += rating.weight as u64;
+        } else {
+            aggregate.histogram.set(index, stars - 1);
+            aggregate.count -= 1;
+            aggregate.sum -= score;
+            aggregate.weighted_sum -= weighted;
+            aggregate.weight_total -= rating.weight as u64;
+        }
+        // Scaled in u64 so large rating counts cannot overflow; averages stay within 5 stars
+        aggregate.average_bps = (aggregate.sum as u64 * 10_000)
+            .checked_div(aggregate.count as u64)
+            .unwrap_or(0) as u32;
+        aggregate.weighted_average_bps = (aggregate.weighted_sum * 10_000)
+            .checked_div(aggregate.weight_total)
+            .unwrap_or(0) as u32;
+        aggregate.last_updated = env.ledger().timestamp();
+        save_rating_aggregate(env, &aggregate);
+
+        emit_rating_aggregate_updated(env, user, aggregate.count, aggregate.average_bps);
+        Self::push_reputation_score(env, &aggregate);
+        if added {
+            Self::mint_milestones(env, &aggregate);
+        }
+        if let Some(categories) = get_rating_categories(env, rating.id) {
+            Self::update_category_ratings(env, user, &categories, added);
+        }
+    }
+
+    fn update_category_ratings(
+        env: &Env,
+        user: &Address,
+        categories: &CategoryScores,
+        added: bool,
+    ) {
+        let mut ratings = get_category_ratings(env, user);
+        if added {
+            ratings.count += 1;
+            ratings.communication_sum += categories.communication;
+            ratings.quality_sum += categories.quality;
+            ratings.timeliness_sum += categories.timeliness;
+        } else {
+            ratings.count -= 1;
+            ratings.communication_sum -= categories.communication;
+            ratings.quality_sum -= categories.quality;
+            ratings.timeliness_sum -= categories.timeliness;
+        }
+        let count = ratings.count;
+        let average_bps =
+            |sum: u32| (sum as u64 * 10_000).checked_div(count as u64).unwrap_or(0) as u32;
+        ratings.communication_bps = average_bps(ratings.communication_sum);
+        ratings.quality_bps = average_bps(ratings.quality_sum);
+        ratings.timeliness_bps = average_bps(ratings.timeliness_sum);
+        ratings.last_updated = env.ledger().timestamp();
+        save_category_ratings(env, &ratings);
+
+        for (skill, average_bps) in [
+            ("communication", ratings.communication_bps),
+            ("quality", ratings.quality_bps),
+            ("timeliness", ratings.timeliness_bps),
+        ] {
+            Self::push_skill_score(env, user, skill, average_bps, ratings.count);
+        }
+    }
+
+    // Feed a category average to the reputation NFT's per-skill scores, on the same x100 scale
+    // and with the same best-effort semantics as push_reputation_score
+    fn push_skill_score(env: &Env, user: &Address, skill: &str, average_bps: u32, count: u32) {
+        let Ok(reputation_contract) = get_reputation_contract(env) else {
+            return;
+        };
+        let _ = env.try_invoke_contract::<(), Error>(
+            &reputation_contract,
+            &Symbol::new(env, REPUTATION_UPDATE_SKILL),
+            (
+                env.current_contract_address(),
+                user.clone(),
+                Symbol::new(env, skill),
+                average_bps / 100,
+                count,
+            )
+                .into_val(env),
+        );
+    }
+
+    // Mint the milestone achievements an aggregate has reached. A milestone is only recorded
+    // once the reputation contract accepts the mint, so a rejected mint is retried on the next
+    // rating.
+    fn mint_milestones(env: &Env, aggregate: &RatingAggregate) {
+        let Ok(reputation_contract) = get_reputation_contract(env) else {
+            return;
+        };
+        let config = get_milestone_config(env);
+        let five_stars = aggregate.histogram.get(4).unwrap_or(0);
+        let milestones = [
+            ("first_five_star", five_stars >= 1, five_stars),
+            (
+                "ten_ratings",
+                aggregate.count >= config.ten_ratings_count,
+                aggregate.count,
+            ),
+            (
+                "top_rated_professional",
+                aggregate.count >= config.top_rated_count
+                    && aggregate.average_bps >= config.top_rated_average_bps,
+                aggregate.average_bps,
+            ),
+        ];
+
+        for (name, reached, value) in milestones {
+            let milestone = String::from_str(env, name);
+            if !reached || is_milestone_minted(env, &aggregate.user, &milestone) {
+                continue;
+            }
+            let minted = env.try_invoke_contract::<(), Error>(
+                &reputation_contract,
+                &Symbol::new(env, REPUTATION_MINT_ACHIEVEMENT),
+                (
+                    env.current_contract_address(),
+                    aggregate.user.clone(),
+                    milestone.clone(),
+                    String::from_str(env, "escrow_ratings"),
+                )
+                    .into_val(env),
+            );
+            if matches!(minted, Ok(Ok(()))) {
+                set_milestone_minted(env, &aggregate.user, &milestone);
+                emit_achievement_earned(env, &aggregate.user, &milestone, value);
+            }
+        }
+    }
+
+    // Weight of a rater's ratings, from their own score on the reputation contract. Raters are
+    // weighted equally while no reputation contract is set.
+    fn rater_weight(env: &Env, rater: &Address) -> u32 {
+        let Ok(reputation_contract) = get_reputation_contract(env) else {
+            return RATER_BASE_WEIGHT;
+        };
+        let score = env.try_invoke_contract::<u32, Error>(
+            &reputation_contract,
+            &Symbol::new(env, REPUTATION_SCORE),
+            (rater.clone(),).into_val(env),
+        );
+        match score {
+            Ok(Ok(score)) => RATER_BASE_WEIGHT + score,
+            _ => RATER_BASE_WEIGHT,
+        }
+    }
+
+    // Feed the aggregate to the reputation NFT, which scales averages by 100. A reputation
+    // contract that rejects the update (e.g. this contract isn't a minter) doesn't block the
+    // rating.
+    fn push_reputation_score(env: &Env, aggregate: &RatingAggregate) {
+        let Ok(reputation_contract) = get_reputation_contract(env) else {
+            return;
+        };
+        let _ = env.try_invoke_contract::<(), Error>(
+            &reputation_contract,
+            &Symbol::new(env, REPUTATION_UPDATE_SCORE),
+            (
+                env.current_contract_address(),
+                aggregate.user.clone(),
+                aggregate.average_bps / 100,
+                aggregate.count,
+            )
+                .into_val(env),
+        );
+    }
+
     fn update_user_statistics(env: &Env, user: &Address, new_rating: u32) -> Result<(), Error> {
         let mut stats = get_user_rating_stats(env, user).unwrap_or(RatingStats {
             user: user.clone(),
@@ -1,3 +1,4 @@
+use offerhub_pausable::PauseError;
 use soroban_sdk::contracterror;
 
 #[contracterror]
@@ -41,4 +42,36 @@ pub enum Error {
     AlreadyPaused = 19,
     NotPaused = 20,
     ContractPaused = 21,
+    /// Escrow has not completed, so the engagement can't be rated yet
+    EngagementNotCompleted = 22,
+    /// Rater and ratee are not the two parties of the escrow
+    NotEngagementParty = 23,
+    /// Rating not found
+    RatingNotFound = 24,
+    /// Escrow was not deployed by the configured escrow factory
+    UnknownEscrow = 25,
+    /// No open flag for this rating
+    FlagNotFound = 26,
+    /// Rating already has an open flag
+    AlreadyFlagged = 27,
+    /// Rating has been removed by a moderator
+    RatingRemoved = 28,
+    /// Milestone thresholds are out of range
+    InvalidMilestoneConfig = 29,
+    /// Feedback for this rating has already been revealed
+    FeedbackAlreadyRevealed = 30,
+    /// Feedback URI is empty or too long
+    InvalidFeedbackUri = 31,
+    /// No escrow factory is configured to vouch for escrows
+    EscrowFactoryNotSet = 32,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
 }
@@ -1,4 +1,5 @@
-use soroban_sdk::{symbol_short, Address, Env, String};
+use crate::types::FlagResolution;
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String};
 
 pub fn emit_rating_submitted(
     env: &Env,
@@ -22,6 +23,40 @@ pub fn emit_rating_submitted(
     );
 }
 
+pub fn emit_escrow_rating_submitted(
+    env: &Env,
+    rating_id: u64,
+    rater: &Address,
+    ratee: &Address,
+    escrow: &Address,
+    score: u32,
+    feedback_hash: &BytesN<32>,
+) {
+    env.events().publish(
+        (symbol_short!("rating"), symbol_short!("escrow")),
+        (
+            rating_id,
+            rater.clone(),
+            ratee.clone(),
+            escrow.clone(),
+            score,
+            feedback_hash.clone(),
+        ),
+    );
+}
+
+pub fn emit_feedback_revealed(
+    env: &Env,
+    rating_id: u64,
+    feedback_hash: &BytesN<32>,
+    text_uri: &String,
+) {
+    env.events().publish(
+        (symbol_short!("feedback"), symbol_short!("reveal")),
+        (rating_id, feedback_hash.clone(), text_uri.clone()),
+    );
+}
+
 pub fn emit_feedback_submitted(
     env: &Env,
     rater: &Address,
@@ -47,6 +82,32 @@ pub fn emit_rating_stats_updated(env: &Env, user: &Address, new_average: u32, to
     );
 }
 
+pub fn emit_rating_flagged(env: &Env, reporter: &Address, rating_id: u64, reason: &String) {
+    env.events().publish(
+        (symbol_short!("rating"), symbol_short!("flag")),
+        (reporter.clone(), rating_id, reason.clone()),
+    );
+}
+
+pub fn emit_rating_flag_resolved(
+    env: &Env,
+    moderator: &Address,
+    rating_id: u64,
+    resolution: FlagResolution,
+) {
+    env.events().publish(
+        (symbol_short!("rating"), symbol_short!("resolve")),
+        (moderator.clone(), rating_id, resolution),
+    );
+}
+
+pub fn emit_rating_aggregate_updated(env: &Env, user: &Address, count: u32, average_bps: u32) {
+    env.events().publish(
+        (symbol_short!("stats"), symbol_short!("aggregate")),
+        (user.clone(), count, average_bps),
+    );
+}
+
 pub fn emit_feedback_reported(
     env: &Env,
     reporter: &Address,
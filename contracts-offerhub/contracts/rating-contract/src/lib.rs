@@ -1,5 +1,5 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
 mod access;
 mod analytics;
@@ -19,13 +19,17 @@ mod error;
 pub use crate::contract::RatingContract;
 use crate::types::UserRatingSummary;
 pub use types::{
-    Feedback, HealthCheckResult, HealthStatus, Rating, RatingStats, UserRatingData,
+    CategoryRatings, CategoryScores, EscrowRating, Feedback, FlagResolution, HealthCheckResult,
+    HealthStatus, MilestoneConfig, ModerationLogEntry, Rating, RatingAggregate, RatingFlag,
+    RatingStats, UserRatingData,
 };
 pub use error::Error;
 
 #[contract]
 pub struct Contract;
 
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| Ok(storage::get_admin(env)));
+
 #[contractimpl]
 impl Contract {
     /// Initialize the rating contract with an admin
@@ -54,16 +58,100 @@ impl Contract {
         )
     }
 
-    pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
-        RatingContract::pause(&env, admin)
+    /// Rate the counterparty of a completed escrow engagement
+    pub fn submit_escrow_rating(
+        env: Env,
+        rater: Address,
+        ratee: Address,
+        escrow: Address,
+        score: u32,
+        feedback_hash: BytesN<32>,
+    ) -> Result<u64, Error> {
+        RatingContract::submit_escrow_rating(env, rater, ratee, escrow, score, feedback_hash, None)
+    }
+
+    /// Rate the counterparty of a completed escrow engagement, with communication, quality and
+    /// timeliness scores alongside the overall score
+    pub fn submit_categorized_rating(
+        env: Env,
+        rater: Address,
+        ratee: Address,
+        escrow: Address,
+        score: u32,
+        feedback_hash: BytesN<32>,
+        categories: CategoryScores,
+    ) -> Result<u64, Error> {
+        RatingContract::submit_escrow_rating(
+            env,
+            rater,
+            ratee,
+            escrow,
+            score,
+            feedback_hash,
+            Some(categories),
+        )
+    }
+
+    /// Get a user's per-category rating averages
+    pub fn get_category_ratings(env: Env, user: Address) -> CategoryRatings {
+        RatingContract::get_category_ratings(env, user)
+    }
+
+    /// Get an escrow-bound rating by id
+    pub fn get_escrow_rating(env: Env, rating_id: u64) -> Result<EscrowRating, Error> {
+        RatingContract::get_escrow_rating(env, rating_id)
+    }
+
+    pub fn get_rating_categories(env: Env, rating_id: u64) -> Option<CategoryScores> {
+        RatingContract::get_rating_categories(env, rating_id)
+    }
+
+    /// Reveal where the committed review text of an escrow-bound rating is published (rater only)
+    pub fn reveal_feedback(env: Env, rating_id: u64, text_uri: String) -> Result<(), Error> {
+        RatingContract::reveal_feedback(env, rating_id, text_uri)
+    }
+
+    /// Page through the escrow-bound ratings a user has received
+    pub fn get_ratings_for(env: Env, user: Address, offset: u32, limit: u32) -> Vec<EscrowRating> {
+        RatingContract::get_ratings_for(env, user, offset, limit)
+    }
+
+    /// Page through the escrow-bound ratings a user has given
+    pub fn get_ratings_by(env: Env, rater: Address, offset: u32, limit: u32) -> Vec<EscrowRating> {
+        RatingContract::get_ratings_by(env, rater, offset, limit)
+    }
+
+    /// Whether the rater has already rated their counterparty for an escrow
+    pub fn has_rated(env: Env, rater: Address, escrow_id: Address) -> bool {
+        RatingContract::has_rated(env, rater, escrow_id)
+    }
+
+    /// Get the aggregate of a user's escrow-bound ratings, with raw and reputation-weighted
+    /// averages
+    pub fn get_rating_summary(env: Env, user: Address) -> RatingAggregate {
+        RatingContract::get_rating_summary(env, user)
     }
 
-    pub fn is_paused(env: Env) -> bool {
-        RatingContract::is_paused(&env)
+    /// Escrow ratings are only accepted for escrows deployed by this factory (admin only)
+    pub fn set_escrow_factory(env: Env, caller: Address, factory: Address) -> Result<(), Error> {
+        RatingContract::set_escrow_factory(env, caller, factory)
     }
 
-    pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
-        RatingContract::unpause(&env, admin)
+    pub fn get_escrow_factory(env: Env) -> Option<Address> {
+        RatingContract::get_escrow_factory(env)
+    }
+
+    /// Set the aggregate thresholds for automatic milestone minting (admin only)
+    pub fn set_milestone_config(
+        env: Env,
+        caller: Address,
+        config: MilestoneConfig,
+    ) -> Result<(), Error> {
+        RatingContract::set_milestone_config(env, caller, config)
+    }
+
+    pub fn get_milestone_config(env: Env) -> MilestoneConfig {
+        RatingContract::get_milestone_config(env)
     }
 
     /// Get rating statistics for a user
@@ -122,6 +210,36 @@ impl Contract {
         RatingContract::moderate_feedback(env, caller, feedback_id, action, reason)
     }
 
+    /// Flag an escrow-bound rating for moderator review
+    pub fn flag_rating(
+        env: Env,
+        reporter: Address,
+        rating_id: u64,
+        reason: String,
+    ) -> Result<(), Error> {
+        RatingContract::flag_rating(env, reporter, rating_id, reason)
+    }
+
+    /// Remove a flagged rating or dismiss the flag (admin/moderator only)
+    pub fn resolve_flag(
+        env: Env,
+        moderator: Address,
+        rating_id: u64,
+        resolution: FlagResolution,
+    ) -> Result<(), Error> {
+        RatingContract::resolve_flag(env, moderator, rating_id, resolution)
+    }
+
+    /// Get a rating's open flag
+    pub fn get_rating_flag(env: Env, rating_id: u64) -> Option<RatingFlag> {
+        RatingContract::get_rating_flag(env, rating_id)
+    }
+
+    /// Get resolved flags, oldest first
+    pub fn get_moderation_log(env: Env, offset: u32, limit: u32) -> Vec<ModerationLogEntry> {
+        RatingContract::get_moderation_log(env, offset, limit)
+    }
+
     /// Get platform-wide rating analytics
     pub fn get_platform_analytics(env: Env) -> Result<Vec<(String, String)>, Error> {
         RatingContract::get_platform_analytics(env)
@@ -1,12 +1,19 @@
 use crate::access::check_moderator;
-use crate::events::{emit_feedback_moderated, emit_feedback_reported};
+use crate::contract::RatingContract;
+use crate::events::{
+    emit_feedback_moderated, emit_feedback_reported, emit_rating_flag_resolved, emit_rating_flagged,
+};
 use crate::storage::{
-    get_feedback, increment_platform_stat, save_feedback_report, update_feedback,
+    append_moderation_log, get_escrow_rating, get_feedback, get_moderation_log_count,
+    get_moderation_log_entry, get_rating_flag, increment_platform_stat, remove_rating_flag,
+    save_escrow_rating, save_feedback_report, save_rating_flag, update_feedback,
 };
 use crate::error::Error;
-use crate::types::{FeedbackReport};
-use crate::validation::validate_moderation_action;
-use soroban_sdk::{Address, Env, String};
+use crate::types::{FeedbackReport, FlagResolution, ModerationLogEntry, RatingFlag};
+use crate::validation::{validate_moderation_action, validate_report_reason};
+use soroban_sdk::{Address, Env, String, Vec};
+
+const MAX_LOG_PAGE: u32 = 100;
 
 pub fn report_feedback(
     env: &Env,
@@ -68,6 +75,88 @@ pub fn moderate_feedback(
     Ok(())
 }
 
+/// Flag an escrow-bound rating for moderator review. A rating has at most one open flag.
+pub fn flag_rating(
+    env: &Env,
+    reporter: &Address,
+    rating_id: u64,
+    reason: &String,
+) -> Result<(), Error> {
+    reporter.require_auth();
+    validate_report_reason(reason)?;
+
+    let rating = get_escrow_rating(env, rating_id)?;
+    if rating.removed {
+        return Err(Error::RatingRemoved);
+    }
+    if get_rating_flag(env, rating_id).is_some() {
+        return Err(Error::AlreadyFlagged);
+    }
+
+    save_rating_flag(
+        env,
+        &RatingFlag {
+            rating_id,
+            reporter: reporter.clone(),
+            reason: reason.clone(),
+            flagged_at: env.ledger().timestamp(),
+        },
+    );
+    increment_platform_stat(env, &String::from_str(env, "total_reports"));
+
+    emit_rating_flagged(env, reporter, rating_id, reason);
+    Ok(())
+}
+
+/// Resolve a rating's open flag. Approving removal hides the rating and takes it out of the
+/// ratee's aggregate; either way the decision is appended to the moderation log.
+pub fn resolve_flag(
+    env: &Env,
+    moderator: &Address,
+    rating_id: u64,
+    resolution: FlagResolution,
+) -> Result<(), Error> {
+    check_moderator(env, moderator)?;
+
+    let flag = get_rating_flag(env, rating_id).ok_or(Error::FlagNotFound)?;
+    if resolution == FlagResolution::ApproveRemoval {
+        let mut rating = get_escrow_rating(env, rating_id)?;
+        rating.removed = true;
+        save_escrow_rating(env, &rating);
+        RatingContract::update_aggregate(env, &rating, false);
+    }
+    remove_rating_flag(env, rating_id);
+
+    append_moderation_log(
+        env,
+        &ModerationLogEntry {
+            rating_id,
+            reporter: flag.reporter,
+            reason: flag.reason,
+            moderator: moderator.clone(),
+            resolution,
+            resolved_at: env.ledger().timestamp(),
+        },
+    );
+
+    emit_rating_flag_resolved(env, moderator, rating_id, resolution);
+    Ok(())
+}
+
+/// Resolved flags, oldest first, at most 100 per page
+pub fn get_moderation_log(env: &Env, offset: u32, limit: u32) -> Vec<ModerationLogEntry> {
+    let mut entries = Vec::new(env);
+    let end = offset
+        .saturating_add(limit.min(MAX_LOG_PAGE))
+        .min(get_moderation_log_count(env));
+    for index in offset..end {
+        if let Some(entry) = get_moderation_log_entry(env, index) {
+            entries.push_back(entry);
+        }
+    }
+    entries
+}
+
 pub fn auto_moderate_feedback(_env: &Env, _feedback_content: &String) -> String {
     // Simple auto-moderation based on content analysis
     // In production, this would use more sophisticated algorithms
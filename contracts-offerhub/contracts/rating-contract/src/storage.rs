@@ -1,7 +1,12 @@
 use crate::types::{
-    Feedback, FeedbackReport, IncentiveRecord, RateLimitEntry, Rating, RatingStats,
-    RatingThreshold, ADMIN, FEEDBACK, FEEDBACK_REPORTS, INCENTIVE_RECORDS, MODERATOR,
-    PLATFORM_STATS, RATE_LIMITS, RATE_LIMIT_BYPASS, RATING, RATING_THRESHOLDS, REPUTATION_CONTRACT,
+    CategoryRatings, CategoryScores, EscrowRating, Feedback, FeedbackReport, IncentiveRecord,
+    MilestoneConfig, ModerationLogEntry, RateLimitEntry, Rating, RatingAggregate, RatingFlag,
+    RatingStats, RatingThreshold, ADMIN, CATEGORY_RATINGS, DEFAULT_TEN_RATINGS_COUNT,
+    DEFAULT_TOP_RATED_AVERAGE_BPS, DEFAULT_TOP_RATED_COUNT, ESCROW_FACTORY, ESCROW_RATED,
+    ESCROW_RATINGS, ESCROW_RATING_COUNT, FEEDBACK, FEEDBACK_REPORTS, INCENTIVE_RECORDS,
+    MILESTONES_MINTED, MILESTONE_CONFIG, MODERATION_LOG, MODERATION_LOG_COUNT, MODERATOR,
+    PLATFORM_STATS, RATE_LIMITS, RATE_LIMIT_BYPASS, RATING, RATINGS_GIVEN, RATINGS_RECEIVED,
+    RATING_AGGREGATES, RATING_CATEGORIES, RATING_FLAGS, RATING_THRESHOLDS, REPUTATION_CONTRACT,
     TOTAL_RATING_COUNT, USER_RATING_STATS, USER_RESTRICTIONS,
 };
 use crate::error::Error;
@@ -120,6 +125,202 @@ pub fn get_user_rating_history(env: &Env, user: &Address, limit: u32, offset: u3
     ratings
 }
 
+// Escrow-bound ratings, numbered from 1
+pub fn next_escrow_rating_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&ESCROW_RATING_COUNT)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&ESCROW_RATING_COUNT, &id);
+    id
+}
+
+pub fn save_escrow_rating(env: &Env, rating: &EscrowRating) {
+    let key = (ESCROW_RATINGS, rating.id);
+    env.storage().persistent().set(&key, rating);
+}
+
+pub fn get_escrow_rating(env: &Env, rating_id: u64) -> Result<EscrowRating, Error> {
+    let key = (ESCROW_RATINGS, rating_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(Error::RatingNotFound)
+}
+
+pub fn save_rating_categories(env: &Env, rating_id: u64, categories: &CategoryScores) {
+    let key = (RATING_CATEGORIES, rating_id);
+    env.storage().persistent().set(&key, categories);
+}
+
+pub fn get_rating_categories(env: &Env, rating_id: u64) -> Option<CategoryScores> {
+    let key = (RATING_CATEGORIES, rating_id);
+    env.storage().persistent().get(&key)
+}
+
+// Which rating, if any, a party left for an escrow
+pub fn get_escrow_rating_by(env: &Env, rater: &Address, escrow: &Address) -> Option<u64> {
+    let key = (ESCROW_RATED, rater.clone(), escrow.clone());
+    env.storage().persistent().get(&key)
+}
+
+pub fn set_escrow_rating_by(env: &Env, rater: &Address, escrow: &Address, rating_id: u64) {
+    let key = (ESCROW_RATED, rater.clone(), escrow.clone());
+    env.storage().persistent().set(&key, &rating_id);
+}
+
+// Per-user indexes of escrow-bound rating ids, by ratee and by rater
+pub fn index_escrow_rating(env: &Env, rating: &EscrowRating) {
+    append_rating_index(env, RATINGS_RECEIVED, &rating.ratee, rating.id);
+    append_rating_index(env, RATINGS_GIVEN, &rating.rater, rating.id);
+}
+
+fn append_rating_index(env: &Env, index: &[u8], user: &Address, rating_id: u64) {
+    let count = get_rating_index_count(env, index, user);
+    env.storage()
+        .persistent()
+        .set(&(index, user.clone(), count), &rating_id);
+    env.storage()
+        .persistent()
+        .set(&(index, user.clone()), &(count + 1));
+}
+
+pub fn get_rating_index_count(env: &Env, index: &[u8], user: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&(index, user.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_rating_index_entry(
+    env: &Env,
+    index: &[u8],
+    user: &Address,
+    position: u32,
+) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&(index, user.clone(), position))
+}
+
+pub fn get_rating_aggregate(env: &Env, user: &Address) -> RatingAggregate {
+    let key = (RATING_AGGREGATES, user.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| RatingAggregate {
+            user: user.clone(),
+            count: 0,
+            sum: 0,
+            average_bps: 0,
+            weighted_sum: 0,
+            weight_total: 0,
+            weighted_average_bps: 0,
+            histogram: Vec::from_array(env, [0; 5]),
+            last_updated: 0,
+        })
+}
+
+pub fn save_rating_aggregate(env: &Env, aggregate: &RatingAggregate) {
+    let key = (RATING_AGGREGATES, aggregate.user.clone());
+    env.storage().persistent().set(&key, aggregate);
+}
+
+pub fn get_category_ratings(env: &Env, user: &Address) -> CategoryRatings {
+    let key = (CATEGORY_RATINGS, user.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| CategoryRatings {
+            user: user.clone(),
+            count: 0,
+            communication_sum: 0,
+            quality_sum: 0,
+            timeliness_sum: 0,
+            communication_bps: 0,
+            quality_bps: 0,
+            timeliness_bps: 0,
+            last_updated: 0,
+        })
+}
+
+pub fn save_category_ratings(env: &Env, ratings: &CategoryRatings) {
+    let key = (CATEGORY_RATINGS, ratings.user.clone());
+    env.storage().persistent().set(&key, ratings);
+}
+
+pub fn get_milestone_config(env: &Env) -> MilestoneConfig {
+    env.storage()
+        .instance()
+        .get(&MILESTONE_CONFIG)
+        .unwrap_or(MilestoneConfig {
+            ten_ratings_count: DEFAULT_TEN_RATINGS_COUNT,
+            top_rated_count: DEFAULT_TOP_RATED_COUNT,
+            top_rated_average_bps: DEFAULT_TOP_RATED_AVERAGE_BPS,
+        })
+}
+
+pub fn save_milestone_config(env: &Env, config: &MilestoneConfig) {
+    env.storage().instance().set(&MILESTONE_CONFIG, config);
+}
+
+pub fn is_milestone_minted(env: &Env, user: &Address, milestone: &String) -> bool {
+    let key = (MILESTONES_MINTED, user.clone(), milestone.clone());
+    env.storage().persistent().has(&key)
+}
+
+pub fn set_milestone_minted(env: &Env, user: &Address, milestone: &String) {
+    let key = (MILESTONES_MINTED, user.clone(), milestone.clone());
+    env.storage().persistent().set(&key, &true);
+}
+
+// Rating flags and the moderation log
+pub fn save_rating_flag(env: &Env, flag: &RatingFlag) {
+    let key = (RATING_FLAGS, flag.rating_id);
+    env.storage().persistent().set(&key, flag);
+}
+
+pub fn get_rating_flag(env: &Env, rating_id: u64) -> Option<RatingFlag> {
+    let key = (RATING_FLAGS, rating_id);
+    env.storage().persistent().get(&key)
+}
+
+pub fn remove_rating_flag(env: &Env, rating_id: u64) {
+    let key = (RATING_FLAGS, rating_id);
+    env.storage().persistent().remove(&key);
+}
+
+pub fn append_moderation_log(env: &Env, entry: &ModerationLogEntry) {
+    let index = get_moderation_log_count(env);
+    env.storage()
+        .persistent()
+        .set(&(MODERATION_LOG, index), entry);
+    env.storage()
+        .instance()
+        .set(&MODERATION_LOG_COUNT, &(index + 1));
+}
+
+pub fn get_moderation_log_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&MODERATION_LOG_COUNT)
+        .unwrap_or(0)
+}
+
+pub fn get_moderation_log_entry(env: &Env, index: u32) -> Option<ModerationLogEntry> {
+    env.storage().persistent().get(&(MODERATION_LOG, index))
+}
+
+pub fn save_escrow_factory(env: &Env, factory: &Address) {
+    env.storage().instance().set(&ESCROW_FACTORY, factory);
+}
+
+pub fn get_escrow_factory(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ESCROW_FACTORY)
+}
+
 // New function to get user feedback IDs
 pub fn get_user_feedback_ids(env: &Env, user: &Address) -> Vec<String> {
     let mut feedback_ids = Vec::new(env);
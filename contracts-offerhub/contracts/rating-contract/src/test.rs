@@ -4,9 +4,9 @@ use crate::Contract;
 use rand::{distributions::Alphanumeric, Rng};
 use reputation_nft_contract::{Contract as ReputationContract, Error as E};
 use soroban_sdk::{
-    log,
+    contract, contractimpl, log,
     testutils::{Address as _, Ledger},
-    vec, Address, Env, String, Vec,
+    vec, Address, Bytes, BytesN, Env, String, Symbol, Vec,
 };
 extern crate std;
 
@@ -1236,4 +1236,569 @@ fn test_submit_rating_fails() {
     client.pause(&admin.clone());
 
     client.submit_rating(&caller, &rated_user, &String::from_str(&env, "cY"), &5u32, &feedback, &category);
-}
\ No newline at end of file
+}
+#[contract]
+pub struct MockEscrow;
+
+#[contractimpl]
+impl MockEscrow {
+    pub fn setup(env: Env, client: Address, freelancer: Address, completed: bool) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "parties"), &(client, freelancer));
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "completed"), &completed);
+    }
+
+    pub fn get_parties(env: Env) -> (Address, Address) {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "parties"))
+            .unwrap()
+    }
+
+    pub fn is_completed(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "completed"))
+            .unwrap()
+    }
+}
+
+#[contract]
+pub struct MockEscrowFactory;
+
+#[contractimpl]
+impl MockEscrowFactory {
+    pub fn add_escrow(env: Env, escrow: Address) {
+        env.storage().instance().set(&escrow, &1u32);
+    }
+
+    pub fn get_escrow_id_by_address(env: Env, escrow_address: Address) -> Option<u32> {
+        env.storage().instance().get(&escrow_address)
+    }
+}
+
+#[contract]
+pub struct MockReputationOracle;
+
+#[contractimpl]
+impl MockReputationOracle {
+    pub fn set_score(env: Env, user: Address, score: u32) {
+        env.storage().instance().set(&user, &score);
+    }
+
+    pub fn get_user_reputation_score(env: Env, user: Address) -> u32 {
+        env.storage().instance().get(&user).unwrap_or(0)
+    }
+}
+
+fn init_with_factory(env: &Env, rating: &ContractClient, admin: &Address) {
+    rating.init(admin);
+    rating.set_escrow_factory(admin, &env.register(MockEscrowFactory, ()));
+}
+
+// Escrow deployed through the rating contract's factory
+fn setup_escrow(
+    env: &Env,
+    rating: &ContractClient,
+    client: &Address,
+    freelancer: &Address,
+    completed: bool,
+) -> Address {
+    let escrow_id = env.register(MockEscrow, ());
+    MockEscrowClient::new(env, &escrow_id).setup(client, freelancer, &completed);
+    MockEscrowFactoryClient::new(env, &rating.get_escrow_factory().unwrap()).add_escrow(&escrow_id);
+    escrow_id
+}
+
+#[test]
+fn test_submit_escrow_rating() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &create_contract(&env));
+    init_with_factory(&env, &client, &Address::generate(&env));
+
+    let escrow_client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let escrow = setup_escrow(&env, &client, &escrow_client, &freelancer, true);
+    let feedback_hash = BytesN::from_array(&env, &[7; 32]);
+
+    let rating_id =
+        client.submit_escrow_rating(&escrow_client, &freelancer, &escrow, &4, &feedback_hash);
+    assert_eq!(rating_id, 1);
+    let rating = client.get_escrow_rating(&rating_id);
+    assert_eq!(rating.rater, escrow_client);
+    assert_eq!(rating.ratee, freelancer);
+    assert_eq!(rating.score, 4);
+    assert_eq!(rating.feedback_hash, feedback_hash);
+
+    // Either party may rate the other
+    assert_eq!(
+        client.submit_escrow_rating(&freelancer, &escrow_client, &escrow, &5, &feedback_hash),
+        2
+    );
+    assert_eq!(
+        client.get_user_rating_stats(&freelancer).average_rating,
+        400
+    );
+}
+
+#[test]
+fn test_submit_escrow_rating_rejects_unfinished_or_outside_engagements() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &create_contract(&env));
+    let admin = Address::generate(&env);
+    init_with_factory(&env, &client, &admin);
+
+    let escrow_client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let feedback_hash = BytesN::from_array(&env, &[1; 32]);
+
+    let open_escrow = setup_escrow(&env, &client, &escrow_client, &freelancer, false);
+    assert_eq!(
+        client.try_submit_escrow_rating(
+            &escrow_client,
+            &freelancer,
+            &open_escrow,
+            &5,
+            &feedback_hash
+        ),
+        Err(Ok(Error::EngagementNotCompleted))
+    );
+
+    let escrow = setup_escrow(&env, &client, &escrow_client, &freelancer, true);
+    assert_eq!(
+        client.try_submit_escrow_rating(&outsider, &freelancer, &escrow, &1, &feedback_hash),
+        Err(Ok(Error::NotEngagementParty))
+    );
+    assert_eq!(
+        client.try_submit_escrow_rating(&escrow_client, &freelancer, &escrow, &6, &feedback_hash),
+        Err(Ok(Error::InvalidRating))
+    );
+
+    // Only escrows the factory deployed can be rated
+    let rogue = env.register(MockEscrow, ());
+    MockEscrowClient::new(&env, &rogue).setup(&escrow_client, &freelancer, &true);
+    assert_eq!(
+        client.try_submit_escrow_rating(&escrow_client, &freelancer, &rogue, &5, &feedback_hash),
+        Err(Ok(Error::UnknownEscrow))
+    );
+    client.submit_escrow_rating(&escrow_client, &freelancer, &escrow, &5, &feedback_hash);
+}
+
+#[test]
+fn test_submit_escrow_rating_requires_escrow_factory() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &create_contract(&env));
+    client.init(&Address::generate(&env));
+
+    let escrow_client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let escrow = env.register(MockEscrow, ());
+    MockEscrowClient::new(&env, &escrow).setup(&escrow_client, &freelancer, &true);
+
+    // Without a factory no escrow can be vouched for, so ratings fail closed
+    assert_eq!(
+        client.try_submit_escrow_rating(
+            &escrow_client,
+            &freelancer,
+            &escrow,
+            &5,
+            &BytesN::from_array(&env, &[1; 32])
+        ),
+        Err(Ok(Error::EscrowFactoryNotSet))
+    );
+}
+
+#[test]
+fn test_escrow_rating_once_per_party() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &create_contract(&env));
+    init_with_factory(&env, &client, &Address::generate(&env));
+
+    let escrow_client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let escrow = setup_escrow(&env, &client, &escrow_client, &freelancer, true);
+    let feedback_hash = BytesN::from_array(&env, &[3; 32]);
+
+    assert!(!client.has_rated(&escrow_client, &escrow));
+    client.submit_escrow_rating(&escrow_client, &freelancer, &escrow, &1, &feedback_hash);
+    assert!(client.has_rated(&escrow_client, &escrow));
+    assert!(!client.has_rated(&freelancer, &escrow));
+
+    assert_eq!(
+        client.try_submit_escrow_rating(&escrow_client, &freelancer, &escrow, &1, &feedback_hash),
+        Err(Ok(Error::AlreadyRated))
+    );
+    assert_eq!(client.get_rating_summary(&freelancer).count, 1);
+
+    // A new engagement between the same parties can be rated again
+    let next_escrow = setup_escrow(&env, &client, &escrow_client, &freelancer, true);
+    client.submit_escrow_rating(
+        &escrow_client,
+        &freelancer,
+        &next_escrow,
+        &5,
+        &feedback_hash,
+    );
+    assert_eq!(client.get_rating_summary(&freelancer).count, 2);
+}
+
+#[test]
+fn test_paginated_ratings_per_user() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &create_contract(&env));
+    init_with_factory(&env, &client, &Address::generate(&env));
+
+    let freelancer = Address::generate(&env);
+    let repeat_client = Address::generate(&env);
+    let feedback_hash = BytesN::from_array(&env, &[4; 32]);
+    let mut escrows = Vec::new(&env);
+    for score in [3u32, 4, 5] {
+        env.ledger().with_mut(|l| l.sequence_number += 1);
+        let escrow = setup_escrow(&env, &client, &repeat_client, &freelancer, true);
+        client.submit_escrow_rating(&repeat_client, &freelancer, &escrow, &score, &feedback_hash);
+        escrows.push_back(escrow);
+    }
+    let other_client = Address::generate(&env);
+    let escrow = setup_escrow(&env, &client, &other_client, &freelancer, true);
+    client.submit_escrow_rating(&other_client, &freelancer, &escrow, &2, &feedback_hash);
+
+    let page = client.get_ratings_for(&freelancer, &1, &2);
+    assert_eq!(page.len(), 2);
+    let second = page.get(0).unwrap();
+    assert_eq!(second.score, 4);
+    assert_eq!(second.escrow, escrows.get(1).unwrap());
+    assert_eq!(second.feedback_hash, feedback_hash);
+    assert_eq!(second.ledger, page.get(1).unwrap().ledger - 1);
+    assert_eq!(
+        client
+            .get_ratings_for(&freelancer, &3, &10)
+            .get(0)
+            .unwrap()
+            .rater,
+        other_client
+    );
+    assert_eq!(client.get_ratings_for(&freelancer, &4, &10).len(), 0);
+
+    let given = client.get_ratings_by(&repeat_client, &0, &10);
+    assert_eq!(given.len(), 3);
+    assert!(given.iter().all(|rating| rating.ratee == freelancer));
+    assert_eq!(client.get_ratings_by(&freelancer, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_reveal_feedback_matches_commitment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &create_contract(&env));
+    init_with_factory(&env, &client, &Address::generate(&env));
+
+    let escrow_client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let escrow = setup_escrow(&env, &client, &escrow_client, &freelancer, true);
+    let review = Bytes::from_slice(&env, b"Delivered on time, great communication");
+    let feedback_hash: BytesN<32> = env.crypto().sha256(&review).into();
+
+    let rating_id =
+        client.submit_escrow_rating(&escrow_client, &freelancer, &escrow, &5, &feedback_hash);
+    assert_eq!(client.get_escrow_rating(&rating_id).feedback_uri, None);
+
+    assert_eq!(
+        client.try_reveal_feedback(&rating_id, &String::from_str(&env, "")),
+        Err(Ok(Error::InvalidFeedbackUri))
+    );
+    let text_uri = String::from_str(&env, "ipfs://bafyreview");
+    client.reveal_feedback(&rating_id, &text_uri);
+    assert_eq!(
+        env.auths()[0].0,
+        escrow_client,
+        "only the rater can reveal their feedback"
+    );
+
+    // The platform checks the text it serves from the URI against the commitment
+    let rating = client.get_escrow_rating(&rating_id);
+    assert_eq!(rating.feedback_uri, Some(text_uri.clone()));
+    let served: BytesN<32> = env.crypto().sha256(&review).into();
+    assert_eq!(served, rating.feedback_hash);
+
+    assert_eq!(
+        client.try_reveal_feedback(&rating_id, &String::from_str(&env, "ipfs://edited")),
+        Err(Ok(Error::FeedbackAlreadyRevealed))
+    );
+}
+
+#[test]
+fn test_rating_summary_feeds_reputation_score() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = create_contract(&env);
+    let client = ContractClient::new(&env, &contract_id);
+    let reputation_cid = env.register(ReputationContract, ());
+    let r_client = reputation_nft_contract::ContractClient::new(&env, &reputation_cid);
+
+    let admin = Address::generate(&env);
+    init_with_factory(&env, &client, &admin);
+    r_client.init(&admin);
+    r_client.add_minter(&admin, &contract_id);
+    client.set_reputation_contract(&admin, &reputation_cid);
+
+    let freelancer = Address::generate(&env);
+    let feedback_hash = BytesN::from_array(&env, &[3; 32]);
+    for score in [4u32, 5, 5] {
+        let escrow_client = Address::generate(&env);
+        let escrow = setup_escrow(&env, &client, &escrow_client, &freelancer, true);
+        client.submit_escrow_rating(&escrow_client, &freelancer, &escrow, &score, &feedback_hash);
+    }
+
+    let summary = client.get_rating_summary(&freelancer);
+    assert_eq!(summary.count, 3);
+    assert_eq!(summary.sum, 14);
+    assert_eq!(summary.average_bps, 46_666);
+    assert_eq!(summary.histogram, vec![&env, 0, 0, 0, 1, 2]);
+    assert_eq!(r_client.get_user_reputation_score(&freelancer), 466);
+}
+
+#[test]
+fn test_rating_summary_weights_raters_by_reputation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &create_contract(&env));
+    let admin = Address::generate(&env);
+    init_with_factory(&env, &client, &admin);
+
+    let oracle = env.register(MockReputationOracle, ());
+    client.set_reputation_contract(&admin, &oracle);
+
+    let freelancer = Address::generate(&env);
+    let veteran = Address::generate(&env);
+    let throwaway = Address::generate(&env);
+    MockReputationOracleClient::new(&env, &oracle).set_score(&veteran, &500);
+    let feedback_hash = BytesN::from_array(&env, &[9; 32]);
+
+    let escrow = setup_escrow(&env, &client, &veteran, &freelancer, true);
+    let veteran_rating =
+        client.submit_escrow_rating(&veteran, &freelancer, &escrow, &5, &feedback_hash);
+    let escrow = setup_escrow(&env, &client, &throwaway, &freelancer, true);
+    let throwaway_rating =
+        client.submit_escrow_rating(&throwaway, &freelancer, &escrow, &1, &feedback_hash);
+    assert_eq!(client.get_escrow_rating(&veteran_rating).weight, 600);
+    assert_eq!(client.get_escrow_rating(&throwaway_rating).weight, 100);
+
+    let summary = client.get_rating_summary(&freelancer);
+    assert_eq!(summary.average_bps, 30_000);
+    assert_eq!(summary.weight_total, 700);
+    assert_eq!(summary.weighted_average_bps, 44_285);
+}
+
+#[test]
+fn test_category_ratings_feed_skill_scores() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = create_contract(&env);
+    let client = ContractClient::new(&env, &contract_id);
+    let reputation_cid = env.register(ReputationContract, ());
+    let r_client = reputation_nft_contract::ContractClient::new(&env, &reputation_cid);
+
+    let admin = Address::generate(&env);
+    init_with_factory(&env, &client, &admin);
+    r_client.init(&admin);
+    r_client.add_minter(&admin, &contract_id);
+    client.set_reputation_contract(&admin, &reputation_cid);
+
+    let freelancer = Address::generate(&env);
+    let feedback_hash = BytesN::from_array(&env, &[6; 32]);
+    let rate = |categories: CategoryScores| {
+        let escrow_client = Address::generate(&env);
+        let escrow = setup_escrow(&env, &client, &escrow_client, &freelancer, true);
+        client.submit_categorized_rating(
+            &escrow_client,
+            &freelancer,
+            &escrow,
+            &4,
+            &feedback_hash,
+            &categories,
+        )
+    };
+    rate(CategoryScores {
+        communication: 5,
+        quality: 4,
+        timeliness: 3,
+    });
+    let rating_id = rate(CategoryScores {
+        communication: 4,
+        quality: 4,
+        timeliness: 2,
+    });
+    assert_eq!(
+        client.get_rating_categories(&rating_id).unwrap().timeliness,
+        2
+    );
+
+    // Ratings without category scores leave the category averages alone
+    let escrow_client = Address::generate(&env);
+    let escrow = setup_escrow(&env, &client, &escrow_client, &freelancer, true);
+    client.submit_escrow_rating(&escrow_client, &freelancer, &escrow, &5, &feedback_hash);
+
+    let categories = client.get_category_ratings(&freelancer);
+    assert_eq!(categories.count, 2);
+    assert_eq!(categories.communication_bps, 45_000);
+    assert_eq!(categories.quality_bps, 40_000);
+    assert_eq!(categories.timeliness_bps, 25_000);
+    assert_eq!(
+        r_client.get_skill_score(&freelancer, &Symbol::new(&env, "communication")),
+        450
+    );
+    assert_eq!(
+        r_client.get_skill_score(&freelancer, &Symbol::new(&env, "timeliness")),
+        250
+    );
+
+    let escrow = setup_escrow(&env, &client, &escrow_client, &freelancer, true);
+    assert_eq!(
+        client.try_submit_categorized_rating(
+            &freelancer,
+            &escrow_client,
+            &escrow,
+            &4,
+            &feedback_hash,
+            &CategoryScores {
+                communication: 0,
+                quality: 4,
+                timeliness: 4,
+            },
+        ),
+        Err(Ok(Error::InvalidRating))
+    );
+}
+
+#[test]
+fn test_milestones_mint_when_aggregate_crosses_thresholds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = create_contract(&env);
+    let client = ContractClient::new(&env, &contract_id);
+    let reputation_cid = env.register(ReputationContract, ());
+    let r_client = reputation_nft_contract::ContractClient::new(&env, &reputation_cid);
+
+    let admin = Address::generate(&env);
+    init_with_factory(&env, &client, &admin);
+    r_client.init(&admin);
+    r_client.add_minter(&admin, &contract_id);
+    client.set_reputation_contract(&admin, &reputation_cid);
+    client.set_milestone_config(
+        &admin,
+        &MilestoneConfig {
+            ten_ratings_count: 3,
+            top_rated_count: 3,
+            top_rated_average_bps: 46_000,
+        },
+    );
+
+    let freelancer = Address::generate(&env);
+    let feedback_hash = BytesN::from_array(&env, &[5; 32]);
+    let rate = |score: u32| {
+        let escrow_client = Address::generate(&env);
+        let escrow = setup_escrow(&env, &client, &escrow_client, &freelancer, true);
+        client.submit_escrow_rating(&escrow_client, &freelancer, &escrow, &score, &feedback_hash);
+    };
+
+    rate(4);
+    assert_eq!(r_client.get_user_achievements(&freelancer).len(), 0);
+    // First 5-star
+    rate(5);
+    assert_eq!(r_client.get_user_achievements(&freelancer).len(), 1);
+    // Rating count and top-rated average reached together
+    rate(5);
+    assert_eq!(r_client.get_user_achievements(&freelancer).len(), 3);
+    // Milestones are minted once
+    rate(5);
+    assert_eq!(r_client.get_user_achievements(&freelancer).len(), 3);
+
+    assert_eq!(
+        client.try_set_milestone_config(
+            &admin,
+            &MilestoneConfig {
+                ten_ratings_count: 10,
+                top_rated_count: 20,
+                top_rated_average_bps: 60_000,
+            },
+        ),
+        Err(Ok(Error::InvalidMilestoneConfig))
+    );
+}
+
+#[test]
+fn test_flag_and_resolve_rating() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &create_contract(&env));
+    let admin = Address::generate(&env);
+    let moderator = Address::generate(&env);
+    init_with_factory(&env, &client, &admin);
+    client.add_moderator(&admin, &moderator);
+
+    let freelancer = Address::generate(&env);
+    let feedback_hash = BytesN::from_array(&env, &[9; 32]);
+    let mut rating_ids = Vec::new(&env);
+    for score in [5u32, 1] {
+        let escrow_client = Address::generate(&env);
+        let escrow = setup_escrow(&env, &client, &escrow_client, &freelancer, true);
+        rating_ids.push_back(client.submit_escrow_rating(
+            &escrow_client,
+            &freelancer,
+            &escrow,
+            &score,
+            &feedback_hash,
+        ));
+    }
+    let abusive = rating_ids.get(1).unwrap();
+    let reason = String::from_str(&env, "retaliatory review");
+
+    // A dismissed flag leaves the rating counted
+    client.flag_rating(&freelancer, &abusive, &reason);
+    assert_eq!(
+        client.try_flag_rating(&freelancer, &abusive, &reason),
+        Err(Ok(Error::AlreadyFlagged))
+    );
+    assert_eq!(
+        client.try_resolve_flag(&freelancer, &abusive, &FlagResolution::Dismiss),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.resolve_flag(&moderator, &abusive, &FlagResolution::Dismiss);
+    assert_eq!(client.get_rating_flag(&abusive), None);
+    assert_eq!(client.get_rating_summary(&freelancer).count, 2);
+
+    client.flag_rating(&freelancer, &abusive, &reason);
+    client.resolve_flag(&moderator, &abusive, &FlagResolution::ApproveRemoval);
+    assert!(client.get_escrow_rating(&abusive).removed);
+    let summary = client.get_rating_summary(&freelancer);
+    assert_eq!(summary.count, 1);
+    assert_eq!(summary.average_bps, 50_000);
+    assert_eq!(summary.weighted_average_bps, 50_000);
+    assert_eq!(summary.histogram, vec![&env, 0, 0, 0, 0, 1]);
+
+    assert_eq!(
+        client.try_flag_rating(&freelancer, &abusive, &reason),
+        Err(Ok(Error::RatingRemoved))
+    );
+    assert_eq!(
+        client.try_resolve_flag(&moderator, &abusive, &FlagResolution::Dismiss),
+        Err(Ok(Error::FlagNotFound))
+    );
+
+    let log = client.get_moderation_log(&0, &10);
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.get(0).unwrap().resolution, FlagResolution::Dismiss);
+    let removal = log.get(1).unwrap();
+    assert_eq!(removal.resolution, FlagResolution::ApproveRemoval);
+    assert_eq!(removal.moderator, moderator);
+    assert_eq!(removal.reporter, freelancer);
+}
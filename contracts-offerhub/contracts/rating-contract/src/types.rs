@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, symbol_short, Address, String, Symbol, Vec};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, String, Symbol, Vec};
 use crate::error::Error;
 
 #[contracttype]
@@ -13,6 +13,108 @@ pub struct Rating {
     pub work_category: String,
 }
 
+/// A rating bound to a completed escrow engagement. `feedback_hash` commits to the
+/// off-chain review text, which the rater may later reveal at `feedback_uri`. Optional
+/// per-category scores are stored separately under the rating's id. `weight` is the rater's weight
+/// when the rating was submitted, at `timestamp` and ledger `ledger`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EscrowRating {
+    pub id: u64,
+    pub rater: Address,
+    pub ratee: Address,
+    pub escrow: Address,
+    pub score: u32,
+    pub feedback_hash: BytesN<32>,
+    pub feedback_uri: Option<String>,
+    pub weight: u32,
+    pub timestamp: u64,
+    pub ledger: u32,
+    pub removed: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum FlagResolution {
+    ApproveRemoval = 0,
+    Dismiss = 1,
+}
+
+/// An open report against an escrow-bound rating, awaiting a moderator.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RatingFlag {
+    pub rating_id: u64,
+    pub reporter: Address,
+    pub reason: String,
+    pub flagged_at: u64,
+}
+
+/// A resolved flag, kept so moderation decisions can be audited.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModerationLogEntry {
+    pub rating_id: u64,
+    pub reporter: Address,
+    pub reason: String,
+    pub moderator: Address,
+    pub resolution: FlagResolution,
+    pub resolved_at: u64,
+}
+
+/// 1 to 5 star scores for individual aspects of an engagement
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CategoryScores {
+    pub communication: u32,
+    pub quality: u32,
+    pub timeliness: u32,
+}
+
+/// Running per-category aggregate of a user's escrow-bound ratings that carried category
+/// scores. Averages are in basis points of a star like `RatingAggregate::average_bps`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CategoryRatings {
+    pub user: Address,
+    pub count: u32,
+    pub communication_sum: u32,
+    pub quality_sum: u32,
+    pub timeliness_sum: u32,
+    pub communication_bps: u32,
+    pub quality_bps: u32,
+    pub timeliness_bps: u32,
+    pub last_updated: u64,
+}
+
+/// Running aggregate of a user's escrow-bound ratings. `average_bps` is the average score in
+/// basis points of a star (45_000 = 4.5 stars) and `histogram` counts 1 to 5 star ratings.
+/// `weighted_average_bps` weighs each rating by its rater's own reputation.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RatingAggregate {
+    pub user: Address,
+    pub count: u32,
+    pub sum: u32,
+    pub average_bps: u32,
+    pub weighted_sum: u64,
+    pub weight_total: u64,
+    pub weighted_average_bps: u32,
+    pub histogram: Vec<u32>,
+    pub last_updated: u64,
+}
+
+/// Aggregate thresholds at which the rating contract mints milestone achievements on the
+/// reputation NFT contract. A first 5-star rating always counts.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MilestoneConfig {
+    pub ten_ratings_count: u32,
+    pub top_rated_count: u32,
+    pub top_rated_average_bps: u32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Feedback {
@@ -133,6 +235,20 @@ pub const USER_RESTRICTIONS: &[u8] = &[12];
 pub const RATE_LIMITS: &[u8] = &[13];
 pub const RATE_LIMIT_BYPASS: &[u8] = &[14];
 pub const CONTRACT_CONFIG: &[u8] = &[15];
+pub const ESCROW_RATINGS: &[u8] = &[16];
+pub const ESCROW_RATING_COUNT: &[u8] = &[17];
+pub const ESCROW_FACTORY: &[u8] = &[18];
+pub const RATING_AGGREGATES: &[u8] = &[19];
+pub const RATING_FLAGS: &[u8] = &[20];
+pub const MODERATION_LOG: &[u8] = &[21];
+pub const MODERATION_LOG_COUNT: &[u8] = &[22];
+pub const ESCROW_RATED: &[u8] = &[23];
+pub const MILESTONE_CONFIG: &[u8] = &[24];
+pub const MILESTONES_MINTED: &[u8] = &[25];
+pub const RATINGS_RECEIVED: &[u8] = &[26];
+pub const RATINGS_GIVEN: &[u8] = &[27];
+pub const CATEGORY_RATINGS: &[u8] = &[28];
+pub const RATING_CATEGORIES: &[u8] = &[29];
 
 // Default configuration values
 pub const DEFAULT_MAX_RATING_PER_DAY: u32 = 10;          // 10 ratings per day
@@ -153,10 +269,19 @@ pub const DEFAULT_RESTRICTION_THRESHOLD: u32 = 250; // 2.50 average rating
 pub const DEFAULT_WARNING_THRESHOLD: u32 = 300; // 3.00 average rating
 pub const DEFAULT_TOP_RATED_THRESHOLD: u32 = 480; // 4.80 average rating
 
+// Default milestone thresholds
+pub const DEFAULT_TEN_RATINGS_COUNT: u32 = 10;
+pub const DEFAULT_TOP_RATED_COUNT: u32 = 20;
+pub const DEFAULT_TOP_RATED_AVERAGE_BPS: u32 = 48_000; // 4.80 average rating
+
+// A rater's weight is this base plus their reputation score (average x 100), so an account with
+// no reputation counts a sixth as much as a top-rated one
+pub const RATER_BASE_WEIGHT: u32 = 100;
+
 pub const TOTAL_RATING_COUNT: Symbol = symbol_short!("TOTALRATE");
 
 pub const MAX_RATING_AGE: u64 = 30 * 24 * 60 * 60; // 30 days in seconds
-pub const PAUSED: Symbol = symbol_short!("PAUSED");
+pub use offerhub_pausable::PAUSED;
 
 pub fn require_auth(address: &Address) -> Result<(), Error> {
     address.require_auth();
@@ -1,6 +1,6 @@
 use crate::storage::{get_user_restriction, has_rated_contract};
 use crate::error::Error;
-use crate::types::{MAX_FEEDBACK_LENGTH, MAX_RATING, MIN_RATING};
+use crate::types::{CategoryScores, MAX_FEEDBACK_LENGTH, MAX_RATING, MIN_RATING};
 use soroban_sdk::{Address, Env, String};
 
 // Additional validation constants
@@ -11,6 +11,7 @@ const MIN_WORK_CATEGORY_LENGTH: u32 = 2;
 const MAX_WORK_CATEGORY_LENGTH: u32 = 50;
 const MIN_REPORT_REASON_LENGTH: u32 = 10;
 const MAX_REPORT_REASON_LENGTH: u32 = 200;
+const MAX_FEEDBACK_URI_LENGTH: u32 = 256;
 
 /// Validate rating value (1-5)
 pub fn validate_rating(rating: u32) -> Result<(), Error> {
@@ -56,6 +57,23 @@ pub fn validate_work_category(work_category: &String) -> Result<(), Error> {
     Ok(())
 }
 
+/// Validate per-category scores (each 1-5)
+pub fn validate_category_scores(categories: &CategoryScores) -> Result<(), Error> {
+    validate_rating(categories.communication)?;
+    validate_rating(categories.quality)?;
+    validate_rating(categories.timeliness)?;
+    Ok(())
+}
+
+/// Validate a revealed feedback URI
+pub fn validate_feedback_uri(text_uri: &String) -> Result<(), Error> {
+    let len = text_uri.len();
+    if len == 0 || len > MAX_FEEDBACK_URI_LENGTH {
+        return Err(Error::InvalidFeedbackUri);
+    }
+    Ok(())
+}
+
 /// Validate report reason
 pub fn validate_report_reason(reason: &String) -> Result<(), Error> {
     let len = reason.len();
@@ -153,6 +171,24 @@ pub fn validate_submit_rating(
     Ok(())
 }
 
+/// Validation for submitting an escrow-bound rating; escrow membership is checked separately
+pub fn validate_submit_escrow_rating(
+    env: &Env,
+    rater: &Address,
+    ratee: &Address,
+    score: u32,
+) -> Result<(), Error> {
+    validate_different_addresses(rater, ratee)?;
+    validate_rating(score)?;
+
+    let restriction = get_user_restriction(env, rater);
+    if restriction == String::from_str(env, "restricted") {
+        return Err(Error::RatingRestricted);
+    }
+    check_spam_prevention(env, rater)?;
+    Ok(())
+}
+
 /// Comprehensive validation for reporting feedback
 pub fn validate_report_feedback(
     _env: &Env,
@@ -0,0 +1,203 @@
+use crate::error::Error;
+use crate::event::{
+    admin_initialized, config_set, referral_registered, reward_accrued, reward_claimed,
+};
+use crate::storage::{
+    get_admin, get_config, get_referrer, get_stats, is_reported, set_admin, set_config,
+    set_referrer, set_reported, set_stats,
+};
+use crate::types::{ReferralConfig, ReferrerStats};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
+
+const FACTORY_GET_ESCROW_ID: &str = "get_escrow_id_by_address";
+const FACTORY_GET_ESCROWS_BY_PARTY: &str = "get_escrows_by_party";
+const TOKEN_TRANSFER: &str = "transfer";
+const MAX_BPS: u32 = 10_000;
+
+pub struct ReferralContract;
+
+impl ReferralContract {
+    /// Initialize the contract with an admin address and reward configuration
+    pub fn initialize(env: Env, admin: Address, config: ReferralConfig) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+        Self::validate_config(&config)?;
+
+        set_admin(&env, &admin);
+        set_config(&env, &config);
+        admin_initialized(&env, admin);
+        config_set(&env, config);
+        Ok(())
+    }
+
+    pub fn set_config(env: Env, admin: Address, config: ReferralConfig) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        Self::validate_config(&config)?;
+
+        set_config(&env, &config);
+        config_set(&env, config);
+        Ok(())
+    }
+
+    pub fn get_config(env: Env) -> Option<ReferralConfig> {
+        get_config(&env)
+    }
+
+    /// Link a new user to the account that referred them. Each user can be referred
+    /// once, only before taking part in their first escrow, and never by themselves or by
+    /// anyone further down their own referral chain.
+    pub fn register_referral(env: Env, referee: Address, referrer: Address) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        referee.require_auth();
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+        if Self::is_upline(&env, &referee, &referrer) {
+            return Err(Error::SelfReferral);
+        }
+        if get_referrer(&env, &referee).is_some() {
+            return Err(Error::AlreadyReferred);
+        }
+
+        let escrows: Vec<Address> = env.invoke_contract(
+            &config.escrow_factory,
+            &Symbol::new(&env, FACTORY_GET_ESCROWS_BY_PARTY),
+            (referee.clone(), 0u32, 1u32).into_val(&env),
+        );
+        if !escrows.is_empty() {
+            return Err(Error::RefereeHasEscrows);
+        }
+
+        set_referrer(&env, &referee, &referrer);
+        let mut stats = get_stats(&env, &referrer);
+        stats.referees += 1;
+        set_stats(&env, &referrer, &stats);
+
+        referral_registered(&env, referrer, referee);
+        Ok(())
+    }
+
+    /// Called by an escrow deployed through the configured factory when it completes.
+    /// Accrues a reward to the referrer of each party, up to the per-referrer cap, and
+    /// returns the total accrued.
+    pub fn record_escrow_completion(
+        env: Env,
+        escrow: Address,
+        client: Address,
+        freelancer: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        escrow.require_auth();
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+
+        let escrow_id: Option<u32> = env.invoke_contract(
+            &config.escrow_factory,
+            &Symbol::new(&env, FACTORY_GET_ESCROW_ID),
+            (escrow.clone(),).into_val(&env),
+        );
+        if escrow_id.is_none() {
+            return Err(Error::UnknownEscrow);
+        }
+        if is_reported(&env, &escrow) {
+            return Err(Error::AlreadyReported);
+        }
+        set_reported(&env, &escrow);
+
+        let reward = amount.max(0) * config.reward_bps as i128 / MAX_BPS as i128;
+        let mut total = 0;
+        for referee in [client, freelancer] {
+            let Some(referrer) = get_referrer(&env, &referee) else {
+                continue;
+            };
+
+            let mut stats = get_stats(&env, &referrer);
+            let accrued = reward.min(config.max_reward_per_referrer - stats.accrued_total);
+            if accrued <= 0 {
+                continue;
+            }
+            stats.accrued_total += accrued;
+            stats.claimable += accrued;
+            set_stats(&env, &referrer, &stats);
+
+            reward_accrued(&env, referrer, referee, escrow.clone(), accrued);
+            total += accrued;
+        }
+        Ok(total)
+    }
+
+    /// Pay out a referrer's claimable rewards from the pool
+    pub fn claim(env: Env, referrer: Address) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        referrer.require_auth();
+        let config = get_config(&env).ok_or(Error::NotInitialized)?;
+
+        let mut stats = get_stats(&env, &referrer);
+        let amount = stats.claimable;
+        if amount <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+        stats.claimable = 0;
+        stats.claimed_total += amount;
+        set_stats(&env, &referrer, &stats);
+
+        env.invoke_contract::<()>(
+            &config.token,
+            &Symbol::new(&env, TOKEN_TRANSFER),
+            (env.current_contract_address(), referrer.clone(), amount).into_val(&env),
+        );
+
+        reward_claimed(&env, referrer, amount);
+        Ok(amount)
+    }
+
+    pub fn get_referrer(env: Env, referee: Address) -> Option<Address> {
+        get_referrer(&env, &referee)
+    }
+
+    pub fn get_referrer_stats(env: Env, referrer: Address) -> ReferrerStats {
+        get_stats(&env, &referrer)
+    }
+
+    /// Whether `account` is `referrer` itself or anywhere up its referral chain. Links are
+    /// only added when this is false, so the chain never loops and the walk ends.
+    fn is_upline(env: &Env, account: &Address, referrer: &Address) -> bool {
+        let mut current = referrer.clone();
+        loop {
+            if current == *account {
+                return true;
+            }
+            match get_referrer(env, &current) {
+                Some(next) => current = next,
+                None => return false,
+            }
+        }
+    }
+
+    fn validate_config(config: &ReferralConfig) -> Result<(), Error> {
+        if config.reward_bps > MAX_BPS || config.max_reward_per_referrer < 0 {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+        caller.require_auth();
+        let admin = get_admin(env).ok_or(Error::NotInitialized)?;
+        if *caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// Reward rate or cap is out of range
+    InvalidConfig = 4,
+    /// Users can't refer themselves or anyone up their own referral chain
+    SelfReferral = 5,
+    /// Referee already has a referrer
+    AlreadyReferred = 6,
+    /// Escrow was not deployed by the configured factory
+    UnknownEscrow = 7,
+    /// Escrow completion has already been reported
+    AlreadyReported = 8,
+    /// Referrer has no claimable rewards
+    NothingToClaim = 9,
+    /// Contract is already paused
+    AlreadyPaused = 10,
+    /// Contract is not paused
+    NotPaused = 11,
+    /// Contract is paused
+    ContractPaused = 12,
+    /// Referee has already taken part in an escrow, so the link would be retroactive
+    RefereeHasEscrows = 13,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
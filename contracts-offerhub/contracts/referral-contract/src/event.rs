@@ -0,0 +1,27 @@
+use crate::types::ReferralConfig;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub fn admin_initialized(e: &Env, admin: Address) {
+    let topics = (Symbol::new(e, "admin_initialized"),);
+    e.events().publish(topics, admin);
+}
+
+pub fn config_set(e: &Env, config: ReferralConfig) {
+    let topics = (Symbol::new(e, "referral_config_set"),);
+    e.events().publish(topics, config);
+}
+
+pub fn referral_registered(e: &Env, referrer: Address, referee: Address) {
+    let topics = (Symbol::new(e, "referral_registered"), referrer);
+    e.events().publish(topics, referee);
+}
+
+pub fn reward_accrued(e: &Env, referrer: Address, referee: Address, escrow: Address, reward: i128) {
+    let topics = (Symbol::new(e, "referral_reward_accrued"), referrer);
+    e.events().publish(topics, (referee, escrow, reward));
+}
+
+pub fn reward_claimed(e: &Env, referrer: Address, amount: i128) {
+    let topics = (Symbol::new(e, "referral_reward_claimed"), referrer);
+    e.events().publish(topics, amount);
+}
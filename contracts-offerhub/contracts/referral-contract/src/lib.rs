@@ -0,0 +1,68 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::ReferralContract;
+pub use crate::error::Error;
+pub use crate::types::{ReferralConfig, ReferrerStats};
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| ReferralContract::pause_admin(env));
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the contract with an admin address and reward configuration
+    pub fn initialize(env: Env, admin: Address, config: ReferralConfig) -> Result<(), Error> {
+        ReferralContract::initialize(env, admin, config)
+    }
+
+    /// Update the reward configuration (admin only)
+    pub fn set_config(env: Env, admin: Address, config: ReferralConfig) -> Result<(), Error> {
+        ReferralContract::set_config(env, admin, config)
+    }
+
+    /// Get the reward configuration
+    pub fn get_config(env: Env) -> Option<ReferralConfig> {
+        ReferralContract::get_config(env)
+    }
+
+    /// Record who referred a new user, before their first escrow
+    pub fn register_referral(env: Env, referee: Address, referrer: Address) -> Result<(), Error> {
+        ReferralContract::register_referral(env, referee, referrer)
+    }
+
+    /// Accrue referral rewards for a completed escrow (reported by the escrow itself)
+    pub fn record_escrow_completion(
+        env: Env,
+        escrow: Address,
+        client: Address,
+        freelancer: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        ReferralContract::record_escrow_completion(env, escrow, client, freelancer, amount)
+    }
+
+    /// Claim accrued referral rewards
+    pub fn claim(env: Env, referrer: Address) -> Result<i128, Error> {
+        ReferralContract::claim(env, referrer)
+    }
+
+    /// Get the account that referred a user
+    pub fn get_referrer(env: Env, referee: Address) -> Option<Address> {
+        ReferralContract::get_referrer(env, referee)
+    }
+
+    /// Get a referrer's referee count and reward balances
+    pub fn get_referrer_stats(env: Env, referrer: Address) -> ReferrerStats {
+        ReferralContract::get_referrer_stats(env, referrer)
+    }
+}
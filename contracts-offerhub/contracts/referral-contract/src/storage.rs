@@ -0,0 +1,74 @@
+use crate::types::{ReferralConfig, ReferrerStats};
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Config,
+    /// referee -> referrer
+    Referrer(Address),
+    Stats(Address),
+    /// escrow -> completion already rewarded
+    Reported(Address),
+}
+
+/// Get the admin address
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_config(env: &Env) -> Option<ReferralConfig> {
+    env.storage().instance().get(&DataKey::Config)
+}
+
+pub fn set_config(env: &Env, config: &ReferralConfig) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+pub fn get_referrer(env: &Env, referee: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Referrer(referee.clone()))
+}
+
+pub fn set_referrer(env: &Env, referee: &Address, referrer: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Referrer(referee.clone()), referrer);
+}
+
+pub fn get_stats(env: &Env, referrer: &Address) -> ReferrerStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Stats(referrer.clone()))
+        .unwrap_or(ReferrerStats {
+            referees: 0,
+            accrued_total: 0,
+            claimable: 0,
+            claimed_total: 0,
+        })
+}
+
+pub fn set_stats(env: &Env, referrer: &Address, stats: &ReferrerStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Stats(referrer.clone()), stats);
+}
+
+pub fn is_reported(env: &Env, escrow: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Reported(escrow.clone()))
+}
+
+pub fn set_reported(env: &Env, escrow: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Reported(escrow.clone()), &true);
+}
@@ -0,0 +1,197 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+#[contract]
+pub struct MockEscrowFactory;
+
+#[contractimpl]
+impl MockEscrowFactory {
+    pub fn add_escrow(env: Env, escrow: Address, id: u32) {
+        env.storage().instance().set(&escrow, &id);
+    }
+
+    pub fn get_escrow_id_by_address(env: Env, escrow_address: Address) -> Option<u32> {
+        env.storage().instance().get(&escrow_address)
+    }
+
+    pub fn add_party_escrow(env: Env, party: Address, escrow: Address) {
+        let mut escrows = Self::get_escrows_by_party(env.clone(), party.clone(), 0, u32::MAX);
+        escrows.push_back(escrow);
+        env.storage().instance().set(&(party,), &escrows);
+    }
+
+    pub fn get_escrows_by_party(
+        env: Env,
+        party: Address,
+        _offset: u32,
+        _limit: u32,
+    ) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&(party,))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+struct Setup<'a> {
+    client: ContractClient<'a>,
+    admin: Address,
+    factory: MockEscrowFactoryClient<'a>,
+    token: TokenClient<'a>,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let factory_id = env.register(MockEscrowFactory, ());
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let client = ContractClient::new(env, &env.register(Contract, ()));
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &ReferralConfig {
+            token: token.clone(),
+            escrow_factory: factory_id.clone(),
+            reward_bps: 100,
+            max_reward_per_referrer: 150,
+        },
+    );
+    StellarAssetClient::new(env, &token).mint(&client.address, &10_000);
+    Setup {
+        client,
+        admin,
+        factory: MockEscrowFactoryClient::new(env, &factory_id),
+        token: TokenClient::new(env, &token),
+    }
+}
+
+#[test]
+fn test_rewards_accrue_up_to_cap_and_are_claimable() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let referrer = Address::generate(&env);
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    setup.client.register_referral(&client, &referrer);
+    setup.client.register_referral(&freelancer, &referrer);
+    assert_eq!(setup.client.get_referrer(&client), Some(referrer.clone()));
+
+    let escrow = Address::generate(&env);
+    assert_eq!(
+        setup
+            .client
+            .try_record_escrow_completion(&escrow, &client, &freelancer, &5_000),
+        Err(Ok(Error::UnknownEscrow))
+    );
+
+    // 1% of 5_000 for each referred party
+    setup.factory.add_escrow(&escrow, &1);
+    assert_eq!(
+        setup
+            .client
+            .record_escrow_completion(&escrow, &client, &freelancer, &5_000),
+        100
+    );
+    assert_eq!(
+        setup
+            .client
+            .try_record_escrow_completion(&escrow, &client, &freelancer, &5_000),
+        Err(Ok(Error::AlreadyReported))
+    );
+
+    // The cap limits what a second escrow can add
+    let second = Address::generate(&env);
+    setup.factory.add_escrow(&second, &2);
+    assert_eq!(
+        setup
+            .client
+            .record_escrow_completion(&second, &client, &freelancer, &10_000),
+        50
+    );
+
+    let stats = setup.client.get_referrer_stats(&referrer);
+    assert_eq!(stats.referees, 2);
+    assert_eq!(stats.accrued_total, 150);
+    assert_eq!(setup.client.claim(&referrer), 150);
+    assert_eq!(setup.token.balance(&referrer), 150);
+    assert_eq!(
+        setup.client.try_claim(&referrer),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_self_and_circular_referrals_are_rejected() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    assert_eq!(
+        setup.client.try_register_referral(&alice, &alice),
+        Err(Ok(Error::SelfReferral))
+    );
+    setup.client.register_referral(&bob, &alice);
+    assert_eq!(
+        setup.client.try_register_referral(&alice, &bob),
+        Err(Ok(Error::SelfReferral))
+    );
+    assert_eq!(
+        setup
+            .client
+            .try_register_referral(&bob, &Address::generate(&env)),
+        Err(Ok(Error::AlreadyReferred))
+    );
+
+    // Longer loops are caught too: carol was referred by bob, who was referred by alice
+    let carol = Address::generate(&env);
+    setup.client.register_referral(&carol, &bob);
+    assert_eq!(
+        setup.client.try_register_referral(&alice, &carol),
+        Err(Ok(Error::SelfReferral))
+    );
+}
+
+#[test]
+fn test_referral_rejected_after_first_escrow() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let referrer = Address::generate(&env);
+    let client = Address::generate(&env);
+
+    setup
+        .factory
+        .add_party_escrow(&client, &Address::generate(&env));
+    assert_eq!(
+        setup.client.try_register_referral(&client, &referrer),
+        Err(Ok(Error::RefereeHasEscrows))
+    );
+    assert_eq!(setup.client.get_referrer(&client), None);
+}
+
+#[test]
+fn test_pause_blocks_referrals_and_claims() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let referrer = Address::generate(&env);
+    let client = Address::generate(&env);
+
+    setup.client.pause(&setup.admin);
+    assert_eq!(
+        setup.client.try_register_referral(&client, &referrer),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        setup.client.try_claim(&referrer),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    setup.client.unpause(&setup.admin);
+    setup.client.register_referral(&client, &referrer);
+    assert_eq!(setup.client.get_referrer(&client), Some(referrer));
+}
@@ -0,0 +1,23 @@
+use soroban_sdk::{contracttype, Address};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralConfig {
+    /// Token rewards are paid in; the contract holds the reward pool
+    pub token: Address,
+    /// Factory that deployed every escrow allowed to report completions
+    pub escrow_factory: Address,
+    /// Reward per completed escrow, in basis points of the escrow amount
+    pub reward_bps: u32,
+    /// Most a single referrer can ever accrue
+    pub max_reward_per_referrer: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferrerStats {
+    pub referees: u32,
+    pub accrued_total: i128,
+    pub claimable: i128,
+    pub claimed_total: i128,
+}
@@ -1,23 +1,43 @@
-use crate::events::{emit_admin_changed, emit_minter_added, emit_minter_removed};
+use crate::events::{emit_admin_changed, emit_diagnostic, emit_minter_added, emit_minter_removed};
 use crate::storage::{
     add_minter as add_minter_storage, get_admin, is_admin, is_minter,
     remove_minter as remove_minter_storage,
 };
 use crate::error::Error;
 use crate::types::{require_auth};
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{symbol_short, Address, Env};
 
 pub fn check_admin(env: &Env, address: &Address) -> Result<(), Error> {
     if !is_admin(env, address) {
+        emit_diagnostic(
+            env,
+            symbol_short!("auth"),
+            (address.clone(), symbol_short!("denied")),
+        );
         return Err(Error::Unauthorized);
     }
+    emit_diagnostic(
+        env,
+        symbol_short!("auth"),
+        (address.clone(), symbol_short!("admin")),
+    );
     require_auth(env, address)
 }
 
 pub fn check_minter(env: &Env, address: &Address) -> Result<(), Error> {
-    if !is_minter(env, address) && !is_admin(env, address) {
+    let path = if is_minter(env, address) {
+        symbol_short!("minter")
+    } else if is_admin(env, address) {
+        symbol_short!("admin")
+    } else {
+        emit_diagnostic(
+            env,
+            symbol_short!("auth"),
+            (address.clone(), symbol_short!("denied")),
+        );
         return Err(Error::Unauthorized);
-    }
+    };
+    emit_diagnostic(env, symbol_short!("auth"), (address.clone(), path));
     require_auth(env, address)
 }
 
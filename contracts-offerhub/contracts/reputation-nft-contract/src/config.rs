@@ -0,0 +1,126 @@
+use crate::access::check_admin;
+use crate::error::Error;
+use crate::events::emit_param_changed;
+use crate::storage::{
+    get_address_param, get_symbol_param, get_u32_param, set_address_param, set_symbol_param,
+    set_u32_param,
+};
+use crate::types::{
+    RatingThreshold, CERTIFICATION_BADGE_THRESHOLD, COMPLETION_BADGE_THRESHOLD,
+    EXCELLENCE_MIN_AVERAGE, EXCELLENCE_MIN_RATINGS, MAX_ACHIEVEMENTS_PER_USER,
+    TOP_RATED_MIN_AVERAGE, TOP_RATED_MIN_RATINGS, VETERAN_MIN_AVERAGE, VETERAN_MIN_RATINGS,
+};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+// Well-known parameter keys
+pub const PARAM_EXCELLENCE_MIN_RATINGS: Symbol = symbol_short!("exc_rat");
+pub const PARAM_EXCELLENCE_MIN_AVERAGE: Symbol = symbol_short!("exc_avg");
+pub const PARAM_TOP_RATED_MIN_RATINGS: Symbol = symbol_short!("top_rat");
+pub const PARAM_TOP_RATED_MIN_AVERAGE: Symbol = symbol_short!("top_avg");
+pub const PARAM_VETERAN_MIN_RATINGS: Symbol = symbol_short!("vet_rat");
+pub const PARAM_VETERAN_MIN_AVERAGE: Symbol = symbol_short!("vet_avg");
+pub const PARAM_FEE_MANAGER: Symbol = symbol_short!("fee_mgr");
+pub const PARAM_KEEPER_REWARD: Symbol = symbol_short!("keep_rwd");
+pub const PARAM_KEEPER_ASSET: Symbol = symbol_short!("keep_ast");
+pub const PARAM_ROYALTY_BPS: Symbol = symbol_short!("roy_bps");
+pub const PARAM_ROYALTY_RECEIVER: Symbol = symbol_short!("roy_rcv");
+pub const PARAM_USER_REGISTRY: Symbol = symbol_short!("usr_reg");
+pub const PARAM_CERTIFICATIONS: Symbol = symbol_short!("cert_ctr");
+pub const PARAM_COMPLETION_BADGE: Symbol = symbol_short!("cmp_bdg");
+pub const PARAM_CERTIFICATION_BADGE: Symbol = symbol_short!("cert_bdg");
+pub const PARAM_MAX_ACHIEVEMENTS: Symbol = symbol_short!("max_ach");
+pub const PARAM_PARTNER_COOLDOWN: Symbol = symbol_short!("prtnr_cd");
+pub const PARAM_COUNCIL: Symbol = symbol_short!("council");
+pub const PARAM_RATING_CONTRACT: Symbol = symbol_short!("rating");
+pub const PARAM_ACHIEVEMENT_LIFETIME: Symbol = symbol_short!("ach_life");
+
+pub fn set_param_u32(env: &Env, caller: &Address, key: &Symbol, value: u32) -> Result<(), Error> {
+    check_admin(env, caller)?;
+    set_u32_param(env, key, value);
+    emit_param_changed(env, key, value);
+    Ok(())
+}
+
+pub fn set_param_address(
+    env: &Env,
+    caller: &Address,
+    key: &Symbol,
+    value: &Address,
+) -> Result<(), Error> {
+    check_admin(env, caller)?;
+    set_address_param(env, key, value);
+    emit_param_changed(env, key, value.clone());
+    Ok(())
+}
+
+pub fn set_param_symbol(
+    env: &Env,
+    caller: &Address,
+    key: &Symbol,
+    value: &Symbol,
+) -> Result<(), Error> {
+    check_admin(env, caller)?;
+    set_symbol_param(env, key, value);
+    emit_param_changed(env, key, value.clone());
+    Ok(())
+}
+
+pub fn get_param_u32(env: &Env, key: &Symbol) -> Option<u32> {
+    get_u32_param(env, key)
+}
+
+pub fn get_param_address(env: &Env, key: &Symbol) -> Option<Address> {
+    get_address_param(env, key)
+}
+
+pub fn get_param_symbol(env: &Env, key: &Symbol) -> Option<Symbol> {
+    get_symbol_param(env, key)
+}
+
+pub fn u32_or(env: &Env, key: &Symbol, default: u32) -> u32 {
+    get_u32_param(env, key).unwrap_or(default)
+}
+
+pub fn excellence_threshold(env: &Env) -> RatingThreshold {
+    RatingThreshold {
+        min_ratings: u32_or(env, &PARAM_EXCELLENCE_MIN_RATINGS, EXCELLENCE_MIN_RATINGS),
+        min_average: u32_or(env, &PARAM_EXCELLENCE_MIN_AVERAGE, EXCELLENCE_MIN_AVERAGE),
+    }
+}
+
+pub fn top_rated_threshold(env: &Env) -> RatingThreshold {
+    RatingThreshold {
+        min_ratings: u32_or(env, &PARAM_TOP_RATED_MIN_RATINGS, TOP_RATED_MIN_RATINGS),
+        min_average: u32_or(env, &PARAM_TOP_RATED_MIN_AVERAGE, TOP_RATED_MIN_AVERAGE),
+    }
+}
+
+pub fn veteran_threshold(env: &Env) -> RatingThreshold {
+    RatingThreshold {
+        min_ratings: u32_or(env, &PARAM_VETERAN_MIN_RATINGS, VETERAN_MIN_RATINGS),
+        min_average: u32_or(env, &PARAM_VETERAN_MIN_AVERAGE, VETERAN_MIN_AVERAGE),
+    }
+}
+
+pub fn completion_badge_threshold(env: &Env) -> u32 {
+    u32_or(env, &PARAM_COMPLETION_BADGE, COMPLETION_BADGE_THRESHOLD)
+}
+
+pub fn certification_badge_threshold(env: &Env) -> u32 {
+    u32_or(env, &PARAM_CERTIFICATION_BADGE, CERTIFICATION_BADGE_THRESHOLD)
+}
+
+// Per-user quota on indexed achievements
+pub fn max_achievements_per_user(env: &Env) -> u32 {
+    u32_or(env, &PARAM_MAX_ACHIEVEMENTS, MAX_ACHIEVEMENTS_PER_USER)
+}
+
+// Seconds a partner dApp waits between allowance mints; zero disables the cooldown
+pub fn partner_mint_cooldown(env: &Env) -> u64 {
+    u32_or(env, &PARAM_PARTNER_COOLDOWN, 0) as u64
+}
+
+// Seconds a soulbound achievement stays valid before maintenance burns it; zero keeps them forever
+pub fn achievement_lifetime(env: &Env) -> u64 {
+    u32_or(env, &PARAM_ACHIEVEMENT_LIFETIME, 0) as u64
+}
@@ -1,22 +1,64 @@
 use crate::access::{
-    add_minter as add_minter_impl, check_minter, check_owner, remove_minter as remove_minter_impl,
-    transfer_admin as transfer_admin_impl,
+    add_minter as add_minter_impl, check_admin, check_minter, check_owner,
+    remove_minter as remove_minter_impl, transfer_admin as transfer_admin_impl,
 };
 use crate::events::{
-    emit_achievement_minted, emit_achievement_unlocked, emit_batch_minted, emit_burned,
-    emit_minted, emit_reputaion_contract_initiated, emit_reputation_updated, emit_transferred,
+    emit_achievement_minted, emit_achievement_unlocked, emit_base_uri_changed, emit_batch_minted,
+    emit_burned, emit_content_committed, emit_contract_completed, emit_debug_mode_changed,
+    emit_delisted, emit_listed, emit_sold, emit_diagnostic, emit_maintenance_performed,
+    emit_mint_allowance_set, emit_minted, emit_partner_template_registered,
+    emit_reputaion_contract_initiated, emit_reputation_penalized, emit_reputation_proof_exported,
+    emit_reputation_updated, emit_skill_score_updated, emit_transferred,
+};
+use crate::config::{
+    certification_badge_threshold, completion_badge_threshold, excellence_threshold,
+    achievement_lifetime, max_achievements_per_user, partner_mint_cooldown, get_param_address,
+    get_param_symbol, get_param_u32, set_param_address as set_param_address_impl,
+    set_param_symbol as set_param_symbol_impl, set_param_u32 as set_param_u32_impl,
+    top_rated_threshold, u32_or, veteran_threshold, PARAM_FEE_MANAGER, PARAM_KEEPER_ASSET,
+    PARAM_KEEPER_REWARD, PARAM_ROYALTY_BPS, PARAM_ROYALTY_RECEIVER, PARAM_CERTIFICATIONS,
+    PARAM_COUNCIL, PARAM_RATING_CONTRACT, PARAM_USER_REGISTRY,
+};
+use crate::metadata::{
+    get_base_uri, get_metadata as get_token_metadata, store_metadata, template_uri, validate_uri,
+    commit_content as commit_content_impl, verify_uri as verify_uri_impl,
 };
-use crate::metadata::{get_metadata as get_token_metadata, store_metadata};
 use crate::storage::{
-    burn_token, check_achievement_prerequisite, decrement_achievement_stats, get_achievement_stats,
-    get_admin, get_leaderboard, get_reputation_score, get_token_owner, get_user_achievements,
-    get_user_rank, index_user_achievement, is_minter, next_token_id, remove_user_achievement_index,
-    save_admin, save_token_owner, store_reputation_score, token_exists, update_achievement_stats,
-    update_leaderboard,
+    burn_token, check_achievement_prerequisite, decrement_achievement_stats, extend_shared_ttl,
+    extend_token_ttls, get_admin, get_completed_contracts as get_completed_contracts_impl,
+    get_listing, get_mint_allowance as get_mint_allowance_impl, get_minter_list,
+    get_partner_last_mint, get_partner_template, save_partner_template,
+    set_base_uri as set_base_uri_impl, set_partner_last_mint, get_reputation_proof_head,
+    get_reputation_score, get_skill_scores, get_token_owner, get_activity_counters, get_minted_at,
+    get_user_achievement_counts, get_user_achievements, has_achievement_type,
+    increment_completed_contracts, index_user_achievement, is_admin, peek_next_token_id,
+    record_burn, record_mint, record_transfer, is_minter, next_token_id, prune_leaderboard,
+    record_reputation_proof, remove_listing, remove_user_achievement_index,
+    reputation_proof_issued_to, save_admin, save_listing, save_token_owner,
+    set_mint_allowance as set_mint_allowance_impl, store_reputation_score, store_skill_score,
+    token_exists, try_get_admin, update_achievement_stats, update_leaderboard,
+};
+use crate::types::{
+    AchievementType, ActivityCounters, ContractConfig, Listing, MaintenanceReport, Metadata,
+    PartnerTemplate, ReputationProof, TokenId, CONTRACT_VERSION, DEBUG_MODE, MAX_BASE_URI_LEN,
+    MAX_MAINTENANCE_ITEMS, MAX_ROYALTY_BPS, MARKET, PAUSED,
 };
-use crate::types::{AchievementType, Metadata, TokenId, PAUSED};
 use crate::error::Error;
-use soroban_sdk::{symbol_short, Address, Env, Map, String, Symbol, Vec};
+use offerhub_math::{bps_of, sub, Rounding};
+use offerhub_reentrancy::ReentrancyGuard;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{
+    symbol_short, token, Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol, Vec,
+};
+
+#[cfg(feature = "statistics")]
+use crate::storage::get_achievement_stats;
+#[cfg(feature = "leaderboard")]
+use crate::{
+    events::emit_leaderboard_root_committed,
+    merkle::{compute_root, leaf_hash, verify_proof},
+    storage::{get_leaderboard, get_leaderboard_root, get_user_rank, save_leaderboard_root},
+};
 
 pub struct ReputationNFTContract;
 
@@ -24,59 +66,42 @@ impl ReputationNFTContract {
     pub fn init(env: Env, admin: Address) -> Result<(), Error> {
         save_admin(&env, &admin);
         env.storage().instance().set(&PAUSED, &false);
+        offerhub_upgradeable::init_version::<Self>(&env);
         emit_reputaion_contract_initiated(&env, &admin);
         Ok(())
     }
 
     // Function to check if contract is paused
     pub fn is_paused(env: &Env) -> bool {
-        env.storage().instance().get(&PAUSED).unwrap_or(false)
+        offerhub_pausable::is_paused(env)
     }
 
-    // Function to pause the contract
-    pub fn pause(env: &Env, admin: Address) -> Result<(), Error> {
-        admin.require_auth();
-        let stored_admin = get_admin(env);
-        if stored_admin != admin {
-            return Err(Error::Unauthorized);
-        }
-        
-        if Self::is_paused(env) {
-            return Err(Error::AlreadyPaused);
-        }
-        
-        env.storage().instance().set(&PAUSED, &true);
-        
-        env.events().publish(
-            (Symbol::new(env, "contract_paused"), admin),
-            env.ledger().timestamp(),
-        );
-        
-        Ok(())
+    pub fn is_surface_paused(env: &Env, surface: Symbol) -> bool {
+        offerhub_pausable::is_surface_paused(env, &surface)
     }
 
-    // Function to unpause the contract
-    pub fn unpause(env: &Env, admin: Address) -> Result<(), Error> {
-        admin.require_auth();
-        let stored_admin = get_admin(env);
-        if stored_admin != admin {
-            return Err(Error::Unauthorized);
-        }
-        
-        if !Self::is_paused(env) {
-            return Err(Error::NotPaused);
-        }
-        
-        env.storage().instance().set(&PAUSED, &false);
-        
-        env.events().publish(
-            (Symbol::new(env, "contract_unpaused"), admin),
-            env.ledger().timestamp(),
-        );
-        
+    pub fn pause_surface(env: &Env, caller: Address, surface: Symbol) -> Result<(), Error> {
+        offerhub_pausable::pause_surface(env, &caller, &get_admin(env), surface)
+            .map_err(Error::from)
+    }
+
+    pub fn unpause_surface(env: &Env, caller: Address, surface: Symbol) -> Result<(), Error> {
+        offerhub_pausable::unpause_surface(env, &caller, &get_admin(env), surface)
+            .map_err(Error::from)
+    }
+
+    // Diagnostic events for incident investigations
+    pub fn set_debug_mode(env: Env, admin: Address, enabled: bool) -> Result<(), Error> {
+        check_admin(&env, &admin)?;
+        env.storage().instance().set(&DEBUG_MODE, &enabled);
+        emit_debug_mode_changed(&env, &admin, enabled);
         Ok(())
     }
 
+    pub fn is_debug_mode(env: Env) -> bool {
+        env.storage().instance().get(&DEBUG_MODE).unwrap_or(false)
+    }
+
     pub fn mint(
         env: Env,
         caller: Address,
@@ -108,6 +133,7 @@ impl ReputationNFTContract {
         index_user_achievement(&env, &to, &token_id);
         update_achievement_stats(&env, &AchievementType::Standard);
         update_leaderboard(&env, &to);
+        record_mint(&env);
         emit_minted(&env, &to, &token_id);
         Ok(())
     }
@@ -122,69 +148,269 @@ impl ReputationNFTContract {
             return Err(Error::ContractPaused);
         }
         check_minter(&env, &caller)?;
+        Self::mint_template(&env, &to, &nft_type)?;
+        Ok(())
+    }
 
-        // Determine achievement type and check prerequisites
-        let (name, description, uri, achievement_type) = match &nft_type {
-            s if *s == symbol_short!("tencontr") => {
-                // Check prerequisite for ProjectMilestone
-                if !check_achievement_prerequisite(&env, &to, &AchievementType::ProjectMilestone) {
-                    return Err(Error::AchievementPrerequisiteNotMet);
-                }
-                (
-                    String::from_str(&env, "10 Completed Contracts"),
-                    String::from_str(&env, "Awarded for completing 10 contracts successfully."),
-                    String::from_str(&env, "ipfs://10-completed-contracts"),
-                    AchievementType::ProjectMilestone,
-                )
-            }
-            s if *s == symbol_short!("5stars5x") => {
-                // Check prerequisite for RatingMilestone
-                if !check_achievement_prerequisite(&env, &to, &AchievementType::RatingMilestone) {
-                    return Err(Error::AchievementPrerequisiteNotMet);
-                }
-                (
-                    String::from_str(&env, "5 Stars 5 Times"),
-                    String::from_str(&env, "Awarded for receiving five 5-star reviews."),
-                    String::from_str(&env, "ipfs://5-stars-5-times"),
-                    AchievementType::RatingMilestone,
-                )
-            }
-            s if *s == symbol_short!("toprated") => {
-                // Check prerequisite for Reputation
-                if !check_achievement_prerequisite(&env, &to, &AchievementType::Reputation) {
-                    return Err(Error::AchievementPrerequisiteNotMet);
-                }
-                (
-                    String::from_str(&env, "Top Rated Freelancer"),
-                    String::from_str(&env, "Awarded for being a top-rated freelancer."),
-                    String::from_str(&env, "ipfs://top-rated-freelancer"),
-                    AchievementType::Reputation,
-                )
+    pub fn set_mint_allowance(
+        env: Env,
+        admin: Address,
+        dapp: Address,
+        template: Symbol,
+        max_mints: u32,
+    ) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        check_admin(&env, &admin)?;
+        if get_partner_template(&env, &template).is_none() {
+            return Err(Error::UnknownTemplate);
+        }
+        set_mint_allowance_impl(&env, &dapp, &template, max_mints);
+        emit_mint_allowance_set(&env, &dapp, &template, max_mints);
+        Ok(())
+    }
+
+    pub fn get_mint_allowance(env: Env, dapp: Address, template: Symbol) -> u32 {
+        get_mint_allowance_impl(&env, &dapp, &template)
+    }
+
+    // Co-branded badges have to be registered before any dApp can mint them
+    pub fn register_partner_template(
+        env: Env,
+        admin: Address,
+        template: Symbol,
+        partner_template: PartnerTemplate,
+    ) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        check_admin(&env, &admin)?;
+        if partner_template.name.is_empty() {
+            return Err(Error::InvalidInput);
+        }
+        validate_uri(&partner_template.uri)?;
+        save_partner_template(&env, &template, &partner_template);
+        emit_partner_template_registered(&env, &template);
+        Ok(())
+    }
+
+    pub fn get_partner_template(env: Env, template: Symbol) -> Option<PartnerTemplate> {
+        get_partner_template(&env, &template)
+    }
+
+    // Partner dApps mint their co-branded template against a bounded allowance,
+    // at most once per partner cooldown
+    pub fn mint_with_allowance(
+        env: Env,
+        dapp: Address,
+        to: Address,
+        template: Symbol,
+    ) -> Result<TokenId, Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        dapp.require_auth();
+        let partner_template =
+            get_partner_template(&env, &template).ok_or(Error::UnknownTemplate)?;
+        let remaining = get_mint_allowance_impl(&env, &dapp, &template);
+        if remaining == 0 {
+            return Err(Error::SupplyExhausted);
+        }
+        let now = env.ledger().timestamp();
+        if let Some(last_mint) = get_partner_last_mint(&env, &dapp) {
+            if now < last_mint.saturating_add(partner_mint_cooldown(&env)) {
+                return Err(Error::CooldownActive);
             }
-            _ => (
-                String::from_str(&env, "Achievement NFT"),
-                String::from_str(&env, "Awarded for a special achievement."),
-                String::from_str(&env, "ipfs://achievement-generic"),
-                AchievementType::CustomAchievement,
-            ),
+        }
+        let token_id = Self::mint_rendered(
+            &env,
+            &to,
+            &template,
+            partner_template.name,
+            partner_template.description,
+            partner_template.uri,
+            AchievementType::CustomAchievement,
+        )?;
+        set_mint_allowance_impl(&env, &dapp, &template, remaining - 1);
+        set_partner_last_mint(&env, &dapp, now);
+        Ok(token_id)
+    }
+
+    // Called by escrow contracts (registered as minters) when a job pays out
+    pub fn record_contract_completion(
+        env: Env,
+        caller: Address,
+        user: Address,
+    ) -> Result<u32, Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        check_minter(&env, &caller)?;
+
+        let completed = increment_completed_contracts(&env, &user);
+        emit_contract_completed(&env, &user, &caller, completed);
+
+        // A missing prerequisite must not block the payment that triggered this call
+        let badge = symbol_short!("tencontr");
+        let (_, _, _, achievement_type) = Self::achievement_template(&env, &badge);
+        if completed >= completion_badge_threshold(&env)
+            && !Self::has_achievement_by_name(&env, &user, "10 Completed Contracts")
+            && Self::check_template_prerequisite(&env, &user, &achievement_type).is_ok()
+        {
+            Self::mint_template(&env, &user, &badge)?;
+        }
+
+        Ok(completed)
+    }
+
+    pub fn get_completed_contracts(env: Env, user: Address) -> u32 {
+        get_completed_contracts_impl(&env, &user)
+    }
+
+    // Anyone can sync a user's valid skill certificates from the certification contract;
+    // enough of them unlock the certified professional badge
+    pub fn sync_certifications(env: Env, user: Address) -> Result<u32, Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        let certifications = match get_param_address(&env, &PARAM_CERTIFICATIONS) {
+            Some(certifications) => certifications,
+            None => return Ok(0),
         };
 
-        // Generate token id after passing prerequisite checks
-        let token_id = next_token_id(&env);
-        save_token_owner(&env, &token_id, &to);
-        let ach_type = achievement_type;
-        store_metadata(&env, &token_id, name, description, uri, Some(ach_type))?;
+        let valid: u32 = env.invoke_contract(
+            &certifications,
+            &Symbol::new(&env, "count_valid_certificates"),
+            (user.clone(),).into_val(&env),
+        );
 
-        // Index achievement and update statistics
-        index_user_achievement(&env, &to, &token_id);
-        update_achievement_stats(&env, &ach_type);
-        update_leaderboard(&env, &to);
+        let badge = symbol_short!("certpro");
+        if valid >= certification_badge_threshold(&env)
+            && !Self::has_achievement_by_name(&env, &user, "Certified Professional")
+        {
+            Self::mint_template(&env, &user, &badge)?;
+        }
+
+        Ok(valid)
+    }
+
+    // Called by the rating contract (registered as a minter) with a user's per-category averages
+    pub fn update_skill_score(
+        env: Env,
+        caller: Address,
+        user: Address,
+        skill: Symbol,
+        score: u32,
+        total_ratings: u32,
+    ) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        check_minter(&env, &caller)?;
 
-        emit_achievement_minted(&env, &to, &nft_type, &token_id);
-        emit_achievement_unlocked(&env, &to, &nft_type, &token_id);
+        store_skill_score(&env, &user, &skill, score, total_ratings);
+        emit_skill_score_updated(&env, &user, &skill, score, total_ratings);
         Ok(())
     }
 
+    pub fn get_skill_score(env: Env, user: Address, skill: Symbol) -> u32 {
+        get_skill_scores(&env, &user)
+            .get(skill)
+            .map(|(score, _)| score)
+            .unwrap_or(0)
+    }
+
+    pub fn get_skill_scores(env: Env, user: Address) -> Map<Symbol, u32> {
+        let mut scores = Map::new(&env);
+        for (skill, (score, _)) in get_skill_scores(&env, &user).iter() {
+            scores.set(skill, score);
+        }
+        scores
+    }
+
+    // Called by authorized contracts (e.g. dispute resolution) when a user loses a dispute
+    pub fn apply_reputation_penalty(
+        env: Env,
+        caller: Address,
+        user: Address,
+        points: u32,
+    ) -> Result<u32, Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        check_minter(&env, &caller)?;
+
+        let (old_score, total_ratings) = get_reputation_score(&env, &user)
+            .map(|(score, total, _)| (score, total))
+            .unwrap_or((0, 0));
+        let new_score = old_score.saturating_sub(points);
+
+        store_reputation_score(&env, &user, new_score, total_ratings);
+        update_leaderboard(&env, &user);
+
+        emit_reputation_penalized(&env, &user, &caller, points, new_score);
+        emit_reputation_updated(&env, &user, old_score, new_score);
+
+        Ok(new_score)
+    }
+
+    // Read-only score lookup for other contracts, 0 for users without ratings
+    pub fn get_user_reputation_score(env: Env, user: Address) -> u32 {
+        get_reputation_score(&env, &user)
+            .map(|(score, _, _)| score)
+            .unwrap_or(0)
+    }
+
+    // Score across every wallet linked to the user's identity in the user registry, weighted by
+    // each wallet's rating count. Same as get_user_reputation_score without a registry set.
+    pub fn get_identity_reputation_score(env: Env, user: Address) -> u32 {
+        let mut weighted: u64 = 0;
+        let mut total: u64 = 0;
+        let mut highest: u32 = 0;
+        for wallet in Self::identity_wallets(&env, &user).iter() {
+            if let Some((score, total_ratings, _)) = get_reputation_score(&env, &wallet) {
+                weighted += score as u64 * total_ratings as u64;
+                total += total_ratings as u64;
+                highest = highest.max(score);
+            }
+        }
+        // Scores set without any ratings behind them can't be weighted
+        weighted
+            .checked_div(total)
+            .map_or(highest, |average| average as u32)
+    }
+
+    // Achievements held by any wallet linked to the user's identity
+    pub fn get_identity_achievements(env: Env, user: Address) -> Vec<TokenId> {
+        let mut achievements = Vec::new(&env);
+        for wallet in Self::identity_wallets(&env, &user).iter() {
+            achievements.append(&get_user_achievements(&env, &wallet));
+        }
+        achievements
+    }
+
+    // Dry-run of mint_achv: runs every check without writing state
+    pub fn simulate_mint(
+        env: Env,
+        caller: Address,
+        to: Address,
+        template: Symbol,
+    ) -> Result<TokenId, Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        if !is_minter(&env, &caller) && !is_admin(&env, &caller) {
+            return Err(Error::Unauthorized);
+        }
+        let (_, _, _, achievement_type) = Self::achievement_template(&env, &template);
+        Self::check_template_prerequisite(&env, &to, &achievement_type)?;
+        if get_user_achievements(&env, &to).len() >= max_achievements_per_user(&env) {
+            return Err(Error::SupplyExhausted);
+        }
+        Ok(peek_next_token_id(&env))
+    }
+
     pub fn transfer(env: Env, from: Address, to: Address, token_id: TokenId) -> Result<(), Error> {
         if Self::is_paused(&env) {
             return Err(Error::ContractPaused);
@@ -200,30 +426,88 @@ impl ReputationNFTContract {
         // Check authorization from the owner
         check_owner(&env, &from)?;
 
-        // Check if token is transferable based on achievement type
-        let metadata = get_token_metadata(&env, &token_id)?;
-        match metadata.achievement_type {
-            AchievementType::Standard | AchievementType::CustomAchievement => {
-                // These types can be transferred
-            }
-            _ => {
-                // Other achievement types are non-transferable
-                return Err(Error::NonTransferableToken);
-            }
+        Self::check_transferable(&env, &token_id)?;
+        Self::move_token(&env, &from, &to, &token_id);
+        Ok(())
+    }
+
+    pub fn list_for_sale(
+        env: Env,
+        owner: Address,
+        token_id: TokenId,
+        price: i128,
+        asset: Address,
+    ) -> Result<(), Error> {
+        if Self::is_surface_paused(&env, MARKET) {
+            return Err(Error::ContractPaused);
+        }
+        if get_token_owner(&env, &token_id)? != owner {
+            return Err(Error::Unauthorized);
         }
+        check_owner(&env, &owner)?;
+        Self::check_transferable(&env, &token_id)?;
+        if price <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        let listing = Listing {
+            seller: owner.clone(),
+            price,
+            asset: asset.clone(),
+        };
+        save_listing(&env, &token_id, &listing);
+        emit_listed(&env, &token_id, &owner, price, &asset);
+        Ok(())
+    }
 
-        // Update ownership and achievements
-        save_token_owner(&env, &token_id, &to);
-        remove_user_achievement_index(&env, &from, &token_id);
-        index_user_achievement(&env, &to, &token_id);
+    pub fn delist(env: Env, owner: Address, token_id: TokenId) -> Result<(), Error> {
+        let listing = get_listing(&env, &token_id).ok_or(Error::NotListed)?;
+        if listing.seller != owner {
+            return Err(Error::Unauthorized);
+        }
+        check_owner(&env, &owner)?;
+        remove_listing(&env, &token_id);
+        emit_delisted(&env, &token_id, &owner);
+        Ok(())
+    }
 
-        // Update leaderboard for both users
-        update_leaderboard(&env, &from);
-        update_leaderboard(&env, &to);
+    pub fn get_listing(env: Env, token_id: TokenId) -> Option<Listing> {
+        get_listing(&env, &token_id)
+    }
 
-        // Emit transferred event
-        emit_transferred(&env, &from, &to, &token_id);
+    // Settles the listed price in the listing asset, carving out the configured royalty
+    pub fn buy(env: Env, buyer: Address, token_id: TokenId) -> Result<(), Error> {
+        if Self::is_surface_paused(&env, MARKET) {
+            return Err(Error::ContractPaused);
+        }
+        let listing = get_listing(&env, &token_id).ok_or(Error::NotListed)?;
+        if get_token_owner(&env, &token_id)? != listing.seller {
+            return Err(Error::NotListed);
+        }
+        if buyer == listing.seller {
+            return Err(Error::InvalidInput);
+        }
+        buyer.require_auth();
+        let _guard = ReentrancyGuard::enter(&env)?;
+
+        let token = token::Client::new(&env, &listing.asset);
+        let royalty = match Self::royalty_for(&env, listing.price)? {
+            Some((receiver, amount)) => {
+                token.transfer(&buyer, &receiver, &amount);
+                amount
+            }
+            None => 0,
+        };
+        token.transfer(&buyer, &listing.seller, &sub(listing.price, royalty)?);
 
+        Self::move_token(&env, &listing.seller, &buyer, &token_id);
+        emit_sold(
+            &env,
+            &token_id,
+            &listing.seller,
+            &buyer,
+            listing.price,
+            royalty,
+        );
         Ok(())
     }
 
@@ -235,6 +519,15 @@ impl ReputationNFTContract {
         get_token_metadata(&env, &token_id)
     }
 
+    // Lightweight ownership views for cross-contract gating; never error
+    pub fn verify_ownership(env: Env, user: Address, achievement_type: AchievementType) -> bool {
+        has_achievement_type(&env, &user, &achievement_type)
+    }
+
+    pub fn owns_token(env: Env, user: Address, token_id: TokenId) -> bool {
+        get_token_owner(&env, &token_id).is_ok_and(|owner| owner == user)
+    }
+
     pub fn add_minter(env: Env, caller: Address, minter: Address) -> Result<(), Error> {
         if Self::is_paused(&env) {
             return Err(Error::ContractPaused);
@@ -254,7 +547,7 @@ impl ReputationNFTContract {
     }
 
     pub fn get_admin(env: Env) -> Result<Address, Error> {
-        Ok(get_admin(&env))
+        try_get_admin(&env).ok_or(Error::NotInitialized)
     }
 
     pub fn transfer_admin(env: Env, caller: Address, new_admin: Address) -> Result<(), Error> {
@@ -264,6 +557,86 @@ impl ReputationNFTContract {
         transfer_admin_impl(&env, &caller, &new_admin)
     }
 
+    // Aggregated configuration view for admin dashboards
+    pub fn get_config(env: Env) -> Result<ContractConfig, Error> {
+        Ok(ContractConfig {
+            admin: get_admin(&env),
+            paused: Self::is_paused(&env),
+            minters: get_minter_list(&env),
+            excellence_threshold: excellence_threshold(&env),
+            top_rated_threshold: top_rated_threshold(&env),
+            veteran_threshold: veteran_threshold(&env),
+            fee_manager: get_param_address(&env, &PARAM_FEE_MANAGER),
+            keeper_reward: u32_or(&env, &PARAM_KEEPER_REWARD, 0),
+            council: get_param_address(&env, &PARAM_COUNCIL),
+            rating_contract: get_param_address(&env, &PARAM_RATING_CONTRACT),
+            base_uri: get_base_uri(&env),
+            version: CONTRACT_VERSION,
+        })
+    }
+
+    // Built-in achievement templates resolve their URIs under this base
+    pub fn set_base_uri(env: Env, admin: Address, base_uri: String) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        check_admin(&env, &admin)?;
+        if base_uri.is_empty() || base_uri.len() > MAX_BASE_URI_LEN {
+            return Err(Error::InvalidUri);
+        }
+        set_base_uri_impl(&env, &base_uri);
+        emit_base_uri_changed(&env, &base_uri);
+        Ok(())
+    }
+
+    pub fn get_base_uri(env: Env) -> String {
+        get_base_uri(&env)
+    }
+
+    // Typed configuration parameters
+    pub fn set_param_u32(env: Env, caller: Address, key: Symbol, value: u32) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        set_param_u32_impl(&env, &caller, &key, value)
+    }
+
+    pub fn set_param_address(
+        env: Env,
+        caller: Address,
+        key: Symbol,
+        value: Address,
+    ) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        set_param_address_impl(&env, &caller, &key, &value)
+    }
+
+    pub fn set_param_symbol(
+        env: Env,
+        caller: Address,
+        key: Symbol,
+        value: Symbol,
+    ) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        set_param_symbol_impl(&env, &caller, &key, &value)
+    }
+
+    pub fn get_param_u32(env: Env, key: Symbol) -> Option<u32> {
+        get_param_u32(&env, &key)
+    }
+
+    pub fn get_param_address(env: Env, key: Symbol) -> Option<Address> {
+        get_param_address(&env, &key)
+    }
+
+    pub fn get_param_symbol(env: Env, key: Symbol) -> Option<Symbol> {
+        get_param_symbol(&env, &key)
+    }
+
     /// New rating-based achievement functions
     pub fn mint_rating_achievement(
         env: Env,
@@ -332,6 +705,7 @@ impl ReputationNFTContract {
         update_achievement_stats(&env, &AchievementType::RatingMilestone);
         update_leaderboard(&env, &to);
 
+        record_mint(&env);
         emit_achievement_minted(&env, &to, &Symbol::new(&env, "achievement"), &token_id);
         Ok(())
     }
@@ -340,31 +714,17 @@ impl ReputationNFTContract {
         Ok(get_user_achievements(&env, &user))
     }
 
+    pub fn get_achievement_counts(env: Env, user: Address) -> Map<AchievementType, u32> {
+        get_user_achievement_counts(&env, &user)
+    }
+
     pub fn burn(env: Env, caller: Address, token_id: TokenId) -> Result<(), Error> {
         if Self::is_paused(&env) {
             return Err(Error::ContractPaused);
         }
         // Only admin or minter can burn
         check_minter(&env, &caller)?;
-        // Get owner to remove index
-        let owner = get_token_owner(&env, &token_id)?;
-
-        // Get achievement type for stats update
-        let metadata = get_token_metadata(&env, &token_id)?;
-        let ach_type = metadata.achievement_type;
-
-        // Remove from user index and update stats
-        remove_user_achievement_index(&env, &owner, &token_id);
-        decrement_achievement_stats(&env, &ach_type);
-
-        // Burn the token
-        burn_token(&env, &token_id);
-
-        // Update leaderboard after removing achievement
-        update_leaderboard(&env, &owner);
-
-        emit_burned(&env, &token_id, &owner);
-        Ok(())
+        Self::destroy_token(&env, &token_id)
     }
 
     pub fn batch_mint(
@@ -385,15 +745,15 @@ impl ReputationNFTContract {
             return Err(Error::InvalidInput);
         }
         if names.len() != len || descriptions.len() != len || uris.len() != len {
-            return Err(Error::InvalidInput);
+            return Err(Error::LengthMismatch);
         }
 
         let mut token_ids = Vec::new(&env);
         for i in 0..len {
-            let to = tos.get(i).ok_or(Error::InvalidInput)?;
-            let name = names.get(i).ok_or(Error::InvalidInput)?;
-            let description = descriptions.get(i).ok_or(Error::InvalidInput)?;
-            let uri = uris.get(i).ok_or(Error::InvalidInput)?;
+            let to = tos.get(i).ok_or(Error::LengthMismatch)?;
+            let name = names.get(i).ok_or(Error::LengthMismatch)?;
+            let description = descriptions.get(i).ok_or(Error::LengthMismatch)?;
+            let uri = uris.get(i).ok_or(Error::LengthMismatch)?;
             let token_id = next_token_id(&env);
 
             save_token_owner(&env, &token_id, &to);
@@ -408,6 +768,7 @@ impl ReputationNFTContract {
             index_user_achievement(&env, &to, &token_id);
             update_achievement_stats(&env, &AchievementType::Standard);
             update_leaderboard(&env, &to);
+            record_mint(&env);
             emit_minted(&env, &to, &token_id);
 
             token_ids.push_back(token_id);
@@ -451,18 +812,65 @@ impl ReputationNFTContract {
     }
 
     // Achievement statistics and leaderboard functions
+    #[cfg(feature = "statistics")]
     pub fn get_achievement_statistics(env: Env) -> Map<AchievementType, u32> {
         get_achievement_stats(&env)
     }
 
+    #[cfg(feature = "leaderboard")]
     pub fn get_achievement_leaderboard(env: Env) -> Map<Address, u32> {
         get_leaderboard(&env)
     }
 
+    #[cfg(feature = "leaderboard")]
     pub fn get_user_achievement_rank(env: Env, user: Address) -> u32 {
         get_user_rank(&env, &user)
     }
 
+    pub fn get_activity_counters(env: Env) -> ActivityCounters {
+        get_activity_counters(&env)
+    }
+
+    // Snapshot the current leaderboard as a Merkle root for off-chain reward claims
+    #[cfg(feature = "leaderboard")]
+    pub fn commit_leaderboard_root(
+        env: Env,
+        admin: Address,
+        epoch: u32,
+    ) -> Result<BytesN<32>, Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        check_admin(&env, &admin)?;
+        if get_leaderboard_root(&env, epoch).is_some() {
+            return Err(Error::InvalidInput);
+        }
+
+        let root = compute_root(&env, &get_leaderboard(&env));
+        save_leaderboard_root(&env, epoch, &root);
+        emit_leaderboard_root_committed(&env, epoch, &root);
+        Ok(root)
+    }
+
+    #[cfg(feature = "leaderboard")]
+    pub fn get_leaderboard_root(env: Env, epoch: u32) -> Option<BytesN<32>> {
+        get_leaderboard_root(&env, epoch)
+    }
+
+    #[cfg(feature = "leaderboard")]
+    pub fn verify_leaderboard_entry(
+        env: Env,
+        epoch: u32,
+        user: Address,
+        score: u32,
+        proof: Vec<BytesN<32>>,
+    ) -> bool {
+        match get_leaderboard_root(&env, epoch) {
+            Some(root) => verify_proof(&env, &root, &leaf_hash(&env, &user, score), &proof),
+            None => false,
+        }
+    }
+
     // Dynamic metadata update based on user performance
     pub fn update_metadata_dynamically(
         env: Env,
@@ -491,6 +899,7 @@ impl ReputationNFTContract {
             metadata.description = description;
         }
         if let Some(uri) = new_uri {
+            validate_uri(&uri)?;
             metadata.uri = uri;
         }
 
@@ -499,6 +908,27 @@ impl ReputationNFTContract {
         Ok(())
     }
 
+    // Record the pinning service's digest of the content behind a freshly minted token
+    pub fn commit_content(
+        env: Env,
+        caller: Address,
+        token_id: TokenId,
+        content_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        check_minter(&env, &caller)?;
+        commit_content_impl(&env, &token_id, &content_hash)?;
+        emit_content_committed(&env, &token_id, &content_hash);
+        Ok(())
+    }
+
+    // Check that a token's URI still matches the content digest committed at mint
+    pub fn verify_uri(env: Env, token_id: TokenId, cid_digest: BytesN<32>) -> Result<bool, Error> {
+        verify_uri_impl(&env, &token_id, &cid_digest)
+    }
+
     // Set achievement prerequisites
     pub fn set_achievement_prerequisite(
         env: Env,
@@ -509,18 +939,283 @@ impl ReputationNFTContract {
         if Self::is_paused(&env) {
             return Err(Error::ContractPaused);
         }
-        let admin = get_admin(&env);
-        if caller != admin {
-            return Err(Error::Unauthorized);
-        }
-        check_owner(&env, &caller)?;
+        check_admin(&env, &caller)?;
         crate::storage::set_achievement_prerequisite(&env, &achievement_type, &prerequisite);
         Ok(())
     }
 
+    // Export a portable reputation proof, chained to the user's previous proof
+    pub fn export_reputation_proof(env: Env, user: Address) -> Result<ReputationProof, Error> {
+        user.require_auth();
+
+        let (score, total_ratings) = get_reputation_score(&env, &user)
+            .map(|(score, total, _)| (score, total))
+            .unwrap_or((0, 0));
+
+        let mut proof = ReputationProof {
+            user: user.clone(),
+            score,
+            total_ratings,
+            achievement_counts: get_user_achievement_counts(&env, &user),
+            ledger: env.ledger().sequence(),
+            prev_hash: get_reputation_proof_head(&env, &user),
+            hash: BytesN::from_array(&env, &[0; 32]),
+        };
+        proof.hash = Self::compute_proof_hash(&env, &proof);
+
+        record_reputation_proof(&env, &user, &proof.hash);
+        emit_reputation_proof_exported(&env, &user, &proof.hash);
+        Ok(proof)
+    }
+
+    // Verify that a proof payload is untampered and was issued by this contract
+    pub fn verify_reputation_proof(env: Env, payload: ReputationProof) -> bool {
+        if Self::compute_proof_hash(&env, &payload) != payload.hash {
+            return false;
+        }
+        reputation_proof_issued_to(&env, &payload.hash) == Some(payload.user)
+    }
+
+    // Permissionless housekeeping in bounded batches: TTL bumps, expiry and leaderboard pruning
+    pub fn perform_maintenance(
+        env: Env,
+        keeper: Address,
+        max_items: u32,
+    ) -> Result<MaintenanceReport, Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        keeper.require_auth();
+        if max_items == 0 || max_items > MAX_MAINTENANCE_ITEMS {
+            return Err(Error::InvalidInput);
+        }
+
+        extend_shared_ttl(&env);
+        let pruned = prune_leaderboard(&env, max_items);
+        let visited = extend_token_ttls(&env, max_items - pruned);
+        let processed = visited.len();
+
+        let mut expired = 0u32;
+        let lifetime = achievement_lifetime(&env);
+        if lifetime > 0 {
+            let now = env.ledger().timestamp();
+            for token_id in visited.iter() {
+                let minted_at = get_minted_at(&env, &token_id).unwrap_or(now);
+                if now >= minted_at.saturating_add(lifetime)
+                    && Self::check_transferable(&env, &token_id).is_err()
+                {
+                    Self::destroy_token(&env, &token_id)?;
+                    expired += 1;
+                }
+            }
+        }
+
+        // TTL bumps cost nothing to repeat, so only pruning and expiry earn a reward
+        let work = pruned + expired;
+        let reward = if work > 0 {
+            Self::pay_keeper(&env, &keeper, work)
+        } else {
+            0
+        };
+
+        let report = MaintenanceReport {
+            processed,
+            pruned,
+            expired,
+            reward,
+        };
+        emit_maintenance_performed(&env, &keeper, &report);
+        Ok(report)
+    }
+
     // Helper functions
     // index_user_achievement is now provided by storage helpers
 
+    fn achievement_template(
+        env: &Env,
+        nft_type: &Symbol,
+    ) -> (String, String, &'static str, AchievementType) {
+        match nft_type {
+            s if *s == symbol_short!("tencontr") => (
+                String::from_str(env, "10 Completed Contracts"),
+                String::from_str(env, "Awarded for completing 10 contracts successfully."),
+                "10-completed-contracts",
+                AchievementType::ProjectMilestone,
+            ),
+            s if *s == symbol_short!("5stars5x") => (
+                String::from_str(env, "5 Stars 5 Times"),
+                String::from_str(env, "Awarded for receiving five 5-star reviews."),
+                "5-stars-5-times",
+                AchievementType::RatingMilestone,
+            ),
+            s if *s == symbol_short!("toprated") => (
+                String::from_str(env, "Top Rated Freelancer"),
+                String::from_str(env, "Awarded for being a top-rated freelancer."),
+                "top-rated-freelancer",
+                AchievementType::Reputation,
+            ),
+            s if *s == symbol_short!("certpro") => (
+                String::from_str(env, "Certified Professional"),
+                String::from_str(env, "Awarded for holding three valid skill certificates."),
+                "certified-professional",
+                AchievementType::CustomAchievement,
+            ),
+            _ => (
+                String::from_str(env, "Achievement NFT"),
+                String::from_str(env, "Awarded for a special achievement."),
+                "achievement-generic",
+                AchievementType::CustomAchievement,
+            ),
+        }
+    }
+
+    fn mint_template(env: &Env, to: &Address, nft_type: &Symbol) -> Result<TokenId, Error> {
+        // Determine achievement type and check prerequisites
+        let (name, description, path, achievement_type) = Self::achievement_template(env, nft_type);
+        Self::check_template_prerequisite(env, to, &achievement_type)?;
+        let uri = template_uri(env, path)?;
+        Self::mint_rendered(env, to, nft_type, name, description, uri, achievement_type)
+    }
+
+    fn mint_rendered(
+        env: &Env,
+        to: &Address,
+        nft_type: &Symbol,
+        name: String,
+        description: String,
+        uri: String,
+        ach_type: AchievementType,
+    ) -> Result<TokenId, Error> {
+        // Generate token id after passing prerequisite checks
+        let token_id = next_token_id(env);
+        save_token_owner(env, &token_id, to);
+        store_metadata(env, &token_id, name, description, uri, Some(ach_type))?;
+
+        // Index achievement and update statistics
+        index_user_achievement(env, to, &token_id);
+        update_achievement_stats(env, &ach_type);
+        update_leaderboard(env, to);
+
+        record_mint(env);
+        emit_achievement_minted(env, to, nft_type, &token_id);
+        emit_achievement_unlocked(env, to, nft_type, &token_id);
+        Ok(token_id)
+    }
+
+    // Only Standard and CustomAchievement badges can change hands
+    fn check_transferable(env: &Env, token_id: &TokenId) -> Result<(), Error> {
+        let metadata = get_token_metadata(env, token_id)?;
+        match metadata.achievement_type {
+            AchievementType::Standard | AchievementType::CustomAchievement => Ok(()),
+            _ => Err(Error::NonTransferableToken),
+        }
+    }
+
+    fn destroy_token(env: &Env, token_id: &TokenId) -> Result<(), Error> {
+        // Get owner to remove index
+        let owner = get_token_owner(env, token_id)?;
+
+        // Get achievement type for stats update
+        let metadata = get_token_metadata(env, token_id)?;
+        let ach_type = metadata.achievement_type;
+
+        // Remove from user index and update stats
+        remove_user_achievement_index(env, &owner, token_id);
+        decrement_achievement_stats(env, &ach_type);
+
+        // Burn the token
+        burn_token(env, token_id);
+
+        // Update leaderboard after removing achievement
+        update_leaderboard(env, &owner);
+
+        record_burn(env);
+        emit_burned(env, token_id, &owner);
+        Ok(())
+    }
+
+    fn move_token(env: &Env, from: &Address, to: &Address, token_id: &TokenId) {
+        // Update ownership and achievements
+        save_token_owner(env, token_id, to);
+        remove_user_achievement_index(env, from, token_id);
+        index_user_achievement(env, to, token_id);
+        remove_listing(env, token_id);
+
+        // Update leaderboard for both users
+        update_leaderboard(env, from);
+        update_leaderboard(env, to);
+
+        record_transfer(env);
+        emit_transferred(env, from, to, token_id);
+    }
+
+    fn royalty_for(env: &Env, price: i128) -> Result<Option<(Address, i128)>, Error> {
+        let Some(receiver) = get_param_address(env, &PARAM_ROYALTY_RECEIVER) else {
+            return Ok(None);
+        };
+        let bps = u32_or(env, &PARAM_ROYALTY_BPS, 0).min(MAX_ROYALTY_BPS);
+        let amount = bps_of(price, bps, Rounding::Down)?;
+        Ok((amount > 0).then_some((receiver, amount)))
+    }
+
+    // Custom achievements are never gated by prerequisites
+    fn check_template_prerequisite(
+        env: &Env,
+        to: &Address,
+        achievement_type: &AchievementType,
+    ) -> Result<(), Error> {
+        if *achievement_type != AchievementType::CustomAchievement
+            && !check_achievement_prerequisite(env, to, achievement_type)
+        {
+            return Err(Error::AchievementPrerequisiteNotMet);
+        }
+        Ok(())
+    }
+
+    fn identity_wallets(env: &Env, user: &Address) -> Vec<Address> {
+        match get_param_address(env, &PARAM_USER_REGISTRY) {
+            Some(registry) => env.invoke_contract(
+                &registry,
+                &Symbol::new(env, "get_linked_wallets"),
+                (user.clone(),).into_val(env),
+            ),
+            None => Vec::from_array(env, [user.clone()]),
+        }
+    }
+
+    // The keeper reward param is paid per pruned or expired item, out of the fee
+    // manager's capped keeper budget
+    fn pay_keeper(env: &Env, keeper: &Address, work: u32) -> i128 {
+        let reward = u32_or(env, &PARAM_KEEPER_REWARD, 0) as i128 * work as i128;
+        match (
+            get_param_address(env, &PARAM_FEE_MANAGER),
+            get_param_address(env, &PARAM_KEEPER_ASSET),
+        ) {
+            (Some(fee_manager), Some(asset)) if reward > 0 => env.invoke_contract::<i128>(
+                &fee_manager,
+                &Symbol::new(env, "pay_keeper_reward"),
+                (
+                    env.current_contract_address(),
+                    keeper.clone(),
+                    asset,
+                    reward,
+                )
+                    .into_val(env),
+            ),
+            _ => 0,
+        }
+    }
+
+    fn compute_proof_hash(env: &Env, proof: &ReputationProof) -> BytesN<32> {
+        let mut data = Bytes::from(proof.prev_hash.clone());
+        data.append(&proof.user.clone().to_xdr(env));
+        data.extend_from_array(&proof.score.to_be_bytes());
+        data.extend_from_array(&proof.total_ratings.to_be_bytes());
+        data.append(&proof.achievement_counts.clone().to_xdr(env));
+        data.extend_from_array(&proof.ledger.to_be_bytes());
+        env.crypto().sha256(&data).to_bytes()
+    }
+
     fn check_rating_achievements(
         env: &Env,
         user: &Address,
@@ -528,7 +1223,28 @@ impl ReputationNFTContract {
         total_ratings: u32,
     ) -> Result<(), Error> {
         // Auto-award achievements based on rating milestones
-        if total_ratings >= 10 && rating_average >= 400 {
+        let excellence = excellence_threshold(env);
+        let top_rated = top_rated_threshold(env);
+        let veteran = veteran_threshold(env);
+
+        let excellence_met =
+            total_ratings >= excellence.min_ratings && rating_average >= excellence.min_average;
+        let top_rated_met =
+            rating_average >= top_rated.min_average && total_ratings >= top_rated.min_ratings;
+        let veteran_met =
+            total_ratings >= veteran.min_ratings && rating_average >= veteran.min_average;
+        emit_diagnostic(
+            env,
+            symbol_short!("threshold"),
+            (
+                user.clone(),
+                rating_average,
+                total_ratings,
+                (excellence_met, top_rated_met, veteran_met),
+            ),
+        );
+
+        if excellence_met {
             // Check if user already has this achievement
             if !Self::has_achievement_by_name(env, user, "Excellence Milestone") {
                 let token_id = next_token_id(env);
@@ -536,7 +1252,7 @@ impl ReputationNFTContract {
             }
         }
 
-        if rating_average >= 480 && total_ratings >= 20 {
+        if top_rated_met {
             // Check if user already has this achievement
             if !Self::has_achievement_by_name(env, user, "Top Rated Professional") {
                 let token_id = next_token_id(env);
@@ -544,7 +1260,7 @@ impl ReputationNFTContract {
             }
         }
 
-        if total_ratings >= 50 && rating_average >= 450 {
+        if veteran_met {
             // Check if user already has this achievement
             if !Self::has_achievement_by_name(env, user, "Veteran Professional") {
                 let token_id = next_token_id(env);
@@ -607,6 +1323,7 @@ impl ReputationNFTContract {
         update_achievement_stats(env, &AchievementType::RatingMilestone);
         update_leaderboard(env, user);
 
+        record_mint(env);
         emit_minted(env, user, token_id);
         emit_achievement_unlocked(env, user, &Symbol::new(env, "milestone"), token_id);
 
@@ -1,3 +1,7 @@
+use offerhub_math::MathError;
+use offerhub_pausable::PauseError;
+use offerhub_reentrancy::ReentrancyError;
+use offerhub_upgradeable::UpgradeError;
 use soroban_sdk::contracterror;
 
 #[contracterror]
@@ -27,4 +31,76 @@ pub enum Error {
     AlreadyPaused = 9,
     NotPaused = 10,
     ContractPaused = 11,
-}
\ No newline at end of file
+
+    /// Parallel input vectors have different lengths
+    LengthMismatch = 12,
+
+    /// URI is empty or exceeds the maximum supported length
+    InvalidUri = 13,
+
+    /// Contract has not been initialized
+    NotInitialized = 15,
+
+    /// No more tokens can be minted for this supply or quota
+    SupplyExhausted = 17,
+
+    /// Operation is not allowed until the cooldown period has elapsed
+    CooldownActive = 18,
+
+    /// Token is not listed for sale
+    NotListed = 21,
+
+    /// Storage is already at this code's version
+    AlreadyMigrated = 22,
+
+    /// Storage version is not one this code can migrate from
+    UnsupportedVersion = 23,
+
+    /// A marketplace settlement is already running in this contract
+    ReentrantCall = 24,
+
+    /// Price arithmetic overflowed
+    ArithmeticOverflow = 25,
+
+    /// No partner template is registered under this name
+    UnknownTemplate = 26,
+
+    /// Content has already been committed for this token
+    ContentAlreadyCommitted = 27,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
+
+impl From<UpgradeError> for Error {
+    fn from(error: UpgradeError) -> Self {
+        match error {
+            UpgradeError::AlreadyMigrated => Error::AlreadyMigrated,
+            UpgradeError::DowngradeNotSupported => Error::UnsupportedVersion,
+        }
+    }
+}
+
+impl From<MathError> for Error {
+    fn from(error: MathError) -> Self {
+        match error {
+            MathError::InvalidBps => Error::InvalidInput,
+            MathError::Overflow | MathError::DivisionByZero => Error::ArithmeticOverflow,
+        }
+    }
+}
+
+impl From<ReentrancyError> for Error {
+    fn from(error: ReentrancyError) -> Self {
+        match error {
+            ReentrancyError::Reentered => Error::ReentrantCall,
+        }
+    }
+}
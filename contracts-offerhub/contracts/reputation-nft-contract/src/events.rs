@@ -1,5 +1,6 @@
+use crate::types::{MaintenanceReport, DEBUG_MODE};
 use crate::TokenId;
-use soroban_sdk::{Address, Env, Symbol, Vec};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, String, Symbol, Val, Vec};
 
 pub fn emit_minted(env: &Env, to: &Address, token_id: &TokenId) {
     let topic = Symbol::new(env, "MINTED");
@@ -73,8 +74,125 @@ pub fn emit_achievement_unlocked(
         .publish((topic,), (user.clone(), achievement_type.clone(), token_id));
 }
 
+pub fn emit_reputation_proof_exported(env: &Env, user: &Address, hash: &BytesN<32>) {
+    let topic = Symbol::new(env, "REPUTATION_PROOF_EXPORTED");
+    env.events().publish(
+        (topic, user.clone()),
+        (hash.clone(), env.ledger().timestamp()),
+    );
+}
+
 pub fn emit_reputation_updated(env: &Env, user: &Address, old_score: u32, new_score: u32) {
     let topic = Symbol::new(env, "REPUTATION_UPDATED");
     env.events()
         .publish((topic,), (user.clone(), old_score, new_score));
 }
+
+pub fn emit_param_changed<V: IntoVal<Env, Val>>(env: &Env, key: &Symbol, value: V) {
+    let topic = Symbol::new(env, "PARAM_CHANGED");
+    env.events().publish((topic, key.clone()), value);
+}
+
+pub fn emit_base_uri_changed(env: &Env, base_uri: &String) {
+    let topic = Symbol::new(env, "BASE_URI");
+    env.events().publish((topic,), base_uri.clone());
+}
+
+pub fn emit_content_committed(env: &Env, token_id: &TokenId, content_hash: &BytesN<32>) {
+    let topic = Symbol::new(env, "CONTENT_COMMITTED");
+    env.events()
+        .publish((topic, *token_id), content_hash.clone());
+}
+
+pub fn emit_partner_template_registered(env: &Env, template: &Symbol) {
+    let topic = Symbol::new(env, "PARTNER_TEMPLATE");
+    env.events().publish((topic, template.clone()), ());
+}
+
+pub fn emit_maintenance_performed(env: &Env, keeper: &Address, report: &MaintenanceReport) {
+    let topic = Symbol::new(env, "MAINTENANCE");
+    env.events()
+        .publish((topic,), (keeper.clone(), report.clone()));
+}
+
+#[cfg(feature = "leaderboard")]
+pub fn emit_leaderboard_root_committed(env: &Env, epoch: u32, root: &soroban_sdk::BytesN<32>) {
+    let topic = Symbol::new(env, "LEADERBOARD_ROOT");
+    env.events().publish((topic, epoch), root.clone());
+}
+
+pub fn emit_debug_mode_changed(env: &Env, admin: &Address, enabled: bool) {
+    let topic = Symbol::new(env, "DEBUG_MODE");
+    env.events().publish((topic, admin.clone()), enabled);
+}
+
+/// Extra instrumentation, only published while the admin has debug mode enabled.
+pub fn emit_diagnostic<D: IntoVal<Env, Val>>(env: &Env, kind: Symbol, data: D) {
+    if !env.storage().instance().get(&DEBUG_MODE).unwrap_or(false) {
+        return;
+    }
+    let topic = Symbol::new(env, "DIAG");
+    env.events().publish((topic, kind), data);
+}
+
+pub fn emit_mint_allowance_set(env: &Env, dapp: &Address, template: &Symbol, max_mints: u32) {
+    let topic = Symbol::new(env, "MINT_ALLOWANCE");
+    env.events()
+        .publish((topic, dapp.clone(), template.clone()), max_mints);
+}
+
+pub fn emit_reputation_penalized(
+    env: &Env,
+    user: &Address,
+    reporter: &Address,
+    points: u32,
+    new_score: u32,
+) {
+    let topic = Symbol::new(env, "REPUTATION_PENALIZED");
+    env.events()
+        .publish((topic, user.clone()), (reporter.clone(), points, new_score));
+}
+
+pub fn emit_contract_completed(env: &Env, user: &Address, recorder: &Address, completed: u32) {
+    let topic = Symbol::new(env, "CONTRACT_COMPLETED");
+    env.events()
+        .publish((topic, user.clone()), (recorder.clone(), completed));
+}
+
+pub fn emit_skill_score_updated(
+    env: &Env,
+    user: &Address,
+    skill: &Symbol,
+    score: u32,
+    total_ratings: u32,
+) {
+    let topic = Symbol::new(env, "SKILL_SCORE_UPDATED");
+    env.events()
+        .publish((topic, user.clone()), (skill.clone(), score, total_ratings));
+}
+
+pub fn emit_listed(env: &Env, token_id: &TokenId, seller: &Address, price: i128, asset: &Address) {
+    let topic = Symbol::new(env, "LISTED");
+    env.events()
+        .publish((topic, *token_id), (seller.clone(), price, asset.clone()));
+}
+
+pub fn emit_delisted(env: &Env, token_id: &TokenId, seller: &Address) {
+    let topic = Symbol::new(env, "DELISTED");
+    env.events().publish((topic, *token_id), seller.clone());
+}
+
+pub fn emit_sold(
+    env: &Env,
+    token_id: &TokenId,
+    seller: &Address,
+    buyer: &Address,
+    price: i128,
+    royalty: i128,
+) {
+    let topic = Symbol::new(env, "SOLD");
+    env.events().publish(
+        (topic, *token_id),
+        (seller.clone(), buyer.clone(), price, royalty),
+    );
+}
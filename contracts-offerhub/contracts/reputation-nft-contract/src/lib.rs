@@ -1,23 +1,32 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Map, String, Symbol, Vec};
 
 mod access;
+mod config;
 mod contract;
 mod events;
+#[cfg(feature = "leaderboard")]
+mod merkle;
 mod metadata;
 mod storage;
 mod test;
 mod types;
 mod error;
+mod upgrade;
 
 pub use crate::contract::ReputationNFTContract;
 pub use error::Error;
 pub use types::Metadata;
-pub use types::{AchievementType, RarityLevel, TokenId};
+pub use types::{
+    AchievementType, ActivityCounters, ContractConfig, Listing, MaintenanceReport, PartnerTemplate,
+    RarityLevel, ReputationProof, TokenId,
+};
 
 #[contract]
 pub struct Contract;
 
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| Ok(storage::get_admin(env)));
+
 #[contractimpl]
 impl Contract {
     #[allow(clippy::too_many_arguments)]
@@ -25,16 +34,40 @@ impl Contract {
         ReputationNFTContract::init(env, admin)
     }
 
-    pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
-        ReputationNFTContract::pause(&env, admin)
+    /// Pause one surface (`market` covers listing and buying) without pausing minting
+    pub fn pause_surface(env: Env, caller: Address, surface: Symbol) -> Result<(), Error> {
+        ReputationNFTContract::pause_surface(&env, caller, surface)
+    }
+
+    pub fn unpause_surface(env: Env, caller: Address, surface: Symbol) -> Result<(), Error> {
+        ReputationNFTContract::unpause_surface(&env, caller, surface)
+    }
+
+    pub fn is_surface_paused(env: Env, surface: Symbol) -> bool {
+        ReputationNFTContract::is_surface_paused(&env, surface)
+    }
+
+    /// Replace the contract code (admin only); call `migrate` from the new code afterwards
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        offerhub_upgradeable::upgrade::<ReputationNFTContract>(&env, &admin, new_wasm_hash)
+    }
+
+    /// Migrate storage to this code's version (admin only)
+    pub fn migrate(env: Env, admin: Address) -> Result<(), Error> {
+        offerhub_upgradeable::migrate::<ReputationNFTContract>(&env, &admin)
+    }
+
+    /// Storage layout version
+    pub fn version(env: Env) -> u32 {
+        offerhub_upgradeable::version(&env)
     }
 
-    pub fn is_paused(env: Env) -> bool {
-        ReputationNFTContract::is_paused(&env)
+    pub fn set_debug_mode(env: Env, admin: Address, enabled: bool) -> Result<(), Error> {
+        ReputationNFTContract::set_debug_mode(env, admin, enabled)
     }
 
-    pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
-        ReputationNFTContract::unpause(&env, admin)
+    pub fn is_debug_mode(env: Env) -> bool {
+        ReputationNFTContract::is_debug_mode(env)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -54,6 +87,28 @@ impl Contract {
         ReputationNFTContract::transfer(env, from, to, token_id)
     }
 
+    pub fn list_for_sale(
+        env: Env,
+        owner: Address,
+        token_id: TokenId,
+        price: i128,
+        asset: Address,
+    ) -> Result<(), Error> {
+        ReputationNFTContract::list_for_sale(env, owner, token_id, price, asset)
+    }
+
+    pub fn delist(env: Env, owner: Address, token_id: TokenId) -> Result<(), Error> {
+        ReputationNFTContract::delist(env, owner, token_id)
+    }
+
+    pub fn get_listing(env: Env, token_id: TokenId) -> Option<Listing> {
+        ReputationNFTContract::get_listing(env, token_id)
+    }
+
+    pub fn buy(env: Env, buyer: Address, token_id: TokenId) -> Result<(), Error> {
+        ReputationNFTContract::buy(env, buyer, token_id)
+    }
+
     pub fn get_owner(env: Env, token_id: TokenId) -> Result<Address, Error> {
         ReputationNFTContract::get_owner(env, token_id)
     }
@@ -66,6 +121,14 @@ impl Contract {
         ReputationNFTContract::get_metadata(env, token_id)
     }
 
+    pub fn verify_ownership(env: Env, user: Address, achievement_type: AchievementType) -> bool {
+        ReputationNFTContract::verify_ownership(env, user, achievement_type)
+    }
+
+    pub fn owns_token(env: Env, user: Address, token_id: TokenId) -> bool {
+        ReputationNFTContract::owns_token(env, user, token_id)
+    }
+
     pub fn add_minter(env: Env, caller: Address, minter: Address) -> Result<(), Error> {
         ReputationNFTContract::add_minter(env, caller, minter)
     }
@@ -98,6 +161,52 @@ impl Contract {
         ReputationNFTContract::transfer_admin(env, caller, new_admin)
     }
 
+    pub fn get_config(env: Env) -> Result<ContractConfig, Error> {
+        ReputationNFTContract::get_config(env)
+    }
+
+    pub fn set_base_uri(env: Env, admin: Address, base_uri: String) -> Result<(), Error> {
+        ReputationNFTContract::set_base_uri(env, admin, base_uri)
+    }
+
+    pub fn get_base_uri(env: Env) -> String {
+        ReputationNFTContract::get_base_uri(env)
+    }
+
+    pub fn set_param_u32(env: Env, caller: Address, key: Symbol, value: u32) -> Result<(), Error> {
+        ReputationNFTContract::set_param_u32(env, caller, key, value)
+    }
+
+    pub fn set_param_address(
+        env: Env,
+        caller: Address,
+        key: Symbol,
+        value: Address,
+    ) -> Result<(), Error> {
+        ReputationNFTContract::set_param_address(env, caller, key, value)
+    }
+
+    pub fn set_param_symbol(
+        env: Env,
+        caller: Address,
+        key: Symbol,
+        value: Symbol,
+    ) -> Result<(), Error> {
+        ReputationNFTContract::set_param_symbol(env, caller, key, value)
+    }
+
+    pub fn get_param_u32(env: Env, key: Symbol) -> Option<u32> {
+        ReputationNFTContract::get_param_u32(env, key)
+    }
+
+    pub fn get_param_address(env: Env, key: Symbol) -> Option<Address> {
+        ReputationNFTContract::get_param_address(env, key)
+    }
+
+    pub fn get_param_symbol(env: Env, key: Symbol) -> Option<Symbol> {
+        ReputationNFTContract::get_param_symbol(env, key)
+    }
+
     pub fn req_auth(_env: Env, _addr: Address) -> Result<(), Error> {
         Ok(())
     }
@@ -111,6 +220,107 @@ impl Contract {
         ReputationNFTContract::mint_achv(env, caller, to, nft_type)
     }
 
+    pub fn simulate_mint(
+        env: Env,
+        caller: Address,
+        to: Address,
+        template: Symbol,
+    ) -> Result<TokenId, Error> {
+        ReputationNFTContract::simulate_mint(env, caller, to, template)
+    }
+
+    pub fn set_mint_allowance(
+        env: Env,
+        admin: Address,
+        dapp: Address,
+        template: Symbol,
+        max_mints: u32,
+    ) -> Result<(), Error> {
+        ReputationNFTContract::set_mint_allowance(env, admin, dapp, template, max_mints)
+    }
+
+    pub fn register_partner_template(
+        env: Env,
+        admin: Address,
+        template: Symbol,
+        partner_template: PartnerTemplate,
+    ) -> Result<(), Error> {
+        ReputationNFTContract::register_partner_template(env, admin, template, partner_template)
+    }
+
+    pub fn get_partner_template(env: Env, template: Symbol) -> Option<PartnerTemplate> {
+        ReputationNFTContract::get_partner_template(env, template)
+    }
+
+    pub fn get_mint_allowance(env: Env, dapp: Address, template: Symbol) -> u32 {
+        ReputationNFTContract::get_mint_allowance(env, dapp, template)
+    }
+
+    pub fn mint_with_allowance(
+        env: Env,
+        dapp: Address,
+        to: Address,
+        template: Symbol,
+    ) -> Result<TokenId, Error> {
+        ReputationNFTContract::mint_with_allowance(env, dapp, to, template)
+    }
+
+    pub fn record_contract_completion(
+        env: Env,
+        caller: Address,
+        user: Address,
+    ) -> Result<u32, Error> {
+        ReputationNFTContract::record_contract_completion(env, caller, user)
+    }
+
+    pub fn get_completed_contracts(env: Env, user: Address) -> u32 {
+        ReputationNFTContract::get_completed_contracts(env, user)
+    }
+
+    pub fn sync_certifications(env: Env, user: Address) -> Result<u32, Error> {
+        ReputationNFTContract::sync_certifications(env, user)
+    }
+
+    pub fn update_skill_score(
+        env: Env,
+        caller: Address,
+        user: Address,
+        skill: Symbol,
+        score: u32,
+        total_ratings: u32,
+    ) -> Result<(), Error> {
+        ReputationNFTContract::update_skill_score(env, caller, user, skill, score, total_ratings)
+    }
+
+    pub fn get_skill_score(env: Env, user: Address, skill: Symbol) -> u32 {
+        ReputationNFTContract::get_skill_score(env, user, skill)
+    }
+
+    pub fn get_skill_scores(env: Env, user: Address) -> Map<Symbol, u32> {
+        ReputationNFTContract::get_skill_scores(env, user)
+    }
+
+    pub fn apply_reputation_penalty(
+        env: Env,
+        caller: Address,
+        user: Address,
+        points: u32,
+    ) -> Result<u32, Error> {
+        ReputationNFTContract::apply_reputation_penalty(env, caller, user, points)
+    }
+
+    pub fn get_user_reputation_score(env: Env, user: Address) -> u32 {
+        ReputationNFTContract::get_user_reputation_score(env, user)
+    }
+
+    pub fn get_identity_reputation_score(env: Env, user: Address) -> u32 {
+        ReputationNFTContract::get_identity_reputation_score(env, user)
+    }
+
+    pub fn get_identity_achievements(env: Env, user: Address) -> Vec<TokenId> {
+        ReputationNFTContract::get_identity_achievements(env, user)
+    }
+
     /// New rating-based achievement functions
     pub fn mint_rating_achievement(
         env: Env,
@@ -132,6 +342,10 @@ impl Contract {
         ReputationNFTContract::get_user_achievements(env, user)
     }
 
+    pub fn get_achievement_counts(env: Env, user: Address) -> Map<AchievementType, u32> {
+        ReputationNFTContract::get_achievement_counts(env, user)
+    }
+
     pub fn upd_reput(
         env: Env,
         caller: Address,
@@ -180,17 +394,8 @@ impl Contract {
         ReputationNFTContract::batch_mint(env, caller, tos, names, descriptions, uris)
     }
 
-    // Achievement statistics and leaderboard functions
-    pub fn ach_stats(env: Env) -> Map<AchievementType, u32> {
-        ReputationNFTContract::get_achievement_statistics(env)
-    }
-
-    pub fn leader(env: Env) -> Map<Address, u32> {
-        ReputationNFTContract::get_achievement_leaderboard(env)
-    }
-
-    pub fn get_rank(env: Env, user: Address) -> u32 {
-        ReputationNFTContract::get_user_achievement_rank(env, user)
+    pub fn get_activity_counters(env: Env) -> ActivityCounters {
+        ReputationNFTContract::get_activity_counters(env)
     }
 
     // Dynamic metadata update
@@ -212,6 +417,19 @@ impl Contract {
         )
     }
 
+    pub fn commit_content(
+        env: Env,
+        caller: Address,
+        token_id: TokenId,
+        content_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        ReputationNFTContract::commit_content(env, caller, token_id, content_hash)
+    }
+
+    pub fn verify_uri(env: Env, token_id: TokenId, cid_digest: BytesN<32>) -> Result<bool, Error> {
+        ReputationNFTContract::verify_uri(env, token_id, cid_digest)
+    }
+
     // Set achievement prerequisites
     pub fn set_achievement_prerequisite(
         env: Env,
@@ -226,4 +444,65 @@ impl Contract {
             prerequisite,
         )
     }
+
+    // Portable reputation proofs
+    pub fn export_reputation_proof(env: Env, user: Address) -> Result<ReputationProof, Error> {
+        ReputationNFTContract::export_reputation_proof(env, user)
+    }
+
+    pub fn verify_reputation_proof(env: Env, payload: ReputationProof) -> bool {
+        ReputationNFTContract::verify_reputation_proof(env, payload)
+    }
+
+    // Keeper-incentivized housekeeping
+    pub fn perform_maintenance(
+        env: Env,
+        keeper: Address,
+        max_items: u32,
+    ) -> Result<MaintenanceReport, Error> {
+        ReputationNFTContract::perform_maintenance(env, keeper, max_items)
+    }
+}
+
+// Optional subsystems; disable the cargo features to ship a smaller WASM
+#[cfg(feature = "statistics")]
+#[contractimpl]
+impl Contract {
+    pub fn ach_stats(env: Env) -> Map<AchievementType, u32> {
+        ReputationNFTContract::get_achievement_statistics(env)
+    }
+}
+
+#[cfg(feature = "leaderboard")]
+#[contractimpl]
+impl Contract {
+    pub fn leader(env: Env) -> Map<Address, u32> {
+        ReputationNFTContract::get_achievement_leaderboard(env)
+    }
+
+    pub fn get_rank(env: Env, user: Address) -> u32 {
+        ReputationNFTContract::get_user_achievement_rank(env, user)
+    }
+
+    pub fn commit_leaderboard_root(
+        env: Env,
+        admin: Address,
+        epoch: u32,
+    ) -> Result<BytesN<32>, Error> {
+        ReputationNFTContract::commit_leaderboard_root(env, admin, epoch)
+    }
+
+    pub fn get_leaderboard_root(env: Env, epoch: u32) -> Option<BytesN<32>> {
+        ReputationNFTContract::get_leaderboard_root(env, epoch)
+    }
+
+    pub fn verify_leaderboard_entry(
+        env: Env,
+        epoch: u32,
+        user: Address,
+        score: u32,
+        proof: Vec<BytesN<32>>,
+    ) -> bool {
+        ReputationNFTContract::verify_leaderboard_entry(env, epoch, user, score, proof)
+    }
 }
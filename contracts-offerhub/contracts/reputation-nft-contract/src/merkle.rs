@@ -0,0 +1,60 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Map, Vec};
+
+pub fn leaf_hash(env: &Env, user: &Address, score: u32) -> BytesN<32> {
+    let mut data = user.clone().to_xdr(env);
+    data.extend_from_array(&score.to_be_bytes());
+    env.crypto().sha256(&data).to_bytes()
+}
+
+/// Hashes a node pair in sorted order so proofs don't need position flags.
+pub fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (left, right) = if a.to_array() <= b.to_array() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let mut data = Bytes::from(left.clone());
+    data.append(&Bytes::from(right.clone()));
+    env.crypto().sha256(&data).to_bytes()
+}
+
+/// Root over the leaderboard in key order; an unpaired node is carried up as-is.
+pub fn compute_root(env: &Env, leaderboard: &Map<Address, u32>) -> BytesN<32> {
+    let mut level: Vec<BytesN<32>> = Vec::new(env);
+    for (user, score) in leaderboard.iter() {
+        level.push_back(leaf_hash(env, &user, score));
+    }
+    if level.is_empty() {
+        return BytesN::from_array(env, &[0; 32]);
+    }
+
+    while level.len() > 1 {
+        let mut next: Vec<BytesN<32>> = Vec::new(env);
+        let mut i = 0u32;
+        while i < level.len() {
+            let left = level.get_unchecked(i);
+            if i + 1 < level.len() {
+                next.push_back(hash_pair(env, &left, &level.get_unchecked(i + 1)));
+            } else {
+                next.push_back(left);
+            }
+            i += 2;
+        }
+        level = next;
+    }
+    level.get_unchecked(0)
+}
+
+pub fn verify_proof(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    let mut node = leaf.clone();
+    for sibling in proof.iter() {
+        node = hash_pair(env, &node, &sibling);
+    }
+    node == *root
+}
@@ -1,7 +1,12 @@
-use crate::storage::{get_token_metadata, save_token_metadata};
-use crate::types::{AchievementType, Metadata, TokenId};
 use crate::error::Error;
-use soroban_sdk::{Env, String};
+use crate::storage::{
+    get_base_uri as stored_base_uri, get_minted_at, get_token_metadata, get_uri_commitment,
+    save_minted_at, save_token_metadata, save_uri_commitment,
+};
+use crate::types::{
+    AchievementType, Metadata, TokenId, UriCommitment, DEFAULT_BASE_URI, MAX_URI_LEN,
+};
+use soroban_sdk::{BytesN, Env, String};
 
 pub fn store_metadata(
     env: &Env,
@@ -11,6 +16,10 @@ pub fn store_metadata(
     uri: String,
     achievement_type: Option<AchievementType>,
 ) -> Result<(), Error> {
+    validate_uri(&uri)?;
+    if get_minted_at(env, token_id).is_none() {
+        save_minted_at(env, token_id, env.ledger().timestamp());
+    }
     let metadata = Metadata {
         name,
         description,
@@ -24,3 +33,57 @@ pub fn store_metadata(
 pub fn get_metadata(env: &Env, token_id: &TokenId) -> Result<Metadata, Error> {
     get_token_metadata(env, token_id)
 }
+
+pub fn validate_uri(uri: &String) -> Result<(), Error> {
+    if uri.is_empty() || uri.len() > MAX_URI_LEN {
+        return Err(Error::InvalidUri);
+    }
+    Ok(())
+}
+
+pub fn get_base_uri(env: &Env) -> String {
+    stored_base_uri(env).unwrap_or_else(|| String::from_str(env, DEFAULT_BASE_URI))
+}
+
+/// `path` under the configured base URI, e.g. `ipfs://` + `top-rated-freelancer`.
+pub fn template_uri(env: &Env, path: &str) -> Result<String, Error> {
+    let base = get_base_uri(env);
+    let base_len = base.len() as usize;
+    let total = base_len + path.len();
+    if total > MAX_URI_LEN as usize {
+        return Err(Error::InvalidUri);
+    }
+    let mut buf = [0u8; MAX_URI_LEN as usize];
+    base.copy_into_slice(&mut buf[..base_len]);
+    buf[base_len..total].copy_from_slice(path.as_bytes());
+    Ok(String::from_bytes(env, &buf[..total]))
+}
+
+/// Binds the caller-supplied digest of the pinned content to the token's current URI.
+/// Write-once, so a later caller can't swap in a digest of different content.
+pub fn commit_content(
+    env: &Env,
+    token_id: &TokenId,
+    content_hash: &BytesN<32>,
+) -> Result<(), Error> {
+    let metadata = get_token_metadata(env, token_id)?;
+    if get_uri_commitment(env, token_id).is_some() {
+        return Err(Error::ContentAlreadyCommitted);
+    }
+    let commitment = UriCommitment {
+        uri: metadata.uri,
+        content_hash: content_hash.clone(),
+    };
+    save_uri_commitment(env, token_id, &commitment);
+    Ok(())
+}
+
+pub fn verify_uri(env: &Env, token_id: &TokenId, cid_digest: &BytesN<32>) -> Result<bool, Error> {
+    let metadata = get_token_metadata(env, token_id)?;
+    // Nothing was committed for this token, so no content can match
+    let Some(committed) = get_uri_commitment(env, token_id) else {
+        return Ok(false);
+    };
+    // The current URI must still be the one the content was committed under
+    Ok(committed.content_hash == *cid_digest && committed.uri == metadata.uri)
+}
@@ -1,9 +1,16 @@
 use crate::types::{
-    AchievementType, ACHIEVEMENT_LEADERBOARD, ACHIEVEMENT_PREREQUISITES, ACHIEVEMENT_STATS, ADMIN,
-    MINTER, TOKEN_METADATA, TOKEN_OWNER, USER_ACHIEVEMENTS, USER_REPUTATION,
+    AchievementType, ActivityCounters, Listing, PartnerTemplate, UriCommitment,
+    ACHIEVEMENT_LEADERBOARD, ACHIEVEMENT_PREREQUISITES, ACHIEVEMENT_STATS, ADMIN,
+    ACTIVITY_COUNTERS, COMPLETED_CONTRACTS, LISTINGS, MAINTENANCE_CURSOR, BASE_URI, MINTER,
+    MINT_ALLOWANCES, PARAMS_ADDRESS, PARTNER_LAST_MINT, PARTNER_TEMPLATES, PARAMS_SYMBOL,
+    PARAMS_U32, REPUTATION_PROOF, REPUTATION_PROOF_HEAD, SKILL_SCORES, TOKEN_METADATA,
+    TOKEN_MINTED_AT, TOKEN_OWNER, TOKEN_URI_COMMITMENT, TTL_EXTEND_TO, TTL_THRESHOLD,
+    USER_ACHIEVEMENTS, USER_REPUTATION,
 };
+#[cfg(feature = "leaderboard")]
+use crate::types::LEADERBOARD_ROOTS;
 use crate::{Error, Metadata, TokenId};
-use soroban_sdk::{Address, Bytes, BytesN, Env, Map, Vec};
+use soroban_sdk::{Address, Bytes, BytesN, Env, Map, String, Symbol, Vec};
 
 pub fn save_token_owner(env: &Env, token_id: &TokenId, owner: &Address) {
     let key_bytes = create_token_key(env, TOKEN_OWNER, token_id);
@@ -44,6 +51,26 @@ pub fn get_token_metadata(env: &Env, token_id: &TokenId) -> Result<Metadata, Err
     Err(Error::TokenDoesNotExist)
 }
 
+pub fn save_uri_commitment(env: &Env, token_id: &TokenId, commitment: &UriCommitment) {
+    let key_bytes = create_token_key(env, TOKEN_URI_COMMITMENT, token_id);
+    env.storage().persistent().set(&key_bytes, commitment);
+}
+
+pub fn get_uri_commitment(env: &Env, token_id: &TokenId) -> Option<UriCommitment> {
+    let key_bytes = create_token_key(env, TOKEN_URI_COMMITMENT, token_id);
+    env.storage().persistent().get(&key_bytes)
+}
+
+pub fn save_minted_at(env: &Env, token_id: &TokenId, timestamp: u64) {
+    let key_bytes = create_token_key(env, TOKEN_MINTED_AT, token_id);
+    env.storage().persistent().set(&key_bytes, &timestamp);
+}
+
+pub fn get_minted_at(env: &Env, token_id: &TokenId) -> Option<u64> {
+    let key_bytes = create_token_key(env, TOKEN_MINTED_AT, token_id);
+    env.storage().persistent().get(&key_bytes)
+}
+
 fn create_token_key(env: &Env, prefix: &[u8], token_id: &TokenId) -> BytesN<32> {
     let mut key_data = Bytes::new(env);
     key_data.extend_from_slice(prefix);
@@ -58,8 +85,12 @@ pub fn save_admin(env: &Env, admin: &Address) {
 }
 
 pub fn get_admin(env: &Env) -> Address {
+    try_get_admin(env).unwrap_or_else(|| soroban_sdk::panic_with_error!(env, Error::NotInitialized))
+}
+
+pub fn try_get_admin(env: &Env) -> Option<Address> {
     let key_bytes = create_simple_key(env, ADMIN);
-    env.storage().persistent().get(&key_bytes).unwrap()
+    env.storage().persistent().get(&key_bytes)
 }
 
 pub fn is_admin(env: &Env, address: &Address) -> bool {
@@ -86,6 +117,10 @@ pub fn is_minter(env: &Env, address: &Address) -> bool {
     minters.contains_key(address.clone())
 }
 
+pub fn get_minter_list(env: &Env) -> Vec<Address> {
+    get_minters(env).keys()
+}
+
 fn get_minters(env: &Env) -> Map<Address, bool> {
     let key_bytes = create_simple_key(env, MINTER);
     env.storage()
@@ -111,6 +146,12 @@ pub fn next_token_id(env: &Env) -> TokenId {
     counter
 }
 
+pub fn peek_next_token_id(env: &Env) -> TokenId {
+    let key_bytes = create_simple_key(env, TOKEN_ID_COUNTER);
+    let counter: TokenId = env.storage().persistent().get(&key_bytes).unwrap_or(0);
+    counter + 1
+}
+
 pub fn bump_token_counter(env: &Env, minted_id: &TokenId) {
     let key_bytes = create_simple_key(env, TOKEN_ID_COUNTER);
     let mut counter: TokenId = env.storage().persistent().get(&key_bytes).unwrap_or(0);
@@ -122,7 +163,6 @@ pub fn bump_token_counter(env: &Env, minted_id: &TokenId) {
 
 // User achievement indexing functions
 pub fn index_user_achievement(env: &Env, user: &Address, token_id: &TokenId) {
-    const MAX_ACHIEVEMENTS_PER_USER: u32 = 100; // Prevent unbounded growth
     let key = create_simple_key(env, USER_ACHIEVEMENTS);
     let mut map_data: Map<Address, Vec<TokenId>> = env
         .storage()
@@ -131,8 +171,8 @@ pub fn index_user_achievement(env: &Env, user: &Address, token_id: &TokenId) {
         .unwrap_or_else(|| Map::new(env));
 
     let mut list: Vec<TokenId> = map_data.get(user.clone()).unwrap_or_else(|| Vec::new(env));
-    if list.len() >= MAX_ACHIEVEMENTS_PER_USER {
-        soroban_sdk::panic_with_error!(env, Error::InvalidInput);
+    if list.len() >= crate::config::max_achievements_per_user(env) {
+        soroban_sdk::panic_with_error!(env, Error::SupplyExhausted);
     }
     list.push_back(*token_id);
     map_data.set(user.clone(), list);
@@ -175,15 +215,46 @@ pub fn get_user_achievements(env: &Env, user: &Address) -> Vec<TokenId> {
     Vec::new(env)
 }
 
+// Activity counter functions
+pub fn get_activity_counters(env: &Env) -> ActivityCounters {
+    let key = create_simple_key(env, ACTIVITY_COUNTERS);
+    env.storage().persistent().get(&key).unwrap_or_default()
+}
+
+fn update_activity_counters(env: &Env, update: impl FnOnce(&mut ActivityCounters)) {
+    let key = create_simple_key(env, ACTIVITY_COUNTERS);
+    let mut counters = get_activity_counters(env);
+    update(&mut counters);
+    env.storage().persistent().set(&key, &counters);
+}
+
+pub fn record_mint(env: &Env) {
+    update_activity_counters(env, |c| c.mints += 1);
+}
+
+pub fn record_burn(env: &Env) {
+    update_activity_counters(env, |c| c.burns += 1);
+}
+
+pub fn record_transfer(env: &Env) {
+    update_activity_counters(env, |c| c.transfers += 1);
+}
+
 // Burn token function
 pub fn burn_token(env: &Env, token_id: &TokenId) {
     let owner_key = create_token_key(env, TOKEN_OWNER, token_id);
     env.storage().persistent().remove(&owner_key);
     let meta_key = create_token_key(env, TOKEN_METADATA, token_id);
     env.storage().persistent().remove(&meta_key);
+    let digest_key = create_token_key(env, TOKEN_URI_COMMITMENT, token_id);
+    env.storage().persistent().remove(&digest_key);
+    let minted_key = create_token_key(env, TOKEN_MINTED_AT, token_id);
+    env.storage().persistent().remove(&minted_key);
+    remove_listing(env, token_id);
 }
 
 // Achievement statistics functions
+#[cfg(feature = "statistics")]
 pub fn update_achievement_stats(env: &Env, achievement_type: &AchievementType) {
     let key = create_simple_key(env, ACHIEVEMENT_STATS);
     let mut stats = env
@@ -198,6 +269,7 @@ pub fn update_achievement_stats(env: &Env, achievement_type: &AchievementType) {
     env.storage().persistent().set(&key, &stats);
 }
 
+#[cfg(feature = "statistics")]
 pub fn decrement_achievement_stats(env: &Env, achievement_type: &AchievementType) {
     let key = create_simple_key(env, ACHIEVEMENT_STATS);
     let mut stats = env
@@ -213,6 +285,7 @@ pub fn decrement_achievement_stats(env: &Env, achievement_type: &AchievementType
     }
 }
 
+#[cfg(feature = "statistics")]
 pub fn get_achievement_stats(env: &Env) -> Map<AchievementType, u32> {
     let key = create_simple_key(env, ACHIEVEMENT_STATS);
     env.storage()
@@ -221,7 +294,15 @@ pub fn get_achievement_stats(env: &Env) -> Map<AchievementType, u32> {
         .unwrap_or_else(|| Map::new(env))
 }
 
+// Without the statistics feature the mint/burn hooks compile to no-ops
+#[cfg(not(feature = "statistics"))]
+pub fn update_achievement_stats(_env: &Env, _achievement_type: &AchievementType) {}
+
+#[cfg(not(feature = "statistics"))]
+pub fn decrement_achievement_stats(_env: &Env, _achievement_type: &AchievementType) {}
+
 // Leaderboard functions
+#[cfg(feature = "leaderboard")]
 pub fn update_leaderboard(env: &Env, user: &Address) {
     let key = create_simple_key(env, ACHIEVEMENT_LEADERBOARD);
     let mut leaderboard = env
@@ -231,11 +312,12 @@ pub fn update_leaderboard(env: &Env, user: &Address) {
         .unwrap_or_else(|| Map::new(env));
 
     let achievements = get_user_achievements(env, user);
-    leaderboard.set(user.clone(), achievements.len() as u32);
+    leaderboard.set(user.clone(), achievements.len());
 
     env.storage().persistent().set(&key, &leaderboard);
 }
 
+#[cfg(feature = "leaderboard")]
 pub fn get_leaderboard(env: &Env) -> Map<Address, u32> {
     let key = create_simple_key(env, ACHIEVEMENT_LEADERBOARD);
     env.storage()
@@ -244,6 +326,28 @@ pub fn get_leaderboard(env: &Env) -> Map<Address, u32> {
         .unwrap_or_else(|| Map::new(env))
 }
 
+#[cfg(feature = "leaderboard")]
+pub fn save_leaderboard_root(env: &Env, epoch: u32, root: &BytesN<32>) {
+    let key = create_simple_key(env, LEADERBOARD_ROOTS);
+    let mut roots: Map<u32, BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Map::new(env));
+    roots.set(epoch, root.clone());
+    env.storage().persistent().set(&key, &roots);
+}
+
+#[cfg(feature = "leaderboard")]
+pub fn get_leaderboard_root(env: &Env, epoch: u32) -> Option<BytesN<32>> {
+    let key = create_simple_key(env, LEADERBOARD_ROOTS);
+    env.storage()
+        .persistent()
+        .get::<BytesN<32>, Map<u32, BytesN<32>>>(&key)
+        .and_then(|roots| roots.get(epoch))
+}
+
+#[cfg(feature = "leaderboard")]
 pub fn get_user_rank(env: &Env, user: &Address) -> u32 {
     let leaderboard = get_leaderboard(env);
     let user_score = leaderboard.get(user.clone()).unwrap_or(0);
@@ -257,6 +361,9 @@ pub fn get_user_rank(env: &Env, user: &Address) -> u32 {
     rank
 }
 
+#[cfg(not(feature = "leaderboard"))]
+pub fn update_leaderboard(_env: &Env, _user: &Address) {}
+
 // Reputation score functions
 pub fn store_reputation_score(env: &Env, user: &Address, rating_average: u32, total_ratings: u32) {
     let key = create_simple_key(env, USER_REPUTATION);
@@ -313,25 +420,364 @@ pub fn get_achievement_prerequisite(
     prereq_map.get(*achievement_type)
 }
 
+pub fn has_achievement_type(env: &Env, user: &Address, achievement_type: &AchievementType) -> bool {
+    for token_id in get_user_achievements(env, user).iter() {
+        if let Ok(metadata) = get_token_metadata(env, &token_id) {
+            if metadata.achievement_type == *achievement_type {
+                return true;
+            }
+        }
+    }
+    false
+}
+
 pub fn check_achievement_prerequisite(
     env: &Env,
     user: &Address,
     achievement_type: &AchievementType,
 ) -> bool {
     if let Some(prerequisite) = get_achievement_prerequisite(env, achievement_type) {
-        let user_achievements = get_user_achievements(env, user);
         // Check if user has any achievement of the prerequisite type
-        for i in 0..user_achievements.len() {
-            if let Some(token_id) = user_achievements.get(i) {
-                if let Ok(metadata) = get_token_metadata(env, &token_id) {
-                    if metadata.achievement_type == prerequisite {
-                        return true;
-                    }
-                }
-            }
-        }
-        false
+        has_achievement_type(env, user, &prerequisite)
     } else {
         true // No prerequisite required
     }
 }
+
+// Reputation proof functions
+pub fn get_reputation_proof_head(env: &Env, user: &Address) -> BytesN<32> {
+    let key = create_simple_key(env, REPUTATION_PROOF_HEAD);
+    let heads = env
+        .storage()
+        .persistent()
+        .get::<BytesN<32>, Map<Address, BytesN<32>>>(&key)
+        .unwrap_or_else(|| Map::new(env));
+
+    heads
+        .get(user.clone())
+        .unwrap_or_else(|| BytesN::from_array(env, &[0; 32]))
+}
+
+pub fn record_reputation_proof(env: &Env, user: &Address, hash: &BytesN<32>) {
+    let key = create_simple_key(env, REPUTATION_PROOF_HEAD);
+    let mut heads = env
+        .storage()
+        .persistent()
+        .get::<BytesN<32>, Map<Address, BytesN<32>>>(&key)
+        .unwrap_or_else(|| Map::new(env));
+    heads.set(user.clone(), hash.clone());
+    env.storage().persistent().set(&key, &heads);
+
+    let proof_key = create_hash_key(env, REPUTATION_PROOF, hash);
+    env.storage().persistent().set(&proof_key, user);
+}
+
+pub fn reputation_proof_issued_to(env: &Env, hash: &BytesN<32>) -> Option<Address> {
+    let proof_key = create_hash_key(env, REPUTATION_PROOF, hash);
+    env.storage().persistent().get(&proof_key)
+}
+
+fn create_hash_key(env: &Env, prefix: &[u8], hash: &BytesN<32>) -> BytesN<32> {
+    let mut key_data = Bytes::new(env);
+    key_data.extend_from_slice(prefix);
+    key_data.append(&Bytes::from(hash.clone()));
+    env.crypto().sha256(&key_data).to_bytes()
+}
+
+pub fn get_user_achievement_counts(env: &Env, user: &Address) -> Map<AchievementType, u32> {
+    let mut counts = Map::new(env);
+    for token_id in get_user_achievements(env, user).iter() {
+        if let Ok(metadata) = get_token_metadata(env, &token_id) {
+            let count = counts.get(metadata.achievement_type).unwrap_or(0);
+            counts.set(metadata.achievement_type, count + 1);
+        }
+    }
+    counts
+}
+
+// Typed configuration parameter functions
+pub fn set_u32_param(env: &Env, key: &Symbol, value: u32) {
+    let map_key = create_simple_key(env, PARAMS_U32);
+    let mut params: Map<Symbol, u32> = env
+        .storage()
+        .persistent()
+        .get(&map_key)
+        .unwrap_or_else(|| Map::new(env));
+    params.set(key.clone(), value);
+    env.storage().persistent().set(&map_key, &params);
+}
+
+pub fn get_u32_param(env: &Env, key: &Symbol) -> Option<u32> {
+    let map_key = create_simple_key(env, PARAMS_U32);
+    env.storage()
+        .persistent()
+        .get::<BytesN<32>, Map<Symbol, u32>>(&map_key)
+        .and_then(|params| params.get(key.clone()))
+}
+
+pub fn set_address_param(env: &Env, key: &Symbol, value: &Address) {
+    let map_key = create_simple_key(env, PARAMS_ADDRESS);
+    let mut params: Map<Symbol, Address> = env
+        .storage()
+        .persistent()
+        .get(&map_key)
+        .unwrap_or_else(|| Map::new(env));
+    params.set(key.clone(), value.clone());
+    env.storage().persistent().set(&map_key, &params);
+}
+
+pub fn get_address_param(env: &Env, key: &Symbol) -> Option<Address> {
+    let map_key = create_simple_key(env, PARAMS_ADDRESS);
+    env.storage()
+        .persistent()
+        .get::<BytesN<32>, Map<Symbol, Address>>(&map_key)
+        .and_then(|params| params.get(key.clone()))
+}
+
+pub fn set_symbol_param(env: &Env, key: &Symbol, value: &Symbol) {
+    let map_key = create_simple_key(env, PARAMS_SYMBOL);
+    let mut params: Map<Symbol, Symbol> = env
+        .storage()
+        .persistent()
+        .get(&map_key)
+        .unwrap_or_else(|| Map::new(env));
+    params.set(key.clone(), value.clone());
+    env.storage().persistent().set(&map_key, &params);
+}
+
+pub fn get_symbol_param(env: &Env, key: &Symbol) -> Option<Symbol> {
+    let map_key = create_simple_key(env, PARAMS_SYMBOL);
+    env.storage()
+        .persistent()
+        .get::<BytesN<32>, Map<Symbol, Symbol>>(&map_key)
+        .and_then(|params| params.get(key.clone()))
+}
+
+// Keeper maintenance functions
+fn extend_if_present(env: &Env, key: &BytesN<32>) -> bool {
+    if env.storage().persistent().has(key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+        return true;
+    }
+    false
+}
+
+pub fn extend_shared_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(TTL_THRESHOLD, TTL_EXTEND_TO);
+    for prefix in [
+        ADMIN,
+        ACTIVITY_COUNTERS,
+        MINTER,
+        TOKEN_ID_COUNTER,
+        USER_ACHIEVEMENTS,
+        ACHIEVEMENT_STATS,
+        ACHIEVEMENT_LEADERBOARD,
+        USER_REPUTATION,
+        ACHIEVEMENT_PREREQUISITES,
+    ] {
+        extend_if_present(env, &create_simple_key(env, prefix));
+    }
+}
+
+/// Bumps the TTL of up to `max_items` tokens, resuming from the stored cursor
+/// and wrapping around once every minted id has been visited. Returns the ids
+/// of the visited tokens that still exist.
+pub fn extend_token_ttls(env: &Env, max_items: u32) -> Vec<TokenId> {
+    let counter_key = create_simple_key(env, TOKEN_ID_COUNTER);
+    let last_id: TokenId = env.storage().persistent().get(&counter_key).unwrap_or(0);
+    let cursor_key = create_simple_key(env, MAINTENANCE_CURSOR);
+    let mut cursor: TokenId = env.storage().persistent().get(&cursor_key).unwrap_or(0);
+    if cursor >= last_id {
+        cursor = 0;
+    }
+
+    let mut visited = Vec::new(env);
+    while visited.len() < max_items && cursor < last_id {
+        cursor += 1;
+        if extend_if_present(env, &create_token_key(env, TOKEN_OWNER, &cursor)) {
+            extend_if_present(env, &create_token_key(env, TOKEN_METADATA, &cursor));
+            extend_if_present(env, &create_token_key(env, TOKEN_URI_COMMITMENT, &cursor));
+            extend_if_present(env, &create_token_key(env, TOKEN_MINTED_AT, &cursor));
+            visited.push_back(cursor);
+        }
+    }
+
+    env.storage().persistent().set(&cursor_key, &cursor);
+    visited
+}
+
+/// Fixes up to `max_items` leaderboard entries that disagree with the achievement
+/// index: users left without achievements are removed and stale scores are
+/// re-synced to the number of achievements actually held.
+#[cfg(feature = "leaderboard")]
+pub fn prune_leaderboard(env: &Env, max_items: u32) -> u32 {
+    let key = create_simple_key(env, ACHIEVEMENT_LEADERBOARD);
+    let mut leaderboard = get_leaderboard(env);
+    let mut pruned = 0u32;
+    for (user, score) in leaderboard.clone().iter() {
+        if pruned >= max_items {
+            break;
+        }
+        let held = get_user_achievements(env, &user).len();
+        if held == 0 {
+            leaderboard.remove(user);
+            pruned += 1;
+        } else if held != score {
+            leaderboard.set(user, held);
+            pruned += 1;
+        }
+    }
+    if pruned > 0 {
+        env.storage().persistent().set(&key, &leaderboard);
+    }
+    pruned
+}
+
+#[cfg(not(feature = "leaderboard"))]
+pub fn prune_leaderboard(_env: &Env, _max_items: u32) -> u32 {
+    0
+}
+
+// Partner dApp allowances, keyed by (dapp, template)
+pub fn set_mint_allowance(env: &Env, dapp: &Address, template: &Symbol, remaining: u32) {
+    let key = create_simple_key(env, MINT_ALLOWANCES);
+    let mut allowances: Map<(Address, Symbol), u32> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Map::new(env));
+    if remaining == 0 {
+        allowances.remove((dapp.clone(), template.clone()));
+    } else {
+        allowances.set((dapp.clone(), template.clone()), remaining);
+    }
+    env.storage().persistent().set(&key, &allowances);
+}
+
+pub fn get_mint_allowance(env: &Env, dapp: &Address, template: &Symbol) -> u32 {
+    let key = create_simple_key(env, MINT_ALLOWANCES);
+    env.storage()
+        .persistent()
+        .get::<BytesN<32>, Map<(Address, Symbol), u32>>(&key)
+        .and_then(|allowances| allowances.get((dapp.clone(), template.clone())))
+        .unwrap_or(0)
+}
+
+pub fn save_partner_template(env: &Env, template: &Symbol, partner_template: &PartnerTemplate) {
+    let key = create_simple_key(env, PARTNER_TEMPLATES);
+    let mut templates: Map<Symbol, PartnerTemplate> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Map::new(env));
+    templates.set(template.clone(), partner_template.clone());
+    env.storage().persistent().set(&key, &templates);
+}
+
+pub fn get_partner_template(env: &Env, template: &Symbol) -> Option<PartnerTemplate> {
+    let key = create_simple_key(env, PARTNER_TEMPLATES);
+    env.storage()
+        .persistent()
+        .get::<BytesN<32>, Map<Symbol, PartnerTemplate>>(&key)
+        .and_then(|templates| templates.get(template.clone()))
+}
+
+pub fn set_base_uri(env: &Env, base_uri: &String) {
+    let key = create_simple_key(env, BASE_URI);
+    env.storage().persistent().set(&key, base_uri);
+}
+
+pub fn get_base_uri(env: &Env) -> Option<String> {
+    let key = create_simple_key(env, BASE_URI);
+    env.storage().persistent().get(&key)
+}
+
+pub fn set_partner_last_mint(env: &Env, dapp: &Address, timestamp: u64) {
+    let key = create_simple_key(env, PARTNER_LAST_MINT);
+    let mut last_mints: Map<Address, u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Map::new(env));
+    last_mints.set(dapp.clone(), timestamp);
+    env.storage().persistent().set(&key, &last_mints);
+}
+
+pub fn get_partner_last_mint(env: &Env, dapp: &Address) -> Option<u64> {
+    let key = create_simple_key(env, PARTNER_LAST_MINT);
+    env.storage()
+        .persistent()
+        .get::<BytesN<32>, Map<Address, u64>>(&key)
+        .and_then(|last_mints| last_mints.get(dapp.clone()))
+}
+
+pub fn get_completed_contracts(env: &Env, user: &Address) -> u32 {
+    let key = create_simple_key(env, COMPLETED_CONTRACTS);
+    env.storage()
+        .persistent()
+        .get::<BytesN<32>, Map<Address, u32>>(&key)
+        .and_then(|completed| completed.get(user.clone()))
+        .unwrap_or(0)
+}
+
+pub fn increment_completed_contracts(env: &Env, user: &Address) -> u32 {
+    let key = create_simple_key(env, COMPLETED_CONTRACTS);
+    let mut completed: Map<Address, u32> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Map::new(env));
+    let count = completed.get(user.clone()).unwrap_or(0) + 1;
+    completed.set(user.clone(), count);
+    env.storage().persistent().set(&key, &completed);
+    count
+}
+
+// Per-skill (score, total_ratings) for each user, with scores on the same x100 scale as the
+// overall reputation score
+pub fn get_skill_scores(env: &Env, user: &Address) -> Map<Symbol, (u32, u32)> {
+    let key = create_simple_key(env, SKILL_SCORES);
+    env.storage()
+        .persistent()
+        .get::<BytesN<32>, Map<Address, Map<Symbol, (u32, u32)>>>(&key)
+        .and_then(|scores| scores.get(user.clone()))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn store_skill_score(
+    env: &Env,
+    user: &Address,
+    skill: &Symbol,
+    score: u32,
+    total_ratings: u32,
+) {
+    let key = create_simple_key(env, SKILL_SCORES);
+    let mut scores: Map<Address, Map<Symbol, (u32, u32)>> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Map::new(env));
+    let mut user_scores = scores.get(user.clone()).unwrap_or_else(|| Map::new(env));
+    user_scores.set(skill.clone(), (score, total_ratings));
+    scores.set(user.clone(), user_scores);
+    env.storage().persistent().set(&key, &scores);
+}
+
+pub fn save_listing(env: &Env, token_id: &TokenId, listing: &Listing) {
+    let key_bytes = create_token_key(env, LISTINGS, token_id);
+    env.storage().persistent().set(&key_bytes, listing);
+}
+
+pub fn get_listing(env: &Env, token_id: &TokenId) -> Option<Listing> {
+    let key_bytes = create_token_key(env, LISTINGS, token_id);
+    env.storage().persistent().get(&key_bytes)
+}
+
+pub fn remove_listing(env: &Env, token_id: &TokenId) {
+    let key_bytes = create_token_key(env, LISTINGS, token_id);
+    env.storage().persistent().remove(&key_bytes);
+}
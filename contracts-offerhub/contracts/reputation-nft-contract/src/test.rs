@@ -1,7 +1,15 @@
 #![cfg(test)]
 
-use crate::{AchievementType, Contract, Error, ReputationNFTContract, TokenId};
-use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env, IntoVal, String};
+use crate::{
+    AchievementType, Contract, ContractClient, Error, PartnerTemplate, ReputationNFTContract,
+    TokenId,
+};
+use offerhub_testutils::{contract_result, create_token};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger as _},
+    vec, Address, BytesN, Env, IntoVal, String, Symbol,
+};
 
 // For direct access to storage functions for testing
 use crate::metadata;
@@ -16,221 +24,6 @@ fn setup() -> (Env, Address, Address) {
     (env, admin, contract_id)
 }
 
-// Contract client for testing
-struct ContractClient {
-    env: Env,
-    contract_id: Address,
-}
-
-#[allow(dead_code)]
-impl ContractClient {
-    fn new(env: Env, contract_id: Address) -> Self {
-        Self { env, contract_id }
-    }
-
-    fn init(&self, admin: Address) -> Result<(), Error> {
-        let env = &self.env;
-        let args = vec![env, admin.into_val(env)];
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("init"), args)
-    }
-
-    fn is_paused(&self) -> Result<bool, Error> {
-        let env = &self.env;
-        let args = vec![env];
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("is_paused"), args)
-    }
-
-     fn pause(&self, admin: Address) -> Result<(), Error> {
-        let env = &self.env;
-        let args = vec![env, admin.into_val(env)];
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("pause"), args)
-    }
-
-    fn unpause(&self, admin: Address) -> Result<(), Error> {
-        let env = &self.env;
-        let args = vec![env, admin.into_val(env)];
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("unpause"), args)
-    }
-
-    fn mint(
-        &self,
-        caller: Address,
-        to: Address,
-        token_id: TokenId,
-        name: String,
-        description: String,
-        uri: String,
-    ) -> Result<(), Error> {
-        let env = &self.env;
-        let args = vec![
-            env,
-            caller.into_val(env),
-            to.into_val(env),
-            token_id.into_val(env),
-            name.into_val(env),
-            description.into_val(env),
-            uri.into_val(env),
-        ];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("mint"), args)
-    }
-
-    fn transfer(&self, from: Address, to: Address, token_id: TokenId) -> Result<(), Error> {
-        let env = &self.env;
-        let args = vec![
-            env,
-            from.into_val(env),
-            to.into_val(env),
-            token_id.into_val(env),
-        ];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("transfer"), args)
-    }
-
-    fn get_owner(&self, token_id: TokenId) -> Result<Address, Error> {
-        let env = &self.env;
-        let args = vec![env, token_id.into_val(env)];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("get_owner"), args)
-    }
-
-    fn get_metadata(&self, token_id: TokenId) -> Result<crate::Metadata, Error> {
-        let env = &self.env;
-        let args = vec![env, token_id.into_val(env)];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("get_meta"), args)
-    }
-
-    fn add_minter(&self, caller: Address, minter: Address) -> Result<(), Error> {
-        let env = &self.env;
-        let args = vec![env, caller.into_val(env), minter.into_val(env)];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("add_mint"), args)
-    }
-
-    fn remove_minter(&self, caller: Address, minter: Address) -> Result<(), Error> {
-        let env = &self.env;
-        let args = vec![env, caller.into_val(env), minter.into_val(env)];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("rem_mint"), args)
-    }
-
-    fn is_minter(&self, address: Address) -> Result<bool, Error> {
-        let env = &self.env;
-        let args = vec![env, address.into_val(env)];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("is_minter"), args)
-    }
-
-    fn get_admin(&self) -> Result<Address, Error> {
-        let args = vec![&self.env];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("get_admin"), args)
-    }
-
-    fn transfer_admin(&self, caller: Address, new_admin: Address) -> Result<(), Error> {
-        let env = &self.env;
-        let args = vec![env, caller.into_val(env), new_admin.into_val(env)];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("tr_admin"), args)
-    }
-
-    fn req_auth(&self, addr: Address) -> Result<(), Error> {
-        let env = &self.env;
-        let args = vec![env, addr.into_val(env)];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("req_auth"), args)
-    }
-
-    fn update_reputation_score(
-        &self,
-        caller: Address,
-        user: Address,
-        rating_average: u32,
-        total_ratings: u32,
-    ) -> Result<(), Error> {
-        let env = &self.env;
-        let args = vec![
-            env,
-            caller.into_val(env),
-            user.into_val(env),
-            rating_average.into_val(env),
-            total_ratings.into_val(env),
-        ];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("upd_reput"), args)
-    }
-
-    fn get_achievement_statistics(&self) -> soroban_sdk::Map<AchievementType, u32> {
-        let env = &self.env;
-        let args = vec![env];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("ach_stats"), args)
-    }
-
-    fn get_achievement_leaderboard(&self) -> soroban_sdk::Map<Address, u32> {
-        let env = &self.env;
-        let args = vec![env];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("leader"), args)
-    }
-
-    fn get_user_achievement_rank(&self, user: Address) -> u32 {
-        let env = &self.env;
-        let args = vec![env, user.into_val(env)];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("get_rank"), args)
-    }
-
-    fn burn(&self, caller: Address, token_id: TokenId) -> Result<(), Error> {
-        let env = &self.env;
-        let args = vec![env, caller.into_val(env), token_id.into_val(env)];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("burn"), args)
-    }
-
-    fn batch_mint(
-        &self,
-        caller: Address,
-        tos: soroban_sdk::Vec<Address>,
-        names: soroban_sdk::Vec<String>,
-        descriptions: soroban_sdk::Vec<String>,
-        uris: soroban_sdk::Vec<String>,
-    ) -> Result<(), Error> {
-        let env = &self.env;
-        let args = vec![
-            env,
-            caller.into_val(env),
-            tos.into_val(env),
-            names.into_val(env),
-            descriptions.into_val(env),
-            uris.into_val(env),
-        ];
-
-        self.env
-            .invoke_contract(&self.contract_id, &symbol_short!("batch_m"), args)
-    }
-}
-
 #[test]
 fn test_init() {
     let (env, admin, contract_id) = setup();
@@ -401,11 +194,11 @@ fn test_admin_can_mint_without_being_minter() {
 fn test_achievement_types() {
     let (env, admin, contract_id) = setup();
     let user = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
+    client.add_minter(&admin, &admin);
 
     // Test different achievement types
     let achievement_types = [
@@ -432,11 +225,11 @@ fn test_achievement_types() {
 
         // Verify token was minted
         let token_id = (i + 1) as u64;
-        let owner = client.get_owner(token_id).unwrap();
+        let owner = client.get_owner(&token_id);
         assert_eq!(owner, user);
 
         // Verify metadata
-        let metadata = client.get_metadata(token_id).unwrap();
+        let metadata = client.get_metadata(&token_id);
         assert!(!metadata.name.is_empty());
         assert!(!metadata.description.is_empty());
         assert!(!metadata.uri.is_empty());
@@ -448,33 +241,33 @@ fn test_transfer_scenarios() {
     let (env, admin, contract_id) = setup();
     let user1 = Address::generate(&env);
     let user2 = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
+    client.add_minter(&admin, &admin);
 
     // Mint an NFT to user1
-    let result = client.mint(
-        admin.clone(),
-        user1.clone(),
-        1,
-        String::from_str(&env, "Test NFT"),
-        String::from_str(&env, "Test Description"),
-        String::from_str(&env, "ipfs://test"),
-    );
+    let result = contract_result(client.try_mint(
+        &admin,
+        &user1,
+        &1,
+        &String::from_str(&env, "Test NFT"),
+        &String::from_str(&env, "Test Description"),
+        &String::from_str(&env, "ipfs://test"),
+    ));
     assert!(result.is_ok());
 
     // Verify initial ownership
-    let owner = client.get_owner(1).unwrap();
+    let owner = client.get_owner(&1);
     assert_eq!(owner, user1);
 
     // Transfer from user1 to user2
-    let transfer_result = client.transfer(user1.clone(), user2.clone(), 1);
+    let transfer_result = contract_result(client.try_transfer(&user1, &user2, &1));
     assert!(transfer_result.is_ok());
 
     // Verify ownership changed
-    let new_owner = client.get_owner(1).unwrap();
+    let new_owner = client.get_owner(&1);
     assert_eq!(new_owner, user2);
 }
 
@@ -482,26 +275,24 @@ fn test_transfer_scenarios() {
 fn test_error_conditions() {
     let (env, admin, contract_id) = setup();
     let user = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
+    client.add_minter(&admin, &admin);
 
     // Test: Mint first token successfully
-    client
-        .mint(
-            admin.clone(),
-            user.clone(),
-            1,
-            String::from_str(&env, "First NFT"),
-            String::from_str(&env, "First Description"),
-            String::from_str(&env, "ipfs://first"),
-        )
-        .unwrap();
+    client.mint(
+        &admin,
+        &user,
+        &1,
+        &String::from_str(&env, "First NFT"),
+        &String::from_str(&env, "First Description"),
+        &String::from_str(&env, "ipfs://first"),
+    );
 
     // Test: Verify token was created successfully
-    let owner = client.get_owner(1).unwrap();
+    let owner = client.get_owner(&1);
     assert_eq!(owner, user);
 
     // Test error conditions using storage functions directly to avoid contract invocation panics
@@ -534,35 +325,30 @@ fn test_error_conditions() {
 fn test_token_already_exist() {
     let (env, admin, contract_id) = setup();
     let user = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
+    client.add_minter(&admin, &admin);
 
     // Test: Mint first token successfully
-    client
-        .mint(
-            admin.clone(),
-            user.clone(),
-            1,
-            String::from_str(&env, "First NFT"),
-            String::from_str(&env, "First Description"),
-            String::from_str(&env, "ipfs://first"),
-        )
-        .unwrap();
-
-    client
-        .mint(
-            admin.clone(),
-            user.clone(),
-            1,
-            String::from_str(&env, "First NFT"),
-            String::from_str(&env, "First Description"),
-            String::from_str(&env, "ipfs://first"),
-        )
-        .unwrap();
+    client.mint(
+        &admin,
+        &user,
+        &1,
+        &String::from_str(&env, "First NFT"),
+        &String::from_str(&env, "First Description"),
+        &String::from_str(&env, "ipfs://first"),
+    );
 
+    client.mint(
+        &admin,
+        &user,
+        &1,
+        &String::from_str(&env, "First NFT"),
+        &String::from_str(&env, "First Description"),
+        &String::from_str(&env, "ipfs://first"),
+    );
 }
 
 
@@ -571,36 +357,36 @@ fn test_minter_management() {
     let (env, admin, contract_id) = setup();
     let minter1 = Address::generate(&env);
     let user = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
 
     // Test: Admin can add minters
-    let add_result = client.add_minter(admin.clone(), minter1.clone());
+    let add_result = contract_result(client.try_add_minter(&admin, &minter1));
     assert!(add_result.is_ok());
 
     // Verify minter was added
-    let is_minter = client.is_minter(minter1.clone()).unwrap();
+    let is_minter = client.is_minter(&minter1);
     assert!(is_minter);
 
     // Test: Minter can mint
-    let mint_result = client.mint(
-        minter1.clone(),
-        user.clone(),
-        1,
-        String::from_str(&env, "Minter NFT"),
-        String::from_str(&env, "Minted by authorized minter"),
-        String::from_str(&env, "ipfs://minter"),
-    );
+    let mint_result = contract_result(client.try_mint(
+        &minter1,
+        &user,
+        &1,
+        &String::from_str(&env, "Minter NFT"),
+        &String::from_str(&env, "Minted by authorized minter"),
+        &String::from_str(&env, "ipfs://minter"),
+    ));
     assert!(mint_result.is_ok());
 
     // Test: Remove minter
-    let remove_result = client.remove_minter(admin.clone(), minter1.clone());
+    let remove_result = contract_result(client.try_remove_minter(&admin, &minter1));
     assert!(remove_result.is_ok());
 
     // Verify minter was removed
-    let is_minter_after = client.is_minter(minter1.clone()).unwrap();
+    let is_minter_after = client.is_minter(&minter1);
     assert!(!is_minter_after);
 
     // No intentamos hacer mint después de remover el minter porque con mock_all_auths
@@ -640,29 +426,29 @@ fn test_unauthorized_minting() {
 fn test_admin_minting_privilege() {
     let (env, admin, contract_id) = setup();
     let user = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths(); // Esto es necesario para evitar errores de autorización
 
     // Verificar que admin no es minter por defecto
-    let is_admin_minter = client.is_minter(admin.clone()).unwrap();
+    let is_admin_minter = client.is_minter(&admin);
     assert!(!is_admin_minter);
 
     // Admin should be able to mint without being explicitly added as minter
-    let result = client.mint(
-        admin.clone(),
-        user.clone(),
-        1,
-        String::from_str(&env, "Admin NFT"),
-        String::from_str(&env, "Minted by admin"),
-        String::from_str(&env, "ipfs://admin"),
-    );
+    let result = contract_result(client.try_mint(
+        &admin,
+        &user,
+        &1,
+        &String::from_str(&env, "Admin NFT"),
+        &String::from_str(&env, "Minted by admin"),
+        &String::from_str(&env, "ipfs://admin"),
+    ));
 
     assert!(result.is_ok());
 
     // Verify token was created
-    let owner = client.get_owner(1).unwrap();
+    let owner = client.get_owner(&1);
     assert_eq!(owner, user);
 }
 
@@ -798,11 +584,11 @@ fn test_complete_mint_validation() {
 fn test_rating_system_integration() {
     let (env, admin, contract_id) = setup();
     let user = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
+    client.add_minter(&admin, &admin);
 
     // En lugar de llamar funciones inexistentes, simplemente probamos las existentes
     // Mint un NFT de achievement existente
@@ -820,10 +606,10 @@ fn test_rating_system_integration() {
     assert!(result.is_ok());
 
     // Verify the achievement token was created
-    let owner = client.get_owner(1).unwrap();
+    let owner = client.get_owner(&1);
     assert_eq!(owner, user);
 
-    let metadata = client.get_metadata(1).unwrap();
+    let metadata = client.get_metadata(&1);
     assert_eq!(metadata.name, String::from_str(&env, "5 Stars 5 Times"));
 }
 
@@ -831,11 +617,11 @@ fn test_rating_system_integration() {
 fn test_reputation_nft_features() {
     let (env, admin, contract_id) = setup();
     let user = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
+    client.add_minter(&admin, &admin);
 
     // Test different achievement types que SÍ existen
     let achievement_types = [
@@ -858,11 +644,11 @@ fn test_reputation_nft_features() {
 
         // Verify token was minted
         let token_id = (i + 1) as u64;
-        let owner = client.get_owner(token_id).unwrap();
+        let owner = client.get_owner(&token_id);
         assert_eq!(owner, user);
 
         // Verify metadata exists
-        let metadata = client.get_metadata(token_id).unwrap();
+        let metadata = client.get_metadata(&token_id);
         assert!(!metadata.name.is_empty());
         assert!(!metadata.description.is_empty());
         assert!(!metadata.uri.is_empty());
@@ -874,46 +660,40 @@ fn test_comprehensive_nft_lifecycle() {
     let (env, admin, contract_id) = setup();
     let original_owner = Address::generate(&env);
     let new_owner = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
+    client.add_minter(&admin, &admin);
 
     // Step 1: Mint NFT
     let name = String::from_str(&env, "Achievement NFT");
     let description = String::from_str(&env, "Special achievement token");
     let uri = String::from_str(&env, "ipfs://special-achievement");
 
-    let mint_result = client.mint(
-        admin.clone(),
-        original_owner.clone(),
-        1,
-        name.clone(),
-        description.clone(),
-        uri.clone(),
-    );
+    let mint_result =
+        contract_result(client.try_mint(&admin, &original_owner, &1, &name, &description, &uri));
     assert!(mint_result.is_ok());
 
     // Step 2: Verify ownership and metadata
-    let owner = client.get_owner(1).unwrap();
+    let owner = client.get_owner(&1);
     assert_eq!(owner, original_owner);
 
-    let metadata = client.get_metadata(1).unwrap();
+    let metadata = client.get_metadata(&1);
     assert_eq!(metadata.name, name);
     assert_eq!(metadata.description, description);
     assert_eq!(metadata.uri, uri);
 
     // Step 3: Transfer NFT
-    let transfer_result = client.transfer(original_owner.clone(), new_owner.clone(), 1);
+    let transfer_result = contract_result(client.try_transfer(&original_owner, &new_owner, &1));
     assert!(transfer_result.is_ok());
 
     // Step 4: Verify new ownership
-    let new_owner_check = client.get_owner(1).unwrap();
+    let new_owner_check = client.get_owner(&1);
     assert_eq!(new_owner_check, new_owner);
 
     // Step 5: Verify metadata persists after transfer
-    let metadata_after_transfer = client.get_metadata(1).unwrap();
+    let metadata_after_transfer = client.get_metadata(&1);
     assert_eq!(metadata_after_transfer.name, name);
     assert_eq!(metadata_after_transfer.description, description);
     assert_eq!(metadata_after_transfer.uri, uri);
@@ -1067,12 +847,12 @@ fn test_unauthorized_access() {
     });
 
     // Create a client for the tests
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
     // Mock auth to avoid errors in tests
     env.mock_all_auths();
 
-    let result = client.req_auth(unauthorized.clone());
+    let result = contract_result(client.try_req_auth(&unauthorized));
     assert!(result.is_ok());
 }
 
@@ -1145,10 +925,10 @@ fn test_mint_and_query() {
     let token_id: TokenId = 1;
 
     // Create client
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
     // Initialize contract
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
 
     // Test minting with admin as caller
     env.mock_all_auths();
@@ -1156,23 +936,23 @@ fn test_mint_and_query() {
     let description = String::from_str(&env, "Test Description");
     let uri = String::from_str(&env, "ipfs://test");
 
-    client
-        .mint(
-            admin.clone(), // caller
-            user.clone(),  // to
+    client.mint(
+        &admin,
+        &// caller
+            user.clone(),
+        &// to
             token_id,
-            name.clone(),
-            description.clone(),
-            uri.clone(),
-        )
-        .unwrap();
+        &name,
+        &description,
+        &uri,
+    );
 
     // Verify token ownership
-    let owner = client.get_owner(token_id).unwrap();
+    let owner = client.get_owner(&token_id);
     assert_eq!(owner, user);
 
     // Verify metadata
-    let metadata = client.get_metadata(token_id).unwrap();
+    let metadata = client.get_metadata(&token_id);
     assert_eq!(metadata.name, name);
     assert_eq!(metadata.description, description);
     assert_eq!(metadata.uri, uri);
@@ -1186,10 +966,10 @@ fn test_transfer() {
     let token_id: TokenId = 1;
 
     // Create client
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
     // Initialize contract
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
 
     // Mint a token to original_owner
     env.mock_all_auths();
@@ -1197,28 +977,25 @@ fn test_transfer() {
     let description = String::from_str(&env, "Test Description");
     let uri = String::from_str(&env, "ipfs://test");
 
-    client
-        .mint(
-            admin.clone(), // admin can mint
+    client.mint(
+        &admin,
+        &// admin can mint
             original_owner.clone(),
-            token_id,
-            name,
-            description,
-            uri,
-        )
-        .unwrap();
+        &token_id,
+        &name,
+        &description,
+        &uri,
+    );
 
     // Verify initial ownership
-    let owner = client.get_owner(token_id).unwrap();
+    let owner = client.get_owner(&token_id);
     assert_eq!(owner, original_owner);
 
     // Transfer the token
-    client
-        .transfer(original_owner.clone(), new_owner.clone(), token_id)
-        .unwrap();
+    client.transfer(&original_owner, &new_owner, &token_id);
 
     // Verify new ownership
-    let new_owner_result = client.get_owner(token_id).unwrap();
+    let new_owner_result = client.get_owner(&token_id);
     assert_eq!(new_owner_result, new_owner);
 }
 
@@ -1230,17 +1007,17 @@ fn test_minter_role() {
     let token_id: TokenId = 1;
 
     // Create client
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
     // Initialize contract
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
 
     // Add minter
     env.mock_all_auths();
-    client.add_minter(admin.clone(), minter.clone()).unwrap();
+    client.add_minter(&admin, &minter);
 
     // Verify minter was added
-    let is_minter = client.is_minter(minter.clone()).unwrap();
+    let is_minter = client.is_minter(&minter);
     assert!(is_minter);
 
     // Test minting with minter
@@ -1248,26 +1025,25 @@ fn test_minter_role() {
     let description = String::from_str(&env, "Minted by minter");
     let uri = String::from_str(&env, "ipfs://minter");
 
-    client
-        .mint(
-            minter.clone(), // caller is minter
+    client.mint(
+        &minter,
+        &// caller is minter
             user.clone(),
-            token_id,
-            name,
-            description,
-            uri,
-        )
-        .unwrap();
+        &token_id,
+        &name,
+        &description,
+        &uri,
+    );
 
     // Verify token was minted
-    let owner = client.get_owner(token_id).unwrap();
+    let owner = client.get_owner(&token_id);
     assert_eq!(owner, user);
 
     // Remove minter
-    client.remove_minter(admin.clone(), minter.clone()).unwrap();
+    client.remove_minter(&admin, &minter);
 
     // Verify minter was removed
-    let is_still_minter = client.is_minter(minter.clone()).unwrap();
+    let is_still_minter = client.is_minter(&minter);
     assert!(!is_still_minter);
 }
 
@@ -1277,19 +1053,17 @@ fn test_admin_transfer() {
     let new_admin = Address::generate(&env);
 
     // Create client
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
     // Initialize contract
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
 
     // Transfer admin
     env.mock_all_auths();
-    client
-        .transfer_admin(admin.clone(), new_admin.clone())
-        .unwrap();
+    client.transfer_admin(&admin, &new_admin);
 
     // Verify new admin
-    let current_admin = client.get_admin().unwrap();
+    let current_admin = client.get_admin();
     assert_eq!(current_admin, new_admin);
 }
 
@@ -1297,11 +1071,11 @@ fn test_admin_transfer() {
 fn test_mint_for_achievement() {
     let (env, admin, contract_id) = setup();
     let user = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
-    client.init(admin.clone()).unwrap();
+    let client = ContractClient::new(&env, &contract_id);
+    client.init(&admin);
     env.mock_all_auths();
     // Add admin as minter for test
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
+    client.add_minter(&admin, &admin);
     // Mint achievement NFT
     let nft_type = symbol_short!("tencontr");
     let result: Result<(), Error> = env.invoke_contract(
@@ -1316,9 +1090,9 @@ fn test_mint_for_achievement() {
     );
     assert!(result.is_ok());
     // Check that token_id 1 exists and is owned by user
-    let owner = client.get_owner(1).unwrap();
+    let owner = client.get_owner(&1);
     assert_eq!(owner, user);
-    let metadata = client.get_metadata(1).unwrap();
+    let metadata = client.get_metadata(&1);
     assert_eq!(
         metadata.name,
         String::from_str(&env, "10 Completed Contracts")
@@ -1329,20 +1103,18 @@ fn test_mint_for_achievement() {
 fn test_auto_rewards_on_reputation_update() {
     let (env, admin, contract_id) = setup();
     let user = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
     // Initialize contract
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
+    client.add_minter(&admin, &admin);
 
     // Test 1: Auto-award for 10+ excellent ratings
-    let result = client.update_reputation_score(
-        admin.clone(),
-        user.clone(),
-        400, // 4.0 average rating
-        10,  // 10 total ratings
-    );
+    let result = contract_result(client.try_update_reputation_score(
+        &admin, &user, &400, // 4.0 average rating
+        &10,  // 10 total ratings
+    ));
     assert!(result.is_ok());
 
     // Verify auto-minted achievement
@@ -1364,12 +1136,10 @@ fn test_auto_rewards_on_reputation_update() {
     });
 
     // Test 2: Auto-award for top-rated professional (480+ average, 20+ ratings)
-    let result2 = client.update_reputation_score(
-        admin.clone(),
-        user.clone(),
-        480, // 4.8 average rating
-        20,  // 20 total ratings
-    );
+    let result2 = contract_result(client.try_update_reputation_score(
+        &admin, &user, &480, // 4.8 average rating
+        &20,  // 20 total ratings
+    ));
     assert!(result2.is_ok());
 
     // Verify second auto-minted achievement
@@ -1391,12 +1161,10 @@ fn test_auto_rewards_on_reputation_update() {
     });
 
     // Test 3: Auto-award for veteran professional (450+ average, 50+ ratings)
-    let result3 = client.update_reputation_score(
-        admin.clone(),
-        user.clone(),
-        450, // 4.5 average rating
-        50,  // 50 total ratings
-    );
+    let result3 = contract_result(client.try_update_reputation_score(
+        &admin, &user, &450, // 4.5 average rating
+        &50,  // 50 total ratings
+    ));
     assert!(result3.is_ok());
 
     // Verify third auto-minted achievement
@@ -1422,29 +1190,25 @@ fn test_auto_rewards_on_reputation_update() {
 fn test_auto_rewards_no_duplicate_achievements() {
     let (env, admin, contract_id) = setup();
     let user = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
     // Initialize contract
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
+    client.add_minter(&admin, &admin);
 
     // First update - should trigger auto-mint
-    let result1 = client.update_reputation_score(
-        admin.clone(),
-        user.clone(),
-        400, // 4.0 average rating
-        10,  // 10 total ratings
-    );
+    let result1 = contract_result(client.try_update_reputation_score(
+        &admin, &user, &400, // 4.0 average rating
+        &10,  // 10 total ratings
+    ));
     assert!(result1.is_ok());
 
     // Second update with same criteria - should NOT trigger duplicate
-    let result2 = client.update_reputation_score(
-        admin.clone(),
-        user.clone(),
-        400, // Same average rating
-        10,  // Same total ratings
-    );
+    let result2 = contract_result(client.try_update_reputation_score(
+        &admin, &user, &400, // Same average rating
+        &10,  // Same total ratings
+    ));
     assert!(result2.is_ok());
 
     // Verify only one achievement was minted
@@ -1459,30 +1223,25 @@ fn test_auto_rewards_no_duplicate_achievements() {
     });
 }
 
+#[cfg(feature = "leaderboard")]
 #[test]
 fn test_auto_rewards_leaderboard_update() {
     let (env, admin, contract_id) = setup();
     let user1 = Address::generate(&env);
     let user2 = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
     // Initialize contract
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
+    client.add_minter(&admin, &admin);
 
     // User 1 gets auto-rewards
-    client
-        .update_reputation_score(admin.clone(), user1.clone(), 400, 10)
-        .unwrap();
-    client
-        .update_reputation_score(admin.clone(), user1.clone(), 480, 20)
-        .unwrap();
+    client.update_reputation_score(&admin, &user1, &400, &10);
+    client.update_reputation_score(&admin, &user1, &480, &20);
 
     // User 2 gets auto-rewards
-    client
-        .update_reputation_score(admin.clone(), user2.clone(), 400, 10)
-        .unwrap();
+    client.update_reputation_score(&admin, &user2, &400, &10);
 
     // Verify leaderboard reflects achievements
     env.as_contract(&contract_id, || {
@@ -1507,24 +1266,21 @@ fn test_auto_rewards_leaderboard_update() {
     });
 }
 
+#[cfg(feature = "statistics")]
 #[test]
 fn test_auto_rewards_achievement_statistics() {
     let (env, admin, contract_id) = setup();
     let user = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
     // Initialize contract
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
+    client.add_minter(&admin, &admin);
 
     // Trigger auto-rewards
-    client
-        .update_reputation_score(admin.clone(), user.clone(), 400, 10)
-        .unwrap();
-    client
-        .update_reputation_score(admin.clone(), user.clone(), 480, 20)
-        .unwrap();
+    client.update_reputation_score(&admin, &user, &400, &10);
+    client.update_reputation_score(&admin, &user, &480, &20);
 
     // Verify achievement statistics
     env.as_contract(&contract_id, || {
@@ -1548,20 +1304,18 @@ fn test_auto_rewards_achievement_statistics() {
 fn test_auto_rewards_insufficient_criteria() {
     let (env, admin, contract_id) = setup();
     let user = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
     // Initialize contract
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
+    client.add_minter(&admin, &admin);
 
     // Test insufficient criteria - should not trigger auto-mint
-    let result = client.update_reputation_score(
-        admin.clone(),
-        user.clone(),
-        300, // 3.0 average rating (too low)
-        5,   // 5 total ratings (too few)
-    );
+    let result = contract_result(client.try_update_reputation_score(
+        &admin, &user, &300, // 3.0 average rating (too low)
+        &5,   // 5 total ratings (too few)
+    ));
     assert!(result.is_ok());
 
     // Verify no achievements were minted
@@ -1580,17 +1334,15 @@ fn test_auto_rewards_insufficient_criteria() {
 fn test_auto_rewards_metadata_consistency() {
     let (env, admin, contract_id) = setup();
     let user = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
     // Initialize contract
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
+    client.add_minter(&admin, &admin);
 
     // Trigger auto-rewards
-    client
-        .update_reputation_score(admin.clone(), user.clone(), 400, 10)
-        .unwrap();
+    client.update_reputation_score(&admin, &user, &400, &10);
 
     // Verify metadata consistency
     env.as_contract(&contract_id, || {
@@ -1613,30 +1365,30 @@ fn test_auto_rewards_metadata_consistency() {
 #[test]
 fn test_pause_unpause() {
     let (env, admin, contract_id) = setup();
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
     // Test pause
-    let _ = client.pause(admin.clone());
-    assert_eq!(client.is_paused().unwrap(), true);
+    client.pause(&admin);
+    assert!(client.is_paused());
 
     // Test unpause
-    let _ = client.unpause(admin.clone());
-    assert_eq!(client.is_paused().unwrap(), false);
+    client.unpause(&admin);
+    assert!(!client.is_paused());
 }
 
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #1)")]
 fn test_pause_unpause_unauthorized() {
     let (env, admin, contract_id) = setup();
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
     let unauthorized = Address::generate(&env);
 
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
     // Test pause
-    let _ = client.pause(unauthorized.clone());
+    client.pause(&unauthorized);
 }
 
 #[test]
@@ -1644,20 +1396,1146 @@ fn test_pause_unpause_unauthorized() {
 fn test_transfer_panic() {
     let (env, admin, contract_id) = setup();
     let user1 = Address::generate(&env);
-    let client = ContractClient::new(env.clone(), contract_id.clone());
+    let client = ContractClient::new(&env, &contract_id);
 
-    client.init(admin.clone()).unwrap();
+    client.init(&admin);
     env.mock_all_auths();
-    client.add_minter(admin.clone(), admin.clone()).unwrap();
-    let _pause_result = client.pause(admin.clone());
+    client.add_minter(&admin, &admin);
+    client.pause(&admin);
 
     // Mint an NFT to user1
-    let _result = client.mint(
-        admin.clone(),
-        user1.clone(),
-        1,
-        String::from_str(&env, "Test NFT"),
-        String::from_str(&env, "Test Description"),
-        String::from_str(&env, "ipfs://test"),
+    client.mint(
+        &admin,
+        &user1,
+        &1,
+        &String::from_str(&env, "Test NFT"),
+        &String::from_str(&env, "Test Description"),
+        &String::from_str(&env, "ipfs://test"),
+    );
+}
+#[test]
+fn test_reputation_proof_export_and_verify() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    client.init(&admin);
+    client.update_reputation_score(&admin, &user, &400, &10);
+
+    let proof = client.export_reputation_proof(&user);
+    assert_eq!(proof.score, 400);
+    assert_eq!(proof.total_ratings, 10);
+    assert_eq!(
+        proof
+            .achievement_counts
+            .get(AchievementType::RatingMilestone),
+        Some(1)
+    );
+    assert!(client.verify_reputation_proof(&proof));
+
+    // A second export chains to the first one
+    let next = client.export_reputation_proof(&user);
+    assert_eq!(next.prev_hash, proof.hash);
+    assert!(client.verify_reputation_proof(&next));
+
+    // Tampered payloads are rejected
+    let mut forged = proof.clone();
+    forged.score = 500;
+    assert!(!client.verify_reputation_proof(&forged));
+}
+
+#[test]
+fn test_get_config() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let minter = Address::generate(&env);
+
+    client.init(&admin);
+    client.add_minter(&admin, &minter);
+
+    let config = client.get_config();
+    assert_eq!(config.admin, admin);
+    assert!(!config.paused);
+    assert_eq!(config.minters, vec![&env, minter.clone()]);
+    assert_eq!(config.excellence_threshold.min_ratings, 10);
+    assert_eq!(config.excellence_threshold.min_average, 400);
+    assert_eq!(config.council, None);
+    assert_eq!(config.rating_contract, None);
+    assert_eq!(config.base_uri, String::from_str(&env, "ipfs://"));
+    assert_eq!(config.version, crate::types::CONTRACT_VERSION);
+
+    let council = Address::generate(&env);
+    let rating = Address::generate(&env);
+    let base_uri = String::from_str(&env, "https://badges.offerhub.io/");
+    client.set_param_address(&admin, &crate::config::PARAM_COUNCIL, &council);
+    client.set_param_address(&admin, &crate::config::PARAM_RATING_CONTRACT, &rating);
+    client.set_base_uri(&admin, &base_uri);
+    assert_eq!(
+        client.try_set_base_uri(&admin, &String::from_str(&env, "")),
+        Err(Ok(Error::InvalidUri))
+    );
+
+    let config = client.get_config();
+    assert_eq!(config.council, Some(council));
+    assert_eq!(config.rating_contract, Some(rating));
+    assert_eq!(config.base_uri, base_uri);
+
+    // Templates resolve under the new base
+    client.mint_achv(&admin, &minter, &symbol_short!("toprated"));
+    let token_id = client.get_user_achievements(&minter).get(0).unwrap();
+    assert_eq!(
+        client.get_metadata(&token_id).uri,
+        String::from_str(&env, "https://badges.offerhub.io/top-rated-freelancer")
+    );
+
+    client.pause(&admin);
+    assert!(client.get_config().paused);
+}
+
+#[test]
+fn test_config_params_drive_rating_thresholds() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+    let non_admin = Address::generate(&env);
+
+    client.init(&admin);
+
+    let key = crate::config::PARAM_EXCELLENCE_MIN_RATINGS;
+    assert_eq!(client.get_param_u32(&key), None);
+
+    // Only the admin can change parameters
+    let result = client.try_set_param_u32(&non_admin, &key, &5);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.set_param_u32(&admin, &key, &5);
+    assert_eq!(client.get_param_u32(&key), Some(5));
+    let config = client.get_config();
+    assert_eq!(config.excellence_threshold.min_ratings, 5);
+
+    // Five ratings now qualify for the excellence milestone
+    client.update_reputation_score(&admin, &user, &400, &5);
+    let achievements = client.get_user_achievements(&user);
+    assert_eq!(achievements.len(), 1);
+
+    let label = symbol_short!("label");
+    let value = symbol_short!("beta");
+    client.set_param_symbol(&admin, &label, &value);
+    assert_eq!(client.get_param_symbol(&label), Some(value));
+
+    let partner = symbol_short!("partner");
+    client.set_param_address(&admin, &partner, &user);
+    assert_eq!(client.get_param_address(&partner), Some(user.clone()));
+}
+
+#[test]
+fn test_config_params_drive_cooldowns_and_quotas() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let dapp = Address::generate(&env);
+    let user = Address::generate(&env);
+    let template = symbol_short!("partner");
+
+    client.init(&admin);
+    client.register_partner_template(&admin, &template, &partner_template(&env));
+    client.set_mint_allowance(&admin, &dapp, &template, &3);
+    client.set_param_u32(&admin, &crate::config::PARAM_PARTNER_COOLDOWN, &60);
+
+    // A partner waits out its cooldown between mints
+    client.mint_with_allowance(&dapp, &user, &template);
+    assert_eq!(
+        client.try_mint_with_allowance(&dapp, &user, &template),
+        Err(Ok(Error::CooldownActive))
+    );
+    env.ledger().with_mut(|l| l.timestamp += 60);
+    client.mint_with_allowance(&dapp, &user, &template);
+
+    // The per-user achievement quota is a parameter too
+    client.set_param_u32(&admin, &crate::config::PARAM_MAX_ACHIEVEMENTS, &2);
+    env.ledger().with_mut(|l| l.timestamp += 60);
+    assert_eq!(
+        client.try_mint_with_allowance(&dapp, &user, &template),
+        Err(Ok(Error::SupplyExhausted))
+    );
+}
+
+#[test]
+fn test_verify_uri_detects_drift() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    client.init(&admin);
+    client.mint(
+        &admin,
+        &user,
+        &1,
+        &String::from_str(&env, "Pinned"),
+        &String::from_str(&env, "Pinned badge"),
+        &String::from_str(&env, "ipfs://pinned-cid"),
+    );
+
+    // Nothing is committed until the pinning service supplies the content hash
+    let digest = BytesN::from_array(&env, &[7; 32]);
+    assert!(!client.verify_uri(&1, &digest));
+
+    client.commit_content(&admin, &1, &digest);
+    assert!(client.verify_uri(&1, &digest));
+    assert!(!client.verify_uri(&1, &BytesN::from_array(&env, &[8; 32])));
+
+    // The commitment can't be replaced afterwards
+    assert_eq!(
+        client.try_commit_content(&admin, &1, &BytesN::from_array(&env, &[8; 32])),
+        Err(Ok(Error::ContentAlreadyCommitted))
+    );
+    assert!(client.verify_uri(&1, &digest));
+
+    // Only minters may commit content
+    assert_eq!(
+        client.try_commit_content(&user, &1, &digest),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Repointing the URI breaks the commitment
+    client.update_metadata_dynamically(
+        &admin,
+        &1,
+        &None,
+        &None,
+        &Some(String::from_str(&env, "ipfs://replaced-cid")),
+    );
+    assert!(!client.verify_uri(&1, &digest));
+
+    assert_eq!(
+        client.try_verify_uri(&999, &digest),
+        Err(Ok(Error::TokenDoesNotExist))
+    );
+}
+
+#[cfg(feature = "leaderboard")]
+#[test]
+fn test_perform_maintenance_prunes_and_pays_keeper() {
+    use fee_manager_contract::{FeeManagerContract, FeeManagerContractClient};
+
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+    let keeper = Address::generate(&env);
+
+    let fee_manager_id = env.register(FeeManagerContract, ());
+    let fee_manager = FeeManagerContractClient::new(&env, &fee_manager_id);
+    fee_manager.initialize(&admin, &Address::generate(&env));
+    fee_manager.collect_fee(&admin, &1000000i128, &1, &user);
+    fee_manager.add_reward_source(&contract_id);
+
+    let token = create_token(&env, &admin);
+    token.mint(&admin, 1000);
+    fee_manager.fund_keeper_budget(&admin, &token.address, &1000, &100);
+
+    client.init(&admin);
+    for id in 1..=3u64 {
+        client.mint(
+            &admin,
+            &user,
+            &id,
+            &String::from_str(&env, "Badge"),
+            &String::from_str(&env, "Badge"),
+            &String::from_str(&env, "ipfs://badge"),
+        );
+    }
+    let other = Address::generate(&env);
+    client.transfer(&user, &other, &1);
+    client.transfer(&other, &user, &1);
+
+    client.set_param_address(&admin, &crate::config::PARAM_FEE_MANAGER, &fee_manager_id);
+    client.set_param_u32(&admin, &crate::config::PARAM_KEEPER_REWARD, &100);
+    client.set_param_address(&admin, &crate::config::PARAM_KEEPER_ASSET, &token.address);
+
+    // Out-of-range batch sizes are rejected
+    assert_eq!(
+        client.try_perform_maintenance(&keeper, &0),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    let report = client.perform_maintenance(&keeper, &2);
+    assert_eq!(report.pruned, 1);
+    assert_eq!(report.processed, 1);
+    assert_eq!(report.reward, 100);
+    assert!(!client.leader().contains_key(other));
+
+    // The next batch resumes from the cursor; bumping TTLs alone earns nothing
+    let report = client.perform_maintenance(&keeper, &5);
+    assert_eq!(report.pruned, 0);
+    assert_eq!(report.processed, 2);
+    assert_eq!(report.expired, 0);
+    assert_eq!(report.reward, 0);
+
+    // Rewards are paid out of the keeper budget held by the fee manager
+    assert_eq!(fee_manager.get_keeper_rewards(&keeper, &token.address), 100);
+    let budget = fee_manager.get_keeper_budget(&token.address).unwrap();
+    assert_eq!(budget.remaining, 900);
+    assert_eq!(
+        fee_manager.claim_keeper_rewards(&keeper, &token.address),
+        100
+    );
+    assert_eq!(token.client.balance(&keeper), 100);
+    assert_eq!(fee_manager.get_keeper_rewards(&keeper, &token.address), 0);
+}
+
+#[test]
+fn test_perform_maintenance_expires_soulbound_achievements() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+    let keeper = Address::generate(&env);
+
+    client.init(&admin);
+    client.set_param_u32(&admin, &crate::config::PARAM_ACHIEVEMENT_LIFETIME, &100);
+    client.mint(
+        &admin,
+        &user,
+        &1,
+        &String::from_str(&env, "Badge"),
+        &String::from_str(&env, "Badge"),
+        &String::from_str(&env, "ipfs://badge"),
+    );
+    client.mint_achv(&admin, &user, &symbol_short!("tencontr"));
+    assert_eq!(client.get_user_achievements(&user).len(), 2);
+
+    // Nothing has expired yet
+    let report = client.perform_maintenance(&keeper, &10);
+    assert_eq!(report.expired, 0);
+
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    let report = client.perform_maintenance(&keeper, &10);
+    assert_eq!(report.processed, 2);
+    assert_eq!(report.expired, 1);
+
+    // Transferable tokens outlive the achievement lifetime
+    let remaining = client.get_user_achievements(&user);
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining.get(0), Some(1));
+}
+
+#[cfg(feature = "leaderboard")]
+#[test]
+fn test_leaderboard_root_commit_and_verify() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+
+    client.init(&admin);
+    for (id, user) in [(1u64, &user1), (2, &user1), (3, &user2)] {
+        client.mint(
+            &admin,
+            user,
+            &id,
+            &String::from_str(&env, "Badge"),
+            &String::from_str(&env, "Badge"),
+            &String::from_str(&env, "ipfs://badge"),
+        );
+    }
+
+    let root = client.commit_leaderboard_root(&admin, &1);
+    assert_eq!(client.get_leaderboard_root(&1), Some(root.clone()));
+
+    // Epochs cannot be overwritten
+    assert_eq!(
+        client.try_commit_leaderboard_root(&admin, &1),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    let leaf1 = crate::merkle::leaf_hash(&env, &user1, 2);
+    let leaf2 = crate::merkle::leaf_hash(&env, &user2, 1);
+    assert_eq!(crate::merkle::hash_pair(&env, &leaf1, &leaf2), root);
+
+    assert!(client.verify_leaderboard_entry(&1, &user1, &2, &vec![&env, leaf2.clone()]));
+    assert!(!client.verify_leaderboard_entry(&1, &user1, &3, &vec![&env, leaf2.clone()]));
+    assert!(!client.verify_leaderboard_entry(&2, &user1, &2, &vec![&env, leaf2]));
+}
+
+#[test]
+fn test_verify_ownership_and_owns_token() {
+    let (env, admin, contract_id) = setup();
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+    env.mock_all_auths();
+
+    env.as_contract(&contract_id, || {
+        ReputationNFTContract::init(env.clone(), admin.clone()).unwrap();
+        ReputationNFTContract::mint_achv(
+            env.clone(),
+            admin.clone(),
+            user.clone(),
+            symbol_short!("toprated"),
+        )
+        .unwrap();
+
+        assert!(ReputationNFTContract::verify_ownership(
+            env.clone(),
+            user.clone(),
+            AchievementType::Reputation
+        ));
+        assert!(!ReputationNFTContract::verify_ownership(
+            env.clone(),
+            user.clone(),
+            AchievementType::Standard
+        ));
+        assert!(!ReputationNFTContract::verify_ownership(
+            env.clone(),
+            other.clone(),
+            AchievementType::Reputation
+        ));
+
+        assert!(ReputationNFTContract::owns_token(
+            env.clone(),
+            user.clone(),
+            1
+        ));
+        assert!(!ReputationNFTContract::owns_token(
+            env.clone(),
+            other.clone(),
+            1
+        ));
+        // Missing tokens report false instead of erroring
+        assert!(!ReputationNFTContract::owns_token(
+            env.clone(),
+            user.clone(),
+            999
+        ));
+    });
+}
+
+#[test]
+fn test_activity_counters() {
+    let (env, admin, contract_id) = setup();
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+
+    client.init(&admin);
+    let counters = client.get_activity_counters();
+    assert_eq!(counters, crate::ActivityCounters::default());
+
+    client.mint(
+        &admin,
+        &user1,
+        &1,
+        &String::from_str(&env, "Badge"),
+        &String::from_str(&env, "Badge"),
+        &String::from_str(&env, "ipfs://badge"),
     );
-}
\ No newline at end of file
+    client.mint_achv(&admin, &user1, &symbol_short!("newbie"));
+    client.transfer(&user1, &user2, &1);
+    client.burn(&admin, &2);
+
+    let counters = client.get_activity_counters();
+    assert_eq!(counters.mints, 2);
+    assert_eq!(counters.transfers, 1);
+    assert_eq!(counters.burns, 1);
+}
+
+#[test]
+fn test_debug_mode_diagnostic_events() {
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::{Symbol, Val};
+
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    let diag_count = |env: &Env| {
+        let diag: Val = Symbol::new(env, "DIAG").into_val(env);
+        let threshold: Val = symbol_short!("threshold").into_val(env);
+        let expected = vec![env, diag, threshold];
+        env.events()
+            .all()
+            .iter()
+            .filter(|(_, topics, _)| *topics == expected)
+            .count()
+    };
+
+    client.init(&admin);
+    assert!(!client.is_debug_mode());
+    client.update_reputation_score(&admin, &user, &300, &5);
+    assert_eq!(diag_count(&env), 0);
+
+    client.set_debug_mode(&admin, &true);
+    assert!(client.is_debug_mode());
+    client.update_reputation_score(&admin, &user, &300, &6);
+    assert_eq!(diag_count(&env), 1);
+
+    let non_admin = Address::generate(&env);
+    assert_eq!(
+        client.try_set_debug_mode(&non_admin, &false),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_simulate_mint_does_not_write_state() {
+    let (env, admin, contract_id) = setup();
+    let user = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    env.mock_all_auths();
+
+    env.as_contract(&contract_id, || {
+        ReputationNFTContract::init(env.clone(), admin.clone()).unwrap();
+
+        let simulated = ReputationNFTContract::simulate_mint(
+            env.clone(),
+            admin.clone(),
+            user.clone(),
+            symbol_short!("toprated"),
+        );
+        assert_eq!(simulated, Ok(1));
+        // Nothing was minted and the counter did not move
+        assert!(!crate::storage::token_exists(&env, &1));
+        assert_eq!(
+            ReputationNFTContract::simulate_mint(
+                env.clone(),
+                admin.clone(),
+                user.clone(),
+                symbol_short!("toprated"),
+            ),
+            Ok(1)
+        );
+
+        assert_eq!(
+            ReputationNFTContract::simulate_mint(
+                env.clone(),
+                stranger.clone(),
+                user.clone(),
+                symbol_short!("toprated"),
+            ),
+            Err(Error::Unauthorized)
+        );
+
+        ReputationNFTContract::set_achievement_prerequisite(
+            env.clone(),
+            admin.clone(),
+            AchievementType::Reputation,
+            AchievementType::ProjectMilestone,
+        )
+        .unwrap();
+        assert_eq!(
+            ReputationNFTContract::simulate_mint(
+                env.clone(),
+                admin.clone(),
+                user.clone(),
+                symbol_short!("toprated"),
+            ),
+            Err(Error::AchievementPrerequisiteNotMet)
+        );
+    });
+}
+
+#[test]
+fn test_granular_errors() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    assert_eq!(client.try_get_admin(), Err(Ok(Error::NotInitialized)));
+
+    client.init(&admin);
+
+    let empty_uri = client.try_mint(
+        &admin,
+        &user,
+        &1,
+        &String::from_str(&env, "Name"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, ""),
+    );
+    assert_eq!(empty_uri, Err(Ok(Error::InvalidUri)));
+
+    let mismatched = client.try_batch_m(
+        &admin,
+        &vec![&env, user.clone(), user.clone()],
+        &vec![&env, String::from_str(&env, "Name")],
+        &vec![&env, String::from_str(&env, "Description")],
+        &vec![&env, String::from_str(&env, "ipfs://uri")],
+    );
+    assert_eq!(mismatched, Err(Ok(Error::LengthMismatch)));
+
+    let digest = BytesN::from_array(&env, &[0; 32]);
+    assert_eq!(
+        client.try_verify_uri(&99, &digest),
+        Err(Ok(Error::TokenDoesNotExist))
+    );
+    assert_eq!(
+        client.try_set_achievement_prerequisite(
+            &user,
+            &AchievementType::Reputation,
+            &AchievementType::Standard
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+fn partner_template(env: &Env) -> PartnerTemplate {
+    PartnerTemplate {
+        name: String::from_str(env, "Partner Champion"),
+        description: String::from_str(env, "Co-branded badge from a partner dApp."),
+        uri: String::from_str(env, "ipfs://partner-champion"),
+    }
+}
+
+#[test]
+fn test_mint_allowance_for_partner_dapp() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let dapp = Address::generate(&env);
+    let user = Address::generate(&env);
+    let template = symbol_short!("partner");
+
+    client.init(&admin);
+
+    // Unregistered templates can neither be granted nor minted
+    assert_eq!(
+        client.try_set_mint_allowance(&admin, &dapp, &template, &2),
+        Err(Ok(Error::UnknownTemplate))
+    );
+    assert_eq!(
+        client.try_mint_with_allowance(&dapp, &user, &template),
+        Err(Ok(Error::UnknownTemplate))
+    );
+    client.register_partner_template(&admin, &template, &partner_template(&env));
+
+    // No allowance yet
+    assert_eq!(
+        client.try_mint_with_allowance(&dapp, &user, &template),
+        Err(Ok(Error::SupplyExhausted))
+    );
+
+    // Only the admin can grant allowances
+    assert_eq!(
+        client.try_set_mint_allowance(&dapp, &dapp, &template, &2),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.set_mint_allowance(&admin, &dapp, &template, &2);
+    assert_eq!(client.get_mint_allowance(&dapp, &template), 2);
+
+    // The badge renders the partner's template
+    let token_id = client.mint_with_allowance(&dapp, &user, &template);
+    assert_eq!(client.get_owner(&token_id), user);
+    let metadata = client.get_metadata(&token_id);
+    assert_eq!(metadata.name, partner_template(&env).name);
+    assert_eq!(metadata.uri, partner_template(&env).uri);
+    assert_eq!(client.get_mint_allowance(&dapp, &template), 1);
+
+    // Allowances are scoped to a single template
+    assert_eq!(client.get_mint_allowance(&dapp, &symbol_short!("other")), 0);
+
+    client.mint_with_allowance(&dapp, &user, &template);
+    assert_eq!(
+        client.try_mint_with_allowance(&dapp, &user, &template),
+        Err(Ok(Error::SupplyExhausted))
+    );
+}
+
+#[test]
+fn test_marketplace_listing_and_buy_with_royalty() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let royalty_receiver = Address::generate(&env);
+
+    let token = create_token(&env, &admin);
+    token.mint(&buyer, 1_000);
+    let asset = token.address.clone();
+
+    client.init(&admin);
+    client.mint(
+        &admin,
+        &seller,
+        &1,
+        &String::from_str(&env, "Badge"),
+        &String::from_str(&env, "Collectible badge"),
+        &String::from_str(&env, "ipfs://badge"),
+    );
+    // 5% royalty
+    client.set_param_u32(&admin, &symbol_short!("roy_bps"), &500);
+    client.set_param_address(&admin, &symbol_short!("roy_rcv"), &royalty_receiver);
+
+    assert_eq!(client.try_buy(&buyer, &1), Err(Ok(Error::NotListed)));
+    assert_eq!(
+        client.try_list_for_sale(&buyer, &1, &400, &asset),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_list_for_sale(&seller, &1, &0, &asset),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    client.list_for_sale(&seller, &1, &400, &asset);
+    client.delist(&seller, &1);
+    assert_eq!(client.get_listing(&1), None);
+
+    client.list_for_sale(&seller, &1, &400, &asset);
+    client.buy(&buyer, &1);
+
+    assert_eq!(client.get_owner(&1), buyer);
+    assert_eq!(client.get_listing(&1), None);
+
+    assert_eq!(token.balance(&buyer), 600);
+    assert_eq!(token.balance(&seller), 380);
+    assert_eq!(token.balance(&royalty_receiver), 20);
+}
+
+#[test]
+fn test_migrate_tracks_storage_version() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let stranger = Address::generate(&env);
+
+    client.init(&admin);
+    assert_eq!(client.version(), 1);
+    assert_eq!(client.try_migrate(&admin), Err(Ok(Error::AlreadyMigrated)));
+
+    // Simulate a deployment from before version tracking
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .remove(&offerhub_upgradeable::VERSION)
+    });
+    assert_eq!(client.version(), 0);
+    assert_eq!(client.try_migrate(&stranger), Err(Ok(Error::Unauthorized)));
+    client.migrate(&admin);
+    assert_eq!(client.version(), 1);
+}
+
+#[test]
+fn test_market_surface_pause_blocks_listing_only() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let seller = Address::generate(&env);
+    let emergency = Address::generate(&env);
+
+    let asset = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let market = symbol_short!("market");
+
+    client.init(&admin);
+    client.add_pauser(&admin, &emergency);
+    client.pause_surface(&emergency, &market);
+
+    // Minting is unaffected by a market pause
+    client.mint(
+        &admin,
+        &seller,
+        &1,
+        &String::from_str(&env, "Badge"),
+        &String::from_str(&env, "Collectible badge"),
+        &String::from_str(&env, "ipfs://badge"),
+    );
+    assert_eq!(
+        client.try_list_for_sale(&seller, &1, &400, &asset),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    client.unpause_surface(&admin, &market);
+    assert!(!client.is_surface_paused(&market));
+    client.list_for_sale(&seller, &1, &400, &asset);
+}
+
+#[test]
+fn test_buy_rejected_while_settlement_in_progress() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let token = create_token(&env, &admin);
+    token.mint(&buyer, 1_000);
+    let asset = token.address;
+
+    client.init(&admin);
+    client.mint(
+        &admin,
+        &seller,
+        &1,
+        &String::from_str(&env, "Badge"),
+        &String::from_str(&env, "Collectible badge"),
+        &String::from_str(&env, "ipfs://badge"),
+    );
+    client.list_for_sale(&seller, &1, &400, &asset);
+
+    // Simulate a buy re-entering through a token hook mid-settlement
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .set(&offerhub_reentrancy::LOCKED, &true)
+    });
+    assert_eq!(client.try_buy(&buyer, &1), Err(Ok(Error::ReentrantCall)));
+
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .remove(&offerhub_reentrancy::LOCKED)
+    });
+    client.buy(&buyer, &1);
+    assert!(!env.as_contract(&contract_id, || offerhub_reentrancy::is_locked(&env)));
+    assert_eq!(client.get_owner(&1), buyer);
+}
+
+#[test]
+fn test_royalty_overflow_is_rejected() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let asset = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+
+    client.init(&admin);
+    client.mint(
+        &admin,
+        &seller,
+        &1,
+        &String::from_str(&env, "Badge"),
+        &String::from_str(&env, "Collectible badge"),
+        &String::from_str(&env, "ipfs://badge"),
+    );
+    client.set_param_u32(&admin, &symbol_short!("roy_bps"), &500);
+    client.set_param_address(&admin, &symbol_short!("roy_rcv"), &admin);
+    client.list_for_sale(&seller, &1, &i128::MAX, &asset);
+
+    assert_eq!(
+        client.try_buy(&buyer, &1),
+        Err(Ok(Error::ArithmeticOverflow))
+    );
+    assert_eq!(client.get_owner(&1), seller);
+}
+
+#[test]
+fn test_non_transferable_badges_cannot_be_listed() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    client.init(&admin);
+    client.mint_achv(&admin, &user, &symbol_short!("tencontr"));
+    let token_id = client.get_user_achievements(&user).get(0).unwrap();
+
+    assert_eq!(
+        client.try_list_for_sale(&user, &token_id, &100, &asset),
+        Err(Ok(Error::NonTransferableToken))
+    );
+}
+
+#[cfg(not(feature = "leaderboard"))]
+#[test]
+fn test_maintenance_without_leaderboard_only_extends_ttls() {
+    let (env, admin, contract_id) = setup();
+    let user = Address::generate(&env);
+    let keeper = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        ReputationNFTContract::init(env.clone(), admin.clone()).unwrap();
+        env.mock_all_auths();
+        ReputationNFTContract::mint(
+            env.clone(),
+            admin.clone(),
+            user.clone(),
+            1,
+            String::from_str(&env, "Badge"),
+            String::from_str(&env, "Badge"),
+            String::from_str(&env, "ipfs://badge"),
+        )
+        .unwrap();
+
+        let report =
+            ReputationNFTContract::perform_maintenance(env.clone(), keeper.clone(), 10).unwrap();
+        assert_eq!(report.pruned, 0);
+        assert_eq!(report.processed, 1);
+    });
+}
+
+#[cfg(not(feature = "statistics"))]
+#[test]
+fn test_mint_and_burn_without_statistics() {
+    let (env, admin, contract_id) = setup();
+    let user = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        ReputationNFTContract::init(env.clone(), admin.clone()).unwrap();
+        env.mock_all_auths();
+        ReputationNFTContract::mint_achv(
+            env.clone(),
+            admin.clone(),
+            user.clone(),
+            symbol_short!("custom"),
+        )
+        .unwrap();
+        let token_id = ReputationNFTContract::get_user_achievements(env.clone(), user.clone())
+            .unwrap()
+            .get(0)
+            .unwrap();
+        ReputationNFTContract::burn(env.clone(), admin.clone(), token_id).unwrap();
+        assert!(!storage::token_exists(&env, &token_id));
+    });
+}
+
+#[test]
+fn test_record_contract_completion_awards_badge() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let escrow = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+
+    client.init(&admin);
+
+    // Only registered minters (escrow contracts) may record completions
+    assert_eq!(
+        client.try_record_contract_completion(&escrow, &freelancer),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.add_minter(&admin, &escrow);
+
+    for expected in 1..crate::types::COMPLETION_BADGE_THRESHOLD {
+        let completed = client.record_contract_completion(&escrow, &freelancer);
+        assert_eq!(completed, expected);
+    }
+    assert_eq!(
+        env.as_contract(&contract_id, || storage::get_user_achievements(
+            &env,
+            &freelancer
+        ))
+        .len(),
+        0
+    );
+
+    client.record_contract_completion(&escrow, &freelancer);
+    assert_eq!(
+        env.as_contract(&contract_id, || storage::get_user_achievements(
+            &env,
+            &freelancer
+        ))
+        .len(),
+        1
+    );
+
+    // The badge is awarded only once
+    client.record_contract_completion(&escrow, &freelancer);
+    assert_eq!(
+        env.as_contract(&contract_id, || storage::get_user_achievements(
+            &env,
+            &freelancer
+        ))
+        .len(),
+        1
+    );
+    assert_eq!(
+        client.get_completed_contracts(&freelancer),
+        crate::types::COMPLETION_BADGE_THRESHOLD + 1
+    );
+}
+
+#[test]
+fn test_update_skill_score() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let rating_contract = Address::generate(&env);
+    let user = Address::generate(&env);
+    let quality = Symbol::new(&env, "quality");
+
+    client.init(&admin);
+
+    assert_eq!(
+        client.try_update_skill_score(&rating_contract, &user, &quality, &450, &2),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.add_minter(&admin, &rating_contract);
+
+    client.update_skill_score(&rating_contract, &user, &quality, &450, &2);
+    assert_eq!(client.get_skill_score(&user, &quality), 450);
+    assert_eq!(
+        client.get_skill_score(&user, &Symbol::new(&env, "timeliness")),
+        0
+    );
+    assert_eq!(client.get_skill_scores(&user).len(), 1);
+}
+
+#[test]
+fn test_apply_reputation_penalty() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let dispute_contract = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    client.init(&admin);
+
+    assert_eq!(
+        client.try_apply_reputation_penalty(&dispute_contract, &user, &50),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.add_minter(&admin, &dispute_contract);
+
+    env.as_contract(&contract_id, || {
+        storage::store_reputation_score(&env, &user, 420, 12)
+    });
+    let score = client.apply_reputation_penalty(&dispute_contract, &user, &50);
+    assert_eq!(score, 370);
+
+    // Scores bottom out at zero and keep the rating count
+    client.apply_reputation_penalty(&dispute_contract, &user, &1_000);
+    assert_eq!(
+        env.as_contract(&contract_id, || storage::get_reputation_score(&env, &user))
+            .map(|(score, total, _)| (score, total)),
+        Some((0, 12))
+    );
+    assert_eq!(client.get_user_reputation_score(&user), 0);
+}
+
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn set_wallets(env: Env, wallets: soroban_sdk::Vec<Address>) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("wallets"), &wallets);
+    }
+
+    pub fn get_linked_wallets(env: Env, _address: Address) -> soroban_sdk::Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("wallets"))
+            .unwrap()
+    }
+}
+
+#[test]
+fn test_identity_reputation_merges_linked_wallets() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let primary = Address::generate(&env);
+    let secondary = Address::generate(&env);
+
+    let registry_id = env.register(MockRegistry, ());
+    MockRegistryClient::new(&env, &registry_id).set_wallets(&vec![
+        &env,
+        primary.clone(),
+        secondary.clone(),
+    ]);
+
+    client.init(&admin);
+
+    env.as_contract(&contract_id, || {
+        storage::store_reputation_score(&env, &primary, 400, 10)
+    });
+    env.as_contract(&contract_id, || {
+        storage::store_reputation_score(&env, &secondary, 480, 30)
+    });
+
+    // Without a registry only the wallet itself counts
+    assert_eq!(client.get_identity_reputation_score(&primary), 400);
+
+    client.set_param_address(&admin, &crate::config::PARAM_USER_REGISTRY, &registry_id);
+
+    // (400 * 10 + 480 * 30) / 40
+    assert_eq!(client.get_identity_reputation_score(&primary), 460);
+    assert_eq!(client.get_user_reputation_score(&primary), 400);
+    assert!(client.get_identity_achievements(&secondary).is_empty());
+}
+
+#[contract]
+pub struct MockCertifications;
+
+#[contractimpl]
+impl MockCertifications {
+    pub fn set_valid(env: Env, count: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("valid"), &count);
+    }
+
+    pub fn count_valid_certificates(env: Env, _holder: Address) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("valid"))
+            .unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_sync_certifications_awards_badge_once() {
+    let (env, admin, contract_id) = setup();
+    env.mock_all_auths();
+    let client = ContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    let certifications_id = env.register(MockCertifications, ());
+    let certifications = MockCertificationsClient::new(&env, &certifications_id);
+    certifications.set_valid(&(crate::types::CERTIFICATION_BADGE_THRESHOLD - 1));
+
+    client.init(&admin);
+
+    // Nothing to count until the certification contract is configured
+    assert_eq!(client.sync_certifications(&user), 0);
+
+    client.set_param_address(
+        &admin,
+        &crate::config::PARAM_CERTIFICATIONS,
+        &certifications_id,
+    );
+    assert_eq!(
+        client.sync_certifications(&user),
+        crate::types::CERTIFICATION_BADGE_THRESHOLD - 1
+    );
+    assert_eq!(
+        env.as_contract(&contract_id, || storage::get_user_achievements(&env, &user))
+            .len(),
+        0
+    );
+
+    certifications.set_valid(&crate::types::CERTIFICATION_BADGE_THRESHOLD);
+
+    client.sync_certifications(&user);
+    client.sync_certifications(&user);
+
+    let achievements =
+        env.as_contract(&contract_id, || storage::get_user_achievements(&env, &user));
+    assert_eq!(achievements.len(), 1);
+    assert_eq!(
+        env.as_contract(&contract_id, || metadata::get_metadata(
+            &env,
+            &achievements.get(0).unwrap()
+        ))
+        .unwrap()
+        .name,
+        String::from_str(&env, "Certified Professional")
+    );
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Env, String, Symbol, symbol_short};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Symbol, Vec, symbol_short};
 use crate::error::Error;
 pub type TokenId = u64;
 
@@ -31,6 +31,101 @@ pub enum RarityLevel {
     Legendary,
 }
 
+/// Portable, hash-chained summary of a user's reputation that can be
+/// presented to partner platforms and verified on-chain.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReputationProof {
+    pub user: Address,
+    pub score: u32,
+    pub total_ratings: u32,
+    pub achievement_counts: Map<AchievementType, u32>,
+    pub ledger: u32,
+    pub prev_hash: BytesN<32>,
+    pub hash: BytesN<32>,
+}
+
+/// Minimum ratings and average (scaled by 100) required for an automatic milestone.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RatingThreshold {
+    pub min_ratings: u32,
+    pub min_average: u32,
+}
+
+/// Aggregated view of the contract configuration for admin dashboards.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractConfig {
+    pub admin: Address,
+    pub paused: bool,
+    pub minters: Vec<Address>,
+    pub excellence_threshold: RatingThreshold,
+    pub top_rated_threshold: RatingThreshold,
+    pub veteran_threshold: RatingThreshold,
+    pub fee_manager: Option<Address>,
+    pub keeper_reward: u32,
+    pub council: Option<Address>,
+    pub rating_contract: Option<Address>,
+    pub base_uri: String,
+    pub version: u32,
+}
+
+/// Work done by a single `perform_maintenance` call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceReport {
+    pub processed: u32,
+    pub pruned: u32,
+    pub expired: u32,
+    pub reward: i128,
+}
+
+/// Lifetime mint, burn and transfer totals since deployment.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ActivityCounters {
+    pub mints: u64,
+    pub burns: u64,
+    pub transfers: u64,
+}
+
+/// Open sale offer for a transferable badge.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Listing {
+    pub seller: Address,
+    pub price: i128,
+    pub asset: Address,
+}
+
+/// Content hash a minter committed for a token, bound to the URI it was served from.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UriCommitment {
+    pub uri: String,
+    pub content_hash: BytesN<32>,
+}
+
+/// Co-branded badge a partner dApp mints under its allowance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartnerTemplate {
+    pub name: String,
+    pub description: String,
+    pub uri: String,
+}
+
+pub const CONTRACT_VERSION: u32 = 1;
+
+// Automatic rating milestone thresholds (rating averages are scaled by 100)
+pub const EXCELLENCE_MIN_RATINGS: u32 = 10;
+pub const EXCELLENCE_MIN_AVERAGE: u32 = 400;
+pub const TOP_RATED_MIN_RATINGS: u32 = 20;
+pub const TOP_RATED_MIN_AVERAGE: u32 = 480;
+pub const VETERAN_MIN_RATINGS: u32 = 50;
+pub const VETERAN_MIN_AVERAGE: u32 = 450;
+
 pub const TOKEN_OWNER: &[u8] = &[0];
 pub const TOKEN_METADATA: &[u8] = &[1];
 pub const ADMIN: &[u8] = &[2];
@@ -40,7 +135,40 @@ pub const ACHIEVEMENT_STATS: &[u8] = &[6];
 pub const ACHIEVEMENT_LEADERBOARD: &[u8] = &[7];
 pub const USER_REPUTATION: &[u8] = &[8];
 pub const ACHIEVEMENT_PREREQUISITES: &[u8] = &[9];
-pub const PAUSED: Symbol = symbol_short!("PAUSED");
+pub const REPUTATION_PROOF_HEAD: &[u8] = &[10];
+pub const REPUTATION_PROOF: &[u8] = &[11];
+pub const PARAMS_U32: &[u8] = &[12];
+pub const PARAMS_ADDRESS: &[u8] = &[13];
+pub const PARAMS_SYMBOL: &[u8] = &[14];
+pub const TOKEN_URI_COMMITMENT: &[u8] = &[15];
+pub const MAX_URI_LEN: u32 = 256; // Caps per-token storage and the stack buffer template_uri fills
+pub const MAX_ACHIEVEMENTS_PER_USER: u32 = 100; // Prevent unbounded growth
+pub const MAINTENANCE_CURSOR: &[u8] = &[16];
+#[cfg(feature = "leaderboard")]
+pub const LEADERBOARD_ROOTS: &[u8] = &[17];
+pub const ACTIVITY_COUNTERS: &[u8] = &[18];
+pub const MINT_ALLOWANCES: &[u8] = &[19];
+pub const LISTINGS: &[u8] = &[20];
+pub const COMPLETED_CONTRACTS: &[u8] = &[21];
+pub const SKILL_SCORES: &[u8] = &[22];
+pub const PARTNER_LAST_MINT: &[u8] = &[23];
+pub const BASE_URI: &[u8] = &[24];
+pub const PARTNER_TEMPLATES: &[u8] = &[25];
+pub const TOKEN_MINTED_AT: &[u8] = &[26];
+pub const DEFAULT_BASE_URI: &str = "ipfs://";
+pub const MAX_BASE_URI_LEN: u32 = 192; // Leaves room for the template path under MAX_URI_LEN
+pub const COMPLETION_BADGE_THRESHOLD: u32 = 10;
+pub const CERTIFICATION_BADGE_THRESHOLD: u32 = 3;
+pub const MAX_ROYALTY_BPS: u32 = 10_000;
+
+// Keeper maintenance bounds (ledgers, assuming ~5s per ledger)
+pub const TTL_THRESHOLD: u32 = 518_400; // ~30 days
+pub const TTL_EXTEND_TO: u32 = 1_036_800; // ~60 days
+pub const MAX_MAINTENANCE_ITEMS: u32 = 50;
+pub use offerhub_pausable::PAUSED;
+// Pausable surface covering listing and buying
+pub const MARKET: Symbol = symbol_short!("market");
+pub const DEBUG_MODE: Symbol = symbol_short!("DEBUG");
 
 pub fn require_auth(_env: &Env, address: &Address) -> Result<(), Error> {
     address.require_auth();
@@ -0,0 +1,27 @@
+use offerhub_upgradeable::{Migratable, UpgradeError, Upgradeable};
+use soroban_sdk::{Address, Env};
+
+use crate::access::check_admin;
+use crate::contract::ReputationNFTContract;
+use crate::error::Error;
+use crate::types::CONTRACT_VERSION;
+
+impl Upgradeable for ReputationNFTContract {
+    type Error = Error;
+    const VERSION: u32 = CONTRACT_VERSION;
+
+    fn authorize_upgrade(env: &Env, caller: &Address) -> Result<(), Error> {
+        // Requires the admin's auth itself
+        check_admin(env, caller)
+    }
+}
+
+impl Migratable for ReputationNFTContract {
+    fn migrate_from(_env: &Env, from_version: u32) -> Result<(), Error> {
+        match from_version {
+            // Deployments from before version tracking already use the v1 layout
+            offerhub_upgradeable::UNVERSIONED => Ok(()),
+            _ => Err(UpgradeError::DowngradeNotSupported.into()),
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use crate::error::Error;
+use crate::event::{
+    consumer_deactivated, consumer_registered, oracle_initialized, rate_limit_updated,
+    reputation_queried, tier_thresholds_set,
+};
+use crate::storage::{
+    get_admin, get_consumer, get_reputation_nft, get_tier_thresholds, set_admin, set_consumer,
+    set_reputation_nft, set_tier_thresholds,
+};
+use crate::types::{BadgeKind, Consumer, ReputationReport, ReputationTier, ORACLE_VERSION};
+use soroban_sdk::{vec, Address, Env, IntoVal, Map, String, Symbol, Vec};
+
+/// Default minimum scores for Bronze, Silver, Gold and Platinum. Scores are the NFT
+/// contract's rating averages, scaled by 100.
+const DEFAULT_TIER_THRESHOLDS: [u32; 4] = [300, 400, 450, 480];
+
+pub struct ReputationOracleContract;
+
+impl ReputationOracleContract {
+    pub fn initialize(env: Env, admin: Address, reputation_nft: Address) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        set_reputation_nft(&env, &reputation_nft);
+        set_tier_thresholds(&env, &Vec::from_array(&env, DEFAULT_TIER_THRESHOLDS));
+        oracle_initialized(&env, admin, reputation_nft);
+        Ok(())
+    }
+
+    pub fn version(_env: Env) -> u32 {
+        ORACLE_VERSION
+    }
+
+    /// Replace the tier thresholds: one strictly ascending minimum score per tier above
+    /// Unrated
+    pub fn set_tier_thresholds(
+        env: Env,
+        admin: Address,
+        thresholds: Vec<u32>,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        if thresholds.len() != DEFAULT_TIER_THRESHOLDS.len() as u32 {
+            return Err(Error::InvalidThresholds);
+        }
+        for i in 1..thresholds.len() {
+            if thresholds.get_unchecked(i) <= thresholds.get_unchecked(i - 1) {
+                return Err(Error::InvalidThresholds);
+            }
+        }
+
+        set_tier_thresholds(&env, &thresholds);
+        tier_thresholds_set(&env, thresholds);
+        Ok(())
+    }
+
+    pub fn get_tier_thresholds(env: Env) -> Result<Vec<u32>, Error> {
+        get_tier_thresholds(&env).ok_or(Error::NotInitialized)
+    }
+
+    /// Tier a score falls into under the current thresholds
+    pub fn tier_for_score(env: Env, score: u32) -> Result<ReputationTier, Error> {
+        let thresholds = get_tier_thresholds(&env).ok_or(Error::NotInitialized)?;
+        let reached = thresholds.iter().filter(|min| score >= *min).count();
+        Ok(match reached {
+            0 => ReputationTier::Unrated,
+            1 => ReputationTier::Bronze,
+            2 => ReputationTier::Silver,
+            3 => ReputationTier::Gold,
+            _ => ReputationTier::Platinum,
+        })
+    }
+
+    /// Register a consumer with its query allowance per window, or reactivate a
+    /// deactivated one under new limits. Usage totals carry over on reactivation.
+    pub fn register_consumer(
+        env: Env,
+        admin: Address,
+        consumer: Address,
+        name: String,
+        max_queries: u32,
+        window: u64,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if max_queries == 0 || window == 0 {
+            return Err(Error::InvalidRateLimit);
+        }
+
+        let existing = get_consumer(&env, &consumer);
+        if existing.as_ref().is_some_and(|c| c.active) {
+            return Err(Error::ConsumerAlreadyRegistered);
+        }
+
+        let now = env.ledger().timestamp();
+        set_consumer(
+            &env,
+            &Consumer {
+                address: consumer.clone(),
+                name: name.clone(),
+                max_queries,
+                window,
+                active: true,
+                registered_at: now,
+                window_start: now,
+                window_queries: 0,
+                total_queries: existing.as_ref().map(|c| c.total_queries).unwrap_or(0),
+                last_query_at: existing.map(|c| c.last_query_at).unwrap_or(0),
+            },
+        );
+        consumer_registered(&env, consumer, name, max_queries, window);
+        Ok(())
+    }
+
+    /// Change an active consumer's query allowance. The current window keeps running.
+    pub fn set_rate_limit(
+        env: Env,
+        admin: Address,
+        consumer: Address,
+        max_queries: u32,
+        window: u64,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if max_queries == 0 || window == 0 {
+            return Err(Error::InvalidRateLimit);
+        }
+
+        let mut record = Self::active_consumer(&env, &consumer)?;
+        record.max_queries = max_queries;
+        record.window = window;
+        set_consumer(&env, &record);
+
+        rate_limit_updated(&env, consumer, max_queries, window);
+        Ok(())
+    }
+
+    pub fn deactivate_consumer(env: Env, admin: Address, consumer: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let mut record = Self::active_consumer(&env, &consumer)?;
+        record.active = false;
+        set_consumer(&env, &record);
+
+        consumer_deactivated(&env, consumer);
+        Ok(())
+    }
+
+    pub fn get_consumer(env: Env, consumer: Address) -> Result<Consumer, Error> {
+        get_consumer(&env, &consumer).ok_or(Error::ConsumerNotFound)
+    }
+
+    /// Full versioned report for a user. Counts against the consumer's rate limit.
+    pub fn get_reputation(
+        env: Env,
+        consumer: Address,
+        user: Address,
+    ) -> Result<ReputationReport, Error> {
+        Self::require_not_paused(&env)?;
+        Self::record_query(&env, &consumer)?;
+        let report = Self::build_report(&env, &user)?;
+        reputation_queried(&env, consumer, user, report.tier);
+        Ok(report)
+    }
+
+    /// Just the user's tier. Counts against the consumer's rate limit.
+    pub fn get_tier(env: Env, consumer: Address, user: Address) -> Result<ReputationTier, Error> {
+        Self::require_not_paused(&env)?;
+        Self::record_query(&env, &consumer)?;
+        let reputation_nft = get_reputation_nft(&env).ok_or(Error::NotInitialized)?;
+        let score: u32 = env.invoke_contract(
+            &reputation_nft,
+            &Symbol::new(&env, "get_user_reputation_score"),
+            vec![&env, user.into_val(&env)],
+        );
+        let tier = Self::tier_for_score(env.clone(), score)?;
+        reputation_queried(&env, consumer, user, tier);
+        Ok(tier)
+    }
+
+    fn build_report(env: &Env, user: &Address) -> Result<ReputationReport, Error> {
+        let reputation_nft = get_reputation_nft(env).ok_or(Error::NotInitialized)?;
+
+        let score: u32 = env.invoke_contract(
+            &reputation_nft,
+            &Symbol::new(env, "get_user_reputation_score"),
+            vec![env, user.into_val(env)],
+        );
+        let badge_counts: Map<BadgeKind, u32> = env.invoke_contract(
+            &reputation_nft,
+            &Symbol::new(env, "get_achievement_counts"),
+            vec![env, user.into_val(env)],
+        );
+        let completed_contracts: u32 = env.invoke_contract(
+            &reputation_nft,
+            &Symbol::new(env, "get_completed_contracts"),
+            vec![env, user.into_val(env)],
+        );
+
+        Ok(ReputationReport {
+            version: ORACLE_VERSION,
+            user: user.clone(),
+            score,
+            tier: Self::tier_for_score(env.clone(), score)?,
+            total_badges: badge_counts.values().iter().sum(),
+            badge_counts,
+            completed_contracts,
+            timestamp: env.ledger().timestamp(),
+        })
+    }
+
+    /// Check the consumer's allowance and count one query, starting a new window once
+    /// the current one has elapsed
+    fn record_query(env: &Env, consumer: &Address) -> Result<(), Error> {
+        consumer.require_auth();
+
+        let mut record = Self::active_consumer(env, consumer)?;
+        let now = env.ledger().timestamp();
+        if now >= record.window_start.saturating_add(record.window) {
+            record.window_start = now;
+            record.window_queries = 0;
+        }
+        if record.window_queries >= record.max_queries {
+            return Err(Error::RateLimitExceeded);
+        }
+
+        record.window_queries += 1;
+        record.total_queries += 1;
+        record.last_query_at = now;
+        set_consumer(env, &record);
+        Ok(())
+    }
+
+    fn active_consumer(env: &Env, consumer: &Address) -> Result<Consumer, Error> {
+        let record = get_consumer(env, consumer).ok_or(Error::ConsumerNotFound)?;
+        if !record.active {
+            return Err(Error::ConsumerInactive);
+        }
+        Ok(record)
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        admin.require_auth();
+        let stored = get_admin(env).ok_or(Error::NotInitialized)?;
+        if *admin != stored {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
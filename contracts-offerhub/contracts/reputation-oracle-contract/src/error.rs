@@ -0,0 +1,42 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// Consumer is not registered
+    ConsumerNotFound = 4,
+    /// Consumer is already registered
+    ConsumerAlreadyRegistered = 5,
+    /// Consumer has been deactivated
+    ConsumerInactive = 6,
+    /// Consumer has used up its queries for the current window
+    RateLimitExceeded = 7,
+    /// Rate limit needs a non-zero query allowance and window
+    InvalidRateLimit = 8,
+    /// Tier thresholds must be one per tier above Unrated, in ascending order
+    InvalidThresholds = 9,
+    /// Contract is already paused
+    AlreadyPaused = 10,
+    /// Contract is not paused
+    NotPaused = 11,
+    /// Contract is paused
+    ContractPaused = 12,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
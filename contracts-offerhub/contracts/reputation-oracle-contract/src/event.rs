@@ -0,0 +1,38 @@
+use crate::types::ReputationTier;
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+pub fn oracle_initialized(e: &Env, admin: Address, reputation_nft: Address) {
+    let topics = (Symbol::new(e, "oracle_initialized"), admin);
+    e.events().publish(topics, reputation_nft);
+}
+
+pub fn tier_thresholds_set(e: &Env, thresholds: Vec<u32>) {
+    let topics = (Symbol::new(e, "tier_thresholds_set"),);
+    e.events().publish(topics, thresholds);
+}
+
+pub fn consumer_registered(
+    e: &Env,
+    consumer: Address,
+    name: String,
+    max_queries: u32,
+    window: u64,
+) {
+    let topics = (Symbol::new(e, "consumer_registered"), consumer);
+    e.events().publish(topics, (name, max_queries, window));
+}
+
+pub fn rate_limit_updated(e: &Env, consumer: Address, max_queries: u32, window: u64) {
+    let topics = (Symbol::new(e, "rate_limit_updated"), consumer);
+    e.events().publish(topics, (max_queries, window));
+}
+
+pub fn consumer_deactivated(e: &Env, consumer: Address) {
+    let topics = (Symbol::new(e, "consumer_deactivated"), consumer);
+    e.events().publish(topics, e.ledger().timestamp());
+}
+
+pub fn reputation_queried(e: &Env, consumer: Address, user: Address, tier: ReputationTier) {
+    let topics = (Symbol::new(e, "reputation_queried"), consumer, user);
+    e.events().publish(topics, tier);
+}
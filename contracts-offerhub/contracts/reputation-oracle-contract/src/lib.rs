@@ -0,0 +1,100 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::ReputationOracleContract;
+pub use crate::error::Error;
+pub use crate::types::{BadgeKind, Consumer, ReputationReport, ReputationTier, ORACLE_VERSION};
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| {
+    ReputationOracleContract::pause_admin(env)
+});
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the oracle with an admin and the reputation NFT contract it reads from
+    pub fn initialize(env: Env, admin: Address, reputation_nft: Address) -> Result<(), Error> {
+        ReputationOracleContract::initialize(env, admin, reputation_nft)
+    }
+
+    /// Version of the report format this oracle serves
+    pub fn version(env: Env) -> u32 {
+        ReputationOracleContract::version(env)
+    }
+
+    /// Replace the minimum scores for Bronze, Silver, Gold and Platinum (admin only)
+    pub fn set_tier_thresholds(
+        env: Env,
+        admin: Address,
+        thresholds: Vec<u32>,
+    ) -> Result<(), Error> {
+        ReputationOracleContract::set_tier_thresholds(env, admin, thresholds)
+    }
+
+    /// Get the minimum scores for Bronze, Silver, Gold and Platinum
+    pub fn get_tier_thresholds(env: Env) -> Result<Vec<u32>, Error> {
+        ReputationOracleContract::get_tier_thresholds(env)
+    }
+
+    /// Get the tier a score falls into
+    pub fn tier_for_score(env: Env, score: u32) -> Result<ReputationTier, Error> {
+        ReputationOracleContract::tier_for_score(env, score)
+    }
+
+    /// Register or reactivate a consumer with its query allowance per window (admin only)
+    pub fn register_consumer(
+        env: Env,
+        admin: Address,
+        consumer: Address,
+        name: String,
+        max_queries: u32,
+        window: u64,
+    ) -> Result<(), Error> {
+        ReputationOracleContract::register_consumer(env, admin, consumer, name, max_queries, window)
+    }
+
+    /// Change a consumer's query allowance (admin only)
+    pub fn set_rate_limit(
+        env: Env,
+        admin: Address,
+        consumer: Address,
+        max_queries: u32,
+        window: u64,
+    ) -> Result<(), Error> {
+        ReputationOracleContract::set_rate_limit(env, admin, consumer, max_queries, window)
+    }
+
+    /// Stop a consumer from querying (admin only)
+    pub fn deactivate_consumer(env: Env, admin: Address, consumer: Address) -> Result<(), Error> {
+        ReputationOracleContract::deactivate_consumer(env, admin, consumer)
+    }
+
+    /// Get a consumer's rate limit and usage
+    pub fn get_consumer(env: Env, consumer: Address) -> Result<Consumer, Error> {
+        ReputationOracleContract::get_consumer(env, consumer)
+    }
+
+    /// Get a user's score, tier and badge counts (registered consumers only)
+    pub fn get_reputation(
+        env: Env,
+        consumer: Address,
+        user: Address,
+    ) -> Result<ReputationReport, Error> {
+        ReputationOracleContract::get_reputation(env, consumer, user)
+    }
+
+    /// Get a user's tier (registered consumers only)
+    pub fn get_tier(env: Env, consumer: Address, user: Address) -> Result<ReputationTier, Error> {
+        ReputationOracleContract::get_tier(env, consumer, user)
+    }
+}
@@ -0,0 +1,54 @@
+use crate::types::Consumer;
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    ReputationNft,
+    /// Minimum score for Bronze, Silver, Gold and Platinum
+    TierThresholds,
+    Consumer(Address),
+}
+
+/// Get the admin address
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_reputation_nft(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::ReputationNft)
+}
+
+pub fn set_reputation_nft(env: &Env, reputation_nft: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ReputationNft, reputation_nft);
+}
+
+pub fn get_tier_thresholds(env: &Env) -> Option<Vec<u32>> {
+    env.storage().instance().get(&DataKey::TierThresholds)
+}
+
+pub fn set_tier_thresholds(env: &Env, thresholds: &Vec<u32>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TierThresholds, thresholds);
+}
+
+pub fn get_consumer(env: &Env, consumer: &Address) -> Option<Consumer> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Consumer(consumer.clone()))
+}
+
+pub fn set_consumer(env: &Env, consumer: &Consumer) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Consumer(consumer.address.clone()), consumer);
+}
@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, map, symbol_short, vec, Address, Env, Map, String};
+
+#[contract]
+pub struct MockReputationNft;
+
+#[contractimpl]
+impl MockReputationNft {
+    pub fn set_score(env: Env, score: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("score"), &score);
+    }
+
+    pub fn get_user_reputation_score(env: Env, _user: Address) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("score"))
+            .unwrap_or(0)
+    }
+
+    pub fn get_achievement_counts(env: Env, _user: Address) -> Map<BadgeKind, u32> {
+        map![
+            &env,
+            (BadgeKind::Reputation, 2),
+            (BadgeKind::ProjectMilestone, 3)
+        ]
+    }
+
+    pub fn get_completed_contracts(_env: Env, _user: Address) -> u32 {
+        7
+    }
+}
+
+fn setup(env: &Env) -> (ContractClient<'_>, MockReputationNftClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let nft = MockReputationNftClient::new(env, &env.register(MockReputationNft, ()));
+    let client = ContractClient::new(env, &env.register(Contract, ()));
+    let admin = Address::generate(env);
+    let consumer = Address::generate(env);
+    client.initialize(&admin, &nft.address);
+    client.register_consumer(
+        &admin,
+        &consumer,
+        &String::from_str(env, "Lending Protocol"),
+        &2,
+        &3_600,
+    );
+    (client, nft, admin, consumer)
+}
+
+#[test]
+fn test_report_combines_score_tier_and_badges() {
+    let env = Env::default();
+    let (client, nft, _, consumer) = setup(&env);
+    let user = Address::generate(&env);
+    nft.set_score(&460);
+
+    let report = client.get_reputation(&consumer, &user);
+    assert_eq!(report.version, ORACLE_VERSION);
+    assert_eq!(client.version(), ORACLE_VERSION);
+    assert_eq!(report.score, 460);
+    assert_eq!(report.tier, ReputationTier::Gold);
+    assert_eq!(report.total_badges, 5);
+    assert_eq!(
+        report.badge_counts.get(BadgeKind::ProjectMilestone),
+        Some(3)
+    );
+    assert_eq!(report.completed_contracts, 7);
+
+    nft.set_score(&0);
+    assert_eq!(client.get_tier(&consumer, &user), ReputationTier::Unrated);
+
+    let usage = client.get_consumer(&consumer);
+    assert_eq!(usage.total_queries, 2);
+    assert_eq!(usage.last_query_at, 1_000);
+}
+
+#[test]
+fn test_consumers_are_rate_limited_per_window() {
+    let env = Env::default();
+    let (client, _, admin, consumer) = setup(&env);
+    let user = Address::generate(&env);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_get_tier(&stranger, &user),
+        Err(Ok(Error::ConsumerNotFound))
+    );
+
+    client.get_tier(&consumer, &user);
+    client.get_reputation(&consumer, &user);
+    assert_eq!(
+        client.try_get_tier(&consumer, &user),
+        Err(Ok(Error::RateLimitExceeded))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 4_600);
+    client.get_tier(&consumer, &user);
+    let usage = client.get_consumer(&consumer);
+    assert_eq!(usage.window_start, 4_600);
+    assert_eq!(usage.window_queries, 1);
+    assert_eq!(usage.total_queries, 3);
+
+    client.set_rate_limit(&admin, &consumer, &10, &3_600);
+    client.get_tier(&consumer, &user);
+    client.get_tier(&consumer, &user);
+
+    client.deactivate_consumer(&admin, &consumer);
+    assert_eq!(
+        client.try_get_tier(&consumer, &user),
+        Err(Ok(Error::ConsumerInactive))
+    );
+
+    client.register_consumer(
+        &admin,
+        &consumer,
+        &String::from_str(&env, "Lending Protocol"),
+        &1,
+        &60,
+    );
+    client.get_tier(&consumer, &user);
+    assert_eq!(client.get_consumer(&consumer).total_queries, 6);
+}
+
+#[test]
+fn test_tier_thresholds_must_ascend() {
+    let env = Env::default();
+    let (client, _, admin, consumer) = setup(&env);
+
+    assert_eq!(
+        client.try_set_tier_thresholds(&admin, &vec![&env, 100, 200, 200, 300]),
+        Err(Ok(Error::InvalidThresholds))
+    );
+    assert_eq!(
+        client.try_set_tier_thresholds(&admin, &vec![&env, 100, 200]),
+        Err(Ok(Error::InvalidThresholds))
+    );
+    assert_eq!(
+        client.try_set_tier_thresholds(&consumer, &vec![&env, 1, 2, 3, 4]),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.set_tier_thresholds(&admin, &vec![&env, 100, 200, 300, 400]);
+    assert_eq!(client.tier_for_score(&99), ReputationTier::Unrated);
+    assert_eq!(client.tier_for_score(&250), ReputationTier::Silver);
+    assert_eq!(client.tier_for_score(&400), ReputationTier::Platinum);
+}
+
+#[test]
+fn test_pause_stops_serving_reports() {
+    let env = Env::default();
+    let (client, nft, admin, consumer) = setup(&env);
+    let user = Address::generate(&env);
+    nft.set_score(&460);
+
+    client.pause(&admin);
+    assert_eq!(
+        client.try_get_reputation(&consumer, &user),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_get_tier(&consumer, &user),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    client.unpause(&admin);
+    assert_eq!(client.get_tier(&consumer, &user), ReputationTier::Gold);
+}
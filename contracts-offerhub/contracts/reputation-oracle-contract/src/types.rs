@@ -0,0 +1,55 @@
+use soroban_sdk::{contracttype, Address, Map, String};
+
+/// Version stamped on every report; bumped whenever `ReputationReport` changes shape.
+pub const ORACLE_VERSION: u32 = 1;
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReputationTier {
+    Unrated = 0,
+    Bronze = 1,
+    Silver = 2,
+    Gold = 3,
+    Platinum = 4,
+}
+
+/// Mirrors the reputation NFT contract's `AchievementType` so its badge counts decode
+/// without depending on that crate.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BadgeKind {
+    Standard,
+    Reputation,
+    ProjectMilestone,
+    RatingMilestone,
+    CustomAchievement,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Consumer {
+    pub address: Address,
+    pub name: String,
+    pub max_queries: u32, // Queries allowed per window
+    pub window: u64,      // Window length in seconds
+    pub active: bool,
+    pub registered_at: u64,
+    pub window_start: u64,
+    pub window_queries: u32,
+    pub total_queries: u64,
+    pub last_query_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationReport {
+    pub version: u32,
+    pub user: Address,
+    pub score: u32,
+    pub tier: ReputationTier,
+    pub total_badges: u32,
+    pub badge_counts: Map<BadgeKind, u32>,
+    pub completed_contracts: u32,
+    pub timestamp: u64,
+}
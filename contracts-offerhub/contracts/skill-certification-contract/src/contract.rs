@@ -0,0 +1,219 @@
+use crate::error::Error;
+use crate::event::{
+    admin_initialized, certificate_issued, certificate_revoked, issuer_added, issuer_removed,
+};
+use crate::storage::{
+    add_holder_entry, get_admin, get_certificate, get_holder_count, get_holder_entry, get_issuer,
+    next_certificate_id, set_admin, set_certificate, set_issuer,
+};
+use crate::types::{Certificate, Issuer};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
+
+const MAX_PAGE_SIZE: u32 = 50;
+
+pub struct SkillCertificationContract;
+
+impl SkillCertificationContract {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        admin_initialized(&env, admin);
+        Ok(())
+    }
+
+    /// Approve an issuer, or re-approve one that was removed
+    pub fn add_issuer(
+        env: Env,
+        admin: Address,
+        issuer: Address,
+        name: String,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let existing = get_issuer(&env, &issuer);
+        if existing.as_ref().is_some_and(|i| i.active) {
+            return Err(Error::IssuerAlreadyApproved);
+        }
+
+        set_issuer(
+            &env,
+            &Issuer {
+                address: issuer.clone(),
+                name: name.clone(),
+                approved_at: env.ledger().timestamp(),
+                active: true,
+                issued: existing.map(|i| i.issued).unwrap_or(0),
+            },
+        );
+        issuer_added(&env, issuer, name);
+        Ok(())
+    }
+
+    /// Remove an issuer's approval. Certificates it issued stay on record but are no
+    /// longer valid.
+    pub fn remove_issuer(env: Env, admin: Address, issuer: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let mut record = get_issuer(&env, &issuer)
+            .filter(|i| i.active)
+            .ok_or(Error::IssuerNotApproved)?;
+        record.active = false;
+        set_issuer(&env, &record);
+
+        issuer_removed(&env, issuer);
+        Ok(())
+    }
+
+    pub fn get_issuer(env: Env, issuer: Address) -> Option<Issuer> {
+        get_issuer(&env, &issuer)
+    }
+
+    pub fn is_approved_issuer(env: Env, issuer: Address) -> bool {
+        get_issuer(&env, &issuer).is_some_and(|i| i.active)
+    }
+
+    /// Mint a certificate to a holder. `expires_at` of 0 issues a certificate that
+    /// never expires.
+    pub fn issue_certificate(
+        env: Env,
+        issuer: Address,
+        holder: Address,
+        skill: Symbol,
+        credential_hash: BytesN<32>,
+        expires_at: u64,
+    ) -> Result<u64, Error> {
+        Self::require_not_paused(&env)?;
+        issuer.require_auth();
+
+        let mut record = get_issuer(&env, &issuer)
+            .filter(|i| i.active)
+            .ok_or(Error::IssuerNotApproved)?;
+        let now = env.ledger().timestamp();
+        if expires_at != 0 && expires_at <= now {
+            return Err(Error::InvalidExpiry);
+        }
+
+        let certificate = Certificate {
+            id: next_certificate_id(&env),
+            issuer: issuer.clone(),
+            holder: holder.clone(),
+            skill: skill.clone(),
+            credential_hash,
+            issued_at: now,
+            expires_at,
+            revoked: false,
+        };
+        set_certificate(&env, &certificate);
+        add_holder_entry(&env, &holder, certificate.id);
+
+        record.issued += 1;
+        set_issuer(&env, &record);
+
+        certificate_issued(&env, certificate.id, issuer, holder, skill, expires_at);
+        Ok(certificate.id)
+    }
+
+    /// Revoke a certificate. Only its issuer or the admin can revoke.
+    pub fn revoke_certificate(env: Env, caller: Address, id: u64) -> Result<(), Error> {
+        caller.require_auth();
+
+        let mut certificate = get_certificate(&env, id).ok_or(Error::CertificateNotFound)?;
+        if caller != certificate.issuer && Some(caller.clone()) != get_admin(&env) {
+            return Err(Error::Unauthorized);
+        }
+        if certificate.revoked {
+            return Err(Error::AlreadyRevoked);
+        }
+
+        certificate.revoked = true;
+        set_certificate(&env, &certificate);
+
+        certificate_revoked(&env, id, caller);
+        Ok(())
+    }
+
+    pub fn get_certificate(env: Env, id: u64) -> Result<Certificate, Error> {
+        get_certificate(&env, id).ok_or(Error::CertificateNotFound)
+    }
+
+    /// A certificate is valid while it is unrevoked, unexpired and its issuer is still
+    /// approved
+    pub fn is_valid(env: Env, id: u64) -> bool {
+        get_certificate(&env, id).is_some_and(|c| Self::check_valid(&env, &c))
+    }
+
+    /// Page through a holder's certificates, oldest first
+    pub fn get_certificates(
+        env: Env,
+        holder: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Certificate> {
+        let count = get_holder_count(&env, &holder);
+        let end = offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+        let mut certificates = Vec::new(&env);
+        for position in offset..end {
+            if let Some(certificate) =
+                get_holder_entry(&env, &holder, position).and_then(|id| get_certificate(&env, id))
+            {
+                certificates.push_back(certificate);
+            }
+        }
+        certificates
+    }
+
+    /// Number of valid certificates a holder has; read by the reputation NFT contract
+    pub fn count_valid_certificates(env: Env, holder: Address) -> u32 {
+        let mut valid = 0;
+        for position in 0..get_holder_count(&env, &holder) {
+            if let Some(certificate) =
+                get_holder_entry(&env, &holder, position).and_then(|id| get_certificate(&env, id))
+            {
+                if Self::check_valid(&env, &certificate) {
+                    valid += 1;
+                }
+            }
+        }
+        valid
+    }
+
+    /// Check whether a holder has a valid certificate for a skill
+    pub fn has_valid_certificate(env: Env, holder: Address, skill: Symbol) -> bool {
+        (0..get_holder_count(&env, &holder)).any(|position| {
+            get_holder_entry(&env, &holder, position)
+                .and_then(|id| get_certificate(&env, id))
+                .is_some_and(|c| c.skill == skill && Self::check_valid(&env, &c))
+        })
+    }
+
+    fn check_valid(env: &Env, certificate: &Certificate) -> bool {
+        !certificate.revoked
+            && (certificate.expires_at == 0 || certificate.expires_at > env.ledger().timestamp())
+            && get_issuer(env, &certificate.issuer).is_some_and(|i| i.active)
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        admin.require_auth();
+        let stored = get_admin(env).ok_or(Error::NotInitialized)?;
+        if *admin != stored {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
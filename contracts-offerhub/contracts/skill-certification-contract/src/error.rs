@@ -0,0 +1,40 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// Address is not an approved issuer
+    IssuerNotApproved = 4,
+    /// Address is already an approved issuer
+    IssuerAlreadyApproved = 5,
+    /// Certificate does not exist
+    CertificateNotFound = 6,
+    /// Certificate has already been revoked
+    AlreadyRevoked = 7,
+    /// Expiry must be in the future
+    InvalidExpiry = 8,
+    /// Contract is already paused
+    AlreadyPaused = 9,
+    /// Contract is not paused
+    NotPaused = 10,
+    /// Contract is paused
+    ContractPaused = 11,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
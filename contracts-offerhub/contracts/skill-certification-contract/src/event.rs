@@ -0,0 +1,33 @@
+use soroban_sdk::{Address, Env, String, Symbol};
+
+pub fn admin_initialized(e: &Env, admin: Address) {
+    let topics = (Symbol::new(e, "admin_initialized"),);
+    e.events().publish(topics, admin);
+}
+
+pub fn issuer_added(e: &Env, issuer: Address, name: String) {
+    let topics = (Symbol::new(e, "issuer_added"), issuer);
+    e.events().publish(topics, name);
+}
+
+pub fn issuer_removed(e: &Env, issuer: Address) {
+    let topics = (Symbol::new(e, "issuer_removed"), issuer);
+    e.events().publish(topics, e.ledger().timestamp());
+}
+
+pub fn certificate_issued(
+    e: &Env,
+    id: u64,
+    issuer: Address,
+    holder: Address,
+    skill: Symbol,
+    expires_at: u64,
+) {
+    let topics = (Symbol::new(e, "certificate_issued"), issuer, holder);
+    e.events().publish(topics, (id, skill, expires_at));
+}
+
+pub fn certificate_revoked(e: &Env, id: u64, revoked_by: Address) {
+    let topics = (Symbol::new(e, "certificate_revoked"), revoked_by);
+    e.events().publish(topics, id);
+}
@@ -0,0 +1,108 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::SkillCertificationContract;
+pub use crate::error::Error;
+pub use crate::types::{Certificate, Issuer};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Symbol, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| {
+    SkillCertificationContract::pause_admin(env)
+});
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the contract with an admin address
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        SkillCertificationContract::initialize(env, admin)
+    }
+
+    /// Approve an issuer such as a bootcamp or assessment partner (admin only)
+    pub fn add_issuer(
+        env: Env,
+        admin: Address,
+        issuer: Address,
+        name: String,
+    ) -> Result<(), Error> {
+        SkillCertificationContract::add_issuer(env, admin, issuer, name)
+    }
+
+    /// Remove an issuer's approval, invalidating its certificates (admin only)
+    pub fn remove_issuer(env: Env, admin: Address, issuer: Address) -> Result<(), Error> {
+        SkillCertificationContract::remove_issuer(env, admin, issuer)
+    }
+
+    /// Get an issuer's registry entry
+    pub fn get_issuer(env: Env, issuer: Address) -> Option<Issuer> {
+        SkillCertificationContract::get_issuer(env, issuer)
+    }
+
+    /// Check whether an address is currently an approved issuer
+    pub fn is_approved_issuer(env: Env, issuer: Address) -> bool {
+        SkillCertificationContract::is_approved_issuer(env, issuer)
+    }
+
+    /// Mint a skill certificate to a holder and return its id (approved issuers only)
+    pub fn issue_certificate(
+        env: Env,
+        issuer: Address,
+        holder: Address,
+        skill: Symbol,
+        credential_hash: BytesN<32>,
+        expires_at: u64,
+    ) -> Result<u64, Error> {
+        SkillCertificationContract::issue_certificate(
+            env,
+            issuer,
+            holder,
+            skill,
+            credential_hash,
+            expires_at,
+        )
+    }
+
+    /// Revoke a certificate (its issuer or the admin)
+    pub fn revoke_certificate(env: Env, caller: Address, id: u64) -> Result<(), Error> {
+        SkillCertificationContract::revoke_certificate(env, caller, id)
+    }
+
+    /// Get a certificate by id
+    pub fn get_certificate(env: Env, id: u64) -> Result<Certificate, Error> {
+        SkillCertificationContract::get_certificate(env, id)
+    }
+
+    /// Check whether a certificate is unrevoked, unexpired and from an approved issuer
+    pub fn is_valid(env: Env, id: u64) -> bool {
+        SkillCertificationContract::is_valid(env, id)
+    }
+
+    /// Page through a holder's certificates
+    pub fn get_certificates(
+        env: Env,
+        holder: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Certificate> {
+        SkillCertificationContract::get_certificates(env, holder, offset, limit)
+    }
+
+    /// Count a holder's valid certificates
+    pub fn count_valid_certificates(env: Env, holder: Address) -> u32 {
+        SkillCertificationContract::count_valid_certificates(env, holder)
+    }
+
+    /// Check whether a holder has a valid certificate for a skill
+    pub fn has_valid_certificate(env: Env, holder: Address, skill: Symbol) -> bool {
+        SkillCertificationContract::has_valid_certificate(env, holder, skill)
+    }
+}
@@ -0,0 +1,82 @@
+use crate::types::{Certificate, Issuer};
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Issuer(Address),
+    NextCertificateId,
+    Certificate(u64),
+    /// holder -> number of certificates issued to it
+    HolderCount(Address),
+    /// (holder, position) -> certificate id
+    HolderEntry(Address, u32),
+}
+
+/// Get the admin address
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_issuer(env: &Env, issuer: &Address) -> Option<Issuer> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Issuer(issuer.clone()))
+}
+
+pub fn set_issuer(env: &Env, issuer: &Issuer) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Issuer(issuer.address.clone()), issuer);
+}
+
+/// Allocate the next certificate id, starting at 1
+pub fn next_certificate_id(env: &Env) -> u64 {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::NextCertificateId)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextCertificateId, &id);
+    id
+}
+
+pub fn get_certificate(env: &Env, id: u64) -> Option<Certificate> {
+    env.storage().persistent().get(&DataKey::Certificate(id))
+}
+
+pub fn set_certificate(env: &Env, certificate: &Certificate) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Certificate(certificate.id), certificate);
+}
+
+/// Add a certificate id to its holder's listing
+pub fn add_holder_entry(env: &Env, holder: &Address, id: u64) {
+    let count = get_holder_count(env, holder);
+    let storage = env.storage().persistent();
+    storage.set(&DataKey::HolderEntry(holder.clone(), count), &id);
+    storage.set(&DataKey::HolderCount(holder.clone()), &(count + 1));
+}
+
+pub fn get_holder_count(env: &Env, holder: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HolderCount(holder.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_holder_entry(env: &Env, holder: &Address, position: u32) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HolderEntry(holder.clone(), position))
+}
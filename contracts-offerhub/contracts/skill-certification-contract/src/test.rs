@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String};
+
+fn setup(env: &Env) -> (ContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let client = ContractClient::new(env, &env.register(Contract, ()));
+    let admin = Address::generate(env);
+    let issuer = Address::generate(env);
+    client.initialize(&admin);
+    client.add_issuer(&admin, &issuer, &String::from_str(env, "Rust Bootcamp"));
+    (client, admin, issuer)
+}
+
+#[test]
+fn test_only_approved_issuers_can_certify() {
+    let env = Env::default();
+    let (client, admin, issuer) = setup(&env);
+    let holder = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[1; 32]);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_issue_certificate(&stranger, &holder, &symbol_short!("rust"), &hash, &0),
+        Err(Ok(Error::IssuerNotApproved))
+    );
+    assert_eq!(
+        client.try_add_issuer(&stranger, &stranger, &String::from_str(&env, "Self")),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_add_issuer(&admin, &issuer, &String::from_str(&env, "Again")),
+        Err(Ok(Error::IssuerAlreadyApproved))
+    );
+    assert_eq!(
+        client.try_issue_certificate(&issuer, &holder, &symbol_short!("rust"), &hash, &1_000),
+        Err(Ok(Error::InvalidExpiry))
+    );
+
+    let id = client.issue_certificate(&issuer, &holder, &symbol_short!("rust"), &hash, &0);
+    let certificate = client.get_certificate(&id);
+    assert_eq!(certificate.holder, holder);
+    assert_eq!(certificate.issuer, issuer);
+    assert!(client.is_valid(&id));
+    assert_eq!(client.get_issuer(&issuer).unwrap().issued, 1);
+    assert_eq!(client.get_certificates(&holder, &0, &10).len(), 1);
+}
+
+#[test]
+fn test_expired_revoked_and_removed_issuer_certificates_are_not_counted() {
+    let env = Env::default();
+    let (client, admin, issuer) = setup(&env);
+    let holder = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[2; 32]);
+
+    let other_issuer = Address::generate(&env);
+    client.add_issuer(&admin, &other_issuer, &String::from_str(&env, "Assessor"));
+
+    let permanent = client.issue_certificate(&issuer, &holder, &symbol_short!("rust"), &hash, &0);
+    let expiring =
+        client.issue_certificate(&issuer, &holder, &symbol_short!("design"), &hash, &2_000);
+    let revoked = client.issue_certificate(&issuer, &holder, &symbol_short!("sql"), &hash, &0);
+    client.issue_certificate(&other_issuer, &holder, &symbol_short!("qa"), &hash, &0);
+    assert_eq!(client.count_valid_certificates(&holder), 4);
+
+    assert_eq!(
+        client.try_revoke_certificate(&other_issuer, &revoked),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.revoke_certificate(&issuer, &revoked);
+    assert_eq!(
+        client.try_revoke_certificate(&admin, &revoked),
+        Err(Ok(Error::AlreadyRevoked))
+    );
+    assert!(!client.is_valid(&revoked));
+    assert_eq!(client.count_valid_certificates(&holder), 3);
+
+    env.ledger().with_mut(|l| l.timestamp = 2_000);
+    assert!(!client.is_valid(&expiring));
+    assert!(!client.has_valid_certificate(&holder, &symbol_short!("design")));
+    assert_eq!(client.count_valid_certificates(&holder), 2);
+
+    client.remove_issuer(&admin, &other_issuer);
+    assert!(!client.is_approved_issuer(&other_issuer));
+    assert_eq!(client.count_valid_certificates(&holder), 1);
+    assert!(client.is_valid(&permanent));
+    assert!(client.has_valid_certificate(&holder, &symbol_short!("rust")));
+    assert_eq!(client.get_certificates(&holder, &0, &10).len(), 4);
+}
+
+#[test]
+fn test_pause_blocks_issuing_but_not_revoking() {
+    let env = Env::default();
+    let (client, admin, issuer) = setup(&env);
+    let holder = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[1; 32]);
+    let id = client.issue_certificate(&issuer, &holder, &symbol_short!("rust"), &hash, &0);
+
+    client.pause(&admin);
+    assert_eq!(
+        client.try_issue_certificate(&issuer, &holder, &symbol_short!("sql"), &hash, &0),
+        Err(Ok(Error::ContractPaused))
+    );
+    client.revoke_certificate(&issuer, &id);
+    assert!(!client.is_valid(&id));
+}
@@ -0,0 +1,28 @@
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol};
+
+/// Organisation allowed to certify skills, e.g. a bootcamp or assessment partner
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Issuer {
+    pub address: Address,
+    pub name: String,
+    pub approved_at: u64,
+    /// Cleared when the admin removes the issuer; its certificates stop counting as valid
+    pub active: bool,
+    pub issued: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Certificate {
+    pub id: u64,
+    pub issuer: Address,
+    pub holder: Address,
+    pub skill: Symbol,
+    /// Hash of the off-chain credential (assessment result, transcript, ...)
+    pub credential_hash: BytesN<32>,
+    pub issued_at: u64,
+    /// 0 means the certificate never expires
+    pub expires_at: u64,
+    pub revoked: bool,
+}
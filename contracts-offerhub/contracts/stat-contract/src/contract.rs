@@ -1,16 +1,15 @@
 
-use soroban_sdk::{Address, Env, Symbol};
-use crate::storage::{ContractStats};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+use crate::storage::{ContractStats, EpochStats};
 use crate::error::Error;
 use crate::types::{DataKey};
-use escrow_contract;
-
-use rating_contract;
-
-use user_registry_contract;
 
 pub struct StatContract;
 
+// Metrics with a dedicated roll-up; any other metric is only totalled
+const METRIC_VOLUME: Symbol = symbol_short!("volume");
+const METRIC_DISPUTE: Symbol = symbol_short!("dispute");
+
 impl StatContract {
 
     pub fn initialize(env: Env, user_registry_id: Address, rating_contract_id: Address, escrow_id: Address, dispute_id: Address, fee_manager_id: Address) {
@@ -80,4 +79,146 @@ impl StatContract {
             total_fees_collected,
         })
     }
+
+    pub fn init_reporting(env: Env, admin: Address, epoch_length: u64) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+        if epoch_length == 0 {
+            return Err(Error::InvalidEpochLength);
+        }
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::EpochLength, &epoch_length);
+        env.events().publish(
+            (Symbol::new(&env, "reporting_initialized"),),
+            (admin, epoch_length, env.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    pub fn set_reporter(
+        env: Env,
+        admin: Address,
+        reporter: Address,
+        authorized: bool,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let key = DataKey::Reporter(reporter.clone());
+        if authorized {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events().publish(
+            (Symbol::new(&env, "reporter_set"), reporter),
+            (authorized, env.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    pub fn is_reporter(env: Env, reporter: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Reporter(reporter))
+    }
+
+    pub fn report(
+        env: Env,
+        reporter: Address,
+        metric: Symbol,
+        value: i128,
+        subject: Address,
+    ) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        reporter.require_auth();
+        if !Self::is_reporter(env.clone(), reporter.clone()) {
+            return Err(Error::ReporterNotAuthorized);
+        }
+
+        let epoch = Self::current_epoch(env.clone())?;
+        let mut stats = Self::get_epoch_stats(env.clone(), epoch);
+        stats.reports += 1;
+
+        if metric == METRIC_VOLUME {
+            stats.volume += value;
+        } else if metric == METRIC_DISPUTE {
+            stats.disputes += 1;
+        }
+
+        let active_key = DataKey::ActiveUser(epoch, subject.clone());
+        if !env.storage().persistent().has(&active_key) {
+            env.storage().persistent().set(&active_key, &true);
+            stats.active_users += 1;
+        }
+
+        let metric_key = DataKey::EpochMetric(epoch, metric.clone());
+        let total: i128 = env.storage().persistent().get(&metric_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&metric_key, &(total + value));
+        env.storage()
+            .persistent()
+            .set(&DataKey::EpochStats(epoch), &stats);
+
+        env.events().publish(
+            (Symbol::new(&env, "activity_reported"), metric),
+            (reporter, subject, value, epoch),
+        );
+        Ok(())
+    }
+
+    pub fn current_epoch(env: Env) -> Result<u64, Error> {
+        let epoch_length: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::EpochLength)
+            .ok_or(Error::NotInitialized)?;
+        Ok(env.ledger().timestamp() / epoch_length)
+    }
+
+    pub fn get_epoch_stats(env: Env, epoch: u64) -> EpochStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EpochStats(epoch))
+            .unwrap_or(EpochStats {
+                epoch,
+                volume: 0,
+                active_users: 0,
+                disputes: 0,
+                reports: 0,
+            })
+    }
+
+    pub fn get_epoch_metric(env: Env, epoch: u64, metric: Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EpochMetric(epoch, metric))
+            .unwrap_or(0)
+    }
+
+    // The reporting admin administers pausing
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        if stored != *admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+        Ok(())
+    }
 }
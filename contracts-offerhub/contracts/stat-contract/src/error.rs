@@ -1,3 +1,4 @@
+use offerhub_pausable::PauseError;
 use soroban_sdk::{contracterror};
 
 #[contracterror]
@@ -5,5 +6,21 @@ use soroban_sdk::{contracterror};
 #[repr(u32)]
 pub enum Error {
     AlreadyInitialized = 1,
+    Unauthorized = 2,
+    InvalidEpochLength = 3,
+    ReporterNotAuthorized = 4,
     NotInitialized = 5,
+    AlreadyPaused = 6,
+    NotPaused = 7,
+    ContractPaused = 8,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
 }
\ No newline at end of file
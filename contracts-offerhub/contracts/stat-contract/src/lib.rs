@@ -1,8 +1,8 @@
 #![no_std]
 pub use crate::contract::StatContract;
-use crate::storage::ContractStats;
+use crate::storage::{ContractStats, EpochStats};
 use crate::error::Error;
-use soroban_sdk::{contract, contractimpl, Address, Env};
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
 
 mod contract;
 mod storage;
@@ -15,6 +15,10 @@ mod test;
 #[contract]
 pub struct StatisticsContract;
 
+offerhub_pausable::impl_pausable!(StatisticsContract, Error, |env, _| {
+    StatContract::pause_admin(env)
+});
+
 #[contractimpl]
 impl StatisticsContract {
     pub fn initialize(
@@ -37,4 +41,43 @@ impl StatisticsContract {
     pub fn get_contract_stats(env: Env) -> Result<ContractStats, Error> {
         StatContract::get_contract_stats(env)
     }
+
+    pub fn init_reporting(env: Env, admin: Address, epoch_length: u64) -> Result<(), Error> {
+        StatContract::init_reporting(env, admin, epoch_length)
+    }
+
+    pub fn set_reporter(
+        env: Env,
+        admin: Address,
+        reporter: Address,
+        authorized: bool,
+    ) -> Result<(), Error> {
+        StatContract::set_reporter(env, admin, reporter, authorized)
+    }
+
+    pub fn is_reporter(env: Env, reporter: Address) -> bool {
+        StatContract::is_reporter(env, reporter)
+    }
+
+    pub fn report(
+        env: Env,
+        reporter: Address,
+        metric: Symbol,
+        value: i128,
+        subject: Address,
+    ) -> Result<(), Error> {
+        StatContract::report(env, reporter, metric, value, subject)
+    }
+
+    pub fn current_epoch(env: Env) -> Result<u64, Error> {
+        StatContract::current_epoch(env)
+    }
+
+    pub fn get_epoch_stats(env: Env, epoch: u64) -> EpochStats {
+        StatContract::get_epoch_stats(env, epoch)
+    }
+
+    pub fn get_epoch_metric(env: Env, epoch: u64, metric: Symbol) -> i128 {
+        StatContract::get_epoch_metric(env, epoch, metric)
+    }
 }
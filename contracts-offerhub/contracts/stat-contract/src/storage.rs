@@ -9,3 +9,13 @@ pub struct ContractStats {
     pub total_disputes: u64,
     pub total_fees_collected: i128,
 }
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EpochStats {
+    pub epoch: u64,
+    pub volume: i128,
+    pub active_users: u32,
+    pub disputes: u32,
+    pub reports: u32,
+}
@@ -2,7 +2,8 @@
 use super::*;
 use soroban_sdk::{contract, contractimpl};
 use soroban_sdk::{
-    testutils::{Address as _},
+    symbol_short,
+    testutils::{Address as _, Ledger},
     Address, Env, String, Vec,
 };
 
@@ -73,7 +74,7 @@ fn test_get_contract_stats() {
     let metadata = String::from_str(&env, "Bulk verified");
     let expires_at = env.ledger().timestamp() + 365 * 24 * 60 * 60; // 1 year
 
-    let _ = user_registry_client.bulk_verify_users(
+    user_registry_client.bulk_verify_users(
         &admin.clone(),
         &users,
         &user_registry_contract::types::VerificationLevel::Premium,
@@ -199,13 +200,13 @@ fn test_get_contract_stats() {
 
     // Add user1 as premium to test zero fees
     fee_manager_client.add_premium_user(&user1);
-    fee_manager_client.collect_fee(&1000000i128, &1, &user1); // 0 fee (premium)
+    fee_manager_client.collect_fee(&admin, &1000000i128, &1, &user1); // 0 fee (premium)
 
     // Collect dispute fee for user2 (non-premium)
-    fee_manager_client.collect_fee(&2000000i128, &2, &user2); // 100,000 fee (5%)
+    fee_manager_client.collect_fee(&admin, &2000000i128, &2, &user2); // 100,000 fee (5%)
 
     // Collect dispute fee for user2 (non-premium)
-    fee_manager_client.collect_fee(&2000000i128, &1, &user2); // 100,000 fee (5%)
+    fee_manager_client.collect_fee(&admin, &2000000i128, &1, &user2); // 100,000 fee (5%)
 
     let fees_after_second_non_premium = fee_manager_client.get_total_fees();
     assert_eq!(fees_after_second_non_premium, 150_000);
@@ -220,3 +221,88 @@ fn test_get_contract_stats() {
     assert_eq!(stats.total_disputes, 2);
     assert_eq!(stats.total_fees_collected, 150_000);
 }
+
+#[test]
+fn test_report_rolls_up_metrics_per_epoch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    let stats_contract_id = env.register(StatisticsContract, ());
+    let stats_client = StatisticsContractClient::new(&env, &stats_contract_id);
+
+    let admin = Address::generate(&env);
+    let escrow = Address::generate(&env);
+    let rating = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+
+    stats_client.init_reporting(&admin, &86_400);
+    stats_client.set_reporter(&admin, &escrow, &true);
+    stats_client.set_reporter(&admin, &rating, &true);
+    assert!(stats_client.is_reporter(&escrow));
+
+    // Only authorized contracts can report
+    assert_eq!(
+        stats_client.try_report(&outsider, &symbol_short!("volume"), &100, &user1),
+        Err(Ok(crate::error::Error::ReporterNotAuthorized))
+    );
+
+    stats_client.report(&escrow, &symbol_short!("volume"), &500, &user1);
+    stats_client.report(&escrow, &symbol_short!("volume"), &300, &user2);
+    stats_client.report(&escrow, &symbol_short!("dispute"), &1, &user1);
+    stats_client.report(&rating, &symbol_short!("rating"), &5, &user2);
+
+    let epoch = stats_client.current_epoch();
+    assert_eq!(epoch, 0);
+    let stats = stats_client.get_epoch_stats(&epoch);
+    assert_eq!(stats.volume, 800);
+    assert_eq!(stats.active_users, 2);
+    assert_eq!(stats.disputes, 1);
+    assert_eq!(stats.reports, 4);
+    assert_eq!(
+        stats_client.get_epoch_metric(&epoch, &symbol_short!("rating")),
+        5
+    );
+
+    // A new epoch starts from an empty roll-up
+    env.ledger().with_mut(|l| l.timestamp = 86_400 + 1_000);
+    stats_client.report(&escrow, &symbol_short!("volume"), &50, &user1);
+    assert_eq!(stats_client.current_epoch(), 1);
+    let stats = stats_client.get_epoch_stats(&1);
+    assert_eq!(stats.volume, 50);
+    assert_eq!(stats.active_users, 1);
+    assert_eq!(stats_client.get_epoch_stats(&0).volume, 800);
+
+    // Revoked reporters are rejected
+    stats_client.set_reporter(&admin, &rating, &false);
+    assert_eq!(
+        stats_client.try_report(&rating, &symbol_short!("rating"), &4, &user1),
+        Err(Ok(crate::error::Error::ReporterNotAuthorized))
+    );
+}
+
+#[test]
+fn test_pause_blocks_reports() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let stats_contract_id = env.register(StatisticsContract, ());
+    let stats_client = StatisticsContractClient::new(&env, &stats_contract_id);
+    let admin = Address::generate(&env);
+    let escrow = Address::generate(&env);
+    let user = Address::generate(&env);
+    stats_client.init_reporting(&admin, &86_400);
+    stats_client.set_reporter(&admin, &escrow, &true);
+
+    stats_client.pause(&admin);
+    assert_eq!(
+        stats_client.try_report(&escrow, &symbol_short!("volume"), &100, &user),
+        Err(Ok(crate::error::Error::ContractPaused))
+    );
+
+    stats_client.unpause(&admin);
+    stats_client.report(&escrow, &symbol_short!("volume"), &100, &user);
+    assert_eq!(stats_client.get_epoch_stats(&0).volume, 100);
+}
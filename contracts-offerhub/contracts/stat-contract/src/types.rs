@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype};
+use soroban_sdk::{contracterror, contracttype, Address, Symbol};
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -16,4 +16,10 @@ pub enum DataKey {
     EscrowContract,
     DisputeContract,
     FeeManagerContract,
+    Admin,
+    EpochLength,
+    Reporter(Address),
+    EpochStats(u64),
+    EpochMetric(u64, Symbol),
+    ActiveUser(u64, Address),
 }
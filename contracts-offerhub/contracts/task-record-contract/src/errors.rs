@@ -1,3 +1,4 @@
+use offerhub_pausable::PauseError;
 use soroban_sdk::contracterror;
 
 #[contracterror]
@@ -6,5 +7,18 @@ use soroban_sdk::contracterror;
 pub enum Errors {
    InvalidInput = 0 ,
    AlreadyInitialized =1 ,
-   Unauthorized =2
+   Unauthorized =2,
+   AlreadyPaused = 3,
+   NotPaused = 4,
+   ContractPaused = 5,
+}
+
+impl From<PauseError> for Errors {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Errors::Unauthorized,
+            PauseError::AlreadyPaused => Errors::AlreadyPaused,
+            PauseError::NotPaused => Errors::NotPaused,
+        }
+    }
 }
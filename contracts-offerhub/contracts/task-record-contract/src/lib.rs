@@ -28,6 +28,10 @@ pub enum DataKey {
 #[contract]
 pub struct TaskRecordContract;
 
+offerhub_pausable::impl_pausable!(TaskRecordContract, Errors, |env, _| {
+    TaskRecordContract::pause_admin(env)
+});
+
 #[contractimpl]
 impl TaskRecordContract {
 
@@ -52,7 +56,10 @@ impl TaskRecordContract {
         client_id: String,
         completed: bool,
     ) -> Result<u64, Errors> {
-    
+        if offerhub_pausable::is_paused(&env) {
+            return Err(Errors::ContractPaused);
+        }
+
         let admin: Address = match env.storage().instance().get(&DataKey::Admin){
               Some(x) => x , 
               None => return Err(Errors::Unauthorized)
@@ -97,6 +104,10 @@ impl TaskRecordContract {
        Ok(task_counter)
     }
 
+    fn pause_admin(env: &Env) -> Result<Address, Errors> {
+        env.storage().instance().get(&DataKey::Admin).ok_or(Errors::Unauthorized)
+    }
+
     pub fn get_tasks_for_freelancer(env: Env, freelancer_id: String) -> Vec<TaskRecord> {
         let task_ids: Vec<u64> = env.storage().persistent()
             .get(&DataKey::FreelancerTasks(freelancer_id))
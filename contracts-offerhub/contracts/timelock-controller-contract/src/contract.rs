@@ -0,0 +1,191 @@
+use crate::error::Error;
+use crate::event::{canceled, delay_updated, executed, initialized, proposer_updated, scheduled};
+use crate::storage::{
+    get_min_delay, get_operation, get_operation_count, get_proposers, next_operation_id,
+    set_min_delay, set_operation, set_proposers,
+};
+use crate::types::{Operation, OperationStatus, TimelockAction};
+use soroban_sdk::{Address, Env, IntoVal, Val, Vec};
+
+const MAX_PAGE_SIZE: u32 = 50;
+
+pub struct TimelockControllerContract;
+
+impl TimelockControllerContract {
+    /// Install the minimum delay and the accounts allowed to schedule. Runs as the
+    /// constructor, so only the deployer sets them, in the deploying transaction.
+    pub fn initialize(env: Env, min_delay: u64, proposers: Vec<Address>) -> Result<(), Error> {
+        if get_min_delay(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+
+        set_min_delay(&env, min_delay);
+        set_proposers(&env, &proposers);
+        initialized(&env, min_delay, proposers);
+        Ok(())
+    }
+
+    /// Schedule an action to become executable after `delay` seconds, which must be at
+    /// least the minimum delay. Changes to the timelock itself go through here too.
+    pub fn schedule(
+        env: Env,
+        proposer: Address,
+        action: TimelockAction,
+        delay: u64,
+    ) -> Result<u64, Error> {
+        Self::require_proposer(&env, &proposer)?;
+        let min_delay = get_min_delay(&env).ok_or(Error::NotInitialized)?;
+        if delay < min_delay {
+            return Err(Error::DelayTooShort);
+        }
+
+        let now = env.ledger().timestamp();
+        let operation = Operation {
+            id: next_operation_id(&env),
+            proposer: proposer.clone(),
+            action: action.clone(),
+            scheduled_at: now,
+            ready_at: now + delay,
+            status: OperationStatus::Pending,
+        };
+        set_operation(&env, &operation);
+
+        scheduled(&env, proposer, operation.id, action, operation.ready_at);
+        Ok(operation.id)
+    }
+
+    /// Execute a matured operation. Anyone can execute; targets see this contract as
+    /// the caller, so it must be their admin. While paused only changes to the timelock
+    /// itself execute.
+    pub fn execute(env: Env, executor: Address, id: u64) -> Result<Val, Error> {
+        executor.require_auth();
+        let mut operation = Self::pending_operation(&env, id)?;
+        if env.ledger().timestamp() < operation.ready_at {
+            return Err(Error::OperationNotReady);
+        }
+
+        operation.status = OperationStatus::Executed;
+        set_operation(&env, &operation);
+
+        let result = match operation.action {
+            TimelockAction::Call(target, function, args) => {
+                Self::require_not_paused(&env)?;
+                env.invoke_contract::<Val>(&target, &function, args)
+            }
+            TimelockAction::UpdateDelay(min_delay) => {
+                set_min_delay(&env, min_delay);
+                delay_updated(&env, min_delay);
+                ().into_val(&env)
+            }
+            TimelockAction::GrantProposer(proposer) => {
+                let mut proposers = get_proposers(&env);
+                if proposers.contains(&proposer) {
+                    return Err(Error::ProposerExists);
+                }
+                proposers.push_back(proposer.clone());
+                set_proposers(&env, &proposers);
+                proposer_updated(&env, proposer, true);
+                ().into_val(&env)
+            }
+            TimelockAction::RevokeProposer(proposer) => {
+                let mut proposers = get_proposers(&env);
+                let index = proposers
+                    .first_index_of(&proposer)
+                    .ok_or(Error::ProposerNotFound)?;
+                proposers.remove(index);
+                set_proposers(&env, &proposers);
+                proposer_updated(&env, proposer, false);
+                ().into_val(&env)
+            }
+        };
+
+        executed(&env, executor, id);
+        Ok(result)
+    }
+
+    /// Cancel a pending operation (proposers only)
+    pub fn cancel(env: Env, proposer: Address, id: u64) -> Result<(), Error> {
+        Self::require_proposer(&env, &proposer)?;
+        let mut operation = Self::pending_operation(&env, id)?;
+
+        operation.status = OperationStatus::Canceled;
+        set_operation(&env, &operation);
+
+        canceled(&env, proposer, id);
+        Ok(())
+    }
+
+    pub fn get_operation(env: Env, id: u64) -> Option<Operation> {
+        get_operation(&env, id)
+    }
+
+    /// Whether an operation is pending and its delay has elapsed
+    pub fn is_ready(env: Env, id: u64) -> bool {
+        get_operation(&env, id).is_some_and(|operation| {
+            operation.status == OperationStatus::Pending
+                && env.ledger().timestamp() >= operation.ready_at
+        })
+    }
+
+    /// Page through all operations, oldest first
+    pub fn get_operations(env: Env, offset: u32, limit: u32) -> Vec<Operation> {
+        let count = get_operation_count(&env);
+        let start = offset as u64 + 1;
+        let end = (offset as u64 + limit.min(MAX_PAGE_SIZE) as u64).min(count);
+
+        let mut operations = Vec::new(&env);
+        for id in start..=end {
+            if let Some(operation) = get_operation(&env, id) {
+                operations.push_back(operation);
+            }
+        }
+        operations
+    }
+
+    pub fn get_min_delay(env: Env) -> u64 {
+        get_min_delay(&env).unwrap_or(0)
+    }
+
+    pub fn get_proposers(env: Env) -> Vec<Address> {
+        get_proposers(&env)
+    }
+
+    fn pending_operation(env: &Env, id: u64) -> Result<Operation, Error> {
+        let operation = get_operation(env, id).ok_or(Error::OperationNotFound)?;
+        if operation.status != OperationStatus::Pending {
+            return Err(Error::OperationNotPending);
+        }
+        Ok(operation)
+    }
+
+    fn require_proposer(env: &Env, proposer: &Address) -> Result<(), Error> {
+        proposer.require_auth();
+        if get_min_delay(env).is_none() {
+            return Err(Error::NotInitialized);
+        }
+        if !get_proposers(env).contains(proposer) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Each proposer acts as the pause admin for its own calls. Pausing only holds back
+    /// scheduled external calls, so it does not wait for the delay. Anyone else is
+    /// checked against the timelock's own address, which leaves just the PAUSER role.
+    pub(crate) fn pause_admin(env: &Env, caller: &Address) -> Result<Address, Error> {
+        if get_min_delay(env).is_none() {
+            return Err(Error::NotInitialized);
+        }
+        if get_proposers(env).contains(caller) {
+            return Ok(caller.clone());
+        }
+        Ok(env.current_contract_address())
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
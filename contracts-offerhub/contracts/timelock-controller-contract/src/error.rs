@@ -0,0 +1,42 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not a proposer
+    Unauthorized = 3,
+    /// Delay is below the minimum delay
+    DelayTooShort = 4,
+    /// Operation not found
+    OperationNotFound = 5,
+    /// Operation has already been executed or canceled
+    OperationNotPending = 6,
+    /// Operation delay has not elapsed yet
+    OperationNotReady = 7,
+    /// Address is already a proposer
+    ProposerExists = 8,
+    /// Address is not a proposer
+    ProposerNotFound = 9,
+    /// Contract is already paused
+    AlreadyPaused = 10,
+    /// Contract is not paused
+    NotPaused = 11,
+    /// Contract is paused
+    ContractPaused = 12,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::types::TimelockAction;
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+pub fn initialized(e: &Env, min_delay: u64, proposers: Vec<Address>) {
+    let topics = (Symbol::new(e, "timelock_initialized"),);
+    e.events().publish(topics, (min_delay, proposers));
+}
+
+pub fn scheduled(e: &Env, proposer: Address, id: u64, action: TimelockAction, ready_at: u64) {
+    let topics = (Symbol::new(e, "operation_scheduled"), proposer);
+    e.events().publish(topics, (id, action, ready_at));
+}
+
+pub fn executed(e: &Env, executor: Address, id: u64) {
+    let topics = (Symbol::new(e, "operation_executed"), executor);
+    e.events().publish(topics, id);
+}
+
+pub fn canceled(e: &Env, proposer: Address, id: u64) {
+    let topics = (Symbol::new(e, "operation_canceled"), proposer);
+    e.events().publish(topics, id);
+}
+
+pub fn delay_updated(e: &Env, min_delay: u64) {
+    let topics = (Symbol::new(e, "min_delay_updated"),);
+    e.events().publish(topics, min_delay);
+}
+
+pub fn proposer_updated(e: &Env, proposer: Address, granted: bool) {
+    let topics = (Symbol::new(e, "proposer_updated"), proposer);
+    e.events().publish(topics, granted);
+}
@@ -0,0 +1,76 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::TimelockControllerContract;
+pub use crate::error::Error;
+pub use crate::types::{Operation, OperationStatus, TimelockAction};
+use soroban_sdk::{contract, contractimpl, panic_with_error, Address, Env, Val, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, TimelockControllerContract::pause_admin);
+
+#[contractimpl]
+impl Contract {
+    /// Set up the timelock with its minimum delay and proposers at deploy
+    pub fn __constructor(env: Env, min_delay: u64, proposers: Vec<Address>) {
+        if let Err(error) =
+            TimelockControllerContract::initialize(env.clone(), min_delay, proposers)
+        {
+            panic_with_error!(&env, error);
+        }
+    }
+
+    /// Schedule an action after a delay and return the operation id (proposers only)
+    pub fn schedule(
+        env: Env,
+        proposer: Address,
+        action: TimelockAction,
+        delay: u64,
+    ) -> Result<u64, Error> {
+        TimelockControllerContract::schedule(env, proposer, action, delay)
+    }
+
+    /// Execute a matured operation; callable by anyone
+    pub fn execute(env: Env, executor: Address, id: u64) -> Result<Val, Error> {
+        TimelockControllerContract::execute(env, executor, id)
+    }
+
+    /// Cancel a pending operation (proposers only)
+    pub fn cancel(env: Env, proposer: Address, id: u64) -> Result<(), Error> {
+        TimelockControllerContract::cancel(env, proposer, id)
+    }
+
+    /// Get an operation by id
+    pub fn get_operation(env: Env, id: u64) -> Option<Operation> {
+        TimelockControllerContract::get_operation(env, id)
+    }
+
+    /// Check whether an operation can be executed now
+    pub fn is_ready(env: Env, id: u64) -> bool {
+        TimelockControllerContract::is_ready(env, id)
+    }
+
+    /// Page through all scheduled operations
+    pub fn get_operations(env: Env, offset: u32, limit: u32) -> Vec<Operation> {
+        TimelockControllerContract::get_operations(env, offset, limit)
+    }
+
+    /// Get the minimum delay in seconds
+    pub fn get_min_delay(env: Env) -> u64 {
+        TimelockControllerContract::get_min_delay(env)
+    }
+
+    /// Get the accounts allowed to schedule and cancel
+    pub fn get_proposers(env: Env) -> Vec<Address> {
+        TimelockControllerContract::get_proposers(env)
+    }
+}
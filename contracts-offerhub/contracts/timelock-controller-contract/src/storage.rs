@@ -0,0 +1,54 @@
+use crate::types::Operation;
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    MinDelay,
+    Proposers,
+    OperationCount,
+    Operation(u64),
+}
+
+pub fn get_min_delay(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&DataKey::MinDelay)
+}
+
+pub fn set_min_delay(env: &Env, delay: u64) {
+    env.storage().instance().set(&DataKey::MinDelay, &delay);
+}
+
+pub fn get_proposers(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Proposers)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_proposers(env: &Env, proposers: &Vec<Address>) {
+    env.storage().instance().set(&DataKey::Proposers, proposers);
+}
+
+pub fn get_operation_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::OperationCount)
+        .unwrap_or(0)
+}
+
+/// Allocate the next operation id, starting at 1
+pub fn next_operation_id(env: &Env) -> u64 {
+    let id = get_operation_count(env) + 1;
+    env.storage().instance().set(&DataKey::OperationCount, &id);
+    id
+}
+
+pub fn get_operation(env: &Env, id: u64) -> Option<Operation> {
+    env.storage().persistent().get(&DataKey::Operation(id))
+}
+
+pub fn set_operation(env: &Env, operation: &Operation) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Operation(operation.id), operation);
+}
@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, symbol_short, vec, Address, Env, IntoVal, Symbol};
+
+const MIN_DELAY: u64 = 2 * 24 * 60 * 60;
+
+// Parameter owner that has handed its admin role to the timelock
+#[contract]
+pub struct MockManaged;
+
+#[contractimpl]
+impl MockManaged {
+    pub fn init(env: Env, admin: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("ADMIN"), &admin);
+    }
+
+    pub fn set_fee_rate(env: Env, admin: Address, rate: u32) {
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("ADMIN"))
+            .unwrap();
+        assert_eq!(stored, admin);
+        admin.require_auth();
+        env.storage().instance().set(&symbol_short!("RATE"), &rate);
+    }
+
+    pub fn get_fee_rate(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("RATE"))
+            .unwrap_or(0)
+    }
+}
+
+fn setup(env: &Env) -> (ContractClient<'_>, Address) {
+    env.mock_all_auths();
+    let proposer = Address::generate(env);
+    let client = ContractClient::new(
+        env,
+        &env.register(Contract, (MIN_DELAY, vec![env, proposer.clone()])),
+    );
+    (client, proposer)
+}
+
+#[test]
+fn test_scheduled_call_executes_after_delay() {
+    let env = Env::default();
+    let (client, proposer) = setup(&env);
+    let managed_id = env.register(MockManaged, ());
+    let managed = MockManagedClient::new(&env, &managed_id);
+    managed.init(&client.address);
+
+    let action = TimelockAction::Call(
+        managed_id.clone(),
+        Symbol::new(&env, "set_fee_rate"),
+        vec![&env, client.address.into_val(&env), 300u32.into_val(&env)],
+    );
+    assert_eq!(
+        client.try_schedule(&proposer, &action, &(MIN_DELAY - 1)),
+        Err(Ok(Error::DelayTooShort))
+    );
+    assert_eq!(
+        client.try_schedule(&Address::generate(&env), &action, &MIN_DELAY),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let id = client.schedule(&proposer, &action, &MIN_DELAY);
+    assert_eq!(client.get_operations(&0, &10).len(), 1);
+    assert!(!client.is_ready(&id));
+
+    // Anyone can execute once the delay has passed
+    let executor = Address::generate(&env);
+    assert!(matches!(
+        client.try_execute(&executor, &id),
+        Err(Ok(Error::OperationNotReady))
+    ));
+    env.ledger().with_mut(|l| l.timestamp += MIN_DELAY);
+    assert!(client.is_ready(&id));
+    client.execute(&executor, &id);
+    assert_eq!(managed.get_fee_rate(), 300);
+    assert_eq!(
+        client.get_operation(&id).unwrap().status,
+        OperationStatus::Executed
+    );
+    assert!(matches!(
+        client.try_execute(&executor, &id),
+        Err(Ok(Error::OperationNotPending))
+    ));
+}
+
+#[test]
+fn test_timelock_changes_itself_through_the_delay() {
+    let env = Env::default();
+    let (client, proposer) = setup(&env);
+    let newcomer = Address::generate(&env);
+
+    let grant = client.schedule(
+        &proposer,
+        &TimelockAction::GrantProposer(newcomer.clone()),
+        &MIN_DELAY,
+    );
+    let shorten = client.schedule(&proposer, &TimelockAction::UpdateDelay(3_600), &MIN_DELAY);
+    client.cancel(&proposer, &shorten);
+    assert_eq!(
+        client.get_operation(&shorten).unwrap().status,
+        OperationStatus::Canceled
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += MIN_DELAY);
+    client.execute(&proposer, &grant);
+    assert!(client.get_proposers().contains(&newcomer));
+    assert!(matches!(
+        client.try_execute(&proposer, &shorten),
+        Err(Ok(Error::OperationNotPending))
+    ));
+    assert_eq!(client.get_min_delay(), MIN_DELAY);
+}
+
+#[test]
+fn test_pause_holds_back_scheduled_calls() {
+    let env = Env::default();
+    let (client, proposer) = setup(&env);
+    let managed_id = env.register(MockManaged, ());
+    let managed = MockManagedClient::new(&env, &managed_id);
+    managed.init(&client.address);
+    let guardian = Address::generate(&env);
+
+    let action = TimelockAction::Call(
+        managed_id.clone(),
+        Symbol::new(&env, "set_fee_rate"),
+        vec![&env, client.address.into_val(&env), 300u32.into_val(&env)],
+    );
+    let id = client.schedule(&proposer, &action, &MIN_DELAY);
+    let newcomer = Address::generate(&env);
+    let grant = client.schedule(
+        &proposer,
+        &TimelockAction::GrantProposer(newcomer.clone()),
+        &MIN_DELAY,
+    );
+    env.ledger().with_mut(|l| l.timestamp += MIN_DELAY);
+
+    assert_eq!(client.try_pause(&guardian), Err(Ok(Error::Unauthorized)));
+    client.add_pauser(&proposer, &guardian);
+    client.pause(&guardian);
+    assert!(matches!(
+        client.try_execute(&proposer, &id),
+        Err(Ok(Error::ContractPaused))
+    ));
+    // Changes to the timelock itself still execute
+    client.execute(&proposer, &grant);
+    assert!(client.get_proposers().contains(&newcomer));
+
+    client.unpause(&proposer);
+    client.execute(&proposer, &id);
+    assert_eq!(managed.get_fee_rate(), 300);
+}
@@ -0,0 +1,33 @@
+use soroban_sdk::{contracttype, Address, Symbol, Val, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimelockAction {
+    /// Call `function` on a contract that has this timelock as its admin
+    Call(Address, Symbol, Vec<Val>),
+    /// Change the minimum delay; goes through the timelock like any other change
+    UpdateDelay(u64),
+    GrantProposer(Address),
+    RevokeProposer(Address),
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum OperationStatus {
+    Pending = 0,
+    Executed = 1,
+    Canceled = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Operation {
+    pub id: u64,
+    pub proposer: Address,
+    pub action: TimelockAction,
+    pub scheduled_at: u64,
+    /// Earliest time anyone can execute the operation
+    pub ready_at: u64,
+    pub status: OperationStatus,
+}
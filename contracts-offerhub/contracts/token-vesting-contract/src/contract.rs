@@ -0,0 +1,193 @@
+use crate::error::Error;
+use crate::event::{admin_initialized, grant_created, grant_revoked, tokens_claimed};
+use crate::storage::{
+    add_beneficiary, get_admin, get_beneficiaries, get_grant, get_token, set_admin, set_grant,
+    set_token,
+};
+use crate::types::Grant;
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
+
+const TOKEN_TRANSFER: &str = "transfer";
+
+pub struct TokenVestingContract;
+
+impl TokenVestingContract {
+    pub fn initialize(env: Env, admin: Address, token: Address) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        set_token(&env, &token);
+        admin_initialized(&env, admin, token);
+        Ok(())
+    }
+
+    /// Create a grant funded from the admin's balance. `cliff` and `duration` are
+    /// measured in seconds from `start`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_grant(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        total: i128,
+        start: u64,
+        cliff: u64,
+        duration: u64,
+        revocable: bool,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(&env)?;
+        Self::require_admin(&env, &admin)?;
+        if total <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if duration == 0 || cliff > duration {
+            return Err(Error::InvalidSchedule);
+        }
+        if get_grant(&env, &beneficiary).is_some() {
+            return Err(Error::GrantExists);
+        }
+        let token = get_token(&env).ok_or(Error::NotInitialized)?;
+
+        Self::transfer(&env, &token, &admin, &env.current_contract_address(), total);
+
+        let grant = Grant {
+            beneficiary: beneficiary.clone(),
+            total,
+            start,
+            cliff,
+            duration,
+            claimed: 0,
+            revocable,
+            revoked_at: None,
+        };
+        set_grant(&env, &grant);
+        add_beneficiary(&env, &beneficiary);
+
+        grant_created(&env, grant);
+        Ok(())
+    }
+
+    /// Transfer everything vested so far to the beneficiary
+    pub fn claim(env: Env, beneficiary: Address) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        beneficiary.require_auth();
+
+        let mut grant = get_grant(&env, &beneficiary).ok_or(Error::GrantNotFound)?;
+        let amount = Self::vested_amount(&env, &grant) - grant.claimed;
+        if amount <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+        let token = get_token(&env).ok_or(Error::NotInitialized)?;
+
+        grant.claimed += amount;
+        set_grant(&env, &grant);
+        Self::transfer(
+            &env,
+            &token,
+            &env.current_contract_address(),
+            &beneficiary,
+            amount,
+        );
+
+        tokens_claimed(&env, beneficiary, amount, grant.claimed);
+        Ok(amount)
+    }
+
+    /// Stop a revocable grant. What has vested stays claimable by the beneficiary; the
+    /// unvested remainder goes back to the admin.
+    pub fn revoke(env: Env, admin: Address, beneficiary: Address) -> Result<i128, Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let mut grant = get_grant(&env, &beneficiary).ok_or(Error::GrantNotFound)?;
+        if !grant.revocable {
+            return Err(Error::NotRevocable);
+        }
+        if grant.revoked_at.is_some() {
+            return Err(Error::AlreadyRevoked);
+        }
+        let token = get_token(&env).ok_or(Error::NotInitialized)?;
+
+        let vested = Self::vested_amount(&env, &grant);
+        let returned = grant.total - vested;
+        grant.revoked_at = Some(env.ledger().timestamp());
+        set_grant(&env, &grant);
+
+        if returned > 0 {
+            Self::transfer(
+                &env,
+                &token,
+                &env.current_contract_address(),
+                &admin,
+                returned,
+            );
+        }
+
+        grant_revoked(&env, beneficiary, vested, returned);
+        Ok(returned)
+    }
+
+    /// Amount the beneficiary could claim right now
+    pub fn claimable(env: Env, beneficiary: Address) -> i128 {
+        get_grant(&env, &beneficiary)
+            .map(|grant| Self::vested_amount(&env, &grant) - grant.claimed)
+            .unwrap_or(0)
+    }
+
+    /// Total vested so far, claimed or not
+    pub fn vested(env: Env, beneficiary: Address) -> i128 {
+        get_grant(&env, &beneficiary)
+            .map(|grant| Self::vested_amount(&env, &grant))
+            .unwrap_or(0)
+    }
+
+    pub fn get_grant(env: Env, beneficiary: Address) -> Option<Grant> {
+        get_grant(&env, &beneficiary)
+    }
+
+    pub fn get_beneficiaries(env: Env) -> Vec<Address> {
+        get_beneficiaries(&env)
+    }
+
+    fn vested_amount(env: &Env, grant: &Grant) -> i128 {
+        let now = grant.revoked_at.unwrap_or_else(|| env.ledger().timestamp());
+        if now < grant.start.saturating_add(grant.cliff) {
+            return 0;
+        }
+
+        let elapsed = now - grant.start;
+        if elapsed >= grant.duration {
+            return grant.total;
+        }
+        grant.total * elapsed as i128 / grant.duration as i128
+    }
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+        caller.require_auth();
+        let admin = get_admin(env).ok_or(Error::NotInitialized)?;
+        if *caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn transfer(env: &Env, token: &Address, from: &Address, to: &Address, amount: i128) {
+        env.invoke_contract::<()>(
+            token,
+            &Symbol::new(env, TOKEN_TRANSFER),
+            (from.clone(), to.clone(), amount).into_val(env),
+        );
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
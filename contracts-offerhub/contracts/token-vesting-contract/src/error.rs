@@ -0,0 +1,44 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// Amount must be positive
+    InvalidAmount = 4,
+    /// Duration is zero or the cliff is longer than the schedule
+    InvalidSchedule = 5,
+    /// Beneficiary already has a grant
+    GrantExists = 6,
+    /// Beneficiary has no grant
+    GrantNotFound = 7,
+    /// Grant was created as irrevocable
+    NotRevocable = 8,
+    /// Grant has already been revoked
+    AlreadyRevoked = 9,
+    /// Nothing has vested since the last claim
+    NothingToClaim = 10,
+    /// Contract is already paused
+    AlreadyPaused = 11,
+    /// Contract is not paused
+    NotPaused = 12,
+    /// Contract is paused
+    ContractPaused = 13,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
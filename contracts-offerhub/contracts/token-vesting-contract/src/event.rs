@@ -0,0 +1,22 @@
+use crate::types::Grant;
+use soroban_sdk::{Address, Env, Symbol};
+
+pub fn admin_initialized(e: &Env, admin: Address, token: Address) {
+    let topics = (Symbol::new(e, "admin_initialized"),);
+    e.events().publish(topics, (admin, token));
+}
+
+pub fn grant_created(e: &Env, grant: Grant) {
+    let topics = (Symbol::new(e, "grant_created"), grant.beneficiary.clone());
+    e.events().publish(topics, grant);
+}
+
+pub fn tokens_claimed(e: &Env, beneficiary: Address, amount: i128, claimed: i128) {
+    let topics = (Symbol::new(e, "tokens_claimed"), beneficiary);
+    e.events().publish(topics, (amount, claimed));
+}
+
+pub fn grant_revoked(e: &Env, beneficiary: Address, vested: i128, returned: i128) {
+    let topics = (Symbol::new(e, "grant_revoked"), beneficiary);
+    e.events().publish(topics, (vested, returned));
+}
@@ -0,0 +1,81 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::TokenVestingContract;
+pub use crate::error::Error;
+pub use crate::types::Grant;
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| TokenVestingContract::pause_admin(env));
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the contract with an admin address and the vested token
+    pub fn initialize(env: Env, admin: Address, token: Address) -> Result<(), Error> {
+        TokenVestingContract::initialize(env, admin, token)
+    }
+
+    /// Create a cliff + linear grant funded by the admin (admin only)
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_grant(
+        env: Env,
+        admin: Address,
+        beneficiary: Address,
+        total: i128,
+        start: u64,
+        cliff: u64,
+        duration: u64,
+        revocable: bool,
+    ) -> Result<(), Error> {
+        TokenVestingContract::create_grant(
+            env,
+            admin,
+            beneficiary,
+            total,
+            start,
+            cliff,
+            duration,
+            revocable,
+        )
+    }
+
+    /// Claim everything vested so far and return the amount transferred
+    pub fn claim(env: Env, beneficiary: Address) -> Result<i128, Error> {
+        TokenVestingContract::claim(env, beneficiary)
+    }
+
+    /// Revoke a revocable grant and return the unvested amount sent back (admin only)
+    pub fn revoke(env: Env, admin: Address, beneficiary: Address) -> Result<i128, Error> {
+        TokenVestingContract::revoke(env, admin, beneficiary)
+    }
+
+    /// Get the amount a beneficiary can claim right now
+    pub fn claimable(env: Env, beneficiary: Address) -> i128 {
+        TokenVestingContract::claimable(env, beneficiary)
+    }
+
+    /// Get the total vested so far for a beneficiary
+    pub fn vested(env: Env, beneficiary: Address) -> i128 {
+        TokenVestingContract::vested(env, beneficiary)
+    }
+
+    /// Get a beneficiary's grant
+    pub fn get_grant(env: Env, beneficiary: Address) -> Option<Grant> {
+        TokenVestingContract::get_grant(env, beneficiary)
+    }
+
+    /// Get every address that has been granted tokens
+    pub fn get_beneficiaries(env: Env) -> Vec<Address> {
+        TokenVestingContract::get_beneficiaries(env)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::types::Grant;
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Token,
+    Grant(Address),
+    Beneficiaries,
+}
+
+/// Get the admin address
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+/// Set the admin address
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Token)
+}
+
+pub fn set_token(env: &Env, token: &Address) {
+    env.storage().instance().set(&DataKey::Token, token);
+}
+
+pub fn get_grant(env: &Env, beneficiary: &Address) -> Option<Grant> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Grant(beneficiary.clone()))
+}
+
+pub fn set_grant(env: &Env, grant: &Grant) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Grant(grant.beneficiary.clone()), grant);
+}
+
+pub fn get_beneficiaries(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Beneficiaries)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn add_beneficiary(env: &Env, beneficiary: &Address) {
+    let mut beneficiaries = get_beneficiaries(env);
+    beneficiaries.push_back(beneficiary.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::Beneficiaries, &beneficiaries);
+}
@@ -0,0 +1,148 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+use soroban_sdk::{Address, Env};
+
+const YEAR: u64 = 365 * 24 * 60 * 60;
+
+struct Setup<'a> {
+    vesting: ContractClient<'a>,
+    admin: Address,
+    token: TokenClient<'a>,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let vesting = ContractClient::new(env, &env.register(Contract, ()));
+    let admin = Address::generate(env);
+    StellarAssetClient::new(env, &token).mint(&admin, &10_000);
+    vesting.initialize(&admin, &token);
+    Setup {
+        vesting,
+        admin,
+        token: TokenClient::new(env, &token),
+    }
+}
+
+#[test]
+fn test_cliff_then_linear_vesting() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let member = Address::generate(&env);
+
+    assert_eq!(
+        setup.vesting.try_create_grant(
+            &setup.admin,
+            &member,
+            &4_000,
+            &1_000,
+            &(2 * YEAR),
+            &YEAR,
+            &false
+        ),
+        Err(Ok(Error::InvalidSchedule))
+    );
+    setup.vesting.create_grant(
+        &setup.admin,
+        &member,
+        &4_000,
+        &1_000,
+        &YEAR,
+        &(4 * YEAR),
+        &false,
+    );
+    assert_eq!(setup.token.balance(&setup.vesting.address), 4_000);
+
+    // Nothing is claimable before the cliff
+    env.ledger().with_mut(|l| l.timestamp = 1_000 + YEAR - 1);
+    assert_eq!(setup.vesting.claimable(&member), 0);
+    assert_eq!(
+        setup.vesting.try_claim(&member),
+        Err(Ok(Error::NothingToClaim))
+    );
+
+    // At the cliff a full year's worth vests at once
+    env.ledger().with_mut(|l| l.timestamp = 1_000 + YEAR);
+    assert_eq!(setup.vesting.claim(&member), 1_000);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000 + 3 * YEAR);
+    assert_eq!(setup.vesting.claimable(&member), 2_000);
+    assert_eq!(
+        setup.vesting.try_revoke(&setup.admin, &member),
+        Err(Ok(Error::NotRevocable))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000 + 10 * YEAR);
+    assert_eq!(setup.vesting.claim(&member), 3_000);
+    assert_eq!(setup.token.balance(&member), 4_000);
+    assert_eq!(setup.vesting.get_grant(&member).unwrap().claimed, 4_000);
+}
+
+#[test]
+fn test_revoke_returns_unvested_and_keeps_vested_claimable() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let partner = Address::generate(&env);
+
+    setup.vesting.create_grant(
+        &setup.admin,
+        &partner,
+        &4_000,
+        &1_000,
+        &0,
+        &(4 * YEAR),
+        &true,
+    );
+    assert_eq!(
+        setup
+            .vesting
+            .try_create_grant(&setup.admin, &partner, &1, &1_000, &0, &YEAR, &true),
+        Err(Ok(Error::GrantExists))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000 + YEAR);
+    assert_eq!(setup.vesting.revoke(&setup.admin, &partner), 3_000);
+    assert_eq!(setup.token.balance(&setup.admin), 9_000);
+    assert_eq!(
+        setup.vesting.try_revoke(&setup.admin, &partner),
+        Err(Ok(Error::AlreadyRevoked))
+    );
+
+    // Vesting stops at revocation
+    env.ledger().with_mut(|l| l.timestamp = 1_000 + 3 * YEAR);
+    assert_eq!(setup.vesting.vested(&partner), 1_000);
+    assert_eq!(setup.vesting.claim(&partner), 1_000);
+    assert_eq!(setup.vesting.claimable(&partner), 0);
+}
+
+#[test]
+fn test_pause_blocks_claims() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let member = Address::generate(&env);
+    setup.vesting.create_grant(
+        &setup.admin,
+        &member,
+        &4_000,
+        &1_000,
+        &YEAR,
+        &(4 * YEAR),
+        &false,
+    );
+    env.ledger().with_mut(|l| l.timestamp = 1_000 + YEAR);
+
+    setup.vesting.pause(&setup.admin);
+    assert_eq!(
+        setup.vesting.try_claim(&member),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    setup.vesting.unpause(&setup.admin);
+    assert_eq!(setup.vesting.claim(&member), 1_000);
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Cliff + linear schedule: nothing vests before `start + cliff`, then the grant vests
+/// linearly from `start` until `start + duration`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grant {
+    pub beneficiary: Address,
+    pub total: i128,
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+    pub claimed: i128,
+    pub revocable: bool,
+    /// When the grant was revoked; vesting stops at this time
+    pub revoked_at: Option<u64>,
+}
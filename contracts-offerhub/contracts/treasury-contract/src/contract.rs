@@ -0,0 +1,224 @@
+use crate::error::Error;
+use crate::event::{
+    budget_removed, budget_set, budget_spent, income_recorded, treasury_initialized,
+};
+use crate::storage::{
+    get_balance, get_budget, get_config, get_departments, remove_budget, set_balance, set_budget,
+    set_config, set_departments,
+};
+use crate::types::{Budget, TreasuryConfig};
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Vec};
+
+const TOKEN_TRANSFER: &str = "transfer";
+const TOKEN_BALANCE: &str = "balance";
+
+pub struct TreasuryContract;
+
+impl TreasuryContract {
+    pub fn initialize(env: Env, governance: Address, epoch_length: u64) -> Result<(), Error> {
+        if get_config(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        governance.require_auth();
+        if epoch_length == 0 {
+            return Err(Error::InvalidEpochLength);
+        }
+
+        let config = TreasuryConfig {
+            governance,
+            epoch_length,
+        };
+        set_config(&env, &config);
+        treasury_initialized(&env, config);
+        Ok(())
+    }
+
+    pub fn get_config(env: Env) -> Option<TreasuryConfig> {
+        get_config(&env)
+    }
+
+    /// Record tokens that arrived by plain transfer, e.g. fee-manager withdrawals to the
+    /// treasury. Anyone can sync; returns the newly recorded amount.
+    pub fn sync_income(env: Env, asset: Address) -> Result<i128, Error> {
+        get_config(&env).ok_or(Error::NotInitialized)?;
+
+        let held: i128 = env.invoke_contract(
+            &asset,
+            &Symbol::new(&env, TOKEN_BALANCE),
+            (env.current_contract_address(),).into_val(&env),
+        );
+        let accounted = get_balance(&env, &asset);
+        let income = held - accounted;
+        if income > 0 {
+            set_balance(&env, &asset, held);
+            income_recorded(&env, asset, None, income, held);
+        }
+        Ok(income.max(0))
+    }
+
+    /// Pay tokens into the treasury, recording the sender as the income source
+    pub fn deposit(env: Env, from: Address, asset: Address, amount: i128) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        from.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        get_config(&env).ok_or(Error::NotInitialized)?;
+
+        env.invoke_contract::<()>(
+            &asset,
+            &Symbol::new(&env, TOKEN_TRANSFER),
+            (from.clone(), env.current_contract_address(), amount).into_val(&env),
+        );
+
+        let balance = get_balance(&env, &asset) + amount;
+        set_balance(&env, &asset, balance);
+        income_recorded(&env, asset, Some(from), amount, balance);
+        Ok(balance)
+    }
+
+    /// Create or replace a department's envelope (governance only). Replacing a budget
+    /// keeps what was already spent this epoch.
+    pub fn set_budget(
+        env: Env,
+        department: Symbol,
+        manager: Address,
+        asset: Address,
+        limit_per_epoch: i128,
+    ) -> Result<(), Error> {
+        Self::require_governance(&env)?;
+        if limit_per_epoch <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let epoch = Self::current_epoch(env.clone());
+        let spent = get_budget(&env, &department)
+            .filter(|b| b.epoch == epoch && b.asset == asset)
+            .map(|b| b.spent)
+            .unwrap_or(0);
+
+        let mut departments = get_departments(&env);
+        if !departments.contains(&department) {
+            departments.push_back(department.clone());
+            set_departments(&env, &departments);
+        }
+
+        let budget = Budget {
+            department,
+            manager,
+            asset,
+            limit_per_epoch,
+            epoch,
+            spent,
+        };
+        set_budget(&env, &budget);
+        budget_set(&env, budget);
+        Ok(())
+    }
+
+    /// Close a department's envelope (governance only)
+    pub fn remove_budget(env: Env, department: Symbol) -> Result<(), Error> {
+        Self::require_governance(&env)?;
+        get_budget(&env, &department).ok_or(Error::BudgetNotFound)?;
+
+        let mut departments = get_departments(&env);
+        if let Some(index) = departments.first_index_of(&department) {
+            departments.remove(index);
+            set_departments(&env, &departments);
+        }
+        remove_budget(&env, &department);
+
+        budget_removed(&env, department);
+        Ok(())
+    }
+
+    /// Send tokens out of a department's envelope and return what is left of it this
+    /// epoch. Only the department's manager can spend.
+    pub fn spend(
+        env: Env,
+        manager: Address,
+        department: Symbol,
+        to: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        Self::require_not_paused(&env)?;
+        manager.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let mut budget = Self::budget_for_epoch(&env, &department)?;
+        if budget.manager != manager {
+            return Err(Error::Unauthorized);
+        }
+        if budget.spent + amount > budget.limit_per_epoch {
+            return Err(Error::BudgetExceeded);
+        }
+        let balance = get_balance(&env, &budget.asset);
+        if amount > balance {
+            return Err(Error::InsufficientBalance);
+        }
+
+        budget.spent += amount;
+        set_budget(&env, &budget);
+        set_balance(&env, &budget.asset, balance - amount);
+
+        env.invoke_contract::<()>(
+            &budget.asset,
+            &Symbol::new(&env, TOKEN_TRANSFER),
+            (env.current_contract_address(), to.clone(), amount).into_val(&env),
+        );
+
+        let remaining = budget.limit_per_epoch - budget.spent;
+        budget_spent(&env, department, to, budget.asset, amount, remaining);
+        Ok(remaining)
+    }
+
+    /// Get a department's budget with the spent amount rolled over to the current epoch
+    pub fn get_budget(env: Env, department: Symbol) -> Result<Budget, Error> {
+        Self::budget_for_epoch(&env, &department)
+    }
+
+    pub fn get_departments(env: Env) -> Vec<Symbol> {
+        get_departments(&env)
+    }
+
+    pub fn get_balance(env: Env, asset: Address) -> i128 {
+        get_balance(&env, &asset)
+    }
+
+    pub fn current_epoch(env: Env) -> u64 {
+        get_config(&env)
+            .map(|config| env.ledger().timestamp() / config.epoch_length)
+            .unwrap_or(0)
+    }
+
+    fn budget_for_epoch(env: &Env, department: &Symbol) -> Result<Budget, Error> {
+        let mut budget = get_budget(env, department).ok_or(Error::BudgetNotFound)?;
+        let epoch = Self::current_epoch(env.clone());
+        if budget.epoch != epoch {
+            budget.epoch = epoch;
+            budget.spent = 0;
+        }
+        Ok(budget)
+    }
+
+    fn require_governance(env: &Env) -> Result<(), Error> {
+        let config = get_config(env).ok_or(Error::NotInitialized)?;
+        config.governance.require_auth();
+        Ok(())
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_config(env)
+            .map(|config| config.governance)
+            .ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract has already been initialized
+    AlreadyInitialized = 1,
+    /// Contract has not been initialized
+    NotInitialized = 2,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 3,
+    /// Amount must be positive
+    InvalidAmount = 4,
+    /// Epoch length must be positive
+    InvalidEpochLength = 5,
+    /// Department has no budget
+    BudgetNotFound = 6,
+    /// Transfer would exceed the department's budget for this epoch
+    BudgetExceeded = 7,
+    /// Treasury does not hold enough of the asset
+    InsufficientBalance = 8,
+    /// Contract is already paused
+    AlreadyPaused = 9,
+    /// Contract is not paused
+    NotPaused = 10,
+    /// Contract is paused
+    ContractPaused = 11,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::types::{Budget, TreasuryConfig};
+use soroban_sdk::{Address, Env, Symbol};
+
+pub fn treasury_initialized(e: &Env, config: TreasuryConfig) {
+    let topics = (Symbol::new(e, "treasury_initialized"),);
+    e.events().publish(topics, config);
+}
+
+pub fn income_recorded(
+    e: &Env,
+    asset: Address,
+    source: Option<Address>,
+    amount: i128,
+    balance: i128,
+) {
+    let topics = (Symbol::new(e, "income_recorded"), asset);
+    e.events().publish(topics, (source, amount, balance));
+}
+
+pub fn budget_set(e: &Env, budget: Budget) {
+    let topics = (Symbol::new(e, "budget_set"), budget.department.clone());
+    e.events().publish(topics, budget);
+}
+
+pub fn budget_removed(e: &Env, department: Symbol) {
+    let topics = (Symbol::new(e, "budget_removed"), department);
+    e.events().publish(topics, e.ledger().timestamp());
+}
+
+pub fn budget_spent(
+    e: &Env,
+    department: Symbol,
+    to: Address,
+    asset: Address,
+    amount: i128,
+    remaining: i128,
+) {
+    let topics = (Symbol::new(e, "budget_spent"), department, to);
+    e.events().publish(topics, (asset, amount, remaining));
+}
@@ -0,0 +1,89 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::TreasuryContract;
+pub use crate::error::Error;
+pub use crate::types::{Budget, TreasuryConfig};
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| TreasuryContract::pause_admin(env));
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the treasury with the governance contract and budget epoch length
+    pub fn initialize(env: Env, governance: Address, epoch_length: u64) -> Result<(), Error> {
+        TreasuryContract::initialize(env, governance, epoch_length)
+    }
+
+    /// Get the treasury configuration
+    pub fn get_config(env: Env) -> Option<TreasuryConfig> {
+        TreasuryContract::get_config(env)
+    }
+
+    /// Record tokens received by plain transfer and return the new income
+    pub fn sync_income(env: Env, asset: Address) -> Result<i128, Error> {
+        TreasuryContract::sync_income(env, asset)
+    }
+
+    /// Pay tokens into the treasury and return the new balance
+    pub fn deposit(env: Env, from: Address, asset: Address, amount: i128) -> Result<i128, Error> {
+        TreasuryContract::deposit(env, from, asset, amount)
+    }
+
+    /// Create or replace a department budget (governance only)
+    pub fn set_budget(
+        env: Env,
+        department: Symbol,
+        manager: Address,
+        asset: Address,
+        limit_per_epoch: i128,
+    ) -> Result<(), Error> {
+        TreasuryContract::set_budget(env, department, manager, asset, limit_per_epoch)
+    }
+
+    /// Remove a department budget (governance only)
+    pub fn remove_budget(env: Env, department: Symbol) -> Result<(), Error> {
+        TreasuryContract::remove_budget(env, department)
+    }
+
+    /// Spend from a department budget and return what is left this epoch (manager only)
+    pub fn spend(
+        env: Env,
+        manager: Address,
+        department: Symbol,
+        to: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        TreasuryContract::spend(env, manager, department, to, amount)
+    }
+
+    /// Get a department budget as of the current epoch
+    pub fn get_budget(env: Env, department: Symbol) -> Result<Budget, Error> {
+        TreasuryContract::get_budget(env, department)
+    }
+
+    /// Get every department with a budget
+    pub fn get_departments(env: Env) -> Vec<Symbol> {
+        TreasuryContract::get_departments(env)
+    }
+
+    /// Get the treasury's accounted balance of an asset
+    pub fn get_balance(env: Env, asset: Address) -> i128 {
+        TreasuryContract::get_balance(env, asset)
+    }
+
+    /// Get the current budget epoch
+    pub fn current_epoch(env: Env) -> u64 {
+        TreasuryContract::current_epoch(env)
+    }
+}
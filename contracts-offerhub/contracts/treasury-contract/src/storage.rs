@@ -0,0 +1,64 @@
+use crate::types::{Budget, TreasuryConfig};
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Config,
+    Departments,
+    Budget(Symbol),
+    /// asset -> balance the treasury has accounted for
+    Balance(Address),
+}
+
+pub fn get_config(env: &Env) -> Option<TreasuryConfig> {
+    env.storage().instance().get(&DataKey::Config)
+}
+
+pub fn set_config(env: &Env, config: &TreasuryConfig) {
+    env.storage().instance().set(&DataKey::Config, config);
+}
+
+pub fn get_departments(env: &Env) -> Vec<Symbol> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Departments)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_departments(env: &Env, departments: &Vec<Symbol>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Departments, departments);
+}
+
+pub fn get_budget(env: &Env, department: &Symbol) -> Option<Budget> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Budget(department.clone()))
+}
+
+pub fn set_budget(env: &Env, budget: &Budget) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Budget(budget.department.clone()), budget);
+}
+
+pub fn remove_budget(env: &Env, department: &Symbol) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Budget(department.clone()));
+}
+
+pub fn get_balance(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Balance(asset.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_balance(env: &Env, asset: &Address, balance: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Balance(asset.clone()), &balance);
+}
@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+use soroban_sdk::{symbol_short, Address, Env};
+
+const EPOCH: u64 = 30 * 24 * 60 * 60;
+
+fn setup(env: &Env) -> (ContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = EPOCH);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let treasury = ContractClient::new(env, &env.register(Contract, ()));
+    treasury.initialize(&Address::generate(env), &EPOCH);
+    (treasury, token)
+}
+
+#[test]
+fn test_fee_withdrawals_are_recorded_as_income() {
+    let env = Env::default();
+    let (treasury, token) = setup(&env);
+    let fee_manager = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&fee_manager, &1_000);
+
+    // Fee-manager withdrawals arrive as plain transfers
+    TokenClient::new(&env, &token).transfer(&fee_manager, &treasury.address, &600);
+    assert_eq!(treasury.get_balance(&token), 0);
+    assert_eq!(treasury.sync_income(&token), 600);
+    assert_eq!(treasury.sync_income(&token), 0);
+
+    treasury.deposit(&fee_manager, &token, &400);
+    assert_eq!(treasury.get_balance(&token), 1_000);
+}
+
+#[test]
+fn test_spend_limits_reset_each_epoch() {
+    let env = Env::default();
+    let (treasury, token) = setup(&env);
+    let manager = Address::generate(&env);
+    let vendor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&treasury.address, &1_000);
+    treasury.sync_income(&token);
+
+    let marketing = symbol_short!("marketing");
+    assert_eq!(
+        treasury.try_spend(&manager, &marketing, &vendor, &100),
+        Err(Ok(Error::BudgetNotFound))
+    );
+    treasury.set_budget(&marketing, &manager, &token, &300);
+
+    assert_eq!(treasury.spend(&manager, &marketing, &vendor, &200), 100);
+    assert_eq!(
+        treasury.try_spend(&manager, &marketing, &vendor, &150),
+        Err(Ok(Error::BudgetExceeded))
+    );
+    assert_eq!(
+        treasury.try_spend(&vendor, &marketing, &vendor, &50),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 2 * EPOCH);
+    assert_eq!(treasury.get_budget(&marketing).spent, 0);
+    assert_eq!(treasury.spend(&manager, &marketing, &vendor, &300), 0);
+
+    assert_eq!(TokenClient::new(&env, &token).balance(&vendor), 500);
+    assert_eq!(treasury.get_balance(&token), 500);
+
+    treasury.remove_budget(&marketing);
+    assert!(treasury.get_departments().is_empty());
+}
+
+#[test]
+fn test_pause_blocks_spending() {
+    let env = Env::default();
+    let (treasury, token) = setup(&env);
+    let governance = treasury.get_config().unwrap().governance;
+    let manager = Address::generate(&env);
+    let vendor = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&treasury.address, &1_000);
+    treasury.sync_income(&token);
+    let marketing = symbol_short!("marketing");
+    treasury.set_budget(&marketing, &manager, &token, &300);
+
+    assert_eq!(treasury.try_pause(&manager), Err(Ok(Error::Unauthorized)));
+    treasury.pause(&governance);
+    assert_eq!(
+        treasury.try_spend(&manager, &marketing, &vendor, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    treasury.unpause(&governance);
+    assert_eq!(treasury.spend(&manager, &marketing, &vendor, &100), 200);
+}
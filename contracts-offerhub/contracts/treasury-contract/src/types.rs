@@ -0,0 +1,24 @@
+use soroban_sdk::{contracttype, Address, Symbol};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryConfig {
+    /// Governance contract that sets budgets
+    pub governance: Address,
+    /// Seconds per budget epoch
+    pub epoch_length: u64,
+}
+
+/// Spending envelope for one department, reset every epoch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Budget {
+    pub department: Symbol,
+    /// Address allowed to spend from this envelope
+    pub manager: Address,
+    pub asset: Address,
+    pub limit_per_epoch: i128,
+    /// Epoch that `spent` refers to
+    pub epoch: u64,
+    pub spent: i128,
+}
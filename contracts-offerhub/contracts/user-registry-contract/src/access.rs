@@ -1,5 +1,6 @@
-use crate::storage::{get_admin, get_moderators, set_admin, set_moderators};
+use crate::storage::{get_admin, get_moderators, get_verifiers, set_admin, set_moderators};
 use crate::error::Error;
+use crate::types::VerificationLevel;
 use soroban_sdk::{Address, Env, Vec};
 
 pub struct AccessControl;
@@ -52,6 +53,27 @@ impl AccessControl {
         Err(Error::Unauthorized)
     }
 
+    /// Check if the caller may grant `level`: admins and moderators grant any level,
+    /// registered verifiers only the levels they were allowed
+    pub fn require_verifier(
+        env: &Env,
+        caller: &Address,
+        level: &VerificationLevel,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let admin = get_admin(env).ok_or(Error::NotInitialized)?;
+        if admin == *caller || get_moderators(env).contains(caller) {
+            return Ok(());
+        }
+
+        match get_verifiers(env).get(caller.clone()) {
+            Some(verifier) if verifier.allowed_levels.contains(level) => Ok(()),
+            Some(_) => Err(Error::LevelNotAllowed),
+            None => Err(Error::Unauthorized),
+        }
+    }
+
     /// Add a moderator (admin only)
     pub fn add_moderator(env: &Env, admin: Address, moderator: Address) -> Result<(), Error> {
         Self::require_admin(env, &admin)?;
@@ -37,6 +37,12 @@ fn test_verification_levels() {
         assert_eq!(VerificationLevel::Basic as u32, 1);
         assert_eq!(VerificationLevel::Premium as u32, 2);
         assert_eq!(VerificationLevel::Enterprise as u32, 3);
+        assert_eq!(VerificationLevel::Verified as u32, 4);
+
+        // Tiers order by rank, not by discriminant
+        assert!(VerificationLevel::Basic < VerificationLevel::Verified);
+        assert!(VerificationLevel::Verified < VerificationLevel::Premium);
+        assert!(VerificationLevel::Premium < VerificationLevel::Enterprise);
     });
 }
 
@@ -2,14 +2,19 @@ use crate::access::AccessControl;
 use crate::events::*;
 use crate::storage::*;
 use crate::types::{
-    require_auth, AllUsersExport, ContractExportResult, PlatformDataExport,
-    PublicationStatus, UserDataExport, UserProfile, UserStatus, VerificationLevel, UserProfileSummary,
+    require_auth, AccountStatus, AllUsersExport, BulkVerificationResult, ContractExportResult,
+    PendingRotation, PlatformDataExport, ProfileCommitment, PublicationStatus, UserDataExport,
+    UserProfile, UserStatus, Verification, VerificationLevel, Verifier, UserProfileSummary,
 };
 use crate::error::Error;
 use crate::validation::{
-    validate_bulk_verification, validate_metadata_update, validate_user_verification,
+    validate_bulk_size, validate_bulk_verification, validate_different_addresses,
+    validate_expiration, validate_metadata_update, validate_user_verification,
 };
-use soroban_sdk::{Address, Env, IntoVal, String, Symbol, Vec};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, String, Symbol, Vec};
+
+const MAX_LINKED_WALLETS: u32 = 10;
+const RECOVERY_DELAY: u64 = 7 * 24 * 3600;
 
 pub struct UserRegistryContract;
 
@@ -31,42 +36,11 @@ impl UserRegistryContract {
 
     // Function to check if contract is paused
     pub fn is_paused(env: &Env) -> bool {
-        env.storage().instance().get(&PAUSED).unwrap_or(false)
-    }
-
-    // Function to pause the contract
-    pub fn pause(env: &Env, admin: Address) -> Result<(), Error> {
-        AccessControl::require_admin(&env, &admin)?;
-        if Self::is_paused(env) {
-            return Err(Error::AlreadyPaused);
-        }
-        
-        env.storage().instance().set(&PAUSED, &true);
-        
-        env.events().publish(
-            (Symbol::new(env, "contract_paused"), admin),
-            env.ledger().timestamp(),
-        );
-        
-        Ok(())
+        offerhub_pausable::is_paused(env)
     }
 
-    // Function to unpause the contract
-    pub fn unpause(env: &Env, admin: Address) -> Result<(), Error> {
-        AccessControl::require_admin(&env, &admin)?;
-        
-        if !Self::is_paused(env) {
-            return Err(Error::NotPaused);
-        }
-        
-        env.storage().instance().set(&PAUSED, &false);
-        
-        env.events().publish(
-            (Symbol::new(env, "contract_unpaused"), admin),
-            env.ledger().timestamp(),
-        );
-        
-        Ok(())
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        AccessControl::get_current_admin(env).ok_or(Error::NotInitialized)
     }
 
 
@@ -99,6 +73,7 @@ impl UserRegistryContract {
         // Add to new profile storage with basic verification
         let profile = create_default_profile(&env, VerificationLevel::Basic, 0); // No expiration
         set_user_profile(&env, &user, &profile);
+        index_user_status(&env, &user);
 
         let count = Self::increment_user_count(&env)?;
 
@@ -155,6 +130,8 @@ impl UserRegistryContract {
         };
 
         set_user_profile(&env, &user, &profile);
+        index_user_status(&env, &user);
+        set_verified_by(&env, &user, &admin);
 
         let count = Self::increment_user_count(&env)?;
         // Update legacy storage for backward compatibility
@@ -177,6 +154,7 @@ impl UserRegistryContract {
         let _profile = get_user_profile(&env, &user).ok_or(Error::UserNotFound)?;
 
         remove_user_profile(&env, &user);
+        remove_verified_by(&env, &user);
 
         // Update legacy storage
         let mut users = get_verified_users(&env);
@@ -229,6 +207,117 @@ impl UserRegistryContract {
         Ok(())
     }
 
+    // ==================== VERIFICATION TIERS ====================
+
+    /// Set a user's verification tier and expiry (admin, moderator or a verifier allowed the
+    /// level). Registers the user when they have no profile yet; an existing profile keeps its
+    /// metadata and validations.
+    pub fn set_verification_level(
+        env: Env,
+        moderator: Address,
+        user: Address,
+        level: VerificationLevel,
+        expires_at: u64, // 0 means no expiration
+    ) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        AccessControl::require_verifier(&env, &moderator, &level)?;
+
+        validate_expiration(&env, expires_at)?;
+
+        if Self::apply_verification(&env, &moderator, &user, &level, expires_at)? {
+            let count = Self::get_total_users(&env)?;
+            emit_total_users(&env, &user, &count);
+        }
+
+        emit_verification_set(&env, &user, &level, expires_at, &moderator);
+        Ok(())
+    }
+
+    /// Set the same verification tier for a batch of users (admin, moderator or a verifier
+    /// allowed the level). Users that can't be verified are reported in the results instead
+    /// of failing the batch, and a single aggregate event is emitted.
+    pub fn bulk_set_verification(
+        env: Env,
+        moderator: Address,
+        users: Vec<Address>,
+        level: VerificationLevel,
+        expires_at: u64, // 0 means no expiration
+    ) -> Result<Vec<BulkVerificationResult>, Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        AccessControl::require_verifier(&env, &moderator, &level)?;
+
+        validate_bulk_size(&users)?;
+        validate_expiration(&env, expires_at)?;
+
+        let mut results = Vec::new(&env);
+        let mut verified_count = 0u32;
+        let mut registered_count = 0u32;
+
+        for user in users.iter() {
+            let result = Self::apply_verification(&env, &moderator, &user, &level, expires_at);
+            if let Ok(registered) = result {
+                verified_count += 1;
+                if registered {
+                    registered_count += 1;
+                }
+            }
+            results.push_back(BulkVerificationResult {
+                user,
+                success: result.is_ok(),
+                error_code: result.err().map(|e| e as u32),
+            });
+        }
+
+        emit_bulk_verification_set(
+            &env,
+            &moderator,
+            &level,
+            verified_count,
+            users.len() - verified_count,
+            registered_count,
+        );
+        Ok(results)
+    }
+
+    /// Get a user's verification tier, or None if they were never verified
+    pub fn get_verification(env: Env, user: Address) -> Option<Verification> {
+        let profile = get_user_profile(&env, &user)?;
+        let is_expired = Self::is_verification_expired(&env, &profile);
+
+        Some(Verification {
+            level: profile.verification_level,
+            verified_at: profile.verified_at,
+            expires_at: profile.expires_at,
+            is_active: !profile.is_blacklisted && !is_expired,
+            verified_by: get_verified_by(&env, &user),
+        })
+    }
+
+    /// Whether the user holds an active verification of at least `min_level`. Meant to be
+    /// cross-called by contracts that gate actions on verification.
+    pub fn has_verification_level(env: Env, user: Address, min_level: VerificationLevel) -> bool {
+        Self::get_verification(env, user)
+            .is_some_and(|verification| verification.is_active && verification.level >= min_level)
+    }
+
+    /// Fail with `InsufficientVerification` unless the user holds an active verification of at
+    /// least `min_level`. Meant to be cross-called before gated actions such as high-value
+    /// escrows or premium publications.
+    pub fn require_min_verification(
+        env: Env,
+        user: Address,
+        min_level: VerificationLevel,
+    ) -> Result<(), Error> {
+        if !Self::has_verification_level(env, user, min_level) {
+            return Err(Error::InsufficientVerification);
+        }
+        Ok(())
+    }
+
     // ==================== BLACKLIST FUNCTIONALITY ====================
 
     /// Add user to blacklist (admin/moderator only)
@@ -255,6 +344,7 @@ impl UserRegistryContract {
         }
 
         add_to_blacklist(&env, &user);
+        index_user_status(&env, &user);
 
         // Update user profile if exists
         if let Some(mut profile) = get_user_profile(&env, &user) {
@@ -278,6 +368,7 @@ impl UserRegistryContract {
         }
 
         remove_from_blacklist(&env, &user);
+        index_user_status(&env, &user);
 
         // Update user profile if exists
         if let Some(mut profile) = get_user_profile(&env, &user) {
@@ -294,6 +385,67 @@ impl UserRegistryContract {
         is_blacklisted(&env, &user)
     }
 
+    // ==================== ACCOUNT STATUS ====================
+
+    /// Set a user's account status (admin/moderator only). Blacklisting here goes through the
+    /// same blacklist as `blacklist_user`; moving to any other status lifts it.
+    pub fn set_user_status(
+        env: Env,
+        moderator: Address,
+        user: Address,
+        status: AccountStatus,
+    ) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        AccessControl::require_admin_or_moderator(&env, &moderator)?;
+
+        // Staff accounts can't be restricted
+        if status != AccountStatus::Active {
+            if AccessControl::get_current_admin(&env) == Some(user.clone()) {
+                return Err(Error::CannotBlacklistAdmin);
+            }
+            if AccessControl::get_all_moderators(&env).contains(&user) {
+                return Err(Error::CannotBlacklistModerator);
+            }
+        }
+
+        let old_status = get_account_status(&env, &user);
+        let blacklisted = status == AccountStatus::Blacklisted;
+
+        set_suspended(&env, &user, status == AccountStatus::Suspended);
+        if blacklisted {
+            add_to_blacklist(&env, &user);
+        } else {
+            remove_from_blacklist(&env, &user);
+        }
+        index_user_status(&env, &user);
+
+        // Update user profile if exists
+        if let Some(mut profile) = get_user_profile(&env, &user) {
+            profile.is_blacklisted = blacklisted;
+            set_user_profile(&env, &user, &profile);
+        }
+
+        emit_user_status_changed(&env, &user, &old_status, &status, &moderator);
+        Ok(())
+    }
+
+    /// Get a user's account status
+    pub fn get_account_status(env: Env, user: Address) -> AccountStatus {
+        get_account_status(&env, &user)
+    }
+
+    /// Fail unless the user's account is active. Meant to be cross-called before on-chain
+    /// actions such as escrow creation or NFT minting so platform bans apply there too.
+    pub fn require_active(env: Env, user: Address) -> Result<(), Error> {
+        match get_account_status(&env, &user) {
+            AccountStatus::Active => Ok(()),
+            AccountStatus::Suspended => Err(Error::UserSuspended),
+            AccountStatus::Blacklisted => Err(Error::UserBlacklisted),
+        }
+    }
+
     // ==================== BULK OPERATIONS ====================
 
     /// Bulk verify users (admin only)
@@ -335,6 +487,8 @@ impl UserRegistryContract {
             };
 
             set_user_profile(&env, &user, &profile);
+            index_user_status(&env, &user);
+            set_verified_by(&env, &user, &admin);
 
             // Update legacy storage
             let mut legacy_users = get_verified_users(&env);
@@ -383,6 +537,245 @@ impl UserRegistryContract {
         Ok(())
     }
 
+    // ==================== PROFILE HASH COMMITMENTS ====================
+
+    /// Commit the hash of the user's off-chain profile document. Earlier hashes stay in the
+    /// history so a profile can later be checked against what was committed at a given time.
+    pub fn commit_profile_hash(env: Env, user: Address, hash: BytesN<32>) -> Result<u32, Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        require_auth(&env, &user)?;
+        Self::require_active(env.clone(), user.clone())?;
+
+        // Rate limit: max 10 commitments per 24h per user
+        let limit_type = String::from_str(&env, "profile_hash");
+        check_rate_limit(&env, &user, &limit_type, 10, 24 * 3600)?;
+
+        let mut commitments = get_profile_commitments(&env, &user);
+        if let Some(current) = commitments.last() {
+            if current.hash == hash {
+                return Err(Error::ValidationFailed);
+            }
+        }
+
+        commitments.push_back(ProfileCommitment {
+            hash: hash.clone(),
+            committed_at: env.ledger().timestamp(),
+            ledger: env.ledger().sequence(),
+        });
+        set_profile_commitments(&env, &user, &commitments);
+
+        let version = commitments.len();
+        emit_profile_hash_committed(&env, &user, &hash, version);
+        Ok(version)
+    }
+
+    /// Get the user's current profile hash
+    pub fn get_profile_hash(env: Env, user: Address) -> Option<ProfileCommitment> {
+        get_profile_commitments(&env, &user).last()
+    }
+
+    /// Get the profile hash that was current at `timestamp`, if one had been committed by then
+    pub fn get_profile_hash_at(
+        env: Env,
+        user: Address,
+        timestamp: u64,
+    ) -> Option<ProfileCommitment> {
+        let mut current = None;
+        for commitment in get_profile_commitments(&env, &user).iter() {
+            if commitment.committed_at > timestamp {
+                break;
+            }
+            current = Some(commitment);
+        }
+        current
+    }
+
+    /// Get every profile hash the user has committed, oldest first
+    pub fn get_profile_hash_history(env: Env, user: Address) -> Vec<ProfileCommitment> {
+        get_profile_commitments(&env, &user)
+    }
+
+    // ==================== LINKED WALLETS ====================
+
+    /// Link another wallet to the primary wallet's identity. Both wallets must authorize, and
+    /// a wallet can belong to only one identity.
+    pub fn link_wallet(env: Env, primary: Address, new_wallet: Address) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        require_auth(&env, &primary)?;
+        require_auth(&env, &new_wallet)?;
+        validate_different_addresses(&primary, &new_wallet)?;
+        Self::require_active(env.clone(), primary.clone())?;
+        Self::require_active(env.clone(), new_wallet.clone())?;
+
+        // The primary can't itself be a secondary, and the new wallet can't already head or
+        // belong to an identity
+        if get_primary_wallet(&env, &primary).is_some()
+            || get_primary_wallet(&env, &new_wallet).is_some()
+            || !get_linked_wallets(&env, &new_wallet).is_empty()
+        {
+            return Err(Error::WalletAlreadyLinked);
+        }
+
+        let mut wallets = get_linked_wallets(&env, &primary);
+        if wallets.len() >= MAX_LINKED_WALLETS {
+            return Err(Error::TooManyLinkedWallets);
+        }
+        wallets.push_back(new_wallet.clone());
+        set_linked_wallets(&env, &primary, &wallets);
+        set_primary_wallet(&env, &new_wallet, &primary);
+
+        emit_wallet_linked(&env, &primary, &new_wallet);
+        Ok(())
+    }
+
+    /// Unlink a secondary wallet from the primary wallet's identity (primary only)
+    pub fn unlink_wallet(env: Env, primary: Address, wallet: Address) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        require_auth(&env, &primary)?;
+
+        if get_primary_wallet(&env, &wallet) != Some(primary.clone()) {
+            return Err(Error::WalletNotLinked);
+        }
+
+        let mut wallets = get_linked_wallets(&env, &primary);
+        if let Some(index) = wallets.first_index_of(&wallet) {
+            wallets.remove(index);
+        }
+        set_linked_wallets(&env, &primary, &wallets);
+        remove_primary_wallet(&env, &wallet);
+
+        emit_wallet_unlinked(&env, &primary, &wallet);
+        Ok(())
+    }
+
+    /// Get the primary wallet of the identity `address` belongs to (itself when unlinked)
+    pub fn resolve_identity(env: Env, address: Address) -> Address {
+        get_primary_wallet(&env, &address).unwrap_or(address)
+    }
+
+    /// Get every wallet of the identity `address` belongs to, primary first
+    pub fn get_linked_wallets(env: Env, address: Address) -> Vec<Address> {
+        let primary = Self::resolve_identity(env.clone(), address);
+        let mut wallets = Vec::from_array(&env, [primary.clone()]);
+        wallets.append(&get_linked_wallets(&env, &primary));
+        wallets
+    }
+
+    // ==================== ACCOUNT RECOVERY ====================
+
+    /// Set or clear (`None`) the guardian allowed to request a rotation of the user's primary
+    /// wallet. Setting a guardian drops any rotation the previous one requested.
+    pub fn set_recovery_guardian(
+        env: Env,
+        user: Address,
+        guardian: Option<Address>,
+    ) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        require_auth(&env, &user)?;
+
+        match &guardian {
+            Some(guardian) => {
+                validate_different_addresses(&user, guardian)?;
+                set_recovery_guardian(&env, &user, guardian);
+            }
+            None => remove_recovery_guardian(&env, &user),
+        }
+        remove_pending_rotation(&env, &user);
+
+        emit_recovery_guardian_set(&env, &user, &guardian);
+        Ok(())
+    }
+
+    /// Get the user's recovery guardian
+    pub fn get_recovery_guardian(env: Env, user: Address) -> Option<Address> {
+        get_recovery_guardian(&env, &user)
+    }
+
+    /// Move the user's identity to `new_address`: profile, verification, profile hashes, linked
+    /// wallets and guardian. Called by the user it happens at once; called by the user's
+    /// guardian it is scheduled and can be executed after the recovery delay, giving the user
+    /// time to cancel. The old address stays linked to the identity so reputation earned under
+    /// it still counts.
+    pub fn rotate_primary(
+        env: Env,
+        old_or_guardian: Address,
+        user_id: Address,
+        new_address: Address,
+    ) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        require_auth(&env, &old_or_guardian)?;
+        require_auth(&env, &new_address)?;
+
+        let by_user = old_or_guardian == user_id;
+        if !by_user && get_recovery_guardian(&env, &user_id) != Some(old_or_guardian.clone()) {
+            return Err(Error::Unauthorized);
+        }
+        Self::check_rotation(&env, &user_id, &new_address)?;
+
+        if by_user {
+            return Self::migrate_identity(&env, &user_id, &new_address);
+        }
+
+        let now = env.ledger().timestamp();
+        let rotation = PendingRotation {
+            new_address: new_address.clone(),
+            guardian: old_or_guardian.clone(),
+            requested_at: now,
+            execute_after: now + RECOVERY_DELAY,
+        };
+        set_pending_rotation(&env, &user_id, &rotation);
+
+        emit_rotation_scheduled(
+            &env,
+            &user_id,
+            &new_address,
+            &old_or_guardian,
+            rotation.execute_after,
+        );
+        Ok(())
+    }
+
+    /// Execute a guardian-requested rotation once its delay has passed (callable by anyone)
+    pub fn execute_rotation(env: Env, user_id: Address) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        let rotation = get_pending_rotation(&env, &user_id).ok_or(Error::RecoveryNotFound)?;
+        if env.ledger().timestamp() < rotation.execute_after {
+            return Err(Error::RecoveryDelayNotElapsed);
+        }
+
+        // The identity may have changed during the delay
+        Self::check_rotation(&env, &user_id, &rotation.new_address)?;
+        Self::migrate_identity(&env, &user_id, &rotation.new_address)
+    }
+
+    /// Cancel a pending guardian-requested rotation (user only)
+    pub fn cancel_rotation(env: Env, user_id: Address) -> Result<(), Error> {
+        require_auth(&env, &user_id)?;
+
+        let rotation = get_pending_rotation(&env, &user_id).ok_or(Error::RecoveryNotFound)?;
+        remove_pending_rotation(&env, &user_id);
+
+        emit_rotation_cancelled(&env, &user_id, &rotation.new_address);
+        Ok(())
+    }
+
+    /// Get the user's pending guardian-requested rotation
+    pub fn get_pending_rotation(env: Env, user_id: Address) -> Option<PendingRotation> {
+        get_pending_rotation(&env, &user_id)
+    }
+
     // ==================== STATUS CHECKING ====================
 
     /// Get comprehensive user status
@@ -452,6 +845,59 @@ impl UserRegistryContract {
         Ok(())
     }
 
+    /// Register a third-party verifier and the levels it may grant (admin only). Adding an
+    /// existing verifier replaces its allowed levels.
+    pub fn add_verifier(
+        env: Env,
+        admin: Address,
+        verifier: Address,
+        allowed_levels: Vec<VerificationLevel>,
+    ) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        AccessControl::require_admin(&env, &admin)?;
+
+        if allowed_levels.is_empty() {
+            return Err(Error::InvalidVerificationLevel);
+        }
+
+        let mut verifiers = get_verifiers(&env);
+        verifiers.set(
+            verifier.clone(),
+            Verifier {
+                allowed_levels: allowed_levels.clone(),
+                added_at: env.ledger().timestamp(),
+            },
+        );
+        set_verifiers(&env, &verifiers);
+
+        emit_verifier_added(&env, &verifier, &allowed_levels, &admin);
+        Ok(())
+    }
+
+    /// Remove a verifier (admin only). Verifications it already made stay in place.
+    pub fn remove_verifier(env: Env, admin: Address, verifier: Address) -> Result<(), Error> {
+        if Self::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+        AccessControl::require_admin(&env, &admin)?;
+
+        let mut verifiers = get_verifiers(&env);
+        if verifiers.remove(verifier.clone()).is_none() {
+            return Err(Error::VerifierNotFound);
+        }
+        set_verifiers(&env, &verifiers);
+
+        emit_verifier_removed(&env, &verifier, &admin);
+        Ok(())
+    }
+
+    /// Get a verifier's allowed levels
+    pub fn get_verifier(env: Env, verifier: Address) -> Option<Verifier> {
+        get_verifiers(&env).get(verifier)
+    }
+
     /// Transfer admin role (current admin only)
     pub fn transfer_admin(
         env: Env,
@@ -548,6 +994,53 @@ impl UserRegistryContract {
         Ok(())
     }
 
+    // ==================== USER ENUMERATION ====================
+
+    /// List users with the given account status, or every indexed user when no filter is
+    /// given (active first, then suspended, then blacklisted). At most 100 per page.
+    pub fn list_users(
+        env: Env,
+        status_filter: Option<AccountStatus>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Address> {
+        let max_limit = 100u32;
+        let actual_limit = if limit == 0 || limit > max_limit {
+            max_limit
+        } else {
+            limit
+        };
+
+        let mut users = Vec::new(&env);
+        let mut skip = offset;
+        for status in Self::statuses(&env, status_filter).iter() {
+            let count = count_indexed_users(&env, &status);
+            if skip >= count {
+                skip -= count;
+                continue;
+            }
+            for position in skip..count {
+                if users.len() >= actual_limit {
+                    return users;
+                }
+                if let Some(user) = get_indexed_user(&env, &status, position) {
+                    users.push_back(user);
+                }
+            }
+            skip = 0;
+        }
+        users
+    }
+
+    /// Count users with the given account status, or every indexed user when no filter is given
+    pub fn count_users(env: Env, status_filter: Option<AccountStatus>) -> u32 {
+        let mut total = 0u32;
+        for status in Self::statuses(&env, status_filter).iter() {
+            total += count_indexed_users(&env, &status);
+        }
+        total
+    }
+
     // ==================== DATA EXPORT FUNCTIONS ====================
 
     /// Export user data (user themselves or admin/moderator)
@@ -867,6 +1360,60 @@ impl UserRegistryContract {
 
     // ==================== HELPER FUNCTIONS ====================
 
+    fn statuses(env: &Env, status_filter: Option<AccountStatus>) -> Vec<AccountStatus> {
+        match status_filter {
+            Some(status) => Vec::from_array(env, [status]),
+            None => Vec::from_array(
+                env,
+                [
+                    AccountStatus::Active,
+                    AccountStatus::Suspended,
+                    AccountStatus::Blacklisted,
+                ],
+            ),
+        }
+    }
+
+    // Writes the verification without emitting events. Returns whether the user was newly
+    // registered.
+    fn apply_verification(
+        env: &Env,
+        moderator: &Address,
+        user: &Address,
+        level: &VerificationLevel,
+        expires_at: u64,
+    ) -> Result<bool, Error> {
+        validate_different_addresses(moderator, user)?;
+
+        if is_blacklisted(env, user) {
+            return Err(Error::AlreadyBlacklisted);
+        }
+
+        let (profile, registered) = match get_user_profile(env, user) {
+            Some(mut profile) => {
+                profile.verification_level = level.clone();
+                profile.verified_at = env.ledger().timestamp();
+                profile.expires_at = expires_at;
+                (profile, false)
+            }
+            None => {
+                Self::increment_user_count(env)?;
+                (create_default_profile(env, level.clone(), expires_at), true)
+            }
+        };
+        set_user_profile(env, user, &profile);
+        index_user_status(env, user);
+
+        set_verified_by(env, user, moderator);
+
+        // Update legacy storage for backward compatibility
+        let mut users = get_verified_users(env);
+        users.set(user.clone(), true);
+        set_verified_users(env, &users);
+
+        Ok(registered)
+    }
+
     fn is_verification_expired(env: &Env, profile: &UserProfile) -> bool {
         if profile.expires_at == 0 {
             return false; // No expiration
@@ -879,6 +1426,82 @@ impl UserRegistryContract {
     }
 
     // Statistcis and Metrics
+    // Only an active primary wallet with a profile can rotate, and only to an address that is
+    // unregistered and not part of another identity (one of its own secondaries is fine).
+    fn check_rotation(env: &Env, user: &Address, new_address: &Address) -> Result<(), Error> {
+        validate_different_addresses(user, new_address)?;
+        get_user_profile(env, user).ok_or(Error::UserNotFound)?;
+        Self::require_active(env.clone(), user.clone())?;
+        if get_primary_wallet(env, user).is_some() {
+            return Err(Error::WalletAlreadyLinked);
+        }
+
+        if is_blacklisted(env, new_address) {
+            return Err(Error::AlreadyBlacklisted);
+        }
+        if get_user_profile(env, new_address).is_some()
+            || get_verified_users(env).contains_key(new_address.clone())
+        {
+            return Err(Error::AlreadyRegistered);
+        }
+        let new_primary = get_primary_wallet(env, new_address);
+        if (new_primary.is_some() && new_primary != Some(user.clone()))
+            || !get_linked_wallets(env, new_address).is_empty()
+        {
+            return Err(Error::WalletAlreadyLinked);
+        }
+        Ok(())
+    }
+
+    fn migrate_identity(env: &Env, old: &Address, new: &Address) -> Result<(), Error> {
+        let profile = get_user_profile(env, old).ok_or(Error::UserNotFound)?;
+        remove_user_profile(env, old);
+        set_user_profile(env, new, &profile);
+
+        let mut users = get_verified_users(env);
+        if users.remove(old.clone()).is_some() {
+            users.set(new.clone(), true);
+            set_verified_users(env, &users);
+        }
+
+        if let Some(verifier) = get_verified_by(env, old) {
+            remove_verified_by(env, old);
+            set_verified_by(env, new, &verifier);
+        }
+
+        let commitments = get_profile_commitments(env, old);
+        if !commitments.is_empty() {
+            set_profile_commitments(env, old, &Vec::new(env));
+            set_profile_commitments(env, new, &commitments);
+        }
+
+        if let Some(guardian) = get_recovery_guardian(env, old) {
+            remove_recovery_guardian(env, old);
+            set_recovery_guardian(env, new, &guardian);
+        }
+        remove_pending_rotation(env, old);
+
+        // The old address becomes a secondary of the new primary. This may leave the identity
+        // one over MAX_LINKED_WALLETS, which only blocks further links.
+        let mut wallets = get_linked_wallets(env, old);
+        if let Some(index) = wallets.first_index_of(new) {
+            wallets.remove(index);
+            remove_primary_wallet(env, new);
+        }
+        wallets.push_back(old.clone());
+        set_linked_wallets(env, old, &Vec::new(env));
+        set_linked_wallets(env, new, &wallets);
+        for wallet in wallets.iter() {
+            set_primary_wallet(env, &wallet, new);
+        }
+
+        unindex_user(env, old);
+        index_user_status(env, new);
+
+        emit_primary_rotated(env, old, new);
+        Ok(())
+    }
+
     pub fn get_total_users(env: &Env) -> Result<u64, Error> {
         env.storage()
             .persistent()
@@ -905,6 +1528,7 @@ impl UserRegistryContract {
         // Format verification level
         let verification_level = match profile.verification_level {
             VerificationLevel::Basic => String::from_str(&env, "Basic"),
+            VerificationLevel::Verified => String::from_str(&env, "Verified"),
             VerificationLevel::Premium => String::from_str(&env, "Premium"),
             VerificationLevel::Enterprise => String::from_str(&env, "Enterprise"),
         };
@@ -1,3 +1,4 @@
+use offerhub_pausable::PauseError;
 use soroban_sdk::{contracterror};
 
 #[contracterror]
@@ -41,4 +42,34 @@ pub enum Error {
     AlreadyPaused = 18,
     NotPaused = 19,
     ContractPaused = 20,
-}
\ No newline at end of file
+    /// User account is suspended
+    UserSuspended = 21,
+    /// User account is blacklisted
+    UserBlacklisted = 22,
+    /// Verifier is not allowed to grant this verification level
+    LevelNotAllowed = 23,
+    /// Address is not a registered verifier
+    VerifierNotFound = 24,
+    /// Wallet is already linked to an identity
+    WalletAlreadyLinked = 25,
+    /// Wallet is not linked to this identity
+    WalletNotLinked = 26,
+    /// Identity already has the maximum number of linked wallets
+    TooManyLinkedWallets = 27,
+    /// No recovery guardian or pending rotation for this user
+    RecoveryNotFound = 28,
+    /// Recovery delay has not elapsed yet
+    RecoveryDelayNotElapsed = 29,
+    /// User does not hold an active verification of the required level
+    InsufficientVerification = 30,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
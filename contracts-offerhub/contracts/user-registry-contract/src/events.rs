@@ -1,5 +1,5 @@
-use crate::types::VerificationLevel;
-use soroban_sdk::{Address, Env, Symbol};
+use crate::types::{AccountStatus, VerificationLevel};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
 
 pub fn emit_user_registered(env: &Env, user: &Address) {
     let topic = Symbol::new(env, "USER_REGISTERED");
@@ -29,6 +29,25 @@ pub fn emit_user_unblacklisted(env: &Env, user: &Address, admin: &Address) {
         .publish((topic,), (user.clone(), admin.clone()));
 }
 
+pub fn emit_user_status_changed(
+    env: &Env,
+    user: &Address,
+    old_status: &AccountStatus,
+    new_status: &AccountStatus,
+    moderator: &Address,
+) {
+    let topic = Symbol::new(env, "USER_STATUS_CHANGED");
+    env.events().publish(
+        (topic,),
+        (
+            user.clone(),
+            old_status.clone(),
+            new_status.clone(),
+            moderator.clone(),
+        ),
+    );
+}
+
 pub fn emit_verification_level_updated(
     env: &Env,
     user: &Address,
@@ -42,6 +61,75 @@ pub fn emit_verification_level_updated(
     );
 }
 
+pub fn emit_verification_set(
+    env: &Env,
+    user: &Address,
+    level: &VerificationLevel,
+    expires_at: u64,
+    moderator: &Address,
+) {
+    let topic = Symbol::new(env, "VERIFICATION_SET");
+    env.events().publish(
+        (topic,),
+        (user.clone(), level.clone(), expires_at, moderator.clone()),
+    );
+}
+
+pub fn emit_profile_hash_committed(env: &Env, user: &Address, hash: &BytesN<32>, version: u32) {
+    let topic = Symbol::new(env, "PROFILE_HASH_COMMITTED");
+    env.events()
+        .publish((topic,), (user.clone(), hash.clone(), version));
+}
+
+pub fn emit_wallet_linked(env: &Env, primary: &Address, wallet: &Address) {
+    let topic = Symbol::new(env, "WALLET_LINKED");
+    env.events()
+        .publish((topic,), (primary.clone(), wallet.clone()));
+}
+
+pub fn emit_wallet_unlinked(env: &Env, primary: &Address, wallet: &Address) {
+    let topic = Symbol::new(env, "WALLET_UNLINKED");
+    env.events()
+        .publish((topic,), (primary.clone(), wallet.clone()));
+}
+
+pub fn emit_recovery_guardian_set(env: &Env, user: &Address, guardian: &Option<Address>) {
+    let topic = Symbol::new(env, "RECOVERY_GUARDIAN_SET");
+    env.events()
+        .publish((topic,), (user.clone(), guardian.clone()));
+}
+
+pub fn emit_rotation_scheduled(
+    env: &Env,
+    user: &Address,
+    new_address: &Address,
+    guardian: &Address,
+    execute_after: u64,
+) {
+    let topic = Symbol::new(env, "ROTATION_SCHEDULED");
+    env.events().publish(
+        (topic,),
+        (
+            user.clone(),
+            new_address.clone(),
+            guardian.clone(),
+            execute_after,
+        ),
+    );
+}
+
+pub fn emit_rotation_cancelled(env: &Env, user: &Address, new_address: &Address) {
+    let topic = Symbol::new(env, "ROTATION_CANCELLED");
+    env.events()
+        .publish((topic,), (user.clone(), new_address.clone()));
+}
+
+pub fn emit_primary_rotated(env: &Env, old_address: &Address, new_address: &Address) {
+    let topic = Symbol::new(env, "PRIMARY_ROTATED");
+    env.events()
+        .publish((topic,), (old_address.clone(), new_address.clone()));
+}
+
 pub fn emit_metadata_updated(env: &Env, user: &Address) {
     let topic = Symbol::new(env, "METADATA_UPDATED");
     env.events().publish((topic,), user.clone());
@@ -57,6 +145,27 @@ pub fn emit_bulk_verification_completed(env: &Env, count: u32) {
     env.events().publish((topic,), count);
 }
 
+pub fn emit_bulk_verification_set(
+    env: &Env,
+    moderator: &Address,
+    level: &VerificationLevel,
+    verified: u32,
+    failed: u32,
+    registered: u32,
+) {
+    let topic = Symbol::new(env, "BULK_VERIFICATION_SET");
+    env.events().publish(
+        (topic,),
+        (
+            moderator.clone(),
+            level.clone(),
+            verified,
+            failed,
+            registered,
+        ),
+    );
+}
+
 pub fn emit_admin_initialized(env: &Env, admin: &Address) {
     let topic = Symbol::new(env, "ADMIN_INITIALIZED");
     env.events().publish((topic,), admin.clone());
@@ -74,6 +183,25 @@ pub fn emit_moderator_added(env: &Env, moderator: &Address, admin: &Address) {
         .publish((topic,), (moderator.clone(), admin.clone()));
 }
 
+pub fn emit_verifier_added(
+    env: &Env,
+    verifier: &Address,
+    allowed_levels: &Vec<VerificationLevel>,
+    admin: &Address,
+) {
+    let topic = Symbol::new(env, "VERIFIER_ADDED");
+    env.events().publish(
+        (topic,),
+        (verifier.clone(), allowed_levels.clone(), admin.clone()),
+    );
+}
+
+pub fn emit_verifier_removed(env: &Env, verifier: &Address, admin: &Address) {
+    let topic = Symbol::new(env, "VERIFIER_REMOVED");
+    env.events()
+        .publish((topic,), (verifier.clone(), admin.clone()));
+}
+
 pub fn emit_moderator_removed(env: &Env, moderator: &Address, admin: &Address) {
     let topic = Symbol::new(env, "MODERATOR_REMOVED");
     env.events()
@@ -12,12 +12,17 @@ mod error;
 
 use crate::contract::UserRegistryContract;
 use crate::error::Error;
-use crate::types::{UserProfile,UserProfileSummary,  UserStatus, VerificationLevel};
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use crate::types::{
+    AccountStatus, BulkVerificationResult, PendingRotation, ProfileCommitment, UserProfile,
+    UserProfileSummary, UserStatus, Verification, VerificationLevel, Verifier,
+};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
 #[contract]
 pub struct Contract;
 
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| UserRegistryContract::pause_admin(env));
+
 #[contractimpl]
 impl Contract {
     // ==================== INITIALIZATION ====================
@@ -27,18 +32,6 @@ impl Contract {
         UserRegistryContract::initialize_admin(env, admin)
     }
 
-     pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
-        UserRegistryContract::pause(&env, admin)
-    }
-
-    pub fn is_paused(env: Env) -> bool {
-        UserRegistryContract::is_paused(&env)
-    }
-
-    pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
-        UserRegistryContract::unpause(&env, admin)
-    }
-
     // ==================== LEGACY FUNCTIONS (for backward compatibility) ====================
 
     /// Legacy function for registering a verified user
@@ -90,6 +83,38 @@ impl Contract {
         UserRegistryContract::renew_verification(env, admin, user, new_expires_at)
     }
 
+    // ==================== VERIFICATION TIERS ====================
+
+    /// Set a user's verification tier and expiry
+    pub fn set_verification_level(
+        env: Env,
+        moderator: Address,
+        user: Address,
+        level: VerificationLevel,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        UserRegistryContract::set_verification_level(env, moderator, user, level, expires_at)
+    }
+
+    /// Get a user's verification tier
+    pub fn get_verification(env: Env, user: Address) -> Option<Verification> {
+        UserRegistryContract::get_verification(env, user)
+    }
+
+    /// Check that a user holds an active verification of at least `min_level`
+    pub fn has_verification_level(env: Env, user: Address, min_level: VerificationLevel) -> bool {
+        UserRegistryContract::has_verification_level(env, user, min_level)
+    }
+
+    /// Fail unless the user holds an active verification of at least the given level
+    pub fn require_min_verification(
+        env: Env,
+        user: Address,
+        min_level: VerificationLevel,
+    ) -> Result<(), Error> {
+        UserRegistryContract::require_min_verification(env, user, min_level)
+    }
+
     // ==================== BLACKLIST FUNCTIONALITY ====================
 
     /// Add user to blacklist
@@ -107,6 +132,28 @@ impl Contract {
         UserRegistryContract::is_user_blacklisted(env, user)
     }
 
+    // ==================== ACCOUNT STATUS ====================
+
+    /// Set a user's account status
+    pub fn set_user_status(
+        env: Env,
+        moderator: Address,
+        user: Address,
+        status: AccountStatus,
+    ) -> Result<(), Error> {
+        UserRegistryContract::set_user_status(env, moderator, user, status)
+    }
+
+    /// Get a user's account status
+    pub fn get_account_status(env: Env, user: Address) -> AccountStatus {
+        UserRegistryContract::get_account_status(env, user)
+    }
+
+    /// Fail unless the user's account is active
+    pub fn require_active(env: Env, user: Address) -> Result<(), Error> {
+        UserRegistryContract::require_active(env, user)
+    }
+
     // ==================== BULK OPERATIONS ====================
 
     /// Bulk verify users
@@ -121,6 +168,17 @@ impl Contract {
         UserRegistryContract::bulk_verify_users(env, admin, users, level, expires_at, metadata)
     }
 
+    /// Set the same verification tier for a batch of users, with a result per user
+    pub fn bulk_set_verification(
+        env: Env,
+        moderator: Address,
+        users: Vec<Address>,
+        level: VerificationLevel,
+        expires_at: u64,
+    ) -> Result<Vec<BulkVerificationResult>, Error> {
+        UserRegistryContract::bulk_set_verification(env, moderator, users, level, expires_at)
+    }
+
     // ==================== USER PROFILE METADATA ====================
 
     /// Update user metadata
@@ -133,6 +191,95 @@ impl Contract {
         UserRegistryContract::update_user_metadata(env, caller, user, metadata)
     }
 
+    // ==================== PROFILE HASH COMMITMENTS ====================
+
+    /// Commit the hash of the user's off-chain profile document
+    pub fn commit_profile_hash(env: Env, user: Address, hash: BytesN<32>) -> Result<u32, Error> {
+        UserRegistryContract::commit_profile_hash(env, user, hash)
+    }
+
+    /// Get the user's current profile hash
+    pub fn get_profile_hash(env: Env, user: Address) -> Option<ProfileCommitment> {
+        UserRegistryContract::get_profile_hash(env, user)
+    }
+
+    /// Get the profile hash that was current at a given time
+    pub fn get_profile_hash_at(
+        env: Env,
+        user: Address,
+        timestamp: u64,
+    ) -> Option<ProfileCommitment> {
+        UserRegistryContract::get_profile_hash_at(env, user, timestamp)
+    }
+
+    /// Get the user's profile hash history
+    pub fn get_profile_hash_history(env: Env, user: Address) -> Vec<ProfileCommitment> {
+        UserRegistryContract::get_profile_hash_history(env, user)
+    }
+
+    // ==================== LINKED WALLETS ====================
+
+    /// Link another wallet to the primary wallet's identity
+    pub fn link_wallet(env: Env, primary: Address, new_wallet: Address) -> Result<(), Error> {
+        UserRegistryContract::link_wallet(env, primary, new_wallet)
+    }
+
+    /// Unlink a secondary wallet from the primary wallet's identity
+    pub fn unlink_wallet(env: Env, primary: Address, wallet: Address) -> Result<(), Error> {
+        UserRegistryContract::unlink_wallet(env, primary, wallet)
+    }
+
+    /// Get the primary wallet of the identity an address belongs to
+    pub fn resolve_identity(env: Env, address: Address) -> Address {
+        UserRegistryContract::resolve_identity(env, address)
+    }
+
+    /// Get every wallet of the identity an address belongs to, primary first
+    pub fn get_linked_wallets(env: Env, address: Address) -> Vec<Address> {
+        UserRegistryContract::get_linked_wallets(env, address)
+    }
+
+    // ==================== ACCOUNT RECOVERY ====================
+
+    /// Set or clear the guardian allowed to request a primary wallet rotation
+    pub fn set_recovery_guardian(
+        env: Env,
+        user: Address,
+        guardian: Option<Address>,
+    ) -> Result<(), Error> {
+        UserRegistryContract::set_recovery_guardian(env, user, guardian)
+    }
+
+    /// Get the user's recovery guardian
+    pub fn get_recovery_guardian(env: Env, user: Address) -> Option<Address> {
+        UserRegistryContract::get_recovery_guardian(env, user)
+    }
+
+    /// Move the user's identity to a new address, at once by the user or delayed by the guardian
+    pub fn rotate_primary(
+        env: Env,
+        old_or_guardian: Address,
+        user_id: Address,
+        new_address: Address,
+    ) -> Result<(), Error> {
+        UserRegistryContract::rotate_primary(env, old_or_guardian, user_id, new_address)
+    }
+
+    /// Execute a guardian-requested rotation after its delay
+    pub fn execute_rotation(env: Env, user_id: Address) -> Result<(), Error> {
+        UserRegistryContract::execute_rotation(env, user_id)
+    }
+
+    /// Cancel a pending guardian-requested rotation
+    pub fn cancel_rotation(env: Env, user_id: Address) -> Result<(), Error> {
+        UserRegistryContract::cancel_rotation(env, user_id)
+    }
+
+    /// Get the user's pending guardian-requested rotation
+    pub fn get_pending_rotation(env: Env, user_id: Address) -> Option<PendingRotation> {
+        UserRegistryContract::get_pending_rotation(env, user_id)
+    }
+
     // ==================== STATUS CHECKING ====================
 
     /// Get comprehensive user status
@@ -187,6 +334,26 @@ impl Contract {
         UserRegistryContract::get_moderators(env)
     }
 
+    /// Register a third-party verifier and the levels it may grant
+    pub fn add_verifier(
+        env: Env,
+        admin: Address,
+        verifier: Address,
+        allowed_levels: Vec<VerificationLevel>,
+    ) -> Result<(), Error> {
+        UserRegistryContract::add_verifier(env, admin, verifier, allowed_levels)
+    }
+
+    /// Remove a verifier
+    pub fn remove_verifier(env: Env, admin: Address, verifier: Address) -> Result<(), Error> {
+        UserRegistryContract::remove_verifier(env, admin, verifier)
+    }
+
+    /// Get a verifier's allowed levels
+    pub fn get_verifier(env: Env, verifier: Address) -> Option<Verifier> {
+        UserRegistryContract::get_verifier(env, verifier)
+    }
+
     // ===== Rate limiting admin helpers =====
     pub fn set_rate_limit_bypass(
         env: Env,
@@ -239,6 +406,23 @@ impl Contract {
         UserRegistryContract::add_dispute_contract(env, admin, contract_address)
     }
 
+    // ==================== USER ENUMERATION ====================
+
+    /// List users, optionally only those with the given account status
+    pub fn list_users(
+        env: Env,
+        status_filter: Option<AccountStatus>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Address> {
+        UserRegistryContract::list_users(env, status_filter, offset, limit)
+    }
+
+    /// Count users, optionally only those with the given account status
+    pub fn count_users(env: Env, status_filter: Option<AccountStatus>) -> u32 {
+        UserRegistryContract::count_users(env, status_filter)
+    }
+
     // ==================== DATA EXPORT FUNCTIONS ====================
 
     /// Export user data (user themselves or admin/moderator)
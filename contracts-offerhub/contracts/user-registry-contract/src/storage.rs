@@ -1,5 +1,8 @@
 use crate::error::Error;
-use crate::types::{PublicationStatus, UserProfile, VerificationLevel};
+use crate::types::{
+    AccountStatus, PendingRotation, ProfileCommitment, PublicationStatus, UserProfile,
+    VerificationLevel, Verifier,
+};
 use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol, Vec};
 
 pub const VERIFIED_USERS: Symbol = symbol_short!("VERIFIED");
@@ -13,7 +16,18 @@ pub const TOTAL_USERS: Symbol = symbol_short!("TOTALUSER");
 pub const RATING_CONTRACT: Symbol = symbol_short!("RATING");
 pub const ESCROW_CONTRACTS: Symbol = symbol_short!("ESCROWS");
 pub const DISPUTE_CONTRACTS: Symbol = symbol_short!("DISPUTES");
-pub const PAUSED: Symbol = symbol_short!("PAUSED");
+pub use offerhub_pausable::PAUSED;
+pub const SUSPENDED: Symbol = symbol_short!("SUSPENDED");
+pub const PROFILE_HASHES: Symbol = symbol_short!("PROFHASH");
+pub const VERIFIERS: Symbol = symbol_short!("VERIFIERS");
+pub const VERIFIED_BY: Symbol = symbol_short!("VERIF_BY");
+pub const STATUS_INDEX: Symbol = symbol_short!("STIDX");
+pub const STATUS_COUNT: Symbol = symbol_short!("STCOUNT");
+pub const INDEXED_STATUS: Symbol = symbol_short!("STOF");
+pub const LINKED_WALLETS: Symbol = symbol_short!("LINKED");
+pub const PRIMARY_WALLET: Symbol = symbol_short!("PRIMARY");
+pub const RECOVERY_GUARDIAN: Symbol = symbol_short!("GUARDIAN");
+pub const PENDING_ROTATION: Symbol = symbol_short!("ROTATION");
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -166,6 +180,228 @@ pub fn is_blacklisted(env: &Env, user: &Address) -> bool {
     blacklist.contains(user)
 }
 
+// Account status functions
+pub fn is_suspended(env: &Env, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&(SUSPENDED, user.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_suspended(env: &Env, user: &Address, suspended: bool) {
+    if suspended {
+        env.storage()
+            .persistent()
+            .set(&(SUSPENDED, user.clone()), &true);
+    } else {
+        env.storage()
+            .persistent()
+            .remove(&(SUSPENDED, user.clone()));
+    }
+}
+
+pub fn get_account_status(env: &Env, user: &Address) -> AccountStatus {
+    if is_blacklisted(env, user) {
+        AccountStatus::Blacklisted
+    } else if is_suspended(env, user) {
+        AccountStatus::Suspended
+    } else {
+        AccountStatus::Active
+    }
+}
+
+// Profile hash commitments, oldest first
+pub fn get_profile_commitments(env: &Env, user: &Address) -> Vec<ProfileCommitment> {
+    env.storage()
+        .persistent()
+        .get(&(PROFILE_HASHES, user.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_profile_commitments(env: &Env, user: &Address, commitments: &Vec<ProfileCommitment>) {
+    env.storage()
+        .persistent()
+        .set(&(PROFILE_HASHES, user.clone()), commitments);
+}
+
+// Per-status user indexes. Each status keeps a dense list `(STATUS_INDEX, status, position)`
+// so it can be paged without loading every user; removal swaps the last entry into the gap.
+pub fn index_user_status(env: &Env, user: &Address) {
+    let status = get_account_status(env, user);
+    let indexed: Option<(AccountStatus, u32)> = env
+        .storage()
+        .persistent()
+        .get(&(INDEXED_STATUS, user.clone()));
+
+    if let Some((old_status, _)) = indexed {
+        if old_status == status {
+            return;
+        }
+        unindex_user(env, user);
+    }
+
+    let position = count_indexed_users(env, &status);
+    env.storage()
+        .persistent()
+        .set(&(STATUS_INDEX, status.clone(), position), user);
+    env.storage()
+        .persistent()
+        .set(&(STATUS_COUNT, status.clone()), &(position + 1));
+    env.storage()
+        .persistent()
+        .set(&(INDEXED_STATUS, user.clone()), &(status, position));
+}
+
+pub fn unindex_user(env: &Env, user: &Address) {
+    let indexed: Option<(AccountStatus, u32)> = env
+        .storage()
+        .persistent()
+        .get(&(INDEXED_STATUS, user.clone()));
+    let Some((status, position)) = indexed else {
+        return;
+    };
+
+    let last = count_indexed_users(env, &status) - 1;
+    if position != last {
+        let moved: Address = env
+            .storage()
+            .persistent()
+            .get(&(STATUS_INDEX, status.clone(), last))
+            .unwrap();
+        env.storage()
+            .persistent()
+            .set(&(STATUS_INDEX, status.clone(), position), &moved);
+        env.storage()
+            .persistent()
+            .set(&(INDEXED_STATUS, moved), &(status.clone(), position));
+    }
+    env.storage()
+        .persistent()
+        .remove(&(STATUS_INDEX, status.clone(), last));
+    env.storage()
+        .persistent()
+        .set(&(STATUS_COUNT, status), &last);
+    env.storage()
+        .persistent()
+        .remove(&(INDEXED_STATUS, user.clone()));
+}
+
+pub fn count_indexed_users(env: &Env, status: &AccountStatus) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&(STATUS_COUNT, status.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_indexed_user(env: &Env, status: &AccountStatus, position: u32) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&(STATUS_INDEX, status.clone(), position))
+}
+
+// Linked wallet functions. A primary wallet lists its secondaries; each secondary points back.
+pub fn get_linked_wallets(env: &Env, primary: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&(LINKED_WALLETS, primary.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_linked_wallets(env: &Env, primary: &Address, wallets: &Vec<Address>) {
+    if wallets.is_empty() {
+        env.storage()
+            .persistent()
+            .remove(&(LINKED_WALLETS, primary.clone()));
+    } else {
+        env.storage()
+            .persistent()
+            .set(&(LINKED_WALLETS, primary.clone()), wallets);
+    }
+}
+
+pub fn get_primary_wallet(env: &Env, wallet: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&(PRIMARY_WALLET, wallet.clone()))
+}
+
+pub fn set_primary_wallet(env: &Env, wallet: &Address, primary: &Address) {
+    env.storage()
+        .persistent()
+        .set(&(PRIMARY_WALLET, wallet.clone()), primary);
+}
+
+pub fn remove_primary_wallet(env: &Env, wallet: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&(PRIMARY_WALLET, wallet.clone()));
+}
+
+// Account recovery functions
+pub fn get_recovery_guardian(env: &Env, user: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&(RECOVERY_GUARDIAN, user.clone()))
+}
+
+pub fn set_recovery_guardian(env: &Env, user: &Address, guardian: &Address) {
+    env.storage()
+        .persistent()
+        .set(&(RECOVERY_GUARDIAN, user.clone()), guardian);
+}
+
+pub fn remove_recovery_guardian(env: &Env, user: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&(RECOVERY_GUARDIAN, user.clone()));
+}
+
+pub fn get_pending_rotation(env: &Env, user: &Address) -> Option<PendingRotation> {
+    env.storage()
+        .persistent()
+        .get(&(PENDING_ROTATION, user.clone()))
+}
+
+pub fn set_pending_rotation(env: &Env, user: &Address, rotation: &PendingRotation) {
+    env.storage()
+        .persistent()
+        .set(&(PENDING_ROTATION, user.clone()), rotation);
+}
+
+pub fn remove_pending_rotation(env: &Env, user: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&(PENDING_ROTATION, user.clone()));
+}
+
+// Verifier functions
+pub fn get_verifiers(env: &Env) -> Map<Address, Verifier> {
+    env.storage()
+        .instance()
+        .get(&VERIFIERS)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn set_verifiers(env: &Env, verifiers: &Map<Address, Verifier>) {
+    env.storage().instance().set(&VERIFIERS, verifiers);
+}
+
+pub fn get_verified_by(env: &Env, user: &Address) -> Option<Address> {
+    env.storage().persistent().get(&(VERIFIED_BY, user.clone()))
+}
+
+pub fn set_verified_by(env: &Env, user: &Address, verifier: &Address) {
+    env.storage()
+        .persistent()
+        .set(&(VERIFIED_BY, user.clone()), verifier);
+}
+
+pub fn remove_verified_by(env: &Env, user: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&(VERIFIED_BY, user.clone()));
+}
+
 // Admin and moderator functions
 pub fn get_admin(env: &Env) -> Option<Address> {
     env.storage().instance().get(&ADMIN)
@@ -3,10 +3,13 @@
 use crate::{
     storage::{get_verified_users, set_verified_users},
     error::Error,
-    types::{VerificationLevel},
+    types::{AccountStatus, VerificationLevel},
     Contract, ContractClient
 };
-use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, String, Vec,
+};
 
 // ==================== LEGACY TESTS ====================
 
@@ -1091,4 +1094,398 @@ fn test_add_verified_user() {
         &expires_at,
         &metadata.clone(),
     );   
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_set_verification_level_tiers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let moderator = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.add_moderator(&admin, &moderator);
+
+    assert_eq!(client.get_verification(&user), None);
+    assert!(!client.has_verification_level(&user, &VerificationLevel::Basic));
+
+    let expires_at = env.ledger().timestamp() + 30 * 24 * 60 * 60;
+    client.set_verification_level(&moderator, &user, &VerificationLevel::Verified, &expires_at);
+
+    let verification = client.get_verification(&user).unwrap();
+    assert_eq!(verification.level, VerificationLevel::Verified);
+    assert_eq!(verification.expires_at, expires_at);
+    assert!(verification.is_active);
+    assert!(client.has_verification_level(&user, &VerificationLevel::Basic));
+    assert!(client.has_verification_level(&user, &VerificationLevel::Verified));
+    assert!(!client.has_verification_level(&user, &VerificationLevel::Premium));
+    assert_eq!(client.get_total_users(), 1);
+
+    // Upgrading keeps the same registration
+    client.set_verification_level(&admin, &user, &VerificationLevel::Enterprise, &0);
+    assert!(client.has_verification_level(&user, &VerificationLevel::Enterprise));
+    assert_eq!(client.get_total_users(), 1);
+
+    client.set_verification_level(&admin, &user, &VerificationLevel::Premium, &expires_at);
+    env.ledger().with_mut(|l| l.timestamp = expires_at + 1);
+    assert!(!client.get_verification(&user).unwrap().is_active);
+    assert!(!client.has_verification_level(&user, &VerificationLevel::Basic));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_set_verification_level_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin) = create_contract(&env);
+    let outsider = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    client.set_verification_level(&outsider, &user, &VerificationLevel::Premium, &0);
+}
+
+#[test]
+fn test_require_min_verification() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.try_require_min_verification(&user, &VerificationLevel::Basic),
+        Err(Ok(Error::InsufficientVerification))
+    );
+
+    client.set_verification_level(&admin, &user, &VerificationLevel::Verified, &0);
+    client.require_min_verification(&user, &VerificationLevel::Verified);
+    assert_eq!(
+        client.try_require_min_verification(&user, &VerificationLevel::Premium),
+        Err(Ok(Error::InsufficientVerification))
+    );
+}
+
+#[test]
+fn test_user_status_and_require_active() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let moderator = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.add_moderator(&admin, &moderator);
+
+    assert_eq!(client.get_account_status(&user), AccountStatus::Active);
+    client.require_active(&user);
+
+    client.set_user_status(&moderator, &user, &AccountStatus::Suspended);
+    assert_eq!(client.get_account_status(&user), AccountStatus::Suspended);
+    assert_eq!(
+        client.try_require_active(&user),
+        Err(Ok(Error::UserSuspended))
+    );
+    assert!(!client.is_user_blacklisted(&user));
+
+    client.set_user_status(&moderator, &user, &AccountStatus::Blacklisted);
+    assert_eq!(client.get_account_status(&user), AccountStatus::Blacklisted);
+    assert_eq!(
+        client.try_require_active(&user),
+        Err(Ok(Error::UserBlacklisted))
+    );
+    assert!(client.is_user_blacklisted(&user));
+
+    client.set_user_status(&admin, &user, &AccountStatus::Active);
+    assert_eq!(client.get_account_status(&user), AccountStatus::Active);
+    assert!(!client.is_user_blacklisted(&user));
+    client.require_active(&user);
+}
+
+#[test]
+fn test_set_user_status_cannot_suspend_moderator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let moderator = Address::generate(&env);
+    client.add_moderator(&admin, &moderator);
+
+    assert_eq!(
+        client.try_set_user_status(&admin, &moderator, &AccountStatus::Suspended),
+        Err(Ok(Error::CannotBlacklistModerator))
+    );
+}
+
+#[test]
+fn test_bulk_set_verification_reports_per_user_results() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let moderator = Address::generate(&env);
+    client.add_moderator(&admin, &moderator);
+
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let banned = Address::generate(&env);
+    client.blacklist_user(&admin, &banned);
+
+    let mut users = Vec::new(&env);
+    users.push_back(user1.clone());
+    users.push_back(banned.clone());
+    users.push_back(user2.clone());
+
+    let results =
+        client.bulk_set_verification(&moderator, &users, &VerificationLevel::Verified, &0);
+    assert_eq!(results.len(), 3);
+    assert!(results.get(0).unwrap().success);
+    assert_eq!(results.get(1).unwrap().user, banned);
+    assert!(!results.get(1).unwrap().success);
+    assert_eq!(
+        results.get(1).unwrap().error_code,
+        Some(Error::AlreadyBlacklisted as u32)
+    );
+    assert!(results.get(2).unwrap().success);
+
+    assert!(client.has_verification_level(&user1, &VerificationLevel::Verified));
+    assert!(client.has_verification_level(&user2, &VerificationLevel::Verified));
+    assert_eq!(client.get_verification(&banned), None);
+    assert_eq!(client.get_total_users(), 2);
+}
+
+#[test]
+fn test_profile_hash_commitments() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin) = create_contract(&env);
+    let user = Address::generate(&env);
+    let first = BytesN::from_array(&env, &[1u8; 32]);
+    let second = BytesN::from_array(&env, &[2u8; 32]);
+
+    assert_eq!(client.get_profile_hash(&user), None);
+
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+    assert_eq!(client.commit_profile_hash(&user, &first), 1);
+
+    env.ledger().with_mut(|l| l.timestamp = 5_000);
+    assert_eq!(client.commit_profile_hash(&user, &second), 2);
+
+    assert_eq!(client.get_profile_hash(&user).unwrap().hash, second);
+    assert_eq!(client.get_profile_hash_history(&user).len(), 2);
+    assert_eq!(client.get_profile_hash_at(&user, &500), None);
+    assert_eq!(
+        client.get_profile_hash_at(&user, &4_999).unwrap().hash,
+        first
+    );
+    assert_eq!(
+        client.get_profile_hash_at(&user, &5_000).unwrap().hash,
+        second
+    );
+
+    // Re-committing the current hash is rejected
+    assert_eq!(
+        client.try_commit_profile_hash(&user, &second),
+        Err(Ok(Error::ValidationFailed))
+    );
+}
+
+#[test]
+fn test_delegated_verifier_levels() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let verifier = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let mut allowed_levels = Vec::new(&env);
+    allowed_levels.push_back(VerificationLevel::Basic);
+    allowed_levels.push_back(VerificationLevel::Verified);
+    client.add_verifier(&admin, &verifier, &allowed_levels);
+    assert_eq!(
+        client.get_verifier(&verifier).unwrap().allowed_levels,
+        allowed_levels
+    );
+
+    client.set_verification_level(&verifier, &user, &VerificationLevel::Verified, &0);
+    let verification = client.get_verification(&user).unwrap();
+    assert_eq!(verification.level, VerificationLevel::Verified);
+    assert_eq!(verification.verified_by, Some(verifier.clone()));
+
+    // Levels above the verifier's allowance are refused
+    assert_eq!(
+        client.try_set_verification_level(&verifier, &user, &VerificationLevel::Premium, &0),
+        Err(Ok(Error::LevelNotAllowed))
+    );
+
+    client.set_verification_level(&admin, &user, &VerificationLevel::Premium, &0);
+    assert_eq!(
+        client.get_verification(&user).unwrap().verified_by,
+        Some(admin.clone())
+    );
+
+    client.remove_verifier(&admin, &verifier);
+    assert_eq!(
+        client.try_set_verification_level(&verifier, &user, &VerificationLevel::Basic, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_list_and_count_users_by_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let user3 = Address::generate(&env);
+    for user in [&user1, &user2, &user3] {
+        client.set_verification_level(&admin, user, &VerificationLevel::Basic, &0);
+    }
+
+    assert_eq!(client.count_users(&None), 3);
+    assert_eq!(client.count_users(&Some(AccountStatus::Active)), 3);
+
+    client.set_user_status(&admin, &user1, &AccountStatus::Suspended);
+    client.blacklist_user(&admin, &user2);
+
+    assert_eq!(client.count_users(&Some(AccountStatus::Active)), 1);
+    assert_eq!(client.count_users(&Some(AccountStatus::Suspended)), 1);
+    assert_eq!(client.count_users(&Some(AccountStatus::Blacklisted)), 1);
+    assert_eq!(client.count_users(&None), 3);
+
+    let active = client.list_users(&Some(AccountStatus::Active), &0, &10);
+    assert_eq!(active, Vec::from_array(&env, [user3.clone()]));
+    assert_eq!(
+        client.list_users(&Some(AccountStatus::Blacklisted), &0, &10),
+        Vec::from_array(&env, [user2.clone()])
+    );
+
+    // Unfiltered pages run across the statuses in order
+    assert_eq!(
+        client.list_users(&None, &0, &2),
+        Vec::from_array(&env, [user3.clone(), user1.clone()])
+    );
+    assert_eq!(
+        client.list_users(&None, &2, &2),
+        Vec::from_array(&env, [user2.clone()])
+    );
+
+    client.unblacklist_user(&admin, &user2);
+    assert_eq!(client.count_users(&Some(AccountStatus::Blacklisted)), 0);
+    assert_eq!(client.count_users(&Some(AccountStatus::Active)), 2);
+}
+
+#[test]
+fn test_link_and_resolve_wallets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin) = create_contract(&env);
+    let primary = Address::generate(&env);
+    let wallet1 = Address::generate(&env);
+    let wallet2 = Address::generate(&env);
+
+    assert_eq!(client.resolve_identity(&wallet1), wallet1);
+
+    client.link_wallet(&primary, &wallet1);
+    client.link_wallet(&primary, &wallet2);
+
+    assert_eq!(client.resolve_identity(&wallet1), primary);
+    assert_eq!(client.resolve_identity(&primary), primary);
+    assert_eq!(
+        client.get_linked_wallets(&wallet2),
+        Vec::from_array(&env, [primary.clone(), wallet1.clone(), wallet2.clone()])
+    );
+
+    // A secondary can't head another identity or be linked twice
+    let other = Address::generate(&env);
+    assert_eq!(
+        client.try_link_wallet(&wallet1, &other),
+        Err(Ok(Error::WalletAlreadyLinked))
+    );
+    assert_eq!(
+        client.try_link_wallet(&other, &wallet2),
+        Err(Ok(Error::WalletAlreadyLinked))
+    );
+
+    client.unlink_wallet(&primary, &wallet1);
+    assert_eq!(client.resolve_identity(&wallet1), wallet1);
+    assert_eq!(
+        client.get_linked_wallets(&primary),
+        Vec::from_array(&env, [primary.clone(), wallet2.clone()])
+    );
+}
+
+#[test]
+fn test_rotate_primary_by_user() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let user = Address::generate(&env);
+    let wallet = Address::generate(&env);
+    let new_address = Address::generate(&env);
+
+    client.set_verification_level(&admin, &user, &VerificationLevel::Verified, &0);
+    client.link_wallet(&user, &wallet);
+    client.rotate_primary(&user, &user, &new_address);
+
+    assert_eq!(client.get_verification(&user), None);
+    let verification = client.get_verification(&new_address).unwrap();
+    assert_eq!(verification.level, VerificationLevel::Verified);
+    assert_eq!(verification.verified_by, Some(admin));
+    assert_eq!(client.count_users(&Some(AccountStatus::Active)), 1);
+
+    // The old address stays in the identity so its reputation still counts
+    assert_eq!(client.resolve_identity(&user), new_address);
+    assert_eq!(
+        client.get_linked_wallets(&user),
+        Vec::from_array(&env, [new_address.clone(), wallet.clone(), user.clone()])
+    );
+}
+
+#[test]
+fn test_rotate_primary_by_guardian_is_delayed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin) = create_contract(&env);
+    let user = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let new_address = Address::generate(&env);
+
+    client.set_verification_level(&admin, &user, &VerificationLevel::Premium, &0);
+
+    // Only the user's guardian can request a rotation
+    assert_eq!(
+        client.try_rotate_primary(&guardian, &user, &new_address),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_recovery_guardian(&user, &Some(guardian.clone()));
+
+    // The user can cancel during the delay
+    client.rotate_primary(&guardian, &user, &new_address);
+    client.cancel_rotation(&user);
+    assert_eq!(client.get_pending_rotation(&user), None);
+
+    client.rotate_primary(&guardian, &user, &new_address);
+    assert_eq!(
+        client.try_execute_rotation(&user),
+        Err(Ok(Error::RecoveryDelayNotElapsed))
+    );
+
+    let execute_after = client.get_pending_rotation(&user).unwrap().execute_after;
+    env.ledger().with_mut(|l| l.timestamp = execute_after);
+    client.execute_rotation(&user);
+
+    assert!(client.has_verification_level(&new_address, &VerificationLevel::Premium));
+    assert_eq!(client.get_recovery_guardian(&new_address), Some(guardian));
+    assert_eq!(client.get_pending_rotation(&user), None);
+    assert_eq!(
+        client.try_execute_rotation(&user),
+        Err(Ok(Error::RecoveryNotFound))
+    );
+}
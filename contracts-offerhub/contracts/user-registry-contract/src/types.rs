@@ -1,12 +1,48 @@
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
 use crate::error::Error;
 
+/// Discriminants are stored on-chain and must not change; new levels are appended.
+/// Ordering follows `rank`, not the discriminant.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum VerificationLevel {
     Basic = 1,
     Premium = 2,
     Enterprise = 3,
+    Verified = 4,
+}
+
+impl VerificationLevel {
+    /// Position of the level in the tier ladder, lowest first
+    pub fn rank(&self) -> u32 {
+        match self {
+            VerificationLevel::Basic => 0,
+            VerificationLevel::Verified => 1,
+            VerificationLevel::Premium => 2,
+            VerificationLevel::Enterprise => 3,
+        }
+    }
+}
+
+impl PartialOrd for VerificationLevel {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for VerificationLevel {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+/// Whether a user may take part in on-chain actions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccountStatus {
+    Active = 0,
+    Suspended = 1,
+    Blacklisted = 2,
 }
 
 #[contracttype]
@@ -38,6 +74,54 @@ pub struct UserProfile {
     pub validations: Vec<ValidationData>,
 }
 
+/// A third-party verifier (e.g. a KYC provider) and the levels it may grant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Verifier {
+    pub allowed_levels: Vec<VerificationLevel>,
+    pub added_at: u64,
+}
+
+/// Outcome for one user of a bulk verification. `error_code` is the `Error` discriminant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BulkVerificationResult {
+    pub user: Address,
+    pub success: bool,
+    pub error_code: Option<u32>,
+}
+
+/// A user's current verification tier, as read by other contracts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Verification {
+    pub level: VerificationLevel,
+    pub verified_at: u64,
+    pub expires_at: u64, // 0 means no expiration
+    pub is_active: bool, // Not expired and not blacklisted
+    pub verified_by: Option<Address>, // Admin, moderator or verifier that set the level
+}
+
+/// Hash of a user's off-chain profile document as of `committed_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProfileCommitment {
+    pub hash: BytesN<32>,
+    pub committed_at: u64,
+    pub ledger: u32,
+}
+
+/// Guardian-requested move of a user's identity to `new_address`, executable once
+/// `execute_after` has passed unless the user cancels it first.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingRotation {
+    pub new_address: Address,
+    pub guardian: Address,
+    pub requested_at: u64,
+    pub execute_after: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct UserStatus {
@@ -55,7 +139,7 @@ pub struct UserStatus {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct UserProfileSummary {
     pub user_address: Address,              // User's address
-    pub verification_level: String,         // Human-readable verification level (e.g., "Basic", "Premium", "Enterprise")
+    pub verification_level: String,         // Human-readable verification level (e.g., "Basic", "Verified", "Premium", "Enterprise")
     pub verified_at: u64,                   // Timestamp of verification
     pub expires_at: u64,                // Formatted expiration status (e.g., "No expiration", "Expired", or timestamp)
     pub metadata: String,                   // User metadata
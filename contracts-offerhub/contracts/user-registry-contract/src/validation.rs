@@ -20,9 +20,10 @@ pub fn validate_metadata(metadata: &String) -> Result<(), Error> {
 /// Validate verification level
 pub fn validate_verification_level(level: &VerificationLevel) -> Result<(), Error> {
     match level {
-        VerificationLevel::Basic | VerificationLevel::Premium | VerificationLevel::Enterprise => {
-            Ok(())
-        }
+        VerificationLevel::Basic
+        | VerificationLevel::Verified
+        | VerificationLevel::Premium
+        | VerificationLevel::Enterprise => Ok(()),
         // Add any invalid cases if needed
     }
 }
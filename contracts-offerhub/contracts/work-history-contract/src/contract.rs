@@ -0,0 +1,143 @@
+use crate::error::Error;
+use crate::event::{work_history_initialized, engagement_attested};
+use crate::storage::{
+    get_admin, set_admin, get_attestation, get_client_count, get_client_entry, get_engagement,
+    get_freelancer_count, get_freelancer_entry, next_attestation_id, save_attestation,
+};
+use crate::types::{Attestation, DurationBand};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+const MAX_PAGE_SIZE: u32 = 50;
+
+pub struct WorkHistoryContract;
+
+impl WorkHistoryContract {
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        if get_admin(&env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+
+        set_admin(&env, &admin);
+        work_history_initialized(&env, admin);
+        Ok(())
+    }
+
+    /// Record a completed engagement. The client attests and the freelancer co-signs in the
+    /// same call, so neither side can add an entry to the CV alone.
+    #[allow(clippy::too_many_arguments)]
+    pub fn attest(
+        env: Env,
+        client: Address,
+        freelancer: Address,
+        role: Symbol,
+        duration_band: DurationBand,
+        start_ledger: u32,
+        end_ledger: u32,
+        engagement_hash: BytesN<32>,
+    ) -> Result<u64, Error> {
+        Self::require_not_paused(&env)?;
+        if client == freelancer {
+            return Err(Error::SelfAttestation);
+        }
+        client.require_auth();
+        freelancer.require_auth();
+
+        if start_ledger > end_ledger || end_ledger > env.ledger().sequence() {
+            return Err(Error::InvalidLedgerRange);
+        }
+        if get_engagement(&env, &engagement_hash).is_some() {
+            return Err(Error::AlreadyAttested);
+        }
+
+        let attestation = Attestation {
+            id: next_attestation_id(&env),
+            client: client.clone(),
+            freelancer: freelancer.clone(),
+            role: role.clone(),
+            duration_band,
+            start_ledger,
+            end_ledger,
+            engagement_hash: engagement_hash.clone(),
+            attested_at: env.ledger().timestamp(),
+        };
+        save_attestation(&env, &attestation);
+
+        engagement_attested(
+            &env,
+            attestation.id,
+            client,
+            freelancer,
+            role,
+            engagement_hash,
+        );
+        Ok(attestation.id)
+    }
+
+    pub fn get_attestation(env: Env, id: u64) -> Result<Attestation, Error> {
+        get_attestation(&env, id).ok_or(Error::AttestationNotFound)
+    }
+
+    pub fn get_attestation_by_engagement(
+        env: Env,
+        engagement_hash: BytesN<32>,
+    ) -> Option<Attestation> {
+        get_engagement(&env, &engagement_hash).and_then(|id| get_attestation(&env, id))
+    }
+
+    pub fn get_cv_length(env: Env, freelancer: Address) -> u32 {
+        get_freelancer_count(&env, &freelancer)
+    }
+
+    /// Page through a freelancer's attested engagements, most recent first
+    pub fn get_cv(env: Env, freelancer: Address, offset: u32, limit: u32) -> Vec<Attestation> {
+        let count = get_freelancer_count(&env, &freelancer);
+        let end = offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+        let mut attestations = Vec::new(&env);
+        for position in offset..end {
+            if let Some(attestation) = get_freelancer_entry(&env, &freelancer, count - 1 - position)
+                .and_then(|id| get_attestation(&env, id))
+            {
+                attestations.push_back(attestation);
+            }
+        }
+        attestations
+    }
+
+    pub fn get_client_attestation_count(env: Env, client: Address) -> u32 {
+        get_client_count(&env, &client)
+    }
+
+    /// Page through the attestations a client has given, most recent first
+    pub fn get_client_attestations(
+        env: Env,
+        client: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Attestation> {
+        let count = get_client_count(&env, &client);
+        let end = offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+        let mut attestations = Vec::new(&env);
+        for position in offset..end {
+            if let Some(attestation) = get_client_entry(&env, &client, count - 1 - position)
+                .and_then(|id| get_attestation(&env, id))
+            {
+                attestations.push_back(attestation);
+            }
+        }
+        attestations
+    }
+
+    pub(crate) fn pause_admin(env: &Env) -> Result<Address, Error> {
+        get_admin(env).ok_or(Error::NotInitialized)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if offerhub_pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+}
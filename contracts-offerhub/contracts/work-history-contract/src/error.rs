@@ -0,0 +1,38 @@
+use offerhub_pausable::PauseError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Client and freelancer must be different addresses
+    SelfAttestation = 1,
+    /// Ledger range is empty, reversed or in the future
+    InvalidLedgerRange = 2,
+    /// This engagement has already been attested
+    AlreadyAttested = 3,
+    /// Attestation does not exist
+    AttestationNotFound = 4,
+    /// Contract is already initialized
+    AlreadyInitialized = 5,
+    /// Contract is not initialized
+    NotInitialized = 6,
+    /// Caller is not authorized to perform this action
+    Unauthorized = 7,
+    /// Contract is already paused
+    AlreadyPaused = 8,
+    /// Contract is not paused
+    NotPaused = 9,
+    /// Contract is paused
+    ContractPaused = 10,
+}
+
+impl From<PauseError> for Error {
+    fn from(error: PauseError) -> Self {
+        match error {
+            PauseError::Unauthorized => Error::Unauthorized,
+            PauseError::AlreadyPaused => Error::AlreadyPaused,
+            PauseError::NotPaused => Error::NotPaused,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+pub fn engagement_attested(
+    e: &Env,
+    id: u64,
+    client: Address,
+    freelancer: Address,
+    role: Symbol,
+    engagement_hash: BytesN<32>,
+) {
+    let topics = (Symbol::new(e, "engagement_attested"), client, freelancer);
+    e.events().publish(topics, (id, role, engagement_hash));
+}
+
+pub fn work_history_initialized(e: &Env, admin: Address) {
+    let topics = (Symbol::new(e, "work_history_initialized"),);
+    e.events().publish(topics, admin);
+}
@@ -0,0 +1,89 @@
+#![no_std]
+
+mod contract;
+mod error;
+mod event;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use crate::contract::WorkHistoryContract;
+pub use crate::error::Error;
+pub use crate::types::{Attestation, DurationBand};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Symbol, Vec};
+
+#[contract]
+pub struct Contract;
+
+offerhub_pausable::impl_pausable!(Contract, Error, |env, _| WorkHistoryContract::pause_admin(env));
+
+#[contractimpl]
+impl Contract {
+    /// Initialize the contract with the admin that manages pausing
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        WorkHistoryContract::initialize(env, admin)
+    }
+
+    /// Attest a completed engagement; requires both the client and the freelancer to sign
+    #[allow(clippy::too_many_arguments)]
+    pub fn attest(
+        env: Env,
+        client: Address,
+        freelancer: Address,
+        role: Symbol,
+        duration_band: DurationBand,
+        start_ledger: u32,
+        end_ledger: u32,
+        engagement_hash: BytesN<32>,
+    ) -> Result<u64, Error> {
+        WorkHistoryContract::attest(
+            env,
+            client,
+            freelancer,
+            role,
+            duration_band,
+            start_ledger,
+            end_ledger,
+            engagement_hash,
+        )
+    }
+
+    /// Get an attestation by id
+    pub fn get_attestation(env: Env, id: u64) -> Result<Attestation, Error> {
+        WorkHistoryContract::get_attestation(env, id)
+    }
+
+    /// Look up the attestation recorded for an engagement hash
+    pub fn get_attestation_by_engagement(
+        env: Env,
+        engagement_hash: BytesN<32>,
+    ) -> Option<Attestation> {
+        WorkHistoryContract::get_attestation_by_engagement(env, engagement_hash)
+    }
+
+    /// Number of engagements on a freelancer's CV
+    pub fn get_cv_length(env: Env, freelancer: Address) -> u32 {
+        WorkHistoryContract::get_cv_length(env, freelancer)
+    }
+
+    /// Page through a freelancer's CV, most recent engagement first
+    pub fn get_cv(env: Env, freelancer: Address, offset: u32, limit: u32) -> Vec<Attestation> {
+        WorkHistoryContract::get_cv(env, freelancer, offset, limit)
+    }
+
+    /// Number of attestations a client has given
+    pub fn get_client_attestation_count(env: Env, client: Address) -> u32 {
+        WorkHistoryContract::get_client_attestation_count(env, client)
+    }
+
+    /// Page through the attestations a client has given, most recent first
+    pub fn get_client_attestations(
+        env: Env,
+        client: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Attestation> {
+        WorkHistoryContract::get_client_attestations(env, client, offset, limit)
+    }
+}
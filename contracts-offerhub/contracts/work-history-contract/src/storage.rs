@@ -0,0 +1,104 @@
+use crate::types::Attestation;
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    NextAttestationId,
+    Attestation(u64),
+    /// engagement hash -> attestation id
+    Engagement(BytesN<32>),
+    /// freelancer -> number of attestations on its CV
+    FreelancerCount(Address),
+    /// (freelancer, position) -> attestation id
+    FreelancerEntry(Address, u32),
+    /// client -> number of attestations it gave
+    ClientCount(Address),
+    /// (client, position) -> attestation id
+    ClientEntry(Address, u32),
+}
+
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Allocate the next attestation id, starting at 1
+pub fn next_attestation_id(env: &Env) -> u64 {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::NextAttestationId)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::NextAttestationId, &id);
+    id
+}
+
+pub fn get_attestation(env: &Env, id: u64) -> Option<Attestation> {
+    env.storage().persistent().get(&DataKey::Attestation(id))
+}
+
+pub fn get_engagement(env: &Env, engagement_hash: &BytesN<32>) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Engagement(engagement_hash.clone()))
+}
+
+/// Store a new attestation and add it to the freelancer's CV and the client's listing
+pub fn save_attestation(env: &Env, attestation: &Attestation) {
+    let storage = env.storage().persistent();
+    storage.set(&DataKey::Attestation(attestation.id), attestation);
+    storage.set(
+        &DataKey::Engagement(attestation.engagement_hash.clone()),
+        &attestation.id,
+    );
+
+    let freelancer = attestation.freelancer.clone();
+    let count = get_freelancer_count(env, &freelancer);
+    storage.set(
+        &DataKey::FreelancerEntry(freelancer.clone(), count),
+        &attestation.id,
+    );
+    storage.set(&DataKey::FreelancerCount(freelancer), &(count + 1));
+
+    let client = attestation.client.clone();
+    let count = get_client_count(env, &client);
+    storage.set(
+        &DataKey::ClientEntry(client.clone(), count),
+        &attestation.id,
+    );
+    storage.set(&DataKey::ClientCount(client), &(count + 1));
+}
+
+pub fn get_freelancer_count(env: &Env, freelancer: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FreelancerCount(freelancer.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_freelancer_entry(env: &Env, freelancer: &Address, position: u32) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FreelancerEntry(freelancer.clone(), position))
+}
+
+pub fn get_client_count(env: &Env, client: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ClientCount(client.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_client_entry(env: &Env, client: &Address, position: u32) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ClientEntry(client.clone(), position))
+}
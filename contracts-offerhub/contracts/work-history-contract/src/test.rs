@@ -0,0 +1,152 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, Address, BytesN, Env};
+
+fn setup(env: &Env) -> ContractClient<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.sequence_number = 1_000);
+    ContractClient::new(env, &env.register(Contract, ()))
+}
+
+#[test]
+fn test_attestation_requires_both_signatures() {
+    let env = Env::default();
+    let history = setup(&env);
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[1; 32]);
+
+    history.attest(
+        &client,
+        &freelancer,
+        &symbol_short!("backend"),
+        &DurationBand::OneToThreeMonths,
+        &100,
+        &900,
+        &hash,
+    );
+
+    let auths = env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == client));
+    assert!(auths.iter().any(|(address, _)| *address == freelancer));
+}
+
+#[test]
+fn test_cv_is_paginated_most_recent_first() {
+    let env = Env::default();
+    let history = setup(&env);
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+
+    assert_eq!(
+        history.try_attest(
+            &client,
+            &client,
+            &symbol_short!("backend"),
+            &DurationBand::UnderOneWeek,
+            &100,
+            &200,
+            &BytesN::from_array(&env, &[0; 32]),
+        ),
+        Err(Ok(Error::SelfAttestation))
+    );
+    assert_eq!(
+        history.try_attest(
+            &client,
+            &freelancer,
+            &symbol_short!("backend"),
+            &DurationBand::UnderOneWeek,
+            &300,
+            &1_001,
+            &BytesN::from_array(&env, &[0; 32]),
+        ),
+        Err(Ok(Error::InvalidLedgerRange))
+    );
+
+    for i in 1..=3u8 {
+        history.attest(
+            &client,
+            &freelancer,
+            &symbol_short!("backend"),
+            &DurationBand::OneToFourWeeks,
+            &(i as u32 * 100),
+            &(i as u32 * 200),
+            &BytesN::from_array(&env, &[i; 32]),
+        );
+    }
+    assert_eq!(
+        history.try_attest(
+            &client,
+            &freelancer,
+            &symbol_short!("backend"),
+            &DurationBand::OneToFourWeeks,
+            &100,
+            &200,
+            &BytesN::from_array(&env, &[1; 32]),
+        ),
+        Err(Ok(Error::AlreadyAttested))
+    );
+
+    assert_eq!(history.get_cv_length(&freelancer), 3);
+    let first_page = history.get_cv(&freelancer, &0, &2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get(0).unwrap().id, 3);
+    assert_eq!(first_page.get(1).unwrap().id, 2);
+    let second_page = history.get_cv(&freelancer, &2, &2);
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get(0).unwrap().start_ledger, 100);
+
+    assert_eq!(history.get_client_attestation_count(&client), 3);
+    assert_eq!(
+        history
+            .get_attestation_by_engagement(&BytesN::from_array(&env, &[2; 32]))
+            .unwrap()
+            .id,
+        2
+    );
+    assert_eq!(
+        history.try_get_attestation(&9),
+        Err(Ok(Error::AttestationNotFound))
+    );
+}
+
+#[test]
+fn test_pause_blocks_attestations() {
+    let env = Env::default();
+    let history = setup(&env);
+    let admin = Address::generate(&env);
+    let pauser = Address::generate(&env);
+    let client = Address::generate(&env);
+    let freelancer = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[1; 32]);
+    history.initialize(&admin);
+    history.add_pauser(&admin, &pauser);
+
+    history.pause(&pauser);
+    assert_eq!(
+        history.try_attest(
+            &client,
+            &freelancer,
+            &symbol_short!("backend"),
+            &DurationBand::OneToThreeMonths,
+            &100,
+            &900,
+            &hash,
+        ),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    history.unpause(&admin);
+    history.attest(
+        &client,
+        &freelancer,
+        &symbol_short!("backend"),
+        &DurationBand::OneToThreeMonths,
+        &100,
+        &900,
+        &hash,
+    );
+    assert_eq!(history.get_cv_length(&freelancer), 1);
+}
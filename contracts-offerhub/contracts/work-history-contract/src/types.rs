@@ -0,0 +1,30 @@
+use soroban_sdk::{contracttype, Address, BytesN, Symbol};
+
+/// Coarse engagement length, so attestations do not disclose exact contract terms
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum DurationBand {
+    UnderOneWeek = 0,
+    OneToFourWeeks = 1,
+    OneToThreeMonths = 2,
+    ThreeToTwelveMonths = 3,
+    OverOneYear = 4,
+}
+
+/// A completed engagement signed by both the client and the freelancer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    pub id: u64,
+    pub client: Address,
+    pub freelancer: Address,
+    /// Role the freelancer held, e.g. "backend" or "designer"
+    pub role: Symbol,
+    pub duration_band: DurationBand,
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+    /// Hash of the off-chain engagement record (contract, escrow id, deliverables)
+    pub engagement_hash: BytesN<32>,
+    pub attested_at: u64,
+}
@@ -453,7 +453,7 @@ pub fn test_bulk_operations(test_setup: &TestSetup, users: Vec<Address>) {
 pub fn test_fee_manager_statistics(test_setup: &TestSetup) {
     // Get fee statistics
     let fee_stats = test_setup.fee_manager.get_fee_stats();
-    let fee_history = test_setup.fee_manager.get_fee_history();
+    let fee_history = test_setup.fee_manager.get_fee_history(&0, &100);
     let premium_users = test_setup.fee_manager.get_premium_users();
     let platform_balance = test_setup.fee_manager.get_platform_balance();
     